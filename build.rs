@@ -1,27 +1,113 @@
 //! Emits linker flags depending on platforms and features.
+//!
+//! This also resolves which UI framework we're building against. The `appkit` and `uikit` features
+//! can both be enabled (e.g, for a crate graph shared between a macOS and an iOS app), in which
+//! case the target OS decides: AppKit for macOS, UIKit for everything else. The result is exposed
+//! to the crate as the `appkit` or `uikit` cfg, and that's what code should gate on - not the
+//! features directly. Mac Catalyst builds additionally get the `catalyst` cfg.
+//!
+//! Linking is pluggable as well: by default we link Apple's frameworks (which also covers Airyx,
+//! as it ships framework bundles), while the `gnustep` feature links GNUstep's libraries instead
+//! and exposes the `gnustep` cfg.
+
+use std::env;
+
+/// How we link against the system's Objective-C frameworks.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Linkage {
+    /// `-framework Foundation` and friends - macOS, iOS and Airyx.
+    Frameworks,
+
+    /// `-lgnustep-base`/`-lgnustep-gui`.
+    GNUstep
+}
+
+impl Linkage {
+    fn link(self, framework: &str) {
+        match self {
+            Linkage::Frameworks => println!("cargo:rustc-link-lib=framework={}", framework),
+
+            // GNUstep folds Foundation and AppKit into two libraries, and has no counterpart for
+            // the rest; anything we can't map is simply skipped, and the corresponding APIs are
+            // expected to degrade at runtime.
+            Linkage::GNUstep => match framework {
+                "Foundation" => println!("cargo:rustc-link-lib=dylib=gnustep-base"),
+                "AppKit" => println!("cargo:rustc-link-lib=dylib=gnustep-gui"),
+                _ => {}
+            }
+        }
+    }
+}
 
 fn main() {
-    println!("cargo:rustc-link-lib=framework=Foundation");
+    let appkit_feature = env::var_os("CARGO_FEATURE_APPKIT").is_some();
+    let uikit_feature = env::var_os("CARGO_FEATURE_UIKIT").is_some();
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+
+    let (appkit, uikit) = match (appkit_feature, uikit_feature) {
+        (true, true) => (target_os == "macos", target_os != "macos"),
+        (appkit, uikit) => (appkit, uikit)
+    };
 
-    #[cfg(feature = "appkit")]
-    println!("cargo:rustc-link-lib=framework=AppKit");
+    println!("cargo:rustc-check-cfg=cfg(appkit)");
+    println!("cargo:rustc-check-cfg=cfg(uikit)");
+    println!("cargo:rustc-check-cfg=cfg(catalyst)");
+    println!("cargo:rustc-check-cfg=cfg(gnustep)");
 
-    #[cfg(feature = "uikit")]
-    println!("cargo:rustc-link-lib=framework=UIKit");
+    let linkage = match env::var_os("CARGO_FEATURE_GNUSTEP").is_some() && appkit {
+        true => {
+            println!("cargo:rustc-cfg=gnustep");
+            Linkage::GNUstep
+        },
 
-    println!("cargo:rustc-link-lib=framework=CoreGraphics");
-    println!("cargo:rustc-link-lib=framework=QuartzCore");
-    println!("cargo:rustc-link-lib=framework=Security");
+        false => Linkage::Frameworks
+    };
+
+    // Mac Catalyst builds are iOS builds (`target_os = "ios"`) with the `macabi` ABI; they use
+    // UIKit, plus a handful of Mac-only UIKit APIs that we gate behind this cfg.
+    if uikit && env::var("CARGO_CFG_TARGET_ABI").map(|abi| abi == "macabi").unwrap_or(false) {
+        println!("cargo:rustc-cfg=catalyst");
+    }
+
+    linkage.link("Foundation");
+
+    if appkit {
+        println!("cargo:rustc-cfg=appkit");
+        linkage.link("AppKit");
+    }
+
+    if uikit {
+        println!("cargo:rustc-cfg=uikit");
+        linkage.link("UIKit");
+    }
+
+    linkage.link("CoreGraphics");
+    linkage.link("QuartzCore");
+    linkage.link("Security");
 
     #[cfg(feature = "webview")]
-    println!("cargo:rustc-link-lib=framework=WebKit");
+    linkage.link("WebKit");
 
     #[cfg(feature = "cloudkit")]
-    println!("cargo:rustc-link-lib=framework=CloudKit");
+    linkage.link("CloudKit");
+
+    #[cfg(feature = "corespotlight")]
+    linkage.link("CoreSpotlight");
+
+    // CoreHaptics isn't linked: it's missing before macOS 10.15, and linking it would stop apps from
+    // launching there. `game_controller::Haptics` loads it at runtime instead.
+    #[cfg(feature = "game-controller")]
+    linkage.link("GameController");
 
     #[cfg(feature = "user-notifications")]
-    println!("cargo:rustc-link-lib=framework=UserNotifications");
+    linkage.link("UserNotifications");
+
+    #[cfg(feature = "persistence")]
+    println!("cargo:rustc-link-lib=dylib=sqlite3");
 
     #[cfg(feature = "quicklook")]
-    println!("cargo:rustc-link-lib=framework=QuickLook");
+    {
+        linkage.link("QuickLook");
+        linkage.link("QuickLookThumbnailing");
+    }
 }
@@ -0,0 +1,372 @@
+//! Derive macros for cacao. These are re-exported from `cacao` itself when its `derive` feature is
+//! enabled, and generate code that refers to `::cacao` - so you shouldn't need to depend on this
+//! crate directly.
+//!
+//! - `WindowWrapper` forwards common `Window` methods to a window field, for structs that wrap
+//! one alongside their own state.
+//! - `ViewWrapper` implements `ObjcAccess` and `Layout` by forwarding to a view field, so the
+//! wrapper can be used anywhere a view can (e.g, `add_subview()`).
+//! - `Dispatch` generates `dispatch()` and `dispatch_background()` helpers for a `Dispatcher`
+//! message type.
+//!
+//! Field names (and, for `Dispatch`, the app delegate type) are configured with a `#[cacao(...)]`
+//! attribute on the type.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitStr, Type};
+
+/// The keys that can appear in a `#[cacao(...)]` attribute. Every derive accepts all of them, so
+/// that a type can derive more than one.
+const KEYS: [&str; 3] = ["window", "view", "app"];
+
+/// Forwards common `Window` methods (`show()`, `close()`, `set_title()`, `set_content_view()`,
+/// and so on) to a field holding a `Window`, by default named `window`.
+///
+/// ```rust,ignore
+/// use cacao::appkit::window::Window;
+/// use cacao::WindowWrapper;
+///
+/// #[derive(WindowWrapper)]
+/// #[cacao(window = "main")]
+/// struct MainWindow {
+///     main: Window,
+///     documents: Vec<String>
+/// }
+///
+/// let window = MainWindow { main: Window::default(), documents: vec![] };
+/// window.set_title("Documents");
+/// window.show();
+/// ```
+#[proc_macro_derive(WindowWrapper, attributes(cacao))]
+pub fn derive_window_wrapper(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(window_wrapper(&input))
+}
+
+/// Implements `ObjcAccess` and `Layout` by forwarding to a field holding a view (or any other
+/// `Layout` type), by default named `view`.
+///
+/// ```rust,ignore
+/// use cacao::layout::Layout;
+/// use cacao::text::Label;
+/// use cacao::view::View;
+/// use cacao::ViewWrapper;
+///
+/// #[derive(ViewWrapper)]
+/// #[cacao(view = "content")]
+/// struct Badge {
+///     content: View,
+///     count: Label
+/// }
+///
+/// let badge = Badge { content: View::new(), count: Label::new() };
+/// badge.add_subview(&badge.count);
+/// ```
+#[proc_macro_derive(ViewWrapper, attributes(cacao))]
+pub fn derive_view_wrapper(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(view_wrapper(&input))
+}
+
+/// Generates `dispatch()` and `dispatch_background()` methods for a message type, which send it
+/// to the app delegate's `Dispatcher::on_ui_message()` and `on_background_message()`
+/// respectively. The app delegate type is required.
+///
+/// ```rust,ignore
+/// use cacao::Dispatch;
+///
+/// #[derive(Debug, Dispatch)]
+/// #[cacao(app = "BasicApp")]
+/// enum Action {
+///     Back,
+///     Load(String)
+/// }
+///
+/// // Rather than `App::<BasicApp, Action>::dispatch_main(Action::Back)`:
+/// Action::Back.dispatch();
+/// ```
+///
+/// This is appkit-only for now.
+#[proc_macro_derive(Dispatch, attributes(cacao))]
+pub fn derive_dispatch(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(dispatch(&input))
+}
+
+/// Turns the result of a derive into tokens, reporting errors at the right spot.
+fn expand(result: syn::Result<TokenStream2>) -> TokenStream {
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into()
+    }
+}
+
+fn window_wrapper(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let field = field(input, "window")?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Sets the title of the wrapped window.
+            pub fn set_title(&self, title: &str) {
+                self.#field.set_title(title);
+            }
+
+            /// Sets the content size of the wrapped window.
+            pub fn set_content_size<F: Into<f64>>(&self, width: F, height: F) {
+                self.#field.set_content_size(width, height);
+            }
+
+            /// Sets the minimum size the wrapped window can shrink to.
+            pub fn set_minimum_content_size<F: Into<f64>>(&self, width: F, height: F) {
+                self.#field.set_minimum_content_size(width, height);
+            }
+
+            /// Sets the content view of the wrapped window.
+            pub fn set_content_view<L: ::cacao::layout::Layout + 'static>(&self, view: &L) {
+                self.#field.set_content_view(view);
+            }
+
+            /// Sets the content view controller of the wrapped window.
+            pub fn set_content_view_controller<VC: ::cacao::utils::Controller + 'static>(&self, controller: &VC) {
+                self.#field.set_content_view_controller(controller);
+            }
+
+            /// Shows the wrapped window.
+            pub fn show(&self) {
+                self.#field.show();
+            }
+
+            /// Closes the wrapped window.
+            pub fn close(&self) {
+                self.#field.close();
+            }
+        }
+    })
+}
+
+fn view_wrapper(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let field = field(input, "view")?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::cacao::objc_access::ObjcAccess for #name #ty_generics #where_clause {
+            fn with_backing_obj_mut<F: Fn(::cacao::foundation::id)>(&self, handler: F) {
+                ::cacao::objc_access::ObjcAccess::with_backing_obj_mut(&self.#field, handler)
+            }
+
+            fn get_from_backing_obj<F: Fn(&::cacao::objc::runtime::Object) -> R, R>(&self, handler: F) -> R {
+                ::cacao::objc_access::ObjcAccess::get_from_backing_obj(&self.#field, handler)
+            }
+        }
+
+        impl #impl_generics ::cacao::layout::Layout for #name #ty_generics #where_clause {}
+    })
+}
+
+fn dispatch(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let app: Type = match attribute(input, "app")? {
+        Some(app) => app.parse()?,
+
+        None => {
+            return Err(Error::new_spanned(
+                name,
+                "`Dispatch` needs the app delegate type, e.g `#[cacao(app = \"MyApp\")]`"
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Sends this message to the app delegate's `on_ui_message()`, on the main thread.
+            pub fn dispatch(self) {
+                ::cacao::appkit::App::<#app, Self>::dispatch_main(self);
+            }
+
+            /// Sends this message to the app delegate's `on_background_message()`.
+            pub fn dispatch_background(self) {
+                ::cacao::appkit::App::<#app, Self>::dispatch_background(self);
+            }
+        }
+    })
+}
+
+/// Returns the value of `key` in the type's `#[cacao(...)]` attribute, if it's set.
+fn attribute(input: &DeriveInput, key: &str) -> syn::Result<Option<LitStr>> {
+    let mut value = None;
+
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("cacao")) {
+        attr.parse_nested_meta(|meta| {
+            if !KEYS.iter().any(|known| meta.path.is_ident(known)) {
+                return Err(meta.error(format!("expected one of: {}", KEYS.join(", "))));
+            }
+
+            let literal: LitStr = meta.value()?.parse()?;
+
+            if meta.path.is_ident(key) {
+                value = Some(literal);
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(value)
+}
+
+/// Returns the name of the field to forward to: either the one set via `#[cacao(key = "...")]`,
+/// or the one named `key`. It's an error if the type doesn't have that field.
+fn field(input: &DeriveInput, key: &str) -> syn::Result<Ident> {
+    let field = match attribute(input, key)? {
+        Some(name) => name.parse::<Ident>()?,
+        None => Ident::new(key, Span::call_site())
+    };
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "expected a struct with named fields"))
+        },
+
+        _ => return Err(Error::new_spanned(&input.ident, "expected a struct with named fields"))
+    };
+
+    match fields.iter().any(|f| f.ident.as_ref() == Some(&field)) {
+        true => Ok(field),
+
+        false => Err(Error::new_spanned(
+            &input.ident,
+            format!("no field named `{}`; set one with `#[cacao({} = \"...\")]`", field, key)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    /// Whether the expansion contains `expected`, compared as token strings so that spacing
+    /// doesn't matter.
+    fn contains(expanded: &TokenStream2, expected: TokenStream2) -> bool {
+        expanded.to_string().contains(&expected.to_string())
+    }
+
+    #[test]
+    fn window_wrapper_forwards_to_the_window_field() {
+        let input: DeriveInput = parse_quote! {
+            struct MainWindow {
+                window: Window,
+                documents: Vec<String>
+            }
+        };
+
+        let expanded = window_wrapper(&input).unwrap();
+        assert!(contains(&expanded, quote!(impl MainWindow)));
+        assert!(contains(&expanded, quote!(self.window.set_title(title);)));
+        assert!(contains(&expanded, quote!(self.window.show();)));
+    }
+
+    #[test]
+    fn window_wrapper_uses_the_configured_field() {
+        let input: DeriveInput = parse_quote! {
+            #[cacao(window = "main")]
+            struct MainWindow {
+                main: Window
+            }
+        };
+
+        let expanded = window_wrapper(&input).unwrap();
+        assert!(contains(&expanded, quote!(self.main.close();)));
+    }
+
+    #[test]
+    fn view_wrapper_implements_layout_for_generic_types() {
+        let input: DeriveInput = parse_quote! {
+            #[cacao(view = "content")]
+            struct Badge<T: Clone> {
+                content: View,
+                value: T
+            }
+        };
+
+        let expanded = view_wrapper(&input).unwrap();
+        assert!(contains(&expanded, quote!(ObjcAccess for Badge<T>)));
+        assert!(contains(
+            &expanded,
+            quote!(
+                impl<T: Clone> ::cacao::layout::Layout for Badge<T> {}
+            )
+        ));
+        assert!(contains(&expanded, quote!(with_backing_obj_mut(&self.content, handler))));
+    }
+
+    #[test]
+    fn view_wrapper_requires_the_field() {
+        let input: DeriveInput = parse_quote! {
+            struct Badge {
+                content: View
+            }
+        };
+
+        let error = view_wrapper(&input).unwrap_err().to_string();
+        assert!(error.contains("no field named `view`"));
+    }
+
+    #[test]
+    fn view_wrapper_requires_named_fields() {
+        let input: DeriveInput = parse_quote! {
+            struct Badge(View);
+        };
+
+        let error = view_wrapper(&input).unwrap_err().to_string();
+        assert_eq!(error, "expected a struct with named fields");
+    }
+
+    #[test]
+    fn unknown_attribute_keys_are_rejected() {
+        let input: DeriveInput = parse_quote! {
+            #[cacao(widow = "main")]
+            struct MainWindow {
+                main: Window
+            }
+        };
+
+        let error = window_wrapper(&input).unwrap_err().to_string();
+        assert_eq!(error, "expected one of: window, view, app");
+    }
+
+    #[test]
+    fn dispatch_sends_to_the_app_delegate() {
+        let input: DeriveInput = parse_quote! {
+            #[cacao(app = "BasicApp")]
+            enum Action {
+                Back,
+                Load(String)
+            }
+        };
+
+        let expanded = dispatch(&input).unwrap();
+        assert!(contains(&expanded, quote!(App::<BasicApp, Self>::dispatch_main(self);)));
+        assert!(contains(&expanded, quote!(App::<BasicApp, Self>::dispatch_background(self);)));
+    }
+
+    #[test]
+    fn dispatch_requires_the_app_delegate_type() {
+        let input: DeriveInput = parse_quote! {
+            enum Action {
+                Back
+            }
+        };
+
+        let error = dispatch(&input).unwrap_err().to_string();
+        assert!(error.contains("needs the app delegate type"));
+    }
+}
@@ -9,7 +9,7 @@ use cacao::color::Color;
 use cacao::layout::{Layout, LayoutConstraint, LayoutConstraintAnimatorProxy};
 use cacao::view::{LayerContentsRedrawPolicy, View, ViewAnimatorProxy};
 
-use cacao::appkit::menu::Menu;
+use cacao::appkit::menu::{Menu, StandardMenuOptions};
 use cacao::appkit::window::{Window, WindowConfig, WindowDelegate};
 use cacao::appkit::{AnimationContext, App, AppDelegate};
 use cacao::appkit::{Event, EventMask, EventMonitor};
@@ -20,7 +20,7 @@ struct BasicApp {
 
 impl AppDelegate for BasicApp {
     fn did_finish_launching(&self) {
-        App::set_menu(Menu::standard());
+        App::set_menu(Menu::standard("Animation", StandardMenuOptions::default()));
         App::activate();
 
         self.window.show();
@@ -7,16 +7,18 @@ use cacao::webview::{WebView, WebViewConfig, WebViewDelegate};
 use cacao::appkit::menu::{Menu, MenuItem};
 use cacao::appkit::toolbar::Toolbar;
 use cacao::appkit::window::{Window, WindowConfig, WindowDelegate, WindowToolbarStyle};
-use cacao::appkit::{App, AppDelegate};
+use cacao::appkit::{App, AppDelegate, EventModifierFlag};
 
 mod toolbar;
-use toolbar::BrowserToolbar;
+use toolbar::{BrowserToolbar, BACK_BUTTON, FWDS_BUTTON};
 
 #[derive(Debug)]
 pub enum Action {
     Back,
     Forwards,
-    Load(String)
+    FocusURLBar,
+    Load(String),
+    Navigated
 }
 
 impl Action {
@@ -32,34 +34,42 @@ struct BasicApp {
 impl AppDelegate for BasicApp {
     fn did_finish_launching(&self) {
         App::set_menu(vec![
-            Menu::new("", vec![
-                MenuItem::Services,
-                MenuItem::Separator,
-                MenuItem::Hide,
-                MenuItem::HideOthers,
-                MenuItem::ShowAll,
-                MenuItem::Separator,
-                MenuItem::Quit,
-            ]),
-            Menu::new("File", vec![MenuItem::CloseWindow]),
-            Menu::new("Edit", vec![
-                MenuItem::Undo,
-                MenuItem::Redo,
-                MenuItem::Separator,
-                MenuItem::Cut,
-                MenuItem::Copy,
-                MenuItem::Paste,
-                MenuItem::Separator,
-                MenuItem::SelectAll,
-            ]),
-            Menu::new("View", vec![MenuItem::EnterFullScreen]),
-            Menu::new("Window", vec![
-                MenuItem::Minimize,
-                MenuItem::Zoom,
-                MenuItem::Separator,
-                MenuItem::new("Bring All to Front"),
-            ]),
-            Menu::new("Help", vec![]),
+            Menu::build("")
+                .item(MenuItem::Services)
+                .separator()
+                .item(MenuItem::Hide)
+                .item(MenuItem::HideOthers)
+                .item(MenuItem::ShowAll)
+                .separator()
+                .item(MenuItem::Quit)
+                .build(),
+            Menu::build("File")
+                .item(
+                    MenuItem::new("Open Location...")
+                        .key_equivalent("l", &[EventModifierFlag::Command])
+                        .action(|| Action::FocusURLBar.dispatch())
+                )
+                .separator()
+                .item(MenuItem::CloseWindow)
+                .build(),
+            Menu::build("Edit")
+                .item(MenuItem::Undo)
+                .item(MenuItem::Redo)
+                .separator()
+                .item(MenuItem::Cut)
+                .item(MenuItem::Copy)
+                .item(MenuItem::Paste)
+                .separator()
+                .item(MenuItem::SelectAll)
+                .build(),
+            Menu::build("View").item(MenuItem::EnterFullScreen).build(),
+            Menu::build("Window")
+                .item(MenuItem::Minimize)
+                .item(MenuItem::Zoom)
+                .separator()
+                .item(MenuItem::new("Bring All to Front"))
+                .build(),
+            Menu::build("Help").build(),
         ]);
 
         App::activate();
@@ -81,8 +91,15 @@ impl Dispatcher for BasicApp {
             Action::Forwards => {
                 webview.go_forward();
             },
+            Action::FocusURLBar => {
+                window.toolbar.delegate.as_ref().unwrap().focus_url_bar();
+            },
             Action::Load(url) => {
                 window.load_url(&url);
+            },
+            Action::Navigated => {
+                window.toolbar.set_item_enabled(BACK_BUTTON, webview.can_go_back());
+                window.toolbar.set_item_enabled(FWDS_BUTTON, webview.can_go_forward());
             }
         }
     }
@@ -93,6 +110,10 @@ pub struct WebViewInstance;
 
 impl WebViewDelegate for WebViewInstance {
     const NAME: &'static str = "BrowserWebViewDelegate";
+
+    fn did_commit_navigation(&self) {
+        Action::Navigated.dispatch();
+    }
 }
 
 struct AppWindow {
@@ -7,8 +7,8 @@ use cacao::appkit::toolbar::{ItemIdentifier, Toolbar, ToolbarDelegate, ToolbarDi
 
 use super::Action;
 
-const BACK_BUTTON: &str = "BackButton";
-const FWDS_BUTTON: &str = "FwdsButton";
+pub const BACK_BUTTON: &str = "BackButton";
+pub const FWDS_BUTTON: &str = "FwdsButton";
 const URL_BAR: &str = "URLBar";
 
 #[derive(Debug)]
@@ -67,6 +67,10 @@ impl BrowserToolbar {
         self.url_bar.set_text(url);
     }
 
+    pub fn focus_url_bar(&self) {
+        self.url_bar.focus();
+    }
+
     fn item_identifiers(&self) -> Vec<ItemIdentifier> {
         vec![
             ItemIdentifier::Custom(BACK_BUTTON),
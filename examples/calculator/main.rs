@@ -9,13 +9,12 @@
 //! It does not attempt to be a good calculator, and does not implement the
 //! extended Calculator view.
 
-use std::sync::RwLock;
-
 use cacao::appkit::window::{TitleVisibility, Window, WindowConfig};
 use cacao::appkit::{App, AppDelegate};
 use cacao::appkit::{Event, EventMask, EventMonitor};
 use cacao::color::Color;
 use cacao::notification_center::Dispatcher;
+use cacao::state::State;
 use cacao::view::View;
 
 mod button_row;
@@ -27,7 +26,7 @@ use content_view::CalculatorView;
 struct CalculatorApp {
     window: Window,
     content: View<CalculatorView>,
-    key_monitor: RwLock<Option<EventMonitor>>
+    key_monitor: State<Option<EventMonitor>>
 }
 
 impl AppDelegate for CalculatorApp {
@@ -35,8 +34,7 @@ impl AppDelegate for CalculatorApp {
         App::activate();
 
         // Event Monitors need to be started after the App has been activated.
-        // We use an RwLock here, but it's possible this entire method can be
-        // &mut self and you wouldn't need these kinds of shenanigans.
+        // The monitor is held in a `State`, since delegate methods take &self.
         //self.start_monitoring();
 
         self.window.set_title("Calculator");
@@ -68,8 +66,7 @@ impl CalculatorApp {
     /// Monitor for key presses, and dispatch if they match an action
     /// we're after.
     pub fn start_monitoring(&self) {
-        let mut lock = self.key_monitor.write().unwrap();
-        *lock = Some(Event::local_monitor(EventMask::KeyDown, |evt| {
+        self.key_monitor.set(Some(Event::local_monitor(EventMask::KeyDown, |evt| {
             let characters = evt.characters();
             println!("{}", characters);
 
@@ -97,7 +94,7 @@ impl CalculatorApp {
             }*/
 
             None
-        }));
+        })));
     }
 }
 
@@ -108,7 +105,7 @@ fn main() {
     App::new("com.example.calculator", CalculatorApp {
         window: Window::new(config),
         content: View::with(CalculatorView::new()),
-        key_monitor: RwLock::new(None)
+        key_monitor: State::default()
     })
     .run();
 }
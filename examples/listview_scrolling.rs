@@ -0,0 +1,163 @@
+//! A benchmark for `ListView` scrolling: it fills a list with 50,000 rows, then scrolls through
+//! the whole thing, forcing a display pass at each step so every visible row is dequeued and
+//! configured. Once it's done, it prints the timings and quits.
+//!
+//! Row configuration goes through `ObjcProperty` for every label and view touched, so this is the
+//! number to watch when changing how property access works. To compare two revisions, run this
+//! against each of them, in release mode:
+//!
+//! `cargo run --release --example listview_scrolling`
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use cacao::layout::{Layout, LayoutConstraint};
+use cacao::listview::{ListView, ListViewDelegate, ListViewRow};
+use cacao::objc::{msg_send, sel};
+use cacao::text::Label;
+use cacao::view::{View, ViewDelegate};
+
+use cacao::appkit::window::Window;
+use cacao::appkit::{App, AppDelegate};
+
+/// How many rows the list has.
+const ROWS: usize = 50_000;
+
+/// How many rows each scroll step moves by - roughly a page, at the default window size.
+const ROWS_PER_STEP: usize = 20;
+
+/// An identifier for the cell(s) we dequeue.
+const ROW: &str = "BenchmarkRowCell";
+
+/// A row with a couple of labels, configured every time it's displayed.
+#[derive(Debug, Default)]
+struct BenchmarkRow {
+    title: Label,
+    detail: Label
+}
+
+impl ViewDelegate for BenchmarkRow {
+    const NAME: &'static str = "BenchmarkRow";
+
+    fn did_load(&mut self, view: View) {
+        view.add_subview(&self.title);
+        view.add_subview(&self.detail);
+
+        LayoutConstraint::activate(&[
+            self.title.top.constraint_equal_to(&view.top).offset(4.),
+            self.title.leading.constraint_equal_to(&view.leading).offset(16.),
+            self.title.trailing.constraint_equal_to(&view.trailing).offset(-16.),
+            self.detail.top.constraint_equal_to(&self.title.bottom).offset(2.),
+            self.detail.leading.constraint_equal_to(&view.leading).offset(16.),
+            self.detail.trailing.constraint_equal_to(&view.trailing).offset(-16.)
+        ]);
+    }
+}
+
+/// The list, which counts how many rows it's configured.
+#[derive(Debug, Default)]
+struct BenchmarkList {
+    view: Option<ListView>,
+    configured: Cell<usize>
+}
+
+impl ListViewDelegate for BenchmarkList {
+    const NAME: &'static str = "BenchmarkList";
+
+    fn did_load(&mut self, view: ListView) {
+        view.register(ROW, BenchmarkRow::default);
+        view.set_row_height(44.);
+        self.view = Some(view);
+    }
+
+    fn number_of_items(&self) -> usize {
+        ROWS
+    }
+
+    fn item_for(&self, row: usize) -> ListViewRow {
+        let mut view = self.view.as_ref().unwrap().dequeue::<BenchmarkRow>(ROW);
+
+        if let Some(view) = &mut view.delegate {
+            view.title.set_text(format!("Row {}", row));
+            view.detail.set_text(format!("{} of {}", row + 1, ROWS));
+        }
+
+        self.configured.set(self.configured.get() + 1);
+        view.into_row()
+    }
+}
+
+struct BenchmarkApp {
+    window: Window,
+    list: ListView<BenchmarkList>
+}
+
+impl BenchmarkApp {
+    /// Scrolls from the top of the list to the bottom, displaying at each step, and returns how
+    /// long each step took.
+    fn scroll_through(&self) -> Vec<Duration> {
+        let mut steps = Vec::with_capacity(ROWS / ROWS_PER_STEP);
+
+        for row in (0..ROWS).step_by(ROWS_PER_STEP) {
+            let start = Instant::now();
+
+            self.list.scroll_row_to_visible(row);
+            unsafe {
+                let _: () = msg_send![&*self.window.objc, displayIfNeeded];
+            }
+
+            steps.push(start.elapsed());
+        }
+
+        steps
+    }
+}
+
+impl AppDelegate for BenchmarkApp {
+    fn did_finish_launching(&self) {
+        App::activate();
+
+        self.window.set_title("ListView Scrolling Benchmark");
+        self.window.set_content_view(&self.list);
+        self.window.show();
+
+        // Get the initial load out of the way, so it doesn't count towards the first step.
+        unsafe {
+            let _: () = msg_send![&*self.window.objc, displayIfNeeded];
+        }
+
+        let delegate = self.list.delegate.as_ref().unwrap();
+        delegate.configured.set(0);
+
+        let mut steps = self.scroll_through();
+        let total: Duration = steps.iter().sum();
+        let configured = delegate.configured.get();
+
+        steps.sort();
+        let median = steps[steps.len() / 2];
+        let p99 = steps[steps.len() * 99 / 100];
+
+        println!("Scrolled through {} rows in {} steps: {:?}", ROWS, steps.len(), total);
+        println!(
+            "Rows configured: {} ({:?} per row)",
+            configured,
+            total / configured.max(1) as u32
+        );
+        println!(
+            "Per step: median {:?}, p99 {:?}, max {:?}",
+            median,
+            p99,
+            steps[steps.len() - 1]
+        );
+
+        App::terminate();
+    }
+}
+
+fn main() {
+    App::new("com.cacao.listview-scrolling", BenchmarkApp {
+        window: Window::default(),
+        list: ListView::with(BenchmarkList::default())
+    })
+    .run();
+}
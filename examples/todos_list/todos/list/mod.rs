@@ -97,7 +97,7 @@ impl ListViewDelegate for TodosListView {
                 actions.push(RowAction::new(
                     "Mark Incomplete",
                     RowActionStyle::Destructive,
-                    move |_action, row| {
+                    move |row, _dismisser| {
                         dispatch_ui(Message::MarkTodoIncomplete(row));
                     }
                 ));
@@ -107,7 +107,7 @@ impl ListViewDelegate for TodosListView {
                 actions.push(RowAction::new(
                     "Mark Complete",
                     RowActionStyle::Regular,
-                    move |_action, row| {
+                    move |row, _dismisser| {
                         dispatch_ui(Message::MarkTodoComplete(row));
                     }
                 ));
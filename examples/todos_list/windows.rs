@@ -3,10 +3,9 @@
 //!
 //! This could be a lot cleaner, and is something I'd like to make cleaner on a framework level.
 
-use std::sync::RwLock;
-
 use cacao::appkit::window::{Window, WindowConfig, WindowDelegate, WindowStyle, WindowToolbarStyle};
 use cacao::notification_center::Dispatcher;
+use cacao::state::State;
 
 use crate::storage::Message;
 
@@ -16,28 +15,28 @@ use crate::todos::TodosWindow;
 
 #[derive(Default)]
 pub struct WindowManager {
-    pub main: RwLock<Option<Window<TodosWindow>>>,
-    pub preferences: RwLock<Option<Window<PreferencesWindow>>>,
-    pub add: RwLock<Option<Window<AddNewTodoWindow>>>
+    pub main: State<Option<Window<TodosWindow>>>,
+    pub preferences: State<Option<Window<PreferencesWindow>>>,
+    pub add: State<Option<Window<AddNewTodoWindow>>>
 }
 
 /// A helper method to handle checking for window existence, and creating
 /// it if not - then showing it.
-fn open_or_show<T, F>(window: &RwLock<Option<Window<T>>>, vendor: F)
+fn open_or_show<T, F>(window: &State<Option<Window<T>>>, vendor: F)
 where
     T: WindowDelegate + 'static,
     F: Fn() -> (WindowConfig, T)
 {
-    let mut lock = window.write().unwrap();
-
-    if let Some(win) = &*lock {
-        win.show();
-    } else {
-        let (config, delegate) = vendor();
-        let win = Window::with(config, delegate);
-        win.show();
-        *lock = Some(win);
-    }
+    window.with_mut(|window| {
+        if let Some(win) = window {
+            win.show();
+        } else {
+            let (config, delegate) = vendor();
+            let win = Window::with(config, delegate);
+            win.show();
+            *window = Some(win);
+        }
+    });
 }
 
 impl WindowManager {
@@ -52,11 +51,11 @@ impl WindowManager {
         W: WindowDelegate + 'static,
         F: Fn() + Send + Sync + 'static
     {
-        let main = self.main.write().unwrap();
-
-        if let Some(main_window) = &*main {
-            main_window.begin_sheet(window, completion);
-        }
+        self.main.with(|main| {
+            if let Some(main_window) = main {
+                main_window.begin_sheet(window, completion);
+            }
+        });
     }
 
     /// Opens a "add file" window, which asks for a code and optional server to
@@ -65,29 +64,25 @@ impl WindowManager {
     pub fn open_add(&self) {
         let callback = || {};
 
-        let mut lock = self.add.write().unwrap();
-
-        if let Some(win) = &*lock {
-            self.begin_sheet(&win, callback);
-        } else {
-            let window = Window::with(WindowConfig::default(), AddNewTodoWindow::new());
-            self.begin_sheet(&window, callback);
-            *lock = Some(window);
-        }
+        self.add.with_mut(|add| {
+            if let Some(win) = add {
+                self.begin_sheet(win, callback);
+            } else {
+                let window = Window::with(WindowConfig::default(), AddNewTodoWindow::new());
+                self.begin_sheet(&window, callback);
+                *add = Some(window);
+            }
+        });
     }
 
     pub fn close_sheet(&self) {
-        let mut add = self.add.write().unwrap();
-
-        if let Some(add_window) = &*add {
-            let main = self.main.write().unwrap();
-
-            if let Some(main_window) = &*main {
-                main_window.end_sheet(&add_window);
-            }
+        if let Some(add_window) = self.add.take() {
+            self.main.with(|main| {
+                if let Some(main_window) = main {
+                    main_window.end_sheet(&add_window);
+                }
+            });
         }
-
-        *add = None;
     }
 
     /// Opens a "add file" window, which asks for a code and optional server to
@@ -140,22 +135,22 @@ impl Dispatcher for WindowManager {
             _ => {}
         }
 
-        if let Some(w) = &*(self.main.read().unwrap()) {
-            if let Some(delegate) = &w.delegate {
+        self.main.with(|window| {
+            if let Some(delegate) = window.as_ref().and_then(|w| w.delegate.as_ref()) {
                 delegate.on_message(message.clone());
             }
-        }
+        });
 
-        if let Some(w) = &*(self.preferences.read().unwrap()) {
-            if let Some(delegate) = &w.delegate {
+        self.preferences.with(|window| {
+            if let Some(delegate) = window.as_ref().and_then(|w| w.delegate.as_ref()) {
                 delegate.on_message(message.clone());
             }
-        }
+        });
 
-        if let Some(w) = &*(self.add.read().unwrap()) {
-            if let Some(delegate) = &w.delegate {
+        self.add.with(|window| {
+            if let Some(delegate) = window.as_ref().and_then(|w| w.delegate.as_ref()) {
                 delegate.on_message(message.clone());
             }
-        }
+        });
     }
 }
@@ -0,0 +1,255 @@
+//! Support for the standard "About" panel, as well as a helper for building a custom one.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::{AboutPanelOptions, App};
+//!
+//! let mut options = AboutPanelOptions::default();
+//! options.set_application_version("1.0.0");
+//! options.set_copyright("Copyright © 2026 Me");
+//!
+//! App::order_front_standard_about_panel_with_options(options);
+//! ```
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel};
+
+use crate::foundation::{id, nil, NSMutableDictionary, NSString};
+use crate::image::Image;
+use crate::text::AttributedString;
+
+#[cfg(feature = "autolayout")]
+use crate::appkit::window::{Window, WindowConfig, WindowStyle};
+#[cfg(feature = "autolayout")]
+use crate::image::ImageView;
+#[cfg(feature = "autolayout")]
+use crate::layout::{Layout, LayoutConstraint};
+#[cfg(feature = "autolayout")]
+use crate::text::{Font, Label, TextAlign};
+#[cfg(feature = "autolayout")]
+use crate::view::View;
+
+/// Options for customizing the standard About panel. Anything left unset falls back to what the
+/// system would normally display (generally pulled from your `Info.plist`).
+#[derive(Debug, Default)]
+pub struct AboutPanelOptions {
+    credits: Option<AttributedString>,
+    application_icon: Option<Image>,
+    application_name: Option<String>,
+    application_version: Option<String>,
+    version: Option<String>,
+    copyright: Option<String>
+}
+
+impl AboutPanelOptions {
+    /// Sets the credits shown in the scrollable area of the panel.
+    pub fn set_credits(&mut self, credits: AttributedString) {
+        self.credits = Some(credits);
+    }
+
+    /// Sets the icon displayed in the panel.
+    pub fn set_application_icon(&mut self, icon: Image) {
+        self.application_icon = Some(icon);
+    }
+
+    /// Sets the application name displayed in the panel.
+    pub fn set_application_name(&mut self, name: &str) {
+        self.application_name = Some(name.to_string());
+    }
+
+    /// Sets the user-facing version (e.g, `1.2.0`). This overrides `CFBundleShortVersionString`.
+    pub fn set_application_version(&mut self, version: &str) {
+        self.application_version = Some(version.to_string());
+    }
+
+    /// Sets the build version, displayed in parentheses after the application version. This
+    /// overrides `CFBundleVersion`.
+    pub fn set_version(&mut self, version: &str) {
+        self.version = Some(version.to_string());
+    }
+
+    /// Sets the copyright line. This overrides `NSHumanReadableCopyright`.
+    pub fn set_copyright(&mut self, copyright: &str) {
+        self.copyright = Some(copyright.to_string());
+    }
+
+    /// Builds the options dictionary that `orderFrontStandardAboutPanelWithOptions:` expects.
+    pub(crate) fn to_dictionary(&self) -> NSMutableDictionary {
+        let mut dictionary = NSMutableDictionary::new();
+
+        if let Some(credits) = &self.credits {
+            dictionary.insert(NSString::new("Credits"), &*credits.0 as *const Object as id);
+        }
+
+        if let Some(icon) = &self.application_icon {
+            dictionary.insert(NSString::new("ApplicationIcon"), &*icon.0 as *const Object as id);
+        }
+
+        let strings = [
+            ("ApplicationName", &self.application_name),
+            ("ApplicationVersion", &self.application_version),
+            ("Version", &self.version),
+            ("Copyright", &self.copyright)
+        ];
+
+        for (key, value) in strings.iter() {
+            if let Some(value) = value {
+                let value = NSString::new(value);
+                dictionary.insert(NSString::new(key), &*value.objc as *const Object as id);
+            }
+        }
+
+        dictionary
+    }
+}
+
+/// Reads a string value out of the main bundle's `Info.plist`.
+#[cfg(feature = "autolayout")]
+fn info_plist_string(key: &str) -> Option<String> {
+    let key = NSString::new(key);
+
+    unsafe {
+        let bundle: id = msg_send![class!(NSBundle), mainBundle];
+        let value: id = msg_send![bundle, objectForInfoDictionaryKey: &*key];
+
+        match value == nil {
+            true => None,
+            false => Some(NSString::retain(value).to_string())
+        }
+    }
+}
+
+/// A helper for apps that want a custom About window, rather than the standard panel. This lays
+/// out the icon, name, version, copyright and credits in the same arrangement as the system
+/// panel, and you can customize the views further before showing the window.
+///
+/// Anything not set in the `AboutPanelOptions` is pulled from your `Info.plist`, and the icon
+/// defaults to the application icon.
+///
+/// You need to retain this for as long as the window is open.
+#[cfg(feature = "autolayout")]
+#[derive(Debug)]
+pub struct AboutWindow {
+    /// The window itself.
+    pub window: Window,
+
+    /// The content view of the window.
+    pub content: View,
+
+    /// Displays the application icon.
+    pub icon: ImageView,
+
+    /// Displays the application name.
+    pub name: Label,
+
+    /// Displays the version.
+    pub version: Label,
+
+    /// Displays the copyright line.
+    pub copyright: Label,
+
+    /// Displays the credits.
+    pub credits: Label
+}
+
+#[cfg(feature = "autolayout")]
+impl AboutWindow {
+    /// Builds a new About window from the given options. Call `show()` on the `window` field to
+    /// display it.
+    pub fn new(options: AboutPanelOptions) -> Self {
+        let mut config = WindowConfig::default();
+        config.set_styles(&[WindowStyle::Titled, WindowStyle::Closable]);
+        config.set_initial_dimensions(0., 0., 300., 320.);
+
+        let window = Window::new(config);
+        window.set_title("");
+
+        let content = View::new();
+
+        let icon = ImageView::new();
+        let application_icon = options.application_icon.unwrap_or_else(|| {
+            Image::with(unsafe {
+                let app: id = msg_send![class!(NSApplication), sharedApplication];
+                msg_send![app, applicationIconImage]
+            })
+        });
+        icon.set_image(&application_icon);
+
+        let name = Label::new();
+        name.set_font(Font::bold_system(14.));
+        name.set_text_alignment(TextAlign::Center);
+        name.set_text(
+            options
+                .application_name
+                .or_else(|| info_plist_string("CFBundleName"))
+                .unwrap_or_default()
+        );
+
+        let version = Label::new();
+        version.set_font(Font::system(11.));
+        version.set_text_alignment(TextAlign::Center);
+
+        let application_version = options
+            .application_version
+            .or_else(|| info_plist_string("CFBundleShortVersionString"));
+        let build = options.version.or_else(|| info_plist_string("CFBundleVersion"));
+        version.set_text(match (application_version, build) {
+            (Some(version), Some(build)) if version != build => format!("Version {} ({})", version, build),
+            (Some(version), _) | (None, Some(version)) => format!("Version {}", version),
+            (None, None) => String::new()
+        });
+
+        let copyright = Label::new();
+        copyright.set_font(Font::system(10.));
+        copyright.set_text_alignment(TextAlign::Center);
+        copyright.set_text(
+            options
+                .copyright
+                .or_else(|| info_plist_string("NSHumanReadableCopyright"))
+                .unwrap_or_default()
+        );
+
+        let credits = Label::new();
+        credits.set_text_alignment(TextAlign::Center);
+        credits.set_max_number_of_lines(0);
+        if let Some(text) = options.credits {
+            credits.set_attributed_text(text);
+        }
+
+        content.add_subview(&icon);
+        content.add_subview(&name);
+        content.add_subview(&version);
+        content.add_subview(&credits);
+        content.add_subview(&copyright);
+        window.set_content_view(&content);
+
+        LayoutConstraint::activate(&[
+            icon.top.constraint_equal_to(&content.top).offset(20.),
+            icon.center_x.constraint_equal_to(&content.center_x),
+            icon.width.constraint_equal_to_constant(64.),
+            icon.height.constraint_equal_to_constant(64.),
+            name.top.constraint_equal_to(&icon.bottom).offset(12.),
+            name.leading.constraint_equal_to(&content.leading).offset(20.),
+            name.trailing.constraint_equal_to(&content.trailing).offset(-20.),
+            version.top.constraint_equal_to(&name.bottom).offset(4.),
+            version.leading.constraint_equal_to(&content.leading).offset(20.),
+            version.trailing.constraint_equal_to(&content.trailing).offset(-20.),
+            credits.top.constraint_equal_to(&version.bottom).offset(12.),
+            credits.leading.constraint_equal_to(&content.leading).offset(20.),
+            credits.trailing.constraint_equal_to(&content.trailing).offset(-20.),
+            copyright.top.constraint_greater_than_or_equal_to(&credits.bottom).offset(12.),
+            copyright.leading.constraint_equal_to(&content.leading).offset(20.),
+            copyright.trailing.constraint_equal_to(&content.trailing).offset(-20.),
+            copyright.bottom.constraint_equal_to(&content.bottom).offset(-20.)
+        ]);
+
+        AboutWindow {
+            window,
+            content,
+            icon,
+            name,
+            version,
+            copyright,
+            credits
+        }
+    }
+}
@@ -3,18 +3,19 @@
 //! for potential future use.
 
 use std::ffi::c_void;
+use std::path::PathBuf;
 
 use block::Block;
 use objc::runtime::{Bool, Class, Object, Sel};
 use objc::{msg_send, sel};
 use url::Url;
 
-use crate::appkit::app::{AppDelegate, APP_PTR};
+use crate::appkit::app::{AppDelegate, Appearance, APP_PTR};
 use crate::appkit::printing::PrintSettings;
 #[cfg(feature = "cloudkit")]
 use crate::cloudkit::share::CKShareMetaData;
 use crate::error::Error;
-use crate::foundation::{id, load_or_register_class, nil, NSArray, NSString, NSUInteger};
+use crate::foundation::{id, load_or_register_class, nil, to_bool, NSArray, NSString, NSUInteger};
 use crate::user_activity::UserActivity;
 
 /// A handy method for grabbing our `AppDelegate` from the pointer. This is different from our
@@ -187,6 +188,28 @@ extern "C" fn open_urls<T: AppDelegate>(this: &Object, _: Sel, _: id, file_urls:
         .collect();
 
     app::<T>(this).open_urls(urls);
+
+    let paths: Vec<PathBuf> = NSArray::retain(file_urls)
+        .iter()
+        .filter(|url| to_bool(unsafe { msg_send![*url, isFileURL] }))
+        .map(|url| {
+            let path = NSString::retain(unsafe { msg_send![url, path] });
+            PathBuf::from(path.to_str())
+        })
+        .collect();
+
+    if !paths.is_empty() {
+        app::<T>(this).open_files(paths);
+    }
+}
+
+/// Fires when the user selects an item from our Open Recent menu.
+extern "C" fn open_recent_document<T: AppDelegate>(this: &Object, _: Sel, url: id) {
+    let uri = NSString::retain(unsafe { msg_send![url, absoluteString] });
+
+    if let Ok(url) = Url::parse(uri.to_str()) {
+        app::<T>(this).open_recent_document(url);
+    }
 }
 
 /// Fires when the application receives an `application:openFileWithoutUI:` message.
@@ -261,6 +284,18 @@ extern "C" fn delegate_handles_key<T: AppDelegate>(this: &Object, _: Sel, _: id,
     Bool::new(app::<T>(this).delegate_handles_key(key.to_str()))
 }
 
+/// Fires when a key path observed on the application changes. We only register for
+/// `effectiveAppearance` (see `App::new()`), but filter anyway in case something else ends up
+/// pointing here.
+extern "C" fn observe_value<T: AppDelegate>(this: &Object, _: Sel, key_path: id, object: id, _: id, _: *mut c_void) {
+    if key_path == nil || NSString::retain(key_path).to_str() != "effectiveAppearance" {
+        return;
+    }
+
+    let appearance = Appearance::from_appearance(unsafe { msg_send![object, effectiveAppearance] });
+    app::<T>(this).did_change_effective_appearance(appearance);
+}
+
 /// Registers an `NSObject` application delegate, and configures it for the various callbacks and
 /// pointers we need to have.
 pub(crate) fn register_app_delegate_class<T: AppDelegate + AppDelegate>() -> &'static Class {
@@ -380,6 +415,10 @@ pub(crate) fn register_app_delegate_class<T: AppDelegate + AppDelegate>() -> &'s
 
         // Opening Files
         decl.add_method(sel!(application:openURLs:), open_urls::<T> as extern "C" fn(_, _, _, _));
+        decl.add_method(
+            sel!(cacaoOpenRecentDocument:),
+            open_recent_document::<T> as extern "C" fn(_, _, _)
+        );
         decl.add_method(
             sel!(application:openFileWithoutUI:),
             open_file_without_ui::<T> as extern "C" fn(_, _, _, _) -> _
@@ -415,5 +454,11 @@ pub(crate) fn register_app_delegate_class<T: AppDelegate + AppDelegate>() -> &'s
             sel!(application:delegateHandlesKey:),
             delegate_handles_key::<T> as extern "C" fn(_, _, _, _) -> _
         );
+
+        // Appearance changes, via KVO
+        decl.add_method(
+            sel!(observeValueForKeyPath:ofObject:change:context:),
+            observe_value::<T> as extern "C" fn(_, _, _, _, _, _)
+        );
     })
 }
@@ -1,6 +1,8 @@
 //! Various types used at the AppController level.
 
-use crate::foundation::NSUInteger;
+use objc::{class, msg_send, sel};
+
+use crate::foundation::{id, nil, to_bool, NSArray, NSInteger, NSString, NSUInteger};
 
 /// Used for determining how an application should handle quitting/terminating.
 /// You return this in your `AppController` `should_terminate` method.
@@ -156,3 +158,130 @@ impl From<&PresentationOption> for NSUInteger {
         }
     }
 }
+
+/// Controls how (and whether) an application shows up in the Dock and app switcher, and whether
+/// it can be activated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ActivationPolicy {
+    /// An ordinary application: it appears in the Dock, has a menu bar, and can be activated.
+    /// This is the default.
+    Regular,
+
+    /// An "agent" application (i.e, `LSUIElement`): it doesn't appear in the Dock or have a menu
+    /// bar, but it can still be activated and show windows. Use this for menu bar apps.
+    Accessory,
+
+    /// A background-only application: it doesn't appear in the Dock and can't create windows or
+    /// be activated.
+    Prohibited
+}
+
+impl From<ActivationPolicy> for NSInteger {
+    fn from(policy: ActivationPolicy) -> Self {
+        match policy {
+            ActivationPolicy::Regular => 0,
+            ActivationPolicy::Accessory => 1,
+            ActivationPolicy::Prohibited => 2
+        }
+    }
+}
+
+impl From<NSInteger> for ActivationPolicy {
+    fn from(policy: NSInteger) -> Self {
+        match policy {
+            1 => ActivationPolicy::Accessory,
+            2 => ActivationPolicy::Prohibited,
+            _ => ActivationPolicy::Regular
+        }
+    }
+}
+
+/// Represents the effective appearance of the application (or a view or window within it).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Appearance {
+    /// The standard, light ("Aqua") appearance.
+    Light,
+
+    /// The dark ("Dark Aqua") appearance.
+    Dark,
+
+    /// The light appearance, with increased contrast enabled.
+    HighContrastLight,
+
+    /// The dark appearance, with increased contrast enabled.
+    HighContrastDark,
+
+    /// The light appearance used for vibrant (translucent) content, e.g in sidebars.
+    VibrantLight,
+
+    /// The dark appearance used for vibrant (translucent) content, e.g in HUD windows.
+    VibrantDark
+}
+
+impl Appearance {
+    /// Returns whether this is one of the dark appearances.
+    pub fn is_dark(&self) -> bool {
+        match self {
+            Appearance::Dark | Appearance::HighContrastDark | Appearance::VibrantDark => true,
+            Appearance::Light | Appearance::HighContrastLight | Appearance::VibrantLight => false
+        }
+    }
+
+    /// Returns an `NSAppearance` to force this appearance onto a window or view.
+    ///
+    /// The high contrast appearances can't be forced - the system applies them based on the
+    /// user's accessibility settings - so these resolve to the standard light or dark appearance.
+    pub(crate) fn to_appearance(&self) -> id {
+        let name = match self {
+            Appearance::Light | Appearance::HighContrastLight => "NSAppearanceNameAqua",
+            Appearance::Dark | Appearance::HighContrastDark => "NSAppearanceNameDarkAqua",
+            Appearance::VibrantLight => "NSAppearanceNameVibrantLight",
+            Appearance::VibrantDark => "NSAppearanceNameVibrantDark"
+        };
+
+        let name = NSString::new(name);
+        unsafe { msg_send![class!(NSAppearance), appearanceNamed: &*name] }
+    }
+
+    /// Given an `NSAppearance`, resolves the closest matching `Appearance`. A `nil` appearance
+    /// (e.g, on systems that predate dark mode) resolves to `Light`.
+    pub(crate) fn from_appearance(appearance: id) -> Self {
+        if appearance == nil {
+            return Appearance::Light;
+        }
+
+        let names = [
+            "NSAppearanceNameAqua",
+            "NSAppearanceNameDarkAqua",
+            "NSAppearanceNameAccessibilityHighContrastAqua",
+            "NSAppearanceNameAccessibilityHighContrastDarkAqua",
+            "NSAppearanceNameVibrantLight",
+            "NSAppearanceNameVibrantDark"
+        ];
+
+        let candidates: Vec<NSString> = names.iter().map(|name| NSString::new(name)).collect();
+        let array: NSArray = candidates
+            .iter()
+            .map(|name| &*name.objc as *const _ as id)
+            .collect::<Vec<id>>()
+            .into();
+
+        let best: id = unsafe { msg_send![appearance, bestMatchFromAppearancesWithNames: &*array] };
+        if best == nil {
+            return Appearance::Light;
+        }
+
+        let position = candidates
+            .iter()
+            .position(|name| to_bool(unsafe { msg_send![best, isEqualToString: &*name.objc] }));
+
+        match position {
+            Some(1) => Appearance::Dark,
+            Some(2) => Appearance::HighContrastLight,
+            Some(3) => Appearance::HighContrastDark,
+            Some(4) => Appearance::VibrantLight,
+            Some(5) => Appearance::VibrantDark,
+            _ => Appearance::Light
+        }
+    }
+}
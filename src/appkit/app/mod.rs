@@ -35,19 +35,23 @@
 //! documentation makes every effort to note.
 
 use std::fmt;
+use std::sync::atomic::{AtomicIsize, Ordering};
 use std::sync::{Arc, Mutex};
 
 use lazy_static::lazy_static;
 
-use objc::rc::{Id, Owned};
+use objc::rc::{Id, Owned, Shared};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
+use crate::appkit::menu::menu::find_item_with_identifier;
+use crate::appkit::menu::menu::MenuRole;
 use crate::appkit::menu::Menu;
-use crate::foundation::{id, nil, AutoReleasePool, NSUInteger, NO, YES};
+use crate::available;
+use crate::foundation::{id, nil, AutoReleasePool, NSInteger, NSString, NSUInteger, NO, YES};
 use crate::invoker::TargetActionHandler;
 use crate::notification_center::Dispatcher;
-use crate::utils::activate_cocoa_multithreading;
+use crate::utils::{activate_cocoa_multithreading, register_builtin_classes};
 
 //use crate::bundle::set_bundle_id;
 
@@ -57,6 +61,12 @@ use class::register_app_class;
 mod delegate;
 use delegate::register_app_delegate_class;
 
+mod about;
+pub use about::AboutPanelOptions;
+
+#[cfg(feature = "autolayout")]
+pub use about::AboutWindow;
+
 mod enums;
 pub use enums::*;
 
@@ -67,6 +77,9 @@ use super::window::Window;
 
 pub(crate) static APP_PTR: &str = "rstAppPtr";
 
+/// The activation policy that `App::activate()` applies. Stored as an `NSInteger`.
+static ACTIVATION_POLICY: AtomicIsize = AtomicIsize::new(0);
+
 /// A handler to make some boilerplate less annoying.
 #[inline]
 pub(crate) fn shared_application<T, F: Fn(id) -> T>(handler: F) -> T {
@@ -143,6 +156,7 @@ where
         activate_cocoa_multithreading();
 
         let pool = AutoReleasePool::new();
+        register_builtin_classes();
 
         let objc: Id<_, _> = unsafe { msg_send_id![register_app_class(), sharedApplication] };
 
@@ -154,6 +168,18 @@ where
             let delegate_ptr: *const T = &*app_delegate;
             delegate.set_ivar(APP_PTR, delegate_ptr as usize);
             let _: () = msg_send![&*objc, setDelegate: &*delegate];
+
+            if available!(macos 10.14) {
+                let key_path = NSString::new("effectiveAppearance");
+                let _: () = msg_send![
+                    &*objc,
+                    addObserver: &*delegate,
+                    forKeyPath: &*key_path,
+                    options: 0 as NSUInteger,
+                    context: std::ptr::null_mut::<std::ffi::c_void>()
+                ];
+            }
+
             delegate
         };
 
@@ -167,6 +193,19 @@ where
     }
 }
 
+impl<T, M> Drop for App<T, M> {
+    /// Removes the appearance observer that `App::new()` registers, so that KVO doesn't message
+    /// the delegate after it's gone.
+    fn drop(&mut self) {
+        if available!(macos 10.14) {
+            unsafe {
+                let key_path = NSString::new("effectiveAppearance");
+                let _: () = msg_send![&*self.objc, removeObserver: &*self.objc_delegate, forKeyPath: &*key_path];
+            }
+        }
+    }
+}
+
 //  This is a very basic "dispatch" mechanism. In macOS, it's critical that UI work happen on the
 //  UI ("main") thread. We can hook into the standard mechanism for this by dispatching on
 //  queues; in our case, we'll just offer two points - one for a background queue, and one
@@ -264,39 +303,139 @@ impl App {
     /// Sets a set of `Menu`'s as the top level Menu for the current application. Note that behind
     /// the scenes, Cocoa/AppKit make a copy of the menu you pass in - so we don't retain it, and
     /// you shouldn't bother to either.
+    ///
+    /// Menus marked via `Menu::set_as_help_menu()` and `Menu::set_as_windows_menu()` are registered
+    /// as the application's Help and Window menus, respectively (see `appkit::help`).
     pub fn set_menu(mut menus: Vec<Menu>) {
-        let main_menu = unsafe {
+        let (main_menu, help_menu, windows_menu) = unsafe {
             let menu_cls = class!(NSMenu);
             let item_cls = class!(NSMenuItem);
             let main_menu: id = msg_send![menu_cls, new];
+            let mut help_menu: id = nil;
+            let mut windows_menu: id = nil;
 
             for menu in menus.iter_mut() {
                 let item: id = msg_send![item_cls, new];
                 let _: () = msg_send![item, setSubmenu:&*menu.0];
                 let _: () = msg_send![main_menu, addItem: item];
+
+                match menu.1 {
+                    MenuRole::Help => help_menu = &*menu.0 as *const Object as id,
+                    MenuRole::Windows => windows_menu = &*menu.0 as *const Object as id,
+                    MenuRole::None => {}
+                }
             }
 
-            main_menu
+            (main_menu, help_menu, windows_menu)
         };
 
         shared_application(move |app| unsafe {
             let _: () = msg_send![app, setMainMenu: main_menu];
+
+            if help_menu != nil {
+                let _: () = msg_send![app, setHelpMenu: help_menu];
+            }
+
+            // This is what gets the list of open windows added to the Window menu.
+            if windows_menu != nil {
+                let _: () = msg_send![app, setWindowsMenu: windows_menu];
+            }
         });
     }
 
+    /// Sets the activation policy for the application. You can call this before `run()` to
+    /// configure the app at launch (e.g, `ActivationPolicy::Accessory` for a menu bar only app),
+    /// or at any point afterwards - e.g, to show a Dock icon only while a window is open.
+    ///
+    /// This is also the policy that `activate()` applies.
+    pub fn set_activation_policy(policy: ActivationPolicy) {
+        let value: NSInteger = policy.into();
+        ACTIVATION_POLICY.store(value as isize, Ordering::SeqCst);
+
+        shared_application(|app| unsafe {
+            let policy: NSInteger = policy.into();
+            let _: () = msg_send![app, setActivationPolicy: policy];
+        });
+    }
+
+    /// Returns the current activation policy for the application.
+    pub fn activation_policy() -> ActivationPolicy {
+        shared_application(|app| {
+            let policy: NSInteger = unsafe { msg_send![app, activationPolicy] };
+            policy.into()
+        })
+    }
+
     /// For nib-less applications (which, if you're here, this is) need to call the activation
     /// routines after the NSMenu has been set, otherwise it won't be interact-able without
     /// switching away from the app and then coming back.
     ///
-    /// @TODO: Accept an ActivationPolicy enum or something.
+    /// This applies the activation policy set via `set_activation_policy()`, defaulting to
+    /// `ActivationPolicy::Regular`.
     pub fn activate() {
         shared_application(|app| unsafe {
-            let _: () = msg_send![app, setActivationPolicy:0];
+            let policy = ACTIVATION_POLICY.load(Ordering::SeqCst) as NSInteger;
+            let _: () = msg_send![app, setActivationPolicy: policy];
             let current_app: id = msg_send![class!(NSRunningApplication), currentApplication];
             let _: () = msg_send![current_app, activateWithOptions:1<<1];
         });
     }
 
+    /// Searches the main menu (and its submenus) for an item with the given identifier, as set
+    /// via `MenuItem::identifier()`.
+    pub fn menu_item_with_identifier(identifier: &str) -> Option<Id<Object, Shared>> {
+        let identifier = NSString::new(identifier);
+
+        shared_application(|app| unsafe {
+            let main_menu: id = msg_send![app, mainMenu];
+            if main_menu == nil {
+                return None;
+            }
+
+            let item = find_item_with_identifier(main_menu, &identifier);
+            match item == nil {
+                true => None,
+                false => Id::retain(item)
+            }
+        })
+    }
+
+    /// Shows the system Character Viewer (emoji & symbols palette). Characters chosen from it are
+    /// inserted into whatever text view currently has focus.
+    pub fn order_front_character_palette() {
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, orderFrontCharacterPalette: nil];
+        });
+    }
+
+    /// Shows the standard About panel, populated from your `Info.plist`.
+    pub fn order_front_standard_about_panel() {
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, orderFrontStandardAboutPanel: nil];
+        });
+    }
+
+    /// Shows the standard About panel, with the given options overriding what would be pulled
+    /// from your `Info.plist`.
+    pub fn order_front_standard_about_panel_with_options(options: AboutPanelOptions) {
+        let options = options.to_dictionary();
+
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, orderFrontStandardAboutPanelWithOptions: &*options];
+        });
+    }
+
+    /// Returns the current effective appearance of the application (e.g, light or dark mode).
+    pub fn effective_appearance() -> Appearance {
+        shared_application(|app| {
+            if !available!(macos 10.14) {
+                return Appearance::Light;
+            }
+
+            Appearance::from_appearance(unsafe { msg_send![app, effectiveAppearance] })
+        })
+    }
+
     pub fn main_window() -> Window {
         shared_application(|app| unsafe {
             let window: id = msg_send![app, mainWindow];
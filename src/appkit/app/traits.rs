@@ -1,12 +1,14 @@
 //! Traits that an implementing application can conform to. These aim to wrap the general
 //! lifecycles across macOS/iOS/etc, while still conforming to a Rust-ish approach.
 
+use std::path::PathBuf;
+
 use url::Url;
 
 use crate::error::Error;
 use crate::user_activity::UserActivity;
 
-use crate::appkit::app::enums::TerminateResponse;
+use crate::appkit::app::enums::{Appearance, TerminateResponse};
 use crate::appkit::menu::Menu;
 use crate::appkit::printing::enums::PrintResponse;
 use crate::appkit::printing::settings::PrintSettings;
@@ -148,6 +150,10 @@ pub trait AppDelegate {
     /// something in their settings).
     fn did_change_screen_parameters(&self) {}
 
+    /// Fired when the effective appearance of the application changes - e.g, the user switched
+    /// between light and dark mode. Only fires on macOS 10.14 and above.
+    fn did_change_effective_appearance(&self, _appearance: Appearance) {}
+
     /// Fired when you have a list of `Url`'s to open. This is best explained by quoting the Apple
     /// documentation verbatim:
     ///
@@ -161,6 +167,24 @@ pub trait AppDelegate {
     /// will _not_ call `application:openFile:` or `application:openFiles`.
     fn open_urls(&self, _urls: Vec<Url>) {}
 
+    /// Fired when the application is asked to open one or more files (e.g, from Finder, or by
+    /// dropping them onto the Dock icon). This is called with the paths of any `file://` URLs
+    /// received by `open_urls` - so you'll generally want to implement one or the other, rather
+    /// than both.
+    fn open_files(&self, _paths: Vec<PathBuf>) {}
+
+    /// Fired when the user picks a document from the Open Recent menu (see
+    /// `MenuItem::OpenRecent`). The default implementation forwards the URL to `open_urls`, and
+    /// - for file URLs - to `open_files`, so you generally only need to implement this if you
+    /// want to treat recent documents differently.
+    fn open_recent_document(&self, url: Url) {
+        if let Ok(path) = url.to_file_path() {
+            self.open_files(vec![path]);
+        }
+
+        self.open_urls(vec![url]);
+    }
+
     /// Fired when the file is requested to be opened programmatically. This is not a commonly used
     /// or implemented method.
     ///
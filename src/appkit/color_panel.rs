@@ -0,0 +1,88 @@
+//! Wraps the shared `NSColorPanel`, the standard Colors window.
+//!
+//! The Colors window sends `changeColor:` up the responder chain as the user picks a color. A
+//! rich text `TextView` (see `TextView::set_rich_text()`) colors its selection with it; custom
+//! views can implement `ViewDelegate::change_color()`.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::ColorPanel;
+//! use cacao::color::Color;
+//!
+//! ColorPanel::set_color(Color::SystemBlue);
+//! ColorPanel::set_shows_alpha(true);
+//! ColorPanel::show();
+//! ```
+
+use objc::{class, msg_send, sel};
+
+use crate::color::Color;
+use crate::foundation::{id, nil, to_bool, NO, YES};
+
+/// A handle to the app-wide Colors window. The panel is shared, so everything here is static.
+#[derive(Debug)]
+pub struct ColorPanel;
+
+impl ColorPanel {
+    /// Brings up the Colors window, the way the standard "Show Colors" menu item does.
+    pub fn show() {
+        unsafe {
+            let _: () = msg_send![shared_color_panel(), orderFront: nil];
+        }
+    }
+
+    /// Hides the Colors window.
+    pub fn hide() {
+        unsafe {
+            let _: () = msg_send![shared_color_panel(), orderOut: nil];
+        }
+    }
+
+    /// Returns whether the Colors window is currently shown.
+    pub fn is_visible() -> bool {
+        unsafe {
+            let exists: bool = to_bool(msg_send![class!(NSColorPanel), sharedColorPanelExists]);
+            exists && to_bool(msg_send![shared_color_panel(), isVisible])
+        }
+    }
+
+    /// Returns the color currently picked in the Colors window.
+    pub fn color() -> Color {
+        Color::retain(unsafe { msg_send![shared_color_panel(), color] })
+    }
+
+    /// Sets the color shown in the Colors window - e.g, to reflect the selection in a custom
+    /// view.
+    pub fn set_color<C: AsRef<Color>>(color: C) {
+        let color: id = color.as_ref().into();
+
+        unsafe {
+            let _: () = msg_send![shared_color_panel(), setColor: color];
+        }
+    }
+
+    /// Sets whether the Colors window offers an opacity slider.
+    pub fn set_shows_alpha(shows: bool) {
+        unsafe {
+            let _: () = msg_send![shared_color_panel(), setShowsAlpha: match shows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether `changeColor:` is sent continuously while the user drags around the color
+    /// wheel (the default), or only once they let go.
+    pub fn set_continuous(continuous: bool) {
+        unsafe {
+            let _: () = msg_send![shared_color_panel(), setContinuous: match continuous {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+/// Returns the shared `NSColorPanel`, creating it if need be.
+fn shared_color_panel() -> id {
+    unsafe { msg_send![class!(NSColorPanel), sharedColorPanel] }
+}
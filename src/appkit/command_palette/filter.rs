@@ -0,0 +1,160 @@
+//! Fuzzy matching for the command palette. A command matches if every character of the query
+//! appears in its title (or subtitle), in order - so `"nwin"` finds "New Window". Matches that
+//! start words, or run together, rank higher, and title matches always rank ahead of commands
+//! that only match on their subtitle.
+
+use super::Command;
+
+/// Points for each matched character.
+const MATCH: usize = 1;
+
+/// Extra points for a match at the start of a word.
+const WORD_START: usize = 8;
+
+/// Extra points for a match right after the previous one.
+const CONSECUTIVE: usize = 4;
+
+/// Extra points when the candidate starts with the query outright.
+const PREFIX: usize = 16;
+
+/// Scores `candidate` against `query`, case-insensitively. Returns `None` if it doesn't match.
+pub(crate) fn score(query: &str, candidate: &str) -> Option<usize> {
+    let mut query = query.chars().flat_map(char::to_lowercase).peekable();
+    let mut score = 0;
+    let mut previous: Option<char> = None;
+    let mut last_match: Option<usize> = None;
+
+    for (index, c) in candidate.chars().enumerate() {
+        let wanted = match query.peek() {
+            Some(wanted) => *wanted,
+            None => break
+        };
+
+        if c.to_lowercase().eq(std::iter::once(wanted)) {
+            score += MATCH;
+
+            if is_word_start(previous, c) {
+                score += WORD_START;
+            }
+
+            if last_match.map(|last| last + 1 == index).unwrap_or(index == 0) {
+                score += CONSECUTIVE;
+            }
+
+            last_match = Some(index);
+            query.next();
+        }
+
+        previous = Some(c);
+    }
+
+    if query.peek().is_some() {
+        return None;
+    }
+
+    Some(score)
+}
+
+/// Whether `c` starts a word: the start of the string, after a separator, or an uppercase
+/// letter following a lowercase one.
+fn is_word_start(previous: Option<char>, c: char) -> bool {
+    match previous {
+        None => true,
+        Some(previous) => !previous.is_alphanumeric() || (previous.is_lowercase() && c.is_uppercase())
+    }
+}
+
+/// Returns the indexes of the commands that match `query`, best match first: title matches by
+/// score, then subtitle-only matches by score. Commands that rank the same keep their order, and
+/// an empty query matches everything.
+pub(crate) fn filter(commands: &[Command], query: &str) -> Vec<usize> {
+    let query = query.trim();
+
+    if query.is_empty() {
+        return (0..commands.len()).collect();
+    }
+
+    let mut matches: Vec<(usize, bool, usize)> = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, command)| {
+            let title =
+                score(query, &command.title).map(
+                    |score| match command.title.to_lowercase().starts_with(&query.to_lowercase()) {
+                        true => score + PREFIX,
+                        false => score
+                    }
+                );
+
+            // Subtitle matches count, but never outrank a title match.
+            match title {
+                Some(score) => Some((index, true, score)),
+
+                None => command
+                    .subtitle
+                    .as_ref()
+                    .and_then(|subtitle| score(query, subtitle))
+                    .map(|score| (index, false, score))
+            }
+        })
+        .collect();
+
+    matches.sort_by(|a, b| (b.1, b.2).cmp(&(a.1, a.2)));
+    matches.into_iter().map(|(index, _, _)| index).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands(titles: &[&str]) -> Vec<Command> {
+        titles.iter().map(|title| Command::new(*title, *title)).collect()
+    }
+
+    #[test]
+    fn matches_subsequences_case_insensitively() {
+        assert!(score("nwin", "New Window").is_some());
+        assert!(score("NW", "new window").is_some());
+        assert!(score("wn", "New Window").is_some());
+        assert!(score("iwn", "New Window").is_none());
+        assert!(score("newer", "New Window").is_none());
+    }
+
+    #[test]
+    fn word_starts_rank_higher() {
+        let commands = commands(&["Unwind Stack", "New Window"]);
+        assert_eq!(filter(&commands, "nw"), vec![1, 0]);
+    }
+
+    #[test]
+    fn prefixes_rank_highest() {
+        let commands = commands(&["Reopen Closed Tab", "Open File", "Toggle Sidebar"]);
+        assert_eq!(filter(&commands, "open"), vec![1, 0]);
+    }
+
+    #[test]
+    fn empty_query_keeps_everything_in_order() {
+        let commands = commands(&["Copy", "Paste", "Cut"]);
+        assert_eq!(filter(&commands, "  "), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn falls_back_to_subtitles() {
+        let commands = vec![
+            Command::new("build", "Build").subtitle("Compile the project"),
+            Command::new("compile", "Compile"),
+        ];
+
+        assert_eq!(filter(&commands, "compile"), vec![1, 0]);
+    }
+
+    #[test]
+    fn weak_title_matches_outrank_strong_subtitle_matches() {
+        let commands = vec![
+            Command::new("build", "Build").subtitle("Copy Project"),
+            Command::new("scope", "Scope Tracking"),
+        ];
+
+        assert_eq!(filter(&commands, "cp"), vec![1, 0]);
+    }
+}
@@ -0,0 +1,459 @@
+//! A command palette: a floating window with a search field over a list of commands, filtered
+//! as you type - the ⌘⇧P (or ⌘K) pattern a lot of modern apps ship.
+//!
+//! The palette handles the fiddly parts for you: the arrow keys move through the results while
+//! the search field keeps focus, Return (or clicking a result) runs the selected command, and
+//! Escape - or clicking outside the palette - dismisses it.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::command_palette::{Command, CommandPalette};
+//!
+//! let palette = CommandPalette::new(|command| {
+//!     match command.id.as_str() {
+//!         "new-window" => println!("Opening a window..."),
+//!         "toggle-sidebar" => println!("Toggling the sidebar..."),
+//!         _ => {}
+//!     }
+//! });
+//!
+//! palette.set_commands(vec![
+//!     Command::new("new-window", "New Window").shortcut("⌘N"),
+//!     Command::new("toggle-sidebar", "Toggle Sidebar").subtitle("View").shortcut("⌃⌘S")
+//! ]);
+//!
+//! // e.g, from a menu item's action:
+//! palette.show();
+//! ```
+//!
+//! Matching is fuzzy: every character typed has to appear in a command's title, in order, so
+//! `"tsb"` finds "Toggle Sidebar". Commands whose words start with what's typed rank first.
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+use core_graphics::geometry::{CGPoint, CGRect};
+
+use objc::{msg_send, sel};
+
+use crate::appkit::window::{TitleVisibility, Window, WindowButton, WindowConfig, WindowDelegate, WindowLevel, WindowStyle};
+use crate::foundation::YES;
+use crate::input::{TextField, TextFieldDelegate};
+use crate::layout::{Layout, LayoutConstraint};
+use crate::listview::{ListView, ListViewDelegate, ListViewRow};
+use crate::text::Font;
+use crate::view::View;
+
+mod filter;
+
+mod row;
+use row::{CommandRow, COMMAND_ROW};
+
+/// The size of the palette window.
+const WIDTH: f64 = 560.;
+const HEIGHT: f64 = 320.;
+
+/// How far below the top of the parent window the palette sits, when shown with `show_in()`.
+const TOP_OFFSET: f64 = 80.;
+
+/// A command the palette can run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Command {
+    /// Identifies the command - this is what you'll match on in the palette's handler.
+    pub id: String,
+
+    /// The title shown in the palette, and matched against what's typed.
+    pub title: String,
+
+    /// Secondary text shown after the title (e.g, the menu the command lives in). This is
+    /// matched against as well, but ranks below title matches.
+    pub subtitle: Option<String>,
+
+    /// The keyboard shortcut shown at the trailing edge (e.g, `"⌘N"`), if any. This is purely for
+    /// display; the palette doesn't register it.
+    pub shortcut: Option<String>
+}
+
+impl Command {
+    /// Creates a command with an identifier and a title.
+    pub fn new<I: Into<String>, T: Into<String>>(id: I, title: T) -> Self {
+        Command {
+            id: id.into(),
+            title: title.into(),
+            subtitle: None,
+            shortcut: None
+        }
+    }
+
+    /// Sets the secondary text shown after the title.
+    pub fn subtitle<S: Into<String>>(mut self, subtitle: S) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// Sets the keyboard shortcut shown at the trailing edge.
+    pub fn shortcut<S: Into<String>>(mut self, shortcut: S) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+}
+
+/// An indirection layer for the handler, for the same reasons as `invoker::Action`.
+struct CommandHandler(Box<dyn Fn(&Command) + 'static>);
+
+impl fmt::Debug for CommandHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandHandler").finish()
+    }
+}
+
+/// The state shared between the palette and the delegates for its window, search field and list.
+#[derive(Debug)]
+struct PaletteState {
+    commands: RefCell<Vec<Command>>,
+
+    /// Indexes into `commands`, in the order they're listed.
+    results: RefCell<Vec<usize>>,
+
+    window: RefCell<Option<Window>>,
+    list: RefCell<Option<ListView>>,
+
+    /// Set while we change the list's selection ourselves, so that it isn't mistaken for a click.
+    selecting: Cell<bool>,
+
+    handler: CommandHandler
+}
+
+impl PaletteState {
+    /// Re-filters the commands for `query`, and selects the best match.
+    fn filter(&self, query: &str) {
+        let results = filter::filter(&self.commands.borrow(), query);
+        let has_results = !results.is_empty();
+        *self.results.borrow_mut() = results;
+
+        // Reloading can shuffle the selection, which shouldn't count as a click either.
+        if let Some(list) = &*self.list.borrow() {
+            self.selecting.set(true);
+            list.reload();
+            self.selecting.set(false);
+        }
+
+        if has_results {
+            self.select(0);
+        }
+    }
+
+    /// Selects the result at `row`, and scrolls to it.
+    fn select(&self, row: usize) {
+        if let Some(list) = &*self.list.borrow() {
+            self.selecting.set(true);
+            list.select_row_indexes(&[row], false);
+            list.scroll_row_to_visible(row);
+            self.selecting.set(false);
+        }
+    }
+
+    /// Moves the selection up (negative `offset`) or down the results, stopping at either end.
+    fn move_selection(&self, offset: isize) {
+        let count = self.results.borrow().len() as isize;
+        if count == 0 {
+            return;
+        }
+
+        let selected = match &*self.list.borrow() {
+            Some(list) => list.get_selected_row_index() as isize,
+            None => return
+        };
+
+        let row = match selected < 0 {
+            true => 0,
+            false => (selected + offset).max(0).min(count - 1)
+        };
+
+        self.select(row as usize);
+    }
+
+    /// Runs the selected command, if there is one.
+    fn perform_selected(&self) {
+        let selected = match &*self.list.borrow() {
+            Some(list) => list.get_selected_row_index(),
+            None => return
+        };
+
+        if selected >= 0 {
+            self.perform(selected as usize);
+        }
+    }
+
+    /// Dismisses the palette, then runs the command at `row` - in that order, so the handler is
+    /// free to show windows of its own.
+    fn perform(&self, row: usize) {
+        let command = match self.results.borrow().get(row) {
+            Some(index) => self.commands.borrow()[*index].clone(),
+            None => return
+        };
+
+        self.dismiss();
+        (self.handler.0)(&command);
+    }
+
+    /// Hides the palette window.
+    fn dismiss(&self) {
+        if let Some(window) = &*self.window.borrow() {
+            if window.is_visible() {
+                window.order_out();
+            }
+        }
+    }
+}
+
+/// The delegate for the palette window, which dismisses the palette when it loses focus.
+#[derive(Debug)]
+pub struct PaletteWindow {
+    state: Rc<PaletteState>
+}
+
+impl WindowDelegate for PaletteWindow {
+    const NAME: &'static str = "RSTCommandPaletteWindow";
+
+    fn did_load(&mut self, window: Window) {
+        *self.state.window.borrow_mut() = Some(window);
+    }
+
+    fn did_resign_key(&self) {
+        self.state.dismiss();
+    }
+
+    fn cancel(&self) {
+        self.state.dismiss();
+    }
+}
+
+/// The delegate for the search field, which filters as you type and handles the keys for moving
+/// through the results.
+#[derive(Debug)]
+pub struct PaletteSearch {
+    state: Rc<PaletteState>
+}
+
+impl TextFieldDelegate for PaletteSearch {
+    const NAME: &'static str = "RSTCommandPaletteSearchField";
+
+    fn text_did_change(&self, value: &str) {
+        self.state.filter(value);
+    }
+
+    fn do_command_by_selector(&self, selector: &str) -> bool {
+        match selector {
+            "moveUp:" => self.state.move_selection(-1),
+            "moveDown:" => self.state.move_selection(1),
+            "insertNewline:" => self.state.perform_selected(),
+            "cancelOperation:" => self.state.dismiss(),
+            _ => return false
+        }
+
+        true
+    }
+}
+
+/// The delegate for the list of results.
+#[derive(Debug)]
+pub struct PaletteResults {
+    state: Rc<PaletteState>
+}
+
+impl ListViewDelegate for PaletteResults {
+    const NAME: &'static str = "RSTCommandPaletteResults";
+
+    fn did_load(&mut self, view: ListView) {
+        view.register(COMMAND_ROW, CommandRow::default);
+        *self.state.list.borrow_mut() = Some(view);
+    }
+
+    fn number_of_items(&self) -> usize {
+        self.state.results.borrow().len()
+    }
+
+    fn item_for(&self, row: usize) -> ListViewRow {
+        let list = self.state.list.borrow();
+        let mut item = list.as_ref().unwrap().dequeue::<CommandRow>(COMMAND_ROW);
+
+        if let Some(view) = &mut item.delegate {
+            let index = self.state.results.borrow()[row];
+            view.configure_with(&self.state.commands.borrow()[index]);
+        }
+
+        item.into_row()
+    }
+
+    fn item_selected(&self, row: Option<usize>) {
+        if let Some(row) = row {
+            if !self.state.selecting.get() {
+                self.state.perform(row);
+            }
+        }
+    }
+}
+
+/// A floating command palette. Create one up front, keep it around, and `show()` it when the
+/// user asks for it; `handler` is called with whichever command they pick.
+///
+/// The palette hides itself once a command is picked, on Escape, and when it loses focus.
+#[derive(Debug)]
+pub struct CommandPalette {
+    /// The palette window.
+    pub window: Window<PaletteWindow>,
+
+    /// The content view of the window.
+    pub content: View,
+
+    /// The search field.
+    pub search: TextField<PaletteSearch>,
+
+    /// The list of matching commands.
+    pub results: ListView<PaletteResults>,
+
+    state: Rc<PaletteState>
+}
+
+impl CommandPalette {
+    /// Creates a palette with no commands. `handler` is called with the command the user picks,
+    /// after the palette has been dismissed.
+    pub fn new<F: Fn(&Command) + 'static>(handler: F) -> Self {
+        let state = Rc::new(PaletteState {
+            commands: RefCell::new(Vec::new()),
+            results: RefCell::new(Vec::new()),
+            window: RefCell::new(None),
+            list: RefCell::new(None),
+            selecting: Cell::new(false),
+            handler: CommandHandler(Box::new(handler))
+        });
+
+        // Borderless windows can't become key, so we use a titled one and hide the titlebar.
+        let mut config = WindowConfig::default();
+        config.set_styles(&[WindowStyle::Titled, WindowStyle::FullSizeContentView]);
+        config.set_initial_dimensions(0., 0., WIDTH, HEIGHT);
+
+        let window = Window::with(config, PaletteWindow { state: state.clone() });
+        window.set_title_visibility(TitleVisibility::Hidden);
+        window.set_titlebar_appears_transparent(true);
+        window.set_button_hidden(WindowButton::Close, true);
+        window.set_button_hidden(WindowButton::Miniaturize, true);
+        window.set_button_hidden(WindowButton::Zoom, true);
+        window.set_movable_by_background(true);
+        window.set_excluded_from_windows_menu(true);
+        window.set_level(WindowLevel::Floating);
+
+        let content = View::new();
+
+        let search = TextField::with(PaletteSearch { state: state.clone() });
+        search.set_font(Font::system(18.));
+        search.set_placeholder_text("Type a command");
+
+        let results = ListView::with(PaletteResults { state: state.clone() });
+        results.set_row_height(30.);
+
+        // Keep keyboard focus in the search field, even when a result is clicked.
+        results.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setRefusesFirstResponder: YES];
+        });
+
+        content.add_subview(&search);
+        content.add_subview(&results);
+        window.set_content_view(&content);
+
+        LayoutConstraint::activate(&[
+            search.top.constraint_equal_to(&content.top).offset(14.),
+            search.leading.constraint_equal_to(&content.leading).offset(14.),
+            search.trailing.constraint_equal_to(&content.trailing).offset(-14.),
+            results.top.constraint_equal_to(&search.bottom).offset(10.),
+            results.leading.constraint_equal_to(&content.leading),
+            results.trailing.constraint_equal_to(&content.trailing),
+            results.bottom.constraint_equal_to(&content.bottom)
+        ]);
+
+        CommandPalette {
+            window,
+            content,
+            search,
+            results,
+            state
+        }
+    }
+
+    /// Replaces the commands listed in the palette.
+    pub fn set_commands(&self, commands: Vec<Command>) {
+        *self.state.commands.borrow_mut() = commands;
+        self.state.filter(&self.search.get_value());
+    }
+
+    /// Sets the placeholder text shown in the empty search field. Defaults to "Type a command".
+    pub fn set_placeholder_text(&self, text: &str) {
+        self.search.set_placeholder_text(text);
+    }
+
+    /// Shows the palette in the middle of the screen, with an empty search field.
+    pub fn show(&self) {
+        self.reset();
+        self.window.center();
+        self.present();
+    }
+
+    /// Shows the palette horizontally centered near the top of `parent`, with an empty search
+    /// field.
+    pub fn show_in<W>(&self, parent: &Window<W>) {
+        self.reset();
+
+        unsafe {
+            let frame: CGRect = msg_send![&*parent.objc, frame];
+            let origin = CGPoint::new(
+                frame.origin.x + (frame.size.width - WIDTH) / 2.,
+                frame.origin.y + frame.size.height - HEIGHT - TOP_OFFSET
+            );
+
+            let _: () = msg_send![&*self.window.objc, setFrameOrigin: origin];
+        }
+
+        self.present();
+    }
+
+    /// Toggles the palette: shows it (as with `show()`) if it's hidden, and dismisses it
+    /// otherwise.
+    pub fn toggle(&self) {
+        match self.is_visible() {
+            true => self.dismiss(),
+            false => self.show()
+        }
+    }
+
+    /// Hides the palette without running anything.
+    pub fn dismiss(&self) {
+        self.state.dismiss();
+    }
+
+    /// Whether the palette is currently shown.
+    pub fn is_visible(&self) -> bool {
+        self.window.is_visible()
+    }
+
+    /// Clears the search field and lists every command.
+    fn reset(&self) {
+        self.search.set_text("");
+        self.state.filter("");
+    }
+
+    /// Brings the palette forward and puts the cursor in the search field.
+    fn present(&self) {
+        self.window.make_key_and_order_front();
+        self.window.make_first_responder(&self.search);
+    }
+}
+
+impl Drop for CommandPalette {
+    /// Hides the palette, and drops the handles the delegates keep to the window and list.
+    fn drop(&mut self) {
+        self.state.dismiss();
+        self.state.window.borrow_mut().take();
+        self.state.list.borrow_mut().take();
+    }
+}
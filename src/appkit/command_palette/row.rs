@@ -0,0 +1,79 @@
+//! The row view the command palette shows for each result.
+
+use crate::color::Color;
+use crate::layout::{Layout, LayoutConstraint};
+use crate::text::{Font, Label, LineBreakMode, TextAlign};
+use crate::view::{View, ViewDelegate};
+
+use super::Command;
+
+/// The identifier `CommandRow`s are registered and dequeued under.
+pub(crate) const COMMAND_ROW: &str = "RSTCommandPaletteRow";
+
+/// A row with the command's title, its subtitle in a secondary color, and its shortcut (if any)
+/// at the trailing edge.
+#[derive(Debug, Default)]
+pub(crate) struct CommandRow {
+    title: Label,
+    subtitle: Label,
+    shortcut: Label
+}
+
+impl CommandRow {
+    /// Updates this row to show `command`.
+    pub fn configure_with(&mut self, command: &Command) {
+        self.title.set_text(&command.title);
+
+        match &command.subtitle {
+            Some(subtitle) => {
+                self.subtitle.set_text(subtitle);
+                self.subtitle.set_hidden(false);
+            },
+
+            None => self.subtitle.set_hidden(true)
+        }
+
+        match &command.shortcut {
+            Some(shortcut) => {
+                self.shortcut.set_text(shortcut);
+                self.shortcut.set_hidden(false);
+            },
+
+            None => self.shortcut.set_hidden(true)
+        }
+    }
+}
+
+impl ViewDelegate for CommandRow {
+    const NAME: &'static str = "RSTCommandPaletteRow";
+
+    fn did_load(&mut self, view: View) {
+        view.add_subview(&self.title);
+        view.add_subview(&self.subtitle);
+        view.add_subview(&self.shortcut);
+
+        self.title.set_font(Font::system(14.));
+        self.title.set_line_break_mode(LineBreakMode::TruncateTail);
+
+        self.subtitle.set_font(Font::system(12.));
+        self.subtitle.set_text_color(Color::LabelSecondary);
+        self.subtitle.set_line_break_mode(LineBreakMode::TruncateTail);
+
+        self.shortcut.set_font(Font::system(12.));
+        self.shortcut.set_text_color(Color::LabelSecondary);
+        self.shortcut.set_text_alignment(TextAlign::Right);
+
+        LayoutConstraint::activate(&[
+            self.title.leading.constraint_equal_to(&view.leading).offset(10.),
+            self.title.center_y.constraint_equal_to(&view.center_y),
+            self.subtitle.leading.constraint_equal_to(&self.title.trailing).offset(8.),
+            self.subtitle.center_y.constraint_equal_to(&view.center_y),
+            self.subtitle
+                .trailing
+                .constraint_less_than_or_equal_to(&self.shortcut.leading)
+                .offset(-8.),
+            self.shortcut.trailing.constraint_equal_to(&view.trailing).offset(-10.),
+            self.shortcut.center_y.constraint_equal_to(&view.center_y)
+        ]);
+    }
+}
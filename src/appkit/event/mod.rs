@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use bitmask_enum::bitmask;
 use block::ConcreteBlock;
 
@@ -6,9 +8,20 @@ use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
 use crate::events::EventType;
-use crate::foundation::{id, nil, NSInteger, NSPoint, NSString};
+use core_graphics::base::CGFloat;
+
+use crate::foundation::{id, nil, to_bool, NSArray, NSInteger, NSPoint, NSString, BOOL};
+use crate::objc_access::ObjcAccess;
+
+mod touch;
+pub use touch::{Touch, TouchPhase};
 
-/// An EventMask describes the type of event.
+/// `NSEventSubtype` values for mouse events coming from a tablet.
+const SUBTYPE_TABLET_POINT: i16 = 1;
+const SUBTYPE_TABLET_PROXIMITY: i16 = 2;
+
+/// An EventMask describes the type of event. Masks can be combined to monitor several kinds of
+/// event at once, e.g `EventMask::KeyDown | EventMask::FlagsChanged`.
 #[bitmask(u64)]
 pub enum EventMask {
     LeftMouseDown = 1 << 1,
@@ -51,10 +64,215 @@ pub enum EventMask {
     ChangeMode = 1 << 38
 }
 
-/// A wrapper over an `NSEvent`.
+impl From<EventType> for EventMask {
+    /// Returns the mask matching events of the given type.
+    fn from(kind: EventType) -> Self {
+        EventMask {
+            bits: 1u64 << (kind as u64)
+        }
+    }
+}
+
+/// An event monitor installed by `Event::local_monitor()` or `Event::global_monitor()`. The
+/// monitor is removed when this is dropped.
 #[derive(Debug)]
 pub struct EventMonitor(pub Id<Object, Owned>);
 
+impl EventMonitor {
+    /// Removes the monitor. This is the same as dropping it, but reads better at call sites that
+    /// want to be explicit.
+    pub fn remove(self) {}
+}
+
+impl Drop for EventMonitor {
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![class!(NSEvent), removeMonitor: &*self.0];
+        }
+    }
+}
+
+/// The details of a key event (`KeyDown`, `KeyUp` or `FlagsChanged`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyEvent {
+    /// Whether this is a key down, key up or modifier change.
+    pub kind: KeyEventKind,
+
+    /// The characters the key press produced, taking modifiers into account (e.g, `"A"` for
+    /// Shift-A). Empty for `FlagsChanged`.
+    pub characters: String,
+
+    /// The characters the key press would have produced without modifiers (other than Shift).
+    /// Empty for `FlagsChanged`.
+    pub characters_ignoring_modifiers: String,
+
+    /// The hardware key code, which doesn't depend on the keyboard layout.
+    pub key_code: u16,
+
+    /// The modifier flags held during the event; test these with `has_modifier()`.
+    pub modifier_flags: NSUInteger,
+
+    /// Whether this is a repeat from the key being held down.
+    pub is_repeat: bool
+}
+
+impl KeyEvent {
+    /// Returns whether `flag` was held during the event.
+    pub fn has_modifier(&self, flag: EventModifierFlag) -> bool {
+        let flag: NSUInteger = flag.into();
+        self.modifier_flags & flag == flag
+    }
+}
+
+/// The kinds of `KeyEvent`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyEventKind {
+    /// A key was pressed.
+    Down,
+
+    /// A key was released.
+    Up,
+
+    /// A modifier key (Shift, Command, etc) was pressed or released.
+    FlagsChanged
+}
+
+/// The details of a mouse event (clicks, drags, movement and scrolling).
+#[derive(Clone, Debug)]
+pub struct MouseEvent {
+    /// The event's type, e.g `EventType::LeftMouseDown`.
+    pub kind: EventType,
+
+    /// Where the event happened, in the coordinates of the window it happened in - or, for events
+    /// that aren't associated with a window (e.g, from a global monitor), in screen coordinates.
+    pub location: NSPoint,
+
+    /// The button number (`0` is the left button, `1` the right).
+    pub button_number: NSInteger,
+
+    /// The click count for clicks (`2` for a double click), and `0` otherwise.
+    pub click_count: NSInteger,
+
+    /// The modifier flags held during the event; test these with `has_modifier()`.
+    pub modifier_flags: NSUInteger,
+
+    /// The scroll amount along the x and y axes, for `ScrollWheel` events.
+    pub scrolling_delta: (CGFloat, CGFloat),
+
+    /// For events from a tablet stylus (rather than a mouse or trackpad), its pressure, tilt and
+    /// so on.
+    pub tablet: Option<TabletPoint>
+}
+
+impl MouseEvent {
+    /// Returns whether `flag` was held during the event.
+    pub fn has_modifier(&self, flag: EventModifierFlag) -> bool {
+        let flag: NSUInteger = flag.into();
+        self.modifier_flags & flag == flag
+    }
+}
+
+/// The state of a tablet stylus, for drawing. Tablets send these as part of mouse events while
+/// the stylus is down (or hovering), and as standalone `TabletPoint` events otherwise.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TabletPoint {
+    /// How hard the stylus is pressed, from `0.0` to `1.0`.
+    pub pressure: f32,
+
+    /// How far the stylus is tilted along the x and y axes, each from `-1.0` to `1.0` (`0.0` is
+    /// upright).
+    pub tilt: NSPoint,
+
+    /// The stylus's rotation in degrees, for styluses that support it.
+    pub rotation: f32,
+
+    /// The pressure on the stylus's barrel wheel (e.g, an airbrush), from `-1.0` to `1.0`.
+    pub tangential_pressure: f32,
+
+    /// The position on the tablet itself, in the tablet's own coordinates, along the x, y and z
+    /// axes. This has far more precision than the location on screen.
+    pub absolute_position: (NSInteger, NSInteger, NSInteger),
+
+    /// Which of the stylus's buttons are pressed, as a bit mask.
+    pub button_mask: NSUInteger,
+
+    /// Identifies the stylus, matching `TabletProximity::device_id`.
+    pub device_id: NSUInteger
+}
+
+/// The kinds of tablet pointing device.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PointingDeviceType {
+    /// The tablet didn't say.
+    Unknown,
+
+    /// The tip of a stylus.
+    Pen,
+
+    /// A puck, or other cursor device.
+    Cursor,
+
+    /// The eraser end of a stylus.
+    Eraser
+}
+
+impl From<NSUInteger> for PointingDeviceType {
+    fn from(kind: NSUInteger) -> Self {
+        match kind {
+            1 => PointingDeviceType::Pen,
+            2 => PointingDeviceType::Cursor,
+            3 => PointingDeviceType::Eraser,
+            _ => PointingDeviceType::Unknown
+        }
+    }
+}
+
+/// Sent when a tablet stylus comes into (or leaves) range of the tablet - e.g, so you can switch
+/// to an eraser tool when the user flips the stylus over.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TabletProximity {
+    /// Whether the device came into range (`true`) or left it (`false`).
+    pub is_entering: bool,
+
+    /// What kind of device it is.
+    pub pointing_device_type: PointingDeviceType,
+
+    /// Identifies the device, for matching up with `TabletPoint::device_id`.
+    pub device_id: NSUInteger,
+
+    /// The index of the device on the tablet, for tablets that support several at once.
+    pub pointing_device_id: NSUInteger,
+
+    /// The device's serial number.
+    pub pointing_device_serial_number: NSUInteger,
+
+    /// A unique identifier for the device, which stays the same across tablets - so you can
+    /// remember per-stylus settings.
+    pub unique_id: u64,
+
+    /// The vendor-defined capabilities of the device, as a bit mask.
+    pub capability_mask: NSUInteger
+}
+
+/// An `Event`, sorted by kind. See `Event::typed()`.
+#[derive(Clone, Debug)]
+pub enum TypedEvent {
+    /// A key event.
+    Key(KeyEvent),
+
+    /// A mouse event.
+    Mouse(MouseEvent),
+
+    /// A standalone tablet event, with the stylus's state.
+    TabletPoint(TabletPoint),
+
+    /// A tablet device coming into or leaving range.
+    TabletProximity(TabletProximity),
+
+    /// Any other kind of event (gestures, tablet events, etc).
+    Other(EventType)
+}
+
 /// A wrapper over an `NSEvent`.
 #[derive(Debug)]
 pub struct Event(pub Id<Object, Owned>);
@@ -88,11 +306,163 @@ impl Event {
         unsafe { msg_send![class!(NSEvent), pressedMouseButtons] }
     }
 
-    /// Reports the current mouse position in screen coordinates.
+    /// Reports the current mouse position in screen coordinates (with the origin at the bottom
+    /// left of the main screen).
     pub fn mouse_location() -> NSPoint {
         unsafe { msg_send![class!(NSEvent), mouseLocation] }
     }
 
+    /// Returns the modifier flags currently held, regardless of which events have been delivered.
+    pub fn current_modifier_flags() -> NSUInteger {
+        unsafe { msg_send![class!(NSEvent), modifierFlags] }
+    }
+
+    /// Returns whether `flag` is currently held.
+    pub fn is_modifier_pressed(flag: EventModifierFlag) -> bool {
+        let flag: NSUInteger = flag.into();
+        Event::current_modifier_flags() & flag == flag
+    }
+
+    /// The longest time between two clicks for them to count as a double click, per the user's
+    /// settings.
+    pub fn double_click_interval() -> Duration {
+        let interval: f64 = unsafe { msg_send![class!(NSEvent), doubleClickInterval] };
+        Duration::from_secs_f64(interval)
+    }
+
+    /// Where the event happened, in the coordinates of its window.
+    pub fn location_in_window(&self) -> NSPoint {
+        unsafe { msg_send![&*self.0, locationInWindow] }
+    }
+
+    /// For pressure events, how hard the user is pressing the trackpad, from `0.0` to `1.0`
+    /// within the current stage.
+    pub fn pressure(&self) -> f32 {
+        unsafe { msg_send![&*self.0, pressure] }
+    }
+
+    /// For pressure events, the current stage: `0` for no click, `1` for a normal click and `2`
+    /// for a force click.
+    pub fn stage(&self) -> NSInteger {
+        unsafe { msg_send![&*self.0, stage] }
+    }
+
+    /// For pressure events, how close the user is to the next (positive) or previous (negative)
+    /// stage - useful for animating towards a force click preview.
+    pub fn stage_transition(&self) -> CGFloat {
+        unsafe { msg_send![&*self.0, stageTransition] }
+    }
+
+    /// For magnify events, the change in magnification (add `1.0` to get a scale factor).
+    pub fn magnification(&self) -> CGFloat {
+        unsafe { msg_send![&*self.0, magnification] }
+    }
+
+    /// For rotate events, the rotation in degrees (counterclockwise is positive).
+    pub fn rotation(&self) -> f32 {
+        unsafe { msg_send![&*self.0, rotation] }
+    }
+
+    /// For swipe events, the horizontal direction: `1.0` for a swipe to the left, `-1.0` for a
+    /// swipe to the right.
+    pub fn delta_x(&self) -> CGFloat {
+        unsafe { msg_send![&*self.0, deltaX] }
+    }
+
+    /// For swipe events, the vertical direction: `1.0` for a swipe up, `-1.0` for a swipe down.
+    pub fn delta_y(&self) -> CGFloat {
+        unsafe { msg_send![&*self.0, deltaY] }
+    }
+
+    /// For mouse and tablet events from a tablet stylus, how far it's tilted along the x and y
+    /// axes, each from `-1.0` to `1.0`.
+    pub fn tilt(&self) -> NSPoint {
+        unsafe { msg_send![&*self.0, tilt] }
+    }
+
+    /// For tablet events, the pressure on the stylus's barrel wheel, from `-1.0` to `1.0`.
+    pub fn tangential_pressure(&self) -> f32 {
+        unsafe { msg_send![&*self.0, tangentialPressure] }
+    }
+
+    /// Returns the stylus's state, for tablet events and mouse events that came from a tablet -
+    /// or `None` for anything else.
+    pub fn tablet_point(&self) -> Option<TabletPoint> {
+        let is_tablet = match self.kind() {
+            EventType::TabletPoint => true,
+            kind if is_mouse_event(kind) => self.subtype() == SUBTYPE_TABLET_POINT,
+            _ => false
+        };
+
+        if !is_tablet {
+            return None;
+        }
+
+        unsafe {
+            Some(TabletPoint {
+                pressure: self.pressure(),
+                tilt: self.tilt(),
+                rotation: self.rotation(),
+                tangential_pressure: self.tangential_pressure(),
+                absolute_position: (msg_send![&*self.0, absoluteX], msg_send![&*self.0, absoluteY], msg_send![
+                    &*self.0, absoluteZ
+                ]),
+                button_mask: msg_send![&*self.0, buttonMask],
+                device_id: msg_send![&*self.0, deviceID]
+            })
+        }
+    }
+
+    /// Returns the details of a tablet proximity event (or a mouse event standing in for one),
+    /// or `None` for anything else.
+    pub fn tablet_proximity(&self) -> Option<TabletProximity> {
+        let is_proximity = match self.kind() {
+            EventType::TabletProximity => true,
+            kind if is_mouse_event(kind) => self.subtype() == SUBTYPE_TABLET_PROXIMITY,
+            _ => false
+        };
+
+        if !is_proximity {
+            return None;
+        }
+
+        unsafe {
+            let is_entering: BOOL = msg_send![&*self.0, isEnteringProximity];
+            let pointing_device_type: NSUInteger = msg_send![&*self.0, pointingDeviceType];
+            let unique_id: u64 = msg_send![&*self.0, uniqueID];
+
+            Some(TabletProximity {
+                is_entering: to_bool(is_entering),
+                pointing_device_type: pointing_device_type.into(),
+                device_id: msg_send![&*self.0, deviceID],
+                pointing_device_id: msg_send![&*self.0, pointingDeviceID],
+                pointing_device_serial_number: msg_send![&*self.0, pointingDeviceSerialNumber],
+                unique_id,
+                capability_mask: msg_send![&*self.0, capabilityMask]
+            })
+        }
+    }
+
+    /// For touch events, returns the touches in `phase` that are meant for `view` (typically the
+    /// view whose delegate is handling the event).
+    pub fn touches<V: ObjcAccess>(&self, phase: TouchPhase, view: &V) -> Vec<Touch> {
+        let touches: id = view.get_from_backing_obj(|obj| unsafe {
+            msg_send![&*self.0, touchesMatchingPhase: phase.bits as NSUInteger, inView: obj]
+        });
+
+        touches_from_set(touches)
+    }
+
+    /// For touch events, returns every touch involved, regardless of phase or view.
+    pub fn all_touches(&self) -> Vec<Touch> {
+        touches_from_set(unsafe { msg_send![&*self.0, allTouches] })
+    }
+
+    /// The event's subtype, which says whether a mouse event came from a tablet.
+    fn subtype(&self) -> i16 {
+        unsafe { msg_send![&*self.0, subtype] }
+    }
+
     /// The button number for a mouse event.
     pub fn button_number(&self) -> NSInteger {
         unsafe { msg_send![&*self.0, buttonNumber] }
@@ -103,6 +473,106 @@ impl Event {
         unsafe { msg_send![&*self.0, clickCount] }
     }
 
+    /// The modifier flags held during the event.
+    pub fn modifier_flags(&self) -> NSUInteger {
+        unsafe { msg_send![&*self.0, modifierFlags] }
+    }
+
+    /// Returns the details of this event, sorted by kind - so that, e.g, a monitor for
+    /// `EventMask::KeyDown | EventMask::LeftMouseDown` can match on what it got:
+    ///
+    /// ```rust,no_run
+    /// use cacao::appkit::{Event, EventMask, TypedEvent};
+    ///
+    /// let monitor = Event::local_monitor(EventMask::KeyDown | EventMask::LeftMouseDown, |event| {
+    ///     match event.typed() {
+    ///         TypedEvent::Key(key) => println!("Pressed {}", key.characters),
+    ///         TypedEvent::Mouse(mouse) => println!("Clicked at {:?}", mouse.location),
+    ///         TypedEvent::Other(_) => {}
+    ///     }
+    ///
+    ///     Some(event)
+    /// });
+    /// ```
+    pub fn typed(&self) -> TypedEvent {
+        let kind = self.kind();
+
+        let key_kind = match kind {
+            EventType::KeyDown => Some(KeyEventKind::Down),
+            EventType::KeyUp => Some(KeyEventKind::Up),
+            EventType::FlagsChanged => Some(KeyEventKind::FlagsChanged),
+            _ => None
+        };
+
+        if let Some(key_kind) = key_kind {
+            // `characters` raises for `FlagsChanged` events.
+            let (characters, characters_ignoring_modifiers, is_repeat) = match key_kind {
+                KeyEventKind::FlagsChanged => (String::new(), String::new(), false),
+                _ => unsafe {
+                    let ignoring = NSString::retain(msg_send![&*self.0, charactersIgnoringModifiers]);
+                    let is_repeat: BOOL = msg_send![&*self.0, isARepeat];
+                    (self.characters(), ignoring.to_string(), to_bool(is_repeat))
+                }
+            };
+
+            return TypedEvent::Key(KeyEvent {
+                kind: key_kind,
+                characters,
+                characters_ignoring_modifiers,
+                key_code: unsafe { msg_send![&*self.0, keyCode] },
+                modifier_flags: self.modifier_flags(),
+                is_repeat
+            });
+        }
+
+        match kind {
+            kind if is_mouse_event(kind) => {
+                let is_click = matches!(
+                    kind,
+                    EventType::LeftMouseDown
+                        | EventType::LeftMouseUp
+                        | EventType::RightMouseDown
+                        | EventType::RightMouseUp
+                        | EventType::OtherMouseDown
+                        | EventType::OtherMouseUp
+                );
+
+                let scrolling_delta = match kind {
+                    EventType::ScrollWheel => unsafe {
+                        (msg_send![&*self.0, scrollingDeltaX], msg_send![&*self.0, scrollingDeltaY])
+                    },
+
+                    _ => (0., 0.)
+                };
+
+                TypedEvent::Mouse(MouseEvent {
+                    kind,
+                    location: unsafe { msg_send![&*self.0, locationInWindow] },
+                    button_number: self.button_number(),
+                    click_count: match is_click {
+                        true => self.click_count(),
+                        false => 0
+                    },
+                    modifier_flags: self.modifier_flags(),
+                    scrolling_delta,
+                    tablet: self.tablet_point()
+                })
+            },
+
+            EventType::TabletPoint => match self.tablet_point() {
+                Some(point) => TypedEvent::TabletPoint(point),
+                None => TypedEvent::Other(kind)
+            },
+
+            EventType::TabletProximity => match self.tablet_proximity() {
+                Some(proximity) => TypedEvent::TabletProximity(proximity),
+                None => TypedEvent::Other(kind)
+            },
+
+            _ => TypedEvent::Other(kind)
+        }
+    }
+
     /*pub fn contains_modifier_flags(&self, flags: &[EventModifierFlag]) -> bool {
         let modifier_flags: NSUInteger = unsafe {
             msg_send![&*self.0, modifierFlags]
@@ -122,6 +592,9 @@ impl Event {
     ///
     /// Note that in order to monitor all possible events, both local and global
     /// monitors are required - the streams don't mix.
+    ///
+    /// Return the event (or a different one) from the handler to have it dispatched as normal, or
+    /// `None` to consume it. The monitor is removed when the returned `EventMonitor` is dropped.
     pub fn local_monitor<F>(mask: EventMask, handler: F) -> EventMonitor
     where
         F: Fn(Event) -> Option<Event> + Send + Sync + 'static
@@ -151,17 +624,16 @@ impl Event {
     ///
     /// Note that in order to monitor all possible events, both local and global
     /// monitors are required - the streams don't mix.
+    ///
+    /// Unlike local monitors, global monitors can only observe events - not modify or consume
+    /// them. Key events are only delivered if the app is trusted for accessibility (see
+    /// System Settings > Privacy & Security > Accessibility).
     pub fn global_monitor<F>(mask: EventMask, handler: F) -> EventMonitor
     where
-        F: Fn(Event) -> Option<Event> + Send + Sync + 'static
+        F: Fn(Event) + Send + Sync + 'static
     {
         let block = ConcreteBlock::new(move |event: id| {
-            let evt = Event::new(event);
-
-            match handler(evt) {
-                Some(mut evt) => &mut *evt.0,
-                None => nil
-            }
+            handler(Event::new(event));
         });
         let block = block.copy();
 
@@ -175,14 +647,45 @@ impl Event {
     }
 }
 
+/// Whether `kind` is a mouse event (clicks, drags, movement and scrolling) - the ones with a
+/// `MouseEvent`, and which can carry tablet data.
+fn is_mouse_event(kind: EventType) -> bool {
+    matches!(
+        kind,
+        EventType::LeftMouseDown
+            | EventType::LeftMouseUp
+            | EventType::RightMouseDown
+            | EventType::RightMouseUp
+            | EventType::OtherMouseDown
+            | EventType::OtherMouseUp
+            | EventType::MouseMoved
+            | EventType::LeftMouseDragged
+            | EventType::RightMouseDragged
+            | EventType::OtherMouseDragged
+            | EventType::ScrollWheel
+    )
+}
+
+/// Reads the touches out of an `NSSet` of `NSTouch`es.
+fn touches_from_set(touches: id) -> Vec<Touch> {
+    if touches == nil {
+        return Vec::new();
+    }
+
+    let touches = NSArray::retain(unsafe { msg_send![touches, allObjects] });
+    touches.iter().map(Touch::retain).collect()
+}
+
 use crate::foundation::NSUInteger;
 
 #[derive(Clone, Copy, Debug)]
 pub enum EventModifierFlag {
     CapsLock,
+    Shift,
     Control,
     Option,
     Command,
+    Function,
     DeviceIndependentFlagsMask
 }
 
@@ -190,9 +693,11 @@ impl From<EventModifierFlag> for NSUInteger {
     fn from(flag: EventModifierFlag) -> NSUInteger {
         match flag {
             EventModifierFlag::CapsLock => 1 << 16,
+            EventModifierFlag::Shift => 1 << 17,
             EventModifierFlag::Control => 1 << 18,
             EventModifierFlag::Option => 1 << 19,
             EventModifierFlag::Command => 1 << 20,
+            EventModifierFlag::Function => 1 << 23,
             EventModifierFlag::DeviceIndependentFlagsMask => 0xffff0000
         }
     }
@@ -202,9 +707,11 @@ impl From<&EventModifierFlag> for NSUInteger {
     fn from(flag: &EventModifierFlag) -> NSUInteger {
         match flag {
             EventModifierFlag::CapsLock => 1 << 16,
+            EventModifierFlag::Shift => 1 << 17,
             EventModifierFlag::Control => 1 << 18,
             EventModifierFlag::Option => 1 << 19,
             EventModifierFlag::Command => 1 << 20,
+            EventModifierFlag::Function => 1 << 23,
             EventModifierFlag::DeviceIndependentFlagsMask => 0xffff0000
         }
     }
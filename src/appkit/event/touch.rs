@@ -0,0 +1,84 @@
+//! Wraps `NSTouch`, a finger on a trackpad. Views receive these once they've opted in with
+//! `View::set_accepts_touches()`; see `ViewDelegate::touches_began()` and friends.
+
+use bitmask_enum::bitmask;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{msg_send, sel};
+
+use core_graphics::geometry::CGSize;
+
+use crate::foundation::{id, to_bool, NSPoint, NSUInteger};
+
+/// The phases a `Touch` goes through, for picking touches out of an event with
+/// `Event::touches()`.
+#[bitmask(u64)]
+pub enum TouchPhase {
+    /// The finger touched down.
+    Began = 1 << 0,
+
+    /// The finger moved.
+    Moved = 1 << 1,
+
+    /// The finger is resting on the trackpad without moving.
+    Stationary = 1 << 2,
+
+    /// The finger lifted off.
+    Ended = 1 << 3,
+
+    /// The system stopped tracking the touch (e.g, a gesture took over).
+    Cancelled = 1 << 4,
+
+    /// Fingers currently on the trackpad (began, moved or stationary).
+    Touching = (1 << 0) | (1 << 1) | (1 << 2),
+
+    /// Every phase.
+    Any = !0
+}
+
+/// A wrapper over an `NSTouch`: one finger on a trackpad, at one point in time.
+#[derive(Clone, Debug)]
+pub struct Touch(pub Id<Object, Shared>);
+
+impl Touch {
+    /// Wraps (and retains) an `NSTouch`.
+    pub(crate) fn retain(touch: id) -> Self {
+        Touch(unsafe { Id::retain(touch).unwrap() })
+    }
+
+    /// Returns whether `other` is the same finger as this, e.g in a later event. Use this to
+    /// follow a finger from `touches_began()` through to `touches_ended()`.
+    pub fn is_same_touch(&self, other: &Touch) -> bool {
+        unsafe {
+            let identity: id = msg_send![&*self.0, identity];
+            let other: id = msg_send![&*other.0, identity];
+            to_bool(msg_send![identity, isEqual: other])
+        }
+    }
+
+    /// The touch's current phase.
+    pub fn phase(&self) -> TouchPhase {
+        let phase: NSUInteger = unsafe { msg_send![&*self.0, phase] };
+
+        TouchPhase { bits: phase as u64 }
+    }
+
+    /// Where the finger is on the trackpad, from `(0, 0)` at the bottom left to `(1, 1)` at the
+    /// top right.
+    pub fn normalized_position(&self) -> NSPoint {
+        unsafe { msg_send![&*self.0, normalizedPosition] }
+    }
+
+    /// The trackpad's size, in points (at 72 per inch) - multiply `normalized_position()` by this
+    /// to get physical distances.
+    pub fn device_size(&self) -> CGSize {
+        unsafe { msg_send![&*self.0, deviceSize] }
+    }
+
+    /// Whether the finger is resting on the trackpad, rather than being used for input. Resting
+    /// touches are only delivered if you asked for them.
+    pub fn is_resting(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, isResting] })
+    }
+}
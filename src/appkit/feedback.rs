@@ -0,0 +1,15 @@
+//! Helpers for giving the user feedback when something they tried to do isn't allowed.
+//!
+//! For a visual equivalent that applies to windows, see `Window::shake()`.
+
+extern "C" {
+    fn NSBeep();
+}
+
+/// Plays the system alert sound. This is the standard way to signal an invalid operation, e.g
+/// pressing a key that has no meaning in the current context.
+pub fn beep() {
+    unsafe {
+        NSBeep();
+    }
+}
@@ -0,0 +1,75 @@
+//! Wraps the shared `NSFontManager` and `NSFontPanel`, the standard Fonts window.
+//!
+//! The Fonts window sends `changeFont:` up the responder chain whenever the user picks something.
+//! A rich text `TextView` (see `TextView::set_rich_text()`) handles this on its own and applies
+//! it to the selection; custom views can implement `ViewDelegate::change_font()`, and use
+//! `FontPanel::convert()` to work out the new font.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::FontPanel;
+//! use cacao::text::Font;
+//!
+//! FontPanel::set_selected_font(Font::system(13.), false);
+//! FontPanel::show();
+//! ```
+
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, nil, to_bool, NO, YES};
+use crate::text::Font;
+
+/// A handle to the app-wide Fonts window. The panel is shared, so everything here is static.
+#[derive(Debug)]
+pub struct FontPanel;
+
+impl FontPanel {
+    /// Brings up the Fonts window, the way the standard "Show Fonts" (Command-T) menu item does.
+    pub fn show() {
+        unsafe {
+            let _: () = msg_send![shared_font_manager(), orderFrontFontPanel: nil];
+        }
+    }
+
+    /// Hides the Fonts window.
+    pub fn hide() {
+        unsafe {
+            let panel: id = msg_send![class!(NSFontPanel), sharedFontPanel];
+            let _: () = msg_send![panel, orderOut: nil];
+        }
+    }
+
+    /// Returns whether the Fonts window is currently shown.
+    pub fn is_visible() -> bool {
+        unsafe {
+            let exists: bool = to_bool(msg_send![class!(NSFontPanel), sharedFontPanelExists]);
+            exists && {
+                let panel: id = msg_send![class!(NSFontPanel), sharedFontPanel];
+                to_bool(msg_send![panel, isVisible])
+            }
+        }
+    }
+
+    /// Tells the Fonts window which font is in use, so it can reflect it. Call this as the
+    /// selection in a custom view changes; pass `true` for `is_multiple` if the selection spans
+    /// more than one font. `TextView` keeps this up to date by itself.
+    pub fn set_selected_font<F: AsRef<Font>>(font: F, is_multiple: bool) {
+        unsafe {
+            let _: () = msg_send![shared_font_manager(), setSelectedFont: &*font.as_ref().0, isMultiple: match is_multiple {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Applies whatever the user just picked in the Fonts window (a new family, a bold trait, a
+    /// bigger size, etc) to `font`, and returns the result. Call this from
+    /// `ViewDelegate::change_font()` for each font in the selection.
+    pub fn convert<F: AsRef<Font>>(font: F) -> Font {
+        Font(unsafe { msg_send_id![shared_font_manager(), convertFont: &*font.as_ref().0] })
+    }
+}
+
+/// Returns the shared `NSFontManager`.
+pub(crate) fn shared_font_manager() -> id {
+    unsafe { msg_send![class!(NSFontManager), sharedFontManager] }
+}
@@ -0,0 +1,74 @@
+//! Showing help for your app - either an Apple Help book bundled with the app, or (for apps that
+//! don't ship one) a page on the web.
+//!
+//! For the Help menu itself, mark a menu via `Menu::set_as_help_menu()` and give it to
+//! `App::set_menu`; AppKit adds its search field to it, which searches every item in your menus.
+//! `MenuItem::help()` makes the standard "<App> Help" item for it.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::menu::{Menu, MenuItem};
+//!
+//! let mut help = Menu::new("Help", vec![MenuItem::help("MyApp", "https://example.com/myapp/help")]);
+//! help.set_as_help_menu();
+//! ```
+
+use objc::{class, msg_send, sel};
+
+use crate::foundation::{id, nil, NSString, BOOL, NSURL};
+
+/// Entry points for showing help.
+#[derive(Debug)]
+pub struct Help;
+
+impl Help {
+    /// Returns whether the app bundles an Apple Help book (i.e, `CFBundleHelpBookName` is set in
+    /// its `Info.plist`).
+    pub fn has_help_book() -> bool {
+        let key = NSString::new("CFBundleHelpBookName");
+
+        unsafe {
+            let bundle: id = msg_send![class!(NSBundle), mainBundle];
+            let name: id = msg_send![bundle, objectForInfoDictionaryKey: &*key];
+            name != nil
+        }
+    }
+
+    /// Opens the app's help book, or `fallback_url` if it doesn't have one.
+    pub fn show(fallback_url: &str) {
+        if !Help::has_help_book() {
+            open_url(fallback_url);
+            return;
+        }
+
+        unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let _: () = msg_send![app, showHelp: nil];
+        }
+    }
+
+    /// Opens the app's help book to the page containing `anchor`, or `fallback_url` if the app
+    /// doesn't have a help book.
+    pub fn show_anchor(anchor: &str, fallback_url: &str) {
+        if !Help::has_help_book() {
+            open_url(fallback_url);
+            return;
+        }
+
+        let anchor = NSString::new(anchor);
+
+        unsafe {
+            let manager: id = msg_send![class!(NSHelpManager), sharedHelpManager];
+            let _: () = msg_send![manager, openHelpAnchor: &*anchor, inBook: nil];
+        }
+    }
+}
+
+/// Opens `url` in the user's default browser.
+fn open_url(url: &str) {
+    let url = NSURL::with_str(url);
+
+    unsafe {
+        let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+        let _: BOOL = msg_send![workspace, openURL: &*url.objc];
+    }
+}
@@ -0,0 +1,84 @@
+//! A fluent builder for `Menu`s, for when nesting `Menu::new(title, vec![...])` calls gets
+//! unwieldy.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::menu::{Menu, MenuItem};
+//! use cacao::events::EventModifierFlag;
+//!
+//! let file = Menu::build("File")
+//!     .action("New Window", "n", || println!("New window!"))
+//!     .separator()
+//!     .submenu("Export", |menu| {
+//!         menu.action("As PDF...", "e", || println!("PDF"))
+//!             .item(MenuItem::new("As Text...").action(|| println!("Text")))
+//!     })
+//!     .item(
+//!         MenuItem::new("Save As...")
+//!             .key_equivalent("s", &[EventModifierFlag::Command, EventModifierFlag::Shift])
+//!             .action(|| println!("Save As"))
+//!     )
+//!     .separator()
+//!     .item(MenuItem::CloseWindow)
+//!     .build();
+//! ```
+//!
+//! Builders produce the same `Menu` values as `Menu::new()`, so the two can be mixed freely.
+
+use crate::appkit::menu::{Menu, MenuItem};
+
+/// Builds up a `Menu`, one item at a time. Create one with `Menu::build()`.
+#[derive(Debug)]
+pub struct MenuBuilder {
+    title: String,
+    items: Vec<MenuItem>
+}
+
+impl MenuBuilder {
+    /// Creates a new, empty builder for a menu with the given title.
+    pub fn new<S: Into<String>>(title: S) -> Self {
+        MenuBuilder {
+            title: title.into(),
+            items: Vec::new()
+        }
+    }
+
+    /// Appends `item` to the menu.
+    pub fn item(mut self, item: MenuItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Appends each of `items` to the menu.
+    pub fn items<I: IntoIterator<Item = MenuItem>>(mut self, items: I) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Appends a separator.
+    pub fn separator(self) -> Self {
+        self.item(MenuItem::Separator)
+    }
+
+    /// Appends a custom item that calls `action` when selected. `key` is the key equivalent
+    /// (combined with the Command key); pass an empty string for none. If you need other
+    /// modifiers, build the item with `MenuItem::new()` and pass it to `item()` instead.
+    pub fn action<S: AsRef<str>, F: Fn() + 'static>(self, title: S, key: &str, action: F) -> Self {
+        self.item(MenuItem::new(title).key(key).action(action))
+    }
+
+    /// Appends an item that opens a submenu. `build` is handed a fresh builder for the submenu.
+    pub fn submenu<S, F>(self, title: S, build: F) -> Self
+    where
+        S: AsRef<str>,
+        F: FnOnce(MenuBuilder) -> MenuBuilder
+    {
+        let title = title.as_ref();
+        let menu = build(MenuBuilder::new(title)).build();
+        self.item(MenuItem::new(title).submenu(menu))
+    }
+
+    /// Consumes the builder, returning the finished `Menu`.
+    pub fn build(self) -> Menu {
+        Menu::new(&self.title, self.items)
+    }
+}
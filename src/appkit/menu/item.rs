@@ -1,6 +1,5 @@
-//! A wrapper for NSMenuItem. Currently only supports menus going
-//! one level deep; this could change in the future but is fine for
-//! now.
+//! A wrapper for NSMenuItem. Custom items can hold a submenu (see `MenuItem::submenu`), so menus
+//! can nest as deep as you need.
 
 use std::fmt;
 
@@ -8,11 +7,19 @@ use objc::rc::{Id, Owned};
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, msg_send_id, sel};
 
+use crate::appkit::font_panel::shared_font_manager;
+use crate::appkit::help::Help;
+use crate::appkit::menu::Menu;
 use crate::events::EventModifierFlag;
-use crate::foundation::{id, load_or_register_class, NSString, NSUInteger};
+use crate::foundation::{id, load_or_register_class, NSInteger, NSString, NSUInteger, YES};
+use crate::image::{Image, SFSymbol};
 
 static BLOCK_PTR: &'static str = "cacaoMenuItemBlockPtr";
 
+extern "C" {
+    static NSMenuItemImportFromDeviceIdentifier: id;
+}
+
 /// An Action is just an indirection layer to get around Rust and optimizing
 /// zero-sum types; without this, pointers to callbacks will end up being
 /// 0x1, and all point to whatever is there first (unsure if this is due to
@@ -103,6 +110,10 @@ pub enum MenuItem {
     /// A standard "Services" menu item.
     Services,
 
+    /// A standard "Open Recent" submenu, populated from the recent documents list (see
+    /// `RecentDocuments`). Selecting an entry calls `AppDelegate::open_recent_document`.
+    OpenRecent,
+
     /// A "hide all other windows" menu item.
     HideOthers,
 
@@ -149,6 +160,27 @@ pub enum MenuItem {
     /// only works on macOS 11.0+.
     ToggleSidebar,
 
+    /// A standard "Bring All to Front" item, for the Window menu.
+    BringAllToFront,
+
+    /// A standard "Show Fonts" item, for the Format menu. Brings up the Fonts window (see
+    /// `FontPanel`).
+    ShowFonts,
+
+    /// A standard "Bold" item, for the Font submenu. Toggles bold on the selected text.
+    Bold,
+
+    /// A standard "Italic" item, for the Font submenu. Toggles italics on the selected text.
+    Italic,
+
+    /// A standard "Underline" item, for the Font submenu. Toggles underlining on the selected
+    /// text.
+    Underline,
+
+    /// A standard "Show Colors" item, for the Format menu. Brings up the Colors window (see
+    /// `ColorPanel`).
+    ShowColors,
+
     /// Represents a Separator. It's useful nonetheless for
     /// separating out pieces of the `NSMenu` structure.
     Separator
@@ -168,8 +200,8 @@ impl MenuItem {
 
             Self::Hide => make_menu_item("Hide", Some("h"), Some(sel!(hide:)), None),
 
-            // This one is a bit tricky to do right, as we need to expose a submenu, which isn't
-            // supported by MenuItem yet.
+            // The submenu here is owned by the system, so we just grab it off the shared
+            // application.
             Self::Services => {
                 let item = make_menu_item("Services", None, None, None);
 
@@ -182,6 +214,13 @@ impl MenuItem {
                 item
             },
 
+            Self::OpenRecent => {
+                let item = make_menu_item("Open Recent", None, None, None);
+                let submenu = crate::appkit::menu::recent::make_open_recent_menu();
+                let _: () = msg_send![&*item, setSubmenu: &*submenu];
+                item
+            },
+
             Self::HideOthers => make_menu_item(
                 "Hide Others",
                 Some("h"),
@@ -216,6 +255,35 @@ impl MenuItem {
                 Some(&[EventModifierFlag::Command, EventModifierFlag::Option])
             ),
 
+            Self::BringAllToFront => make_menu_item("Bring All to Front", None, Some(sel!(arrangeInFront:)), None),
+
+            // The font items are handled by the shared font manager rather than the responder
+            // chain; it works out the new font, then sends `changeFont:` down to the selection.
+            Self::ShowFonts => {
+                let item = make_menu_item("Show Fonts", Some("t"), Some(sel!(orderFrontFontPanel:)), None);
+                let _: () = msg_send![&*item, setTarget: shared_font_manager()];
+                item
+            },
+
+            // The tags are `NSBoldFontMask` and `NSItalicFontMask`.
+            Self::Bold => {
+                let item = make_menu_item("Bold", Some("b"), Some(sel!(addFontTrait:)), None);
+                let _: () = msg_send![&*item, setTarget: shared_font_manager()];
+                let _: () = msg_send![&*item, setTag: 2 as NSInteger];
+                item
+            },
+
+            Self::Italic => {
+                let item = make_menu_item("Italic", Some("i"), Some(sel!(addFontTrait:)), None);
+                let _: () = msg_send![&*item, setTarget: shared_font_manager()];
+                let _: () = msg_send![&*item, setTag: 1 as NSInteger];
+                item
+            },
+
+            Self::Underline => make_menu_item("Underline", Some("u"), Some(sel!(underline:)), None),
+
+            Self::ShowColors => make_menu_item("Show Colors", Some("C"), Some(sel!(orderFrontColorPanel:)), None),
+
             Self::Separator => {
                 let cls = class!(NSMenuItem);
                 msg_send_id![cls, separatorItem]
@@ -223,12 +291,74 @@ impl MenuItem {
         }
     }
 
+    /// Returns the standard "<App> Help" item (Command-?), which opens the app's help book - or, if
+    /// the app doesn't bundle one, `fallback_url`. See `appkit::help`.
+    pub fn help<S: AsRef<str>>(app_name: S, fallback_url: &str) -> Self {
+        let fallback_url = fallback_url.to_string();
+
+        MenuItem::new(format!("{} Help", app_name.as_ref()))
+            .key("?")
+            .action(move || Help::show(&fallback_url))
+    }
+
+    /// Returns an item that AppKit fills in with Continuity Camera options ("Take Photo", "Scan
+    /// Documents", etc) for each of the user's nearby devices. It's only enabled while a view that
+    /// accepts Continuity Camera (see `ViewDelegate::accepts_continuity_camera()`) is in the
+    /// responder chain.
+    pub fn import_from_device() -> Self {
+        let item = make_menu_item("Import from iPhone or iPad", None, None, None);
+
+        unsafe {
+            let _: () = msg_send![&*item, setIdentifier: NSMenuItemImportFromDeviceIdentifier];
+        }
+
+        MenuItem::Custom(item)
+    }
+
+    /// Returns a `Custom` menu item that sends `action` up the responder chain, for standard
+    /// items that need a different title (e.g, "Quit <App>").
+    pub(crate) fn with_selector<S: AsRef<str>>(title: S, key: &str, action: Sel) -> Self {
+        MenuItem::Custom(make_menu_item(title, Some(key), Some(action), None))
+    }
+
     /// Returns a `Custom` menu item, with the given title. You can configure this further with the
     /// builder methods on this object.
     pub fn new<S: AsRef<str>>(title: S) -> Self {
         MenuItem::Custom(make_menu_item(title, None, None, None))
     }
 
+    /// Returns a `Custom` menu item showing `image` next to `title`, firing `action` when chosen.
+    ///
+    /// The image is marked as a template, so that it adapts to dark mode and highlighting.
+    pub fn with_image<S: AsRef<str>, F: Fn() + 'static>(title: S, image: Image, action: F) -> Self {
+        MenuItem::new(title).image(image).action(action)
+    }
+
+    /// Returns a `Custom` menu item showing an SF Symbol next to `title`; see `with_image()`. The
+    /// title doubles as the symbol's accessibility description.
+    ///
+    /// SF Symbols require macOS 11.0 (Big Sur); see `Image::symbol()`.
+    pub fn with_symbol<S: AsRef<str>, F: Fn() + 'static>(title: S, symbol: SFSymbol, action: F) -> Self {
+        let image = Image::symbol(symbol, title.as_ref());
+        MenuItem::with_image(title, image, action)
+    }
+
+    /// Sets the image shown next to the title, marking it as a template image. This does nothing
+    /// if called on a `MenuItem` that is not `Custom`.
+    pub fn image(self, mut image: Image) -> Self {
+        if let MenuItem::Custom(objc) = self {
+            image.set_template(true);
+
+            unsafe {
+                let _: () = msg_send![&*objc, setImage: &*image.0];
+            }
+
+            return MenuItem::Custom(objc);
+        }
+
+        self
+    }
+
     /// Configures the a custom item to have specified key equivalent. This does nothing if called
     /// on a `MenuItem` type that is not `Custom`,
     pub fn key(self, key: &str) -> Self {
@@ -265,6 +395,62 @@ impl MenuItem {
         self
     }
 
+    /// Sets both the key equivalent and its modifier flags for this menu item in one go - e.g,
+    /// `.key_equivalent("s", &[EventModifierFlag::Command, EventModifierFlag::Shift])` for
+    /// "Save As...". This does nothing if called on a `MenuItem` that is not `Custom`.
+    pub fn key_equivalent(self, key: &str, modifiers: &[EventModifierFlag]) -> Self {
+        self.key(key).modifiers(modifiers)
+    }
+
+    /// Sets an identifier for this menu item, which you can use to look it up later via
+    /// `Menu::item_with_identifier()` or `App::menu_item_with_identifier()`. This does nothing if called on a `MenuItem` that is not
+    /// `Custom`.
+    ///
+    /// UI tests (e.g, XCUITest) match menu items against this identifier too, so it doubles as
+    /// the item's accessibility identifier.
+    pub fn identifier(self, identifier: &str) -> Self {
+        if let MenuItem::Custom(objc) = self {
+            unsafe {
+                let identifier = NSString::new(identifier);
+                let _: () = msg_send![&*objc, setIdentifier: &*identifier];
+            }
+
+            return MenuItem::Custom(objc);
+        }
+
+        self
+    }
+
+    /// Marks this menu item as an alternate for the item directly preceding it. Alternates are
+    /// only shown while the user holds the modifier keys they're configured with (typically
+    /// `Option`), and must share the same key equivalent as the item they replace. This does
+    /// nothing if called on a `MenuItem` that is not `Custom`.
+    pub fn alternate(self) -> Self {
+        if let MenuItem::Custom(objc) = self {
+            unsafe {
+                let _: () = msg_send![&*objc, setAlternate: YES];
+            }
+
+            return MenuItem::Custom(objc);
+        }
+
+        self
+    }
+
+    /// Attaches `menu` as a submenu of this item. This does nothing if called on a `MenuItem` that
+    /// is not `Custom`.
+    pub fn submenu(self, menu: Menu) -> Self {
+        if let MenuItem::Custom(objc) = self {
+            unsafe {
+                let _: () = msg_send![&*objc, setSubmenu: &*menu.0];
+            }
+
+            return MenuItem::Custom(objc);
+        }
+
+        self
+    }
+
     /// Attaches a target/action handler to dispatch events. This does nothing if called on a
     /// `MenuItem` that is not `Custom`.
     ///
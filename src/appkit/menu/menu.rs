@@ -2,17 +2,34 @@
 
 use std::sync::{Arc, Mutex};
 
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::{CGPoint, CGRect};
 use objc::rc::{Id, Owned, Shared};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
+use crate::appkit::menu::builder::MenuBuilder;
 use crate::appkit::menu::item::MenuItem;
-use crate::foundation::{id, NSInteger, NSString};
+use crate::foundation::{id, nil, to_bool, NSInteger, NSString};
+use crate::layout::Layout;
+
+/// Special roles a top-level menu can have, which `App::set_menu` registers with the application.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MenuRole {
+    /// Just a menu.
+    None,
+
+    /// The Help menu, which gets the system search field.
+    Help,
+
+    /// The Window menu, which gets the list of open windows.
+    Windows
+}
 
 /// A struct that represents an `NSMenu`. It takes ownership of items, and handles instrumenting
 /// them throughout the application lifecycle.
 #[derive(Debug)]
-pub struct Menu(pub Id<Object, Owned>);
+pub struct Menu(pub Id<Object, Owned>, pub(crate) MenuRole);
 
 impl Menu {
     /// Creates a new `Menu` with the given title, and uses the passed items as submenu items.
@@ -25,7 +42,7 @@ impl Menu {
     ///     to get the menu functioning.
     ///
     pub fn new(title: &str, items: Vec<MenuItem>) -> Self {
-        Menu(unsafe {
+        let menu = unsafe {
             let cls = class!(NSMenu);
             let alloc = msg_send_id![cls, alloc];
             let title = NSString::new(title);
@@ -37,7 +54,27 @@ impl Menu {
             }
 
             menu
-        })
+        };
+
+        Menu(menu, MenuRole::None)
+    }
+
+    /// Marks this as the application's Help menu, once it's passed to `App::set_menu`. AppKit adds
+    /// its search field to the Help menu, which searches every item in your menus.
+    pub fn set_as_help_menu(&mut self) {
+        self.1 = MenuRole::Help;
+    }
+
+    /// Marks this as the application's Window menu, once it's passed to `App::set_menu`. AppKit
+    /// keeps the list of open windows in the Window menu.
+    pub fn set_as_windows_menu(&mut self) {
+        self.1 = MenuRole::Windows;
+    }
+
+    /// Returns a `MenuBuilder` for a menu with the given title, which can be a more readable way
+    /// to put together larger menus (especially ones with submenus).
+    pub fn build(title: &str) -> MenuBuilder {
+        MenuBuilder::new(title)
     }
 
     /// Given a set of `MenuItem`s, merges them into an existing Menu (e.g, for a context menu on a
@@ -71,37 +108,67 @@ impl Menu {
         menu
     }
 
-    /// Convenience method for the bare-minimum NSMenu structure that "just works" for all
-    /// applications, as expected.
-    pub fn standard() -> Vec<Menu> {
-        vec![
-            Menu::new("", vec![
-                MenuItem::Services,
-                MenuItem::Separator,
-                MenuItem::Hide,
-                MenuItem::HideOthers,
-                MenuItem::ShowAll,
-                MenuItem::Separator,
-                MenuItem::Quit,
-            ]),
-            Menu::new("File", vec![MenuItem::CloseWindow]),
-            Menu::new("Edit", vec![
-                MenuItem::Undo,
-                MenuItem::Redo,
-                MenuItem::Separator,
-                MenuItem::Cut,
-                MenuItem::Copy,
-                MenuItem::Paste,
-                MenuItem::Separator,
-                MenuItem::SelectAll,
-            ]),
-            Menu::new("View", vec![MenuItem::EnterFullScreen]),
-            Menu::new("Window", vec![
-                MenuItem::Minimize,
-                MenuItem::Zoom,
-                MenuItem::Separator,
-                MenuItem::new("Bring All to Front"),
-            ]),
-        ]
+    /// Shows this menu at `(x, y)` in `view`'s coordinates - e.g, beneath a custom button. This
+    /// blocks until the menu is dismissed, and returns whether the user picked an item (which will
+    /// have run its action).
+    pub fn popup_at<V: Layout>(&self, view: &V, x: f64, y: f64) -> bool {
+        let point = CGPoint::new(x as CGFloat, y as CGFloat);
+
+        view.get_from_backing_obj(|obj| unsafe {
+            to_bool(msg_send![&*self.0, popUpMenuPositioningItem: nil, atLocation: point, inView: obj])
+        })
+    }
+
+    /// Shows this menu just below `view`, lined up with its leading edge, the way a pull-down
+    /// button does. Returns whether the user picked an item; see `popup_at()`.
+    pub fn popup_below<V: Layout>(&self, view: &V) -> bool {
+        let (height, flipped) = view.get_from_backing_obj(|obj| unsafe {
+            let bounds: CGRect = msg_send![obj, bounds];
+            (bounds.size.height as f64, to_bool(msg_send![obj, isFlipped]))
+        });
+
+        // Leave a small gap, as AppKit does for pull-down buttons.
+        match flipped {
+            true => self.popup_at(view, 0., height + 4.),
+            false => self.popup_at(view, 0., -4.)
+        }
+    }
+
+    /// Searches this menu (and any submenus) for an item with the given identifier, as set via
+    /// `MenuItem::identifier()`. You can use the returned item to, say, toggle its state or
+    /// enabled status.
+    pub fn item_with_identifier(&self, identifier: &str) -> Option<Id<Object, Shared>> {
+        let identifier = NSString::new(identifier);
+        let item = unsafe { find_item_with_identifier(&*self.0 as *const Object as id, &identifier) };
+
+        match item.is_null() {
+            true => None,
+            false => unsafe { Id::retain(item) }
+        }
+    }
+}
+
+/// Recursively walks a menu looking for an item with the given identifier. Returns `nil` if one
+/// couldn't be found.
+pub(crate) unsafe fn find_item_with_identifier(menu: id, identifier: &NSString) -> id {
+    let count: NSInteger = msg_send![menu, numberOfItems];
+
+    for index in 0..count {
+        let item: id = msg_send![menu, itemAtIndex: index];
+        let item_identifier: id = msg_send![item, identifier];
+
+        if item_identifier != nil && to_bool(msg_send![item_identifier, isEqualToString: &**identifier]) {
+            return item;
+        }
+
+        let submenu: id = msg_send![item, submenu];
+        if submenu != nil {
+            let found = find_item_with_identifier(submenu, identifier);
+            if found != nil {
+                return found;
+            }
+        }
     }
+
+    nil
 }
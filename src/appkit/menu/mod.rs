@@ -5,3 +5,12 @@ pub use menu::Menu;
 
 pub mod item;
 pub use item::MenuItem;
+
+pub mod builder;
+pub use builder::MenuBuilder;
+
+mod standard;
+pub use standard::StandardMenuOptions;
+
+mod recent;
+pub use recent::RecentDocuments;
@@ -0,0 +1,169 @@
+//! Support for the "Open Recent" menu, backed by `NSDocumentController`'s list of recent
+//! documents. This works without adopting the rest of the `NSDocument` architecture: you note
+//! files as they're opened, add a `MenuItem::OpenRecent` to your menu, and handle selections in
+//! `AppDelegate::open_recent_document`.
+
+use std::path::Path;
+
+use objc::rc::{Id, Owned};
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, msg_send_id, sel};
+use url::Url;
+
+use crate::foundation::{id, load_or_register_class, nil, to_bool, NSArray, NSInteger, NSString, NSUInteger};
+
+/// A handle for working with the system-wide list of recent documents for your app.
+#[derive(Debug)]
+pub struct RecentDocuments;
+
+impl RecentDocuments {
+    /// Adds a URL to the recent documents list (and, as a result, the Open Recent menu and the
+    /// Dock menu). Call this whenever the user opens or saves a file.
+    pub fn note_url(url: &Url) {
+        let url = NSString::new(url.as_str());
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), URLWithString: &*url];
+            if url != nil {
+                let controller = document_controller();
+                let _: () = msg_send![controller, noteNewRecentDocumentURL: url];
+            }
+        }
+    }
+
+    /// Adds a file path to the recent documents list. See `note_url` for more information.
+    pub fn note_path<P: AsRef<Path>>(path: P) {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), fileURLWithPath: &*path];
+            let controller = document_controller();
+            let _: () = msg_send![controller, noteNewRecentDocumentURL: url];
+        }
+    }
+
+    /// Returns the current list of recent documents, most recent first.
+    pub fn urls() -> Vec<Url> {
+        let urls: id = unsafe { msg_send![document_controller(), recentDocumentURLs] };
+
+        NSArray::retain(urls)
+            .iter()
+            .filter_map(|url| {
+                let uri = NSString::retain(unsafe { msg_send![url, absoluteString] });
+                Url::parse(uri.to_str()).ok()
+            })
+            .collect()
+    }
+
+    /// Clears the recent documents list.
+    pub fn clear() {
+        unsafe {
+            let _: () = msg_send![document_controller(), clearRecentDocuments: nil];
+        }
+    }
+
+    /// Returns the maximum number of recent documents the system will keep track of, based on
+    /// the user's preferences.
+    pub fn maximum_count() -> usize {
+        let count: NSUInteger = unsafe { msg_send![document_controller(), maximumRecentDocumentCount] };
+        count as usize
+    }
+}
+
+/// Returns the shared `NSDocumentController`.
+fn document_controller() -> id {
+    unsafe { msg_send![class!(NSDocumentController), sharedDocumentController] }
+}
+
+/// Builds the "Open Recent" submenu. The menu repopulates itself from the recent documents list
+/// each time it's opened, via a delegate.
+pub(crate) fn make_open_recent_menu() -> Id<Object, Owned> {
+    unsafe {
+        let title = NSString::new("Open Recent");
+        let alloc = msg_send_id![class!(NSMenu), alloc];
+        let menu: Id<Object, Owned> = msg_send_id![alloc, initWithTitle: &*title];
+
+        // NSMenu holds its delegate weakly, and the Open Recent menu lives for as long as the
+        // menu bar does, so we intentionally leak the delegate here.
+        let delegate: Id<Object, Owned> = msg_send_id![register_open_recent_delegate_class(), new];
+        let _: () = msg_send![&*menu, setDelegate: &*delegate];
+        std::mem::forget(delegate);
+
+        menu
+    }
+}
+
+/// Rebuilds the menu items from the recent documents list, right before the menu is shown.
+extern "C" fn menu_needs_update(this: &Object, _: Sel, menu: id) {
+    unsafe {
+        let _: () = msg_send![menu, removeAllItems];
+
+        let urls: id = msg_send![document_controller(), recentDocumentURLs];
+        let urls = NSArray::retain(urls);
+        let file_manager: id = msg_send![class!(NSFileManager), defaultManager];
+
+        for url in urls.iter() {
+            let path: id = msg_send![url, path];
+            let title: id = msg_send![file_manager, displayNameAtPath: path];
+            let empty = NSString::new("");
+
+            let alloc: id = msg_send![class!(NSMenuItem), alloc];
+            let item: id = msg_send![
+                alloc,
+                initWithTitle: title,
+                action: sel!(openRecentDocument:),
+                keyEquivalent: &*empty
+            ];
+            let _: () = msg_send![item, setTarget: this];
+            let _: () = msg_send![item, setRepresentedObject: url];
+
+            let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+            let icon: id = msg_send![workspace, iconForFile: path];
+            let _: () = msg_send![icon, setSize: core_graphics::geometry::CGSize::new(16., 16.)];
+            let _: () = msg_send![item, setImage: icon];
+
+            let _: () = msg_send![menu, addItem: item];
+            let _: () = msg_send![item, release];
+        }
+
+        let count: NSInteger = msg_send![menu, numberOfItems];
+        if count > 0 {
+            let separator: id = msg_send![class!(NSMenuItem), separatorItem];
+            let _: () = msg_send![menu, addItem: separator];
+        }
+
+        let title = NSString::new("Clear Menu");
+        let empty = NSString::new("");
+        let alloc: id = msg_send![class!(NSMenuItem), alloc];
+        let clear: id = msg_send![
+            alloc,
+            initWithTitle: &*title,
+            action: sel!(clearRecentDocuments:),
+            keyEquivalent: &*empty
+        ];
+        let _: () = msg_send![clear, setTarget: document_controller()];
+        let _: () = msg_send![menu, addItem: clear];
+        let _: () = msg_send![clear, release];
+    }
+}
+
+/// Fires when a recent document is selected, and forwards it on to the app delegate.
+extern "C" fn open_recent_document(_: &Object, _: Sel, sender: id) {
+    unsafe {
+        let url: id = msg_send![sender, representedObject];
+        let app: id = msg_send![class!(NSApplication), sharedApplication];
+        let delegate: id = msg_send![app, delegate];
+
+        if delegate != nil && to_bool(msg_send![delegate, respondsToSelector: sel!(cacaoOpenRecentDocument:)]) {
+            let _: () = msg_send![delegate, cacaoOpenRecentDocument: url];
+        }
+    }
+}
+
+/// Injects an `NSMenuDelegate` that populates the Open Recent menu.
+fn register_open_recent_delegate_class() -> &'static Class {
+    load_or_register_class("NSObject", "RSTOpenRecentMenuDelegate", |decl| unsafe {
+        decl.add_method(sel!(menuNeedsUpdate:), menu_needs_update as extern "C" fn(_, _, _));
+        decl.add_method(sel!(openRecentDocument:), open_recent_document as extern "C" fn(_, _, _));
+    })
+}
@@ -0,0 +1,193 @@
+//! Generates the conventional macOS menu bar (App, File, Edit, View, Window and Help, plus
+//! Format for text editing apps), so that apps only need to supply their own items.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::App;
+//! use cacao::appkit::menu::{Menu, MenuItem, StandardMenuOptions};
+//!
+//! let mut options = StandardMenuOptions::default();
+//! options.settings = Some(Box::new(|| println!("Show settings")));
+//! options.file = vec![MenuItem::new("New Note").key("n").action(|| println!("New note"))];
+//! options.open_recent = true;
+//! options.help_url = Some("https://example.com/help".into());
+//!
+//! App::set_menu(Menu::standard("Notes", options));
+//! ```
+
+use std::fmt;
+
+use objc::sel;
+
+use crate::appkit::menu::{Menu, MenuItem};
+use crate::available;
+
+/// Configures the menus generated by `Menu::standard()`. Each `Vec` is appended to the
+/// corresponding menu, in a spot that follows the usual conventions.
+#[derive(Default)]
+pub struct StandardMenuOptions {
+    /// Adds a "Settings..." item (Command-,) to the app menu, calling this when chosen. It's
+    /// titled "Preferences..." before macOS 13, to match the system.
+    pub settings: Option<Box<dyn Fn() + 'static>>,
+
+    /// Items for the app menu, placed after About and Settings.
+    pub app: Vec<MenuItem>,
+
+    /// Items for the File menu, placed at the top (e.g, "New" and "Open...").
+    pub file: Vec<MenuItem>,
+
+    /// Adds an "Open Recent" submenu to the File menu; see `RecentDocuments`.
+    pub open_recent: bool,
+
+    /// Items for the Edit menu, placed after the standard editing items.
+    pub edit: Vec<MenuItem>,
+
+    /// Adds a Format menu, with the standard Font submenu (Show Fonts, Bold, Italic and
+    /// Underline) and Show Colors - for apps with rich text editing.
+    pub format: bool,
+
+    /// Adds a "Toggle Sidebar" item (Option-Command-S) to the View menu.
+    pub toggle_sidebar: bool,
+
+    /// Items for the View menu, placed before "Enter Full Screen".
+    pub view: Vec<MenuItem>,
+
+    /// Items for the Window menu, placed after Minimize and Zoom.
+    pub window: Vec<MenuItem>,
+
+    /// Adds the standard "<App> Help" item, which opens your help book - or this URL, if you
+    /// don't bundle one. See `MenuItem::help()`.
+    pub help_url: Option<String>,
+
+    /// Items for the Help menu. The Help menu is left out if this is empty and `help_url` isn't
+    /// set.
+    pub help: Vec<MenuItem>
+}
+
+impl fmt::Debug for StandardMenuOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StandardMenuOptions")
+            .field("settings", &self.settings.is_some())
+            .field("app", &self.app)
+            .field("file", &self.file)
+            .field("open_recent", &self.open_recent)
+            .field("edit", &self.edit)
+            .field("format", &self.format)
+            .field("toggle_sidebar", &self.toggle_sidebar)
+            .field("view", &self.view)
+            .field("window", &self.window)
+            .field("help_url", &self.help_url)
+            .field("help", &self.help)
+            .finish()
+    }
+}
+
+impl Menu {
+    /// Returns the conventional menu bar for an app named `app_name`, with the items from
+    /// `options` merged in. Pass the result to `App::set_menu()`.
+    ///
+    /// `StandardMenuOptions::default()` gives you the bare-minimum structure that "just works"
+    /// for all applications, as expected.
+    pub fn standard(app_name: &str, options: StandardMenuOptions) -> Vec<Menu> {
+        let mut app = vec![MenuItem::About(app_name.to_string()), MenuItem::Separator];
+
+        if let Some(settings) = options.settings {
+            let title = match available!(macos 13) {
+                true => "Settings…",
+                false => "Preferences…"
+            };
+
+            app.push(MenuItem::new(title).key(",").action(settings));
+        }
+
+        app.extend(options.app);
+        if app.len() > 2 {
+            app.push(MenuItem::Separator);
+        }
+
+        app.extend(vec![
+            MenuItem::Services,
+            MenuItem::Separator,
+            MenuItem::with_selector(format!("Hide {}", app_name), "h", sel!(hide:)),
+            MenuItem::HideOthers,
+            MenuItem::ShowAll,
+            MenuItem::Separator,
+            MenuItem::with_selector(format!("Quit {}", app_name), "q", sel!(terminate:)),
+        ]);
+
+        let mut file = options.file;
+        if options.open_recent {
+            file.push(MenuItem::OpenRecent);
+        }
+        if !file.is_empty() {
+            file.push(MenuItem::Separator);
+        }
+        file.push(MenuItem::CloseWindow);
+
+        let mut edit = vec![
+            MenuItem::Undo,
+            MenuItem::Redo,
+            MenuItem::Separator,
+            MenuItem::Cut,
+            MenuItem::Copy,
+            MenuItem::Paste,
+            MenuItem::Separator,
+            MenuItem::SelectAll,
+        ];
+        if !options.edit.is_empty() {
+            edit.push(MenuItem::Separator);
+            edit.extend(options.edit);
+        }
+
+        let mut view = Vec::new();
+        if options.toggle_sidebar {
+            view.push(MenuItem::ToggleSidebar);
+        }
+        view.extend(options.view);
+        if !view.is_empty() {
+            view.push(MenuItem::Separator);
+        }
+        view.push(MenuItem::EnterFullScreen);
+
+        let mut window = vec![MenuItem::Minimize, MenuItem::Zoom];
+        if !options.window.is_empty() {
+            window.push(MenuItem::Separator);
+            window.extend(options.window);
+        }
+        window.extend(vec![MenuItem::Separator, MenuItem::BringAllToFront]);
+
+        let mut menus = vec![Menu::new(app_name, app), Menu::new("File", file), Menu::new("Edit", edit)];
+
+        if options.format {
+            let font = Menu::new("Font", vec![
+                MenuItem::ShowFonts,
+                MenuItem::Separator,
+                MenuItem::Bold,
+                MenuItem::Italic,
+                MenuItem::Underline,
+            ]);
+
+            menus.push(Menu::new("Format", vec![
+                MenuItem::new("Font").submenu(font),
+                MenuItem::ShowColors,
+            ]));
+        }
+
+        menus.push(Menu::new("View", view));
+        let mut window = Menu::new("Window", window);
+        window.set_as_windows_menu();
+        menus.push(window);
+
+        let mut help = Vec::new();
+        if let Some(url) = &options.help_url {
+            help.push(MenuItem::help(app_name, url));
+        }
+        help.extend(options.help);
+        if !help.is_empty() {
+            let mut help = Menu::new("Help", help);
+            help.set_as_help_menu();
+            menus.push(help);
+        }
+
+        menus
+    }
+}
@@ -14,6 +14,9 @@ pub use animation::AnimationContext;
 mod app;
 pub use app::*;
 
+mod color_panel;
+pub use color_panel::ColorPanel;
+
 mod cursor;
 pub use cursor::{Cursor, CursorType};
 
@@ -23,10 +26,33 @@ pub use enums::FocusRingType;
 mod event;
 pub use event::*;
 
+mod font_panel;
+pub use font_panel::FontPanel;
+
+#[cfg(feature = "autolayout")]
+pub mod command_palette;
+
+#[cfg(feature = "autolayout")]
+mod task_sheet;
+#[cfg(feature = "autolayout")]
+pub use task_sheet::TaskSheet;
+
 pub mod menu;
 pub mod printing;
+pub mod running_application;
+pub mod scripting;
 pub mod toolbar;
+pub mod touchbar;
 pub mod window;
+pub mod workspace;
 
+#[cfg(not(gnustep))]
+pub mod feedback;
 pub mod haptics;
+pub mod help;
 pub mod segmentedcontrol;
+pub mod spellchecker;
+
+#[cfg(feature = "event-synthesis")]
+#[cfg_attr(docsrs, doc(cfg(feature = "event-synthesis")))]
+pub mod synthesis;
@@ -1,6 +1,6 @@
 //! Enums used through the general printing flow.
 
-use crate::foundation::NSUInteger;
+use crate::foundation::{NSInteger, NSUInteger};
 
 /// Used for handling printing files. You return this in relevant `AppController` methods.
 #[derive(Copy, Clone, Debug)]
@@ -30,3 +30,45 @@ impl From<PrintResponse> for NSUInteger {
         }
     }
 }
+
+/// The orientation of the paper.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PrintOrientation {
+    /// Taller than it is wide.
+    Portrait,
+
+    /// Wider than it is tall.
+    Landscape
+}
+
+impl From<PrintOrientation> for NSInteger {
+    fn from(orientation: PrintOrientation) -> NSInteger {
+        match orientation {
+            PrintOrientation::Portrait => 0,
+            PrintOrientation::Landscape => 1
+        }
+    }
+}
+
+/// How content that doesn't fit on a page, along one axis, is split up.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PaginationMode {
+    /// Split the content across as many pages as it needs.
+    Automatic,
+
+    /// Scale the content down to fit on one page.
+    Fit,
+
+    /// Cut off whatever doesn't fit on one page.
+    Clip
+}
+
+impl From<PaginationMode> for NSInteger {
+    fn from(mode: PaginationMode) -> NSInteger {
+        match mode {
+            PaginationMode::Automatic => 0,
+            PaginationMode::Fit => 1,
+            PaginationMode::Clip => 2
+        }
+    }
+}
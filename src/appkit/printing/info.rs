@@ -0,0 +1,90 @@
+//! Wraps `NSPrintInfo`, which describes how a print job lays out pages: paper orientation,
+//! margins, pagination and so on.
+
+use core_graphics::base::CGFloat;
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::appkit::printing::enums::{PaginationMode, PrintOrientation};
+use crate::foundation::{id, NSInteger, NO, YES};
+
+/// Describes how a print job lays out its pages. Changes only apply to this instance; the shared
+/// one (i.e, the user's page setup) is left alone unless you modify it via `PrintInfo::shared()`.
+#[derive(Clone, Debug)]
+pub struct PrintInfo(pub Id<Object, Shared>);
+
+impl PrintInfo {
+    /// Returns the app's shared print info, which reflects the user's choices in Page Setup.
+    pub fn shared() -> Self {
+        PrintInfo(unsafe { msg_send_id![class!(NSPrintInfo), sharedPrintInfo] })
+    }
+
+    /// Sets whether pages are printed in portrait or landscape.
+    pub fn set_orientation(&self, orientation: PrintOrientation) {
+        let orientation: NSInteger = orientation.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setOrientation: orientation];
+        }
+    }
+
+    /// Sets the page margins, in points.
+    pub fn set_margins(&self, top: f64, left: f64, bottom: f64, right: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setTopMargin: top as CGFloat];
+            let _: () = msg_send![&*self.0, setLeftMargin: left as CGFloat];
+            let _: () = msg_send![&*self.0, setBottomMargin: bottom as CGFloat];
+            let _: () = msg_send![&*self.0, setRightMargin: right as CGFloat];
+        }
+    }
+
+    /// Sets how content wider than a page is handled. For web content, `PaginationMode::Fit`
+    /// keeps pages from being cut off on the right.
+    pub fn set_horizontal_pagination(&self, mode: PaginationMode) {
+        let mode: NSInteger = mode.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setHorizontalPagination: mode];
+        }
+    }
+
+    /// Sets how content taller than a page is handled.
+    pub fn set_vertical_pagination(&self, mode: PaginationMode) {
+        let mode: NSInteger = mode.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setVerticalPagination: mode];
+        }
+    }
+
+    /// Sets whether content narrower than the page is centered horizontally.
+    pub fn set_horizontally_centered(&self, centered: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setHorizontallyCentered: match centered {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether content shorter than the page is centered vertically.
+    pub fn set_vertically_centered(&self, centered: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setVerticallyCentered: match centered {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+impl Default for PrintInfo {
+    /// Returns a copy of the shared print info, to customize for a single job.
+    fn default() -> Self {
+        PrintInfo(unsafe {
+            let shared: id = msg_send![class!(NSPrintInfo), sharedPrintInfo];
+            msg_send_id![shared, copy]
+        })
+    }
+}
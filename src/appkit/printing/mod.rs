@@ -2,7 +2,13 @@
 //! itself).
 
 pub mod enums;
-pub use enums::PrintResponse;
+pub use enums::{PaginationMode, PrintOrientation, PrintResponse};
+
+pub mod info;
+pub use info::PrintInfo;
+
+pub mod pages;
+pub use pages::{PageDelegate, PrintOperation};
 
 pub mod settings;
 pub use settings::PrintSettings;
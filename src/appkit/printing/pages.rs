@@ -0,0 +1,393 @@
+//! Printing (and PDF export) of content that you lay out into pages yourself - reports, lists,
+//! tables and so on - rather than a view that's already on screen.
+//!
+//! Implement `PageDelegate` to say how many pages there are and draw each one (plus optional
+//! headers and footers), then run a `PrintOperation` with it:
+//!
+//! ```rust,no_run
+//! use cacao::appkit::printing::{PageDelegate, PrintOperation};
+//! use core_graphics::context::CGContextRef;
+//! use core_graphics::geometry::{CGRect, CGSize};
+//!
+//! struct Report {
+//!     rows: Vec<String>
+//! }
+//!
+//! const ROW_HEIGHT: f64 = 20.;
+//!
+//! impl PageDelegate for Report {
+//!     fn page_count(&self, page_size: CGSize) -> usize {
+//!         let rows_per_page = (page_size.height / ROW_HEIGHT).floor().max(1.) as usize;
+//!         (self.rows.len() + rows_per_page - 1) / rows_per_page
+//!     }
+//!
+//!     fn draw_page(&self, page: usize, bounds: CGRect, context: &CGContextRef) {
+//!         // Draw the rows that belong on `page` (zero-based) within `bounds`.
+//!     }
+//! }
+//!
+//! let operation = PrintOperation::new(Report { rows: vec![] });
+//! operation.set_job_title("Quarterly Report");
+//! operation.run();
+//! ```
+
+use std::ffi::c_void;
+use std::fmt;
+use std::path::Path;
+
+use core_graphics::base::CGFloat;
+use core_graphics::context::{CGContext, CGContextRef};
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+
+use objc::rc::{Id, Owned, Shared};
+use objc::runtime::{Bool, Class, Object, Sel};
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::appkit::window::Window;
+use crate::foundation::{id, load_or_register_class, nil, to_bool, NSInteger, NSString, BOOL, NO, YES};
+use crate::utils::{load, NSRange};
+
+static PAGE_LAYOUT_PTR: &str = "rstPageLayoutPtr";
+
+/// Supplies the pages for a `PrintOperation`.
+///
+/// Every `bounds` handed to you has its origin at `(0, 0)`, with `y` increasing downwards; the
+/// context is clipped to it.
+pub trait PageDelegate {
+    /// Returns how many pages there are, given the size of the area each page's content is drawn
+    /// in (i.e, the paper size, less margins, headers and footers).
+    fn page_count(&self, page_size: CGSize) -> usize;
+
+    /// Draws the content of `page` (zero-based).
+    fn draw_page(&self, page: usize, bounds: CGRect, context: &CGContextRef);
+
+    /// The height reserved at the top of each page for `draw_header()`. Defaults to `0.`.
+    fn header_height(&self) -> CGFloat {
+        0.
+    }
+
+    /// Draws the header for `page` (zero-based) of `page_count` - e.g, a title.
+    fn draw_header(&self, _page: usize, _page_count: usize, _bounds: CGRect, _context: &CGContextRef) {}
+
+    /// The height reserved at the bottom of each page for `draw_footer()`. Defaults to `0.`.
+    fn footer_height(&self) -> CGFloat {
+        0.
+    }
+
+    /// Draws the footer for `page` (zero-based) of `page_count` - e.g, "Page 2 of 5".
+    fn draw_footer(&self, _page: usize, _page_count: usize, _bounds: CGRect, _context: &CGContextRef) {}
+}
+
+/// The delegate, plus the page geometry it was laid out for. The backing view reads this.
+struct PageLayout {
+    delegate: Box<dyn PageDelegate>,
+    page_size: CGSize,
+    page_count: usize
+}
+
+impl fmt::Debug for PageLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PageLayout")
+            .field("page_size", &self.page_size)
+            .field("page_count", &self.page_count)
+            .finish()
+    }
+}
+
+impl PageLayout {
+    /// The rect that `page` (zero-based) occupies in the backing view, which stacks pages
+    /// vertically.
+    fn page_rect(&self, page: usize) -> CGRect {
+        CGRect::new(&CGPoint::new(0., self.page_size.height * page as CGFloat), &self.page_size)
+    }
+}
+
+/// Wraps `NSPrintOperation`, printing (or exporting) the pages supplied by a `PageDelegate`.
+///
+/// The delegate is owned by the view that draws the pages, which AppKit keeps alive for as long
+/// as the operation runs - so it's fine to drop this after `run_modal_for_window()` returns.
+#[derive(Debug)]
+pub struct PrintOperation {
+    /// The underlying `NSPrintOperation`.
+    pub objc: Id<Object, Shared>,
+
+    _view: Option<Id<Object, Shared>>,
+    page_count: usize
+}
+
+impl PrintOperation {
+    /// Creates a print operation for `delegate`, laid out for the shared print info (i.e, the
+    /// user's current page setup).
+    pub fn new<T: PageDelegate + 'static>(delegate: T) -> Self {
+        unsafe {
+            let print_info: id = msg_send![class!(NSPrintInfo), sharedPrintInfo];
+            let (view, page_count) = page_view(Box::new(delegate), print_info);
+            let objc = msg_send_id![class!(NSPrintOperation), printOperationWithView: &*view, printInfo: print_info];
+
+            PrintOperation {
+                objc,
+                _view: Some(view),
+                page_count
+            }
+        }
+    }
+
+    /// Creates an operation that writes `delegate`'s pages to a PDF at `path`, without showing any
+    /// panels. Call `run()` to perform it.
+    pub fn export_pdf<T: PageDelegate + 'static, P: AsRef<Path>>(delegate: T, path: P) -> Self {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+
+        unsafe {
+            let print_info: id = msg_send![class!(NSPrintInfo), sharedPrintInfo];
+            let (view, page_count) = page_view(Box::new(delegate), print_info);
+            let bounds: CGRect = msg_send![&*view, bounds];
+
+            let objc = msg_send_id![
+                class!(NSPrintOperation),
+                PDFOperationWithView: &*view,
+                insideRect: bounds,
+                toPath: &*path,
+                printInfo: print_info
+            ];
+
+            PrintOperation {
+                objc,
+                _view: Some(view),
+                page_count
+            }
+        }
+    }
+
+    /// Wraps an `NSPrintOperation` for content that lays itself out (e.g, a `WebView`).
+    pub(crate) fn with_objc(objc: Id<Object, Shared>) -> Self {
+        PrintOperation {
+            objc,
+            _view: None,
+            page_count: 0
+        }
+    }
+
+    /// Returns how many pages the delegate laid out. This is `0` for operations whose content
+    /// lays itself out, such as `WebView::print()`.
+    pub fn page_count(&self) -> usize {
+        self.page_count
+    }
+
+    /// Sets the title of the job, as shown in the print queue (and used as the default name when
+    /// saving as PDF).
+    pub fn set_job_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setJobTitle: &*title];
+        }
+    }
+
+    /// Sets whether the print panel is shown. Defaults to `true` for printing.
+    pub fn set_shows_print_panel(&self, shows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setShowsPrintPanel: match shows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the progress panel is shown while the pages are generated.
+    pub fn set_shows_progress_panel(&self, shows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setShowsProgressPanel: match shows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Runs the operation, blocking until it's done. Returns whether it succeeded (`false` if it
+    /// failed, or the user cancelled).
+    pub fn run(&self) -> bool {
+        let result: BOOL = unsafe { msg_send![&*self.objc, runOperation] };
+        to_bool(result)
+    }
+
+    /// Runs the operation with its panels as sheets on `window`, returning immediately.
+    pub fn run_modal_for_window<W>(&self, window: &Window<W>) {
+        unsafe {
+            let _: () = msg_send![
+                &*self.objc,
+                runOperationModalForWindow: &*window.objc,
+                delegate: nil,
+                didRunSelector: std::ptr::null::<c_void>(),
+                contextInfo: std::ptr::null_mut::<c_void>()
+            ];
+        }
+    }
+}
+
+/// Lays out `delegate` for `print_info`, and creates the view that draws it. The view takes
+/// ownership of the layout (and frees it in `dealloc`); this returns the view and the page count.
+unsafe fn page_view(delegate: Box<dyn PageDelegate>, print_info: id) -> (Id<Object, Shared>, usize) {
+    let paper: CGSize = msg_send![print_info, paperSize];
+    let left: CGFloat = msg_send![print_info, leftMargin];
+    let right: CGFloat = msg_send![print_info, rightMargin];
+    let top: CGFloat = msg_send![print_info, topMargin];
+    let bottom: CGFloat = msg_send![print_info, bottomMargin];
+
+    let page_size = CGSize::new(paper.width - left - right, paper.height - top - bottom);
+    let content_size = CGSize::new(
+        page_size.width,
+        page_size.height - delegate.header_height() - delegate.footer_height()
+    );
+    let page_count = delegate.page_count(content_size);
+
+    let layout = Box::new(PageLayout {
+        delegate,
+        page_size,
+        page_count
+    });
+
+    let frame = CGRect::new(
+        &CGPoint::new(0., 0.),
+        &CGSize::new(page_size.width, page_size.height * page_count.max(1) as CGFloat)
+    );
+
+    let mut view: Id<Object, Owned> = msg_send_id![msg_send_id![register_page_view_class(), alloc], initWithFrame: frame];
+    view.set_ivar(PAGE_LAYOUT_PTR, Box::into_raw(layout) as usize);
+
+    (Id::into_shared(view), page_count)
+}
+
+/// Frees the `PageLayout` (and with it, the delegate) that the view owns.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        let layout_ptr: usize = *this.get_ivar(PAGE_LAYOUT_PTR);
+
+        if layout_ptr != 0 {
+            this.set_ivar(PAGE_LAYOUT_PTR, 0usize);
+            drop(Box::from_raw(layout_ptr as *mut PageLayout));
+        }
+
+        let _: () = msg_send![super(this, class!(NSView)), dealloc];
+    }
+}
+
+/// Pages are laid out top to bottom.
+extern "C" fn is_flipped(_: &Object, _: Sel) -> Bool {
+    Bool::YES
+}
+
+/// Tells AppKit how many pages there are, so it asks for each one via `rectForPage:`.
+extern "C" fn knows_page_range(this: &Object, _: Sel, range: *mut c_void) -> Bool {
+    let layout = load::<PageLayout>(this, PAGE_LAYOUT_PTR);
+
+    unsafe {
+        *(range as *mut NSRange) = NSRange {
+            location: 1,
+            length: layout.page_count as _
+        };
+    }
+
+    Bool::YES
+}
+
+/// Returns the rect for a (one-based) page number.
+extern "C" fn rect_for_page(this: &Object, _: Sel, page: NSInteger) -> CGRect {
+    let layout = load::<PageLayout>(this, PAGE_LAYOUT_PTR);
+    layout.page_rect((page.max(1) - 1) as usize)
+}
+
+/// Draws whichever pages intersect `dirty`, each split into header, content and footer.
+extern "C" fn draw_rect(this: &Object, _: Sel, dirty: CGRect) {
+    let layout = load::<PageLayout>(this, PAGE_LAYOUT_PTR);
+    let delegate = &layout.delegate;
+    let header_height = delegate.header_height();
+    let footer_height = delegate.footer_height();
+
+    let context = unsafe {
+        let current_context: id = msg_send![class!(NSGraphicsContext), currentContext];
+        let context_ptr: core_graphics::sys::CGContextRef = msg_send![current_context, CGContext];
+        CGContext::from_existing_context_ptr(context_ptr)
+    };
+
+    for page in 0..layout.page_count {
+        let page_rect = layout.page_rect(page);
+
+        if !page_rect.intersects(&dirty) {
+            continue;
+        }
+
+        let width = page_rect.size.width;
+        let content_height = page_rect.size.height - header_height - footer_height;
+        let origin = page_rect.origin.y;
+
+        let sections: [(CGFloat, CGFloat, &dyn Fn(CGRect)); 3] = [
+            (origin, header_height, &|bounds| {
+                delegate.draw_header(page, layout.page_count, bounds, &context)
+            }),
+            (origin + header_height, content_height, &|bounds| {
+                delegate.draw_page(page, bounds, &context)
+            }),
+            (origin + header_height + content_height, footer_height, &|bounds| {
+                delegate.draw_footer(page, layout.page_count, bounds, &context)
+            })
+        ];
+
+        for (y, height, draw) in sections.iter() {
+            if *height <= 0. {
+                continue;
+            }
+
+            let bounds = CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(width, *height));
+
+            context.save();
+            context.translate(0., *y);
+            context.clip_to_rect(bounds);
+            draw(bounds);
+            context.restore();
+        }
+    }
+}
+
+/// Injects an `NSView` subclass that draws the pages of a `PageLayout`.
+fn register_page_view_class() -> &'static Class {
+    load_or_register_class("NSView", "RSTPrintPagesView", |decl| unsafe {
+        decl.add_ivar::<usize>(PAGE_LAYOUT_PTR);
+
+        decl.add_method(sel!(isFlipped), is_flipped as extern "C" fn(_, _) -> _);
+        decl.add_method(sel!(knowsPageRange:), knows_page_range as extern "C" fn(_, _, _) -> _);
+        decl.add_method(sel!(rectForPage:), rect_for_page as extern "C" fn(_, _, _) -> _);
+        decl.add_method(sel!(drawRect:), draw_rect as extern "C" fn(_, _, _));
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core_graphics::context::CGContextRef;
+    use core_graphics::geometry::{CGRect, CGSize};
+
+    use super::{PageDelegate, PageLayout};
+
+    struct Blank;
+
+    impl PageDelegate for Blank {
+        fn page_count(&self, _page_size: CGSize) -> usize {
+            3
+        }
+
+        fn draw_page(&self, _page: usize, _bounds: CGRect, _context: &CGContextRef) {}
+    }
+
+    #[test]
+    fn pages_stack_vertically() {
+        let layout = PageLayout {
+            delegate: Box::new(Blank),
+            page_size: CGSize::new(500., 700.),
+            page_count: 3
+        };
+
+        let rect = layout.page_rect(2);
+        assert_eq!(rect.origin.y, 1400.);
+        assert_eq!(rect.size.height, 700.);
+    }
+}
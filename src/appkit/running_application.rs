@@ -0,0 +1,373 @@
+//! Wraps `NSRunningApplication`, for inspecting and controlling apps that are running - including
+//! this one - and `NSWorkspace` notifications for apps launching and terminating.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::running_application::{RunningApplication, WorkspaceApplicationEvent};
+//!
+//! // Keep these around for as long as you want the callbacks.
+//! let launches = RunningApplication::observe_workspace(WorkspaceApplicationEvent::Launched, |app| {
+//!     println!("Launched: {:?}", app.localized_name());
+//! });
+//!
+//! if let Some(finder) = RunningApplication::with_bundle_identifier("com.apple.finder").first() {
+//!     let termination = finder.observe_terminated(|terminated| {
+//!         println!("Finder terminated: {}", terminated);
+//!     });
+//! }
+//! ```
+
+use std::ffi::c_void;
+use std::fmt;
+
+use block::ConcreteBlock;
+
+use objc::rc::{Id, Owned, Shared};
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::appkit::ActivationPolicy;
+use crate::foundation::{id, load_or_register_class, nil, to_bool, NSArray, NSInteger, NSNumber, NSString, NSUInteger};
+use crate::image::Image;
+use crate::utils::load;
+
+static OBSERVER_HANDLER_PTR: &str = "rstRunningApplicationHandlerPtr";
+
+/// Options for `RunningApplication::activate()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ActivationOption {
+    /// Brings all of the app's windows forward, rather than just its frontmost one.
+    AllWindows,
+
+    /// Activates the app even if another app is currently active (which otherwise wins).
+    IgnoringOtherApps
+}
+
+impl From<&ActivationOption> for NSUInteger {
+    fn from(option: &ActivationOption) -> Self {
+        match option {
+            ActivationOption::AllWindows => 1 << 0,
+            ActivationOption::IgnoringOtherApps => 1 << 1
+        }
+    }
+}
+
+/// The app lifecycle notifications posted by `NSWorkspace`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WorkspaceApplicationEvent {
+    /// An app finished launching.
+    Launched,
+
+    /// An app terminated.
+    Terminated,
+
+    /// An app became active.
+    Activated,
+
+    /// An app stopped being active.
+    Deactivated,
+
+    /// An app was hidden.
+    Hidden,
+
+    /// An app was unhidden.
+    Unhidden
+}
+
+impl WorkspaceApplicationEvent {
+    /// The notification name for this event.
+    fn name(&self) -> &'static str {
+        match self {
+            WorkspaceApplicationEvent::Launched => "NSWorkspaceDidLaunchApplicationNotification",
+            WorkspaceApplicationEvent::Terminated => "NSWorkspaceDidTerminateApplicationNotification",
+            WorkspaceApplicationEvent::Activated => "NSWorkspaceDidActivateApplicationNotification",
+            WorkspaceApplicationEvent::Deactivated => "NSWorkspaceDidDeactivateApplicationNotification",
+            WorkspaceApplicationEvent::Hidden => "NSWorkspaceDidHideApplicationNotification",
+            WorkspaceApplicationEvent::Unhidden => "NSWorkspaceDidUnhideApplicationNotification"
+        }
+    }
+}
+
+/// Wraps `NSRunningApplication`.
+#[derive(Clone, Debug)]
+pub struct RunningApplication(pub Id<Object, Shared>);
+
+impl RunningApplication {
+    /// Wraps (and retains) an `NSRunningApplication` vended by the system.
+    pub fn retain(app: id) -> Self {
+        RunningApplication(unsafe { Id::retain(app).unwrap() })
+    }
+
+    /// Returns this app.
+    pub fn current() -> Self {
+        RunningApplication(unsafe { msg_send_id![class!(NSRunningApplication), currentApplication] })
+    }
+
+    /// Returns the app with the given process identifier, if there is one.
+    pub fn with_process_identifier(pid: i32) -> Option<Self> {
+        let app: id = unsafe { msg_send![class!(NSRunningApplication), runningApplicationWithProcessIdentifier: pid] };
+
+        match app == nil {
+            true => None,
+            false => Some(RunningApplication::retain(app))
+        }
+    }
+
+    /// Returns every running instance of the app with the given bundle identifier.
+    pub fn with_bundle_identifier(bundle_identifier: &str) -> Vec<Self> {
+        let bundle_identifier = NSString::new(bundle_identifier);
+
+        let apps = NSArray::retain(unsafe {
+            msg_send![
+                class!(NSRunningApplication),
+                runningApplicationsWithBundleIdentifier: &*bundle_identifier
+            ]
+        });
+
+        apps.iter().map(RunningApplication::retain).collect()
+    }
+
+    /// The process identifier of the app.
+    pub fn process_identifier(&self) -> i32 {
+        unsafe { msg_send![&*self.0, processIdentifier] }
+    }
+
+    /// The bundle identifier of the app, if it has one.
+    pub fn bundle_identifier(&self) -> Option<String> {
+        let identifier: id = unsafe { msg_send![&*self.0, bundleIdentifier] };
+
+        match identifier == nil {
+            true => None,
+            false => Some(NSString::retain(identifier).to_string())
+        }
+    }
+
+    /// The localized name of the app, if it has one.
+    pub fn localized_name(&self) -> Option<String> {
+        let name: id = unsafe { msg_send![&*self.0, localizedName] };
+
+        match name == nil {
+            true => None,
+            false => Some(NSString::retain(name).to_string())
+        }
+    }
+
+    /// The app's icon, if it has one.
+    pub fn icon(&self) -> Option<Image> {
+        let icon: id = unsafe { msg_send![&*self.0, icon] };
+
+        match icon == nil {
+            true => None,
+            false => Some(Image::with(icon))
+        }
+    }
+
+    /// The app's activation policy (i.e, whether it shows in the Dock).
+    pub fn activation_policy(&self) -> ActivationPolicy {
+        let policy: NSInteger = unsafe { msg_send![&*self.0, activationPolicy] };
+        policy.into()
+    }
+
+    /// Whether the app is the frontmost app.
+    pub fn is_active(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, isActive] })
+    }
+
+    /// Whether the app is hidden.
+    pub fn is_hidden(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, isHidden] })
+    }
+
+    /// Whether the app has finished launching.
+    pub fn is_finished_launching(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, isFinishedLaunching] })
+    }
+
+    /// Whether the app has terminated.
+    pub fn is_terminated(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, isTerminated] })
+    }
+
+    /// Whether the app owns the menu bar (which isn't always the active app).
+    pub fn owns_menu_bar(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, ownsMenuBar] })
+    }
+
+    /// Activates the app. Returns `false` if it couldn't be (e.g, it's terminated).
+    pub fn activate(&self, options: &[ActivationOption]) -> bool {
+        let options: NSUInteger = options.iter().fold(0, |mask, option| mask | NSUInteger::from(option));
+        to_bool(unsafe { msg_send![&*self.0, activateWithOptions: options] })
+    }
+
+    /// Hides the app. Returns whether the request was sent.
+    pub fn hide(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, hide] })
+    }
+
+    /// Unhides the app. Returns whether the request was sent.
+    pub fn unhide(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, unhide] })
+    }
+
+    /// Asks the app to quit, as choosing Quit from its menu would (so it may ask to save changes,
+    /// or refuse). Returns whether the request was sent.
+    pub fn terminate(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, terminate] })
+    }
+
+    /// Kills the app without giving it a chance to clean up. Returns whether the request was sent.
+    pub fn force_terminate(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, forceTerminate] })
+    }
+
+    /// Calls `handler` with the new value whenever the app becomes (or stops being) active.
+    ///
+    /// Observation stops when the returned `ApplicationObserver` is dropped.
+    pub fn observe_active<F: Fn(bool) + 'static>(&self, handler: F) -> ApplicationObserver {
+        ApplicationObserver::new(self, "active", handler)
+    }
+
+    /// Calls `handler` with the new value when the app terminates.
+    ///
+    /// Observation stops when the returned `ApplicationObserver` is dropped.
+    pub fn observe_terminated<F: Fn(bool) + 'static>(&self, handler: F) -> ApplicationObserver {
+        ApplicationObserver::new(self, "terminated", handler)
+    }
+
+    /// Calls `handler` whenever `NSWorkspace` posts `event` for any app, with that app.
+    ///
+    /// The handler is called on the main thread. Observation stops when the returned
+    /// `WorkspaceObserver` is dropped.
+    pub fn observe_workspace<F>(event: WorkspaceApplicationEvent, handler: F) -> WorkspaceObserver
+    where
+        F: Fn(RunningApplication) + 'static
+    {
+        let name = NSString::new(event.name());
+        let key = NSString::new("NSWorkspaceApplicationKey");
+
+        let block = ConcreteBlock::new(move |notification: id| unsafe {
+            let info: id = msg_send![notification, userInfo];
+            let app: id = msg_send![info, objectForKey: &*key];
+
+            if app != nil {
+                handler(RunningApplication::retain(app));
+            }
+        });
+        let block = block.copy();
+
+        WorkspaceObserver(unsafe {
+            let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+            let center: id = msg_send![workspace, notificationCenter];
+            let queue: id = msg_send![class!(NSOperationQueue), mainQueue];
+
+            msg_send_id![
+                center,
+                addObserverForName: &*name,
+                object: nil,
+                queue: queue,
+                usingBlock: &*block
+            ]
+        })
+    }
+}
+
+/// An indirection layer for observation callbacks, for the same reasons as `invoker::Action`.
+struct ObservationHandler(Box<dyn Fn(bool) + 'static>);
+
+impl fmt::Debug for ObservationHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObservationHandler").finish()
+    }
+}
+
+/// A key-value observation of a `RunningApplication` property. Dropping this stops observing.
+#[derive(Debug)]
+pub struct ApplicationObserver {
+    app: Id<Object, Shared>,
+    observer: Id<Object, Shared>,
+    key_path: &'static str,
+    handler: Box<ObservationHandler>
+}
+
+impl ApplicationObserver {
+    fn new<F: Fn(bool) + 'static>(app: &RunningApplication, key_path: &'static str, handler: F) -> Self {
+        let handler = Box::new(ObservationHandler(Box::new(handler)));
+
+        let observer: Id<Object, Shared> = unsafe {
+            let mut observer: Id<Object, Owned> = msg_send_id![register_observer_class(), new];
+
+            let handler_ptr: *const ObservationHandler = &*handler;
+            observer.set_ivar(OBSERVER_HANDLER_PTR, handler_ptr as usize);
+
+            Id::into_shared(observer)
+        };
+
+        let key = NSString::new(key_path);
+
+        unsafe {
+            let _: () = msg_send![
+                &*app.0,
+                addObserver: &*observer,
+                forKeyPath: &*key,
+                options: 1usize,
+                context: std::ptr::null_mut::<c_void>()
+            ];
+        }
+
+        ApplicationObserver {
+            app: app.0.clone(),
+            observer,
+            key_path,
+            handler
+        }
+    }
+}
+
+impl Drop for ApplicationObserver {
+    fn drop(&mut self) {
+        let key = NSString::new(self.key_path);
+
+        unsafe {
+            let _: () = msg_send![&*self.app, removeObserver: &*self.observer, forKeyPath: &*key];
+        }
+    }
+}
+
+/// An `NSWorkspace` notification observer. Dropping this stops observing.
+#[derive(Debug)]
+pub struct WorkspaceObserver(Id<Object, Shared>);
+
+impl Drop for WorkspaceObserver {
+    fn drop(&mut self) {
+        unsafe {
+            let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
+            let center: id = msg_send![workspace, notificationCenter];
+            let _: () = msg_send![center, removeObserver: &*self.0];
+        }
+    }
+}
+
+/// Called when an observed property changes; reads the new value off the application.
+extern "C" fn observe_value(this: &Object, _: Sel, key_path: id, object: id, _change: id, _context: *mut c_void) {
+    let value: id = unsafe { msg_send![object, valueForKeyPath: key_path] };
+
+    let value = match value == nil {
+        true => false,
+        false => NSNumber::retain(value).as_bool()
+    };
+
+    let handler = load::<ObservationHandler>(this, OBSERVER_HANDLER_PTR);
+    (handler.0)(value);
+}
+
+/// Injects an `NSObject` subclass that forwards key-value observations of an
+/// `NSRunningApplication` to a Rust handler.
+fn register_observer_class() -> &'static Class {
+    load_or_register_class("NSObject", "RSTRunningApplicationObserver", |decl| unsafe {
+        decl.add_ivar::<usize>(OBSERVER_HANDLER_PTR);
+
+        decl.add_method(
+            sel!(observeValueForKeyPath:ofObject:change:context:),
+            observe_value as extern "C" fn(_, _, _, _, _, _)
+        );
+    })
+}
@@ -0,0 +1,337 @@
+//! Scriptability: handlers for raw Apple Events, and Rust-implemented AppleScript commands.
+//!
+//! ## Apple Events
+//! `AppleEvents::on()` registers a handler for a specific event class and ID - e.g, the `GURL`
+//! event that's sent when a URL with your scheme is opened, or a custom event that another app
+//! sends you.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::scripting::{four_char_code, AppleEvents};
+//!
+//! AppleEvents::on(four_char_code(b"GURL"), four_char_code(b"GURL"), |event| {
+//!     println!("Asked to open {:?}", event.direct_parameter());
+//!     Ok(None)
+//! });
+//! ```
+//!
+//! ## AppleScript commands
+//! For `tell application "MyApp" to ...` support, your app needs a scripting definition (an
+//! `.sdef` file, referenced by `OSAScriptingDefinition` in your `Info.plist`, along with
+//! `NSAppleScriptEnabled`). Point each command's `cocoa class` at `SCRIPT_COMMAND_CLASS`, and
+//! register a handler for it by name:
+//!
+//! ```xml
+//! <command name="greet" code="MyAppGret">
+//!     <cocoa class="CacaoScriptCommand_NSScriptCommand"/>
+//!     <direct-parameter type="text"/>
+//!     <result type="text"/>
+//! </command>
+//! ```
+//!
+//! ```rust,no_run
+//! use cacao::appkit::scripting::ScriptCommands;
+//!
+//! ScriptCommands::on("greet", |command| {
+//!     let name = command.direct_parameter().unwrap_or_default();
+//!     Ok(Some(format!("Hello, {}!", name)))
+//! });
+//! ```
+//!
+//! Handlers should be registered early (e.g, in `AppDelegate::did_finish_launching`), before
+//! any scripts are sent. Arguments and results are strings; richer object model support (classes,
+//! properties, `every window`) isn't covered here.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, load_or_register_class, load_or_register_class_with_optional_generated_suffix, nil, NSString};
+
+/// The name of the `NSScriptCommand` subclass to use as the `cocoa class` for commands in your
+/// scripting definition.
+pub const SCRIPT_COMMAND_CLASS: &str = "CacaoScriptCommand_NSScriptCommand";
+
+/// The keyword for an event's direct parameter (`'----'`).
+const KEY_DIRECT_OBJECT: u32 = four_char_code(b"----");
+
+/// The keyword for an error message in a reply (`'errs'`).
+const KEY_ERROR_STRING: u32 = four_char_code(b"errs");
+
+/// The keyword for an error number in a reply (`'errn'`).
+const KEY_ERROR_NUMBER: u32 = four_char_code(b"errn");
+
+/// `errAEEventFailed`, the generic "this didn't work" error.
+const ERR_AE_EVENT_FAILED: i32 = -10000;
+
+/// What a handler returns: an optional string result, or an error message to hand back to the
+/// script.
+pub type ScriptResult = Result<Option<String>, String>;
+
+type EventHandler = Rc<dyn Fn(&AppleEvent) -> ScriptResult>;
+type CommandHandler = Rc<dyn Fn(&ScriptCommand) -> ScriptResult>;
+
+thread_local! {
+    /// Apple Events and script commands are handled on the main thread.
+    static EVENT_HANDLERS: RefCell<HashMap<(u32, u32), EventHandler>> = RefCell::new(HashMap::new());
+    static COMMAND_HANDLERS: RefCell<HashMap<String, CommandHandler>> = RefCell::new(HashMap::new());
+
+    /// The object that `NSAppleEventManager` calls into. The manager doesn't retain it, so we do.
+    static EVENT_RECEIVER: Id<Object, Shared> = unsafe {
+        msg_send_id![register_event_receiver_class(), new]
+    };
+}
+
+/// Packs a four character code (e.g, `b"GURL"`) into the `u32` that Apple Events use.
+pub const fn four_char_code(code: &[u8; 4]) -> u32 {
+    ((code[0] as u32) << 24) | ((code[1] as u32) << 16) | ((code[2] as u32) << 8) | (code[3] as u32)
+}
+
+/// Wraps an incoming Apple Event (an `NSAppleEventDescriptor`).
+#[derive(Debug)]
+pub struct AppleEvent(pub Id<Object, Shared>);
+
+impl AppleEvent {
+    /// The event class, e.g `four_char_code(b"GURL")`.
+    pub fn event_class(&self) -> u32 {
+        unsafe { msg_send![&*self.0, eventClass] }
+    }
+
+    /// The event ID, e.g `four_char_code(b"GURL")`.
+    pub fn event_id(&self) -> u32 {
+        unsafe { msg_send![&*self.0, eventID] }
+    }
+
+    /// The direct parameter, as a string - if there is one, and it can be coerced to text.
+    pub fn direct_parameter(&self) -> Option<String> {
+        self.parameter(KEY_DIRECT_OBJECT)
+    }
+
+    /// The parameter for `keyword`, as a string - if there is one, and it can be coerced to text.
+    pub fn parameter(&self, keyword: u32) -> Option<String> {
+        unsafe {
+            let descriptor: id = msg_send![&*self.0, paramDescriptorForKeyword: keyword];
+            string_value(descriptor)
+        }
+    }
+}
+
+/// Registers handlers for Apple Events.
+#[derive(Debug)]
+pub struct AppleEvents;
+
+impl AppleEvents {
+    /// Registers `handler` for events of the given class and ID, replacing any existing handler
+    /// (including AppKit's own, so be careful with core events like `odoc`). A returned string
+    /// is sent back as the reply's direct parameter; an error is sent back as the error message.
+    pub fn on<F>(event_class: u32, event_id: u32, handler: F)
+    where
+        F: Fn(&AppleEvent) -> ScriptResult + 'static
+    {
+        EVENT_HANDLERS.with(|handlers| {
+            handlers.borrow_mut().insert((event_class, event_id), Rc::new(handler));
+        });
+
+        EVENT_RECEIVER.with(|receiver| unsafe {
+            let manager: id = msg_send![class!(NSAppleEventManager), sharedAppleEventManager];
+            let _: () = msg_send![
+                manager,
+                setEventHandler: &**receiver,
+                andSelector: sel!(handleAppleEvent:withReplyEvent:),
+                forEventClass: event_class,
+                andEventID: event_id
+            ];
+        });
+    }
+
+    /// Removes the handler for events of the given class and ID.
+    pub fn remove(event_class: u32, event_id: u32) {
+        EVENT_HANDLERS.with(|handlers| {
+            handlers.borrow_mut().remove(&(event_class, event_id));
+        });
+
+        unsafe {
+            let manager: id = msg_send![class!(NSAppleEventManager), sharedAppleEventManager];
+            let _: () = msg_send![manager, removeEventHandlerForEventClass: event_class, andEventID: event_id];
+        }
+    }
+}
+
+/// Wraps an `NSScriptCommand` that's being executed.
+#[derive(Debug)]
+pub struct ScriptCommand(pub Id<Object, Shared>);
+
+impl ScriptCommand {
+    /// The command's name, as given in the scripting definition.
+    pub fn name(&self) -> String {
+        unsafe {
+            let description: id = msg_send![&*self.0, commandDescription];
+            NSString::retain(msg_send![description, commandName]).to_string()
+        }
+    }
+
+    /// The direct parameter, as a string - if there is one.
+    pub fn direct_parameter(&self) -> Option<String> {
+        unsafe {
+            let value: id = msg_send![&*self.0, directParameter];
+            object_to_string(value)
+        }
+    }
+
+    /// The argument with the given name (as given in the scripting definition), as a string - if
+    /// it was passed.
+    pub fn argument(&self, name: &str) -> Option<String> {
+        let name = NSString::new(name);
+
+        unsafe {
+            let arguments: id = msg_send![&*self.0, evaluatedArguments];
+            let value: id = msg_send![arguments, objectForKey: &*name];
+            object_to_string(value)
+        }
+    }
+}
+
+/// Registers handlers for AppleScript commands.
+#[derive(Debug)]
+pub struct ScriptCommands;
+
+impl ScriptCommands {
+    /// Registers `handler` for the command named `name` in your scripting definition, replacing
+    /// any existing handler. A returned string becomes the command's result; an error is raised
+    /// in the calling script.
+    pub fn on<F>(name: &str, handler: F)
+    where
+        F: Fn(&ScriptCommand) -> ScriptResult + 'static
+    {
+        // Make sure the class exists by the time a script instantiates it.
+        register_script_command_class();
+
+        COMMAND_HANDLERS.with(|handlers| {
+            handlers.borrow_mut().insert(name.to_string(), Rc::new(handler));
+        });
+    }
+}
+
+/// Coerces a descriptor to text, if possible.
+unsafe fn string_value(descriptor: id) -> Option<String> {
+    if descriptor == nil {
+        return None;
+    }
+
+    let value: id = msg_send![descriptor, stringValue];
+    object_to_string(value)
+}
+
+/// Converts a script argument to a string, if it's a string (or can describe itself as one, e.g
+/// numbers).
+unsafe fn object_to_string(value: id) -> Option<String> {
+    if value == nil {
+        return None;
+    }
+
+    match NSString::is(value) {
+        true => Some(NSString::retain(value).to_string()),
+        false => Some(NSString::retain(msg_send![value, description]).to_string())
+    }
+}
+
+/// Dispatches an incoming Apple Event to its handler, and fills in the reply.
+extern "C" fn handle_apple_event(_this: &Object, _: Sel, event: id, reply: id) {
+    let event = AppleEvent(unsafe { Id::retain(event).unwrap() });
+    let key = (event.event_class(), event.event_id());
+    let handler = EVENT_HANDLERS.with(|handlers| handlers.borrow().get(&key).cloned());
+
+    let result = match handler {
+        Some(handler) => handler(&event),
+        None => return
+    };
+
+    unsafe {
+        match result {
+            Ok(Some(value)) => {
+                let value = NSString::new(&value);
+                let descriptor: id = msg_send![class!(NSAppleEventDescriptor), descriptorWithString: &*value];
+                let _: () = msg_send![reply, setParamDescriptor: descriptor, forKeyword: KEY_DIRECT_OBJECT];
+            },
+
+            Ok(None) => {},
+
+            Err(message) => {
+                let message = NSString::new(&message);
+                let descriptor: id = msg_send![class!(NSAppleEventDescriptor), descriptorWithString: &*message];
+                let _: () = msg_send![reply, setParamDescriptor: descriptor, forKeyword: KEY_ERROR_STRING];
+
+                let number: id = msg_send![class!(NSAppleEventDescriptor), descriptorWithInt32: ERR_AE_EVENT_FAILED];
+                let _: () = msg_send![reply, setParamDescriptor: number, forKeyword: KEY_ERROR_NUMBER];
+            }
+        }
+    }
+}
+
+/// Runs the handler for a script command. Returns the result object (or `nil`).
+extern "C" fn perform_command(this: &Object, _: Sel) -> id {
+    let command = ScriptCommand(unsafe { Id::retain(this as *const Object as id).unwrap() });
+    let name = command.name();
+    let handler = COMMAND_HANDLERS.with(|handlers| handlers.borrow().get(&name).cloned());
+
+    let result = match handler {
+        Some(handler) => handler(&command),
+        None => Err(format!("The \"{}\" command isn't supported.", name))
+    };
+
+    unsafe {
+        match result {
+            Ok(Some(value)) => {
+                // The `NSString` releases its reference when it drops, so the caller gets its own.
+                let value = NSString::new(&value);
+                let value: id = msg_send![&*value.objc, retain];
+                msg_send![value, autorelease]
+            },
+
+            Ok(None) => nil,
+
+            Err(message) => {
+                let message = NSString::new(&message);
+                // `setScriptErrorNumber:` takes an `int`, not an `NSInteger`.
+                let _: () = msg_send![this, setScriptErrorNumber: ERR_AE_EVENT_FAILED];
+                let _: () = msg_send![this, setScriptErrorString: &*message];
+                nil
+            }
+        }
+    }
+}
+
+/// Registers the object that `NSAppleEventManager` calls into.
+fn register_event_receiver_class() -> &'static Class {
+    load_or_register_class("NSObject", "RSTAppleEventReceiver", |decl| unsafe {
+        decl.add_method(
+            sel!(handleAppleEvent:withReplyEvent:),
+            handle_apple_event as extern "C" fn(_, _, _, _)
+        );
+    })
+}
+
+/// Registers the `NSScriptCommand` subclass that scripting definitions point at. This needs a
+/// predictable name, so it doesn't get the usual random suffix.
+fn register_script_command_class() -> &'static Class {
+    load_or_register_class_with_optional_generated_suffix("NSScriptCommand", "CacaoScriptCommand", false, |decl| unsafe {
+        decl.add_method(
+            sel!(performDefaultImplementation),
+            perform_command as extern "C" fn(_, _) -> _
+        );
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::four_char_code;
+
+    #[test]
+    fn packs_four_char_codes() {
+        assert_eq!(four_char_code(b"GURL"), 0x4755524C);
+        assert_eq!(four_char_code(b"----"), 0x2D2D2D2D);
+    }
+}
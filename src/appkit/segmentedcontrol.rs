@@ -25,7 +25,7 @@ use crate::utils::{load, properties::ObjcProperty};
 #[cfg(feature = "autolayout")]
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 use crate::appkit::FocusRingType;
 
 /// Wraps `NSButton` on appkit, and `UIButton` on iOS and tvOS.
@@ -205,7 +205,7 @@ impl SegmentedControl {
     pub fn set_background_color<C: AsRef<Color>>(&self, color: C) {
         let color: id = color.as_ref().into();
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             let cell: id = msg_send![obj, cell];
             let _: () = msg_send![cell, setBackgroundColor: color];
@@ -236,7 +236,7 @@ impl SegmentedControl {
     ///
     /// On appkit, this is done by way of an `AttributedString` under the hood.
     pub fn set_text_color<C: AsRef<Color>>(&self, color: C) {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         self.objc.with_mut(move |obj| unsafe {
             let text: id = msg_send![obj, attributedTitle];
             let len: isize = msg_send![text, length];
@@ -250,7 +250,7 @@ impl SegmentedControl {
 
     // @TODO: Figure out how to handle oddities like this.
     /// For buttons on appkit, one might need to disable the border. This does that.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn set_bordered(&self, is_bordered: bool) {
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setBordered:match is_bordered {
@@ -272,7 +272,7 @@ impl SegmentedControl {
     /// Sets how the control should draw a focus ring when a user is focused on it.
     ///
     /// This is an appkit-only method.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn set_focus_ring_type(&self, focus_ring_type: FocusRingType) {
         let ring_type: NSUInteger = focus_ring_type.into();
 
@@ -0,0 +1,275 @@
+//! Wraps `NSSpellChecker`, for checking spelling and grammar outside of the standard text views
+//! (e.g, in a custom text view, or when validating form input).
+//!
+//! Each `SpellChecker` represents a "spell document" - words that you ignore are scoped to it, and
+//! forgotten once it's dropped. Learned words, on the other hand, go into the user's dictionary
+//! and persist across apps.
+//!
+//! Ranges are expressed in UTF-16 code units, as that's how `NSString` counts them.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::spellchecker::SpellChecker;
+//!
+//! let checker = SpellChecker::new();
+//! for range in checker.misspelled_ranges("Ths is a tset") {
+//!     println!("Misspelled: {:?}", range);
+//! }
+//! ```
+
+use std::ops::Range;
+
+use objc::{class, msg_send, sel};
+
+use crate::foundation::{id, nil, to_bool, NSArray, NSInteger, NSString, NSUInteger, NO, YES};
+use crate::utils::NSRange;
+
+/// `NSNotFound`, as returned in the `location` of an `NSRange` when nothing was found.
+const NOT_FOUND: NSUInteger = NSInteger::MAX as NSUInteger;
+
+/// A grammar issue found in a piece of text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrammarIssue {
+    /// The range of the offending text.
+    pub range: Range<usize>,
+
+    /// A user-facing description of the issue.
+    pub description: String,
+
+    /// Suggested replacements, if any.
+    pub corrections: Vec<String>
+}
+
+/// A handle for checking spelling and grammar, backed by the shared `NSSpellChecker`.
+#[derive(Debug)]
+pub struct SpellChecker {
+    /// The spell document tag that ignored words are scoped to.
+    pub tag: NSInteger
+}
+
+impl Default for SpellChecker {
+    fn default() -> Self {
+        SpellChecker::new()
+    }
+}
+
+impl SpellChecker {
+    /// Creates a new `SpellChecker`, with its own (empty) set of ignored words.
+    pub fn new() -> Self {
+        let tag: NSInteger = unsafe { msg_send![class!(NSSpellChecker), uniqueSpellDocumentTag] };
+
+        SpellChecker { tag }
+    }
+
+    /// Returns the first misspelled word at or after `start`, if there is one.
+    pub fn check_spelling(&self, text: &str, start: usize) -> Option<Range<usize>> {
+        let text = NSString::new(text);
+
+        let range: NSRange = unsafe {
+            msg_send![
+                shared(),
+                checkSpellingOfString: &*text,
+                startingAt: start as NSInteger,
+                language: nil,
+                wrap: NO,
+                inSpellDocumentWithTag: self.tag,
+                wordCount: std::ptr::null_mut::<NSInteger>()
+            ]
+        };
+
+        match range.location == NOT_FOUND || range.length == 0 {
+            true => None,
+            false => Some(range.into())
+        }
+    }
+
+    /// Returns the ranges of every misspelled word in `text`.
+    pub fn misspelled_ranges(&self, text: &str) -> Vec<Range<usize>> {
+        let mut ranges = vec![];
+        let mut start = 0;
+
+        while let Some(range) = self.check_spelling(text, start) {
+            start = range.end;
+            ranges.push(range);
+        }
+
+        ranges
+    }
+
+    /// Checks the grammar of `text`, returning any issues found in the first sentence (at or after
+    /// `start`) that has them.
+    pub fn check_grammar(&self, text: &str, start: usize) -> Vec<GrammarIssue> {
+        let text = NSString::new(text);
+
+        unsafe {
+            let mut details: id = nil;
+            let _: NSRange = msg_send![
+                shared(),
+                checkGrammarOfString: &*text,
+                startingAt: start as NSInteger,
+                language: nil,
+                wrap: NO,
+                inSpellDocumentWithTag: self.tag,
+                details: &mut details
+            ];
+
+            if details == nil {
+                return vec![];
+            }
+
+            NSArray::retain(details)
+                .iter()
+                .map(|detail| {
+                    let range: id = msg_send![detail, objectForKey: &*NSString::new("NSGrammarRange")];
+                    let range: NSRange = msg_send![range, rangeValue];
+
+                    let description: id = msg_send![detail, objectForKey: &*NSString::new("NSGrammarUserDescription")];
+                    let description = match description == nil {
+                        true => String::new(),
+                        false => NSString::retain(description).to_string()
+                    };
+
+                    let corrections: id = msg_send![detail, objectForKey: &*NSString::new("NSGrammarCorrections")];
+                    let corrections = strings(corrections);
+
+                    GrammarIssue {
+                        range: range.into(),
+                        description,
+                        corrections
+                    }
+                })
+                .collect()
+        }
+    }
+
+    /// Returns suggested replacements for a misspelled word, in order of likelihood.
+    pub fn guesses(&self, word: &str) -> Vec<String> {
+        let length = word.encode_utf16().count();
+        let word = NSString::new(word);
+        let range = NSRange { location: 0, length };
+
+        strings(unsafe {
+            msg_send![
+                shared(),
+                guessesForWordRange: range,
+                inString: &*word,
+                language: nil,
+                inSpellDocumentWithTag: self.tag
+            ]
+        })
+    }
+
+    /// Returns the correction that autocorrect would apply to `word`, if any.
+    pub fn correction(&self, word: &str) -> Option<String> {
+        let length = word.encode_utf16().count();
+        let word = NSString::new(word);
+        let range = NSRange { location: 0, length };
+
+        unsafe {
+            let correction: id = msg_send![
+                shared(),
+                correctionForWordRange: range,
+                inString: &*word,
+                language: nil,
+                inSpellDocumentWithTag: self.tag
+            ];
+
+            match correction == nil {
+                true => None,
+                false => Some(NSString::retain(correction).to_string())
+            }
+        }
+    }
+
+    /// Ignores `word` for the lifetime of this `SpellChecker`.
+    pub fn ignore_word(&self, word: &str) {
+        let word = NSString::new(word);
+
+        unsafe {
+            let _: () = msg_send![shared(), ignoreWord: &*word, inSpellDocumentWithTag: self.tag];
+        }
+    }
+
+    /// Returns the words ignored by this `SpellChecker`.
+    pub fn ignored_words(&self) -> Vec<String> {
+        strings(unsafe { msg_send![shared(), ignoredWordsInSpellDocumentWithTag: self.tag] })
+    }
+
+    /// Adds `word` to the user's dictionary. This is system-wide, and persists.
+    pub fn learn_word(word: &str) {
+        let word = NSString::new(word);
+
+        unsafe {
+            let _: () = msg_send![shared(), learnWord: &*word];
+        }
+    }
+
+    /// Removes `word` from the user's dictionary.
+    pub fn unlearn_word(word: &str) {
+        let word = NSString::new(word);
+
+        unsafe {
+            let _: () = msg_send![shared(), unlearnWord: &*word];
+        }
+    }
+
+    /// Returns whether `word` is in the user's dictionary.
+    pub fn has_learned_word(word: &str) -> bool {
+        let word = NSString::new(word);
+        to_bool(unsafe { msg_send![shared(), hasLearnedWord: &*word] })
+    }
+
+    /// Returns the languages that spell checking is available for, e.g `en` or `fr`.
+    pub fn available_languages() -> Vec<String> {
+        strings(unsafe { msg_send![shared(), availableLanguages] })
+    }
+
+    /// Returns the language currently used for checking.
+    pub fn language() -> String {
+        NSString::retain(unsafe { msg_send![shared(), language] }).to_string()
+    }
+
+    /// Sets the language used for checking. Returns `false` if the language isn't available.
+    ///
+    /// Note that this is shared across the app, and also affects the standard text views.
+    pub fn set_language(language: &str) -> bool {
+        let language = NSString::new(language);
+        to_bool(unsafe { msg_send![shared(), setLanguage: &*language] })
+    }
+
+    /// Sets whether the language is detected automatically from the text being checked, rather
+    /// than always using `language()`.
+    pub fn set_automatically_identifies_languages(identifies: bool) {
+        unsafe {
+            let _: () = msg_send![shared(), setAutomaticallyIdentifiesLanguages: match identifies {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+impl Drop for SpellChecker {
+    /// Closes the spell document, which forgets any ignored words.
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![shared(), closeSpellDocumentWithTag: self.tag];
+        }
+    }
+}
+
+/// Returns the shared `NSSpellChecker`.
+fn shared() -> id {
+    unsafe { msg_send![class!(NSSpellChecker), sharedSpellChecker] }
+}
+
+/// Converts a (possibly nil) `NSArray` of `NSString`s into a `Vec<String>`.
+fn strings(array: id) -> Vec<String> {
+    if array == nil {
+        return vec![];
+    }
+
+    NSArray::retain(array)
+        .iter()
+        .map(|string| NSString::retain(string).to_string())
+        .collect()
+}
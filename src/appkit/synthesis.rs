@@ -0,0 +1,105 @@
+//! Synthesizes keyboard and mouse input via `CGEvent`, for UI automation and tests.
+//!
+//! Posted events go through the system like real input, so they reach whichever app is frontmost -
+//! not necessarily yours. Posting requires the app (or, when run from a terminal, the terminal) to
+//! be trusted for accessibility in System Settings > Privacy & Security > Accessibility.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::synthesis::{click, press_key, MouseButton};
+//! use cacao::appkit::EventModifierFlag;
+//! use core_graphics::geometry::CGPoint;
+//!
+//! // Command-A, then a click.
+//! press_key(0x00, &[EventModifierFlag::Command]).unwrap();
+//! click(CGPoint::new(200., 300.), MouseButton::Left).unwrap();
+//! ```
+//!
+//! Note that `CGEvent` positions are in global display coordinates, with the origin at the _top_
+//! left of the main display - unlike `Event::mouse_location()`, which has it at the bottom left.
+//!
+//! To use this module, you must specify the `event-synthesis` feature flag in your `Cargo.toml`.
+
+use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation, CGEventType, CGMouseButton};
+use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+use core_graphics::geometry::CGPoint;
+
+use crate::appkit::EventModifierFlag;
+use crate::error::{Error, CACAO_ERROR_DOMAIN};
+use crate::foundation::NSUInteger;
+
+/// Mouse buttons that can be synthesized.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MouseButton {
+    /// The primary (left) button.
+    Left,
+
+    /// The secondary (right) button.
+    Right
+}
+
+impl MouseButton {
+    fn events(&self) -> (CGEventType, CGEventType, CGMouseButton) {
+        match self {
+            MouseButton::Left => (CGEventType::LeftMouseDown, CGEventType::LeftMouseUp, CGMouseButton::Left),
+            MouseButton::Right => (CGEventType::RightMouseDown, CGEventType::RightMouseUp, CGMouseButton::Right)
+        }
+    }
+}
+
+/// Creates an event source that posts as if from the hardware.
+fn source() -> Result<CGEventSource, Error> {
+    CGEventSource::new(CGEventSourceStateID::HIDSystemState).map_err(|_| failed("create an event source"))
+}
+
+/// The error returned when CoreGraphics refuses to create an event.
+fn failed(action: &str) -> Error {
+    Error::custom(CACAO_ERROR_DOMAIN, 0, &format!("Could not {}.", action))
+}
+
+/// Converts modifier flags to their `CGEventFlags` equivalents (which share the same bits).
+fn flags(modifiers: &[EventModifierFlag]) -> CGEventFlags {
+    let bits = modifiers
+        .iter()
+        .fold(0 as NSUInteger, |bits, modifier| bits | NSUInteger::from(modifier));
+
+    CGEventFlags::from_bits_truncate(bits as u64)
+}
+
+/// Posts a key down or key up for the (layout independent) virtual `key_code`, with `modifiers`
+/// held.
+pub fn post_key(key_code: u16, key_down: bool, modifiers: &[EventModifierFlag]) -> Result<(), Error> {
+    let event = CGEvent::new_keyboard_event(source()?, key_code, key_down).map_err(|_| failed("create a key event"))?;
+    event.set_flags(flags(modifiers));
+    event.post(CGEventTapLocation::HID);
+    Ok(())
+}
+
+/// Presses and releases the key with the (layout independent) virtual `key_code`, with
+/// `modifiers` held - e.g, `0x00` ("A" on a US keyboard) with `Command` for Select All.
+pub fn press_key(key_code: u16, modifiers: &[EventModifierFlag]) -> Result<(), Error> {
+    post_key(key_code, true, modifiers)?;
+    post_key(key_code, false, modifiers)
+}
+
+/// Moves the mouse cursor to `location`.
+pub fn move_mouse(location: CGPoint) -> Result<(), Error> {
+    let event = CGEvent::new_mouse_event(source()?, CGEventType::MouseMoved, location, CGMouseButton::Left)
+        .map_err(|_| failed("create a mouse event"))?;
+
+    event.post(CGEventTapLocation::HID);
+    Ok(())
+}
+
+/// Clicks `button` at `location`.
+pub fn click(location: CGPoint, button: MouseButton) -> Result<(), Error> {
+    let (down, up, cg_button) = button.events();
+
+    for kind in [down, up].iter() {
+        let event =
+            CGEvent::new_mouse_event(source()?, *kind, location, cg_button).map_err(|_| failed("create a mouse event"))?;
+
+        event.post(CGEventTapLocation::HID);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,294 @@
+//! A helper for the very common case of running a long task while showing a modal sheet with a
+//! progress bar and a cancel button.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::TaskSheet;
+//! use cacao::appkit::window::Window;
+//! use cacao::progress::Progress;
+//!
+//! let window = Window::default();
+//! let progress = Progress::new(100);
+//! let sheet = TaskSheet::new("Importing photos...", progress.clone(), || {
+//!     println!("Cancelled!");
+//! });
+//!
+//! sheet.begin(&window);
+//!
+//! // Elsewhere, on a background thread, update `progress` as work completes. The sheet
+//! // dismisses itself once the progress is finished or cancelled.
+//! ```
+
+use std::ffi::c_void;
+use std::fmt;
+
+use objc::rc::{Id, Owned};
+use objc::runtime::{Class, Object, Sel};
+use objc::{msg_send, msg_send_id, sel};
+
+use crate::appkit::window::{Window, WindowConfig, WindowStyle};
+use crate::button::Button;
+use crate::foundation::{id, load_or_register_class, nil, to_bool, NSString, BOOL, NO};
+use crate::layout::{Layout, LayoutConstraint};
+use crate::progress::{Progress, ProgressIndicator};
+use crate::text::Label;
+use crate::utils::load;
+use crate::view::View;
+
+static TASK_SHEET_HANDLER_PTR: &str = "rstTaskSheetHandlerPtr";
+static TASK_SHEET_WINDOW: &str = "rstTaskSheetWindow";
+static TASK_SHEET_INDICATOR: &str = "rstTaskSheetIndicator";
+static TASK_SHEET_PROGRESS: &str = "rstTaskSheetProgress";
+
+/// The key paths on `NSProgress` that we observe.
+const OBSERVED_KEY_PATHS: [&str; 2] = ["fractionCompleted", "cancelled"];
+
+/// An indirection layer for the cancellation callback, for the same reasons as `invoker::Action`.
+struct CancelHandler(Box<dyn Fn() + 'static>);
+
+impl fmt::Debug for CancelHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancelHandler").finish()
+    }
+}
+
+/// A sheet that displays a title, a progress bar bound to a `Progress`, and a cancel button.
+///
+/// The progress bar is updated via key-value observation on the `Progress`, so you can update it
+/// from any thread. The sheet dismisses itself once the `Progress` is finished or cancelled.
+///
+/// You need to retain this for as long as the sheet is shown; dropping it ends the sheet.
+#[derive(Debug)]
+pub struct TaskSheet {
+    /// The window that's presented as a sheet.
+    pub window: Window,
+
+    /// The `Progress` this sheet is observing.
+    pub progress: Progress,
+
+    /// The content view of the sheet.
+    pub content: View,
+
+    /// The title label.
+    pub title: Label,
+
+    /// The progress bar.
+    pub indicator: ProgressIndicator,
+
+    /// The cancel button.
+    pub cancel_button: Button,
+
+    observer: Id<Object, Owned>,
+    handler: Box<CancelHandler>
+}
+
+impl TaskSheet {
+    /// Creates a new `TaskSheet`. `on_cancel` is called if the user clicks the cancel button, after
+    /// the `Progress` has been cancelled.
+    pub fn new<F: Fn() + 'static>(title: &str, progress: Progress, on_cancel: F) -> Self {
+        let mut config = WindowConfig::default();
+        config.set_styles(&[WindowStyle::Titled]);
+        config.set_initial_dimensions(0., 0., 380., 110.);
+
+        let window = Window::new(config);
+        let content = View::new();
+
+        let label = Label::new();
+        label.set_text(title);
+
+        let indicator = ProgressIndicator::new();
+        indicator.set_indeterminate(false);
+
+        let cancel_button = Button::new("Cancel");
+
+        content.add_subview(&label);
+        content.add_subview(&indicator);
+        content.add_subview(&cancel_button);
+        window.set_content_view(&content);
+
+        LayoutConstraint::activate(&[
+            label.top.constraint_equal_to(&content.top).offset(20.),
+            label.leading.constraint_equal_to(&content.leading).offset(20.),
+            label.trailing.constraint_equal_to(&content.trailing).offset(-20.),
+            indicator.top.constraint_equal_to(&label.bottom).offset(12.),
+            indicator.leading.constraint_equal_to(&content.leading).offset(20.),
+            indicator.trailing.constraint_equal_to(&content.trailing).offset(-20.),
+            cancel_button.top.constraint_equal_to(&indicator.bottom).offset(12.),
+            cancel_button.trailing.constraint_equal_to(&content.trailing).offset(-20.),
+            cancel_button.bottom.constraint_equal_to(&content.bottom).offset(-20.)
+        ]);
+
+        let handler = Box::new(CancelHandler(Box::new(on_cancel)));
+
+        let observer: Id<Object, Owned> = unsafe {
+            let mut observer: Id<Object, Owned> = msg_send_id![register_task_sheet_observer_class(), new];
+
+            let handler_ptr: *const CancelHandler = &*handler;
+            observer.set_ivar(TASK_SHEET_HANDLER_PTR, handler_ptr as usize);
+            observer.set_ivar::<id>(TASK_SHEET_WINDOW, &*window.objc as *const Object as id);
+            observer.set_ivar::<id>(TASK_SHEET_PROGRESS, &*progress.objc as *const Object as id);
+            observer.set_ivar::<id>(TASK_SHEET_INDICATOR, indicator.objc.get(|obj| obj as *const Object as id));
+
+            observer
+        };
+
+        cancel_button.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setTarget: &*observer];
+            let _: () = msg_send![obj, setAction: sel!(cancel:)];
+        });
+
+        for key_path in OBSERVED_KEY_PATHS.iter() {
+            let key_path = NSString::new(key_path);
+
+            unsafe {
+                let _: () = msg_send![
+                    &*progress.objc,
+                    addObserver: &*observer,
+                    forKeyPath: &*key_path,
+                    options: 1usize,
+                    context: std::ptr::null_mut::<c_void>()
+                ];
+            }
+        }
+
+        TaskSheet {
+            window,
+            progress,
+            content,
+            title: label,
+            indicator,
+            cancel_button,
+            observer,
+            handler
+        }
+    }
+
+    /// Presents this as a sheet on the given window.
+    pub fn begin<W>(&self, parent: &Window<W>) {
+        unsafe {
+            let _: () = msg_send![&*self.observer, update];
+            let _: () = msg_send![&*parent.objc, beginSheet: &*self.window.objc, completionHandler: nil];
+        }
+    }
+
+    /// Dismisses the sheet, if it's currently being shown. You generally don't need to call this,
+    /// as the sheet dismisses itself when the `Progress` finishes or is cancelled.
+    pub fn end(&self) {
+        unsafe {
+            dismiss(&self.observer);
+        }
+    }
+}
+
+impl Drop for TaskSheet {
+    /// Removes our key-value observers and ensures the sheet is dismissed, as the observer's
+    /// pointers are only valid for as long as this struct exists.
+    ///
+    /// An `update` may already be queued on the main thread (and the observer is retained until
+    /// it runs), so the pointers are cleared too; the callbacks bail out once they're gone.
+    fn drop(&mut self) {
+        for key_path in OBSERVED_KEY_PATHS.iter() {
+            let key_path = NSString::new(key_path);
+
+            unsafe {
+                let _: () = msg_send![&*self.progress.objc, removeObserver: &*self.observer, forKeyPath: &*key_path];
+            }
+        }
+
+        self.cancel_button.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setTarget: nil];
+        });
+
+        self.end();
+
+        unsafe {
+            self.observer.set_ivar(TASK_SHEET_HANDLER_PTR, 0usize);
+            self.observer.set_ivar::<id>(TASK_SHEET_WINDOW, nil);
+            self.observer.set_ivar::<id>(TASK_SHEET_PROGRESS, nil);
+            self.observer.set_ivar::<id>(TASK_SHEET_INDICATOR, nil);
+        }
+    }
+}
+
+/// Ends the sheet, provided it's attached to a parent window.
+unsafe fn dismiss(observer: &Object) {
+    let sheet: id = *observer.get_ivar(TASK_SHEET_WINDOW);
+
+    if sheet == nil {
+        return;
+    }
+
+    let parent: id = msg_send![sheet, sheetParent];
+
+    if parent != nil {
+        let _: () = msg_send![parent, endSheet: sheet];
+    }
+}
+
+/// Fires when an observed key path on the `NSProgress` changes. This can happen on any thread, so
+/// we bounce over to the main thread before touching any UI.
+extern "C" fn observe_value(this: &Object, _: Sel, _: id, _: id, _: id, _: *mut c_void) {
+    unsafe {
+        let _: () = msg_send![this, performSelectorOnMainThread: sel!(update), withObject: nil, waitUntilDone: NO];
+    }
+}
+
+/// Syncs the progress bar with the `NSProgress`, and dismisses the sheet if the work is done.
+extern "C" fn update(this: &Object, _: Sel) {
+    unsafe {
+        let progress: id = *this.get_ivar(TASK_SHEET_PROGRESS);
+        let indicator: id = *this.get_ivar(TASK_SHEET_INDICATOR);
+
+        // The `TaskSheet` was dropped while this was queued.
+        if progress == nil || indicator == nil {
+            return;
+        }
+
+        let fraction: f64 = msg_send![progress, fractionCompleted];
+        let _: () = msg_send![indicator, setDoubleValue: fraction * 100.];
+
+        let finished: BOOL = msg_send![progress, isFinished];
+        let cancelled: BOOL = msg_send![progress, isCancelled];
+
+        if to_bool(finished) || to_bool(cancelled) {
+            dismiss(this);
+        }
+    }
+}
+
+/// Fires when the cancel button is clicked.
+extern "C" fn cancel(this: &Object, _: Sel, _: id) {
+    unsafe {
+        let progress: id = *this.get_ivar(TASK_SHEET_PROGRESS);
+
+        if progress == nil {
+            return;
+        }
+
+        let _: () = msg_send![progress, cancel];
+    }
+
+    let handler = load::<CancelHandler>(this, TASK_SHEET_HANDLER_PTR);
+    (handler.0)();
+
+    unsafe {
+        dismiss(this);
+    }
+}
+
+/// Injects an `NSObject` subclass that observes the `NSProgress` and acts as the target for the
+/// cancel button.
+fn register_task_sheet_observer_class() -> &'static Class {
+    load_or_register_class("NSObject", "RSTTaskSheetObserver", |decl| unsafe {
+        decl.add_ivar::<usize>(TASK_SHEET_HANDLER_PTR);
+        decl.add_ivar::<id>(TASK_SHEET_WINDOW);
+        decl.add_ivar::<id>(TASK_SHEET_INDICATOR);
+        decl.add_ivar::<id>(TASK_SHEET_PROGRESS);
+
+        decl.add_method(
+            sel!(observeValueForKeyPath:ofObject:change:context:),
+            observe_value as extern "C" fn(_, _, _, _, _, _)
+        );
+        decl.add_method(sel!(update), update as extern "C" fn(_, _));
+        decl.add_method(sel!(cancel:), cancel as extern "C" fn(_, _, _));
+    })
+}
@@ -1,8 +1,10 @@
 //! Various types used for Toolbar configuration.
 
-use objc::rc::Id;
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send_id, sel};
 
-use crate::foundation::{id, NSString, NSUInteger};
+use crate::foundation::{id, NSInteger, NSString, NSUInteger};
 
 /// Represents the display mode(s) a Toolbar can render in.
 #[derive(Clone, Copy, Debug)]
@@ -140,3 +142,33 @@ impl From<ToolbarSizeMode> for NSUInteger {
         }
     }
 }
+
+/// A badge shown on a toolbar item, e.g a count of unread messages. See `Toolbar::set_item_badge()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ToolbarItemBadge {
+    /// A short piece of text.
+    Text(String),
+
+    /// A number.
+    Count(usize),
+
+    /// A dot, for "something's new here".
+    Indicator
+}
+
+impl ToolbarItemBadge {
+    /// Creates the backing `NSItemBadge`.
+    pub(crate) fn to_objc(&self) -> Id<Object, Shared> {
+        unsafe {
+            match self {
+                Self::Text(text) => {
+                    let text = NSString::new(text);
+                    msg_send_id![class!(NSItemBadge), badgeWithText: &*text]
+                },
+
+                Self::Count(count) => msg_send_id![class!(NSItemBadge), badgeWithCount: *count as NSInteger],
+                Self::Indicator => msg_send_id![class!(NSItemBadge), indicatorBadge]
+            }
+        }
+    }
+}
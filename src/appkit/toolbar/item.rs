@@ -12,9 +12,10 @@ use objc::{class, msg_send, msg_send_id, sel};
 
 use crate::appkit::segmentedcontrol::SegmentedControl;
 use crate::button::{BezelStyle, Button};
-use crate::foundation::{id, NSString, NO, YES};
-use crate::image::Image;
+use crate::foundation::{id, NSInteger, NSString, NO, YES};
+use crate::image::{Image, SFSymbol};
 use crate::invoker::TargetActionHandler;
+use crate::view::SplitViewController;
 
 /// Wraps `NSToolbarItem`. Enables configuring things like size, view, and so on.
 #[derive(Debug)]
@@ -49,6 +50,87 @@ impl ToolbarItem {
         }
     }
 
+    /// Creates and returns a new `ToolbarItem` showing `image`, with `label` underneath (and
+    /// in the customization palette), firing `action` when clicked.
+    ///
+    /// The image is marked as a template, so that it adapts to dark mode and the toolbar's tint
+    /// and state; use `set_image()` afterwards if you need a full color image.
+    pub fn with_image<S, F>(identifier: S, label: &str, mut image: Image, action: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn(*const Object) + Send + Sync + 'static
+    {
+        let mut item = ToolbarItem::new(identifier);
+        image.set_template(true);
+
+        unsafe {
+            let label = NSString::new(label);
+            let _: () = msg_send![&*item.objc, setPaletteLabel: &*label];
+            let _: () = msg_send![&*item.objc, setToolTip: &*label];
+        }
+
+        item.set_title(label);
+        item.set_image(image);
+        item.set_bordered(true);
+        item.set_action(action);
+        item
+    }
+
+    /// Creates and returns a new `ToolbarItem` showing an SF Symbol; see `with_image()`. The
+    /// label doubles as the symbol's accessibility description.
+    ///
+    /// SF Symbols require macOS 11.0 (Big Sur); see `Image::symbol()`.
+    pub fn with_symbol<S, F>(identifier: S, label: &str, symbol: SFSymbol, action: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn(*const Object) + Send + Sync + 'static
+    {
+        ToolbarItem::with_image(identifier, label, Image::symbol(symbol, label), action)
+    }
+
+    /// Creates and returns a new `ToolbarItem` that tracks the divider at `divider_index` in the
+    /// given `SplitViewController`. Items placed before this one in your identifier list will sit
+    /// above the pane(s) to the left of the divider, and the rest will sit above the pane(s) to
+    /// the right - keeping toolbar sections aligned with your split view as the user resizes it.
+    ///
+    /// For the common case of tracking the sidebar divider of the window's content split view,
+    /// `ItemIdentifier::SidebarTracker` does this for you.
+    ///
+    /// This API was introduced in macOS 11.0 (Big Sur); you should check against this at runtime
+    /// if you support older versions.
+    pub fn tracking_separator<S, Sidebar, Content, Details>(
+        identifier: S,
+        split_view_controller: &SplitViewController<Sidebar, Content, Details>,
+        divider_index: usize
+    ) -> Self
+    where
+        S: Into<String>
+    {
+        let identifier = identifier.into();
+
+        let objc = unsafe {
+            let identifer = NSString::new(&identifier);
+            let split_view: id = msg_send![&*split_view_controller.objc, splitView];
+            let divider_index = divider_index as NSInteger;
+
+            msg_send_id![
+                class!(NSTrackingSeparatorToolbarItem),
+                trackingSeparatorToolbarItemWithIdentifier: &*identifer,
+                splitView: split_view,
+                dividerIndex: divider_index
+            ]
+        };
+
+        ToolbarItem {
+            identifier,
+            objc,
+            button: None,
+            segmented_control: None,
+            image: None,
+            handler: None
+        }
+    }
+
     /// Sets the title for this item.
     pub fn set_title(&mut self, title: &str) {
         unsafe {
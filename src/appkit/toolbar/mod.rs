@@ -9,7 +9,10 @@ use objc::rc::{Id, Owned, Shared};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
-use crate::foundation::{id, nil, NSString, NSUInteger, NO, YES};
+use crate::available;
+use crate::error::Error;
+use crate::foundation::{id, nil, NSArray, NSInteger, NSString, NSUInteger, NO, YES};
+use crate::utils::responds_to;
 
 mod class;
 use class::register_toolbar_class;
@@ -21,7 +24,7 @@ mod traits;
 pub use traits::ToolbarDelegate;
 
 mod enums;
-pub use enums::{ItemIdentifier, ToolbarDisplayMode, ToolbarSizeMode};
+pub use enums::{ItemIdentifier, ToolbarDisplayMode, ToolbarItemBadge, ToolbarSizeMode};
 
 pub(crate) static TOOLBAR_PTR: &str = "cacaoToolbarPtr";
 
@@ -131,6 +134,117 @@ impl<T> Toolbar<T> {
             let _: () = msg_send![&*self.objc, setSelectedItemIdentifier:&*identifier];
         }
     }
+
+    /// Returns the identifiers of the items currently in the toolbar, in order.
+    pub fn item_identifiers(&self) -> Vec<String> {
+        self.items()
+            .iter()
+            .map(|item| NSString::retain(unsafe { msg_send![item, itemIdentifier] }).to_string())
+            .collect()
+    }
+
+    /// Enables or disables the item(s) with the given identifier, e.g to disable "Back" when
+    /// there's no history.
+    ///
+    /// This turns off automatic validation for the item, so that AppKit doesn't flip it back.
+    pub fn set_item_enabled(&self, item_identifier: &str, enabled: bool) {
+        self.with_items(item_identifier, |item| unsafe {
+            let enabled = match enabled {
+                true => YES,
+                false => NO
+            };
+
+            let _: () = msg_send![item, setAutovalidates: NO];
+            let _: () = msg_send![item, setEnabled: enabled];
+
+            // Items backed by a view (buttons, etc) don't always pass this on.
+            let view: id = msg_send![item, view];
+            if view != nil && responds_to(&*view, sel!(setEnabled:)) {
+                let _: () = msg_send![view, setEnabled: enabled];
+            }
+        });
+    }
+
+    /// Inserts the item with the given identifier at `index`, asking the delegate for it via
+    /// `ToolbarDelegate::item_for()`. The identifier must be one of the delegate's allowed item
+    /// identifiers.
+    pub fn insert_item(&self, item_identifier: &ItemIdentifier, index: usize) {
+        unsafe {
+            let _: () = msg_send![
+                &*self.objc,
+                insertItemWithItemIdentifier: item_identifier.to_nsstring(),
+                atIndex: index as NSInteger
+            ];
+        }
+    }
+
+    /// Removes the item at `index`.
+    pub fn remove_item_at_index(&self, index: usize) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, removeItemAtIndex: index as NSInteger];
+        }
+    }
+
+    /// Removes the first item with the given identifier, if it's in the toolbar.
+    pub fn remove_item(&self, item_identifier: &str) {
+        if let Some(index) = self.index_of(item_identifier) {
+            self.remove_item_at_index(index);
+        }
+    }
+
+    /// Swaps the first item with the given identifier for a fresh one from
+    /// `ToolbarDelegate::item_for()` - e.g after changing which view it should show.
+    pub fn reload_item(&self, item_identifier: &'static str) {
+        if let Some(index) = self.index_of(item_identifier) {
+            self.remove_item_at_index(index);
+            self.insert_item(&ItemIdentifier::Custom(item_identifier), index);
+        }
+    }
+
+    /// Sets (or, with `None`, removes) the badge on the item(s) with the given identifier.
+    ///
+    /// Badges were introduced in macOS 13 (Ventura); on older versions this returns an
+    /// unsupported error (see `Error::is_unsupported_on_this_os()`).
+    pub fn set_item_badge(&self, item_identifier: &str, badge: Option<ToolbarItemBadge>) -> Result<(), Error> {
+        if !available!(macos 13) {
+            return Err(Error::unsupported_on_this_os("Toolbar::set_item_badge", "macOS 13"));
+        }
+
+        let badge = badge.map(|badge| badge.to_objc());
+        let badge: id = match &badge {
+            Some(badge) => &**badge as *const Object as id,
+            None => nil
+        };
+
+        self.with_items(item_identifier, |item| unsafe {
+            let _: () = msg_send![item, setBadge: badge];
+        });
+
+        Ok(())
+    }
+
+    /// Returns the toolbar's current `NSToolbarItem`s.
+    fn items(&self) -> NSArray {
+        NSArray::retain(unsafe { msg_send![&*self.objc, items] })
+    }
+
+    /// Returns the index of the first item with the given identifier.
+    fn index_of(&self, item_identifier: &str) -> Option<usize> {
+        self.item_identifiers()
+            .iter()
+            .position(|identifier| identifier == item_identifier)
+    }
+
+    /// Runs `handler` for each item with the given identifier.
+    fn with_items<F: Fn(id)>(&self, item_identifier: &str, handler: F) {
+        for item in self.items().iter() {
+            let identifier = NSString::retain(unsafe { msg_send![item, itemIdentifier] });
+
+            if identifier.to_str() == item_identifier {
+                handler(item);
+            }
+        }
+    }
 }
 
 impl<T> fmt::Debug for Toolbar<T> {
@@ -0,0 +1,32 @@
+//! Handles the Objective-C functionality for the Touch Bar module.
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{msg_send, sel};
+
+use crate::appkit::touchbar::{TouchBarDelegate, TOUCHBAR_PTR};
+use crate::foundation::{id, load_or_register_class, NSString};
+use crate::utils::load;
+
+/// Loads the delegate, grabs whatever item is for this identifier, and returns what the
+/// Objective-C runtime needs.
+extern "C" fn item_for_identifier<T: TouchBarDelegate>(this: &Object, _: Sel, _: id, identifier: id) -> id {
+    let touch_bar = load::<T>(this, TOUCHBAR_PTR);
+    let identifier = NSString::retain(identifier);
+
+    let item = touch_bar.item_for(identifier.to_str());
+    unsafe { msg_send![&*item.objc, self] }
+}
+
+/// Registers an `NSObject` subclass to act as our `NSTouchBarDelegate`, and configures it to hold
+/// a pointer back to the Rust-side delegate.
+pub(crate) fn register_touch_bar_delegate_class<T: TouchBarDelegate>(instance: &T) -> &'static Class {
+    load_or_register_class("NSObject", instance.subclass_name(), |decl| unsafe {
+        // For callbacks
+        decl.add_ivar::<usize>(TOUCHBAR_PTR);
+
+        decl.add_method(
+            sel!(touchBar:makeItemForIdentifier:),
+            item_for_identifier::<T> as extern "C" fn(_, _, _, _) -> _
+        );
+    })
+}
@@ -0,0 +1,48 @@
+//! Various types used for Touch Bar configuration.
+
+use objc::rc::Id;
+
+use crate::foundation::{id, NSString};
+
+/// Represents an item identifier for items in a Touch Bar.
+#[derive(Clone, Debug)]
+pub enum TouchBarItemIdentifier {
+    /// Represents a custom item. Use this when you need to handle your own item types.
+    Custom(&'static str),
+
+    /// A small, fixed-width space.
+    FixedSpaceSmall,
+
+    /// A large, fixed-width space.
+    FixedSpaceLarge,
+
+    /// A space that grows to fill available room.
+    FlexibleSpace,
+
+    /// A placeholder for items vended by other Touch Bars further down the responder chain (e.g,
+    /// the system text editing controls when a text field is focused).
+    OtherItemsProxy
+}
+
+extern "C" {
+    static NSTouchBarItemIdentifierFixedSpaceSmall: id;
+    static NSTouchBarItemIdentifierFixedSpaceLarge: id;
+    static NSTouchBarItemIdentifierFlexibleSpace: id;
+    static NSTouchBarItemIdentifierOtherItemsProxy: id;
+}
+
+impl TouchBarItemIdentifier {
+    /// Returns the NSString necessary for the Touch Bar to operate.
+    pub(crate) fn to_nsstring(&self) -> id {
+        unsafe {
+            match self {
+                // FIXME: We shouldn't use autorelease here
+                Self::Custom(s) => Id::autorelease_return(NSString::new(s).objc),
+                Self::FixedSpaceSmall => NSTouchBarItemIdentifierFixedSpaceSmall,
+                Self::FixedSpaceLarge => NSTouchBarItemIdentifierFixedSpaceLarge,
+                Self::FlexibleSpace => NSTouchBarItemIdentifierFlexibleSpace,
+                Self::OtherItemsProxy => NSTouchBarItemIdentifierOtherItemsProxy
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Implements wrappers for `NSTouchBarItem` types.
+
+use objc::rc::{Id, Owned};
+use objc::runtime::{Class, Object};
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::button::Button;
+use crate::error::{Error, CACAO_ERROR_DOMAIN};
+use crate::foundation::{id, NSString};
+use crate::invoker::TargetActionHandler;
+use crate::text::Label;
+use crate::utils::responds_to;
+
+/// Wraps `NSCustomTouchBarItem` and `NSSliderTouchBarItem`. Custom items can hold a `Button` or
+/// `Label`; slider items manage their own `NSSlider`.
+#[derive(Debug)]
+pub struct TouchBarItem {
+    pub identifier: String,
+    pub objc: Id<Object, Owned>,
+    pub button: Option<Button>,
+    pub label: Option<Label>,
+    handler: Option<TargetActionHandler>
+}
+
+impl TouchBarItem {
+    /// Internal method for wrapping an item of a given class.
+    fn with_class(cls: &Class, identifier: String) -> Self {
+        let objc = unsafe {
+            let identifer = NSString::new(&identifier);
+            let alloc = msg_send_id![cls, alloc];
+            msg_send_id![alloc, initWithIdentifier: &*identifer]
+        };
+
+        TouchBarItem {
+            identifier,
+            objc,
+            button: None,
+            label: None,
+            handler: None
+        }
+    }
+
+    /// Creates and returns a new custom `TouchBarItem`, which can hold a `Button` or `Label`.
+    pub fn new<S: Into<String>>(identifier: S) -> Self {
+        Self::with_class(class!(NSCustomTouchBarItem), identifier.into())
+    }
+
+    /// Creates and returns a new slider `TouchBarItem`.
+    pub fn slider<S: Into<String>>(identifier: S) -> Self {
+        Self::with_class(class!(NSSliderTouchBarItem), identifier.into())
+    }
+
+    /// Sets the label shown when this item is in the customization palette, and - for sliders -
+    /// next to the slider itself. Custom items have no label of their own; set one on their
+    /// `Button` or `Label` instead.
+    pub fn set_title(&self, title: &str) {
+        unsafe {
+            let title = NSString::new(title);
+            let _: () = msg_send![&*self.objc, setCustomizationLabel:&*title];
+
+            if responds_to(&self.objc, sel!(setLabel:)) {
+                let _: () = msg_send![&*self.objc, setLabel:&*title];
+            }
+        }
+    }
+
+    /// Sets and takes ownership of the button for this item.
+    pub fn set_button(&mut self, button: Button) {
+        button.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![&*self.objc, setView: obj];
+        });
+
+        self.button = Some(button);
+    }
+
+    /// Sets and takes ownership of the label for this item.
+    pub fn set_label(&mut self, label: Label) {
+        label.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![&*self.objc, setView: obj];
+        });
+
+        self.label = Some(label);
+    }
+
+    /// For slider items, sets the minimum and maximum values of the slider.
+    pub fn set_slider_range(&self, min: f64, max: f64) {
+        unsafe {
+            let slider: id = msg_send![&*self.objc, slider];
+            let _: () = msg_send![slider, setMinValue: min];
+            let _: () = msg_send![slider, setMaxValue: max];
+        }
+    }
+
+    /// For slider items, sets the current value of the slider.
+    pub fn set_slider_value(&self, value: f64) {
+        unsafe {
+            let slider: id = msg_send![&*self.objc, slider];
+            let _: () = msg_send![slider, setDoubleValue: value];
+        }
+    }
+
+    /// For slider items, returns the current value of the slider.
+    pub fn slider_value(&self) -> f64 {
+        unsafe {
+            let slider: id = msg_send![&*self.objc, slider];
+            msg_send![slider, doubleValue]
+        }
+    }
+
+    /// Sets an action on this item, which fires as a slider item's value changes.
+    ///
+    /// Custom items don't have an action of their own (set it on their `Button` instead), and
+    /// return an `Error`.
+    pub fn set_action<F: Fn(*const Object) + Send + Sync + 'static>(&mut self, action: F) -> Result<(), Error> {
+        if !responds_to(&self.objc, sel!(setAction:)) {
+            return Err(Error::custom(
+                CACAO_ERROR_DOMAIN,
+                0,
+                &format!("{} has no action; set one on its view instead", self.objc.class().name())
+            ));
+        }
+
+        let handler = TargetActionHandler::new(&*self.objc, action);
+        self.handler = Some(handler);
+        Ok(())
+    }
+}
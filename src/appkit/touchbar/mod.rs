@@ -0,0 +1,133 @@
+//! Implements `NSTouchBar` support, for Macs that ship with a Touch Bar.
+//!
+//! The API here mirrors the `Toolbar` module: you implement a `TouchBarDelegate` that vends
+//! identifiers and items, create a `TouchBar` with it, and attach it to a `Window`.
+
+use std::fmt;
+
+use objc::rc::{Id, Owned, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, nil, NSArray, NSString};
+
+mod class;
+use class::register_touch_bar_delegate_class;
+
+mod enums;
+pub use enums::TouchBarItemIdentifier;
+
+mod item;
+pub use item::TouchBarItem;
+
+mod traits;
+pub use traits::TouchBarDelegate;
+
+pub(crate) static TOUCHBAR_PTR: &str = "cacaoTouchBarPtr";
+
+/// A wrapper for `NSTouchBar`. Holds (retains) pointers for the Objective-C runtime
+/// where our `NSTouchBar` and associated delegate live.
+pub struct TouchBar<T = ()> {
+    /// The Objective-C runtime Touch Bar.
+    pub objc: Id<Object, Shared>,
+
+    /// A pointer to the underlying delegate.
+    pub objc_delegate: Id<Object, Shared>,
+
+    /// The user supplied delegate.
+    pub delegate: Option<Box<T>>
+}
+
+impl<T> TouchBar<T>
+where
+    T: TouchBarDelegate + 'static
+{
+    /// Creates a new `NSTouchBar` instance, configures it appropriately, sets up the delegate
+    /// chain, and retains it all.
+    pub fn new(delegate: T) -> Self {
+        let cls = register_touch_bar_delegate_class::<T>(&delegate);
+        let mut delegate = Box::new(delegate);
+
+        let (objc, objc_delegate) = unsafe {
+            let mut touch_bar: Id<Object, Owned> = msg_send_id![class!(NSTouchBar), new];
+            let mut objc_delegate: Id<Object, Owned> = msg_send_id![cls, new];
+
+            let ptr: *const T = &*delegate;
+            objc_delegate.set_ivar(TOUCHBAR_PTR, ptr as usize);
+
+            let objc_delegate: Id<Object, Shared> = Id::into_shared(objc_delegate);
+            let _: () = msg_send![&mut touch_bar, setDelegate: &*objc_delegate];
+
+            let identifiers: NSArray = delegate
+                .default_item_identifiers()
+                .iter()
+                .map(|identifier| identifier.to_nsstring())
+                .collect::<Vec<id>>()
+                .into();
+
+            let _: () = msg_send![&mut touch_bar, setDefaultItemIdentifiers: &*identifiers];
+
+            (Id::into_shared(touch_bar), objc_delegate)
+        };
+
+        let _ret = &mut delegate.did_load(TouchBar {
+            objc: objc.clone(),
+            objc_delegate: objc_delegate.clone(),
+            delegate: None
+        });
+
+        TouchBar {
+            objc,
+            objc_delegate,
+            delegate: Some(delegate)
+        }
+    }
+}
+
+impl<T> TouchBar<T> {
+    /// Sets the identifier used to persist user customizations of this Touch Bar. Without this,
+    /// the Touch Bar cannot be customized.
+    pub fn set_customization_identifier(&self, identifier: &str) {
+        let identifier = NSString::new(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCustomizationIdentifier:&*identifier];
+        }
+    }
+
+    /// Sets the item that should be centered in the Touch Bar, if any.
+    pub fn set_principal_item(&self, identifier: &str) {
+        let identifier = NSString::new(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPrincipalItemIdentifier:&*identifier];
+        }
+    }
+}
+
+impl<T> fmt::Debug for TouchBar<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let delegate = match &self.delegate {
+            Some(d) => format!("Some({:p})", d),
+            None => "None".to_string()
+        };
+
+        f.debug_struct("TouchBar")
+            .field("objc", &self.objc)
+            .field("objc_delegate", &self.objc_delegate)
+            .field("delegate", &delegate)
+            .finish()
+    }
+}
+
+impl<T> Drop for TouchBar<T> {
+    /// A bit of extra cleanup for the delegate system. If we have a non-`None` delegate, this is
+    /// the OG TouchBar and should be cleaned up for any possible cyclical references.
+    fn drop(&mut self) {
+        if self.delegate.is_some() {
+            unsafe {
+                let _: () = msg_send![&*self.objc, setDelegate: nil];
+            }
+        }
+    }
+}
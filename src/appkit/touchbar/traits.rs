@@ -0,0 +1,27 @@
+//! Traits that can be used for Touch Bar construction.
+
+use crate::appkit::touchbar::{TouchBar, TouchBarItem, TouchBarItemIdentifier};
+
+/// A trait that you can implement to have your struct/etc act as an `NSTouchBarDelegate`.
+pub trait TouchBarDelegate {
+    /// Used to cache subclass creations on the Objective-C side.
+    /// You can just set this to be the name of your view type. This
+    /// value *must* be unique per-type.
+    const NAME: &'static str;
+
+    /// You should rarely (read: probably never) need to implement this yourself.
+    /// It simply acts as a getter for the associated `NAME` const on this trait.
+    fn subclass_name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    /// This method can be used to configure your Touch Bar, if you need to do things involving
+    /// the handle.
+    fn did_load(&mut self, _touch_bar: TouchBar) {}
+
+    /// The items shown in this Touch Bar, in order.
+    fn default_item_identifiers(&self) -> Vec<TouchBarItemIdentifier>;
+
+    /// For a given `identifier`, return the `TouchBarItem` that should be displayed.
+    fn item_for(&self, _identifier: &str) -> &TouchBarItem;
+}
@@ -4,19 +4,23 @@
 use std::sync::Once;
 
 use core_graphics::base::CGFloat;
+use core_graphics::geometry::{CGPoint, CGRect};
 
 use objc::declare::ClassDecl;
 use objc::runtime::{Bool, Class, Object, Sel};
-use objc::{class, sel};
+use objc::{class, msg_send, sel};
 
-use crate::appkit::window::{WindowDelegate, WINDOW_DELEGATE_PTR};
-use crate::foundation::{id, load_or_register_class, NSUInteger};
-use crate::utils::{load, CGSize};
+use crate::appkit::window::{WindowDelegate, NS_WINDOW_OCCLUSION_STATE_VISIBLE, WINDOW_DELEGATE_PTR};
+use crate::foundation::{id, load_or_register_class, nil, NSString, NSUInteger};
+use crate::utils::{load_weak, release_weak, CGSize};
 
 /// Called when an `NSWindowDelegate` receives a `windowWillClose:` event.
 /// Good place to clean up memory and what not.
 extern "C" fn should_close<T: WindowDelegate>(this: &Object, _: Sel, _: id) -> Bool {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
+    let window = match load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        Some(window) => window,
+        None => return Bool::YES
+    };
 
     Bool::new(window.should_close())
 }
@@ -24,37 +28,45 @@ extern "C" fn should_close<T: WindowDelegate>(this: &Object, _: Sel, _: id) -> B
 /// Called when an `NSWindowDelegate` receives a `windowWillClose:` event.
 /// Good place to clean up memory and what not.
 extern "C" fn will_close<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.will_close();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.will_close();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowWillMove:` event.
 extern "C" fn will_move<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.will_move();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.will_move();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidMove:` event.
 extern "C" fn did_move<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_move();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_move();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
 extern "C" fn did_change_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_change_screen();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_change_screen();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
 extern "C" fn did_change_screen_profile<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_change_screen_profile();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_change_screen_profile();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
 extern "C" fn will_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id, size: CGSize) -> CGSize {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
+    let window = match load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        Some(window) => window,
+        None => return size
+    };
     let s = window.will_resize(size.width as f64, size.height as f64);
 
     CGSize {
@@ -65,55 +77,66 @@ extern "C" fn will_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id, size:
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
 extern "C" fn did_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_resize();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_resize();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
 extern "C" fn will_start_live_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.will_start_live_resize();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.will_start_live_resize();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
 extern "C" fn did_end_live_resize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_end_live_resize();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_end_live_resize();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
 extern "C" fn will_miniaturize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.will_miniaturize();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.will_miniaturize();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
 extern "C" fn did_miniaturize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_miniaturize();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_miniaturize();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreen:` event.
 extern "C" fn did_deminiaturize<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_deminiaturize();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_deminiaturize();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
 extern "C" fn will_enter_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.will_enter_full_screen();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.will_enter_full_screen();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
 extern "C" fn did_enter_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_enter_full_screen();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_enter_full_screen();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
 extern "C" fn content_size_for_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id, size: CGSize) -> CGSize {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
+    let window = match load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        Some(window) => window,
+        None => return size
+    };
 
     let (width, height) = window.content_size_for_full_screen(size.width as f64, size.height as f64);
 
@@ -125,7 +148,10 @@ extern "C" fn content_size_for_full_screen<T: WindowDelegate>(this: &Object, _:
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
 extern "C" fn options_for_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id, options: NSUInteger) -> NSUInteger {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
+    let window = match load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        Some(window) => window,
+        None => return options
+    };
 
     let desired_opts = window.presentation_options_for_full_screen();
 
@@ -134,7 +160,7 @@ extern "C" fn options_for_full_screen<T: WindowDelegate>(this: &Object, _: Sel,
     } else {
         let mut opts: NSUInteger = 0;
         for opt in desired_opts.unwrap() {
-            opts = opts << NSUInteger::from(opt);
+            opts |= NSUInteger::from(opt);
         }
 
         opts
@@ -143,74 +169,158 @@ extern "C" fn options_for_full_screen<T: WindowDelegate>(this: &Object, _: Sel,
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
 extern "C" fn will_exit_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.will_exit_full_screen();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.will_exit_full_screen();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
 extern "C" fn did_exit_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_exit_full_screen();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_exit_full_screen();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
 extern "C" fn did_fail_to_enter_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_fail_to_enter_full_screen();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_fail_to_enter_full_screen();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeScreenProfile:` event.
 extern "C" fn did_fail_to_exit_full_screen<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_fail_to_exit_full_screen();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_fail_to_exit_full_screen();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidChangeBackingProperties:` event.
-extern "C" fn did_change_backing_properties<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
+/// If the scale factor changed as part of this, we additionally forward that along.
+extern "C" fn did_change_backing_properties<T: WindowDelegate>(this: &Object, _: Sel, notification: id) {
+    let window = match load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        Some(window) => window,
+        None => return
+    };
     window.did_change_backing_properties();
+
+    let (old_scale, new_scale) = unsafe {
+        let key = NSString::new("NSBackingPropertyOldScaleFactorKey");
+        let user_info: id = msg_send![notification, userInfo];
+        let old_scale: id = msg_send![user_info, objectForKey: &*key];
+
+        let object: id = msg_send![notification, object];
+        let new_scale: CGFloat = msg_send![object, backingScaleFactor];
+
+        match old_scale == nil {
+            true => (new_scale, new_scale),
+            false => (msg_send![old_scale, doubleValue], new_scale)
+        }
+    };
+
+    if old_scale != new_scale {
+        window.did_change_backing_scale_factor(old_scale as f64, new_scale as f64);
+    }
 }
 
-/// Called when an `NSWindowDelegate` receives a `windowDidChangeBackingProperties:` event.
-extern "C" fn did_change_occlusion_state<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
+/// Called when an `NSWindowDelegate` receives a `windowDidChangeOcclusionState:` event.
+extern "C" fn did_change_occlusion_state<T: WindowDelegate>(this: &Object, _: Sel, notification: id) {
+    let window = match load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        Some(window) => window,
+        None => return
+    };
     window.did_change_occlusion_state();
+
+    let is_visible = unsafe {
+        let object: id = msg_send![notification, object];
+        let state: NSUInteger = msg_send![object, occlusionState];
+        state & NS_WINDOW_OCCLUSION_STATE_VISIBLE != 0
+    };
+
+    window.did_change_occlusion_visibility(is_visible);
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidUpdate:` event.
 extern "C" fn did_update<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_update();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_update();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidExpose:` event.
 extern "C" fn did_become_main<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_become_main();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_become_main();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidExpose:` event.
 extern "C" fn did_resign_main<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_resign_main();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_resign_main();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidExpose:` event.
 extern "C" fn did_become_key<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_become_key();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_become_key();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidExpose:` event.
 extern "C" fn did_resign_key<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_resign_key();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_resign_key();
+    }
 }
 
 /// Called when an `NSWindowDelegate` receives a `windowDidExpose:` event.
 extern "C" fn did_expose<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.did_expose();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.did_expose();
+    }
+}
+
+/// Called when an `NSWindowDelegate` receives a `windowWillUseStandardFrame:defaultFrame:` event.
+extern "C" fn standard_frame<T: WindowDelegate>(this: &Object, _: Sel, _: id, default_frame: CGRect) -> CGRect {
+    let window = match load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        Some(window) => window,
+        None => return default_frame
+    };
+
+    window.standard_frame(default_frame.into()).into()
+}
+
+/// Called when an `NSWindowDelegate` receives a `windowShouldZoom:toFrame:` event.
+extern "C" fn should_zoom<T: WindowDelegate>(this: &Object, _: Sel, _: id, frame: CGRect) -> Bool {
+    let window = match load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        Some(window) => window,
+        None => return Bool::YES
+    };
+
+    Bool::new(window.should_zoom(frame.into()))
+}
+
+/// Called when an `NSWindowDelegate` receives a `window:shouldPopUpDocumentPathMenu:` event.
+extern "C" fn should_pop_up_document_path_menu<T: WindowDelegate>(this: &Object, _: Sel, _: id, _: id) -> Bool {
+    let window = match load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        Some(window) => window,
+        None => return Bool::YES
+    };
+
+    Bool::new(window.should_show_document_path_menu())
+}
+
+/// Called when an `NSWindowDelegate` receives a
+/// `window:shouldDragDocumentWithEvent:from:withPasteboard:` event.
+extern "C" fn should_drag_document<T: WindowDelegate>(this: &Object, _: Sel, _: id, _: id, _: CGPoint, _: id) -> Bool {
+    let window = match load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        Some(window) => window,
+        None => return Bool::YES
+    };
+
+    Bool::new(window.should_drag_document())
 }
 
 /// Called as part of the responder chain, when, say, the ESC key is hit. If your
@@ -218,8 +328,18 @@ extern "C" fn did_expose<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
 /// window to close when the Esc key is hit. This is mostly useful for Sheet-presented
 /// windows, and so the default response from delegates is `false` and must be opted in to.
 extern "C" fn cancel<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
-    let window = load::<T>(this, WINDOW_DELEGATE_PTR);
-    window.cancel();
+    if let Some(window) = load_weak::<T>(this, WINDOW_DELEGATE_PTR) {
+        window.cancel();
+    }
+}
+
+/// Releases the weak reference to the delegate, before the window is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    release_weak(this, WINDOW_DELEGATE_PTR);
+
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSWindow)), dealloc];
+    }
 }
 
 /// Injects an `NSWindowDelegate` subclass, with some callback and pointer ivars for what we
@@ -227,6 +347,7 @@ extern "C" fn cancel<T: WindowDelegate>(this: &Object, _: Sel, _: id) {
 pub(crate) fn register_window_class_with_delegate<T: WindowDelegate>(instance: &T) -> &'static Class {
     load_or_register_class("NSWindow", instance.subclass_name(), |decl| unsafe {
         decl.add_ivar::<usize>(WINDOW_DELEGATE_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
 
         // NSWindowDelegate methods
         decl.add_method(sel!(windowShouldClose:), should_close::<T> as extern "C" fn(_, _, _) -> _);
@@ -318,5 +439,25 @@ pub(crate) fn register_window_class_with_delegate<T: WindowDelegate>(instance: &
         decl.add_method(sel!(windowDidExpose:), did_expose::<T> as extern "C" fn(_, _, _));
         decl.add_method(sel!(windowDidUpdate:), did_update::<T> as extern "C" fn(_, _, _));
         decl.add_method(sel!(cancelOperation:), cancel::<T> as extern "C" fn(_, _, _));
+
+        // Zooming
+        decl.add_method(
+            sel!(windowWillUseStandardFrame:defaultFrame:),
+            standard_frame::<T> as extern "C" fn(_, _, _, _) -> _
+        );
+        decl.add_method(
+            sel!(windowShouldZoom:toFrame:),
+            should_zoom::<T> as extern "C" fn(_, _, _, _) -> _
+        );
+
+        // The title bar's document (proxy) icon
+        decl.add_method(
+            sel!(window:shouldPopUpDocumentPathMenu:),
+            should_pop_up_document_path_menu::<T> as extern "C" fn(_, _, _, _) -> _
+        );
+        decl.add_method(
+            sel!(window:shouldDragDocumentWithEvent:from:withPasteboard:),
+            should_drag_document::<T> as extern "C" fn(_, _, _, _, _, _) -> _
+        );
     })
 }
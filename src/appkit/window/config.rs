@@ -32,7 +32,18 @@ pub struct WindowConfig {
     /// for other variants.
     ///
     /// This setting is notably important for Preferences windows.
-    pub toolbar_style: WindowToolbarStyle
+    pub toolbar_style: WindowToolbarStyle,
+
+    /// Whether to cascade the window from the last one created with this set: each is offset down
+    /// and to the right, the way new document windows are, rather than stacking at the same
+    /// origin. The first such window stays where `initial_dimensions` puts it. Defaults to
+    /// `false`.
+    pub cascade: bool,
+
+    /// Whether AppKit may open windows as tabs, and add the tab-related items to the Window
+    /// menu. This is a setting for the whole app, applied when the window is created; see
+    /// `Window::set_tabbing_mode()` for finer control. Defaults to `false`.
+    pub allows_automatic_tabbing: bool
 }
 
 impl Default for WindowConfig {
@@ -41,7 +52,9 @@ impl Default for WindowConfig {
             style: 0,
             initial_dimensions: Rect::new(100., 100., 1024., 768.),
             defer: true,
-            toolbar_style: WindowToolbarStyle::Automatic
+            toolbar_style: WindowToolbarStyle::Automatic,
+            cascade: false,
+            allows_automatic_tabbing: false
         };
 
         config.set_styles(&[
@@ -83,4 +96,9 @@ impl WindowConfig {
     pub fn set_toolbar_style(&mut self, style: WindowToolbarStyle) {
         self.toolbar_style = style;
     }
+
+    /// Sets whether this window cascades from the last one created with this set. See `cascade`.
+    pub fn set_cascade(&mut self, cascade: bool) {
+        self.cascade = cascade;
+    }
 }
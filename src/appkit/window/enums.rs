@@ -135,6 +135,34 @@ impl From<WindowToolbarStyle> for NSUInteger {
     }
 }
 
+/// Describes the separator drawn between the titlebar (and toolbar) and the content of a window.
+/// This setting is specific to macOS 11.0+ (Big Sur and onwards).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TitlebarSeparatorStyle {
+    /// Let the system decide, based on the window's content.
+    Automatic,
+
+    /// No separator.
+    None,
+
+    /// A thin line.
+    Line,
+
+    /// A shadow, as used in pre-11.0 windows.
+    Shadow
+}
+
+impl From<TitlebarSeparatorStyle> for NSInteger {
+    fn from(style: TitlebarSeparatorStyle) -> Self {
+        match style {
+            TitlebarSeparatorStyle::Automatic => 0,
+            TitlebarSeparatorStyle::None => 1,
+            TitlebarSeparatorStyle::Line => 2,
+            TitlebarSeparatorStyle::Shadow => 3
+        }
+    }
+}
+
 /// Describe the level of the window. Stacking of window levels take precedence over stacking
 /// of windows withing each level.
 #[derive(Clone, Copy, Debug)]
@@ -178,3 +206,73 @@ impl From<WindowLevel> for NSInteger {
         }
     }
 }
+
+/// The standard buttons in a window's titlebar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowButton {
+    /// The close (red) button.
+    Close,
+
+    /// The miniaturize (yellow) button.
+    Miniaturize,
+
+    /// The zoom (green) button.
+    Zoom,
+
+    /// The document icon shown beside the title of document windows.
+    DocumentIcon
+}
+
+impl From<WindowButton> for NSUInteger {
+    fn from(button: WindowButton) -> Self {
+        match button {
+            WindowButton::Close => 0,
+            WindowButton::Miniaturize => 1,
+            WindowButton::Zoom => 2,
+            WindowButton::DocumentIcon => 4
+        }
+    }
+}
+
+/// Where to place a window relative to another, in `Window::order_relative_to()` and
+/// `Window::add_tabbed_window()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowOrdering {
+    /// In front of the other window (or, for tabs, after it).
+    Above,
+
+    /// Behind the other window (or, for tabs, before it).
+    Below
+}
+
+impl From<WindowOrdering> for NSInteger {
+    fn from(ordering: WindowOrdering) -> Self {
+        match ordering {
+            WindowOrdering::Above => 1,
+            WindowOrdering::Below => -1
+        }
+    }
+}
+
+/// Whether a window opens as a tab in an existing window, or on its own.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowTabbingMode {
+    /// Follow the user's "Prefer tabs when opening documents" setting.
+    Automatic,
+
+    /// Always open as a tab, when there's a window with the same tabbing identifier.
+    Preferred,
+
+    /// Never open as a tab.
+    Disallowed
+}
+
+impl From<WindowTabbingMode> for NSInteger {
+    fn from(mode: WindowTabbingMode) -> Self {
+        match mode {
+            WindowTabbingMode::Automatic => 0,
+            WindowTabbingMode::Preferred => 1,
+            WindowTabbingMode::Disallowed => 2
+        }
+    }
+}
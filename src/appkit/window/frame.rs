@@ -0,0 +1,63 @@
+//! Helpers for persisting and restoring window frames.
+//!
+//! AppKit encodes a saved frame (`-[NSWindow stringWithSavedFrame]`) as eight space separated
+//! numbers: the window frame (x, y, width, height), followed by the visible frame of the screen the
+//! window was on at the time. We only care about the former.
+
+use core_graphics::geometry::CGRect;
+use objc::{class, msg_send, sel};
+
+use crate::foundation::{id, NSArray};
+use crate::geometry::Rect;
+
+/// Parses the window frame out of a saved frame string. Returns `None` if the string isn't in the
+/// expected format.
+pub(crate) fn parse_saved_frame(descriptor: &str) -> Option<Rect> {
+    let values = descriptor
+        .split_whitespace()
+        .map(|value| value.parse::<f64>().ok())
+        .collect::<Option<Vec<f64>>>()?;
+
+    match values.as_slice() {
+        [x, y, width, height, ..] if *width > 0. && *height > 0. => Some(Rect::new(*y, *x, *width, *height)),
+        _ => None
+    }
+}
+
+/// Returns whether `frame` overlaps the visible area of any connected screen.
+pub(crate) fn is_on_connected_screen(frame: Rect) -> bool {
+    let screens = NSArray::retain(unsafe { msg_send![class!(NSScreen), screens] });
+
+    screens.iter().any(|screen: id| {
+        let visible: CGRect = unsafe { msg_send![screen, visibleFrame] };
+        overlaps(frame, visible.into())
+    })
+}
+
+/// Whether two rects share any area.
+fn overlaps(a: Rect, b: Rect) -> bool {
+    a.left < b.left + b.width && b.left < a.left + a.width && a.top < b.top + b.height && b.top < a.top + a.height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_saved_frame() {
+        let frame = parse_saved_frame("100 200 800 600 0 0 1440 875 ").unwrap();
+        assert_eq!((frame.left, frame.top, frame.width, frame.height), (100., 200., 800., 600.));
+
+        assert!(parse_saved_frame("").is_none());
+        assert!(parse_saved_frame("100 200 0 600").is_none());
+        assert!(parse_saved_frame("a b c d").is_none());
+    }
+
+    #[test]
+    fn overlap_excludes_adjacent_rects() {
+        let screen = Rect::new(0., 0., 1440., 900.);
+        assert!(overlaps(Rect::new(850., 1400., 800., 600.), screen));
+        assert!(!overlaps(Rect::new(0., 1440., 800., 600.), screen));
+        assert!(!overlaps(Rect::new(-600., -2000., 800., 600.), screen));
+    }
+}
@@ -8,21 +8,29 @@
 //! not bother providing access to them. If you require functionality like that, you're free to use
 //! the `objc` field on a `Window` to instrument it with the Objective-C runtime on your own.
 
+use std::cell::Cell;
+
 use block::ConcreteBlock;
 
 use core_graphics::base::CGFloat;
-use core_graphics::geometry::{CGRect, CGSize};
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
 
 use objc::rc::{Id, Owned, Shared};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
 use crate::appkit::toolbar::{Toolbar, ToolbarDelegate};
+use crate::appkit::touchbar::{TouchBar, TouchBarDelegate};
+use crate::appkit::Appearance;
+use crate::available;
 use crate::color::Color;
-use crate::foundation::{id, nil, to_bool, NSInteger, NSString, NSUInteger, NO, YES};
-use crate::layout::Layout;
+use crate::error::Error;
+use crate::foundation::{id, nil, to_bool, NSArray, NSInteger, NSString, NSUInteger, NO, NSURL, YES};
+use crate::geometry::Rect;
+use crate::image::Image;
+use crate::layout::{accessibility, Layout};
 use crate::objc_access::ObjcAccess;
-use crate::utils::{os, Controller};
+use crate::utils::{responds_to, Controller, DelegateRef};
 use crate::view::View;
 
 mod class;
@@ -31,17 +39,27 @@ use class::register_window_class_with_delegate;
 mod config;
 pub use config::WindowConfig;
 
+mod frame;
+
 mod controller;
 pub use controller::WindowController;
 
 mod enums;
 pub use enums::*;
 
+mod registry;
+pub use registry::WindowRegistry;
+
+mod sheets;
+
 mod traits;
 pub use traits::WindowDelegate;
 
 pub(crate) static WINDOW_DELEGATE_PTR: &str = "rstWindowDelegate";
 
+/// `NSWindowOcclusionStateVisible`.
+pub(crate) const NS_WINDOW_OCCLUSION_STATE_VISIBLE: NSUInteger = 1 << 1;
+
 /// A `Window` represents your way of interacting with an `NSWindow`. It wraps the various moving
 /// pieces to enable you to focus on reacting to lifecycle methods and doing your thing.
 #[derive(Debug)]
@@ -49,6 +67,9 @@ pub struct Window<T = ()> {
     /// Represents an `NS/UIWindow` in the Objective-C runtime.
     pub objc: Id<Object, Shared>,
 
+    /// Keeps the delegate reachable from the Objective-C side; callbacks stop once this drops.
+    pub(crate) delegate_ref: Option<DelegateRef>,
+
     /// A delegate for this window.
     pub delegate: Option<Box<T>>
 }
@@ -69,8 +90,11 @@ impl Window {
     pub fn new(config: WindowConfig) -> Window {
         let objc = unsafe {
             // This behavior might make sense to keep as default (YES), but I think the majority of
-            // apps that would use this toolkit wouldn't be tab-oriented...
-            let _: () = msg_send![class!(NSWindow), setAllowsAutomaticWindowTabbing: NO];
+            // apps that would use this toolkit wouldn't be tab-oriented... so it's opt-in.
+            let _: () = msg_send![class!(NSWindow), setAllowsAutomaticWindowTabbing: match config.allows_automatic_tabbing {
+                true => YES,
+                false => NO
+            }];
 
             // Other types of backing (Retained/NonRetained) are archaic, dating back to the
             // NeXTSTEP era, and are outright deprecated... so we don't allow setting them.
@@ -95,10 +119,14 @@ impl Window {
 
             let _: () = msg_send![&*window, setRestorable: NO];
 
+            if config.cascade {
+                cascade_new_window(&*window as *const Object as id);
+            }
+
             // This doesn't exist prior to Big Sur, but is important to support for Big Sur.
             //
             // Why this isn't a setting on the Toolbar itself I'll never know.
-            if os::is_minimum_version(11) {
+            if available!(macos 11) || responds_to(&window, sel!(setToolbarStyle:)) {
                 let toolbar_style: NSUInteger = config.toolbar_style.into();
                 let _: () = msg_send![&*window, setToolbarStyle: toolbar_style];
             }
@@ -106,16 +134,30 @@ impl Window {
             window
         };
 
+        #[cfg(feature = "object-tracking")]
+        crate::debug::track(&objc);
+
         Window {
             objc: objc,
-            delegate: None
+            delegate: None,
+            delegate_ref: None
         }
     }
 
-    pub(crate) unsafe fn existing(window: *mut Object) -> Window {
+    /// Wraps an `NSWindow` that was created elsewhere - e.g, by `winit`, obtained via its raw
+    /// window handle. This lets you attach cacao toolbars, sheets and content to a window that
+    /// cacao doesn't own.
+    ///
+    /// The returned `Window` has no delegate, and dropping it leaves the window as-is.
+    ///
+    /// # Safety
+    ///
+    /// `window` must be a valid, non-null pointer to an `NSWindow`.
+    pub unsafe fn existing(window: *mut Object) -> Window {
         Window {
             objc: Id::retain(window).unwrap(),
-            delegate: None
+            delegate: None,
+            delegate_ref: None
         }
     }
 }
@@ -132,10 +174,13 @@ where
         let class = register_window_class_with_delegate::<T>(&delegate);
         let mut delegate = Box::new(delegate);
 
-        let objc: Id<Object, Shared> = unsafe {
+        let (objc, delegate_ref): (Id<Object, Shared>, DelegateRef) = unsafe {
             // This behavior might make sense to keep as default (YES), but I think the majority of
-            // apps that would use this toolkit wouldn't be tab-oriented...
-            let _: () = msg_send![class!(NSWindow), setAllowsAutomaticWindowTabbing: NO];
+            // apps that would use this toolkit wouldn't be tab-oriented... so it's opt-in.
+            let _: () = msg_send![class!(NSWindow), setAllowsAutomaticWindowTabbing: match config.allows_automatic_tabbing {
+                true => YES,
+                false => NO
+            }];
 
             // Other types of backing (Retained/NonRetained) are archaic, dating back to the
             // NeXTSTEP era, and are outright deprecated... so we don't allow setting them.
@@ -152,8 +197,7 @@ where
                 },
             ];
 
-            let delegate_ptr: *const T = &*delegate;
-            window.set_ivar(WINDOW_DELEGATE_PTR, delegate_ptr as usize);
+            let delegate_ref = DelegateRef::attach(&mut *window, WINDOW_DELEGATE_PTR, &*delegate);
 
             // This is very important! NSWindow is an old class and has some behavior that we need
             // to disable, like... this. If we don't set this, we'll segfault entirely because the
@@ -166,27 +210,36 @@ where
 
             let _: () = msg_send![&*window, setRestorable: NO];
 
+            if config.cascade {
+                cascade_new_window(&*window as *const Object as id);
+            }
+
             // This doesn't exist prior to Big Sur, but is important to support for Big Sur.
             //
             // Why this isn't a setting on the Toolbar itself I'll never know.
-            if os::is_minimum_version(11) {
+            if available!(macos 11) || responds_to(&window, sel!(setToolbarStyle:)) {
                 let toolbar_style: NSUInteger = config.toolbar_style.into();
                 let _: () = msg_send![&*window, setToolbarStyle: toolbar_style];
             }
 
-            window.into()
+            (window.into(), delegate_ref)
         };
 
         {
             (&mut delegate).did_load(Window {
                 delegate: None,
+                delegate_ref: None,
                 objc: objc.clone()
             });
         }
 
+        #[cfg(feature = "object-tracking")]
+        crate::debug::track(&objc);
+
         Window {
             objc: objc,
-            delegate: Some(delegate)
+            delegate: Some(delegate),
+            delegate_ref: Some(delegate_ref)
         }
     }
 }
@@ -204,20 +257,93 @@ impl<T> Window<T> {
     /// Sets the subtitle (smaller text bellow the title on unified and expanded title bars) on the
     /// underlying window. When this property is an empty string, the system removes the subtitle
     /// from the window layout. Allocates and passes an `NSString` over to the Objective C runtime.
-    /// Does nothing when less than version 11.
-    pub fn set_subtittle(&self, subtitle: &str) {
-        if !os::is_minimum_version(11) {
-            return;
+    ///
+    /// Requires macOS 11; returns an error (see `Error::is_unsupported_on_this_os()`) otherwise.
+    pub fn set_subtittle(&self, subtitle: &str) -> Result<(), Error> {
+        if !available!(macos 11) {
+            return Err(Error::unsupported_on_this_os("Window::set_subtittle", "macOS 11"));
         }
 
         unsafe {
             let subtitle = NSString::new(subtitle);
             let _: () = msg_send![&*self.objc, setSubtitle: &*subtitle];
         }
+
+        Ok(())
+    }
+
+    /// Sets the file (or folder) this window is showing, which puts its icon next to the title.
+    /// Users can Command-click the title (or right click the icon) for a menu of the folders
+    /// containing it, and drag the icon to move or copy the file elsewhere - which document apps
+    /// are expected to support.
+    ///
+    /// This doesn't change the title itself; see `set_title_with_represented_filename()` for that.
+    pub fn set_represented_url(&self, url: &NSURL) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setRepresentedURL: &*url.objc];
+        }
+    }
+
+    /// Removes the file set with `set_represented_url()` (or `set_represented_filename()`), along
+    /// with the title bar icon.
+    pub fn clear_represented_url(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setRepresentedURL: nil];
+        }
+    }
+
+    /// Returns the file this window is showing, if one's been set.
+    pub fn represented_url(&self) -> Option<NSURL<'static>> {
+        let url: id = unsafe { msg_send![&*self.objc, representedURL] };
+
+        match url == nil {
+            true => None,
+            false => Some(NSURL::retain(url))
+        }
+    }
+
+    /// Like `set_represented_url()`, but takes a path.
+    pub fn set_represented_filename(&self, path: &str) {
+        unsafe {
+            let path = NSString::new(path);
+            let _: () = msg_send![&*self.objc, setRepresentedFilename: &*path];
+        }
+    }
+
+    /// Sets the file this window is showing (as with `set_represented_filename()`), and titles
+    /// the window after it the way the system does for documents - e.g, `"Report.txt"` rather
+    /// than the whole path.
+    pub fn set_title_with_represented_filename(&self, path: &str) {
+        unsafe {
+            let path = NSString::new(path);
+            let _: () = msg_send![&*self.objc, setTitleWithRepresentedFilename: &*path];
+        }
+    }
+
+    /// Marks the window's document as having unsaved changes (or not). This dims the title bar
+    /// icon and puts a dot in the close button, so users know to save before closing.
+    pub fn set_document_edited(&self, edited: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setDocumentEdited:match edited {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether the window's document is marked as having unsaved changes.
+    pub fn is_document_edited(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isDocumentEdited] })
     }
 
     /// Sets the title visibility for the underlying window.
+    ///
+    /// Does nothing on AppKit implementations that don't support it (e.g, GNUstep).
     pub fn set_title_visibility(&self, visibility: TitleVisibility) {
+        if !responds_to(&self.objc, sel!(setTitleVisibility:)) {
+            return;
+        }
+
         unsafe {
             let v = NSInteger::from(visibility);
             let _: () = msg_send![&*self.objc, setTitleVisibility: v];
@@ -235,7 +361,13 @@ impl<T> Window<T> {
     }
 
     /// Used for setting whether this titlebar appears transparent.
+    ///
+    /// Does nothing on AppKit implementations that don't support it (e.g, GNUstep).
     pub fn set_titlebar_appears_transparent(&self, transparent: bool) {
+        if !responds_to(&self.objc, sel!(setTitlebarAppearsTransparent:)) {
+            return;
+        }
+
         unsafe {
             let _: () = msg_send![&*self.objc, setTitlebarAppearsTransparent:match transparent {
                 true => YES,
@@ -244,6 +376,22 @@ impl<T> Window<T> {
         }
     }
 
+    /// Replaces the style mask for this window. Most styles should be configured up front via
+    /// `WindowConfig`, but some - e.g, `WindowStyle::FullSizeContentView`, which a full-height
+    /// sidebar requires - can be toggled on an existing window.
+    pub fn set_styles(&self, styles: &[WindowStyle]) {
+        let mut style: NSUInteger = 0;
+
+        for mask in styles {
+            let i: NSUInteger = mask.into();
+            style = style | i;
+        }
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setStyleMask: style];
+        }
+    }
+
     /// Used for setting this Window autosave name.
     pub fn set_autosave_name(&self, name: &str) {
         unsafe {
@@ -252,6 +400,66 @@ impl<T> Window<T> {
         }
     }
 
+    /// Returns this window's frame, encoded as a string suitable for persisting wherever you like
+    /// (e.g, alongside a document) and handing back to `restore_frame` later. This is the same
+    /// format that `set_autosave_name` uses under the hood.
+    pub fn saved_frame(&self) -> String {
+        let descriptor = NSString::retain(unsafe { msg_send![&*self.objc, stringWithSavedFrame] });
+        descriptor.to_string()
+    }
+
+    /// Restores a frame previously returned by `saved_frame`.
+    ///
+    /// The frame is only applied if it's (at least partially) on a currently connected screen -
+    /// a window saved on an external display that has since been unplugged would otherwise
+    /// reopen out of reach. Returns whether the frame was applied; if not, the window is left
+    /// where it is, and you'll likely want to `cascade_from_key_window()` or `center()` it.
+    pub fn restore_frame(&self, descriptor: &str) -> bool {
+        let frame = match frame::parse_saved_frame(descriptor) {
+            Some(frame) => frame,
+            None => return false
+        };
+
+        if !frame::is_on_connected_screen(frame) {
+            return false;
+        }
+
+        let descriptor = NSString::new(descriptor);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setFrameFromString: &*descriptor];
+        }
+
+        true
+    }
+
+    /// Centers this window on its screen - slightly above center, as AppKit prefers.
+    pub fn center(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, center];
+        }
+    }
+
+    /// Positions this window down and to the right of the current key window, the way document
+    /// windows are typically staggered. If there's no key window (or this is it), the window is
+    /// centered instead.
+    pub fn cascade_from_key_window(&self) {
+        unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let key_window: id = msg_send![app, keyWindow];
+
+            if key_window == nil || key_window == &*self.objc as *const Object as id {
+                self.center();
+                return;
+            }
+
+            // Cascading from the zero point doesn't move the key window; it just hands back the
+            // point that the next window should cascade from.
+            let next: CGPoint = msg_send![key_window, cascadeTopLeftFromPoint: CGPoint::new(0., 0.)];
+            let _: CGPoint = msg_send![&*self.objc, cascadeTopLeftFromPoint: next];
+        }
+    }
+
     /// Sets the content size for this window.
     pub fn set_content_size<F: Into<f64>>(&self, width: F, height: F) {
         unsafe {
@@ -296,6 +504,13 @@ impl<T> Window<T> {
         unsafe { msg_send_id![&self.objc, toolbar] }
     }
 
+    /// Sets the Touch Bar displayed while this window is key, on Macs that have one.
+    pub fn set_touch_bar<TB: TouchBarDelegate>(&self, touch_bar: &TouchBar<TB>) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTouchBar:&*touch_bar.objc];
+        }
+    }
+
     /// Toggles whether the toolbar is shown for this window. Has no effect if no toolbar exists on
     /// this window.
     pub fn toggle_toolbar_shown(&self) {
@@ -305,11 +520,20 @@ impl<T> Window<T> {
     }
 
     /// Set the toolbar style
-    pub fn set_toolbar_style(&self, style: WindowToolbarStyle) {
+    ///
+    /// Requires macOS 11 (or an AppKit implementation that provides it, e.g GNUstep); returns an
+    /// error (see `Error::is_unsupported_on_this_os()`) otherwise.
+    pub fn set_toolbar_style(&self, style: WindowToolbarStyle) -> Result<(), Error> {
+        if !available!(macos 11) && !responds_to(&self.objc, sel!(setToolbarStyle:)) {
+            return Err(Error::unsupported_on_this_os("Window::set_toolbar_style", "macOS 11"));
+        }
+
         let style: NSUInteger = style.into();
         unsafe {
             let _: () = msg_send![&*self.objc, setToolbarStyle: style];
         }
+
+        Ok(())
     }
 
     /// Sets the window level, which determines the stacking order of windows on the screen.
@@ -327,6 +551,81 @@ impl<T> Window<T> {
         }
     }
 
+    /// Moves the window to the front of its level, without making it key.
+    pub fn order_front(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, orderFront: nil];
+        }
+    }
+
+    /// Moves the window to the back of its level.
+    pub fn order_back(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, orderBack: nil];
+        }
+    }
+
+    /// Moves the window to the front of its level, even if the app isn't active.
+    pub fn order_front_regardless(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, orderFrontRegardless];
+        }
+    }
+
+    /// Moves the window directly in front of or behind `other` (e.g, to keep an inspector just
+    /// behind the document it belongs to).
+    pub fn order_relative_to<W>(&self, ordering: WindowOrdering, other: &Window<W>) {
+        let ordering: NSInteger = ordering.into();
+
+        unsafe {
+            let number: NSInteger = msg_send![&*other.objc, windowNumber];
+            let _: () = msg_send![&*self.objc, orderWindow: ordering, relativeTo: number];
+        }
+    }
+
+    /// Positions the window with its top-left corner at `(x, y)` (in screen coordinates, where y
+    /// grows upwards), nudged to fit on screen, and returns the point the next window should
+    /// cascade from - down and to the right of this one's title bar. Pass `(0., 0.)` to leave the
+    /// window where it is and just get the next point.
+    ///
+    /// For the common case, `WindowConfig::cascade` does this for you.
+    pub fn cascade_top_left_from_point(&self, x: f64, y: f64) -> (f64, f64) {
+        let point = CGPoint::new(x as CGFloat, y as CGFloat);
+
+        let next: CGPoint = unsafe { msg_send![&*self.objc, cascadeTopLeftFromPoint: point] };
+        (next.x as f64, next.y as f64)
+    }
+
+    /// Sets whether this window opens as a tab in an existing window with the same tabbing
+    /// identifier (see `set_tabbing_identifier()`), or on its own.
+    pub fn set_tabbing_mode(&self, mode: WindowTabbingMode) {
+        let mode: NSInteger = mode.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTabbingMode: mode];
+        }
+    }
+
+    /// Sets the identifier that decides which windows can be tabbed together - typically one per
+    /// kind of document.
+    pub fn set_tabbing_identifier(&self, identifier: &str) {
+        let identifier = NSString::new(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTabbingIdentifier: &*identifier];
+        }
+    }
+
+    /// Adds `window` as a tab in this window's tab group, after (`Above`) or before (`Below`)
+    /// this window's tab. As ever, you'll need to keep `window` alive.
+    pub fn add_tabbed_window<W>(&self, window: &Window<W>, ordering: WindowOrdering) {
+        let ordering: NSInteger = ordering.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, addTabbedWindow: &*window.objc, ordered: ordering];
+        }
+    }
+
     /// Set whether the toolbar toggle button is shown. Has no effect if no toolbar exists on this
     /// window.
     pub fn set_shows_toolbar_button(&self, shows: bool) {
@@ -338,6 +637,23 @@ impl<T> Window<T> {
         }
     }
 
+    /// Sets an identifier for this window that UI tests (e.g, XCUITest) can use to find it,
+    /// regardless of its title.
+    pub fn set_accessibility_identifier(&self, identifier: &str) {
+        accessibility::set_accessibility_identifier(&*self.objc as *const Object as id, identifier);
+    }
+
+    /// Returns the identifier set via `set_accessibility_identifier()`, if any.
+    pub fn accessibility_identifier(&self) -> Option<String> {
+        accessibility::accessibility_identifier(&*self.objc as *const Object as id)
+    }
+
+    /// Searches this window's content view (and its descendants) for a view with the given
+    /// accessibility identifier, returning the first match.
+    pub fn find_view_with_accessibility_identifier(&self, identifier: &str) -> Option<Id<Object, Shared>> {
+        unsafe { accessibility::find_view_with_accessibility_identifier(self.content_view(), identifier) }
+    }
+
     /// Return the objc ContentView from the window
     pub(crate) unsafe fn content_view(&self) -> id {
         let id: *mut Object = msg_send![&*self.objc, contentView];
@@ -376,6 +692,91 @@ impl<T> Window<T> {
         }
     }
 
+    /// Shakes the window horizontally, the same way the login window does when given the wrong
+    /// password. Pair this with `appkit::feedback::beep()` to signal an invalid operation.
+    pub fn shake(&self) {
+        let offsets: [CGFloat; 9] = [-10., 10., -10., 10., -6., 6., -3., 3., 0.];
+
+        unsafe {
+            let frame: CGRect = msg_send![&*self.objc, frame];
+            let origin = frame.origin;
+
+            let values: NSArray = offsets
+                .iter()
+                .map(|offset| {
+                    let point = CGPoint::new(origin.x + offset, origin.y);
+                    let value: id = msg_send![class!(NSValue), valueWithPoint: point];
+                    value
+                })
+                .collect::<Vec<id>>()
+                .into();
+
+            let animation: id = msg_send![class!(CAKeyframeAnimation), animation];
+            let _: () = msg_send![animation, setValues: &*values];
+            let _: () = msg_send![animation, setDuration: 0.4 as f64];
+
+            // The shake is installed as the window's `frameOrigin` animation, which would
+            // otherwise stick around and replay on every later animated move - so we put back
+            // whatever was there before once it's done.
+            let previous: Option<Id<Object, Shared>> = Id::retain(msg_send![&*self.objc, animations]);
+
+            let key = NSString::new("frameOrigin");
+            let animations: id = msg_send![class!(NSDictionary), dictionaryWithObject: animation, forKey: &*key];
+            let _: () = msg_send![&*self.objc, setAnimations: animations];
+
+            let window = self.objc.clone();
+            let shake = ConcreteBlock::new(move |_context: id| {
+                let animator: id = msg_send![&*window, animator];
+                let _: () = msg_send![animator, setFrameOrigin: origin];
+            });
+            let shake = shake.copy();
+
+            let window = self.objc.clone();
+            let restore = ConcreteBlock::new(move || {
+                let animations: id = match &previous {
+                    Some(animations) => &**animations as *const Object as id,
+                    None => msg_send![class!(NSDictionary), dictionary]
+                };
+
+                let _: () = msg_send![&*window, setAnimations: animations];
+            });
+            let restore = restore.copy();
+
+            let _: () = msg_send![
+                class!(NSAnimationContext),
+                runAnimationGroup: &*shake,
+                completionHandler: &*restore
+            ];
+        }
+    }
+
+    /// Renders the content of this window into an `Image`, at the window's backing scale factor.
+    /// See `Layout::snapshot()` for more information.
+    ///
+    /// Returns `None` if the window has no content view, or its content view is empty.
+    pub fn snapshot(&self) -> Option<Image> {
+        unsafe {
+            let content_view: id = msg_send![&*self.objc, contentView];
+            if content_view == nil {
+                return None;
+            }
+
+            let bounds: CGRect = msg_send![content_view, bounds];
+            let rep: id = msg_send![content_view, bitmapImageRepForCachingDisplayInRect: bounds];
+            if rep == nil {
+                return None;
+            }
+
+            let _: () = msg_send![content_view, cacheDisplayInRect: bounds, toBitmapImageRep: rep];
+
+            let alloc = msg_send_id![class!(NSImage), alloc];
+            let image: Id<Object, Shared> = msg_send_id![alloc, initWithSize: bounds.size];
+            let _: () = msg_send![&*image, addRepresentation: rep];
+
+            Some(Image(image))
+        }
+    }
+
     /// Toggles a Window being full screen or not.
     pub fn toggle_full_screen(&self) {
         unsafe {
@@ -392,6 +793,165 @@ impl<T> Window<T> {
         }
     }
 
+    /// Sets whether this window is opaque. Windows with rounded or otherwise non-rectangular
+    /// custom chrome need to be non-opaque, with a clear background color.
+    pub fn set_opaque(&self, opaque: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setOpaque:match opaque {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether this window draws a shadow.
+    pub fn set_has_shadow(&self, has_shadow: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setHasShadow:match has_shadow {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether this window draws a shadow.
+    pub fn has_shadow(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, hasShadow] })
+    }
+
+    /// Recomputes the window shadow from its current contents. Call this after changing the shape
+    /// of a non-opaque window (e.g, after `set_corner_radius()`).
+    pub fn invalidate_shadow(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, invalidateShadow];
+        }
+    }
+
+    /// Rounds the corners of this window's content by `radius` points, making the window
+    /// non-opaque with a clear background so that the corners show through, and updating the
+    /// shadow to match.
+    ///
+    /// AppKit has no corner radius setting for windows; this is meant for borderless (custom
+    /// chrome) windows, as titled windows have their corners drawn by the system.
+    pub fn set_corner_radius(&self, radius: f64) {
+        unsafe {
+            let clear: id = msg_send![class!(NSColor), clearColor];
+            let _: () = msg_send![&*self.objc, setOpaque: NO];
+            let _: () = msg_send![&*self.objc, setBackgroundColor: clear];
+
+            let content_view: id = msg_send![&*self.objc, contentView];
+            if content_view != nil {
+                let _: () = msg_send![content_view, setWantsLayer: YES];
+
+                let layer: id = msg_send![content_view, layer];
+                let _: () = msg_send![layer, setCornerRadius: radius as CGFloat];
+                let _: () = msg_send![layer, setMasksToBounds: YES];
+            }
+
+            let _: () = msg_send![&*self.objc, invalidateShadow];
+        }
+    }
+
+    /// Toggles `WindowStyle::FullSizeContentView`, which extends the content view underneath
+    /// the titlebar (and toolbar). Pair this with `set_titlebar_appears_transparent()` and
+    /// `set_title_visibility()` for custom titlebars, and use `content_layout_rect()` to keep
+    /// content clear of the titlebar where needed.
+    pub fn set_full_size_content_view(&self, full_size: bool) {
+        let full_size_mask: NSUInteger = WindowStyle::FullSizeContentView.into();
+
+        unsafe {
+            let mask: NSUInteger = msg_send![&*self.objc, styleMask];
+            let mask = match full_size {
+                true => mask | full_size_mask,
+                false => mask & !full_size_mask
+            };
+
+            let _: () = msg_send![&*self.objc, setStyleMask: mask];
+        }
+    }
+
+    /// Returns the area of the content view that isn't covered by the titlebar or toolbar, in
+    /// window coordinates. With a full size content view, this is where your content should go
+    /// (or where it should be inset to).
+    pub fn content_layout_rect(&self) -> Rect {
+        let rect: CGRect = unsafe { msg_send![&*self.objc, contentLayoutRect] };
+        rect.into()
+    }
+
+    /// Returns the height of the titlebar (including any toolbar), i.e how much a full size
+    /// content view needs to be inset at the top.
+    pub fn titlebar_height(&self) -> f64 {
+        unsafe {
+            let frame: CGRect = msg_send![&*self.objc, frame];
+            let content_rect: CGRect = msg_send![&*self.objc, contentRectForFrameRect: frame];
+            let layout_rect: CGRect = msg_send![&*self.objc, contentLayoutRect];
+
+            // With a full size content view, the content rect covers the titlebar too; the layout
+            // rect never does.
+            (content_rect.size.height - layout_rect.size.height) as f64
+        }
+    }
+
+    /// Shows or hides one of the standard titlebar buttons (the "traffic lights").
+    pub fn set_button_hidden(&self, button: WindowButton, hidden: bool) {
+        let button: NSUInteger = button.into();
+
+        unsafe {
+            let button: id = msg_send![&*self.objc, standardWindowButton: button];
+
+            if button != nil {
+                let _: () = msg_send![button, setHidden:match hidden {
+                    true => YES,
+                    false => NO
+                }];
+            }
+        }
+    }
+
+    /// Moves the close, miniaturize and zoom buttons so that the close button sits `x` points from
+    /// the left edge of the window and `y` points from the top, keeping their usual spacing. The
+    /// titlebar area grows to fit, which is handy for custom titlebars that are taller than the
+    /// standard one.
+    ///
+    /// AppKit lays the buttons out again whenever the window resizes or leaves full screen, so the
+    /// position is reapplied for as long as the returned `WindowButtonsPosition` is kept around.
+    pub fn set_buttons_position(&self, x: f64, y: f64) -> WindowButtonsPosition {
+        let (x, y) = (x as CGFloat, y as CGFloat);
+        position_window_buttons(&*self.objc as *const Object as id, x, y);
+
+        let names = [
+            "NSWindowDidResizeNotification",
+            "NSWindowDidEndLiveResizeNotification",
+            "NSWindowDidExitFullScreenNotification"
+        ];
+
+        let observers = names
+            .iter()
+            .map(|name| {
+                let name = NSString::new(name);
+                let block = ConcreteBlock::new(move |notification: id| {
+                    let window: id = unsafe { msg_send![notification, object] };
+                    position_window_buttons(window, x, y);
+                });
+                let block = block.copy();
+
+                unsafe {
+                    let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+
+                    msg_send_id![
+                        center,
+                        addObserverForName: &*name,
+                        object: &*self.objc,
+                        queue: nil,
+                        usingBlock: &*block
+                    ]
+                }
+            })
+            .collect();
+
+        WindowButtonsPosition(observers)
+    }
+
     /// Returns whether this window is opaque or not.
     pub fn is_opaque(&self) -> bool {
         to_bool(unsafe { msg_send![&*self.objc, isOpaque] })
@@ -416,6 +976,32 @@ impl<T> Window<T> {
         }
     }
 
+    /// Moves keyboard focus to `view` (e.g, a `TextField`), returning whether it accepted it.
+    /// The view must be in this window.
+    pub fn make_first_responder<V: ObjcAccess>(&self, view: &V) -> bool {
+        view.get_from_backing_obj(|obj| to_bool(unsafe { msg_send![&*self.objc, makeFirstResponder: obj] }))
+    }
+
+    /// Takes keyboard focus away from whatever has it, leaving the window itself as the first
+    /// responder. Returns `false` if the current first responder refused to give it up (e.g, a
+    /// text field holding invalid input).
+    pub fn clear_first_responder(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, makeFirstResponder: nil] })
+    }
+
+    /// Zooms the window to its standard frame (see `WindowDelegate::standard_frame()`), or back to
+    /// where it was if it's already zoomed - the same as Option-clicking the green button.
+    pub fn zoom(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, zoom: nil];
+        }
+    }
+
+    /// Returns whether this window is zoomed (i.e, at its standard frame).
+    pub fn is_zoomed(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isZoomed] })
+    }
+
     /// Runs the print panel, and if the user does anything except cancel, prints the window and
     /// its contents.
     pub fn print(&self) {
@@ -446,6 +1032,14 @@ impl<T> Window<T> {
         to_bool(unsafe { msg_send![&*self.objc, isKeyWindow] })
     }
 
+    /// Returns whether any part of this window is currently visible to the user. Unlike
+    /// `is_visible()`, this accounts for the window being covered by other windows, being on
+    /// another Space, and so on.
+    pub fn is_occlusion_visible(&self) -> bool {
+        let state: NSUInteger = unsafe { msg_send![&*self.objc, occlusionState] };
+        state & NS_WINDOW_OCCLUSION_STATE_VISIBLE != 0
+    }
+
     /// Returns whether this window can become the key window.
     pub fn can_become_key(&self) -> bool {
         to_bool(unsafe { msg_send![&*self.objc, canBecomeKeyWindow] })
@@ -486,11 +1080,57 @@ impl<T> Window<T> {
         }
     }
 
+    /// Sets the separator style for this window, from a raw `NSTitlebarSeparatorStyle` value.
+    #[deprecated(note = "Use `set_titlebar_separator` with a `TitlebarSeparatorStyle` instead.")]
+    pub fn set_titlebar_separator_style(&self, style: NSInteger) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTitlebarSeparatorStyle: style];
+        }
+    }
+
     /// Sets the separator style for this window.
-    pub fn set_titlebar_separator_style(&self, style: crate::foundation::NSInteger) {
+    ///
+    /// Requires macOS 11 (or an AppKit implementation that provides it, e.g GNUstep); returns an
+    /// error (see `Error::is_unsupported_on_this_os()`) otherwise.
+    pub fn set_titlebar_separator(&self, style: TitlebarSeparatorStyle) -> Result<(), Error> {
+        if !available!(macos 11) && !responds_to(&self.objc, sel!(setTitlebarSeparatorStyle:)) {
+            return Err(Error::unsupported_on_this_os("Window::set_titlebar_separator", "macOS 11"));
+        }
+
+        let style: NSInteger = style.into();
+
         unsafe {
             let _: () = msg_send![&*self.objc, setTitlebarSeparatorStyle: style];
         }
+
+        Ok(())
+    }
+
+    /// Forces this window (and everything in it) into `appearance`, regardless of the system
+    /// setting - e.g, for a media viewer that should always be dark.
+    pub fn set_appearance(&self, appearance: Appearance) {
+        let appearance = appearance.to_appearance();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAppearance: appearance];
+        }
+    }
+
+    /// Removes an appearance set with `set_appearance`, so that this window follows the
+    /// application (and system) appearance again.
+    pub fn clear_appearance(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAppearance: nil];
+        }
+    }
+
+    /// Returns the appearance this window is actually drawn with, taking any override into account.
+    pub fn effective_appearance(&self) -> Appearance {
+        if !available!(macos 10.14) {
+            return Appearance::Light;
+        }
+
+        Appearance::from_appearance(unsafe { msg_send![&*self.objc, effectiveAppearance] })
     }
 
     /// Returns the backing scale (e.g, `1.0` for non retina, `2.0` for retina) used on this
@@ -535,6 +1175,159 @@ impl<T> Window<T> {
             let _: () = msg_send![&*self.objc, endSheet:&*window.objc];
         }
     }
+
+    /// Like `begin_sheet()`, but waits for any sheet queued before it: each queued sheet is only
+    /// presented once the previous one has been dismissed, and completion handlers run in order.
+    /// Use this when several confirmations might pile up on the same window.
+    ///
+    /// As with `begin_sheet()`, you'll need to keep `window` alive until it's been dismissed.
+    pub fn queue_sheet<F, W>(&self, window: &Window<W>, completion: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+        W: WindowDelegate + 'static
+    {
+        sheets::enqueue(&self.objc, &window.objc, completion);
+    }
+
+    /// Presents `window` as a critical sheet: it's shown right away, on top of any sheet that's
+    /// already attached (which resumes once this is dismissed). Reserve this for things the user
+    /// must deal with immediately, such as an error that stops the current operation.
+    pub fn begin_critical_sheet<F, W>(&self, window: &Window<W>, completion: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+        W: WindowDelegate + 'static
+    {
+        let block = ConcreteBlock::new(move |_response: NSInteger| {
+            completion();
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, beginCriticalSheet: &*window.objc, completionHandler: &*block];
+        }
+    }
+
+    /// Returns the sheet that's currently showing on this window, if any.
+    pub fn attached_sheet(&self) -> Option<Window> {
+        unsafe {
+            let sheet: id = msg_send![&*self.objc, attachedSheet];
+
+            match sheet == nil {
+                true => None,
+                false => Some(Window::existing(sheet))
+            }
+        }
+    }
+
+    /// Returns the sheets attached to this window (including ones AppKit is holding back), in the
+    /// order they were begun. Sheets waiting in the `queue_sheet()` queue aren't included; see
+    /// `queued_sheet_count()`.
+    pub fn sheets(&self) -> Vec<Window> {
+        let sheets = NSArray::retain(unsafe { msg_send![&*self.objc, sheets] });
+        sheets.iter().map(|sheet| unsafe { Window::existing(sheet) }).collect()
+    }
+
+    /// Returns how many sheets are waiting in the `queue_sheet()` queue, behind the one showing.
+    pub fn queued_sheet_count(&self) -> usize {
+        sheets::pending_count(&*self.objc as *const Object as id)
+    }
+
+    /// Drops any sheets waiting in the `queue_sheet()` queue, without showing them or running
+    /// their completion handlers. Whatever's showing now is left alone.
+    pub fn cancel_queued_sheets(&self) {
+        sheets::cancel_pending(&*self.objc as *const Object as id);
+    }
+
+    /// Cancels any queued sheets, then ends every sheet attached to this window, newest first.
+    /// Completion handlers run for the sheets that were attached.
+    pub fn end_all_sheets(&self) {
+        self.cancel_queued_sheets();
+
+        let sheets = NSArray::retain(unsafe { msg_send![&*self.objc, sheets] });
+        let sheets: Vec<id> = sheets.iter().collect();
+
+        for sheet in sheets.into_iter().rev() {
+            unsafe {
+                let _: () = msg_send![&*self.objc, endSheet: sheet];
+            }
+        }
+    }
+}
+
+/// Keeps the titlebar buttons of a window where `Window::set_buttons_position()` put them.
+/// Dropping this leaves the buttons where they are, but AppKit will move them back the next time
+/// the window resizes.
+#[derive(Debug)]
+pub struct WindowButtonsPosition(Vec<Id<Object, Shared>>);
+
+impl Drop for WindowButtonsPosition {
+    fn drop(&mut self) {
+        unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+
+            for observer in &self.0 {
+                let _: () = msg_send![center, removeObserver: &**observer];
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// Where the next window created with `WindowConfig::cascade` set should put its top-left
+    /// corner. This starts out as the zero point, which leaves the first window where it is.
+    static NEXT_CASCADE_POINT: Cell<CGPoint> = Cell::new(CGPoint::new(0., 0.));
+}
+
+/// Cascades a newly created `window` from the previous one, and remembers where the next should go.
+fn cascade_new_window(window: id) {
+    NEXT_CASCADE_POINT.with(|point| {
+        let next: CGPoint = unsafe { msg_send![window, cascadeTopLeftFromPoint: point.get()] };
+        point.set(next);
+    });
+}
+
+/// Lays out the close, miniaturize and zoom buttons of `window` with the close button `x` points
+/// from the left and `y` points from the top, growing the titlebar container to fit.
+fn position_window_buttons(window: id, x: CGFloat, y: CGFloat) {
+    unsafe {
+        let buttons: Vec<id> = [WindowButton::Close, WindowButton::Miniaturize, WindowButton::Zoom]
+            .iter()
+            .map(|button| msg_send![window, standardWindowButton: NSUInteger::from(*button)])
+            .collect();
+
+        if buttons.iter().any(|button| *button == nil) {
+            return;
+        }
+
+        // The buttons live in the titlebar view, which lives in the titlebar container.
+        let titlebar_view: id = msg_send![buttons[0], superview];
+        let container: id = if titlebar_view != nil {
+            msg_send![titlebar_view, superview]
+        } else {
+            nil
+        };
+        if container == nil {
+            return;
+        }
+
+        let close_frame: CGRect = msg_send![buttons[0], frame];
+        let miniaturize_frame: CGRect = msg_send![buttons[1], frame];
+        let spacing = miniaturize_frame.origin.x - close_frame.origin.x;
+        let button_height = close_frame.size.height;
+
+        // Center the buttons vertically in a titlebar that's `y` taller than them on each side.
+        let titlebar_height = button_height + y * 2.;
+        let window_frame: CGRect = msg_send![window, frame];
+        let mut container_frame: CGRect = msg_send![container, frame];
+        container_frame.size.height = titlebar_height;
+        container_frame.origin.y = window_frame.size.height - titlebar_height;
+        let _: () = msg_send![container, setFrame: container_frame];
+
+        for (index, button) in buttons.iter().enumerate() {
+            let origin = CGPoint::new(x + spacing * index as CGFloat, y);
+            let _: () = msg_send![*button, setFrameOrigin: origin];
+        }
+    }
 }
 
 impl<T> Drop for Window<T> {
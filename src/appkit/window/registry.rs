@@ -0,0 +1,170 @@
+//! A registry for keeping track of multiple windows by identifier.
+//!
+//! Most apps end up with a handful of windows (a main window, preferences, an about window, and
+//! so on), and without something like this you wind up threading `RwLock<Option<Window<T>>>`
+//! fields through your `AppDelegate` for each one. `WindowRegistry` lets you hand a `Window<T>`
+//! off under a string identifier, and then look it up, show it, or close it from anywhere on the
+//! main thread.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::window::{Window, WindowConfig, WindowRegistry};
+//!
+//! WindowRegistry::register("preferences", Window::new(WindowConfig::default()));
+//!
+//! // Later, e.g from a menu item handler...
+//! WindowRegistry::show("preferences");
+//! ```
+//!
+//! Windows are automatically removed from the registry when they close.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use block::ConcreteBlock;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::appkit::window::Window;
+use crate::foundation::{id, nil, NSString};
+
+/// An entry in the registry. We keep the `NSWindow` pointer around separately so that we can
+/// show/close windows without needing to know their delegate type.
+#[derive(Debug)]
+struct RegistryEntry {
+    pub objc: Id<Object, Shared>,
+    pub window: Rc<dyn Any>,
+    pub close_observer: Id<Object, Shared>
+}
+
+impl Drop for RegistryEntry {
+    /// Removes the close observer from the notification center.
+    fn drop(&mut self) {
+        unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+            let _: () = msg_send![center, removeObserver: &*self.close_observer];
+        }
+    }
+}
+
+thread_local! {
+    /// Windows are main-thread only, so the registry is too.
+    static WINDOWS: RefCell<HashMap<String, RegistryEntry>> = RefCell::new(HashMap::new());
+}
+
+/// A main-thread registry of windows, keyed by string identifiers.
+///
+/// Windows are stored as `Rc<Window<T>>`; the registry holds one reference, and `register` and
+/// `get` hand out others. When a registered window closes, the registry drops its reference.
+#[derive(Debug)]
+pub struct WindowRegistry;
+
+impl WindowRegistry {
+    /// Registers a window under the given identifier, replacing (and releasing) any window
+    /// previously registered under it. The identifier is also set as the `NSWindow` identifier.
+    ///
+    /// Returns a reference-counted handle to the window, which you're free to keep or drop.
+    pub fn register<T: 'static>(identifier: &str, window: Window<T>) -> Rc<Window<T>> {
+        let objc = window.objc.clone();
+        let window = Rc::new(window);
+
+        let key = identifier.to_string();
+        let block = ConcreteBlock::new(move |_notification: id| {
+            WindowRegistry::remove(&key);
+        });
+        let block = block.copy();
+
+        let close_observer: Id<Object, Shared> = unsafe {
+            let ns_identifier = NSString::new(identifier);
+            let _: () = msg_send![&*objc, setIdentifier: &*ns_identifier];
+
+            let name = NSString::new("NSWindowWillCloseNotification");
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+            msg_send_id![
+                center,
+                addObserverForName: &*name,
+                object: &*objc,
+                queue: nil,
+                usingBlock: &*block
+            ]
+        };
+
+        let entry = RegistryEntry {
+            objc,
+            window: window.clone(),
+            close_observer
+        };
+
+        // Pull any previous entry out before dropping it, so that we're not holding the borrow
+        // while its window (and delegate) are torn down.
+        let previous = WINDOWS.with(|windows| windows.borrow_mut().insert(identifier.to_string(), entry));
+        drop(previous);
+
+        window
+    }
+
+    /// Returns the window registered under the given identifier, provided it exists and its
+    /// delegate is of type `T`.
+    pub fn get<T: 'static>(identifier: &str) -> Option<Rc<Window<T>>> {
+        let window = WINDOWS.with(|windows| windows.borrow().get(identifier).map(|entry| entry.window.clone()))?;
+        window.downcast::<Window<T>>().ok()
+    }
+
+    /// Returns whether a window is registered under the given identifier.
+    pub fn contains(identifier: &str) -> bool {
+        WINDOWS.with(|windows| windows.borrow().contains_key(identifier))
+    }
+
+    /// Returns the identifiers of all currently registered windows.
+    pub fn identifiers() -> Vec<String> {
+        WINDOWS.with(|windows| windows.borrow().keys().cloned().collect())
+    }
+
+    /// Shows (and makes key) the window registered under the given identifier. Returns `false` if
+    /// no such window is registered.
+    pub fn show(identifier: &str) -> bool {
+        match WindowRegistry::objc(identifier) {
+            Some(objc) => {
+                unsafe {
+                    let _: () = msg_send![&*objc, makeKeyAndOrderFront: nil];
+                }
+
+                true
+            },
+
+            None => false
+        }
+    }
+
+    /// Closes the window registered under the given identifier, which also removes it from the
+    /// registry. Returns `false` if no such window is registered.
+    pub fn close(identifier: &str) -> bool {
+        match WindowRegistry::objc(identifier) {
+            Some(objc) => {
+                unsafe {
+                    let _: () = msg_send![&*objc, close];
+                }
+
+                true
+            },
+
+            None => false
+        }
+    }
+
+    /// Removes the window registered under the given identifier from the registry, without
+    /// closing it. Returns `false` if no such window is registered.
+    pub fn remove(identifier: &str) -> bool {
+        let entry = WINDOWS.with(|windows| windows.borrow_mut().remove(identifier));
+        entry.is_some()
+    }
+
+    /// Clones out the backing `NSWindow` for an identifier. We do this rather than messaging it
+    /// while borrowed, as closing fires the notification that mutates the registry.
+    fn objc(identifier: &str) -> Option<Id<Object, Shared>> {
+        WINDOWS.with(|windows| windows.borrow().get(identifier).map(|entry| entry.objc.clone()))
+    }
+}
@@ -0,0 +1,125 @@
+//! A per-window queue for sheets, backing `Window::queue_sheet()`.
+//!
+//! AppKit will hold back a sheet begun while another is attached, but nothing tells you which
+//! one is showing or what's still waiting, and a critical sheet jumps ahead of all of them. This
+//! keeps queued sheets on the Rust side instead, and only begins the next one once the previous
+//! one has ended - so completion handlers run in order, and pending sheets can be cancelled.
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+
+use block::ConcreteBlock;
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{msg_send, sel};
+
+use crate::foundation::{id, NSInteger};
+
+/// A sheet waiting for its turn, along with the handler to run once it's dismissed.
+struct QueuedSheet {
+    sheet: Id<Object, Shared>,
+    completion: Box<dyn Fn() + 'static>
+}
+
+/// The queue for a single parent window.
+#[derive(Default)]
+struct SheetQueue {
+    presenting: bool,
+    pending: VecDeque<QueuedSheet>
+}
+
+thread_local! {
+    /// Sheet queues, keyed by the address of the parent `NSWindow`. Windows are main-thread only,
+    /// so these are too. Entries are removed once a queue runs dry.
+    static SHEET_QUEUES: RefCell<HashMap<usize, SheetQueue>> = RefCell::new(HashMap::new());
+}
+
+/// Adds `sheet` to the queue for `parent`, beginning it right away if no queued sheet is showing.
+pub(crate) fn enqueue<F: Fn() + 'static>(parent: &Id<Object, Shared>, sheet: &Id<Object, Shared>, completion: F) {
+    let key = &**parent as *const Object as usize;
+
+    let entry = QueuedSheet {
+        sheet: sheet.clone(),
+        completion: Box::new(completion)
+    };
+
+    let begin_now = SHEET_QUEUES.with(|queues| {
+        let mut queues = queues.borrow_mut();
+        let queue = queues.entry(key).or_default();
+
+        match queue.presenting {
+            true => {
+                queue.pending.push_back(entry);
+                None
+            },
+
+            false => {
+                queue.presenting = true;
+                Some(entry)
+            }
+        }
+    });
+
+    if let Some(entry) = begin_now {
+        begin(parent.clone(), entry);
+    }
+}
+
+/// Begins `entry` on `parent`; when it ends, runs its handler and moves on to the next one.
+fn begin(parent: Id<Object, Shared>, entry: QueuedSheet) {
+    let QueuedSheet { sheet, completion } = entry;
+    let window = parent.clone();
+
+    let block = ConcreteBlock::new(move |_response: NSInteger| {
+        completion();
+        advance(&window);
+    });
+    let block = block.copy();
+
+    unsafe {
+        let _: () = msg_send![&*parent, beginSheet: &*sheet, completionHandler: &*block];
+    }
+}
+
+/// Begins the next queued sheet for `parent`, if there is one.
+fn advance(parent: &Id<Object, Shared>) {
+    let key = &**parent as *const Object as usize;
+
+    let next = SHEET_QUEUES.with(|queues| {
+        let mut queues = queues.borrow_mut();
+        let next = queues.get_mut(&key).and_then(|queue| queue.pending.pop_front());
+
+        if next.is_none() {
+            queues.remove(&key);
+        }
+
+        next
+    });
+
+    if let Some(entry) = next {
+        begin(parent.clone(), entry);
+    }
+}
+
+/// Returns how many sheets are waiting behind the one showing for `parent`.
+pub(crate) fn pending_count(parent: id) -> usize {
+    SHEET_QUEUES.with(|queues| {
+        queues
+            .borrow()
+            .get(&(parent as usize))
+            .map(|queue| queue.pending.len())
+            .unwrap_or(0)
+    })
+}
+
+/// Drops the sheets waiting for `parent` without showing them; their handlers aren't run. A
+/// queued sheet that's already showing is left alone.
+pub(crate) fn cancel_pending(parent: id) {
+    // Dropping the handlers could drop windows; do that outside of the borrow.
+    let _cancelled = SHEET_QUEUES.with(|queues| {
+        queues
+            .borrow_mut()
+            .get_mut(&(parent as usize))
+            .map(|queue| std::mem::take(&mut queue.pending))
+    });
+}
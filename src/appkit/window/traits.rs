@@ -4,6 +4,7 @@
 
 use crate::appkit::app::PresentationOption;
 use crate::appkit::window::Window;
+use crate::geometry::Rect;
 
 /// Lifecycle events for anything that `impl Window`'s. These map to the standard Cocoa
 /// lifecycle methods, but mix in a few extra things to handle offering configuration tools
@@ -73,6 +74,14 @@ pub trait WindowDelegate {
     /// such as rendering in retina vs non-retina environments. It's rare to need this though.
     fn did_change_backing_properties(&self) {}
 
+    /// Fired when the backing scale factor of the window changes, e.g when it's dragged from a
+    /// Retina display to a non-Retina one. This fires in addition to
+    /// `did_change_backing_properties`, and only when the scale factor actually changed.
+    ///
+    /// If you're rendering into layers or bitmaps yourself, this is where you'd re-render at the
+    /// new scale.
+    fn did_change_backing_scale_factor(&self, _old_scale: f64, _new_scale: f64) {}
+
     /// Fires when this window is about to become the key window.
     fn did_become_key(&self) {}
 
@@ -104,10 +113,28 @@ pub trait WindowDelegate {
     /// Specify options for when this window goes full screen.
     /// By default, this returns `None`, which tells the system to proceed as it normally would
     /// without customization.
+    ///
+    /// The options must include `PresentationOption::FullScreen`, and hiding the menu bar
+    /// requires hiding the Dock too (e.g, `AutoHideDock` with `AutoHideMenuBar`); otherwise the
+    /// system raises an exception.
     fn presentation_options_for_full_screen(&self) -> Option<&[PresentationOption]> {
         None
     }
 
+    /// Fires when the user zooms the window (e.g, double clicking the title bar, or Option-clicking
+    /// the green button), to find the frame it should zoom to. `default_frame` (in screen
+    /// coordinates) covers the whole visible screen; return something smaller (e.g, the size
+    /// your content actually needs) to have zooming fit the window to its content instead.
+    fn standard_frame(&self, default_frame: Rect) -> Rect {
+        default_frame
+    }
+
+    /// Fires before the window zooms to `frame` (its standard frame, or back to where it was).
+    /// Return `false` to prevent it.
+    fn should_zoom(&self, _frame: Rect) -> bool {
+        true
+    }
+
     /// Fires when this window is about to go full screen.
     fn will_enter_full_screen(&self) {}
 
@@ -130,12 +157,30 @@ pub trait WindowDelegate {
     /// app-level event, just for a Window.
     fn did_change_occlusion_state(&self) {}
 
+    /// Fired alongside `did_change_occlusion_state`, passing whether any part of the window is
+    /// now visible. You can use this to pause expensive work (animations, polling) while the window
+    /// is completely hidden.
+    fn did_change_occlusion_visibility(&self, _is_visible: bool) {}
+
     /// Fired when the Window receives a `didExpose` message from higher up in the chain.
     fn did_expose(&self) {}
 
     /// Fired when the Window receives an `update` message from higher up in the chain.
     fn did_update(&self) {}
 
+    /// Called when the user Command-clicks the title of a window showing a file (see
+    /// `Window::set_represented_url()`). Return `false` to not show the menu of folders
+    /// containing it.
+    fn should_show_document_path_menu(&self) -> bool {
+        true
+    }
+
+    /// Called when the user starts dragging the title bar icon of a window showing a file. Return
+    /// `false` to not let the file be dragged elsewhere.
+    fn should_drag_document(&self) -> bool {
+        true
+    }
+
     /// If you want your window to close when the `ESC` key is hit, implement this.
     /// This is mostly useful for windows that present as modal sheets.
     fn cancel(&self) {}
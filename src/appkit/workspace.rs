@@ -0,0 +1,326 @@
+//! Wraps `NSWorkspace`, for working with files (and other apps) the way Finder does.
+
+use block::ConcreteBlock;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::appkit::running_application::RunningApplication;
+use crate::available;
+use crate::color::Color;
+use crate::error::Error;
+use crate::foundation::{id, nil, to_bool, NSArray, NSMutableDictionary, NSString, BOOL, NO, NSURL, YES};
+use crate::image::Image;
+
+/// A `(original, new)` pair of file URLs, as reported by `Workspace::duplicate_urls()` and
+/// `Workspace::recycle_urls()`.
+pub type MovedURL = (NSURL<'static>, NSURL<'static>);
+
+/// Options for opening URLs and applications, mirroring `NSWorkspaceOpenConfiguration`. The
+/// `Default` matches the system defaults.
+#[derive(Clone, Debug)]
+pub struct OpenConfiguration {
+    /// Whether to bring the app to the foreground. Defaults to `true`.
+    pub activates: bool,
+
+    /// Whether to hide the app (and its windows) once opened. Defaults to `false`.
+    pub hides: bool,
+
+    /// Whether opened documents are added to the Recent Items menu. Defaults to `true`.
+    pub adds_to_recent_items: bool,
+
+    /// Whether to launch a new instance of the app, even if one is already running. Defaults to
+    /// `false`.
+    pub creates_new_application_instance: bool,
+
+    /// Whether the user may be prompted (e.g, to pick an app to open with). Defaults to `true`.
+    pub prompts_user_if_needed: bool,
+
+    /// Command line arguments passed to the app, if this launches it.
+    pub arguments: Vec<String>
+}
+
+impl Default for OpenConfiguration {
+    fn default() -> Self {
+        OpenConfiguration {
+            activates: true,
+            hides: false,
+            adds_to_recent_items: true,
+            creates_new_application_instance: false,
+            prompts_user_if_needed: true,
+            arguments: Vec::new()
+        }
+    }
+}
+
+impl OpenConfiguration {
+    /// Builds the `NSWorkspaceOpenConfiguration` for these options.
+    fn to_objc(&self) -> Id<Object, Shared> {
+        let flag = |value: bool| match value {
+            true => YES,
+            false => NO
+        };
+
+        let arguments: Vec<NSString> = self.arguments.iter().map(|argument| NSString::new(argument)).collect();
+        let arguments = NSArray::from_retainables(&arguments);
+
+        unsafe {
+            let configuration: Id<Object, Shared> = msg_send_id![class!(NSWorkspaceOpenConfiguration), configuration];
+            let _: () = msg_send![&*configuration, setActivates: flag(self.activates)];
+            let _: () = msg_send![&*configuration, setHides: flag(self.hides)];
+            let _: () = msg_send![&*configuration, setAddsToRecentItems: flag(self.adds_to_recent_items)];
+            let _: () = msg_send![&*configuration, setCreatesNewApplicationInstance: flag(self.creates_new_application_instance)];
+            let _: () = msg_send![&*configuration, setPromptsUserIfNeeded: flag(self.prompts_user_if_needed)];
+            let _: () = msg_send![&*configuration, setArguments: &*arguments];
+            configuration
+        }
+    }
+}
+
+/// Wraps the shared `NSWorkspace`.
+///
+/// Completion callbacks can come back on a separate thread, so react accordingly to get to the
+/// main thread if you need to.
+#[derive(Debug)]
+pub struct Workspace(pub Id<Object, Shared>);
+
+impl Default for Workspace {
+    /// Returns the shared workspace.
+    fn default() -> Self {
+        Workspace(unsafe { msg_send_id![class!(NSWorkspace), sharedWorkspace] })
+    }
+}
+
+impl Workspace {
+    /// Duplicates the files at `urls`, as Finder's "Duplicate" command does. On success, the
+    /// completion receives each original URL paired with the URL of its copy.
+    pub fn duplicate_urls<F>(&self, urls: &[NSURL], completion: F)
+    where
+        F: Fn(Result<Vec<MovedURL>, Error>) + Send + Sync + 'static
+    {
+        let urls = NSArray::from_retainables(urls);
+        let block = moved_urls_block(completion);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, duplicateURLs: &*urls, completionHandler: &*block];
+        }
+    }
+
+    /// Moves the files at `urls` to the Trash. On success, the completion receives each original
+    /// URL paired with its URL in the Trash.
+    pub fn recycle_urls<F>(&self, urls: &[NSURL], completion: F)
+    where
+        F: Fn(Result<Vec<MovedURL>, Error>) + Send + Sync + 'static
+    {
+        let urls = NSArray::from_retainables(urls);
+        let block = moved_urls_block(completion);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, recycleURLs: &*urls, completionHandler: &*block];
+        }
+    }
+
+    /// Opens `url` with the default app for it (e.g, a browser for web URLs).
+    ///
+    /// Requires macOS 10.15; on anything prior, the completion receives an error (see
+    /// `Error::is_unsupported_on_this_os()`).
+    pub fn open_url<F>(&self, url: &NSURL, configuration: &OpenConfiguration, completion: F)
+    where
+        F: Fn(Result<RunningApplication, Error>) + Send + Sync + 'static
+    {
+        if !available!(macos 10.15) {
+            completion(Err(Error::unsupported_on_this_os("Workspace::open_url", "macOS 10.15")));
+            return;
+        }
+
+        let configuration = configuration.to_objc();
+        let block = open_block(completion);
+
+        unsafe {
+            let _: () = msg_send![
+                &*self.0,
+                openURL: &*url.objc,
+                configuration: &*configuration,
+                completionHandler: &*block
+            ];
+        }
+    }
+
+    /// Opens `urls` with the app at `application_url`.
+    ///
+    /// Requires macOS 10.15; on anything prior, the completion receives an error (see
+    /// `Error::is_unsupported_on_this_os()`).
+    pub fn open_urls_with_application<F>(
+        &self,
+        urls: &[NSURL],
+        application_url: &NSURL,
+        configuration: &OpenConfiguration,
+        completion: F
+    ) where
+        F: Fn(Result<RunningApplication, Error>) + Send + Sync + 'static
+    {
+        if !available!(macos 10.15) {
+            completion(Err(Error::unsupported_on_this_os(
+                "Workspace::open_urls_with_application",
+                "macOS 10.15"
+            )));
+            return;
+        }
+
+        let urls = NSArray::from_retainables(urls);
+        let configuration = configuration.to_objc();
+        let block = open_block(completion);
+
+        unsafe {
+            let _: () = msg_send![
+                &*self.0,
+                openURLs: &*urls,
+                withApplicationAtURL: &*application_url.objc,
+                configuration: &*configuration,
+                completionHandler: &*block
+            ];
+        }
+    }
+
+    /// Launches (or, if it's already running, activates) the app at `application_url`.
+    ///
+    /// Requires macOS 10.15; on anything prior, the completion receives an error (see
+    /// `Error::is_unsupported_on_this_os()`).
+    pub fn open_application<F>(&self, application_url: &NSURL, configuration: &OpenConfiguration, completion: F)
+    where
+        F: Fn(Result<RunningApplication, Error>) + Send + Sync + 'static
+    {
+        if !available!(macos 10.15) {
+            completion(Err(Error::unsupported_on_this_os(
+                "Workspace::open_application",
+                "macOS 10.15"
+            )));
+            return;
+        }
+
+        let configuration = configuration.to_objc();
+        let block = open_block(completion);
+
+        unsafe {
+            let _: () = msg_send![
+                &*self.0,
+                openApplicationAtURL: &*application_url.objc,
+                configuration: &*configuration,
+                completionHandler: &*block
+            ];
+        }
+    }
+
+    /// Returns the icon Finder shows for the file at `path`.
+    pub fn icon_for_file(&self, path: &str) -> Image {
+        let path = NSString::new(path);
+        Image::with(unsafe { msg_send![&*self.0, iconForFile: &*path] })
+    }
+
+    /// Returns the URL of the desktop image for the main screen, if there is one.
+    pub fn desktop_image_url(&self) -> Option<NSURL<'static>> {
+        unsafe {
+            let screen: id = msg_send![class!(NSScreen), mainScreen];
+            let url: id = msg_send![&*self.0, desktopImageURLForScreen: screen];
+
+            match url == nil {
+                true => None,
+                false => Some(NSURL::retain(url))
+            }
+        }
+    }
+
+    /// Sets the desktop image for the main screen to the image file at `url`.
+    pub fn set_desktop_image_url(&self, url: &NSURL) -> Result<(), Error> {
+        let options = NSMutableDictionary::new();
+
+        unsafe {
+            let screen: id = msg_send![class!(NSScreen), mainScreen];
+            let mut error: id = nil;
+            let success: BOOL = msg_send![
+                &*self.0,
+                setDesktopImageURL: &*url.objc,
+                forScreen: screen,
+                options: &*options,
+                error: &mut error,
+            ];
+
+            match to_bool(success) {
+                true => Ok(()),
+                false => Err(Error::new(error))
+            }
+        }
+    }
+
+    /// Unmounts and ejects the volume at `url` (e.g, a USB drive or disk image).
+    pub fn unmount_and_eject(&self, url: &NSURL) -> Result<(), Error> {
+        unsafe {
+            let mut error: id = nil;
+            let success: BOOL = msg_send![&*self.0, unmountAndEjectDeviceAtURL: &*url.objc, error: &mut error];
+
+            match to_bool(success) {
+                true => Ok(()),
+                false => Err(Error::new(error))
+            }
+        }
+    }
+
+    /// Returns the names of the Finder file labels (tags), e.g "Red". Index `0` is "None".
+    pub fn file_labels(&self) -> Vec<String> {
+        let labels = NSArray::retain(unsafe { msg_send![&*self.0, fileLabels] });
+        labels.iter().map(|label| NSString::retain(label).to_string()).collect()
+    }
+
+    /// Returns the colors of the Finder file labels, in the same order as `file_labels()`.
+    pub fn file_label_colors(&self) -> Vec<Color> {
+        let colors = NSArray::retain(unsafe { msg_send![&*self.0, fileLabelColors] });
+        colors.iter().map(Color::retain).collect()
+    }
+
+    /// Opens Finder windows for the files at `urls`, with the files selected.
+    pub fn activate_file_viewer_selecting_urls(&self, urls: &[NSURL]) {
+        let urls = NSArray::from_retainables(urls);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, activateFileViewerSelectingURLs: &*urls];
+        }
+    }
+}
+
+/// Wraps a completion callback in the `void (^)(NSRunningApplication *, NSError *)` block that
+/// `NSWorkspace` expects for opening URLs and applications.
+fn open_block<F>(completion: F) -> block::RcBlock<(id, id), ()>
+where
+    F: Fn(Result<RunningApplication, Error>) + Send + Sync + 'static
+{
+    let block = ConcreteBlock::new(move |app: id, error: id| match app == nil {
+        true => completion(Err(Error::new(error))),
+        false => completion(Ok(RunningApplication::retain(app)))
+    });
+
+    block.copy()
+}
+
+/// Wraps a completion callback in the `void (^)(NSDictionary<NSURL *, NSURL *> *, NSError *)`
+/// block that `NSWorkspace` expects for file operations.
+fn moved_urls_block<F>(completion: F) -> block::RcBlock<(id, id), ()>
+where
+    F: Fn(Result<Vec<MovedURL>, Error>) + Send + Sync + 'static
+{
+    let block = ConcreteBlock::new(move |new_urls: id, error: id| {
+        if error != nil {
+            completion(Err(Error::new(error)));
+            return;
+        }
+
+        let moved = match new_urls == nil {
+            true => Vec::new(),
+            false => NSMutableDictionary::retain(new_urls).entries::<NSURL, NSURL>()
+        };
+
+        completion(Ok(moved));
+    });
+
+    block.copy()
+}
@@ -0,0 +1,336 @@
+//! Forms: a group of controls edited together as one typed value, the way a preferences pane
+//! with Apply and Revert buttons works.
+//!
+//! A `Form<T>` holds the value being edited and the last applied (saved) value. Each field is a
+//! `Property` that maps onto one part of `T`, so controls bind to it like they would any other
+//! property. The form tracks whether the value has changed since it was last applied, and runs an
+//! optional validation closure on every edit.
+//!
+//! ```rust,no_run
+//! use cacao::binding::Form;
+//! use cacao::input::TextField;
+//! use cacao::switch::Switch;
+//!
+//! #[derive(Clone, PartialEq)]
+//! struct Settings {
+//!     username: String,
+//!     notifications: bool
+//! }
+//!
+//! let mut form = Form::new(Settings {
+//!     username: String::from("ryan"),
+//!     notifications: true
+//! });
+//!
+//! let username = TextField::new();
+//! let mut notifications = Switch::new("Show notifications");
+//!
+//! form.bind_text_field(&username, |s| s.username.clone(), |s, username| s.username = username);
+//! form.bind_switch(&mut notifications, |s| s.notifications, |s, on| s.notifications = on);
+//!
+//! form.set_validator(|s| match s.username.is_empty() {
+//!     true => Err(String::from("Please enter a username.")),
+//!     false => Ok(())
+//! });
+//!
+//! // Enable the Apply and Revert buttons as the form changes.
+//! let _dirty = form.dirty().observe(|dirty| println!("Has changes: {}", dirty));
+//!
+//! // Later, from the Apply button...
+//! if let Ok(settings) = form.apply() {
+//!     // ...save `settings`.
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::binding::{Binding, Property, Subscription};
+
+#[cfg(any(appkit, uikit))]
+use crate::input::TextField;
+
+#[cfg(appkit)]
+use crate::select::Select;
+
+#[cfg(appkit)]
+use crate::switch::Switch;
+
+/// A validation closure; returns a message describing the problem for invalid values.
+type Validator<T> = Box<dyn Fn(&T) -> Result<(), String>>;
+
+/// The saved value and validator, shared with the observer that keeps the form state current.
+struct FormState<T> {
+    saved: RefCell<T>,
+    validator: RefCell<Option<Validator<T>>>,
+    dirty: Property<bool>,
+    error: Property<Option<String>>
+}
+
+impl<T: PartialEq + 'static> FormState<T> {
+    /// Recomputes the dirty flag and validation error for `value`.
+    fn refresh(&self, value: &T) {
+        let dirty = *self.saved.borrow() != *value;
+
+        let error = match &*self.validator.borrow() {
+            Some(validator) => validator(value).err(),
+            None => None
+        };
+
+        self.dirty.set(dirty);
+        self.error.set(error);
+    }
+}
+
+/// A typed value edited through a group of controls, with validation and a dirty flag. See the
+/// module documentation for an example.
+///
+/// Fields and control bindings live for as long as the form does.
+pub struct Form<T> {
+    value: Property<T>,
+    state: Rc<FormState<T>>,
+    subscriptions: Vec<Subscription>,
+    bindings: Vec<Binding>
+}
+
+impl<T: Clone + PartialEq + 'static> Form<T> {
+    /// Creates a form editing `initial`, which is also taken as the saved value.
+    pub fn new(initial: T) -> Self {
+        let value = Property::new(initial.clone());
+
+        let state = Rc::new(FormState {
+            saved: RefCell::new(initial),
+            validator: RefCell::new(None),
+            dirty: Property::new(false),
+            error: Property::new(None)
+        });
+
+        let observer = state.clone();
+        let subscription = value.observe(move |value| observer.refresh(value));
+
+        Form {
+            value,
+            state,
+            subscriptions: vec![subscription],
+            bindings: Vec::new()
+        }
+    }
+
+    /// Sets the closure used to validate the value, returning an error message for values that
+    /// can't be applied. It runs right away, and again on every edit.
+    pub fn set_validator<F: Fn(&T) -> Result<(), String> + 'static>(&self, validator: F) {
+        *self.state.validator.borrow_mut() = Some(Box::new(validator));
+        self.value.with(|value| self.state.refresh(value));
+    }
+
+    /// Returns a property for one part of the value: `get` reads it out of `T`, and `set` writes
+    /// it back. The property and the form stay in sync both ways, so it can be bound to a control
+    /// or observed on its own.
+    pub fn field<U, G, S>(&mut self, get: G, set: S) -> Property<U>
+    where
+        U: Clone + PartialEq + 'static,
+        G: Fn(&T) -> U + 'static,
+        S: Fn(&mut T, U) + 'static
+    {
+        let get = Rc::new(get);
+        let field = Property::new(self.value.with(|value| get(value)));
+
+        // Either side may be mid-notification when the other updates, and setting a property
+        // from inside its own observer panics - so each direction only writes real changes.
+        let value = self.value.clone();
+        let read = get.clone();
+        let to_form = field.observe(move |new| {
+            if value.with(|value| read(value) != *new) {
+                value.update(|value| set(value, new.clone()));
+            }
+        });
+
+        let handle = field.clone();
+        let from_form = self.value.observe(move |value| {
+            let new = get(value);
+
+            if handle.with(|current| *current != new) {
+                handle.set(new);
+            }
+        });
+
+        self.subscriptions.push(to_form);
+        self.subscriptions.push(from_form);
+        field
+    }
+
+    /// Binds `input` to the `String` that `get` and `set` map onto. See `field()`.
+    #[cfg(any(appkit, uikit))]
+    pub fn bind_text_field<D, G, S>(&mut self, input: &TextField<D>, get: G, set: S)
+    where
+        G: Fn(&T) -> String + 'static,
+        S: Fn(&mut T, String) + 'static
+    {
+        let field = self.field(get, set);
+        self.bindings.push(input.bind_value(&field));
+    }
+
+    /// Binds the checked state of `switch` to the `bool` that `get` and `set` map onto. See
+    /// `field()`.
+    #[cfg(appkit)]
+    pub fn bind_switch<G, S>(&mut self, switch: &mut Switch, get: G, set: S)
+    where
+        G: Fn(&T) -> bool + 'static,
+        S: Fn(&mut T, bool) + 'static
+    {
+        let field = self.field(get, set);
+        self.bindings.push(switch.bind_checked(&field));
+    }
+
+    /// Binds the selected index of `select` to the `usize` that `get` and `set` map onto. See
+    /// `field()`.
+    #[cfg(appkit)]
+    pub fn bind_select<G, S>(&mut self, select: &mut Select, get: G, set: S)
+    where
+        G: Fn(&T) -> usize + 'static,
+        S: Fn(&mut T, usize) + 'static
+    {
+        let field = self.field(get, set);
+        self.bindings.push(select.bind_selected_index(&field));
+    }
+
+    /// Keeps a binding alive for as long as the form, for controls bound to a `field()` by hand.
+    pub fn hold(&mut self, binding: Binding) {
+        self.bindings.push(binding);
+    }
+
+    /// Returns a clone of the value as currently edited.
+    pub fn value(&self) -> T {
+        self.value.get()
+    }
+
+    /// Returns a clone of the last applied value.
+    pub fn saved_value(&self) -> T {
+        self.state.saved.borrow().clone()
+    }
+
+    /// Replaces the value being edited; bound controls update to match.
+    pub fn set_value(&self, value: T) {
+        self.value.set(value);
+    }
+
+    /// Registers `observer` to be called with the whole value after every edit.
+    pub fn observe<F: Fn(&T) + 'static>(&self, observer: F) -> Subscription {
+        self.value.observe(observer)
+    }
+
+    /// Returns whether the value has changed since it was last applied.
+    pub fn is_dirty(&self) -> bool {
+        self.state.dirty.get()
+    }
+
+    /// Returns the dirty flag as a property, to observe - e.g, to enable Apply and Revert buttons.
+    pub fn dirty(&self) -> Property<bool> {
+        self.state.dirty.clone()
+    }
+
+    /// Returns whether the current value passes validation.
+    pub fn is_valid(&self) -> bool {
+        self.state.error.with(|error| error.is_none())
+    }
+
+    /// Returns the validation error for the current value, if there is one.
+    pub fn validation_error(&self) -> Option<String> {
+        self.state.error.get()
+    }
+
+    /// Returns the validation error as a property, to observe - e.g, with a `Label` bound to show
+    /// it.
+    pub fn error(&self) -> Property<Option<String>> {
+        self.state.error.clone()
+    }
+
+    /// Validates the value and, if it passes, makes it the saved value and returns it. Otherwise,
+    /// returns the validation error and leaves the form as is.
+    pub fn apply(&self) -> Result<T, String> {
+        if let Some(error) = self.validation_error() {
+            return Err(error);
+        }
+
+        let value = self.value();
+        *self.state.saved.borrow_mut() = value.clone();
+        self.state.refresh(&value);
+        Ok(value)
+    }
+
+    /// Throws away any edits, putting the saved value back into the bound controls.
+    pub fn revert(&self) {
+        self.set_value(self.saved_value());
+    }
+
+    /// Replaces both the saved and edited value - e.g, after loading settings from disk.
+    pub fn reset(&self, value: T) {
+        *self.state.saved.borrow_mut() = value.clone();
+        self.value.set(value);
+        self.value.with(|value| self.state.refresh(value));
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Form<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Form")
+            .field("value", &self.value)
+            .field("saved", &*self.state.saved.borrow())
+            .field("dirty", &self.state.dirty)
+            .field("bindings", &self.bindings.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Form;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Settings {
+        name: String,
+        count: usize
+    }
+
+    fn form() -> Form<Settings> {
+        Form::new(Settings {
+            name: String::from("cacao"),
+            count: 1
+        })
+    }
+
+    #[test]
+    fn fields_track_dirty_state() {
+        let mut form = form();
+        let name = form.field(|s| s.name.clone(), |s, name| s.name = name);
+
+        name.set(String::from("rust"));
+        assert!(form.is_dirty());
+        assert_eq!(form.value().name, "rust");
+
+        form.revert();
+        assert!(!form.is_dirty());
+        assert_eq!(name.get(), "cacao");
+    }
+
+    #[test]
+    fn apply_respects_validation() {
+        let mut form = form();
+        let count = form.field(|s| s.count, |s, count| s.count = count);
+
+        form.set_validator(|s| match s.count {
+            0 => Err(String::from("Count can't be zero.")),
+            _ => Ok(())
+        });
+
+        count.set(0);
+        assert!(form.apply().is_err());
+        assert!(form.is_dirty());
+
+        count.set(5);
+        assert_eq!(form.apply().map(|s| s.count), Ok(5));
+        assert!(!form.is_dirty());
+        assert_eq!(form.saved_value().count, 5);
+    }
+}
@@ -0,0 +1,388 @@
+//! A lightweight binding layer, for keeping controls in sync with values.
+//!
+//! A `Property<T>` is a shared, observable value. Controls can bind to one - e.g, a `Label` that
+//! displays a `Property<String>` - and will update whenever it changes. Editable controls (text
+//! fields, switches) bind both ways, so user edits flow back into the property as well.
+//!
+//! For simple UIs, this cuts out most of the `Dispatcher` message plumbing: rather than sending a
+//! message up to your app delegate and back down to the control, you set the property and the
+//! control follows.
+//!
+//! ```rust,no_run
+//! use cacao::binding::Property;
+//! use cacao::input::TextField;
+//! use cacao::text::Label;
+//!
+//! let name = Property::new(String::from("World"));
+//!
+//! let input = TextField::new();
+//! let label = Label::new();
+//!
+//! // These need to be held for as long as the binding should stay active.
+//! let _input_binding = input.bind_value(&name);
+//! let _label_binding = label.bind_text(&name);
+//!
+//! // Updates the text field and the label.
+//! name.set(String::from("Cacao"));
+//! ```
+//!
+//! Properties live on the main thread, as do the controls bound to them. To update one from a
+//! background thread, grab a `PropertySender` via `sender()`; it forwards values to the main queue.
+//!
+//! For a group of controls edited together (e.g, a preferences pane with Apply and Revert
+//! buttons), see `Form`.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::{Rc, Weak};
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, NSString};
+use crate::invoker::TargetActionHandler;
+
+mod form;
+pub use form::Form;
+
+thread_local! {
+    /// Properties that can be reached from a `PropertySender`, keyed by id.
+    static SENDABLE_PROPERTIES: RefCell<HashMap<usize, Weak<dyn Any>>> = RefCell::new(HashMap::new());
+
+    /// A counter for handing out property ids.
+    static NEXT_PROPERTY_ID: Cell<usize> = Cell::new(0);
+}
+
+/// An observer callback. These are reference counted so that we can call them without holding a
+/// borrow on the observer list (observers are free to observe or set other properties).
+type Observer<T> = Rc<dyn Fn(&T)>;
+
+struct PropertyInner<T> {
+    id: usize,
+    value: RefCell<T>,
+    observers: RefCell<Vec<(usize, Observer<T>)>>,
+    next_observer_id: Cell<usize>
+}
+
+impl<T> Drop for PropertyInner<T> {
+    fn drop(&mut self) {
+        // This can run during thread teardown, at which point the registry may already be gone.
+        let _ = SENDABLE_PROPERTIES.try_with(|properties| {
+            properties.borrow_mut().remove(&self.id);
+        });
+    }
+}
+
+/// A shared, observable value. Cloning a `Property` gives you another handle to the same value.
+///
+/// This is neither `Send` nor `Sync`; use `sender()` to update it from other threads.
+pub struct Property<T> {
+    inner: Rc<PropertyInner<T>>
+}
+
+impl<T: 'static> Property<T> {
+    /// Creates a new `Property` holding `value`.
+    pub fn new(value: T) -> Self {
+        let id = NEXT_PROPERTY_ID.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            id
+        });
+
+        Property {
+            inner: Rc::new(PropertyInner {
+                id,
+                value: RefCell::new(value),
+                observers: RefCell::new(Vec::new()),
+                next_observer_id: Cell::new(0)
+            })
+        }
+    }
+
+    /// Runs `handler` with a reference to the current value.
+    pub fn with<R, F: FnOnce(&T) -> R>(&self, handler: F) -> R {
+        handler(&self.inner.value.borrow())
+    }
+
+    /// Registers `observer` to be called whenever the value changes. The observer is removed when
+    /// the returned `Subscription` is dropped.
+    pub fn observe<F: Fn(&T) + 'static>(&self, observer: F) -> Subscription {
+        let observer_id = self.inner.next_observer_id.get();
+        self.inner.next_observer_id.set(observer_id + 1);
+        self.inner.observers.borrow_mut().push((observer_id, Rc::new(observer)));
+
+        let inner = Rc::downgrade(&self.inner);
+
+        Subscription::new(move || {
+            if let Some(inner) = inner.upgrade() {
+                inner.observers.borrow_mut().retain(|(id, _)| *id != observer_id);
+            }
+        })
+    }
+
+    /// Like `observe`, but also calls `observer` immediately with the current value. This is what
+    /// control bindings use, so that the control starts out in sync.
+    pub fn observe_and_apply<F: Fn(&T) + 'static>(&self, observer: F) -> Subscription {
+        self.with(|value| observer(value));
+        self.observe(observer)
+    }
+
+    /// Notifies observers of the current value. Observers can read this property, but setting it
+    /// from inside an observer will panic.
+    fn notify(&self) {
+        let observers: Vec<Observer<T>> = self
+            .inner
+            .observers
+            .borrow()
+            .iter()
+            .map(|(_, observer)| observer.clone())
+            .collect();
+
+        let value = self.inner.value.borrow();
+        for observer in observers {
+            observer(&value);
+        }
+    }
+}
+
+impl<T: Clone + 'static> Property<T> {
+    /// Returns a clone of the current value.
+    pub fn get(&self) -> T {
+        self.inner.value.borrow().clone()
+    }
+}
+
+impl<T: PartialEq + 'static> Property<T> {
+    /// Sets the value, notifying observers if it changed.
+    ///
+    /// Setting a value that's equal to the current one is a no-op; this is what stops two-way
+    /// bindings from looping.
+    pub fn set(&self, value: T) {
+        {
+            let mut current = self.inner.value.borrow_mut();
+
+            if *current == value {
+                return;
+            }
+
+            *current = value;
+        }
+
+        self.notify();
+    }
+
+    /// Updates the value in place, notifying observers if it changed.
+    pub fn update<F: FnOnce(&mut T)>(&self, handler: F)
+    where
+        T: Clone
+    {
+        let mut value = self.get();
+        handler(&mut value);
+        self.set(value);
+    }
+}
+
+impl<T: PartialEq + Send + 'static> Property<T> {
+    /// Returns a `PropertySender`, which can be used to set this property from any thread.
+    pub fn sender(&self) -> PropertySender<T> {
+        let inner: Rc<dyn Any> = self.inner.clone();
+
+        SENDABLE_PROPERTIES.with(|properties| {
+            properties.borrow_mut().insert(self.inner.id, Rc::downgrade(&inner));
+        });
+
+        PropertySender {
+            id: self.inner.id,
+            _value: PhantomData
+        }
+    }
+}
+
+impl<T> Clone for Property<T> {
+    fn clone(&self) -> Self {
+        Property {
+            inner: self.inner.clone()
+        }
+    }
+}
+
+impl<T: Default + 'static> Default for Property<T> {
+    fn default() -> Self {
+        Property::new(T::default())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Property<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Property")
+            .field("value", &*self.inner.value.borrow())
+            .field("observers", &self.inner.observers.borrow().len())
+            .finish()
+    }
+}
+
+/// A thread-safe handle for setting a `Property`. Values are forwarded to the main queue, and
+/// silently dropped if the property no longer exists by the time they arrive.
+pub struct PropertySender<T> {
+    id: usize,
+    _value: PhantomData<fn(T)>
+}
+
+impl<T: PartialEq + Send + 'static> PropertySender<T> {
+    /// Sets the property to `value`, asynchronously, on the main thread.
+    pub fn send(&self, value: T) {
+        let id = self.id;
+
+        dispatch::Queue::main().exec_async(move || {
+            let inner = SENDABLE_PROPERTIES.with(|properties| properties.borrow().get(&id).and_then(Weak::upgrade));
+
+            if let Some(Ok(inner)) = inner.map(|inner| inner.downcast::<PropertyInner<T>>()) {
+                Property { inner }.set(value);
+            }
+        });
+    }
+}
+
+impl<T> Clone for PropertySender<T> {
+    fn clone(&self) -> Self {
+        PropertySender {
+            id: self.id,
+            _value: PhantomData
+        }
+    }
+}
+
+impl<T> fmt::Debug for PropertySender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PropertySender").field("id", &self.id).finish()
+    }
+}
+
+/// Removes an observer (e.g, from a `Property`) when dropped.
+#[must_use = "the observer is removed when this is dropped"]
+pub struct Subscription(Option<Box<dyn FnOnce()>>);
+
+impl Subscription {
+    /// Creates a `Subscription` that calls `unsubscribe` when dropped.
+    pub(crate) fn new<F: FnOnce() + 'static>(unsubscribe: F) -> Self {
+        Subscription(Some(Box::new(unsubscribe)))
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(unsubscribe) = self.0.take() {
+            unsubscribe();
+        }
+    }
+}
+
+impl fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscription").finish()
+    }
+}
+
+/// An active binding between a `Property` and a control. The binding is removed when this is
+/// dropped, so hold onto it for as long as the control should stay in sync.
+#[must_use = "the binding is removed when this is dropped"]
+#[derive(Debug)]
+pub struct Binding {
+    _subscription: Subscription,
+    _observer: Option<NotificationObserver>,
+    _handler: Option<TargetActionHandler>
+}
+
+impl Binding {
+    /// A binding that only flows from the property to the control.
+    pub(crate) fn one_way(subscription: Subscription) -> Self {
+        Binding {
+            _subscription: subscription,
+            _observer: None,
+            _handler: None
+        }
+    }
+
+    /// A binding that also flows back from the control, via a notification.
+    pub(crate) fn with_notification(subscription: Subscription, observer: NotificationObserver) -> Self {
+        Binding {
+            _subscription: subscription,
+            _observer: Some(observer),
+            _handler: None
+        }
+    }
+
+    /// A binding that also flows back from the control, via its target/action.
+    pub(crate) fn with_action(subscription: Subscription, handler: TargetActionHandler) -> Self {
+        Binding {
+            _subscription: subscription,
+            _observer: None,
+            _handler: Some(handler)
+        }
+    }
+}
+
+/// A block-based `NSNotificationCenter` observer, removed on drop.
+#[derive(Debug)]
+pub(crate) struct NotificationObserver(Id<Object, Shared>);
+
+impl NotificationObserver {
+    /// Observes notifications named `name`, posted by `object`. The handler is called on the main
+    /// queue.
+    pub(crate) fn new<F: Fn() + 'static>(name: &str, object: &Object, handler: F) -> Self {
+        let name = NSString::new(name);
+        let block = block::ConcreteBlock::new(move |_notification: id| handler());
+        let block = block.copy();
+
+        NotificationObserver(unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+            let queue: id = msg_send![class!(NSOperationQueue), mainQueue];
+
+            msg_send_id![
+                center,
+                addObserverForName: &*name,
+                object: object,
+                queue: queue,
+                usingBlock: &*block
+            ]
+        })
+    }
+}
+
+impl Drop for NotificationObserver {
+    fn drop(&mut self) {
+        unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+            let _: () = msg_send![center, removeObserver: &*self.0];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Property;
+
+    #[test]
+    fn property_notifies_on_change() {
+        let property = Property::new(1);
+        let seen = Rc::new(RefCell::new(vec![]));
+
+        let observed = seen.clone();
+        let subscription = property.observe_and_apply(move |value| observed.borrow_mut().push(*value));
+
+        property.set(2);
+        property.set(2);
+        drop(subscription);
+        property.set(3);
+
+        assert_eq!(*seen.borrow(), vec![1, 2]);
+        assert_eq!(property.get(), 3);
+    }
+}
@@ -27,23 +27,46 @@ use objc::{msg_send, msg_send_id, sel};
 
 pub use enums::*;
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 use crate::appkit::FocusRingType;
 use crate::color::Color;
 use crate::control::Control;
-use crate::foundation::{id, load_or_register_class, nil, NSString, NSUInteger, NO, YES};
+#[cfg(uikit)]
+use crate::foundation::NSInteger;
+use crate::foundation::{id, load_or_register_class, nil, NSString, NSUInteger, StaticClass, NO, YES};
 use crate::image::Image;
 use crate::invoker::TargetActionHandler;
+#[cfg(appkit)]
 use crate::keys::Key;
 use crate::layout::Layout;
 #[cfg(feature = "autolayout")]
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 use crate::objc_access::ObjcAccess;
-use crate::text::{AttributedString, Font};
+#[cfg(appkit)]
+use crate::text::AttributedString;
+use crate::text::Font;
 use crate::utils::properties::ObjcProperty;
 
 mod enums;
 
+#[cfg(appkit)]
+mod pull_down;
+
+#[cfg(appkit)]
+pub use pull_down::PullDownButton;
+
+/// `UIButtonTypeSystem`.
+#[cfg(uikit)]
+const UI_BUTTON_TYPE_SYSTEM: NSInteger = 1;
+
+/// `UIControlStateNormal`.
+#[cfg(uikit)]
+const UI_CONTROL_STATE_NORMAL: NSUInteger = 0;
+
+/// `UIControlEventAllEvents`.
+#[cfg(uikit)]
+const UI_CONTROL_EVENT_ALL_EVENTS: NSUInteger = 0xFFFFFFFF;
+
 /// Wraps `NSButton` on appkit, and `UIButton` on iOS and tvOS.
 ///
 /// You'd use this type to create a button that a user can interact with. Buttons can be configured
@@ -121,10 +144,25 @@ impl Button {
     /// Creates a new `NSButton` instance, configures it appropriately,
     /// and retains the necessary Objective-C runtime pointer.
     pub fn new(text: &str) -> Self {
+        Button::init(register_class(), text)
+    }
+
+    /// Creates a new `Button` backed by a custom subclass, as created via `Subclass::button()`.
+    /// This lets you override methods that `Button` doesn't expose.
+    ///
+    /// Panics if `class` doesn't descend from the class backing `Button`.
+    pub fn with_class(class: &'static Class, text: &str) -> Self {
+        crate::subclass::assert_subclass_of(class, register_class());
+        Button::init(class, text)
+    }
+
+    /// Shared initializer for `new` and `with_class`.
+    fn init(class: &'static Class, text: &str) -> Self {
         let title = NSString::new(text);
 
+        #[cfg(appkit)]
         let view: id = unsafe {
-            let button: id = msg_send![register_class(), buttonWithTitle:&*title
+            let button: id = msg_send![class, buttonWithTitle:&*title
                 target:nil
                 action:nil
             ];
@@ -137,6 +175,17 @@ impl Button {
             button
         };
 
+        #[cfg(uikit)]
+        let view: id = unsafe {
+            let button: id = msg_send![class, buttonWithType: UI_BUTTON_TYPE_SYSTEM];
+            let _: () = msg_send![button, setTitle:&*title forState: UI_CONTROL_STATE_NORMAL];
+
+            #[cfg(feature = "autolayout")]
+            let _: () = msg_send![button, setTranslatesAutoresizingMaskIntoConstraints: NO];
+
+            button
+        };
+
         Button {
             handler: None,
             image: None,
@@ -176,23 +225,37 @@ impl Button {
     }
 
     /// Changes the text of the button
-    #[cfg(feature = "appkit")]
     pub fn set_text(&self, text: &str) {
         let title = NSString::new(text);
+
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setTitle:&*title];
         });
+
+        #[cfg(uikit)]
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setTitle:&*title forState: UI_CONTROL_STATE_NORMAL];
+        });
     }
 
     /// Sets an image on the underlying button.
     pub fn set_image(&mut self, image: Image) {
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setImage:&*image.0];
         });
 
+        #[cfg(uikit)]
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setImage:&*image.0 forState: UI_CONTROL_STATE_NORMAL];
+        });
+
         self.image = Some(image);
     }
 
+    /// Sets where the image sits relative to the title. Only supported on appkit.
+    #[cfg(appkit)]
     pub fn set_image_position(&self, image_position: ImagePosition) {
         let position: NSUInteger = image_position.into();
         self.objc.with_mut(|obj| unsafe {
@@ -201,7 +264,7 @@ impl Button {
     }
 
     /// Sets the bezel style for this button. Only supported on appkit.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn set_bezel_style(&self, bezel_style: BezelStyle) {
         let style: NSUInteger = bezel_style.into();
 
@@ -223,15 +286,21 @@ impl Button {
     pub fn set_background_color<C: AsRef<Color>>(&self, color: C) {
         let color: id = color.as_ref().into();
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             let cell: id = msg_send![obj, cell];
             let _: () = msg_send![cell, setBackgroundColor: color];
         });
+
+        #[cfg(uikit)]
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setBackgroundColor: color];
+        });
     }
 
     /// Set a key to be bound to this button. When the key is pressed, the action coupled to this
-    /// button will fire.
+    /// button will fire. Only supported on appkit.
+    #[cfg(appkit)]
     pub fn set_key_equivalent<'a, K>(&self, key: K)
     where
         K: Into<Key<'a>>
@@ -254,7 +323,7 @@ impl Button {
     ///
     /// On appkit, this is done by way of an `AttributedString` under the hood.
     pub fn set_text_color<C: AsRef<Color>>(&self, color: C) {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         self.objc.with_mut(move |obj| unsafe {
             let text: id = msg_send![obj, attributedTitle];
             let len: isize = msg_send![text, length];
@@ -264,11 +333,20 @@ impl Button {
 
             let _: () = msg_send![obj, setAttributedTitle:&*attr_str];
         });
+
+        #[cfg(uikit)]
+        {
+            let color: id = color.as_ref().into();
+
+            self.objc.with_mut(|obj| unsafe {
+                let _: () = msg_send![obj, setTitleColor: color forState: UI_CONTROL_STATE_NORMAL];
+            });
+        }
     }
 
     // @TODO: Figure out how to handle oddities like this.
     /// For buttons on appkit, one might need to disable the border. This does that.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn set_bordered(&self, is_bordered: bool) {
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setBordered:match is_bordered {
@@ -282,15 +360,22 @@ impl Button {
     pub fn set_font<F: AsRef<Font>>(&self, font: F) {
         let font = font.as_ref().clone();
 
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setFont:&*font];
         });
+
+        #[cfg(uikit)]
+        self.objc.with_mut(|obj| unsafe {
+            let label: id = msg_send![obj, titleLabel];
+            let _: () = msg_send![label, setFont:&*font];
+        });
     }
 
     /// Sets how the control should draw a focus ring when a user is focused on it.
     ///
     /// This is an appkit-only method.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn set_focus_ring_type(&self, focus_ring_type: FocusRingType) {
         let ring_type: NSUInteger = focus_ring_type.into();
 
@@ -301,11 +386,19 @@ impl Button {
 
     /// Toggles the highlighted status of the button.
     pub fn set_highlighted(&self, highlight: bool) {
+        let highlight = match highlight {
+            true => YES,
+            false => NO
+        };
+
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
-            let _: () = msg_send![obj, highlight:match highlight {
-                true => YES,
-                false => NO
-            }];
+            let _: () = msg_send![obj, highlight: highlight];
+        });
+
+        #[cfg(uikit)]
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setHighlighted: highlight];
         });
     }
 }
@@ -343,24 +436,36 @@ impl Drop for Button {
     // Just to be sure, let's... nil these out. They should be weak references,
     // but I'd rather be paranoid and remove them later.
     fn drop(&mut self) {
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setTarget: nil];
             let _: () = msg_send![obj, setAction: nil];
         });
+
+        #[cfg(uikit)]
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, removeTarget: nil action: sel!(perform:) forControlEvents: UI_CONTROL_EVENT_ALL_EVENTS];
+        });
     }
 }
 
 /// Registers an `NSButton` subclass, and configures it to hold some ivars
 /// for various things we need to store.
-fn register_class() -> &'static Class {
-    #[cfg(feature = "appkit")]
-    let super_class = "NSButton";
-    #[cfg(all(feature = "uikit", not(feature = "appkit")))]
-    let super_class = "UIButton";
-    load_or_register_class(super_class, "RSTButton", |decl| unsafe {})
+pub(crate) fn register_class() -> &'static Class {
+    static CLASS: StaticClass = StaticClass::new();
+
+    CLASS.get_or_register(|| {
+        #[cfg(appkit)]
+        let super_class = "NSButton";
+        #[cfg(uikit)]
+        let super_class = "UIButton";
+        load_or_register_class(super_class, "RSTButton", |decl| unsafe {})
+    })
 }
 
 #[test]
 fn test_button() {
+    crate::testing::initialize();
+
     let button = Button::new("foobar");
 }
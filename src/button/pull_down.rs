@@ -0,0 +1,206 @@
+//! Wraps `NSPopUpButton` in pull-down mode: a button that shows a menu of actions when clicked,
+//! like the "Action" (gear) buttons found in toolbars and under source lists.
+//!
+//! Unlike a `Select`, a pull-down button has no selection - its title stays put, and each item
+//! runs its own action.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::menu::MenuItem;
+//! use cacao::button::PullDownButton;
+//!
+//! let button = PullDownButton::new("Share", vec![
+//!     MenuItem::new("Copy Link").action(|| println!("Copy link")),
+//!     MenuItem::new("Email...").action(|| println!("Email"))
+//! ]);
+//! ```
+
+use core_graphics::geometry::CGRect;
+use objc::runtime::{Class, Object};
+use objc::{msg_send, sel};
+
+use crate::appkit::menu::{Menu, MenuItem};
+use crate::control::Control;
+#[cfg(feature = "autolayout")]
+use crate::foundation::NO;
+use crate::foundation::{id, load_or_register_class, NSInteger, NSString, YES};
+use crate::geometry::Rect;
+use crate::image::Image;
+use crate::layout::Layout;
+#[cfg(feature = "autolayout")]
+use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
+use crate::objc_access::ObjcAccess;
+use crate::utils::properties::ObjcProperty;
+
+/// A button that pulls down a menu of actions. See the module documentation for more.
+#[derive(Debug)]
+pub struct PullDownButton {
+    /// A handle for the underlying Objective-C object.
+    pub objc: ObjcProperty,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime left layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub left: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime right layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub right: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub center_y: LayoutAnchorY
+}
+
+impl PullDownButton {
+    /// Creates a pull-down button titled `title`, which shows `items` when clicked.
+    pub fn new(title: &str, items: Vec<MenuItem>) -> Self {
+        let zero: CGRect = Rect::zero().into();
+
+        let view: id = unsafe {
+            let alloc: id = msg_send![register_class(), alloc];
+            let button: id = msg_send![alloc, initWithFrame:zero pullsDown:YES];
+
+            #[cfg(feature = "autolayout")]
+            let _: () = msg_send![button, setTranslatesAutoresizingMaskIntoConstraints: NO];
+
+            button
+        };
+
+        let button = PullDownButton {
+            #[cfg(feature = "autolayout")]
+            top: LayoutAnchorY::top(view),
+
+            #[cfg(feature = "autolayout")]
+            left: LayoutAnchorX::left(view),
+
+            #[cfg(feature = "autolayout")]
+            leading: LayoutAnchorX::leading(view),
+
+            #[cfg(feature = "autolayout")]
+            right: LayoutAnchorX::right(view),
+
+            #[cfg(feature = "autolayout")]
+            trailing: LayoutAnchorX::trailing(view),
+
+            #[cfg(feature = "autolayout")]
+            bottom: LayoutAnchorY::bottom(view),
+
+            #[cfg(feature = "autolayout")]
+            width: LayoutAnchorDimension::width(view),
+
+            #[cfg(feature = "autolayout")]
+            height: LayoutAnchorDimension::height(view),
+
+            #[cfg(feature = "autolayout")]
+            center_x: LayoutAnchorX::center(view),
+
+            #[cfg(feature = "autolayout")]
+            center_y: LayoutAnchorY::center(view),
+
+            objc: ObjcProperty::retain(view)
+        };
+
+        button.set_menu(title, items);
+        button
+    }
+
+    /// Replaces the items shown when the button is clicked, keeping the title.
+    pub fn set_items(&self, items: Vec<MenuItem>) {
+        let title = self.objc.get(|obj| unsafe {
+            let item: id = msg_send![obj, itemAtIndex: 0 as NSInteger];
+            let title: id = msg_send![item, title];
+            NSString::retain(title).to_string()
+        });
+
+        self.set_menu(&title, items);
+    }
+
+    /// Sets the title shown on the button.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        self.objc.with_mut(|obj| unsafe {
+            // In pull-down mode, the button shows the first item of the menu.
+            let item: id = msg_send![obj, itemAtIndex: 0 as NSInteger];
+            let _: () = msg_send![item, setTitle: &*title];
+        });
+    }
+
+    /// Shows `image` on the button, alongside the title (or in place of it, if the title is
+    /// empty) - e.g, a gear for an action menu.
+    pub fn set_image(&self, image: Image) {
+        self.objc.with_mut(|obj| unsafe {
+            let item: id = msg_send![obj, itemAtIndex: 0 as NSInteger];
+            let _: () = msg_send![item, setImage: &*image.0];
+        });
+    }
+
+    /// Builds the menu: in pull-down mode, the first item stands in for the title, and isn't
+    /// shown in the menu itself.
+    fn set_menu(&self, title: &str, items: Vec<MenuItem>) {
+        let mut all = Vec::with_capacity(items.len() + 1);
+        all.push(MenuItem::new(title));
+        all.extend(items);
+
+        let menu = Menu::new("", all);
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setMenu: &*menu.0];
+        });
+    }
+}
+
+impl ObjcAccess for PullDownButton {
+    fn with_backing_obj_mut<F: Fn(id)>(&self, handler: F) {
+        self.objc.with_mut(handler);
+    }
+
+    fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
+        self.objc.get(handler)
+    }
+}
+
+impl Layout for PullDownButton {
+    fn add_subview<V: Layout>(&self, _view: &V) {
+        panic!(
+            r#"
+            Tried to add a subview to a PullDownButton. This is not allowed in Cacao. If you think this should be supported,
+            open a discussion on the GitHub repo.
+        "#
+        );
+    }
+}
+
+impl Control for PullDownButton {}
+
+/// Registers an `NSPopUpButton` subclass for pull-down buttons.
+fn register_class() -> &'static Class {
+    load_or_register_class("NSPopUpButton", "CacaoPullDownButton", |decl| unsafe {})
+}
@@ -14,8 +14,8 @@ use core_graphics::base::CGFloat;
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel};
 
+use crate::available;
 use crate::foundation::{id, load_or_register_class, nil, NSArray, NSInteger};
-use crate::utils::os;
 
 pub(crate) const AQUA_LIGHT_COLOR_NORMAL_CONTRAST: &'static str = "AQUA_LIGHT_COLOR_NORMAL_CONTRAST";
 pub(crate) const AQUA_LIGHT_COLOR_HIGH_CONTRAST: &'static str = "AQUA_LIGHT_COLOR_HIGH_CONTRAST";
@@ -42,7 +42,7 @@ extern "C" {
 /// Pull requests to implement that check would be welcome.
 fn get_effective_color(this: &Object) -> id {
     #[cfg(target_os = "macos")]
-    if os::is_minimum_semversion(10, 14, 0) {
+    if available!(macos 10.14) {
         unsafe {
             let mut appearance: id = msg_send![class!(NSAppearance), currentAppearance];
             if appearance == nil {
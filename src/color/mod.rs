@@ -23,13 +23,13 @@ use objc::rc::{Id, Owned};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
-use crate::foundation::id;
-use crate::utils::os;
+use crate::available;
+use crate::foundation::{id, nil, NSString};
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 mod appkit_dynamic_color;
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 use appkit_dynamic_color::{
     AQUA_DARK_COLOR_HIGH_CONTRAST, AQUA_DARK_COLOR_NORMAL_CONTRAST, AQUA_LIGHT_COLOR_HIGH_CONTRAST,
     AQUA_LIGHT_COLOR_NORMAL_CONTRAST
@@ -217,7 +217,7 @@ pub enum Color {
     /// The default color to use for thin separators/lines that
     /// do not allow content underneath to be visible.
     /// This value automatically switches to the correct variant depending on light or dark mode.
-    #[cfg(feature = "uikit")]
+    #[cfg(uikit)]
     OpaqueSeparator,
 
     /// The default color to use for rendering links.
@@ -231,15 +231,40 @@ pub enum Color {
     LightText,
 
     /// The background color for a given window in the system theme.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     MacOSWindowBackgroundColor,
 
     /// The background color that should appear under a page per the system theme.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     MacOSUnderPageBackgroundColor
 }
 
 impl Color {
+    /// Wraps (and retains) an existing `NSColor` (on macOS) or `UIColor` (everywhere else) - e.g,
+    /// one handed back from a system API.
+    pub fn retain(color: id) -> Self {
+        Color::Custom(Arc::new(RwLock::new(unsafe { Id::retain(color).unwrap() })))
+    }
+
+    /// Looks up a color by name in the app's asset catalog. Named colors are dynamic, so they
+    /// pick up the variants (dark mode, high contrast, etc) defined in the catalog.
+    ///
+    /// Returns `None` if there's no color with that name.
+    pub fn named(name: &str) -> Option<Self> {
+        let name = NSString::new(name);
+
+        #[cfg(appkit)]
+        let color: id = unsafe { msg_send![class!(NSColor), colorNamed: &*name] };
+
+        #[cfg(uikit)]
+        let color: id = unsafe { msg_send![class!(UIColor), colorNamed: &*name] };
+
+        match color == nil {
+            true => None,
+            false => Some(Color::retain(color))
+        }
+    }
+
     /// Creates and returns a color in the RGB space, with the specified
     /// alpha level.
     pub fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
@@ -247,9 +272,9 @@ impl Color {
         let g = green as CGFloat / 255.0;
         let b = blue as CGFloat / 255.0;
         let a = alpha as CGFloat / 255.0;
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let ptr = unsafe { msg_send_id![class!(NSColor), colorWithCalibratedRed: r, green: g, blue: b, alpha: a] };
-        #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+        #[cfg(uikit)]
         let ptr = unsafe { msg_send_id![class!(UIColor), colorWithRed: r, green: g, blue: b, alpha: a] };
 
         Color::Custom(Arc::new(RwLock::new(ptr)))
@@ -270,7 +295,7 @@ impl Color {
         let a = alpha as CGFloat / 255.0;
 
         Color::Custom(Arc::new(RwLock::new(unsafe {
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             {
                 msg_send_id![
                     class!(NSColor),
@@ -281,7 +306,7 @@ impl Color {
                 ]
             }
 
-            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            #[cfg(uikit)]
             {
                 msg_send_id![class!(UIColor), colorWithHue: h, saturation: s, brightness: b, alpha: a]
             }
@@ -298,12 +323,12 @@ impl Color {
     /// specified alpha.
     pub fn white_alpha(level: CGFloat, alpha: CGFloat) -> Self {
         Color::Custom(Arc::new(RwLock::new(unsafe {
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             {
                 msg_send_id![class!(NSColor), colorWithCalibratedWhite: level, alpha: alpha]
             }
 
-            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            #[cfg(uikit)]
             {
                 msg_send_id![class!(UIColor), colorWithWhite: level, alpha: alpha]
             }
@@ -341,7 +366,7 @@ impl Color {
     /// "default" or "light" color.
     ///
     /// Returning a dynamic color in your handler is unsupported and may panic.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn dynamic<F>(handler: F) -> Self
     where
         F: Fn(Style) -> Color + 'static
@@ -418,10 +443,10 @@ impl From<&Color> for id {
 /// Handles color fallback for system-provided colors.
 macro_rules! system_color_with_fallback {
     ($class:ident, $color:ident, $fallback:ident) => {{
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         {
             #[cfg(feature = "color-fallbacks")]
-            if os::minimum_semversion(10, 10, 0) {
+            if available!(macos 10.10) {
                 msg_send![$class, $color]
             } else {
                 msg_send![$class, $fallback]
@@ -431,7 +456,7 @@ macro_rules! system_color_with_fallback {
             msg_send![$class, $color]
         }
 
-        #[cfg(feature = "uikit")]
+        #[cfg(uikit)]
         {
             msg_send![$class, $color]
         }
@@ -447,10 +472,10 @@ macro_rules! system_color_with_fallback {
 /// The goal here is to make sure that this can't reasonably break on OS's, as `Color` is kind of
 /// an important piece. It's not on the framework to make your app look good, though.
 unsafe fn to_objc(obj: &Color) -> id {
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     let color = class!(NSColor);
 
-    #[cfg(feature = "uikit")]
+    #[cfg(uikit)]
     let color = class!(UIColor);
 
     match obj {
@@ -493,17 +518,17 @@ unsafe fn to_objc(obj: &Color) -> id {
         Color::SystemBackgroundTertiary => system_color_with_fallback!(color, tertiarySystemBackgroundColor, clearColor),
         Color::Separator => system_color_with_fallback!(color, separatorColor, lightGrayColor),
 
-        #[cfg(feature = "uikit")]
+        #[cfg(uikit)]
         Color::OpaqueSeparator => system_color_with_fallback!(color, opaqueSeparatorColor, darkGrayColor),
 
         Color::Link => system_color_with_fallback!(color, linkColor, blueColor),
         Color::DarkText => system_color_with_fallback!(color, darkTextColor, blackColor),
         Color::LightText => system_color_with_fallback!(color, lightTextColor, whiteColor),
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         Color::MacOSWindowBackgroundColor => system_color_with_fallback!(color, windowBackgroundColor, clearColor),
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         Color::MacOSUnderPageBackgroundColor => system_color_with_fallback!(color, underPageBackgroundColor, clearColor)
     }
 }
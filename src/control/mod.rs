@@ -1,8 +1,10 @@
-use objc::runtime::Object;
+use objc::runtime::{Object, Sel};
 use objc::{class, msg_send, sel};
 
-use crate::foundation::{id, NSUInteger, NO, YES};
-use crate::objc_access::ObjcAccess;
+use crate::available;
+use crate::error::Error;
+use crate::foundation::{id, to_bool, NSInteger, NSUInteger, NO, YES};
+use crate::layout::Layout;
 
 /// Use this enum for specifying NSControl size types.
 #[derive(Copy, Clone, Debug)]
@@ -18,13 +20,19 @@ pub enum ControlSize {
 
     /// A large control. Only available on macOS 11.0+.
     /// If you pass this to the `set_control_size` method on the `Control` trait, it will
-    /// transparently map to `Regular` on 10.15 and below.
+    /// transparently map to `Regular` on 10.15 and below; `try_set_control_size` returns an error
+    /// there instead.
     Large
 }
 
-/// A trait that view wrappers must conform to. Enables managing the subview tree.
+/// A trait that control wrappers conform to. This provides the behavior common to all controls
+/// (enabled state, tags and so on), enabling generic code - e.g, forms - to manipulate
+/// them uniformly.
+///
+/// As every control is also a view, hiding them and setting tooltips is handled by `Layout`
+/// (e.g, `Layout::set_hidden` and `Layout::set_tooltip`).
 #[allow(unused_variables)]
-pub trait Control: ObjcAccess {
+pub trait Control: Layout {
     /// Whether this control is enabled or not.
     fn set_enabled(&self, is_enabled: bool) {
         self.with_backing_obj_mut(|obj| unsafe {
@@ -35,21 +43,78 @@ pub trait Control: ObjcAccess {
         });
     }
 
-    /// Sets the underlying control size.
+    /// Returns whether this control is enabled.
+    fn is_enabled(&self) -> bool {
+        self.get_from_backing_obj(|obj| to_bool(unsafe { msg_send![obj, isEnabled] }))
+    }
+
+    /// Sets a tag on this control. Tags are integers you can use to identify controls, e.g when
+    /// a single handler is shared between several of them.
+    fn set_tag(&self, tag: NSInteger) {
+        self.with_backing_obj_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setTag: tag];
+        });
+    }
+
+    /// Returns the tag for this control.
+    fn get_tag(&self) -> NSInteger {
+        self.get_from_backing_obj(|obj| unsafe { msg_send![obj, tag] })
+    }
+
+    /// Sends this control's action to its target, as though the user had interacted with it.
+    /// Returns `true` if the action was successfully sent.
+    #[cfg(appkit)]
+    fn send_action(&self) -> bool {
+        self.get_from_backing_obj(|obj| unsafe {
+            let target: id = msg_send![obj, target];
+            let action: Sel = msg_send![obj, action];
+            to_bool(msg_send![obj, sendAction: action, to: target])
+        })
+    }
+
+    /// Sends the actions associated with value changes on this control, as though the user had
+    /// interacted with it.
+    #[cfg(uikit)]
+    fn send_action(&self) {
+        // UIControlEventValueChanged | UIControlEventPrimaryActionTriggered
+        let events: NSUInteger = (1 << 12) | (1 << 13);
+
+        self.with_backing_obj_mut(|obj| unsafe {
+            let _: () = msg_send![obj, sendActionsForControlEvents: events];
+        });
+    }
+
+    /// Sets the underlying control size. `ControlSize::Large` falls back to `Regular` where it
+    /// isn't supported; use `try_set_control_size()` to find out when that happens.
     fn set_control_size(&self, size: ControlSize) {
+        let size = match (size, available!(macos 11)) {
+            (ControlSize::Large, false) => ControlSize::Regular,
+            (size, _) => size
+        };
+
+        let _ = self.try_set_control_size(size);
+    }
+
+    /// Sets the underlying control size.
+    ///
+    /// `ControlSize::Large` requires macOS 11; passing it on an older system returns an error
+    /// (see `Error::is_unsupported_on_this_os()`) and leaves the control size unchanged.
+    fn try_set_control_size(&self, size: ControlSize) -> Result<(), Error> {
         let control_size: NSUInteger = match size {
             ControlSize::Mini => 2,
             ControlSize::Small => 1,
             ControlSize::Regular => 0,
 
-            ControlSize::Large => match crate::utils::os::is_minimum_version(11) {
+            ControlSize::Large => match available!(macos 11) {
                 true => 3,
-                false => 0
+                false => return Err(Error::unsupported_on_this_os("ControlSize::Large", "macOS 11"))
             }
         };
 
         self.with_backing_obj_mut(|obj| unsafe {
             let _: () = msg_send![obj, setControlSize: control_size];
         });
+
+        Ok(())
     }
 }
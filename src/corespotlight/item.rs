@@ -0,0 +1,100 @@
+//! Describes content to be indexed by Spotlight.
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, nil, NSArray, NSData, NSString};
+
+/// A piece of app content that can show up in Spotlight results - a document, a contact, a
+/// recipe, and so on.
+///
+/// When the user selects the result, your app is sent a user activity that carries the
+/// `unique_identifier`; see `UserActivity::searchable_item_identifier()`.
+#[derive(Clone, Debug, Default)]
+pub struct SearchableItem {
+    /// Identifies this item within your app. This is what you get back when the user selects it,
+    /// and what you delete it by - so it should be enough to find the content again (e.g, a
+    /// database ID, or a path).
+    pub unique_identifier: String,
+
+    /// An optional grouping (e.g, `"notes"` or a folder identifier), which lets you delete a whole
+    /// group of items at once.
+    pub domain_identifier: Option<String>,
+
+    /// The title shown in search results.
+    pub title: String,
+
+    /// A short description, shown below the title.
+    pub description: Option<String>,
+
+    /// Additional terms that this item should match on.
+    pub keywords: Vec<String>,
+
+    /// Image data (PNG, JPEG, etc) for the result's thumbnail.
+    pub thumbnail_data: Option<Vec<u8>>,
+
+    /// The uniform type identifier of the content. Defaults to `public.content`.
+    pub content_type: Option<String>
+}
+
+impl SearchableItem {
+    /// Creates a new item with the given identifier and title.
+    pub fn new(unique_identifier: &str, title: &str) -> Self {
+        SearchableItem {
+            unique_identifier: unique_identifier.to_string(),
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Builds the `CSSearchableItem` (and its attribute set) for this item.
+    pub(crate) fn to_objc(&self) -> Id<Object, Shared> {
+        let content_type = NSString::new(self.content_type.as_deref().unwrap_or("public.content"));
+        let unique_identifier = NSString::new(&self.unique_identifier);
+        let domain_identifier = self.domain_identifier.as_deref().map(NSString::new);
+        let title = NSString::new(&self.title);
+
+        unsafe {
+            let alloc: id = msg_send![class!(CSSearchableItemAttributeSet), alloc];
+            let attributes: id = msg_send![alloc, initWithItemContentType: &*content_type];
+            let _: () = msg_send![attributes, setTitle: &*title];
+
+            if let Some(description) = &self.description {
+                let description = NSString::new(description);
+                let _: () = msg_send![attributes, setContentDescription: &*description];
+            }
+
+            if !self.keywords.is_empty() {
+                let keywords: Vec<NSString> = self.keywords.iter().map(|keyword| NSString::new(keyword)).collect();
+                let keywords: NSArray = keywords
+                    .iter()
+                    .map(|keyword| &*keyword.objc as *const Object as id)
+                    .collect::<Vec<id>>()
+                    .into();
+
+                let _: () = msg_send![attributes, setKeywords: &*keywords];
+            }
+
+            if let Some(data) = &self.thumbnail_data {
+                let data = NSData::with_slice(data);
+                let _: () = msg_send![attributes, setThumbnailData: &*data];
+            }
+
+            let domain_identifier: id = match &domain_identifier {
+                Some(domain) => &*domain.objc as *const Object as id,
+                None => nil
+            };
+
+            let item = msg_send_id![
+                msg_send_id![class!(CSSearchableItem), alloc],
+                initWithUniqueIdentifier: &*unique_identifier,
+                domainIdentifier: domain_identifier,
+                attributeSet: attributes,
+            ];
+
+            let _: () = msg_send![attributes, release];
+            item
+        }
+    }
+}
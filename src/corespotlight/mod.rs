@@ -0,0 +1,157 @@
+//! Wraps `CoreSpotlight.framework`, for making your app's content searchable from Spotlight.
+//!
+//! Index content by handing `SearchableItem`s to a `SearchableIndex`. When the user picks one of
+//! your results, the app is sent a user activity; check for it in
+//! `AppDelegate::continue_user_activity()` and deep link to the content:
+//!
+//! ```rust,no_run
+//! use cacao::appkit::AppDelegate;
+//! use cacao::user_activity::UserActivity;
+//!
+//! struct MyApp;
+//!
+//! impl AppDelegate for MyApp {
+//!     fn continue_user_activity<F: Fn()>(&self, activity: UserActivity, _handler: F) -> bool {
+//!         match activity.searchable_item_identifier() {
+//!             Some(identifier) => {
+//!                 // Open the note, document, etc with this identifier.
+//!                 true
+//!             },
+//!
+//!             None => false
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! To use this module, you must specify the `corespotlight` feature flag in your `Cargo.toml`.
+
+use block::ConcreteBlock;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::error::Error;
+use crate::foundation::{id, nil, to_bool, NSArray, NSString};
+
+mod item;
+pub use item::SearchableItem;
+
+extern "C" {
+    /// The activity type of user activities created for selected Spotlight results.
+    pub(crate) static CSSearchableItemActionType: id;
+
+    /// The `userInfo` key holding the selected item's unique identifier.
+    pub(crate) static CSSearchableItemActivityIdentifier: id;
+}
+
+/// Wraps `CSSearchableIndex`, the on-device index that Spotlight searches.
+///
+/// Completion callbacks can come back on a separate thread, so react accordingly to get to the
+/// main thread if you need to.
+#[derive(Debug)]
+pub struct SearchableIndex(pub Id<Object, Shared>);
+
+impl Default for SearchableIndex {
+    /// Returns the default searchable index for the app.
+    fn default() -> Self {
+        SearchableIndex(unsafe { msg_send_id![class!(CSSearchableIndex), defaultSearchableIndex] })
+    }
+}
+
+impl SearchableIndex {
+    /// Returns whether indexing is available on this device.
+    pub fn is_indexing_available() -> bool {
+        to_bool(unsafe { msg_send![class!(CSSearchableIndex), isIndexingAvailable] })
+    }
+
+    /// Adds (or updates, if the identifier is already indexed) `items` in the index.
+    pub fn index<F>(&self, items: &[SearchableItem], completion: F)
+    where
+        F: Fn(Result<(), Error>) + Send + Sync + 'static
+    {
+        let items: Vec<Id<Object, Shared>> = items.iter().map(|item| item.to_objc()).collect();
+        let items: NSArray = items
+            .iter()
+            .map(|item| &**item as *const Object as id)
+            .collect::<Vec<id>>()
+            .into();
+
+        let block = completion_block(completion);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, indexSearchableItems: &*items, completionHandler: &*block];
+        }
+    }
+
+    /// Removes the items with the given unique identifiers from the index.
+    pub fn delete_items<F>(&self, identifiers: &[&str], completion: F)
+    where
+        F: Fn(Result<(), Error>) + Send + Sync + 'static
+    {
+        let identifiers = strings_to_array(identifiers);
+        let block = completion_block(completion);
+
+        unsafe {
+            let _: () = msg_send![
+                &*self.0,
+                deleteSearchableItemsWithIdentifiers: &*identifiers,
+                completionHandler: &*block
+            ];
+        }
+    }
+
+    /// Removes every item in the given domains from the index.
+    pub fn delete_domains<F>(&self, domains: &[&str], completion: F)
+    where
+        F: Fn(Result<(), Error>) + Send + Sync + 'static
+    {
+        let domains = strings_to_array(domains);
+        let block = completion_block(completion);
+
+        unsafe {
+            let _: () = msg_send![
+                &*self.0,
+                deleteSearchableItemsWithDomainIdentifiers: &*domains,
+                completionHandler: &*block
+            ];
+        }
+    }
+
+    /// Removes everything this app has indexed.
+    pub fn delete_all<F>(&self, completion: F)
+    where
+        F: Fn(Result<(), Error>) + Send + Sync + 'static
+    {
+        let block = completion_block(completion);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, deleteAllSearchableItemsWithCompletionHandler: &*block];
+        }
+    }
+}
+
+/// Wraps a completion callback in the `void (^)(NSError *)` block that CoreSpotlight expects.
+fn completion_block<F>(completion: F) -> block::RcBlock<(id,), ()>
+where
+    F: Fn(Result<(), Error>) + Send + Sync + 'static
+{
+    let block = ConcreteBlock::new(move |error: id| match error == nil {
+        true => completion(Ok(())),
+        false => completion(Err(Error::new(error)))
+    });
+
+    block.copy()
+}
+
+/// Converts a slice of strings into an `NSArray` of `NSString`s.
+fn strings_to_array(strings: &[&str]) -> NSArray {
+    let strings: Vec<NSString> = strings.iter().map(|string| NSString::new(string)).collect();
+
+    strings
+        .iter()
+        .map(|string| &*string.objc as *const Object as id)
+        .collect::<Vec<id>>()
+        .into()
+}
@@ -0,0 +1,149 @@
+//! An opt-in debugging subsystem for tracking down memory issues, enabled via the
+//! `object-tracking` feature.
+//!
+//! With it enabled, every Objective-C object that cacao wraps - views and other controls (via
+//! their property wrappers), windows, and view controllers - is counted, by class, until the
+//! Objective-C object itself is deallocated. You can dump these counts at any point to find
+//! objects that aren't being released:
+//!
+//! ```rust,no_run
+//! cacao::debug::dump_live_objects();
+//! ```
+//!
+//! It also prints warnings for two common lifetime mistakes: dropping a view while it's still
+//! installed in a view hierarchy (it's removed as a result), and the system calling into a
+//! component after its Rust delegate has been dropped (those calls are ignored).
+//!
+//! This has a runtime cost, and is intended for debug builds only.
+
+use std::collections::{HashMap, HashSet};
+use std::ffi::c_void;
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, ffi, msg_send, sel};
+
+use crate::foundation::{id, load_or_register_class, nil};
+
+lazy_static! {
+    static ref LIVE_OBJECTS: Mutex<HashMap<String, usize>> = Mutex::new(HashMap::new());
+    static ref WARNED_CLASSES: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+static TRACKED_CLASS_PTR: &str = "rstTrackedClass";
+
+/// Used as the key for the associated tracker object; only its address matters.
+static TRACKER_KEY: u8 = 0;
+
+/// Called when the tracked object (and so, its tracker) is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        let ptr: usize = *this.get_ivar(TRACKED_CLASS_PTR);
+
+        if ptr != 0 {
+            let class = Box::from_raw(ptr as *mut String);
+            let mut objects = LIVE_OBJECTS.lock().unwrap();
+
+            if let Some(count) = objects.get_mut(&*class) {
+                *count -= 1;
+
+                if *count == 0 {
+                    objects.remove(&*class);
+                }
+            }
+
+            this.set_ivar(TRACKED_CLASS_PTR, 0usize);
+        }
+
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
+/// Registers the tracker class, which does nothing but note when it's deallocated.
+fn register_tracker_class() -> &'static Class {
+    load_or_register_class("NSObject", "RSTObjectTracker", |decl| unsafe {
+        decl.add_ivar::<usize>(TRACKED_CLASS_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
+    })
+}
+
+/// Counts `obj` as live until it's deallocated. Tracking the same object again does nothing.
+///
+/// Rather than counting Rust references (which says nothing about whether the Objective-C side
+/// is still around), this attaches a tracker as an associated object; the runtime releases it
+/// when `obj` is deallocated, at which point the count goes back down.
+pub(crate) fn track(obj: &Object) {
+    let key = &TRACKER_KEY as *const u8 as *const c_void;
+    let obj = obj as *const Object as id;
+
+    unsafe {
+        let existing: id = ffi::objc_getAssociatedObject(obj as *const _, key) as id;
+        if existing != nil {
+            return;
+        }
+
+        let class = (*obj).class().name().to_string();
+        *LIVE_OBJECTS.lock().unwrap().entry(class.clone()).or_insert(0) += 1;
+
+        let tracker: id = msg_send![register_tracker_class(), new];
+        (&mut *tracker).set_ivar(TRACKED_CLASS_PTR, Box::into_raw(Box::new(class)) as usize);
+
+        // The associated object retains the tracker, so we can let go of ours.
+        ffi::objc_setAssociatedObject(obj as *mut _, key, tracker as *mut _, ffi::OBJC_ASSOCIATION_RETAIN_NONATOMIC);
+
+        let _: () = msg_send![tracker, release];
+    }
+}
+
+/// Warns if a view that's being dropped is still installed in a view hierarchy. This needs to be
+/// called before the view removes itself from its superview.
+pub(crate) fn check_view_drop(view: &Object) {
+    let superview: id = unsafe { msg_send![view, superview] };
+
+    if superview != nil {
+        eprintln!(
+            "[cacao] {} was dropped while still installed in the view hierarchy, and has been removed from it. Keep the Rust value alive for as long as the view should be on screen.",
+            view.class().name()
+        );
+    }
+}
+
+/// Warns that `obj` received a callback after its Rust delegate was dropped. This is only printed
+/// once per class, as it tends to happen for every subsequent callback.
+pub(crate) fn warn_delegate_dropped(obj: &Object) {
+    let class = obj.class().name();
+
+    if WARNED_CLASSES.lock().unwrap().insert(class.to_string()) {
+        eprintln!(
+            "[cacao] {} is still alive and receiving callbacks after its delegate was dropped; they're being ignored. Something is retaining the Objective-C object past the life of its Rust owner.",
+            class
+        );
+    }
+}
+
+/// Returns the number of live objects, by class name, sorted with the most numerous first.
+pub fn live_objects() -> Vec<(String, usize)> {
+    let objects = LIVE_OBJECTS.lock().unwrap();
+    let mut counts: Vec<(String, usize)> = objects.iter().map(|(class, count)| (class.clone(), *count)).collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// Returns the total number of live objects being tracked.
+pub fn total_live_objects() -> usize {
+    LIVE_OBJECTS.lock().unwrap().values().sum()
+}
+
+/// Prints the live object counts, by class, to stderr.
+pub fn dump_live_objects() {
+    let counts = live_objects();
+
+    eprintln!(
+        "[cacao] {} live objects:",
+        counts.iter().map(|(_, count)| count).sum::<usize>()
+    );
+    for (class, count) in counts {
+        eprintln!("    {:>6}  {}", count, class);
+    }
+}
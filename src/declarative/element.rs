@@ -0,0 +1,225 @@
+//! Descriptions of views, as returned from a component function. These are cheap to build and
+//! throw away; the `Root` reconciles them against the real controls.
+
+use std::fmt;
+use std::rc::Rc;
+
+/// The direction a stack lays its children out in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Axis {
+    /// Children are laid out top to bottom.
+    Vertical,
+
+    /// Children are laid out leading to trailing.
+    Horizontal
+}
+
+/// A description of a view. Build these with the functions in this module (`vstack`, `label`,
+/// `button` and so on), rather than by hand.
+#[derive(Clone)]
+pub enum Element {
+    /// A container that lays out its children in a row or column.
+    Stack {
+        /// The direction children are laid out in.
+        axis: Axis,
+
+        /// The space between each child.
+        spacing: f64,
+
+        /// The space between the children and the edges of the stack.
+        padding: f64,
+
+        /// The child elements.
+        children: Vec<Element>
+    },
+
+    /// A non-editable piece of text.
+    Label {
+        /// The text to display.
+        text: String
+    },
+
+    /// A push button.
+    Button {
+        /// The button title.
+        title: String,
+
+        /// Called when the button is clicked.
+        on_click: Rc<dyn Fn()>
+    },
+
+    /// An editable, single-line text field.
+    TextField {
+        /// The text in the field.
+        value: String,
+
+        /// Placeholder text, shown when the field is empty.
+        placeholder: String,
+
+        /// Called with the new value whenever the user edits the field.
+        on_change: Option<Rc<dyn Fn(String)>>
+    },
+
+    /// A checkbox-style switch.
+    Switch {
+        /// The switch title.
+        title: String,
+
+        /// Whether the switch is checked.
+        checked: bool,
+
+        /// Called with the new state whenever the user toggles the switch.
+        on_toggle: Option<Rc<dyn Fn(bool)>>
+    },
+
+    /// An empty view, for taking up space in a stack.
+    Spacer
+}
+
+impl Element {
+    /// Sets the space between each child of a stack. This has no effect on other elements.
+    pub fn spacing(mut self, value: f64) -> Self {
+        if let Element::Stack { spacing, .. } = &mut self {
+            *spacing = value;
+        }
+
+        self
+    }
+
+    /// Sets the space between the children of a stack and its edges. This has no effect on other
+    /// elements.
+    pub fn padding(mut self, value: f64) -> Self {
+        if let Element::Stack { padding, .. } = &mut self {
+            *padding = value;
+        }
+
+        self
+    }
+
+    /// Sets the placeholder text of a text field. This has no effect on other elements.
+    pub fn placeholder<S: Into<String>>(mut self, text: S) -> Self {
+        if let Element::TextField { placeholder, .. } = &mut self {
+            *placeholder = text.into();
+        }
+
+        self
+    }
+}
+
+impl fmt::Debug for Element {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Element::Stack {
+                axis,
+                spacing,
+                padding,
+                children
+            } => f
+                .debug_struct("Stack")
+                .field("axis", axis)
+                .field("spacing", spacing)
+                .field("padding", padding)
+                .field("children", children)
+                .finish(),
+
+            Element::Label { text } => f.debug_struct("Label").field("text", text).finish(),
+            Element::Button { title, .. } => f.debug_struct("Button").field("title", title).finish(),
+
+            Element::TextField { value, placeholder, .. } => f
+                .debug_struct("TextField")
+                .field("value", value)
+                .field("placeholder", placeholder)
+                .finish(),
+
+            Element::Switch { title, checked, .. } => f
+                .debug_struct("Switch")
+                .field("title", title)
+                .field("checked", checked)
+                .finish(),
+
+            Element::Spacer => f.write_str("Spacer")
+        }
+    }
+}
+
+/// A stack that lays out `children` top to bottom.
+pub fn vstack(children: Vec<Element>) -> Element {
+    Element::Stack {
+        axis: Axis::Vertical,
+        spacing: 8.,
+        padding: 0.,
+        children
+    }
+}
+
+/// A stack that lays out `children` leading to trailing.
+pub fn hstack(children: Vec<Element>) -> Element {
+    Element::Stack {
+        axis: Axis::Horizontal,
+        spacing: 8.,
+        padding: 0.,
+        children
+    }
+}
+
+/// A non-editable piece of text.
+pub fn label<S: Into<String>>(text: S) -> Element {
+    Element::Label { text: text.into() }
+}
+
+/// A push button, calling `on_click` when clicked.
+pub fn button<S: Into<String>, F: Fn() + 'static>(title: S, on_click: F) -> Element {
+    Element::Button {
+        title: title.into(),
+        on_click: Rc::new(on_click)
+    }
+}
+
+/// A text field displaying `value`, calling `on_change` as the user edits it.
+pub fn text_field<S: Into<String>, F: Fn(String) + 'static>(value: S, on_change: F) -> Element {
+    Element::TextField {
+        value: value.into(),
+        placeholder: String::new(),
+        on_change: Some(Rc::new(on_change))
+    }
+}
+
+/// A switch that's checked according to `checked`, calling `on_toggle` when the user toggles it.
+pub fn switch<S: Into<String>, F: Fn(bool) + 'static>(title: S, checked: bool, on_toggle: F) -> Element {
+    Element::Switch {
+        title: title.into(),
+        checked,
+        on_toggle: Some(Rc::new(on_toggle))
+    }
+}
+
+/// An empty view, for taking up space in a stack.
+pub fn spacer() -> Element {
+    Element::Spacer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{label, vstack, Axis, Element};
+
+    #[test]
+    fn stack_modifiers() {
+        let stack = vstack(vec![label("Hello")]).spacing(4.).padding(12.);
+
+        match stack {
+            Element::Stack {
+                axis,
+                spacing,
+                padding,
+                children
+            } => {
+                assert_eq!(axis, Axis::Vertical);
+                assert_eq!(spacing, 4.);
+                assert_eq!(padding, 12.);
+                assert_eq!(children.len(), 1);
+            },
+
+            _ => panic!("expected a stack")
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! An optional, declarative layer for building views.
+//!
+//! Rather than creating controls and wiring them up by hand, you write a component function that
+//! returns a tree of `Element`s describing what the UI should look like. A `Root` renders that
+//! tree into real controls (the same `View`, `Label`, `Button` etc you'd use otherwise), and when
+//! state changes, it re-runs the function and updates the existing controls to match - only
+//! rebuilding the ones whose kind actually changed.
+//!
+//! State lives in hooks: `Context::use_state` returns a handle to a value that persists across
+//! renders, and setting it schedules a re-render.
+//!
+//! ```rust,no_run
+//! use cacao::declarative::{button, label, vstack, Context, Element, Root};
+//!
+//! fn counter(cx: &mut Context) -> Element {
+//!     let count = cx.use_state(|| 0);
+//!
+//!     vstack(vec![
+//!         label(format!("Clicked {} times", count.get())),
+//!         button("Click me", move || count.set(count.get() + 1)),
+//!     ])
+//!     .padding(16.)
+//! }
+//!
+//! let root = Root::new(counter);
+//!
+//! // `root.view` is a regular `View`; add it to a window or another view, and keep `root` alive
+//! // for as long as it's on screen.
+//! ```
+//!
+//! This is built entirely on top of the existing wrappers, and you can mix the two freely - a
+//! `Root` can live anywhere a `View` can. The set of elements is intentionally small for now;
+//! anything more involved is best done with the controls directly.
+//!
+//! Hooks follow the same rule as in other hook-based frameworks: call them unconditionally, and in
+//! the same order, on every render. They're identified by the order they're called in.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+use crate::layout::LayoutConstraint;
+use crate::view::View;
+
+mod element;
+pub use element::{button, hstack, label, spacer, switch, text_field, vstack, Axis, Element};
+
+mod native;
+use native::Native;
+
+thread_local! {
+    /// Live hosts, keyed by id. Re-renders are scheduled on the main queue, which requires `Send`
+    /// closures; the closure carries the id and looks the host up here.
+    static HOSTS: RefCell<HashMap<usize, Weak<Host>>> = RefCell::new(HashMap::new());
+
+    static NEXT_HOST_ID: Cell<usize> = Cell::new(0);
+}
+
+/// The state behind a `Root`.
+struct Host {
+    id: usize,
+    view: View,
+    component: Box<dyn Fn(&mut Context) -> Element>,
+    hooks: RefCell<Vec<Rc<dyn Any>>>,
+    tree: RefCell<Option<Native>>,
+    constraints: RefCell<Vec<LayoutConstraint>>,
+    render_scheduled: Cell<bool>
+}
+
+impl Host {
+    /// Runs the component, and reconciles the result against the current controls.
+    fn render(self: &Rc<Self>) {
+        self.render_scheduled.set(false);
+
+        let element = {
+            let mut context = Context {
+                host: self.clone(),
+                index: 0
+            };
+
+            (self.component)(&mut context)
+        };
+
+        let previous = self.tree.borrow_mut().take();
+
+        let tree = match previous {
+            Some(previous) => {
+                let (tree, replaced) = previous.reconcile(element);
+
+                if replaced {
+                    self.attach(&tree);
+                }
+
+                tree
+            },
+
+            None => {
+                let tree = Native::mount(element);
+                self.attach(&tree);
+                tree
+            }
+        };
+
+        *self.tree.borrow_mut() = Some(tree);
+    }
+
+    /// Adds the top-level control to the root view, pinned to its edges.
+    fn attach(&self, tree: &Native) {
+        tree.add_to(&self.view);
+
+        let (top, bottom, leading, trailing) = tree.anchors();
+        let constraints = vec![
+            top.constraint_equal_to(&self.view.top),
+            leading.constraint_equal_to(&self.view.leading),
+            trailing.constraint_equal_to(&self.view.trailing),
+            bottom.constraint_equal_to(&self.view.bottom),
+        ];
+
+        LayoutConstraint::deactivate(&self.constraints.borrow());
+        LayoutConstraint::activate(&constraints);
+        *self.constraints.borrow_mut() = constraints;
+    }
+
+    /// Schedules a re-render on the next pass of the main queue. Multiple state changes before
+    /// then are coalesced into a single render.
+    fn schedule_render(&self) {
+        if self.render_scheduled.replace(true) {
+            return;
+        }
+
+        let id = self.id;
+
+        dispatch::Queue::main().exec_async(move || {
+            let host = HOSTS.with(|hosts| hosts.borrow().get(&id).and_then(Weak::upgrade));
+
+            if let Some(host) = host {
+                host.render();
+            }
+        });
+    }
+}
+
+impl Drop for Host {
+    fn drop(&mut self) {
+        if let Some(tree) = self.tree.get_mut().take() {
+            tree.unmount();
+        }
+
+        let _ = HOSTS.try_with(|hosts| hosts.borrow_mut().remove(&self.id));
+    }
+}
+
+/// Renders a component into a `View`, and keeps it up to date as the component's state changes.
+///
+/// Dropping the `Root` tears down the rendered controls, so hold onto it for as long as it should
+/// be on screen.
+pub struct Root {
+    /// The view that the component renders into. Add this to your view hierarchy.
+    pub view: View,
+
+    host: Rc<Host>
+}
+
+impl Root {
+    /// Creates a new `Root`, and renders `component` into it.
+    pub fn new<F>(component: F) -> Self
+    where
+        F: Fn(&mut Context) -> Element + 'static
+    {
+        let id = NEXT_HOST_ID.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            id
+        });
+
+        let view = View::new();
+
+        let host = Rc::new(Host {
+            id,
+            view: view.clone_as_handle(),
+            component: Box::new(component),
+            hooks: RefCell::new(Vec::new()),
+            tree: RefCell::new(None),
+            constraints: RefCell::new(Vec::new()),
+            render_scheduled: Cell::new(false)
+        });
+
+        HOSTS.with(|hosts| hosts.borrow_mut().insert(id, Rc::downgrade(&host)));
+        host.render();
+
+        Root { view, host }
+    }
+
+    /// Re-renders the component immediately, rather than waiting on a state change.
+    pub fn render(&self) {
+        self.host.render();
+    }
+}
+
+impl fmt::Debug for Root {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Root")
+            .field("view", &self.view)
+            .field("tree", &*self.host.tree.borrow())
+            .finish()
+    }
+}
+
+/// Passed to component functions, and used to access hooks.
+pub struct Context {
+    host: Rc<Host>,
+    index: usize
+}
+
+impl Context {
+    /// Returns a handle to a piece of state that persists across renders. `initial` is called to
+    /// create the value on the first render only.
+    ///
+    /// Panics if hooks are called in a different order than on the previous render.
+    pub fn use_state<T, F>(&mut self, initial: F) -> UseState<T>
+    where
+        T: 'static,
+        F: FnOnce() -> T
+    {
+        let index = self.index;
+        self.index += 1;
+
+        let mut hooks = self.host.hooks.borrow_mut();
+
+        if index == hooks.len() {
+            hooks.push(Rc::new(RefCell::new(initial())));
+        }
+
+        let value = hooks[index]
+            .clone()
+            .downcast::<RefCell<T>>()
+            .expect("Hook order changed between renders; hooks must be called in the same order every time");
+
+        UseState {
+            value,
+            host: Rc::downgrade(&self.host)
+        }
+    }
+}
+
+impl fmt::Debug for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Context").field("index", &self.index).finish()
+    }
+}
+
+/// A handle to a piece of component state, returned from `Context::use_state`. Setting the value
+/// schedules a re-render of the component that owns it.
+///
+/// Handles are cheap to clone, and are typically moved into event handlers.
+pub struct UseState<T> {
+    value: Rc<RefCell<T>>,
+    host: Weak<Host>
+}
+
+impl<T> UseState<T> {
+    /// Runs `handler` with a reference to the current value.
+    pub fn with<R, F: FnOnce(&T) -> R>(&self, handler: F) -> R {
+        handler(&self.value.borrow())
+    }
+
+    /// Sets the value, and schedules a re-render.
+    pub fn set(&self, value: T) {
+        *self.value.borrow_mut() = value;
+        self.schedule_render();
+    }
+
+    /// Updates the value in place, and schedules a re-render.
+    pub fn update<F: FnOnce(&mut T)>(&self, handler: F) {
+        handler(&mut self.value.borrow_mut());
+        self.schedule_render();
+    }
+
+    fn schedule_render(&self) {
+        if let Some(host) = self.host.upgrade() {
+            host.schedule_render();
+        }
+    }
+}
+
+impl<T: Clone> UseState<T> {
+    /// Returns a clone of the current value.
+    pub fn get(&self) -> T {
+        self.value.borrow().clone()
+    }
+}
+
+impl<T> Clone for UseState<T> {
+    fn clone(&self) -> Self {
+        UseState {
+            value: self.value.clone(),
+            host: self.host.clone()
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for UseState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UseState").field(&*self.value.borrow()).finish()
+    }
+}
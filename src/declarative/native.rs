@@ -0,0 +1,479 @@
+//! The real controls backing an `Element` tree, and the logic for reconciling a new tree against
+//! them.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use objc::runtime::Object;
+use objc::{msg_send, sel};
+
+use crate::binding::NotificationObserver;
+use crate::button::Button;
+use crate::foundation::{NSInteger, NSString};
+use crate::input::TextField;
+use crate::layout::{Layout, LayoutAnchorX, LayoutAnchorY, LayoutConstraint};
+use crate::switch::Switch;
+use crate::text::Label;
+use crate::view::View;
+
+use super::element::{Axis, Element};
+
+/// A Rust callback for a button or switch.
+#[derive(Clone)]
+enum Handler {
+    Click(Rc<dyn Fn()>),
+    Toggle(Rc<dyn Fn(bool)>)
+}
+
+thread_local! {
+    /// Target/action handlers need to be `Send + Sync`, but the callbacks in an `Element` tree
+    /// capture main-thread state. Actions only ever fire on the main thread, so the action just
+    /// carries an id, and we look the callback up here.
+    static HANDLERS: RefCell<HashMap<usize, Handler>> = RefCell::new(HashMap::new());
+
+    static NEXT_HANDLER_ID: Cell<usize> = Cell::new(0);
+}
+
+/// Stores `handler`, returning an id that can be used to call it.
+fn register_handler(handler: Handler) -> usize {
+    let id = NEXT_HANDLER_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    });
+
+    HANDLERS.with(|handlers| handlers.borrow_mut().insert(id, handler));
+    id
+}
+
+/// Replaces the handler stored for `id`.
+fn update_handler(id: usize, handler: Handler) {
+    HANDLERS.with(|handlers| handlers.borrow_mut().insert(id, handler));
+}
+
+/// Removes the handler stored for `id`.
+fn remove_handler(id: usize) {
+    let _ = HANDLERS.try_with(|handlers| handlers.borrow_mut().remove(&id));
+}
+
+/// Calls the handler stored for `id`, if there is one. The handler is cloned out first, so it's
+/// free to trigger changes to the handler registry.
+fn perform_handler(id: usize, sender: *const Object) {
+    let handler = HANDLERS.with(|handlers| handlers.borrow().get(&id).cloned());
+
+    match handler {
+        Some(Handler::Click(on_click)) => on_click(),
+
+        Some(Handler::Toggle(on_toggle)) => {
+            let state: NSInteger = unsafe { msg_send![sender, state] };
+            on_toggle(state != 0);
+        },
+
+        None => {}
+    }
+}
+
+/// The control backing a single `Element`.
+pub(crate) enum Native {
+    Stack {
+        view: View,
+        axis: Axis,
+        spacing: f64,
+        padding: f64,
+        children: Vec<Native>,
+        constraints: Vec<LayoutConstraint>
+    },
+
+    Label(Label),
+
+    Button {
+        button: Button,
+        handler: usize
+    },
+
+    TextField {
+        field: TextField,
+        on_change: Rc<RefCell<Option<Rc<dyn Fn(String)>>>>,
+        observer: NotificationObserver
+    },
+
+    Switch {
+        switch: Switch,
+        handler: usize
+    },
+
+    Spacer(View)
+}
+
+impl Native {
+    /// Builds the controls for `element`.
+    pub(crate) fn mount(element: Element) -> Native {
+        match element {
+            Element::Stack {
+                axis,
+                spacing,
+                padding,
+                children
+            } => {
+                let view = View::new();
+                let children: Vec<Native> = children.into_iter().map(Native::mount).collect();
+
+                for child in &children {
+                    child.add_to(&view);
+                }
+
+                let constraints = stack_constraints(&view, axis, spacing, padding, &children);
+                LayoutConstraint::activate(&constraints);
+
+                Native::Stack {
+                    view,
+                    axis,
+                    spacing,
+                    padding,
+                    children,
+                    constraints
+                }
+            },
+
+            Element::Label { text } => {
+                let label = Label::new();
+                label.set_text(text);
+                Native::Label(label)
+            },
+
+            Element::Button { title, on_click } => {
+                let mut button = Button::new(&title);
+                let handler = register_handler(Handler::Click(on_click));
+                button.set_action(move |sender| perform_handler(handler, sender));
+                Native::Button { button, handler }
+            },
+
+            Element::TextField {
+                value,
+                placeholder,
+                on_change
+            } => {
+                let field = TextField::new();
+                field.set_text(&value);
+                field.set_placeholder_text(&placeholder);
+
+                let on_change = Rc::new(RefCell::new(on_change));
+                let callback = on_change.clone();
+                let objc = field.objc.clone();
+
+                let observer = field.objc.get(|obj| {
+                    NotificationObserver::new("NSControlTextDidChangeNotification", obj, move || {
+                        let handler = callback.borrow().clone();
+
+                        if let Some(handler) = handler {
+                            let value = objc.get(|obj| unsafe { NSString::retain(msg_send![obj, stringValue]).to_string() });
+
+                            handler(value);
+                        }
+                    })
+                });
+
+                Native::TextField {
+                    field,
+                    on_change,
+                    observer
+                }
+            },
+
+            Element::Switch {
+                title,
+                checked,
+                on_toggle
+            } => {
+                let mut switch = Switch::new(&title);
+                switch.set_checked(checked);
+
+                let handler = register_handler(match on_toggle {
+                    Some(on_toggle) => Handler::Toggle(on_toggle),
+                    None => Handler::Toggle(Rc::new(|_| {}))
+                });
+                switch.set_action(move |sender| perform_handler(handler, sender));
+
+                Native::Switch { switch, handler }
+            },
+
+            Element::Spacer => Native::Spacer(View::new())
+        }
+    }
+
+    /// Updates these controls to match `element`. If `element` can't be represented by the
+    /// existing controls, they're torn down and a new set is returned; the `bool` indicates
+    /// whether this happened, in which case the caller needs to re-attach the result.
+    pub(crate) fn reconcile(self, element: Element) -> (Native, bool) {
+        if !self.kind_matches(&element) {
+            self.unmount();
+            return (Native::mount(element), true);
+        }
+
+        let native = match (self, element) {
+            (
+                Native::Stack {
+                    view,
+                    axis,
+                    spacing: old_spacing,
+                    padding: old_padding,
+                    children,
+                    constraints
+                },
+                Element::Stack {
+                    spacing,
+                    padding,
+                    children: elements,
+                    ..
+                }
+            ) => {
+                let mut relayout = children.len() != elements.len() || spacing != old_spacing || padding != old_padding;
+                let mut old_children = children.into_iter();
+                let mut new_children = Vec::with_capacity(elements.len());
+
+                for element in elements {
+                    match old_children.next() {
+                        Some(child) => {
+                            let (child, replaced) = child.reconcile(element);
+
+                            if replaced {
+                                child.add_to(&view);
+                                relayout = true;
+                            }
+
+                            new_children.push(child);
+                        },
+
+                        None => {
+                            let child = Native::mount(element);
+                            child.add_to(&view);
+                            new_children.push(child);
+                        }
+                    }
+                }
+
+                for child in old_children {
+                    child.unmount();
+                }
+
+                let constraints = if relayout {
+                    LayoutConstraint::deactivate(&constraints);
+                    let constraints = stack_constraints(&view, axis, spacing, padding, &new_children);
+                    LayoutConstraint::activate(&constraints);
+                    constraints
+                } else {
+                    constraints
+                };
+
+                Native::Stack {
+                    view,
+                    axis,
+                    spacing,
+                    padding,
+                    children: new_children,
+                    constraints
+                }
+            },
+
+            (Native::Label(label), Element::Label { text }) => {
+                if label.get_text() != text {
+                    label.set_text(text);
+                }
+
+                Native::Label(label)
+            },
+
+            (Native::Button { button, handler }, Element::Button { title, on_click }) => {
+                button.set_text(&title);
+                update_handler(handler, Handler::Click(on_click));
+                Native::Button { button, handler }
+            },
+
+            (
+                Native::TextField {
+                    field,
+                    on_change: slot,
+                    observer
+                },
+                Element::TextField {
+                    value,
+                    placeholder,
+                    on_change
+                }
+            ) => {
+                // Don't clobber the field (and the cursor position) if this is just the value the
+                // user typed coming back around.
+                if field.get_value() != value {
+                    field.set_text(&value);
+                }
+
+                field.set_placeholder_text(&placeholder);
+                *slot.borrow_mut() = on_change;
+
+                Native::TextField {
+                    field,
+                    on_change: slot,
+                    observer
+                }
+            },
+
+            (
+                Native::Switch { mut switch, handler },
+                Element::Switch {
+                    title,
+                    checked,
+                    on_toggle
+                }
+            ) => {
+                switch.objc.with_mut(|obj| unsafe {
+                    let title = NSString::new(&title);
+                    let _: () = msg_send![obj, setTitle: &*title];
+                });
+
+                switch.set_checked(checked);
+
+                update_handler(handler, match on_toggle {
+                    Some(on_toggle) => Handler::Toggle(on_toggle),
+                    None => Handler::Toggle(Rc::new(|_| {}))
+                });
+
+                Native::Switch { switch, handler }
+            },
+
+            (native, _) => native
+        };
+
+        (native, false)
+    }
+
+    /// Whether `element` can be applied to these controls.
+    fn kind_matches(&self, element: &Element) -> bool {
+        match (self, element) {
+            (Native::Stack { axis: a, .. }, Element::Stack { axis: b, .. }) => a == b,
+            (Native::Label(_), Element::Label { .. }) => true,
+            (Native::Button { .. }, Element::Button { .. }) => true,
+            (Native::TextField { .. }, Element::TextField { .. }) => true,
+            (Native::Switch { .. }, Element::Switch { .. }) => true,
+            (Native::Spacer(_), Element::Spacer) => true,
+            _ => false
+        }
+    }
+
+    /// Adds the backing control to `parent`.
+    pub(crate) fn add_to(&self, parent: &View) {
+        match self {
+            Native::Stack { view, .. } | Native::Spacer(view) => parent.add_subview(view),
+            Native::Label(label) => parent.add_subview(label),
+            Native::Button { button, .. } => parent.add_subview(button),
+            Native::TextField { field, .. } => parent.add_subview(field),
+            Native::Switch { switch, .. } => parent.add_subview(switch)
+        }
+    }
+
+    /// Removes the backing control from its superview, and drops any handlers.
+    pub(crate) fn unmount(self) {
+        match self {
+            Native::Stack { view, children, .. } => {
+                for child in children {
+                    child.unmount();
+                }
+
+                view.remove_from_superview();
+            },
+
+            Native::Spacer(view) => view.remove_from_superview(),
+            Native::Label(label) => label.remove_from_superview(),
+
+            Native::Button { button, handler } => {
+                button.remove_from_superview();
+                remove_handler(handler);
+            },
+
+            Native::TextField { field, .. } => field.remove_from_superview(),
+
+            Native::Switch { switch, handler } => {
+                switch.remove_from_superview();
+                remove_handler(handler);
+            }
+        }
+    }
+
+    /// The top, bottom, leading and trailing anchors of the backing control.
+    pub(crate) fn anchors(&self) -> (&LayoutAnchorY, &LayoutAnchorY, &LayoutAnchorX, &LayoutAnchorX) {
+        match self {
+            Native::Stack { view, .. } | Native::Spacer(view) => (&view.top, &view.bottom, &view.leading, &view.trailing),
+            Native::Label(label) => (&label.top, &label.bottom, &label.leading, &label.trailing),
+            Native::Button { button, .. } => (&button.top, &button.bottom, &button.leading, &button.trailing),
+            Native::TextField { field, .. } => (&field.top, &field.bottom, &field.leading, &field.trailing),
+            Native::Switch { switch, .. } => (&switch.top, &switch.bottom, &switch.leading, &switch.trailing)
+        }
+    }
+}
+
+impl fmt::Debug for Native {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Native::Stack {
+                view, axis, children, ..
+            } => f
+                .debug_struct("Stack")
+                .field("view", view)
+                .field("axis", axis)
+                .field("children", children)
+                .finish(),
+
+            Native::Label(label) => f.debug_tuple("Label").field(label).finish(),
+            Native::Button { button, .. } => f.debug_tuple("Button").field(button).finish(),
+            Native::TextField { field, .. } => f.debug_tuple("TextField").field(field).finish(),
+            Native::Switch { switch, .. } => f.debug_tuple("Switch").field(switch).finish(),
+            Native::Spacer(view) => f.debug_tuple("Spacer").field(view).finish()
+        }
+    }
+}
+
+/// Builds the constraints that lay out `children` in a stack along `axis`.
+fn stack_constraints(view: &View, axis: Axis, spacing: f64, padding: f64, children: &[Native]) -> Vec<LayoutConstraint> {
+    let mut constraints = Vec::new();
+    let mut previous: Option<&Native> = None;
+
+    for child in children {
+        let (top, bottom, leading, trailing) = child.anchors();
+
+        match axis {
+            Axis::Vertical => {
+                constraints.push(leading.constraint_equal_to(&view.leading).offset(padding));
+                constraints.push(trailing.constraint_equal_to(&view.trailing).offset(-padding));
+
+                constraints.push(match previous {
+                    Some(previous) => top.constraint_equal_to(previous.anchors().1).offset(spacing),
+                    None => top.constraint_equal_to(&view.top).offset(padding)
+                });
+            },
+
+            Axis::Horizontal => {
+                constraints.push(top.constraint_equal_to(&view.top).offset(padding));
+                constraints.push(bottom.constraint_equal_to(&view.bottom).offset(-padding));
+
+                constraints.push(match previous {
+                    Some(previous) => leading.constraint_equal_to(previous.anchors().3).offset(spacing),
+                    None => leading.constraint_equal_to(&view.leading).offset(padding)
+                });
+            }
+        }
+
+        previous = Some(child);
+    }
+
+    if let Some(last) = previous {
+        let (_, bottom, _, trailing) = last.anchors();
+
+        constraints.push(match axis {
+            Axis::Vertical => bottom.constraint_equal_to(&view.bottom).offset(-padding),
+            Axis::Horizontal => trailing.constraint_equal_to(&view.trailing).offset(-padding)
+        });
+    }
+
+    constraints
+}
@@ -4,16 +4,26 @@
 //! an `NSError`. This might not be what you need, though, so if it's missing something... well,
 //! it's up for discussion.
 
+use std::collections::HashMap;
 use std::error;
 use std::fmt;
 
+use objc::runtime::Object;
 use objc::{class, msg_send, sel};
 
-use crate::foundation::{id, nil, NSInteger, NSString};
+use crate::foundation::{id, nil, NSArray, NSInteger, NSMutableDictionary, NSString};
+
+/// The domain used for errors that originate in cacao itself, rather than from an `NSError`.
+pub const CACAO_ERROR_DOMAIN: &str = "com.cacao-rs";
+
+/// The code (in `CACAO_ERROR_DOMAIN`) for calls to APIs that the running OS doesn't support.
+pub const UNSUPPORTED_ON_THIS_OS: usize = 1;
 
 /// A wrapper around pieces of data extracted from `NSError`. This could be improved: right now, it
 /// allocates `String` instances when theoretically it could be avoided, and we might be erasing
 /// certain parts of the `NSError` object that are useful.
+///
+/// Fallible operations throughout the framework return `Result<T, Error>`.
 #[derive(Clone, Debug)]
 pub struct Error {
     /// Represents the code. Some of these can be... archaic.
@@ -23,29 +33,78 @@ pub struct Error {
     pub domain: String,
 
     /// Maps over to `[NSError localizedDescription]`.
-    pub description: String
+    pub description: String,
+
+    /// Maps over to `[NSError localizedFailureReason]`, if there is one.
+    pub failure_reason: Option<String>,
+
+    /// Maps over to `[NSError localizedRecoverySuggestion]`, if there is one.
+    pub recovery_suggestion: Option<String>,
+
+    /// The string-representable entries of `[NSError userInfo]`. Values that aren't strings are
+    /// converted via their `description`.
+    pub user_info: HashMap<String, String>
 }
 
 impl Error {
     /// Given an `NSError` (i.e, an id reference) we'll pull out the relevant information and
     /// configure this. We pull out the information as it makes the error thread safe this way,
     /// which is... easier, in some cases.
+    ///
+    /// Some APIs return failure without populating an error; if `error` is `nil`, this returns a
+    /// generic error in the cacao domain.
     pub fn new(error: id) -> Self {
-        let (code, domain, description) = unsafe {
+        if error == nil {
+            return Error::custom(CACAO_ERROR_DOMAIN, 0, "An unknown error occurred.");
+        }
+
+        unsafe {
             let code: usize = msg_send![error, code];
             let domain = NSString::retain(msg_send![error, domain]);
             let description = NSString::retain(msg_send![error, localizedDescription]);
 
-            (code, domain, description)
-        };
+            Error {
+                code,
+                domain: domain.to_string(),
+                description: description.to_string(),
+                failure_reason: optional_string(msg_send![error, localizedFailureReason]),
+                recovery_suggestion: optional_string(msg_send![error, localizedRecoverySuggestion]),
+                user_info: user_info(msg_send![error, userInfo])
+            }
+        }
+    }
 
+    /// Creates an error that didn't originate from an `NSError` - e.g, for failures detected on
+    /// the Rust side.
+    pub fn custom(domain: &str, code: usize, description: &str) -> Self {
         Error {
             code,
             domain: domain.to_string(),
-            description: description.to_string()
+            description: description.to_string(),
+            failure_reason: None,
+            recovery_suggestion: None,
+            user_info: HashMap::new()
         }
     }
 
+    /// Creates an error for a call to `api` that requires a newer OS than the one running, e.g
+    /// `Error::unsupported_on_this_os("Window::set_subtitle", "macOS 11")`.
+    pub fn unsupported_on_this_os(api: &str, requirement: &str) -> Self {
+        let mut error = Error::custom(
+            CACAO_ERROR_DOMAIN,
+            UNSUPPORTED_ON_THIS_OS,
+            &format!("{} is not supported on this OS.", api)
+        );
+
+        error.failure_reason = Some(format!("{} requires {} or later.", api, requirement));
+        error
+    }
+
+    /// Returns whether this error came from calling an API the running OS doesn't support.
+    pub fn is_unsupported_on_this_os(&self) -> bool {
+        self.domain == CACAO_ERROR_DOMAIN && self.code == UNSUPPORTED_ON_THIS_OS
+    }
+
     /// Returns a boxed `Error`.
     pub fn boxed(error: id) -> Box<Self> {
         Box::new(Error::new(error))
@@ -54,13 +113,65 @@ impl Error {
     /// Used for cases where we need to return an `NSError` back to the system (e.g, top-level
     /// error handling). We just create a new `NSError` so the `Error` crate can be mostly
     /// thread safe.
+    ///
+    /// The descriptions and string `user_info` entries are carried over.
     pub fn into_nserror(self) -> id {
+        let mut info = NSMutableDictionary::new();
+
+        for (key, value) in self.user_info.iter() {
+            let value = NSString::new(value);
+            info.insert(NSString::new(key), &*value.objc as *const Object as id);
+        }
+
+        let entries = [
+            ("NSLocalizedDescription", Some(&self.description)),
+            ("NSLocalizedFailureReason", self.failure_reason.as_ref()),
+            ("NSLocalizedRecoverySuggestion", self.recovery_suggestion.as_ref())
+        ];
+
+        for (key, value) in entries.iter() {
+            if let Some(value) = value {
+                let value = NSString::new(value);
+                info.insert(NSString::new(key), &*value.objc as *const Object as id);
+            }
+        }
+
         unsafe {
             let domain = NSString::new(&self.domain);
             let code = self.code as NSInteger;
-            msg_send![class!(NSError), errorWithDomain: &*domain, code: code, userInfo: nil]
+            msg_send![class!(NSError), errorWithDomain: &*domain, code: code, userInfo: &*info]
+        }
+    }
+}
+
+/// Converts a possibly-nil `NSString` into an `Option<String>`.
+fn optional_string(string: id) -> Option<String> {
+    match string == nil {
+        true => None,
+        false => Some(NSString::retain(string).to_string())
+    }
+}
+
+/// Flattens an `NSError` `userInfo` dictionary into `String` keys and values.
+fn user_info(dictionary: id) -> HashMap<String, String> {
+    let mut info = HashMap::new();
+
+    if dictionary == nil {
+        return info;
+    }
+
+    unsafe {
+        let keys: id = msg_send![dictionary, allKeys];
+
+        for key in NSArray::retain(keys).iter() {
+            let value: id = msg_send![dictionary, objectForKey: key];
+            let key = NSString::retain(msg_send![key, description]);
+            let value = NSString::retain(msg_send![value, description]);
+            info.insert(key.to_string(), value.to_string());
         }
     }
+
+    info
 }
 
 impl fmt::Display for Error {
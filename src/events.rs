@@ -9,6 +9,9 @@ pub enum EventModifierFlag {
     /// CapsLock (or shift... oddly named...) is held.
     CapsLock,
 
+    /// Shift is held.
+    Shift,
+
     /// Control is held.
     Control,
 
@@ -18,6 +21,9 @@ pub enum EventModifierFlag {
     /// Command (CMD) is held.
     Command,
 
+    /// The Function (Fn) key is held.
+    Function,
+
     /// Device independent flags mask.
     DeviceIndependentFlagsMask
 }
@@ -26,9 +32,11 @@ impl From<EventModifierFlag> for NSUInteger {
     fn from(flag: EventModifierFlag) -> NSUInteger {
         match flag {
             EventModifierFlag::CapsLock => 1 << 16,
+            EventModifierFlag::Shift => 1 << 17,
             EventModifierFlag::Control => 1 << 18,
             EventModifierFlag::Option => 1 << 19,
             EventModifierFlag::Command => 1 << 20,
+            EventModifierFlag::Function => 1 << 23,
             EventModifierFlag::DeviceIndependentFlagsMask => 0xffff0000
         }
     }
@@ -38,9 +46,11 @@ impl From<&EventModifierFlag> for NSUInteger {
     fn from(flag: &EventModifierFlag) -> NSUInteger {
         match flag {
             EventModifierFlag::CapsLock => 1 << 16,
+            EventModifierFlag::Shift => 1 << 17,
             EventModifierFlag::Control => 1 << 18,
             EventModifierFlag::Option => 1 << 19,
             EventModifierFlag::Command => 1 << 20,
+            EventModifierFlag::Function => 1 << 23,
             EventModifierFlag::DeviceIndependentFlagsMask => 0xffff0000
         }
     }
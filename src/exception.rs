@@ -0,0 +1,142 @@
+//! Support for catching Objective-C exceptions.
+//!
+//! Objective-C signals programmer errors (an unrecognized selector, an out-of-bounds index, an
+//! invalid argument, and so on) by throwing an `NSException`. These can't unwind through Rust
+//! frames, so by default they abort the process with little in the way of explanation.
+//!
+//! With the `exception` feature enabled, you can wrap calls that might throw in
+//! `catch_exception`, and get the exception back as a Rust value:
+//!
+//! ```rust,no_run
+//! use cacao::exception::catch_exception;
+//!
+//! let result = catch_exception(|| {
+//!     // Something that messages Objective-C, and might throw...
+//! });
+//!
+//! if let Err(exception) = result {
+//!     eprintln!("Caught {}: {}", exception.name, exception.reason);
+//! }
+//! ```
+//!
+//! If you'd rather not wrap calls yourself, the `catch-all` feature wraps every message send in
+//! the framework (including those made while dispatching to your delegates) and turns any
+//! exception into a panic with a readable message, rather than an abort with none.
+
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::{error, fmt};
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel};
+
+use crate::error::Error;
+use crate::foundation::{id, nil, to_bool, NSArray, NSString};
+
+/// The domain used when converting an `Exception` into an `Error`.
+pub const EXCEPTION_ERROR_DOMAIN: &str = "com.cacao-rs.exception";
+
+/// The pieces of an `NSException`, pulled out so that it can be passed around freely (e.g,
+/// between threads).
+#[derive(Clone, Debug)]
+pub struct Exception {
+    /// The exception name, e.g `NSInvalidArgumentException`.
+    pub name: String,
+
+    /// A human-readable reason for the exception.
+    pub reason: String,
+
+    /// The string-representable entries of the exception's `userInfo`.
+    pub user_info: HashMap<String, String>
+}
+
+impl Exception {
+    /// Pulls the relevant information out of an `NSException`. Anything thrown that isn't an
+    /// `NSException` (which is legal, if rare) is described as best we can.
+    pub fn new(exception: id) -> Self {
+        if exception == nil {
+            return Exception {
+                name: "UnknownException".to_string(),
+                reason: "A nil exception was thrown.".to_string(),
+                user_info: HashMap::new()
+            };
+        }
+
+        unsafe {
+            if !to_bool(msg_send![exception, isKindOfClass: class!(NSException)]) {
+                return Exception {
+                    name: "UnknownException".to_string(),
+                    reason: NSString::retain(msg_send![exception, description]).to_string(),
+                    user_info: HashMap::new()
+                };
+            }
+
+            let reason: id = msg_send![exception, reason];
+            let mut user_info = HashMap::new();
+
+            let info: id = msg_send![exception, userInfo];
+            if info != nil {
+                let keys: id = msg_send![info, allKeys];
+
+                for key in NSArray::retain(keys).iter() {
+                    let value: id = msg_send![info, objectForKey: key];
+                    let key = NSString::retain(msg_send![key, description]);
+                    let value = NSString::retain(msg_send![value, description]);
+                    user_info.insert(key.to_string(), value.to_string());
+                }
+            }
+
+            Exception {
+                name: NSString::retain(msg_send![exception, name]).to_string(),
+                reason: match reason == nil {
+                    true => String::new(),
+                    false => NSString::retain(reason).to_string()
+                },
+                user_info
+            }
+        }
+    }
+}
+
+impl fmt::Display for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.reason)
+    }
+}
+
+impl error::Error for Exception {}
+
+impl From<Exception> for Error {
+    /// Converts an `Exception` into an `Error`, with the exception name as the failure reason.
+    fn from(exception: Exception) -> Self {
+        let mut error = Error::custom(EXCEPTION_ERROR_DOMAIN, 0, &exception.reason);
+        error.failure_reason = Some(exception.name);
+        error.user_info = exception.user_info;
+        error
+    }
+}
+
+/// Runs `closure`, catching any Objective-C exception thrown while it runs and returning it as an
+/// `Exception`.
+///
+/// Rust panics inside the closure are not caught here; they're carried across the Objective-C
+/// boundary safely and resumed once this returns.
+pub fn catch_exception<F, R>(closure: F) -> Result<R, Exception>
+where
+    F: FnOnce() -> R
+{
+    let closure = AssertUnwindSafe(closure);
+
+    // Panics can't unwind through the Objective-C `@try` frame, so we stop them on this side and
+    // resume them once we're back out.
+    let result = unsafe { objc::exception::catch(move || panic::catch_unwind(closure)) };
+
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(panic)) => panic::resume_unwind(panic),
+        Err(exception) => Err(Exception::new(match exception {
+            Some(exception) => &*exception as *const _ as *const Object as id,
+            None => nil
+        }))
+    }
+}
@@ -0,0 +1,361 @@
+//! Wraps `NSFileCoordinator` and `NSFilePresenter`, which keep your app, the iCloud sync daemon
+//! and any other process from reading and writing the same file at the same time.
+//!
+//! ```rust,no_run
+//! use cacao::filesystem::{FileCoordinator, ReadingOptions};
+//! use cacao::foundation::NSURL;
+//!
+//! let url = NSURL::with_path("/path/to/document.txt");
+//!
+//! let text = FileCoordinator::new().coordinate_reading(&url, ReadingOptions::default(), |url| {
+//!     std::fs::read_to_string(url.pathbuf())
+//! });
+//! ```
+//!
+//! If your app keeps a document open, register a `FilePresenter` for it, so that you find out
+//! when it's changed, moved or deleted by someone else.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::RwLock;
+
+use block::{Block, ConcreteBlock};
+
+use objc::rc::{Id, Owned, Shared};
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::error::Error;
+use crate::foundation::{id, load_or_register_class, nil, NSUInteger, NSURL};
+
+pub(crate) static FILE_PRESENTER_PTR: &str = "rstFilePresenterPtr";
+
+/// Options for `FileCoordinator::coordinate_reading()`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReadingOptions {
+    /// Don't ask presenters to save their changes first; you'll read what's on disk.
+    pub without_changes: bool,
+
+    /// Resolve the URL if it's a symbolic link.
+    pub resolves_symbolic_link: bool,
+
+    /// Only read metadata (e.g, the size), which doesn't need the file to be downloaded.
+    pub immediately_available_metadata_only: bool
+}
+
+impl From<ReadingOptions> for NSUInteger {
+    fn from(options: ReadingOptions) -> Self {
+        let mut mask = 0;
+
+        if options.without_changes {
+            mask |= 1 << 0;
+        }
+
+        if options.resolves_symbolic_link {
+            mask |= 1 << 1;
+        }
+
+        if options.immediately_available_metadata_only {
+            mask |= 1 << 2;
+        }
+
+        mask
+    }
+}
+
+/// What kind of write you're coordinating, so other processes can react appropriately.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WritingIntent {
+    /// Changing the file's contents in place.
+    Contents,
+
+    /// Deleting the file.
+    Deleting,
+
+    /// Moving the file.
+    Moving,
+
+    /// Merging changes into the file.
+    Merging,
+
+    /// Replacing the file outright.
+    Replacing
+}
+
+impl From<WritingIntent> for NSUInteger {
+    fn from(intent: WritingIntent) -> Self {
+        match intent {
+            WritingIntent::Contents => 0,
+            WritingIntent::Deleting => 1 << 0,
+            WritingIntent::Moving => 1 << 1,
+            WritingIntent::Merging => 1 << 2,
+            WritingIntent::Replacing => 1 << 3
+        }
+    }
+}
+
+/// A wrapper for `NSFileCoordinator`.
+///
+/// Coordinated reads and writes wait for conflicting access elsewhere to finish first (and may
+/// wait for iCloud to download the file), so make them off of the main thread where you can.
+#[derive(Debug)]
+pub struct FileCoordinator(pub Id<Object, Shared>);
+
+impl Default for FileCoordinator {
+    fn default() -> Self {
+        FileCoordinator::new()
+    }
+}
+
+impl FileCoordinator {
+    /// Creates a coordinator.
+    pub fn new() -> Self {
+        FileCoordinator(unsafe {
+            msg_send_id![
+                msg_send_id![class!(NSFileCoordinator), alloc],
+                initWithFilePresenter: nil
+            ]
+        })
+    }
+
+    /// Creates a coordinator acting on behalf of `presenter`, so that it isn't notified about
+    /// its own changes.
+    pub fn with_presenter(presenter: &FilePresenter) -> Self {
+        FileCoordinator(unsafe {
+            msg_send_id![
+                msg_send_id![class!(NSFileCoordinator), alloc],
+                initWithFilePresenter: &*presenter.objc
+            ]
+        })
+    }
+
+    /// Waits until it's safe to read `url`, then calls `reader` with the URL to actually read
+    /// from (which may differ, if the file was moved in the meantime).
+    pub fn coordinate_reading<F, R>(&self, url: &NSURL, options: ReadingOptions, reader: F) -> Result<R, Error>
+    where
+        F: FnOnce(NSURL<'static>) -> R
+    {
+        let options: NSUInteger = options.into();
+
+        self.coordinate(reader, |accessor, error| unsafe {
+            let _: () = msg_send![
+                &*self.0,
+                coordinateReadingItemAtURL: &*url.objc,
+                options: options,
+                error: error,
+                byAccessor: accessor,
+            ];
+        })
+    }
+
+    /// Waits until it's safe to write to `url`, then calls `writer` with the URL to actually
+    /// write to (which may differ, if the file was moved in the meantime).
+    pub fn coordinate_writing<F, R>(&self, url: &NSURL, intent: WritingIntent, writer: F) -> Result<R, Error>
+    where
+        F: FnOnce(NSURL<'static>) -> R
+    {
+        let options: NSUInteger = intent.into();
+
+        self.coordinate(writer, |accessor, error| unsafe {
+            let _: () = msg_send![
+                &*self.0,
+                coordinateWritingItemAtURL: &*url.objc,
+                options: options,
+                error: error,
+                byAccessor: accessor,
+            ];
+        })
+    }
+
+    /// Tells presenters of `url` (and the system) that the file was moved to `destination` by
+    /// code running inside `coordinate_writing()` with `WritingIntent::Moving`.
+    pub fn item_did_move(&self, url: &NSURL, destination: &NSURL) {
+        unsafe {
+            let _: () = msg_send![&*self.0, itemAtURL: &*url.objc, didMoveToURL: &*destination.objc];
+        }
+    }
+
+    /// Runs a coordination method, handing it an accessor block that calls `accessor` (at most
+    /// once) and an error out-parameter. The coordination methods are synchronous, so
+    /// everything here can live on the stack.
+    fn coordinate<F, R, C>(&self, accessor: F, coordinate: C) -> Result<R, Error>
+    where
+        F: FnOnce(NSURL<'static>) -> R,
+        C: FnOnce(&Block<(id,), ()>, &mut id)
+    {
+        let accessor = RefCell::new(Some(accessor));
+        let result = RefCell::new(None);
+
+        let block = ConcreteBlock::new(|url: id| {
+            if let Some(accessor) = accessor.borrow_mut().take() {
+                *result.borrow_mut() = Some(accessor(NSURL::retain(url)));
+            }
+        });
+
+        let mut error: id = nil;
+        coordinate(&block, &mut error);
+
+        match result.into_inner() {
+            Some(result) => Ok(result),
+            None => Err(Error::new(error))
+        }
+    }
+}
+
+/// Receives notifications about a file your app has open, when another process (or another
+/// part of your app) changes it. These are called on the main thread.
+#[allow(unused_variables)]
+pub trait FilePresenterDelegate {
+    /// The file's contents changed; reload it.
+    fn did_change(&self) {}
+
+    /// The file was moved (or renamed) to `url`.
+    fn did_move(&self, url: NSURL<'static>) {}
+
+    /// The file is about to be deleted; close it.
+    fn will_delete(&self) {}
+}
+
+/// What the Objective-C side of a `FilePresenter` points to.
+struct PresenterState {
+    url: RwLock<NSURL<'static>>,
+    delegate: Box<dyn FilePresenterDelegate>
+}
+
+impl fmt::Debug for PresenterState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PresenterState").field("url", &self.url).finish()
+    }
+}
+
+/// An `NSFilePresenter` for a file your app has open. It's registered with `NSFileCoordinator`
+/// when created, and unregistered when dropped.
+#[derive(Debug)]
+pub struct FilePresenter {
+    /// The Objective-C object acting as the presenter.
+    pub objc: Id<Object, Owned>,
+
+    state: Box<PresenterState>
+}
+
+impl FilePresenter {
+    /// Starts presenting the file at `url`, sending notifications about it to `delegate`.
+    pub fn new<T: FilePresenterDelegate + 'static>(url: &NSURL, delegate: T) -> Self {
+        let state = Box::new(PresenterState {
+            url: RwLock::new(NSURL::retain(&*url.objc as *const Object as id)),
+            delegate: Box::new(delegate)
+        });
+
+        let ptr: *const PresenterState = &*state;
+
+        let objc: Id<Object, Owned> = unsafe {
+            let mut presenter: Id<Object, Owned> = msg_send_id![register_presenter_class(), new];
+            presenter.set_ivar(FILE_PRESENTER_PTR, ptr as usize);
+
+            let _: () = msg_send![class!(NSFileCoordinator), addFilePresenter: &*presenter];
+            presenter
+        };
+
+        FilePresenter { objc, state }
+    }
+
+    /// Returns the URL of the file, which follows it if it's moved.
+    pub fn url(&self) -> NSURL<'static> {
+        self.state.url.read().unwrap().clone()
+    }
+}
+
+impl Drop for FilePresenter {
+    /// Unregisters the presenter. Callbacks may already be queued on the main queue (and the
+    /// presenter is retained until they run), so the state pointer is cleared too; the callbacks
+    /// bail out once it's gone.
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![class!(NSFileCoordinator), removeFilePresenter: &*self.objc];
+            self.objc.set_ivar(FILE_PRESENTER_PTR, 0usize);
+        }
+    }
+}
+
+/// Returns the state the presenter points to, or `None` if its `FilePresenter` has been dropped.
+fn state(this: &Object) -> Option<&PresenterState> {
+    let ptr: usize = unsafe { *this.get_ivar(FILE_PRESENTER_PTR) };
+
+    match ptr {
+        0 => None,
+        ptr => Some(unsafe { &*(ptr as *const PresenterState) })
+    }
+}
+
+/// Returns the presented file's URL.
+///
+/// This is called from whichever thread is coordinating access, so the URL can be replaced (and
+/// released) by `presented_item_did_move` at any point after the lock is dropped; we hand back
+/// an autoreleased reference taken while it's still held.
+extern "C" fn presented_item_url(this: &Object, _: Sel) -> id {
+    let state = match state(this) {
+        Some(state) => state,
+        None => return nil
+    };
+
+    let url = state.url.read().unwrap();
+
+    unsafe {
+        let retained: id = msg_send![&*url.objc, retain];
+        msg_send![retained, autorelease]
+    }
+}
+
+/// Delivers callbacks on the main queue.
+extern "C" fn presented_item_operation_queue(_: &Object, _: Sel) -> id {
+    unsafe { msg_send![class!(NSOperationQueue), mainQueue] }
+}
+
+/// Forwards content changes to the delegate.
+extern "C" fn presented_item_did_change(this: &Object, _: Sel) {
+    if let Some(state) = state(this) {
+        state.delegate.did_change();
+    }
+}
+
+/// Follows the file to its new URL, and lets the delegate know.
+extern "C" fn presented_item_did_move(this: &Object, _: Sel, url: id) {
+    if let Some(state) = state(this) {
+        *state.url.write().unwrap() = NSURL::retain(url);
+        state.delegate.did_move(NSURL::retain(url));
+    }
+}
+
+/// Lets the delegate close the file (if it's still around), then lets the deletion go ahead.
+extern "C" fn accommodate_deletion(this: &Object, _: Sel, completion: id) {
+    if let Some(state) = state(this) {
+        state.delegate.will_delete();
+    }
+
+    unsafe {
+        let completion = completion as *const Block<(id,), ()>;
+        (*completion).call((nil,));
+    }
+}
+
+/// Registers an `NSObject` subclass that conforms to `NSFilePresenter`.
+fn register_presenter_class() -> &'static Class {
+    load_or_register_class("NSObject", "RSTFilePresenter", |decl| unsafe {
+        decl.add_ivar::<usize>(FILE_PRESENTER_PTR);
+
+        decl.add_method(sel!(presentedItemURL), presented_item_url as extern "C" fn(_, _) -> _);
+        decl.add_method(
+            sel!(presentedItemOperationQueue),
+            presented_item_operation_queue as extern "C" fn(_, _) -> _
+        );
+        decl.add_method(sel!(presentedItemDidChange), presented_item_did_change as extern "C" fn(_, _));
+        decl.add_method(
+            sel!(presentedItemDidMoveToURL:),
+            presented_item_did_move as extern "C" fn(_, _, _)
+        );
+        decl.add_method(
+            sel!(accommodatePresentedItemDeletionWithCompletionHandler:),
+            accommodate_deletion as extern "C" fn(_, _, _)
+        );
+    })
+}
@@ -1,7 +1,6 @@
 //! A wrapper for `NSFileManager`, which is necessary for macOS/iOS (the sandbox makes things
 //! tricky, and this transparently handles it for you).
 
-use std::error::Error;
 use std::sync::{Arc, RwLock};
 
 use objc::rc::{Id, Owned};
@@ -9,7 +8,7 @@ use objc::runtime::{Object, BOOL};
 use objc::{class, msg_send, msg_send_id, sel};
 use url::Url;
 
-use crate::error::Error as AppKitError;
+use crate::error::Error;
 use crate::filesystem::enums::{SearchPathDirectory, SearchPathDomainMask};
 use crate::foundation::{id, nil, NSString, NSUInteger, NO};
 
@@ -41,28 +40,34 @@ impl FileManager {
     /// Given a directory/domain combination, will attempt to get the directory that matches.
     /// Returns a PathBuf that wraps the given location. If there's an error on the Objective-C
     /// side, we attempt to catch it and bubble it up.
-    pub fn get_directory(&self, directory: SearchPathDirectory, in_domain: SearchPathDomainMask) -> Result<Url, Box<dyn Error>> {
+    pub fn get_directory(&self, directory: SearchPathDirectory, in_domain: SearchPathDomainMask) -> Result<Url, Error> {
         let dir: NSUInteger = directory.into();
         let mask: NSUInteger = in_domain.into();
 
         let directory = unsafe {
             let manager = self.0.read().unwrap();
+
+            let mut error: id = nil;
             let dir: id = msg_send![&**manager, URLForDirectory:dir
                 inDomain:mask
                 appropriateForURL:nil
                 create:NO
-                error:nil];
+                error:&mut error];
+
+            if dir == nil {
+                return Err(Error::new(error));
+            }
 
             NSString::retain(msg_send![dir, absoluteString])
         };
 
-        Url::parse(directory.to_str()).map_err(|e| e.into())
+        Url::parse(directory.to_str()).map_err(|e| Error::custom("com.cacao-rs.url", 0, &e.to_string()))
     }
 
     /// Given two paths, moves file (`from`) to the location specified in `to`. This can result in
     /// an error on the Objective-C side, which we attempt to handle and bubble up as a result if
     /// so.
-    pub fn move_item(&self, from: Url, to: Url) -> Result<(), Box<dyn Error>> {
+    pub fn move_item(&self, from: Url, to: Url) -> Result<(), Error> {
         let from = NSString::new(from.as_str());
         let to = NSString::new(to.as_str());
 
@@ -74,10 +79,10 @@ impl FileManager {
             // already, so... going to leave it as read.
             let manager = self.0.read().unwrap();
 
-            let error: id = nil;
-            let result: BOOL = msg_send![&**manager, moveItemAtURL:from_url toURL:to_url error:&error];
+            let mut error: id = nil;
+            let result: BOOL = msg_send![&**manager, moveItemAtURL:from_url toURL:to_url error:&mut error];
             if result == NO {
-                return Err(AppKitError::new(error).into());
+                return Err(Error::new(error));
             }
         }
 
@@ -7,10 +7,20 @@ pub use manager::FileManager;
 pub mod traits;
 pub use traits::*;
 
+pub mod coordination;
+pub use coordination::{FileCoordinator, FilePresenter, FilePresenterDelegate, ReadingOptions, WritingIntent};
+
 pub mod save;
 pub use save::FileSavePanel;
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 pub mod select;
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 pub use select::FileSelectPanel;
+
+#[cfg(appkit)]
+pub mod sandbox;
+#[cfg(appkit)]
+pub use sandbox::{container_directory, home_directory, is_sandboxed, real_home_directory, FolderAccess};
+
+pub mod ubiquity;
@@ -0,0 +1,190 @@
+//! Helpers for working with files from inside the App Sandbox.
+//!
+//! Sandboxed apps can only reach files outside their container that the user has granted access
+//! to - usually by picking them in an open panel. That access only lasts until the app quits,
+//! unless you keep a security-scoped bookmark around and resolve it on the next launch.
+//! `FolderAccess` handles that whole dance:
+//!
+//! ```rust,no_run
+//! use cacao::filesystem::FolderAccess;
+//!
+//! const LIBRARY: &str = "library-folder";
+//!
+//! // On launch, pick up access granted in an earlier session...
+//! match FolderAccess::restore(LIBRARY) {
+//!     Some(access) => println!("Using {:?}", access.path()),
+//!
+//!     // ...or ask for it.
+//!     None => FolderAccess::request(LIBRARY, "Choose your library folder", |access| {
+//!         if let Some(access) = access {
+//!             println!("Using {:?}", access.path());
+//!         }
+//!     })
+//! }
+//! ```
+//!
+//! None of this gets in the way when your app isn't sandboxed; the bookmarks still work, and
+//! still track the folder if the user moves it.
+
+use std::env;
+use std::ffi::CStr;
+use std::path::PathBuf;
+
+use objc::msg_send;
+use objc::runtime::Object;
+
+use crate::defaults::{UserDefaults, Value};
+use crate::error::Error;
+use crate::filesystem::FileSelectPanel;
+use crate::foundation::{id, to_bool, NSData, NSString, NSURLBookmarkCreationOption, NSURLBookmarkResolutionOption, BOOL, NSURL};
+
+extern "C" {
+    fn NSHomeDirectory() -> id;
+}
+
+/// Returns whether this app is running inside the App Sandbox.
+pub fn is_sandboxed() -> bool {
+    env::var_os("APP_SANDBOX_CONTAINER_ID").is_some()
+}
+
+/// Returns the app's home directory. Inside the sandbox, this is the app's container (i.e,
+/// `~/Library/Containers/<bundle id>/Data`); outside of it, this is the user's home directory.
+pub fn home_directory() -> PathBuf {
+    let path = NSString::retain(unsafe { NSHomeDirectory() });
+    PathBuf::from(path.to_str())
+}
+
+/// Returns the user's real home directory (e.g, `/Users/me`), even from inside the sandbox.
+///
+/// Being able to build a path doesn't mean you can read it: outside of your container, you still
+/// need the user to grant access (see `FolderAccess`), or a matching entitlement.
+pub fn real_home_directory() -> PathBuf {
+    unsafe {
+        let passwd = libc::getpwuid(libc::getuid());
+
+        if passwd.is_null() || (*passwd).pw_dir.is_null() {
+            return home_directory();
+        }
+
+        let dir = CStr::from_ptr((*passwd).pw_dir);
+        PathBuf::from(dir.to_string_lossy().into_owned())
+    }
+}
+
+/// Returns the app's sandbox container, or `None` if the app isn't sandboxed.
+pub fn container_directory() -> Option<PathBuf> {
+    match is_sandboxed() {
+        true => Some(home_directory()),
+        false => None
+    }
+}
+
+/// Persistent, user-granted access to a folder. Access lasts as long as this does; dropping it
+/// gives up access until the next `restore()`.
+///
+/// The security-scoped bookmark that makes this work is kept in `UserDefaults::standard()`, under
+/// the key you pass in.
+#[derive(Debug)]
+pub struct FolderAccess {
+    url: NSURL<'static>,
+    accessing: bool
+}
+
+impl FolderAccess {
+    /// Shows an open panel asking the user to pick a folder, showing `message` at the top. If they
+    /// do, a bookmark for it is saved under `key` (replacing any earlier one) and `handler` is
+    /// called with access to it; if they cancel, or the bookmark can't be made, `handler` is
+    /// called with `None`.
+    pub fn request<F>(key: &'static str, message: &str, handler: F)
+    where
+        F: Fn(Option<FolderAccess>) + 'static
+    {
+        let mut panel = FileSelectPanel::new();
+        panel.set_can_choose_files(false);
+        panel.set_can_choose_directories(true);
+        panel.set_allows_multiple_selection(false);
+        panel.set_message(message);
+
+        panel.show(move |urls| {
+            let access = match urls.into_iter().next() {
+                Some(url) => FolderAccess::grant(key, &url).ok(),
+                None => None
+            };
+
+            handler(access);
+        });
+    }
+
+    /// Saves a bookmark for `url` under `key`, and returns access to it. Use this for a folder
+    /// the user picked some other way (e.g, by dropping it onto your window).
+    pub fn grant(key: &str, url: &NSURL) -> Result<FolderAccess, Error> {
+        save_bookmark(key, url)?;
+        Ok(FolderAccess::start(NSURL::retain(&*url.objc as *const Object as id)))
+    }
+
+    /// Resolves the bookmark saved under `key`, returning access to its folder. Returns `None` if
+    /// there's no bookmark, or it no longer resolves (e.g, the folder was deleted), in which case
+    /// you'll want to `request()` access again.
+    ///
+    /// If the folder has moved since the bookmark was made, the bookmark is refreshed.
+    pub fn restore(key: &str) -> Option<FolderAccess> {
+        let defaults = UserDefaults::standard();
+        let data = NSData::with_slice(defaults.get(key)?.as_data()?);
+
+        let (url, is_stale) = NSURL::resolve_bookmark_data(&data, &[NSURLBookmarkResolutionOption::SecurityScoped], None).ok()?;
+
+        let access = FolderAccess::start(url);
+
+        // Bookmarks can only be created while we have access, so this has to come second.
+        if is_stale {
+            let _ = save_bookmark(key, &access.url);
+        }
+
+        Some(access)
+    }
+
+    /// Removes the bookmark saved under `key`, so the next `restore()` returns `None`.
+    pub fn forget(key: &str) {
+        let mut defaults = UserDefaults::standard();
+        defaults.remove(key);
+    }
+
+    /// Returns the URL of the folder.
+    pub fn url(&self) -> &NSURL<'static> {
+        &self.url
+    }
+
+    /// Returns the path of the folder.
+    pub fn path(&self) -> PathBuf {
+        self.url.pathbuf()
+    }
+
+    /// Starts accessing the security-scoped resource at `url`. Outside of the sandbox there's
+    /// nothing to start, and this is recorded so that we don't try to stop it either.
+    fn start(url: NSURL<'static>) -> FolderAccess {
+        let accessing: BOOL = unsafe { msg_send![&*url.objc, startAccessingSecurityScopedResource] };
+
+        FolderAccess {
+            url,
+            accessing: to_bool(accessing)
+        }
+    }
+}
+
+impl Drop for FolderAccess {
+    fn drop(&mut self) {
+        if self.accessing {
+            self.url.stop_accessing_security_scoped_resource();
+        }
+    }
+}
+
+/// Creates a security-scoped bookmark for `url`, and stores it in the standard user defaults.
+fn save_bookmark(key: &str, url: &NSURL) -> Result<(), Error> {
+    let data = url.bookmark_data(&[NSURLBookmarkCreationOption::SecurityScoped], &[], None)?;
+
+    let mut defaults = UserDefaults::standard();
+    defaults.insert(key, Value::Data(data.into_vec()));
+
+    Ok(())
+}
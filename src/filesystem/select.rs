@@ -13,7 +13,7 @@ use objc::{class, msg_send, msg_send_id, sel};
 use crate::filesystem::enums::ModalResponse;
 use crate::foundation::{id, nil, NSInteger, NSString, NO, NSURL, YES};
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 use crate::appkit::window::{Window, WindowDelegate};
 
 #[derive(Debug)]
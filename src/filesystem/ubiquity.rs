@@ -0,0 +1,120 @@
+//! iCloud Drive support for `FileManager`: finding the app's ubiquity (iCloud) containers, and
+//! moving documents in and out of them.
+//!
+//! Anything in a container's `Documents` folder shows up in iCloud Drive. To follow upload and
+//! download progress on macOS, run a `metadata::MetadataQuery` scoped to
+//! `SearchScope::UbiquitousDocuments`, and check each item's `download_status()`; to read and
+//! write documents safely while they sync, go through a `FileCoordinator`.
+//!
+//! Your app needs the iCloud Documents entitlement (with the containers it uses) for any of this
+//! to work.
+
+use objc::runtime::{Object, BOOL};
+use objc::{msg_send, sel};
+
+use crate::error::Error;
+use crate::filesystem::FileManager;
+use crate::foundation::{id, nil, to_bool, NSString, NO, NSURL, YES};
+
+impl FileManager {
+    /// Returns whether the user is signed in to iCloud, with iCloud Drive enabled.
+    pub fn is_ubiquity_available(&self) -> bool {
+        let manager = self.0.read().unwrap();
+        let token: id = unsafe { msg_send![&**manager, ubiquityIdentityToken] };
+        token != nil
+    }
+
+    /// Returns the URL of the ubiquity container with the given identifier (e.g,
+    /// `"iCloud.com.example.notes"`) - or, for `None`, the first one in your entitlements. Returns
+    /// `None` if iCloud isn't available.
+    ///
+    /// The first call for a container can take a while, as it sets the container up; Apple
+    /// recommends making it off of the main thread.
+    pub fn ubiquity_container_url(&self, identifier: Option<&str>) -> Option<NSURL<'static>> {
+        let identifier = identifier.map(NSString::new);
+        let identifier: id = match &identifier {
+            Some(identifier) => &**identifier as *const Object as id,
+            None => nil
+        };
+
+        let manager = self.0.read().unwrap();
+        let url: id = unsafe { msg_send![&**manager, URLForUbiquityContainerIdentifier: identifier] };
+
+        match url == nil {
+            true => None,
+            false => Some(NSURL::retain(url))
+        }
+    }
+
+    /// Returns the `Documents` folder of the given ubiquity container (see
+    /// `ubiquity_container_url()`); this is what shows up in iCloud Drive.
+    pub fn ubiquity_documents_url(&self, identifier: Option<&str>) -> Option<NSURL<'static>> {
+        let container = self.ubiquity_container_url(identifier)?;
+        let documents = NSString::new("Documents");
+
+        Some(NSURL::retain(unsafe {
+            msg_send![&*container.objc, URLByAppendingPathComponent: &*documents, isDirectory: YES]
+        }))
+    }
+
+    /// Moves a local file into iCloud, at `destination` (somewhere inside a ubiquity container).
+    ///
+    /// This can take a while, and coordinates with other processes, so Apple recommends calling it
+    /// off of the main thread.
+    pub fn move_to_ubiquity(&self, item: &NSURL, destination: &NSURL) -> Result<(), Error> {
+        self.set_ubiquitous(true, item, destination)
+    }
+
+    /// Moves a file out of iCloud, to the local `destination`. The same threading advice as
+    /// `move_to_ubiquity()` applies.
+    pub fn move_from_ubiquity(&self, item: &NSURL, destination: &NSURL) -> Result<(), Error> {
+        self.set_ubiquitous(false, item, destination)
+    }
+
+    /// Returns whether `item` is stored in iCloud.
+    pub fn is_ubiquitous(&self, item: &NSURL) -> bool {
+        let manager = self.0.read().unwrap();
+        to_bool(unsafe { msg_send![&**manager, isUbiquitousItemAtURL: &*item.objc] })
+    }
+
+    /// Starts downloading an iCloud file that isn't on this device yet (or is out of date).
+    pub fn start_downloading(&self, item: &NSURL) -> Result<(), Error> {
+        self.ubiquity_operation(|manager, error| unsafe {
+            msg_send![manager, startDownloadingUbiquitousItemAtURL: &*item.objc, error: error]
+        })
+    }
+
+    /// Removes the local copy of an iCloud file, leaving it in iCloud (and downloadable again).
+    pub fn evict(&self, item: &NSURL) -> Result<(), Error> {
+        self.ubiquity_operation(|manager, error| unsafe {
+            msg_send![manager, evictUbiquitousItemAtURL: &*item.objc, error: error]
+        })
+    }
+
+    /// Calls `setUbiquitous:itemAtURL:destinationURL:error:`.
+    fn set_ubiquitous(&self, ubiquitous: bool, item: &NSURL, destination: &NSURL) -> Result<(), Error> {
+        self.ubiquity_operation(|manager, error| unsafe {
+            msg_send![
+                manager,
+                setUbiquitous: match ubiquitous {
+                    true => YES,
+                    false => NO
+                },
+                itemAtURL: &*item.objc,
+                destinationURL: &*destination.objc,
+                error: error,
+            ]
+        })
+    }
+
+    /// Runs an operation that reports failure via a `BOOL` and an `NSError` out-parameter.
+    fn ubiquity_operation<F: Fn(&Object, &mut id) -> BOOL>(&self, operation: F) -> Result<(), Error> {
+        let manager = self.0.read().unwrap();
+        let mut error: id = nil;
+
+        match to_bool(operation(&**manager, &mut error)) {
+            true => Ok(()),
+            false => Err(Error::new(error))
+        }
+    }
+}
@@ -1,10 +1,11 @@
+use std::iter::FromIterator;
 use std::ops::{Deref, DerefMut};
 
 use objc::rc::{Id, Owned};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
-use crate::foundation::id;
+use crate::foundation::{id, Retainable};
 
 /// A wrapper for `NSArray` that makes common operations in our framework a bit easier to handle
 /// and reason about. This also provides a central place to look at replacing with `CFArray` if
@@ -44,8 +45,40 @@ impl NSArray {
             array: self
         }
     }
+
+    /// Creates an `NSArray` holding the objects backing `items`, which are left as-is.
+    pub fn from_retainables<'a, T, I>(items: I) -> Self
+    where
+        T: Retainable + 'a,
+        I: IntoIterator<Item = &'a T>
+    {
+        let objects: Vec<id> = items.into_iter().map(|item| item.as_id()).collect();
+        objects.into()
+    }
+
+    /// Returns an iterator over the `NSArray` that wraps each item as a `T`. Every item in the
+    /// array must be of the class `T` wraps.
+    pub fn iter_as<'a, T: Retainable + 'a>(&'a self) -> impl Iterator<Item = T> + 'a {
+        self.iter().map(T::retain)
+    }
+
+    /// Copies the items out of the `NSArray` into a `Vec`, wrapping each as a `T`. Every item in
+    /// the array must be of the class `T` wraps.
+    pub fn to_vec<T: Retainable>(&self) -> Vec<T> {
+        self.iter_as().collect()
+    }
+}
+
+impl<T: Retainable> FromIterator<T> for NSArray {
+    /// Creates an `NSArray` holding the objects backing each item.
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        // The array retains what it holds, so the wrappers can be dropped afterwards.
+        let items: Vec<T> = items.into_iter().collect();
+        NSArray::from_retainables(&items)
+    }
 }
 
+/// An iterator over the (unretained) objects in an `NSArray`.
 #[derive(Debug)]
 pub struct NSArrayIterator<'a> {
     next_index: usize,
@@ -3,6 +3,8 @@ use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::ffi::CString;
 use std::hash::{Hash, Hasher};
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
 use std::sync::{Arc, RwLock};
 use std::thread;
 use std::time::Instant;
@@ -14,6 +16,10 @@ use objc::runtime::Class;
 
 lazy_static! {
     static ref CLASSES: ClassMap = ClassMap::new();
+
+    /// Stand-ins for superclasses that don't exist on every AppKit implementation; see
+    /// `set_superclass_fallback`.
+    static ref SUPERCLASS_FALLBACKS: RwLock<HashMap<&'static str, &'static str>> = RwLock::new(HashMap::new());
 }
 
 thread_local! {
@@ -30,6 +36,17 @@ thread_local! {
         let hash = hasher.finish();
         (hash << 1) | 1
     });
+
+    /// How many times this thread has gone through the `ClassMap`; see `class_lookups`.
+    #[cfg(test)]
+    static CLASS_LOOKUPS: Cell<usize> = Cell::new(0);
+}
+
+/// Returns how many times the current thread has looked up (or registered) a class through the
+/// `ClassMap`. Tests use this to check that hot paths hit a `StaticClass` instead.
+#[cfg(test)]
+pub(crate) fn class_lookups() -> usize {
+    CLASS_LOOKUPS.with(|lookups| lookups.get())
 }
 
 /// Represents an entry in a `ClassMap`. We store an optional superclass_name for debugging
@@ -116,6 +133,51 @@ impl ClassMap {
     }
 }
 
+/// A per-call-site handle for a class that never varies (i.e, isn't generic over a delegate).
+///
+/// `ClassMap` lookups hash the class names and take a lock, which adds up for classes that get
+/// allocated thousands of times (e.g, `ListView` rows). Registration functions for such classes
+/// can keep one of these in a `static`, so that everything after the first call is a single
+/// atomic load:
+///
+/// ```rust,ignore
+/// pub(crate) fn register_view_class() -> &'static Class {
+///     static CLASS: StaticClass = StaticClass::new();
+///     CLASS.get_or_register(|| load_or_register_class("NSView", "RSTView", |decl| unsafe {}))
+/// }
+/// ```
+///
+/// Don't use this in a generic function: the `static` would be shared by every instantiation.
+#[derive(Debug)]
+pub(crate) struct StaticClass(AtomicPtr<Class>);
+
+impl StaticClass {
+    /// Returns an empty handle.
+    pub const fn new() -> Self {
+        StaticClass(AtomicPtr::new(ptr::null_mut()))
+    }
+
+    /// Returns the cached class, calling `register` to look it up (or create it) the first time.
+    ///
+    /// If two threads race here, both call `register` - which is fine, since it goes through the
+    /// `ClassMap` and returns the same class either way.
+    #[inline]
+    pub fn get_or_register<F>(&self, register: F) -> &'static Class
+    where
+        F: FnOnce() -> &'static Class
+    {
+        let class = self.0.load(Ordering::Acquire);
+
+        if !class.is_null() {
+            return unsafe { &*class };
+        }
+
+        let class = register();
+        self.0.store(class as *const Class as *mut Class, Ordering::Release);
+        class
+    }
+}
+
 /// Calls through to `load_or_register_class_with_optional_generated_suffix`, specifying that we
 /// should append a random suffix to the generated class name. This is important for situations
 /// where we may be loading classes from e.g two different bundles and need to avoid collision.
@@ -137,6 +199,32 @@ where
     load_or_register_class_with_optional_generated_suffix(superclass_name, subclass_name, true, config)
 }
 
+/// Registers `fallback` as a stand-in for `superclass_name`, for runtimes where the latter doesn't
+/// exist. When a subclass of `superclass_name` is requested and the runtime can't find it, the
+/// subclass is registered on top of `fallback` instead.
+///
+/// This is mostly useful for non-Apple AppKit implementations (GNUstep, Airyx), which may lack
+/// some of the more modern classes - e.g, `NSVisualEffectView` can reasonably fall back to
+/// `NSView`. It has no effect on a class that the runtime does provide.
+pub fn set_superclass_fallback(superclass_name: &'static str, fallback: &'static str) {
+    let mut writer = SUPERCLASS_FALLBACKS.write().unwrap();
+    writer.insert(superclass_name, fallback);
+}
+
+/// Loads `superclass_name` from the runtime, or its registered fallback if it doesn't exist.
+fn load_superclass(superclass_name: &'static str) -> Option<&'static Class> {
+    if let Some(superclass) = CLASSES.load(superclass_name, None) {
+        return Some(superclass);
+    }
+
+    let fallback = {
+        let reader = SUPERCLASS_FALLBACKS.read().unwrap();
+        reader.get(superclass_name).copied()
+    };
+
+    fallback.and_then(|fallback| CLASSES.load(fallback, None))
+}
+
 /// Attempts to load a subclass, given a `superclass_name` and subclass_name. If
 /// the subclass cannot be loaded, it's dynamically created and injected into
 /// the runtime, and then returned. The returned value can be used for allocating new instances of
@@ -147,7 +235,8 @@ where
 ///
 /// If the superclass cannot be loaded, this will panic. If the subclass cannot be
 /// created, this will panic. In general, this is expected to work, and if it doesn't,
-/// the entire framework will not really work.
+/// the entire framework will not really work. If you're targeting a runtime that may be missing
+/// the superclass, use `try_load_or_register_class` instead.
 ///
 /// There's definitely room to optimize here, but it works for now.
 #[inline(always)]
@@ -160,55 +249,92 @@ pub fn load_or_register_class_with_optional_generated_suffix<F>(
 where
     F: Fn(&mut ClassDecl) + 'static
 {
+    match try_load_or_register_class_with_optional_generated_suffix(
+        superclass_name,
+        subclass_name,
+        should_append_random_subclass_name_suffix,
+        config
+    ) {
+        Some(class) => class,
+
+        None => panic!(
+            "Attempted to create subclass for {}, but unable to load superclass of type {}.",
+            subclass_name, superclass_name
+        )
+    }
+}
+
+/// Like `load_or_register_class`, but returns `None` if neither the superclass nor a fallback
+/// registered via `set_superclass_fallback` exists in the runtime. Use this for classes that
+/// might be missing on some AppKit implementations, and degrade accordingly.
+pub fn try_load_or_register_class<F>(
+    superclass_name: &'static str,
+    subclass_name: &'static str,
+    config: F
+) -> Option<&'static Class>
+where
+    F: Fn(&mut ClassDecl) + 'static
+{
+    try_load_or_register_class_with_optional_generated_suffix(superclass_name, subclass_name, true, config)
+}
+
+/// The fallible core of `load_or_register_class_with_optional_generated_suffix`.
+fn try_load_or_register_class_with_optional_generated_suffix<F>(
+    superclass_name: &'static str,
+    subclass_name: &'static str,
+    should_append_random_subclass_name_suffix: bool,
+    config: F
+) -> Option<&'static Class>
+where
+    F: Fn(&mut ClassDecl) + 'static
+{
+    #[cfg(test)]
+    CLASS_LOOKUPS.with(|lookups| lookups.set(lookups.get() + 1));
+
     if let Some(subclass) = CLASSES.load(subclass_name, Some(superclass_name)) {
-        return subclass;
+        return Some(subclass);
     }
 
-    // If we can't find the class anywhere, then we'll attempt to load the superclass and register
-    // our new class type.
-    if let Some(superclass) = CLASSES.load(superclass_name, None) {
-        // When we're generating a new Subclass name, we need to append a random-ish component
-        // due to some oddities that can come up in certain scenarios (e.g, various bundler
-        // situations appear to have odd rules about subclass name usage/registration, this simply
-        // guarantees that we almost always have a unique name to register with the ObjC runtime).
-        //
-        // For more context, see: https://github.com/ryanmcgrath/cacao/issues/63
-        let objc_subclass_name = match should_append_random_subclass_name_suffix {
-            true => format!(
-                "{}_{}_{}",
-                subclass_name,
-                superclass_name,
-                RNG_SEED.with(|rng| {
-                    rng.set(rng.get().wrapping_add(0xa0761d6478bd642f));
-                    let s = rng.get();
-                    let t = u128::from(s) * (u128::from(s ^ 0xe7037ed1a0b428db));
-                    ((t >> 64) as u64) ^ (t as u64)
-                })
-            ),
-
-            false => format!("{}_{}", subclass_name, superclass_name)
-        };
-
-        match ClassDecl::new(&objc_subclass_name, superclass) {
-            Some(mut decl) => {
-                config(&mut decl);
-
-                let class = decl.register();
-                CLASSES.store(subclass_name, Some(superclass_name), class);
-                return class;
-            },
-
-            None => {
-                panic!(
-                    "Subclass of type {}_{} could not be allocated.",
-                    subclass_name, superclass_name
-                );
-            }
+    // If we can't find the class anywhere, then we'll attempt to load the superclass (or its
+    // fallback) and register our new class type.
+    let superclass = load_superclass(superclass_name)?;
+
+    // When we're generating a new Subclass name, we need to append a random-ish component
+    // due to some oddities that can come up in certain scenarios (e.g, various bundler
+    // situations appear to have odd rules about subclass name usage/registration, this simply
+    // guarantees that we almost always have a unique name to register with the ObjC runtime).
+    //
+    // For more context, see: https://github.com/ryanmcgrath/cacao/issues/63
+    let objc_subclass_name = match should_append_random_subclass_name_suffix {
+        true => format!(
+            "{}_{}_{}",
+            subclass_name,
+            superclass_name,
+            RNG_SEED.with(|rng| {
+                rng.set(rng.get().wrapping_add(0xa0761d6478bd642f));
+                let s = rng.get();
+                let t = u128::from(s) * (u128::from(s ^ 0xe7037ed1a0b428db));
+                ((t >> 64) as u64) ^ (t as u64)
+            })
+        ),
+
+        false => format!("{}_{}", subclass_name, superclass_name)
+    };
+
+    match ClassDecl::new(&objc_subclass_name, superclass) {
+        Some(mut decl) => {
+            config(&mut decl);
+
+            let class = decl.register();
+            CLASSES.store(subclass_name, Some(superclass_name), class);
+            Some(class)
+        },
+
+        None => {
+            panic!(
+                "Subclass of type {}_{} could not be allocated.",
+                subclass_name, superclass_name
+            );
         }
     }
-
-    panic!(
-        "Attempted to create subclass for {}, but unable to load superclass of type {}.",
-        subclass_name, superclass_name
-    );
 }
@@ -1,11 +1,11 @@
-use std::collections::HashMap;
+use std::iter::FromIterator;
 use std::ops::{Deref, DerefMut};
 
 use objc::rc::{Id, Owned};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
-use crate::foundation::{id, NSString};
+use crate::foundation::{id, nil, NSArray, NSString, Retainable};
 
 /// A wrapper for `NSMutableDictionary`.
 #[derive(Debug)]
@@ -29,6 +29,15 @@ impl NSMutableDictionary {
         NSMutableDictionary(unsafe { msg_send_id![class!(NSMutableDictionary), new] })
     }
 
+    /// In some cases, we're vended a dictionary by the system that we need to call retain on.
+    /// This handles that case.
+    ///
+    /// If the dictionary isn't actually mutable (i.e, it's an `NSDictionary`), it can only be read
+    /// from - don't `insert()` into it.
+    pub fn retain(dictionary: id) -> Self {
+        NSMutableDictionary(unsafe { Id::retain(dictionary).unwrap() })
+    }
+
     /// Inserts an object into the backing NSMutablyDictionary.
     ///
     /// This intentionally requires `NSString` be allocated ahead of time.
@@ -37,6 +46,69 @@ impl NSMutableDictionary {
             let _: () = msg_send![&*self.0, setObject:object forKey:&*key];
         }
     }
+
+    /// Inserts `value` for `key`. Keys are copied by the dictionary, so must support `NSCopying`
+    /// (as `NSString` and `NSNumber` do).
+    pub fn insert_retainable<K: Retainable, V: Retainable>(&mut self, key: &K, value: &V) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setObject: value.as_id(), forKey: key.as_id()];
+        }
+    }
+
+    /// Returns the value for `key`, wrapped as a `V` (which must be the class of the value).
+    pub fn get<K: Retainable, V: Retainable>(&self, key: &K) -> Option<V> {
+        let value: id = unsafe { msg_send![&*self.0, objectForKey: key.as_id()] };
+
+        match value == nil {
+            true => None,
+            false => Some(V::retain(value))
+        }
+    }
+
+    /// Returns the number of entries in the dictionary.
+    pub fn count(&self) -> usize {
+        unsafe { msg_send![&*self.0, count] }
+    }
+
+    /// Returns the entries of the dictionary, wrapped as `K` and `V` (which must be the classes of
+    /// every key and value). Collect these into a `HashMap` (or other collection) after mapping
+    /// them to Rust types:
+    ///
+    /// ```rust,no_run
+    /// use std::collections::HashMap;
+    /// use cacao::foundation::{NSMutableDictionary, NSNumber, NSString};
+    ///
+    /// # let dictionary = NSMutableDictionary::new();
+    /// let counts: HashMap<String, i64> = dictionary
+    ///     .entries::<NSString, NSNumber>()
+    ///     .into_iter()
+    ///     .map(|(key, value)| (key.to_string(), value.as_i64()))
+    ///     .collect();
+    /// ```
+    pub fn entries<K: Retainable, V: Retainable>(&self) -> Vec<(K, V)> {
+        let keys = NSArray::retain(unsafe { msg_send![&*self.0, allKeys] });
+
+        keys.iter()
+            .map(|key| {
+                let value: id = unsafe { msg_send![&*self.0, objectForKey: key] };
+                (K::retain(key), V::retain(value))
+            })
+            .collect()
+    }
+}
+
+impl<K: Retainable, V: Retainable> FromIterator<(K, V)> for NSMutableDictionary {
+    /// Creates a dictionary from `(key, value)` pairs - e.g, from a `HashMap` whose keys and
+    /// values have been converted to their Foundation counterparts.
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(entries: I) -> Self {
+        let mut dictionary = NSMutableDictionary::new();
+
+        for (key, value) in entries {
+            dictionary.insert_retainable(&key, &value);
+        }
+
+        dictionary
+    }
 }
 
 impl Deref for NSMutableDictionary {
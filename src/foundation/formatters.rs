@@ -0,0 +1,405 @@
+//! Wrappers for Foundation's formatters, which turn numbers, dates and byte counts into
+//! localized strings. These follow the user's locale (and settings) by default, which is
+//! generally what you want for anything shown in your UI.
+//!
+//! ```rust,no_run
+//! use std::time::{Duration, SystemTime};
+//! use cacao::foundation::{ByteCountFormatter, ByteCountStyle, DateFormatter, DateStyle, NumberFormatter, NumberStyle};
+//! use cacao::foundation::RelativeDateFormatter;
+//!
+//! let price = NumberFormatter::new(NumberStyle::Currency);
+//! price.set_currency_code("EUR");
+//! println!("{}", price.format(19.99));
+//!
+//! let dates = DateFormatter::new(DateStyle::Medium, DateStyle::Short);
+//! println!("{}", dates.format(SystemTime::now()));
+//!
+//! let relative = RelativeDateFormatter::new().unwrap();
+//! println!("{}", relative.format(SystemTime::now() - Duration::from_secs(7200)));
+//!
+//! println!("{}", ByteCountFormatter::new(ByteCountStyle::File).format(1_500_000));
+//! ```
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use objc::rc::{Id, Owned};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::available;
+use crate::error::Error;
+use crate::foundation::{id, NSInteger, NSNumber, NSString, NSUInteger, NO, YES};
+
+/// How a `NumberFormatter` presents numbers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NumberStyle {
+    /// No grouping or fraction digits beyond what's set (e.g, `1234.5`).
+    Plain,
+
+    /// Grouped, with locale-appropriate separators (e.g, `1,234.5`).
+    Decimal,
+
+    /// Currency, in the locale's currency unless you set one (e.g, `$1,234.50`).
+    Currency,
+
+    /// A percentage, where `1.0` is 100% (e.g, `12%`).
+    Percent,
+
+    /// Scientific notation (e.g, `1.2345E3`).
+    Scientific,
+
+    /// Spelled out in words (e.g, `one thousand two hundred thirty-four`).
+    SpellOut,
+
+    /// An ordinal (e.g, `3rd`).
+    Ordinal
+}
+
+impl From<NumberStyle> for NSUInteger {
+    fn from(style: NumberStyle) -> Self {
+        match style {
+            NumberStyle::Plain => 0,
+            NumberStyle::Decimal => 1,
+            NumberStyle::Currency => 2,
+            NumberStyle::Percent => 3,
+            NumberStyle::Scientific => 4,
+            NumberStyle::SpellOut => 5,
+            NumberStyle::Ordinal => 6
+        }
+    }
+}
+
+/// A wrapper for `NSNumberFormatter`, for locale-aware number formatting.
+#[derive(Debug)]
+pub struct NumberFormatter(pub Id<Object, Owned>);
+
+impl NumberFormatter {
+    /// Creates a formatter with the given style, using the user's current locale.
+    pub fn new(style: NumberStyle) -> Self {
+        let style: NSUInteger = style.into();
+
+        NumberFormatter(unsafe {
+            let formatter: Id<Object, Owned> = msg_send_id![class!(NSNumberFormatter), new];
+            let _: () = msg_send![&*formatter, setNumberStyle: style];
+            formatter
+        })
+    }
+
+    /// Sets the locale to format for (e.g, `"de_DE"`), rather than the user's.
+    pub fn set_locale(&self, identifier: &str) {
+        let locale = locale(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setLocale: locale];
+        }
+    }
+
+    /// Sets the ISO 4217 currency code (e.g, `"EUR"`) used by `NumberStyle::Currency`.
+    pub fn set_currency_code(&self, code: &str) {
+        let code = NSString::new(code);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setCurrencyCode:&*code];
+        }
+    }
+
+    /// Sets the minimum number of digits shown after the decimal separator.
+    pub fn set_minimum_fraction_digits(&self, digits: usize) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setMinimumFractionDigits: digits as NSUInteger];
+        }
+    }
+
+    /// Sets the maximum number of digits shown after the decimal separator.
+    pub fn set_maximum_fraction_digits(&self, digits: usize) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setMaximumFractionDigits: digits as NSUInteger];
+        }
+    }
+
+    /// Formats `value`.
+    pub fn format(&self, value: f64) -> String {
+        let number = NSNumber::float(value);
+
+        unsafe {
+            let string: id = msg_send![&*self.0, stringFromNumber:&*number.0];
+            NSString::retain(string).to_string()
+        }
+    }
+}
+
+/// How much detail a `DateFormatter` shows for the date or time.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DateStyle {
+    /// Left out entirely.
+    None,
+
+    /// Numeric only (e.g, `11/23/37` or `3:30 PM`).
+    Short,
+
+    /// Abbreviated (e.g, `Nov 23, 1937` or `3:30:32 PM`).
+    Medium,
+
+    /// Long form (e.g, `November 23, 1937` or `3:30:32 PM PST`).
+    Long,
+
+    /// Everything (e.g, `Tuesday, April 12, 1952 AD` or `3:30:42 PM Pacific Standard Time`).
+    Full
+}
+
+impl From<DateStyle> for NSUInteger {
+    fn from(style: DateStyle) -> Self {
+        match style {
+            DateStyle::None => 0,
+            DateStyle::Short => 1,
+            DateStyle::Medium => 2,
+            DateStyle::Long => 3,
+            DateStyle::Full => 4
+        }
+    }
+}
+
+/// A wrapper for `NSDateFormatter`, for locale-aware date and time formatting.
+#[derive(Debug)]
+pub struct DateFormatter(pub Id<Object, Owned>);
+
+impl DateFormatter {
+    /// Creates a formatter that shows the date and time with the given styles. Pass
+    /// `DateStyle::None` for either to leave it out.
+    pub fn new(date_style: DateStyle, time_style: DateStyle) -> Self {
+        let date_style: NSUInteger = date_style.into();
+        let time_style: NSUInteger = time_style.into();
+
+        DateFormatter(unsafe {
+            let formatter: Id<Object, Owned> = msg_send_id![class!(NSDateFormatter), new];
+            let _: () = msg_send![&*formatter, setDateStyle: date_style];
+            let _: () = msg_send![&*formatter, setTimeStyle: time_style];
+            formatter
+        })
+    }
+
+    /// Creates a formatter from a template of the fields you want (e.g, `"MMMd"` or `"jjmm"`),
+    /// which is rearranged (and punctuated) to suit the locale - so `"MMMd"` gives you `Nov 23`
+    /// in the US and `23 Nov` in the UK.
+    pub fn with_template(template: &str) -> Self {
+        let formatter = DateFormatter::new(DateStyle::None, DateStyle::None);
+        formatter.set_template(template);
+        formatter
+    }
+
+    /// Sets the locale to format for (e.g, `"de_DE"`), rather than the user's. If you're using a
+    /// template, set it again after this so it's rearranged for the new locale.
+    pub fn set_locale(&self, identifier: &str) {
+        let locale = locale(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setLocale: locale];
+        }
+    }
+
+    /// Sets the template to format with; see `with_template()`.
+    pub fn set_template(&self, template: &str) {
+        let template = NSString::new(template);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setLocalizedDateFormatFromTemplate:&*template];
+        }
+    }
+
+    /// Sets whether dates close to today are shown as e.g, "Today" or "Yesterday". This only
+    /// applies when formatting with date styles, not templates.
+    pub fn set_uses_relative_formatting(&self, relative: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setDoesRelativeDateFormatting: match relative {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Formats `time`.
+    pub fn format(&self, time: SystemTime) -> String {
+        let date = to_nsdate(time);
+
+        unsafe {
+            let string: id = msg_send![&*self.0, stringFromDate: date];
+            NSString::retain(string).to_string()
+        }
+    }
+}
+
+/// How a `RelativeDateFormatter` spells out units.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RelativeDateUnitsStyle {
+    /// E.g, `2 hours ago`.
+    Full,
+
+    /// E.g, `two hours ago`.
+    SpellOut,
+
+    /// E.g, `2 hr. ago`.
+    Short,
+
+    /// E.g, `2h ago`.
+    Abbreviated
+}
+
+impl From<RelativeDateUnitsStyle> for NSInteger {
+    fn from(style: RelativeDateUnitsStyle) -> Self {
+        match style {
+            RelativeDateUnitsStyle::Full => 0,
+            RelativeDateUnitsStyle::SpellOut => 1,
+            RelativeDateUnitsStyle::Short => 2,
+            RelativeDateUnitsStyle::Abbreviated => 3
+        }
+    }
+}
+
+/// A wrapper for `NSRelativeDateTimeFormatter`, for phrases like "2 hours ago" or "in 3 days".
+///
+/// This requires macOS 10.15+ or iOS 13+.
+#[derive(Debug)]
+pub struct RelativeDateFormatter(pub Id<Object, Owned>);
+
+impl RelativeDateFormatter {
+    /// Creates a formatter using the user's current locale.
+    ///
+    /// Returns an error (see `Error::is_unsupported_on_this_os()`) prior to macOS 10.15 or iOS 13,
+    /// where `NSRelativeDateTimeFormatter` doesn't exist.
+    pub fn new() -> Result<Self, Error> {
+        if !available!(macos 10.15, ios 13, tvos 13) {
+            return Err(Error::unsupported_on_this_os("RelativeDateFormatter::new", "macOS 10.15"));
+        }
+
+        Ok(RelativeDateFormatter(unsafe {
+            msg_send_id![class!(NSRelativeDateTimeFormatter), new]
+        }))
+    }
+
+    /// Sets the locale to format for (e.g, `"de_DE"`), rather than the user's.
+    pub fn set_locale(&self, identifier: &str) {
+        let locale = locale(identifier);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setLocale: locale];
+        }
+    }
+
+    /// Sets how units are spelled out.
+    pub fn set_units_style(&self, style: RelativeDateUnitsStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setUnitsStyle: style];
+        }
+    }
+
+    /// Sets whether to use named phrases where there are some (e.g, "yesterday" rather than "1
+    /// day ago").
+    pub fn set_uses_named_phrases(&self, named: bool) {
+        let style: NSInteger = match named {
+            true => 1,
+            false => 0
+        };
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setDateTimeStyle: style];
+        }
+    }
+
+    /// Formats `time` relative to now.
+    pub fn format(&self, time: SystemTime) -> String {
+        self.format_relative_to(time, SystemTime::now())
+    }
+
+    /// Formats `time` relative to `reference`.
+    pub fn format_relative_to(&self, time: SystemTime, reference: SystemTime) -> String {
+        let date = to_nsdate(time);
+        let reference = to_nsdate(reference);
+
+        unsafe {
+            let string: id = msg_send![&*self.0, localizedStringForDate: date, relativeToDate: reference];
+            NSString::retain(string).to_string()
+        }
+    }
+}
+
+/// How a `ByteCountFormatter` counts (and labels) bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ByteCountStyle {
+    /// Suitable for file sizes; the same as `Decimal` on macOS.
+    File,
+
+    /// Suitable for memory sizes; the same as `Binary` on macOS.
+    Memory,
+
+    /// 1 KB is 1000 bytes.
+    Decimal,
+
+    /// 1 KB is 1024 bytes.
+    Binary
+}
+
+impl From<ByteCountStyle> for NSInteger {
+    fn from(style: ByteCountStyle) -> Self {
+        match style {
+            ByteCountStyle::File => 0,
+            ByteCountStyle::Memory => 1,
+            ByteCountStyle::Decimal => 2,
+            ByteCountStyle::Binary => 3
+        }
+    }
+}
+
+/// A wrapper for `NSByteCountFormatter`, for sizes like "1.5 MB".
+#[derive(Debug)]
+pub struct ByteCountFormatter(pub Id<Object, Owned>);
+
+impl ByteCountFormatter {
+    /// Creates a formatter with the given style.
+    pub fn new(style: ByteCountStyle) -> Self {
+        let style: NSInteger = style.into();
+
+        ByteCountFormatter(unsafe {
+            let formatter: Id<Object, Owned> = msg_send_id![class!(NSByteCountFormatter), new];
+            let _: () = msg_send![&*formatter, setCountStyle: style];
+            formatter
+        })
+    }
+
+    /// Sets whether an exact byte count is shown alongside the rounded one (e.g, "1.5 MB
+    /// (1,500,000 bytes)").
+    pub fn set_includes_actual_byte_count(&self, includes: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setIncludesActualByteCount: match includes {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Formats `bytes`.
+    pub fn format(&self, bytes: i64) -> String {
+        unsafe {
+            let string: id = msg_send![&*self.0, stringFromByteCount: bytes];
+            NSString::retain(string).to_string()
+        }
+    }
+}
+
+/// Returns an (autoreleased) `NSLocale` for `identifier`.
+fn locale(identifier: &str) -> id {
+    let identifier = NSString::new(identifier);
+
+    unsafe { msg_send![class!(NSLocale), localeWithLocaleIdentifier:&*identifier] }
+}
+
+/// Converts a `SystemTime` into an (autoreleased) `NSDate`.
+pub(crate) fn to_nsdate(time: SystemTime) -> id {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs_f64(),
+        Err(e) => -e.duration().as_secs_f64()
+    };
+
+    unsafe { msg_send![class!(NSDate), dateWithTimeIntervalSince1970: seconds] }
+}
@@ -25,11 +25,17 @@ mod autoreleasepool;
 pub use autoreleasepool::AutoReleasePool;
 
 mod array;
-pub use array::NSArray;
+pub use array::{NSArray, NSArrayIterator};
 
 mod class;
-pub(crate) use class::ClassMap;
-pub use class::{load_or_register_class, load_or_register_class_with_optional_generated_suffix};
+pub use class::{
+    load_or_register_class, load_or_register_class_with_optional_generated_suffix, set_superclass_fallback,
+    try_load_or_register_class
+};
+pub(crate) use class::{ClassMap, StaticClass};
+
+#[cfg(test)]
+pub(crate) use class::class_lookups;
 
 mod data;
 pub use data::NSData;
@@ -37,15 +43,26 @@ pub use data::NSData;
 mod dictionary;
 pub use dictionary::NSMutableDictionary;
 
+mod formatters;
+pub(crate) use formatters::to_nsdate;
+pub use formatters::{
+    ByteCountFormatter, ByteCountStyle, DateFormatter, DateStyle, NumberFormatter, NumberStyle, RelativeDateFormatter,
+    RelativeDateUnitsStyle
+};
+
 mod number;
 pub use number::NSNumber;
 
+mod retainable;
+pub use retainable::Retainable;
+
 mod string;
 pub use string::NSString;
 
 // Separate named module to not conflict with the `url` crate. Go figure.
 mod urls;
-pub use urls::{NSURLBookmarkCreationOption, NSURLBookmarkResolutionOption, NSURL};
+pub(crate) use urls::to_system_time;
+pub use urls::{NSURLBookmarkCreationOption, NSURLBookmarkResolutionOption, NSURLComponents, NSURL};
 
 /// Bool mapping types differ between ARM and x64. There's a number of places that we need to check
 /// against BOOL results throughout the framework, and this just simplifies some mismatches.
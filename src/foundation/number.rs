@@ -1,4 +1,5 @@
 use std::ffi::CStr;
+use std::ops::{Deref, DerefMut};
 use std::os::raw::c_char;
 
 use objc::rc::{Id, Owned};
@@ -90,3 +91,19 @@ impl NSNumber {
         to_bool(result)
     }
 }
+
+impl Deref for NSNumber {
+    type Target = Object;
+
+    /// Derefs to the underlying Objective-C Object.
+    fn deref(&self) -> &Object {
+        &*self.0
+    }
+}
+
+impl DerefMut for NSNumber {
+    /// Derefs to the underlying Objective-C Object.
+    fn deref_mut(&mut self) -> &mut Object {
+        &mut *self.0
+    }
+}
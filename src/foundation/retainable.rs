@@ -0,0 +1,60 @@
+use std::ops::Deref;
+
+use objc::runtime::Object;
+
+use crate::foundation::{id, NSArray, NSData, NSMutableDictionary, NSNumber, NSString, NSURL};
+
+/// Implemented by the Foundation wrappers in this module, so that they can be moved in and out of
+/// `NSArray`s and `NSMutableDictionary`s generically:
+///
+/// ```rust,no_run
+/// use cacao::foundation::{NSArray, NSString, NSURL};
+///
+/// let urls: NSArray = vec![NSURL::with_str("https://example.com/")].into_iter().collect();
+/// let names: Vec<String> = urls.iter_as::<NSURL>().filter_map(|url| url.host()).collect();
+/// ```
+pub trait Retainable: Deref<Target = Object> {
+    /// Wraps (and retains) an object vended by the system, which must be of the right class.
+    fn retain(handle: id) -> Self;
+
+    /// Returns a pointer to the backing object, for passing to Objective-C.
+    fn as_id(&self) -> id {
+        &**self as *const Object as id
+    }
+}
+
+impl Retainable for NSArray {
+    fn retain(handle: id) -> Self {
+        NSArray::retain(handle)
+    }
+}
+
+impl Retainable for NSData {
+    fn retain(handle: id) -> Self {
+        NSData::retain(handle)
+    }
+}
+
+impl Retainable for NSMutableDictionary {
+    fn retain(handle: id) -> Self {
+        NSMutableDictionary::retain(handle)
+    }
+}
+
+impl Retainable for NSNumber {
+    fn retain(handle: id) -> Self {
+        NSNumber::retain(handle)
+    }
+}
+
+impl Retainable for NSString<'_> {
+    fn retain(handle: id) -> Self {
+        NSString::retain(handle)
+    }
+}
+
+impl Retainable for NSURL<'_> {
+    fn retain(handle: id) -> Self {
+        NSURL::retain(handle)
+    }
+}
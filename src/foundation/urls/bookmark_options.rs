@@ -64,3 +64,13 @@ impl From<NSURLBookmarkResolutionOption> for NSUInteger {
         }
     }
 }
+
+impl From<&NSURLBookmarkResolutionOption> for NSUInteger {
+    fn from(flag: &NSURLBookmarkResolutionOption) -> NSUInteger {
+        match flag {
+            NSURLBookmarkResolutionOption::WithoutUI => 1u64 << 8,
+            NSURLBookmarkResolutionOption::WithoutMounting => 1u64 << 9,
+            NSURLBookmarkResolutionOption::SecurityScoped => 1 << 10
+        }
+    }
+}
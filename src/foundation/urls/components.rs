@@ -0,0 +1,228 @@
+use objc::rc::{Id, Owned};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, nil, NSArray, NSNumber, NSString, NSURL, YES};
+
+/// Wraps `NSURLComponents`, for taking URLs apart and putting them together.
+///
+/// Getters return the percent-decoded values, and setters percent-encode as needed - so you can
+/// deal in plain strings throughout:
+///
+/// ```rust,no_run
+/// use cacao::foundation::NSURLComponents;
+///
+/// let mut components = NSURLComponents::new();
+/// components.set_scheme(Some("https"));
+/// components.set_host(Some("example.com"));
+/// components.set_path("/search");
+/// components.set_query_items(&[("q", Some("rust & cocoa"))]);
+///
+/// let url = components.url().unwrap();
+/// assert_eq!(url.absolute_string(), "https://example.com/search?q=rust%20%26%20cocoa");
+/// ```
+#[derive(Debug)]
+pub struct NSURLComponents {
+    /// A reference to the backing `NSURLComponents`.
+    pub objc: Id<Object, Owned>
+}
+
+impl NSURLComponents {
+    /// Creates a new, empty set of components.
+    pub fn new() -> Self {
+        NSURLComponents {
+            objc: unsafe { msg_send_id![class!(NSURLComponents), new] }
+        }
+    }
+
+    /// Parses `url` into its components. Returns `None` if it isn't a valid URL.
+    pub fn parse(url: &str) -> Option<Self> {
+        let url = NSString::new(url);
+
+        unsafe {
+            let components: Option<Id<Object, Owned>> = msg_send_id![class!(NSURLComponents), componentsWithString: &*url];
+
+            components.map(|objc| NSURLComponents { objc })
+        }
+    }
+
+    /// Returns the components of `url`.
+    pub fn from_url(url: &NSURL) -> Self {
+        NSURLComponents {
+            objc: unsafe {
+                msg_send_id![
+                    msg_send_id![class!(NSURLComponents), alloc],
+                    initWithURL: &*url.objc,
+                    resolvingAgainstBaseURL: YES,
+                ]
+            }
+        }
+    }
+
+    /// Builds a URL from these components. Returns `None` if they don't form a valid URL (e.g, a
+    /// host is set but the path doesn't start with `/`).
+    pub fn url(&self) -> Option<NSURL<'static>> {
+        let url: id = unsafe { msg_send![&*self.objc, URL] };
+
+        match url == nil {
+            true => None,
+            false => Some(NSURL::retain(url))
+        }
+    }
+
+    /// The scheme, e.g `https`.
+    pub fn scheme(&self) -> Option<String> {
+        optional_string(unsafe { msg_send![&*self.objc, scheme] })
+    }
+
+    /// Sets (or, with `None`, removes) the scheme.
+    pub fn set_scheme(&mut self, scheme: Option<&str>) {
+        let scheme = scheme.map(NSString::new);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setScheme: optional_id(&scheme)];
+        }
+    }
+
+    /// The host, e.g `example.com`.
+    pub fn host(&self) -> Option<String> {
+        optional_string(unsafe { msg_send![&*self.objc, host] })
+    }
+
+    /// Sets (or, with `None`, removes) the host.
+    pub fn set_host(&mut self, host: Option<&str>) {
+        let host = host.map(NSString::new);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setHost: optional_id(&host)];
+        }
+    }
+
+    /// The port, if one is specified.
+    pub fn port(&self) -> Option<u16> {
+        let port: id = unsafe { msg_send![&*self.objc, port] };
+
+        match port == nil {
+            true => None,
+            false => Some(NSNumber::retain(port).as_i64() as u16)
+        }
+    }
+
+    /// Sets (or, with `None`, removes) the port.
+    pub fn set_port(&mut self, port: Option<u16>) {
+        let port = port.map(|port| NSNumber::integer(port as i64));
+        let port: id = match &port {
+            Some(port) => &*port.0 as *const Object as id,
+            None => nil
+        };
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPort: port];
+        }
+    }
+
+    /// The path, e.g `/docs/Read Me.txt`. This is empty (rather than `None`) if there's no path.
+    pub fn path(&self) -> String {
+        optional_string(unsafe { msg_send![&*self.objc, path] }).unwrap_or_default()
+    }
+
+    /// Sets the path.
+    pub fn set_path(&mut self, path: &str) {
+        let path = NSString::new(path);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPath: &*path];
+        }
+    }
+
+    /// The query items, in order, as `(name, value)` pairs. A name with no `=` has a value of
+    /// `None`.
+    pub fn query_items(&self) -> Vec<(String, Option<String>)> {
+        let items: id = unsafe { msg_send![&*self.objc, queryItems] };
+
+        if items == nil {
+            return Vec::new();
+        }
+
+        NSArray::retain(items)
+            .iter()
+            .map(|item| unsafe {
+                let name = NSString::retain(msg_send![item, name]).to_string();
+                (name, optional_string(msg_send![item, value]))
+            })
+            .collect()
+    }
+
+    /// Replaces the query with `items`. An empty slice removes the query entirely.
+    pub fn set_query_items(&mut self, items: &[(&str, Option<&str>)]) {
+        if items.is_empty() {
+            unsafe {
+                let _: () = msg_send![&*self.objc, setQueryItems: nil];
+            }
+
+            return;
+        }
+
+        let items: Vec<Id<Object, Owned>> = items
+            .iter()
+            .map(|(name, value)| {
+                let name = NSString::new(name);
+                let value = value.map(NSString::new);
+
+                unsafe {
+                    msg_send_id![
+                        msg_send_id![class!(NSURLQueryItem), alloc],
+                        initWithName: &*name,
+                        value: optional_id(&value),
+                    ]
+                }
+            })
+            .collect();
+
+        let items: NSArray = items
+            .iter()
+            .map(|item| &**item as *const Object as id)
+            .collect::<Vec<id>>()
+            .into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setQueryItems: &*items];
+        }
+    }
+
+    /// The fragment (the part after `#`), if there is one.
+    pub fn fragment(&self) -> Option<String> {
+        optional_string(unsafe { msg_send![&*self.objc, fragment] })
+    }
+
+    /// Sets (or, with `None`, removes) the fragment.
+    pub fn set_fragment(&mut self, fragment: Option<&str>) {
+        let fragment = fragment.map(NSString::new);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setFragment: optional_id(&fragment)];
+        }
+    }
+}
+
+impl Default for NSURLComponents {
+    fn default() -> Self {
+        NSURLComponents::new()
+    }
+}
+
+/// Converts a possibly-nil `NSString` into an `Option<String>`.
+pub(crate) fn optional_string(string: id) -> Option<String> {
+    match string == nil {
+        true => None,
+        false => Some(NSString::retain(string).to_string())
+    }
+}
+
+/// Returns the backing object of an optional `NSString`, or `nil`.
+fn optional_id(string: &Option<NSString>) -> id {
+    match string {
+        Some(string) => &*string.objc as *const Object as id,
+        None => nil
+    }
+}
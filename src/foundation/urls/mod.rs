@@ -1,13 +1,17 @@
-use std::error::Error;
+use std::convert::TryFrom;
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use url::Url;
 
 use objc::rc::{Id, Shared};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
-use crate::foundation::{id, nil, NSData, NSString, NSUInteger};
+use crate::error::Error;
+use crate::foundation::{id, nil, to_bool, NSData, NSNumber, NSString, NSUInteger, BOOL, NO};
 
 mod bookmark_options;
 pub use bookmark_options::{NSURLBookmarkCreationOption, NSURLBookmarkResolutionOption};
@@ -15,6 +19,18 @@ pub use bookmark_options::{NSURLBookmarkCreationOption, NSURLBookmarkResolutionO
 mod resource_keys;
 pub use resource_keys::{NSURLFileResource, NSURLResourceKey, NSUbiquitousItemDownloadingStatus};
 
+mod components;
+use components::optional_string;
+pub use components::NSURLComponents;
+
+extern "C" {
+    static NSURLFileSizeKey: id;
+    static NSURLTypeIdentifierKey: id;
+    static NSURLCreationDateKey: id;
+    static NSURLContentModificationDateKey: id;
+    static NSURLIsDirectoryKey: id;
+}
+
 /// Wraps `NSURL` for use throughout the framework.
 ///
 /// This type may also be returned to users in some callbacks (e.g, file manager/selectors) as it's
@@ -56,6 +72,84 @@ impl<'a> NSURL<'a> {
         }
     }
 
+    /// Creates a file URL for `path`. Relative paths are resolved against the current directory.
+    ///
+    /// The path is percent-encoded as needed; `pathbuf()` gives it back, decoded.
+    pub fn with_path<P: AsRef<Path>>(path: P) -> Self {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+
+        Self {
+            objc: unsafe { msg_send_id![class!(NSURL), fileURLWithPath:&*path] },
+            phantom: PhantomData
+        }
+    }
+
+    /// Returns whether this is a `file:` URL (including file reference URLs).
+    pub fn is_file_url(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isFileURL] })
+    }
+
+    /// Returns whether this is a file reference URL - one that identifies a file by its ID rather
+    /// than its path, and so keeps pointing at it if it's moved or renamed.
+    pub fn is_file_reference_url(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isFileReferenceURL] })
+    }
+
+    /// Returns a file reference URL for the file this (file) URL points to. Returns `None` if this
+    /// isn't a file URL, or the file doesn't exist.
+    ///
+    /// Use `file_path_url()` to get back to a path-based URL, which reflects any moves since.
+    pub fn file_reference_url(&self) -> Option<Self> {
+        let url: id = unsafe { msg_send![&*self.objc, fileReferenceURL] };
+
+        match url == nil {
+            true => None,
+            false => Some(NSURL::retain(url))
+        }
+    }
+
+    /// Returns a path-based URL for a file reference URL (or this URL again, if it's already
+    /// path-based). Returns `None` if the file no longer exists.
+    pub fn file_path_url(&self) -> Option<Self> {
+        let url: id = unsafe { msg_send![&*self.objc, filePathURL] };
+
+        match url == nil {
+            true => None,
+            false => Some(NSURL::retain(url))
+        }
+    }
+
+    /// Returns the scheme, e.g `https`.
+    pub fn scheme(&self) -> Option<String> {
+        optional_string(unsafe { msg_send![&*self.objc, scheme] })
+    }
+
+    /// Returns the host, e.g `example.com`.
+    pub fn host(&self) -> Option<String> {
+        optional_string(unsafe { msg_send![&*self.objc, host] })
+    }
+
+    /// Returns the (percent-decoded) path.
+    pub fn path(&self) -> Option<String> {
+        optional_string(unsafe { msg_send![&*self.objc, path] })
+    }
+
+    /// Returns the (still percent-encoded) query string.
+    pub fn query(&self) -> Option<String> {
+        optional_string(unsafe { msg_send![&*self.objc, query] })
+    }
+
+    /// Returns the (still percent-encoded) fragment.
+    pub fn fragment(&self) -> Option<String> {
+        optional_string(unsafe { msg_send![&*self.objc, fragment] })
+    }
+
+    /// Returns this URL's components; see `NSURLComponents` for decoded query items, and for
+    /// building modified URLs.
+    pub fn components(&self) -> NSURLComponents {
+        NSURLComponents::from_url(self)
+    }
+
     /// Returns the absolute string path that this URL points to.
     ///
     /// Note that if the underlying file moved, this won't be accurate - you likely want to
@@ -84,7 +178,7 @@ impl<'a> NSURL<'a> {
         options: &[NSURLBookmarkCreationOption],
         resource_value_keys: &[NSURLResourceKey],
         relative_to_url: Option<NSURL>
-    ) -> Result<NSData, Box<dyn Error>> {
+    ) -> Result<NSData, Error> {
         let mut opts: NSUInteger = 0;
         for mask in options {
             let i: NSUInteger = mask.into();
@@ -94,43 +188,131 @@ impl<'a> NSURL<'a> {
         // Build NSArray of resource keys
         let resource_keys = nil;
 
-        // Mutability woes mean we just go through a match here to satisfy message passing needs.
-        let bookmark_data = NSData::retain(match relative_to_url {
-            Some(relative_url) => unsafe {
-                msg_send![
-                    &*self.objc,
-                    bookmarkDataWithOptions: opts,
-                    includingResourceValuesForKeys: resource_keys,
-                    relativeToURL: &*relative_url,
-                    error: nil,
-                ]
-            },
-
-            None => unsafe {
-                msg_send![
-                    &*self.objc,
-                    bookmarkDataWithOptions: opts,
-                    includingResourceValuesForKeys: resource_keys,
-                    relativeToURL: nil,
-                    error: nil,
-                ]
-            }
-        });
+        let relative_to_url: id = match &relative_to_url {
+            Some(url) => &*url.objc as *const Object as id,
+            None => nil
+        };
 
-        // Check for errors...
-        //Err("LOL".into())
+        unsafe {
+            let mut error: id = nil;
+            let data: id = msg_send![
+                &*self.objc,
+                bookmarkDataWithOptions: opts,
+                includingResourceValuesForKeys: resource_keys,
+                relativeToURL: relative_to_url,
+                error: &mut error,
+            ];
+
+            if data == nil {
+                return Err(Error::new(error));
+            }
 
-        Ok(bookmark_data)
+            Ok(NSData::retain(data))
+        }
     }
 
-    /// Converts bookmark data into a URL.
+    /// Converts bookmark data into a URL. `data_is_stale` is set to whether the bookmark data is
+    /// stale (i.e, the file has moved since it was created); if it is, replace your stored
+    /// bookmark data with fresh data from the returned URL.
     pub fn from_bookmark_data(
         data: NSData,
         options: &[NSURLBookmarkResolutionOption],
         relative_to_url: Option<NSURL>,
-        data_is_stale: bool
-    ) -> Result<Self, Box<dyn Error>> {
-        Err("LOL".into())
+        data_is_stale: &mut bool
+    ) -> Result<Self, Error> {
+        let (url, is_stale) = NSURL::resolve_bookmark_data(&data, options, relative_to_url.as_ref())?;
+        *data_is_stale = is_stale;
+        Ok(url)
+    }
+
+    /// Converts bookmark data into a URL, along with whether the bookmark data is stale (i.e, the
+    /// file has moved since it was created). If it is, replace your stored bookmark data with
+    /// fresh data from the returned URL.
+    pub fn resolve_bookmark_data(
+        data: &NSData,
+        options: &[NSURLBookmarkResolutionOption],
+        relative_to_url: Option<&NSURL>
+    ) -> Result<(Self, bool), Error> {
+        let mut opts: NSUInteger = 0;
+        for mask in options {
+            let i: NSUInteger = mask.into();
+            opts = opts | i;
+        }
+
+        let relative_to_url: id = match relative_to_url {
+            Some(url) => &*url.objc as *const Object as id,
+            None => nil
+        };
+
+        unsafe {
+            let mut is_stale: BOOL = NO;
+            let mut error: id = nil;
+            let url: id = msg_send![
+                class!(NSURL),
+                URLByResolvingBookmarkData: &**data,
+                options: opts,
+                relativeToURL: relative_to_url,
+                bookmarkDataIsStale: &mut is_stale,
+                error: &mut error,
+            ];
+
+            if url == nil {
+                return Err(Error::new(error));
+            }
+
+            Ok((NSURL::retain(url), to_bool(is_stale)))
+        }
+    }
+
+    /// Fetches a resource value for a file URL. `Ok(None)` means the value isn't available for
+    /// this resource (e.g, a directory has no file size).
+    fn resource_value(&self, key: id) -> Result<Option<id>, Error> {
+        unsafe {
+            let mut value: id = nil;
+            let mut error: id = nil;
+            let success: BOOL = msg_send![
+                &*self.objc,
+                getResourceValue: &mut value,
+                forKey: key,
+                error: &mut error,
+            ];
+
+            match (to_bool(success), value == nil) {
+                (false, _) => Err(Error::new(error)),
+                (true, true) => Ok(None),
+                (true, false) => Ok(Some(value))
+            }
+        }
+    }
+
+    /// Returns the size of the file, in bytes.
+    pub fn file_size(&self) -> Result<Option<u64>, Error> {
+        let value = self.resource_value(unsafe { NSURLFileSizeKey })?;
+        Ok(value.map(|value| NSNumber::retain(value).as_i64() as u64))
+    }
+
+    /// Returns whether the resource is a directory.
+    pub fn is_directory(&self) -> Result<bool, Error> {
+        let value = self.resource_value(unsafe { NSURLIsDirectoryKey })?;
+        Ok(value.map(|value| NSNumber::retain(value).as_bool()).unwrap_or(false))
+    }
+
+    /// Returns the uniform type identifier of the file's content, e.g `public.png`.
+    pub fn content_type(&self) -> Result<Option<String>, Error> {
+        let value = self.resource_value(unsafe { NSURLTypeIdentifierKey })?;
+        Ok(value.map(|value| NSString::retain(value).to_string()))
+    }
+
+    /// Returns when the file was created.
+    pub fn creation_date(&self) -> Result<Option<SystemTime>, Error> {
+        let value = self.resource_value(unsafe { NSURLCreationDateKey })?;
+        Ok(value.map(to_system_time))
+    }
+
+    /// Returns when the file's content was last modified.
+    pub fn content_modification_date(&self) -> Result<Option<SystemTime>, Error> {
+        let value = self.resource_value(unsafe { NSURLContentModificationDateKey })?;
+        Ok(value.map(to_system_time))
     }
 
     /// In an app that has adopted App Sandbox, makes the resource pointed to by a security-scoped URL available to the app.
@@ -154,6 +336,32 @@ impl<'a> NSURL<'a> {
     }
 }
 
+/// Converts an `NSDate` into a `SystemTime`.
+pub(crate) fn to_system_time(date: id) -> SystemTime {
+    let seconds: f64 = unsafe { msg_send![date, timeIntervalSince1970] };
+
+    match seconds >= 0. {
+        true => UNIX_EPOCH + Duration::from_secs_f64(seconds),
+        false => UNIX_EPOCH - Duration::from_secs_f64(-seconds)
+    }
+}
+
+impl From<&Url> for NSURL<'_> {
+    /// Creates an `NSURL` from a `url::Url`.
+    fn from(url: &Url) -> Self {
+        NSURL::with_str(url.as_str())
+    }
+}
+
+impl TryFrom<&NSURL<'_>> for Url {
+    type Error = url::ParseError;
+
+    /// Parses the `NSURL`'s absolute string as a `url::Url`.
+    fn try_from(url: &NSURL<'_>) -> Result<Self, Self::Error> {
+        Url::parse(&url.absolute_string())
+    }
+}
+
 impl Deref for NSURL<'_> {
     type Target = Object;
 
@@ -0,0 +1,287 @@
+//! Wraps the inputs on a controller (`GCControllerButtonInput` and
+//! `GCControllerDirectionPad`), and the profiles that group them.
+
+use block::ConcreteBlock;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{msg_send, sel};
+
+use crate::available;
+use crate::foundation::{id, nil, to_bool, BOOL, NO, YES};
+
+/// A button (or trigger) on a controller.
+#[derive(Clone, Debug)]
+pub struct Button(pub Id<Object, Shared>);
+
+impl Button {
+    /// How far the button is pressed, from `0.0` to `1.0`. Most face buttons are digital, and
+    /// only ever report `0.0` or `1.0`; triggers report everything in between.
+    pub fn value(&self) -> f32 {
+        unsafe { msg_send![&*self.0, value] }
+    }
+
+    /// Whether the button counts as pressed. For analog buttons, this kicks in partway.
+    pub fn is_pressed(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.0, isPressed] })
+    }
+
+    /// Calls `handler` with the value and pressed state whenever either changes. Handlers are
+    /// called on the main thread. Setting a new handler replaces the old one.
+    pub fn on_change<F: Fn(f32, bool) + 'static>(&self, handler: F) {
+        let block = ConcreteBlock::new(move |_button: id, value: f32, pressed: BOOL| {
+            handler(value, to_bool(pressed));
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setValueChangedHandler: &*block];
+        }
+    }
+
+    /// Removes a handler set with `on_change()`.
+    pub fn clear_on_change(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setValueChangedHandler: nil];
+        }
+    }
+}
+
+/// A thumbstick or directional pad, reporting a position on two axes.
+#[derive(Clone, Debug)]
+pub struct Stick(pub Id<Object, Shared>);
+
+impl Stick {
+    /// The position along the x axis, from `-1.0` (left) to `1.0` (right).
+    pub fn x(&self) -> f32 {
+        unsafe {
+            let axis: id = msg_send![&*self.0, xAxis];
+            msg_send![axis, value]
+        }
+    }
+
+    /// The position along the y axis, from `-1.0` (down) to `1.0` (up).
+    pub fn y(&self) -> f32 {
+        unsafe {
+            let axis: id = msg_send![&*self.0, yAxis];
+            msg_send![axis, value]
+        }
+    }
+
+    /// Returns the stick's up direction as a button - handy for menus, where the stick acts like
+    /// a directional pad.
+    pub fn up(&self) -> Button {
+        Button(unsafe { element(msg_send![&*self.0, up]) })
+    }
+
+    /// Returns the stick's down direction as a button.
+    pub fn down(&self) -> Button {
+        Button(unsafe { element(msg_send![&*self.0, down]) })
+    }
+
+    /// Returns the stick's left direction as a button.
+    pub fn left(&self) -> Button {
+        Button(unsafe { element(msg_send![&*self.0, left]) })
+    }
+
+    /// Returns the stick's right direction as a button.
+    pub fn right(&self) -> Button {
+        Button(unsafe { element(msg_send![&*self.0, right]) })
+    }
+
+    /// Calls `handler` with the `(x, y)` position whenever it changes. Handlers are called on the
+    /// main thread. Setting a new handler replaces the old one.
+    pub fn on_change<F: Fn(f32, f32) + 'static>(&self, handler: F) {
+        let block = ConcreteBlock::new(move |_stick: id, x: f32, y: f32| {
+            handler(x, y);
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setValueChangedHandler: &*block];
+        }
+    }
+
+    /// Removes a handler set with `on_change()`.
+    pub fn clear_on_change(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setValueChangedHandler: nil];
+        }
+    }
+}
+
+/// The buttons on a full gamepad (see `Gamepad::button()`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    /// The bottom face button (A on Xbox, Cross on PlayStation).
+    A,
+
+    /// The right face button (B on Xbox, Circle on PlayStation).
+    B,
+
+    /// The left face button (X on Xbox, Square on PlayStation).
+    X,
+
+    /// The top face button (Y on Xbox, Triangle on PlayStation).
+    Y,
+
+    /// The left shoulder button.
+    LeftShoulder,
+
+    /// The right shoulder button.
+    RightShoulder,
+
+    /// The left trigger, which is analog.
+    LeftTrigger,
+
+    /// The right trigger, which is analog.
+    RightTrigger,
+
+    /// Pressing in the left thumbstick. Not every controller has this.
+    LeftThumbstick,
+
+    /// Pressing in the right thumbstick. Not every controller has this.
+    RightThumbstick,
+
+    /// The menu (or start) button. Requires macOS 10.15, iOS 13 or tvOS 13.
+    Menu,
+
+    /// The options (or select) button. Not every controller has this. Requires macOS 10.15,
+    /// iOS 13 or tvOS 13.
+    Options,
+
+    /// The home button. Not every controller has this, and the system may reserve it. Requires
+    /// macOS 11, iOS 14 or tvOS 14.
+    Home
+}
+
+/// The sticks on a full gamepad (see `Gamepad::stick()`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GamepadStick {
+    /// The directional pad.
+    DirectionPad,
+
+    /// The left thumbstick.
+    Left,
+
+    /// The right thumbstick.
+    Right
+}
+
+/// A wrapper over `GCExtendedGamepad`: the layout of a full controller (e.g, an Xbox or
+/// PlayStation controller), with face buttons, shoulders, triggers, two thumbsticks and a
+/// directional pad.
+#[derive(Clone, Debug)]
+pub struct Gamepad(pub Id<Object, Shared>);
+
+impl Gamepad {
+    /// Returns `button`, or `None` if this controller (or this OS) doesn't have it.
+    pub fn button(&self, button: GamepadButton) -> Option<Button> {
+        let supported = match button {
+            GamepadButton::Menu | GamepadButton::Options => available!(macos 10.15, ios 13, tvos 13),
+            GamepadButton::Home => available!(macos 11, ios 14, tvos 14),
+            _ => true
+        };
+
+        if !supported {
+            return None;
+        }
+
+        let element: id = unsafe {
+            match button {
+                GamepadButton::A => msg_send![&*self.0, buttonA],
+                GamepadButton::B => msg_send![&*self.0, buttonB],
+                GamepadButton::X => msg_send![&*self.0, buttonX],
+                GamepadButton::Y => msg_send![&*self.0, buttonY],
+                GamepadButton::LeftShoulder => msg_send![&*self.0, leftShoulder],
+                GamepadButton::RightShoulder => msg_send![&*self.0, rightShoulder],
+                GamepadButton::LeftTrigger => msg_send![&*self.0, leftTrigger],
+                GamepadButton::RightTrigger => msg_send![&*self.0, rightTrigger],
+                GamepadButton::LeftThumbstick => msg_send![&*self.0, leftThumbstickButton],
+                GamepadButton::RightThumbstick => msg_send![&*self.0, rightThumbstickButton],
+                GamepadButton::Menu => msg_send![&*self.0, buttonMenu],
+                GamepadButton::Options => msg_send![&*self.0, buttonOptions],
+                GamepadButton::Home => msg_send![&*self.0, buttonHome]
+            }
+        };
+
+        unsafe { Id::retain(element) }.map(Button)
+    }
+
+    /// Returns `stick`.
+    pub fn stick(&self, stick: GamepadStick) -> Stick {
+        Stick(unsafe {
+            element(match stick {
+                GamepadStick::DirectionPad => msg_send![&*self.0, dpad],
+                GamepadStick::Left => msg_send![&*self.0, leftThumbstick],
+                GamepadStick::Right => msg_send![&*self.0, rightThumbstick]
+            })
+        })
+    }
+
+    /// Calls `handler` whenever any input on the gamepad changes - useful if you'd rather read
+    /// everything at once than set up handlers for each button.
+    pub fn on_change<F: Fn(&Gamepad) + 'static>(&self, handler: F) {
+        let block = ConcreteBlock::new(move |gamepad: id, _element: id| {
+            handler(&Gamepad(unsafe { element(gamepad) }));
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setValueChangedHandler: &*block];
+        }
+    }
+}
+
+/// A wrapper over `GCMicroGamepad`: the layout of the Siri Remote on tvOS, with a touch
+/// surface acting as a directional pad, and two buttons.
+#[derive(Clone, Debug)]
+pub struct MicroGamepad(pub Id<Object, Shared>);
+
+impl MicroGamepad {
+    /// The touch surface (or clickpad), as a directional pad.
+    pub fn dpad(&self) -> Stick {
+        Stick(unsafe { element(msg_send![&*self.0, dpad]) })
+    }
+
+    /// The primary button (clicking the touch surface).
+    pub fn button_a(&self) -> Button {
+        Button(unsafe { element(msg_send![&*self.0, buttonA]) })
+    }
+
+    /// The secondary button (play/pause).
+    pub fn button_x(&self) -> Button {
+        Button(unsafe { element(msg_send![&*self.0, buttonX]) })
+    }
+
+    /// The menu (or back) button.
+    pub fn button_menu(&self) -> Button {
+        Button(unsafe { element(msg_send![&*self.0, buttonMenu]) })
+    }
+
+    /// Whether the touch surface reports where the finger is (`true`), or how far it's moved
+    /// from where it touched down (`false`, the default).
+    pub fn set_reports_absolute_dpad_values(&self, absolute: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setReportsAbsoluteDpadValues: match absolute {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Whether the touch surface rotates its values when the remote is turned sideways.
+    pub fn set_allows_rotation(&self, allows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setAllowsRotation: match allows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
+
+/// Retains an input element (or profile) that's guaranteed to exist.
+unsafe fn element(element: id) -> Id<Object, Shared> {
+    Id::retain(element).unwrap()
+}
@@ -0,0 +1,153 @@
+//! Rumble for controllers that support it, via the `CHHapticEngine` that `GCDeviceHaptics`
+//! hands out.
+//!
+//! CoreHaptics (and the GameController haptics API) only exist on newer systems, so nothing here
+//! is linked directly: the framework is loaded on first use, and its constants are looked up at
+//! runtime. Linking them would stop apps from launching at all on older systems.
+
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+use libc::{c_char, dlopen, dlsym, RTLD_DEFAULT, RTLD_LAZY};
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::error::Error;
+use crate::foundation::{id, nil, to_bool, NSArray, BOOL};
+
+lazy_static! {
+    /// Whether CoreHaptics could be loaded.
+    static ref CORE_HAPTICS: bool = unsafe {
+        let path = b"/System/Library/Frameworks/CoreHaptics.framework/CoreHaptics\0";
+        !dlopen(path.as_ptr() as *const c_char, RTLD_LAZY).is_null()
+    };
+}
+
+/// Looks up the string constant named `symbol` (which must be nul-terminated), returning `None`
+/// if this system doesn't have it.
+fn constant(symbol: &[u8]) -> Option<id> {
+    unsafe {
+        let ptr = dlsym(RTLD_DEFAULT, symbol.as_ptr() as *const c_char) as *const id;
+
+        match ptr.is_null() {
+            true => None,
+            false => Some(*ptr)
+        }
+    }
+}
+
+/// The haptic engine for a controller (see `GameController::haptics()`).
+#[derive(Debug)]
+pub struct Haptics {
+    /// The underlying `CHHapticEngine`.
+    pub engine: Id<Object, Shared>
+}
+
+impl Haptics {
+    /// Creates an engine for `haptics` (a `GCDeviceHaptics`), driving every motor in the
+    /// controller.
+    pub(crate) fn new(haptics: &Object) -> Option<Self> {
+        if !*CORE_HAPTICS {
+            return None;
+        }
+
+        let locality = constant(b"GCHapticsLocalityDefault\0")?;
+        let engine: Option<Id<Object, Shared>> = unsafe { msg_send_id![haptics, createEngineWithLocality: locality] };
+
+        engine.map(|engine| Haptics { engine })
+    }
+
+    /// Starts the engine. `rumble()` does this for you, but starting it ahead of time avoids a
+    /// delay on the first rumble.
+    pub fn start(&self) -> Result<(), Error> {
+        let mut error: id = nil;
+        let started: BOOL = unsafe { msg_send![&*self.engine, startAndReturnError: &mut error] };
+
+        match to_bool(started) {
+            true => Ok(()),
+            false => Err(Error::new(error))
+        }
+    }
+
+    /// Stops the engine, cutting off anything that's playing.
+    pub fn stop(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.engine, stopWithCompletionHandler: nil];
+        }
+    }
+
+    /// Rumbles the controller for `duration`. `intensity` (how strong) and `sharpness` (how
+    /// crisp, rather than rounded) both range from `0.0` to `1.0`.
+    pub fn rumble(&self, intensity: f32, sharpness: f32, duration: Duration) -> Result<(), Error> {
+        let (continuous, intensity_id, sharpness_id) = match (
+            constant(b"CHHapticEventTypeHapticContinuous\0"),
+            constant(b"CHHapticEventParameterIDHapticIntensity\0"),
+            constant(b"CHHapticEventParameterIDHapticSharpness\0")
+        ) {
+            (Some(continuous), Some(intensity), Some(sharpness)) => (continuous, intensity, sharpness),
+            _ => return Err(Error::unsupported_on_this_os("Haptics::rumble", "macOS 11"))
+        };
+
+        self.start()?;
+
+        let mut error: id = nil;
+
+        unsafe {
+            let intensity = parameter(intensity_id, intensity);
+            let sharpness = parameter(sharpness_id, sharpness);
+            let parameters = NSArray::new(&[as_id(&intensity), as_id(&sharpness)]);
+
+            let event: Id<Object, Shared> = msg_send_id![
+                msg_send_id![class!(CHHapticEvent), alloc],
+                initWithEventType: continuous,
+                parameters: &*parameters,
+                relativeTime: 0f64,
+                duration: duration.as_secs_f64(),
+            ];
+
+            let events = NSArray::new(&[as_id(&event)]);
+            let empty = NSArray::new(&[]);
+
+            let pattern: Option<Id<Object, Shared>> = msg_send_id![
+                msg_send_id![class!(CHHapticPattern), alloc],
+                initWithEvents: &*events,
+                parameters: &*empty,
+                error: &mut error,
+            ];
+
+            let pattern = match pattern {
+                Some(pattern) => pattern,
+                None => return Err(Error::new(error))
+            };
+
+            let player: id = msg_send![&*self.engine, createPlayerWithPattern: &*pattern, error: &mut error];
+            if player == nil {
+                return Err(Error::new(error));
+            }
+
+            // CHHapticTimeImmediate
+            let started: BOOL = msg_send![player, startAtTime: 0f64, error: &mut error];
+
+            match to_bool(started) {
+                true => Ok(()),
+                false => Err(Error::new(error))
+            }
+        }
+    }
+}
+
+/// Returns a `CHHapticEventParameter`.
+unsafe fn parameter(parameter_id: id, value: f32) -> Id<Object, Shared> {
+    msg_send_id![
+        msg_send_id![class!(CHHapticEventParameter), alloc],
+        initWithParameterID: parameter_id,
+        value: value,
+    ]
+}
+
+/// Returns `object` as an `id`, for building arrays.
+fn as_id(object: &Id<Object, Shared>) -> id {
+    &**object as *const Object as id
+}
@@ -0,0 +1,244 @@
+//! Wraps `GameController.framework`, for reading input from game controllers (Xbox, PlayStation,
+//! MFi, and the Siri Remote on tvOS) on macOS and tvOS.
+//!
+//! Controllers come and go, so watch for them with a `ControllerObserver`, then either poll
+//! their inputs each frame or set change handlers on them:
+//!
+//! ```rust,no_run
+//! use cacao::game_controller::{ControllerObserver, GameController, GamepadButton, GamepadStick};
+//!
+//! let mut observer = ControllerObserver::new();
+//!
+//! observer.on_connect(|controller| {
+//!     if let Some(gamepad) = controller.gamepad() {
+//!         if let Some(jump) = gamepad.button(GamepadButton::A) {
+//!             jump.on_change(|_value, pressed| {
+//!                 if pressed {
+//!                     println!("Jump!");
+//!                 }
+//!             });
+//!         }
+//!     }
+//! });
+//!
+//! // Later, e.g in your game loop:
+//! for controller in GameController::controllers() {
+//!     if let Some(gamepad) = controller.gamepad() {
+//!         let stick = gamepad.stick(GamepadStick::Left);
+//!         println!("Moving by ({}, {})", stick.x(), stick.y());
+//!     }
+//! }
+//! ```
+//!
+//! Controllers that are already connected when your app launches are announced via `on_connect()`
+//! too, once the run loop is running. Keep the `ControllerObserver` around for as long as you
+//! want to hear about them.
+//!
+//! To use this module, you must specify the `game-controller` feature flag in your `Cargo.toml`.
+
+use block::ConcreteBlock;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::available;
+use crate::foundation::{id, nil, NSArray, NSInteger, NSString};
+
+mod elements;
+pub use elements::{Button, Gamepad, GamepadButton, GamepadStick, MicroGamepad, Stick};
+
+mod haptics;
+pub use haptics::Haptics;
+
+extern "C" {
+    static GCControllerDidConnectNotification: id;
+    static GCControllerDidDisconnectNotification: id;
+}
+
+/// A wrapper over a `GCController`: one connected controller.
+#[derive(Clone, Debug)]
+pub struct GameController(pub Id<Object, Shared>);
+
+impl GameController {
+    /// Wraps (and retains) a `GCController`.
+    fn retain(controller: id) -> Self {
+        GameController(unsafe { Id::retain(controller).unwrap() })
+    }
+
+    /// Returns every connected controller.
+    pub fn controllers() -> Vec<GameController> {
+        let controllers = NSArray::retain(unsafe { msg_send![class!(GCController), controllers] });
+        controllers.iter().map(GameController::retain).collect()
+    }
+
+    /// Returns the controller the user used most recently, if any are connected.
+    ///
+    /// Requires macOS 11 or tvOS 14; this always returns `None` on older systems.
+    pub fn current() -> Option<GameController> {
+        if !available!(macos 11, ios 14, tvos 14) {
+            return None;
+        }
+
+        let controller: id = unsafe { msg_send![class!(GCController), current] };
+
+        match controller == nil {
+            true => None,
+            false => Some(GameController::retain(controller))
+        }
+    }
+
+    /// Starts looking for wireless controllers that are in pairing mode, for up to a minute
+    /// (calling `handler` when it's done). Newly paired controllers are announced via
+    /// `ControllerObserver::on_connect()`.
+    pub fn start_wireless_discovery<F: Fn() + Send + Sync + 'static>(handler: F) {
+        let block = ConcreteBlock::new(move || {
+            handler();
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![
+                class!(GCController),
+                startWirelessControllerDiscoveryWithCompletionHandler: &*block
+            ];
+        }
+    }
+
+    /// Stops looking for wireless controllers.
+    pub fn stop_wireless_discovery() {
+        unsafe {
+            let _: () = msg_send![class!(GCController), stopWirelessControllerDiscovery];
+        }
+    }
+
+    /// The controller's vendor name (e.g, `"Xbox Wireless Controller"`), if it has one.
+    pub fn vendor_name(&self) -> Option<String> {
+        let name: id = unsafe { msg_send![&*self.0, vendorName] };
+
+        match name == nil {
+            true => None,
+            false => Some(NSString::retain(name).to_string())
+        }
+    }
+
+    /// The kind of controller (e.g, `"DualSense"`), in a form that's suitable for choosing
+    /// button glyphs with.
+    ///
+    /// Requires macOS 10.15 or tvOS 13; this always returns `None` on older systems.
+    pub fn product_category(&self) -> Option<String> {
+        if !available!(macos 10.15, ios 13, tvos 13) {
+            return None;
+        }
+
+        Some(NSString::retain(unsafe { msg_send![&*self.0, productCategory] }).to_string())
+    }
+
+    /// The player number (from `0` to `3`) shown on the controller's lights, if one's been set.
+    pub fn player_index(&self) -> Option<usize> {
+        let index: NSInteger = unsafe { msg_send![&*self.0, playerIndex] };
+
+        match index < 0 {
+            true => None,
+            false => Some(index as usize)
+        }
+    }
+
+    /// Sets the player number (from `0` to `3`) shown on the controller's lights, or clears it
+    /// with `None`.
+    pub fn set_player_index(&self, index: Option<usize>) {
+        // GCControllerPlayerIndexUnset
+        let index: NSInteger = index.map(|index| index as NSInteger).unwrap_or(-1);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setPlayerIndex: index];
+        }
+    }
+
+    /// Returns the controller's inputs as a full gamepad, or `None` if it isn't one (e.g, it's a
+    /// Siri Remote).
+    pub fn gamepad(&self) -> Option<Gamepad> {
+        unsafe { Id::retain(msg_send![&*self.0, extendedGamepad]) }.map(Gamepad)
+    }
+
+    /// Returns the controller's inputs as a Siri Remote-style micro gamepad, or `None` if it
+    /// doesn't support that profile.
+    pub fn micro_gamepad(&self) -> Option<MicroGamepad> {
+        unsafe { Id::retain(msg_send![&*self.0, microGamepad]) }.map(MicroGamepad)
+    }
+
+    /// Returns a haptic engine for the controller, or `None` if it doesn't support haptics.
+    ///
+    /// Controller haptics require macOS 11 or tvOS 14; this always returns `None` on older
+    /// systems.
+    pub fn haptics(&self) -> Option<Haptics> {
+        if !available!(macos 11, ios 14, tvos 14) {
+            return None;
+        }
+
+        let haptics: id = unsafe { msg_send![&*self.0, haptics] };
+
+        match haptics == nil {
+            true => None,
+            false => Haptics::new(unsafe { &*haptics })
+        }
+    }
+}
+
+/// Tells you when controllers connect and disconnect. Handlers are called on the main thread,
+/// and stop being called once this is dropped.
+#[derive(Debug, Default)]
+pub struct ControllerObserver {
+    observers: Vec<Id<Object, Shared>>
+}
+
+impl ControllerObserver {
+    /// Creates an observer with no handlers.
+    pub fn new() -> Self {
+        ControllerObserver::default()
+    }
+
+    /// Calls `handler` with each controller that connects.
+    pub fn on_connect<F: Fn(GameController) + 'static>(&mut self, handler: F) {
+        self.observe(unsafe { GCControllerDidConnectNotification }, handler);
+    }
+
+    /// Calls `handler` with each controller that disconnects. Drop anything you're holding on to
+    /// for it.
+    pub fn on_disconnect<F: Fn(GameController) + 'static>(&mut self, handler: F) {
+        self.observe(unsafe { GCControllerDidDisconnectNotification }, handler);
+    }
+
+    /// Observes `name`, calling `handler` with the controller the notification is about.
+    fn observe<F: Fn(GameController) + 'static>(&mut self, name: id, handler: F) {
+        let block = ConcreteBlock::new(move |notification: id| {
+            let controller: id = unsafe { msg_send![notification, object] };
+            handler(GameController::retain(controller));
+        });
+        let block = block.copy();
+
+        self.observers.push(unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+            let queue: id = msg_send![class!(NSOperationQueue), mainQueue];
+
+            msg_send_id![
+                center,
+                addObserverForName: name,
+                object: nil,
+                queue: queue,
+                usingBlock: &*block
+            ]
+        });
+    }
+}
+
+impl Drop for ControllerObserver {
+    fn drop(&mut self) {
+        unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+            for observer in self.observers.drain(..) {
+                let _: () = msg_send![center, removeObserver: &*observer];
+            }
+        }
+    }
+}
@@ -39,6 +39,90 @@ impl Rect {
             height: 0.0
         }
     }
+
+    /// Returns this rect with each edge moved to the nearest device pixel, for a display with the
+    /// given backing `scale` (e.g, `2.0` for Retina). Frames on pixel boundaries draw crisply;
+    /// ones that straddle them get blurry, antialiased edges.
+    ///
+    /// For a view's own geometry, `Layout::backing_aligned_rect()` does this using the view's
+    /// current scale.
+    pub fn aligned_to_pixels(&self, scale: f64) -> Rect {
+        let left = align_to_pixels(self.left, scale);
+        let top = align_to_pixels(self.top, scale);
+
+        Rect {
+            top,
+            left,
+            width: align_to_pixels(self.left + self.width, scale) - left,
+            height: align_to_pixels(self.top + self.height, scale) - top
+        }
+    }
+}
+
+/// Rounds `points` to the nearest device pixel, for a display with the given backing `scale`
+/// (e.g, `1.0` or `2.0`, or fractional values for scaled display modes). Use this for
+/// constraint offsets and hand-computed frames that should land on pixel boundaries:
+///
+/// ```rust,no_run
+/// use cacao::geometry::align_to_pixels;
+/// use cacao::layout::{Layout, LayoutConstraint};
+/// use cacao::view::View;
+///
+/// let content = View::new();
+/// let child = View::new();
+/// content.add_subview(&child);
+///
+/// let scale = content.backing_scale_factor();
+/// LayoutConstraint::activate(&[
+///     child.top.constraint_equal_to(&content.top).offset(align_to_pixels(100. / 3., scale))
+/// ]);
+/// ```
+///
+/// A scale of zero or less is treated as `1.0`.
+pub fn align_to_pixels(points: f64, scale: f64) -> f64 {
+    let scale = match scale > 0. {
+        true => scale,
+        false => 1.
+    };
+
+    (points * scale).round() / scale
+}
+
+/// Converts `points` to device pixels, for a display with the given backing `scale`.
+pub fn points_to_pixels(points: f64, scale: f64) -> f64 {
+    points * scale
+}
+
+/// Converts device `pixels` to points, for a display with the given backing `scale`.
+pub fn pixels_to_points(pixels: f64, scale: f64) -> f64 {
+    match scale > 0. {
+        true => pixels / scale,
+        false => pixels
+    }
+}
+
+/// How `Layout::backing_aligned_rect()` moves edges that fall between device pixels.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PixelAlignment {
+    /// Moves each edge inward, so the result fits inside the original rect.
+    Inward,
+
+    /// Moves each edge outward, so the result covers the original rect.
+    Outward,
+
+    /// Moves each edge to whichever pixel boundary is closest.
+    Nearest
+}
+
+impl From<PixelAlignment> for u64 {
+    /// Maps to the `NSAlignAllEdges*` options for `backingAlignedRect:options:`.
+    fn from(alignment: PixelAlignment) -> Self {
+        match alignment {
+            PixelAlignment::Inward => (1 << 0) | (1 << 1) | (1 << 2) | (1 << 3),
+            PixelAlignment::Outward => (1 << 8) | (1 << 9) | (1 << 10) | (1 << 11),
+            PixelAlignment::Nearest => (1 << 16) | (1 << 17) | (1 << 18) | (1 << 19)
+        }
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -65,3 +149,34 @@ impl From<CGRect> for Rect {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligns_to_the_nearest_pixel() {
+        assert_eq!(align_to_pixels(10.3, 1.), 10.);
+        assert_eq!(align_to_pixels(10.3, 2.), 10.5);
+        assert_eq!(align_to_pixels(10.2, 2.), 10.);
+        assert_eq!(align_to_pixels(10.3, 0.), 10.);
+    }
+
+    #[test]
+    fn aligns_rect_edges_rather_than_sizes() {
+        // The right edge (20.6) rounds up even though the width alone (10.3) would round down.
+        let rect = Rect::new(0.4, 10.3, 10.3, 5.).aligned_to_pixels(1.);
+
+        assert_eq!(rect.left, 10.);
+        assert_eq!(rect.width, 11.);
+        assert_eq!(rect.top, 0.);
+        assert_eq!(rect.height, 5.);
+    }
+
+    #[test]
+    fn converts_between_points_and_pixels() {
+        assert_eq!(points_to_pixels(12., 2.), 24.);
+        assert_eq!(pixels_to_points(24., 2.), 12.);
+        assert_eq!(pixels_to_points(24., 0.), 24.);
+    }
+}
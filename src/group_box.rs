@@ -0,0 +1,385 @@
+//! Wraps `NSBox`, for visually grouping related controls (e.g, sections of a settings form) and
+//! for drawing separator lines.
+//!
+//! ```rust,no_run
+//! use cacao::group_box::{GroupBox, HorizontalSeparator};
+//! use cacao::layout::Layout;
+//! use cacao::switch::Switch;
+//!
+//! let general = GroupBox::titled("General");
+//! let launch_at_login = Switch::new("Launch at login");
+//! general.add_subview(&launch_at_login);
+//!
+//! let separator = HorizontalSeparator::new();
+//! ```
+//!
+//! Subviews are added to the box's content view, which sits inside the border (and below the
+//! title). With autolayout, pin them to the box's anchors, leaving room for the title.
+
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::{CGRect, CGSize};
+use objc::runtime::{Class, Object};
+use objc::{msg_send, sel};
+
+use crate::color::Color;
+use crate::foundation::{id, load_or_register_class, NSString, NSUInteger, NO};
+use crate::geometry::Rect;
+use crate::layout::Layout;
+#[cfg(feature = "autolayout")]
+use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
+use crate::objc_access::ObjcAccess;
+use crate::utils::properties::ObjcProperty;
+
+/// The overall appearance of a `GroupBox`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BoxStyle {
+    /// The standard, system-drawn group box.
+    Primary,
+
+    /// A single line; see `HorizontalSeparator` and `VerticalSeparator`.
+    Separator,
+
+    /// Drawn using the border and fill colors, border width and corner radius you set.
+    Custom
+}
+
+impl From<BoxStyle> for NSUInteger {
+    fn from(style: BoxStyle) -> Self {
+        match style {
+            BoxStyle::Primary => 0,
+            BoxStyle::Separator => 2,
+            BoxStyle::Custom => 4
+        }
+    }
+}
+
+/// Where a `GroupBox` draws its title, if at all.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TitlePosition {
+    /// No title.
+    None,
+
+    /// Above the top border.
+    AboveTop,
+
+    /// Over the top border.
+    AtTop,
+
+    /// Below the top border.
+    BelowTop,
+
+    /// Above the bottom border.
+    AboveBottom,
+
+    /// Over the bottom border.
+    AtBottom,
+
+    /// Below the bottom border.
+    BelowBottom
+}
+
+impl From<TitlePosition> for NSUInteger {
+    fn from(position: TitlePosition) -> Self {
+        match position {
+            TitlePosition::None => 0,
+            TitlePosition::AboveTop => 1,
+            TitlePosition::AtTop => 2,
+            TitlePosition::BelowTop => 3,
+            TitlePosition::AboveBottom => 4,
+            TitlePosition::AtBottom => 5,
+            TitlePosition::BelowBottom => 6
+        }
+    }
+}
+
+/// A wrapper for `NSBox`. Holds (retains) pointers for the Objective-C runtime
+/// where our `NSBox` lives.
+#[derive(Debug)]
+pub struct GroupBox {
+    /// A pointer to the underlying Objective-C Object.
+    pub objc: ObjcProperty,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime left layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub left: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime right layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub right: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for GroupBox {
+    fn default() -> Self {
+        GroupBox::new()
+    }
+}
+
+impl GroupBox {
+    /// Creates a new, untitled group box.
+    pub fn new() -> Self {
+        let group_box = GroupBox::with_frame(Rect::zero());
+        group_box.set_title_position(TitlePosition::None);
+        group_box
+    }
+
+    /// Creates a new group box with `title` drawn over its top border.
+    pub fn titled(title: &str) -> Self {
+        let group_box = GroupBox::with_frame(Rect::zero());
+        group_box.set_title(title);
+        group_box
+    }
+
+    /// Creates the backing `NSBox`. Separators work out which way they run from their initial
+    /// frame, which is why this takes one.
+    fn with_frame(frame: Rect) -> Self {
+        let frame: CGRect = frame.into();
+
+        let view: id = unsafe {
+            let alloc: id = msg_send![register_class(), alloc];
+            let view: id = msg_send![alloc, initWithFrame: frame];
+
+            #[cfg(feature = "autolayout")]
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+
+            view
+        };
+
+        GroupBox {
+            #[cfg(feature = "autolayout")]
+            top: LayoutAnchorY::top(view),
+
+            #[cfg(feature = "autolayout")]
+            left: LayoutAnchorX::left(view),
+
+            #[cfg(feature = "autolayout")]
+            leading: LayoutAnchorX::leading(view),
+
+            #[cfg(feature = "autolayout")]
+            right: LayoutAnchorX::right(view),
+
+            #[cfg(feature = "autolayout")]
+            trailing: LayoutAnchorX::trailing(view),
+
+            #[cfg(feature = "autolayout")]
+            bottom: LayoutAnchorY::bottom(view),
+
+            #[cfg(feature = "autolayout")]
+            width: LayoutAnchorDimension::width(view),
+
+            #[cfg(feature = "autolayout")]
+            height: LayoutAnchorDimension::height(view),
+
+            #[cfg(feature = "autolayout")]
+            center_x: LayoutAnchorX::center(view),
+
+            #[cfg(feature = "autolayout")]
+            center_y: LayoutAnchorY::center(view),
+
+            objc: ObjcProperty::retain(view)
+        }
+    }
+
+    /// Sets the title, showing it over the top border if it wasn't shown already.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setTitle: &*title];
+
+            let position: NSUInteger = msg_send![obj, titlePosition];
+            if position == TitlePosition::None.into() {
+                let _: () = msg_send![obj, setTitlePosition: NSUInteger::from(TitlePosition::AtTop)];
+            }
+        });
+    }
+
+    /// Sets where the title is drawn. `TitlePosition::None` hides it.
+    pub fn set_title_position(&self, position: TitlePosition) {
+        let position: NSUInteger = position.into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setTitlePosition: position];
+        });
+    }
+
+    /// Sets the style of this box.
+    pub fn set_style(&self, style: BoxStyle) {
+        let style: NSUInteger = style.into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setBoxType: style];
+        });
+    }
+
+    /// Sets the border color. Only used with `BoxStyle::Custom`.
+    pub fn set_border_color<C: AsRef<Color>>(&self, color: C) {
+        let color: id = color.as_ref().into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setBorderColor: color];
+        });
+    }
+
+    /// Sets the fill (background) color. Only used with `BoxStyle::Custom`.
+    pub fn set_fill_color<C: AsRef<Color>>(&self, color: C) {
+        let color: id = color.as_ref().into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setFillColor: color];
+        });
+    }
+
+    /// Sets the border width. Only used with `BoxStyle::Custom`.
+    pub fn set_border_width(&self, width: CGFloat) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setBorderWidth: width];
+        });
+    }
+
+    /// Sets the corner radius. Only used with `BoxStyle::Custom`.
+    pub fn set_corner_radius(&self, radius: CGFloat) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setCornerRadius: radius];
+        });
+    }
+
+    /// Sets the horizontal and vertical distance between the border and the content view.
+    pub fn set_content_margins(&self, horizontal: CGFloat, vertical: CGFloat) {
+        let margins = CGSize::new(horizontal, vertical);
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setContentViewMargins: margins];
+        });
+    }
+}
+
+impl ObjcAccess for GroupBox {
+    fn with_backing_obj_mut<F: Fn(id)>(&self, handler: F) {
+        self.objc.with_mut(handler);
+    }
+
+    fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
+        self.objc.get(handler)
+    }
+}
+
+impl Layout for GroupBox {
+    /// Adds `view` to the box's content view, rather than the box itself.
+    fn add_subview<V: Layout>(&self, view: &V) {
+        self.objc.with_mut(|obj| {
+            view.with_backing_obj_mut(|subview| unsafe {
+                let content_view: id = msg_send![obj, contentView];
+                let _: () = msg_send![content_view, addSubview: subview];
+            });
+        });
+    }
+}
+
+/// A horizontal separator line, built on `NSBox`. With autolayout, pin its leading and trailing
+/// edges; it takes care of its own height.
+#[derive(Debug)]
+pub struct HorizontalSeparator(pub GroupBox);
+
+impl Default for HorizontalSeparator {
+    fn default() -> Self {
+        HorizontalSeparator::new()
+    }
+}
+
+impl HorizontalSeparator {
+    /// Creates a new horizontal separator.
+    pub fn new() -> Self {
+        let separator = GroupBox::with_frame(Rect::new(0., 0., 10., 1.));
+        separator.set_style(BoxStyle::Separator);
+
+        #[cfg(feature = "autolayout")]
+        separator.height.constraint_equal_to_constant(1.).set_active(true);
+
+        HorizontalSeparator(separator)
+    }
+}
+
+/// A vertical separator line, built on `NSBox`. With autolayout, pin its top and bottom edges; it
+/// takes care of its own width.
+#[derive(Debug)]
+pub struct VerticalSeparator(pub GroupBox);
+
+impl Default for VerticalSeparator {
+    fn default() -> Self {
+        VerticalSeparator::new()
+    }
+}
+
+impl VerticalSeparator {
+    /// Creates a new vertical separator.
+    pub fn new() -> Self {
+        let separator = GroupBox::with_frame(Rect::new(0., 0., 1., 10.));
+        separator.set_style(BoxStyle::Separator);
+
+        #[cfg(feature = "autolayout")]
+        separator.width.constraint_equal_to_constant(1.).set_active(true);
+
+        VerticalSeparator(separator)
+    }
+}
+
+impl ObjcAccess for HorizontalSeparator {
+    fn with_backing_obj_mut<F: Fn(id)>(&self, handler: F) {
+        self.0.with_backing_obj_mut(handler);
+    }
+
+    fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
+        self.0.get_from_backing_obj(handler)
+    }
+}
+
+impl Layout for HorizontalSeparator {}
+
+impl ObjcAccess for VerticalSeparator {
+    fn with_backing_obj_mut<F: Fn(id)>(&self, handler: F) {
+        self.0.with_backing_obj_mut(handler);
+    }
+
+    fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
+        self.0.get_from_backing_obj(handler)
+    }
+}
+
+impl Layout for VerticalSeparator {}
+
+/// Registers an `NSBox` subclass.
+fn register_class() -> &'static Class {
+    load_or_register_class("NSBox", "RSTGroupBox", |decl| unsafe {})
+}
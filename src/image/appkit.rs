@@ -6,16 +6,164 @@
 //! It just enforces that coordinates are judged from the top-left, which is what most people look
 //! for in the modern era. It also implements a few helpers for things like setting a background
 //! color, and enforcing layer backing by default.
+//!
+//! For editable image views (image wells), it also holds the change handler, and remembers the
+//! file an image was dropped from until the handler has been called.
+
+use std::fmt;
+
+use objc::rc::Id;
+use objc::runtime::{Bool, Class, Object, Sel};
+use objc::{class, msg_send, sel};
+
+use crate::dragdrop::DragInfo;
+use crate::foundation::{id, load_or_register_class, nil, StaticClass, NSURL};
+use crate::image::Image;
+
+pub(crate) static IMAGE_WELL_HANDLER_PTR: &str = "rstImageWellHandlerPtr";
+static IMAGE_WELL_DROPPED_URL: &str = "rstImageWellDroppedURL";
+
+/// An indirection layer for the change handler, for the same reasons as `invoker::Action`.
+pub(crate) struct ImageWellHandler(pub Box<dyn Fn(Option<Image>, Option<NSURL<'static>>) + 'static>);
+
+impl fmt::Debug for ImageWellHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImageWellHandler").finish()
+    }
+}
+
+/// Swaps in a new change handler (or none), dropping the old one once it's no longer reachable
+/// from the view.
+pub(crate) unsafe fn replace_handler(view: &mut Object, handler: Option<ImageWellHandler>) {
+    let ptr = match handler {
+        Some(handler) => Box::into_raw(Box::new(handler)) as usize,
+        None => 0
+    };
+
+    let old: usize = *view.get_ivar(IMAGE_WELL_HANDLER_PTR);
+    view.set_ivar(IMAGE_WELL_HANDLER_PTR, ptr);
+
+    if old != 0 {
+        let _old = Box::from_raw(old as *mut ImageWellHandler);
+    }
+}
+
+/// Replaces the stored URL of a dropped file, releasing the old one.
+unsafe fn set_dropped_url(view: &mut Object, url: id) {
+    let old: id = *view.get_ivar(IMAGE_WELL_DROPPED_URL);
+    if old != nil {
+        let _: () = msg_send![old, release];
+    }
+
+    if url != nil {
+        let _: id = msg_send![url, retain];
+    }
+
+    view.set_ivar(IMAGE_WELL_DROPPED_URL, url);
+}
+
+/// Notes the file being dropped (if it is one) before `NSImageView` loads the image from it; its
+/// action fires as part of that, and picks the URL up.
+extern "C" fn perform_drag_operation(this: &mut Object, _: Sel, info: id) -> Bool {
+    let info = DragInfo {
+        info: unsafe { Id::retain(info).unwrap() }
+    };
+
+    let url = info
+        .get_pasteboard()
+        .get_file_urls()
+        .ok()
+        .and_then(|urls| urls.into_iter().find(|url| url.is_file_url()));
 
-use objc::runtime::Class;
+    unsafe {
+        set_dropped_url(this, match &url {
+            Some(url) => &*url.objc as *const Object as id,
+            None => nil
+        });
 
-use crate::foundation::load_or_register_class;
+        let performed: Bool = msg_send![super(this, class!(NSImageView)), performDragOperation: &*info.info];
+
+        // If the drop didn't take, the action won't fire to clear this.
+        if !performed.as_bool() {
+            set_dropped_url(this, nil);
+        }
+
+        performed
+    }
+}
+
+/// The action `NSImageView` sends when the user drops, pastes, cuts or deletes an image.
+extern "C" fn image_did_change(this: &mut Object, _: Sel, _sender: id) {
+    let (image, url) = unsafe {
+        let image: id = msg_send![this, image];
+        let url: id = *this.get_ivar(IMAGE_WELL_DROPPED_URL);
+
+        let image = match image == nil {
+            true => None,
+            false => Some(Image::with(image))
+        };
+
+        let url = match url == nil {
+            true => None,
+            false => Some(NSURL::retain(url))
+        };
+
+        set_dropped_url(this, nil);
+        (image, url)
+    };
+
+    // The handler is taken out of the view while it runs, so that setting a new one from inside
+    // it can't free it mid-call. It's put back afterwards, unless it was replaced.
+    let ptr: usize = unsafe { *this.get_ivar(IMAGE_WELL_HANDLER_PTR) };
+    if ptr == 0 {
+        return;
+    }
+
+    let handler = unsafe {
+        this.set_ivar(IMAGE_WELL_HANDLER_PTR, 0usize);
+        Box::from_raw(ptr as *mut ImageWellHandler)
+    };
+
+    (handler.0)(image, url);
+
+    let current: usize = unsafe { *this.get_ivar(IMAGE_WELL_HANDLER_PTR) };
+    if current == 0 {
+        unsafe {
+            this.set_ivar(IMAGE_WELL_HANDLER_PTR, Box::into_raw(handler) as usize);
+        }
+    }
+}
+
+/// Drops the change handler and any pending URL, before the view is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        replace_handler(this, None);
+        set_dropped_url(this, nil);
+
+        let _: () = msg_send![super(this, class!(NSImageView)), dealloc];
+    }
+}
 
 /// Injects an `NSView` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
 /// used if there's no delegates.
 pub(crate) fn register_image_view_class() -> &'static Class {
-    load_or_register_class("NSImageView", "RSTImageView", |decl| unsafe {
-        //decl.add_method(sel!(isFlipped), enforce_normalcy as extern "C" fn(_, _) -> _);
+    static CLASS: StaticClass = StaticClass::new();
+
+    CLASS.get_or_register(|| {
+        load_or_register_class("NSImageView", "RSTImageView", |decl| unsafe {
+            //decl.add_method(sel!(isFlipped), enforce_normalcy as extern "C" fn(_, _) -> _);
+
+            decl.add_ivar::<usize>(IMAGE_WELL_HANDLER_PTR);
+            decl.add_ivar::<id>(IMAGE_WELL_DROPPED_URL);
+            decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
+
+            // Editing (i.e, image wells)
+            decl.add_method(
+                sel!(performDragOperation:),
+                perform_drag_operation as extern "C" fn(_, _, _) -> _
+            );
+            decl.add_method(sel!(imageDidChange:), image_did_change as extern "C" fn(_, _, _));
+        })
     })
 }
@@ -12,8 +12,8 @@ use core_graphics::{
 };
 
 use super::icons::*;
-use crate::foundation::{id, NSData, NSString, NSURL};
-use crate::utils::os;
+use crate::available;
+use crate::foundation::{id, NSData, NSString, NSUInteger, NSURL};
 
 /// Specifies resizing behavior for image drawing.
 #[derive(Copy, Clone, Debug)]
@@ -123,9 +123,9 @@ pub struct Image(pub Id<Object, Shared>);
 
 impl Image {
     fn class() -> &'static Class {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let class = class!(NSImage);
-        #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+        #[cfg(uikit)]
         let class = class!(UIImage);
 
         class
@@ -190,7 +190,7 @@ impl Image {
     #[cfg(target_os = "macos")]
     pub fn toolbar_icon(icon: MacSystemIcon, accessibility_description: &str) -> Self {
         Image(unsafe {
-            match os::is_minimum_version(11) {
+            match available!(macos 11) {
                 true => {
                     let icon = NSString::new(icon.to_sfsymbol_str());
                     let desc = NSString::new(accessibility_description);
@@ -218,16 +218,9 @@ impl Image {
     /// ever exposes a compatible API, this can be tweaked in a PR.
     #[cfg(any(target_os = "macos", target_os = "ios"))]
     pub fn symbol(symbol: SFSymbol, accessibility_description: &str) -> Self {
-        // SFSymbols is macOS 11.0+
-        #[cfg(feature = "appkit")]
-        let min_version = 11;
-
-        // SFSymbols is iOS 13.0+.
-        #[cfg(all(feature = "uikit", not(feature = "appkit")))]
-        let min_version = 13;
-
         Image(unsafe {
-            match os::is_minimum_version(min_version) {
+            // SFSymbols is macOS 11.0+, iOS 13.0+.
+            match available!(macos 11, ios 13) {
                 true => {
                     let icon = NSString::new(symbol.to_str());
                     let desc = NSString::new(accessibility_description);
@@ -239,11 +232,11 @@ impl Image {
                 },
 
                 false => {
-                    #[cfg(feature = "appkit")]
+                    #[cfg(appkit)]
                     panic!("SFSymbols are only supported on macOS 11.0 and up.");
 
-                    #[cfg(all(feature = "uikit", not(feature = "appkit")))]
-                    panic!("SFSymbols are only supported on macOS 11.0 and up.");
+                    #[cfg(uikit)]
+                    panic!("SFSymbols are only supported on iOS 13.0 and up.");
                 }
             }
         })
@@ -253,7 +246,7 @@ impl Image {
     ///
     /// This is currently only supported on AppKit-based backends, and has
     /// only been tested on macOS.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn draw<F>(config: DrawConfig, handler: F) -> Self
     where
         F: Fn(CGRect, &CGContextRef) -> bool + 'static
@@ -293,6 +286,54 @@ impl Image {
             ]
         })
     }
+
+    /// Marks this image as a template image: only its alpha channel is used, and AppKit tints
+    /// it to fit where it's shown - dark mode, toolbar and menu highlight states, and so on.
+    /// SF Symbols are template images already.
+    ///
+    /// The underlying `NSImage` is copied first, so that anything else sharing it (clones of this
+    /// `Image`, or AppKit's cache of named images) is left as it was.
+    #[cfg(appkit)]
+    pub fn set_template(&mut self, template: bool) {
+        unsafe {
+            self.0 = msg_send_id![&*self.0, copy];
+            let _: () = msg_send![&*self.0, setTemplate: Bool::new(template)];
+        }
+    }
+
+    /// Returns whether this is a template image.
+    #[cfg(appkit)]
+    pub fn is_template(&self) -> bool {
+        let template: Bool = unsafe { msg_send![&*self.0, isTemplate] };
+        template.as_bool()
+    }
+
+    /// Encodes this image as PNG data, e.g for writing a snapshot out to disk. Returns `None` if
+    /// the image couldn't be encoded.
+    #[cfg(appkit)]
+    pub fn png_data(&self) -> Option<Vec<u8>> {
+        unsafe {
+            let tiff: id = msg_send![&*self.0, TIFFRepresentation];
+            if tiff.is_null() {
+                return None;
+            }
+
+            let rep: id = msg_send![class!(NSBitmapImageRep), imageRepWithData: tiff];
+            if rep.is_null() {
+                return None;
+            }
+
+            // NSBitmapImageFileTypePNG
+            let file_type: NSUInteger = 4;
+            let properties: id = msg_send![class!(NSDictionary), dictionary];
+            let data: id = msg_send![rep, representationUsingType: file_type, properties: properties];
+            if data.is_null() {
+                return None;
+            }
+
+            Some(NSData::retain(data).into_vec())
+        }
+    }
 }
 
 #[test]
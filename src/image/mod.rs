@@ -1,3 +1,6 @@
+#[cfg(appkit)]
+use std::cell::RefCell;
+
 use core_foundation::base::TCFType;
 
 use objc::rc::{Id, Shared};
@@ -6,6 +9,9 @@ use objc::{msg_send, msg_send_id, sel};
 
 use crate::color::Color;
 use crate::foundation::{id, nil, NSArray, NSString, NO, YES};
+
+#[cfg(appkit)]
+use crate::foundation::{to_bool, NSURL};
 use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
 use crate::utils::properties::ObjcProperty;
@@ -14,17 +20,17 @@ use crate::layer::Layer;
 #[cfg(feature = "autolayout")]
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 mod appkit;
 
-#[cfg(feature = "appkit")]
-use appkit::register_image_view_class;
+#[cfg(appkit)]
+pub(crate) use appkit::register_image_view_class;
 
-#[cfg(feature = "uikit")]
+#[cfg(uikit)]
 mod uikit;
 
-#[cfg(all(feature = "uikit", not(feature = "appkit")))]
-use uikit::register_image_view_class;
+#[cfg(uikit)]
+pub(crate) use uikit::register_image_view_class;
 
 mod image;
 pub use image::{DrawConfig, Image, ResizeBehavior};
@@ -40,7 +46,7 @@ fn allocate_view(registration_fn: fn() -> &'static Class) -> id {
         #[cfg(feature = "autolayout")]
         let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let _: () = msg_send![view, setWantsLayer: YES];
 
         view
@@ -165,6 +171,73 @@ impl ImageView {
         });
     }
 
+    /// Returns the image currently shown, if there is one.
+    pub fn image(&self) -> Option<Image> {
+        let image: id = self.objc.get(|obj| unsafe { msg_send![obj, image] });
+
+        match image == nil {
+            true => None,
+            false => Some(Image::with(image))
+        }
+    }
+
+    /// Removes the image from the image view. This doesn't call the `on_change()` handler.
+    pub fn clear(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setImage: nil];
+        });
+    }
+
+    /// Makes this an image well: the user can drag an image (or image file) onto it, paste one
+    /// in, or cut or delete the current one. Use `on_change()` to hear about it.
+    #[cfg(appkit)]
+    pub fn set_editable(&self, editable: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setEditable: match editable {
+                true => YES,
+                false => NO
+            }];
+
+            let _: () = msg_send![obj, setTarget: obj];
+            let _: () = msg_send![obj, setAction: sel!(imageDidChange:)];
+        });
+    }
+
+    /// Returns whether the user can change the image.
+    #[cfg(appkit)]
+    pub fn is_editable(&self) -> bool {
+        self.objc.get(|obj| to_bool(unsafe { msg_send![obj, isEditable] }))
+    }
+
+    /// Calls `handler` whenever the user changes the image in an editable image view (see
+    /// `set_editable()`). It's passed the new image - or `None` if they cut or deleted it - and,
+    /// if it was dropped in from a file, that file's URL.
+    ///
+    /// ```rust,no_run
+    /// use cacao::image::ImageView;
+    ///
+    /// let avatar = ImageView::new();
+    /// avatar.set_editable(true);
+    /// avatar.on_change(|image, url| {
+    ///     match (image, url) {
+    ///         (Some(_), Some(url)) => println!("Picked {:?}", url.pathbuf()),
+    ///         (Some(_), None) => println!("Pasted an image"),
+    ///         (None, _) => println!("Removed the image")
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// The handler lives as long as the underlying `NSImageView`, and setting a new one replaces
+    /// it.
+    #[cfg(appkit)]
+    pub fn on_change<F: Fn(Option<Image>, Option<NSURL<'static>>) + 'static>(&self, handler: F) {
+        let handler = RefCell::new(Some(appkit::ImageWellHandler(Box::new(handler))));
+
+        self.objc.with_mut(|obj| unsafe {
+            appkit::replace_handler(&mut *obj, handler.borrow_mut().take());
+        });
+    }
+
     /*pub fn set_image_scaling(&self, scaling_type: ImageScale) {
         self.objc.with_mut(|obj| unsafe {
 
@@ -206,6 +279,8 @@ impl Drop for ImageView {
 
 #[test]
 fn test_image() {
+    crate::testing::initialize();
+
     let image_view = ImageView::new();
     image_view.set_background_color(Color::SystemBlue);
     let image_bytes = include_bytes!("../../test-data/favicon.ico");
@@ -1,48 +1,101 @@
 use objc::rc::{Id, Owned};
 use objc::runtime::{Bool, Class, Object, Sel};
-use objc::{msg_send, sel};
+use objc::{class, msg_send, sel};
 
-use crate::foundation::{id, load_or_register_class, NSString};
+use crate::foundation::{id, load_or_register_class, nil, to_bool, NSString, StaticClass};
 use crate::input::{TextFieldDelegate, TEXTFIELD_DELEGATE_PTR};
-use crate::utils::load;
+use crate::utils::{load_weak, release_weak};
 
 /// Called when editing this text field has ended (e.g. user pressed enter).
 extern "C" fn text_did_end_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _info: id) {
-    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
     let s = NSString::retain(unsafe { msg_send![this, stringValue] });
     view.text_did_end_editing(s.to_str());
 }
 
 extern "C" fn text_did_begin_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _info: id) {
-    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
     let s = NSString::retain(unsafe { msg_send![this, stringValue] });
     view.text_did_begin_editing(s.to_str());
 }
 
+/// Called when the text changes. If an input method is mid-composition, the text contains marked
+/// (uncommitted) text, and we route it to `marked_text_did_change` instead.
 extern "C" fn text_did_change<T: TextFieldDelegate>(this: &Object, _: Sel, _info: id) {
-    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
     let s = NSString::retain(unsafe { msg_send![this, stringValue] });
-    view.text_did_change(s.to_str());
+
+    match has_marked_text(this) {
+        true => view.marked_text_did_change(s.to_str()),
+        false => view.text_did_change(s.to_str())
+    }
 }
 
 extern "C" fn text_should_begin_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _info: id) -> Bool {
-    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return Bool::YES
+    };
     let s = NSString::retain(unsafe { msg_send![this, stringValue] });
 
     Bool::new(view.text_should_begin_editing(s.to_str()))
 }
 
 extern "C" fn text_should_end_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _info: id) -> Bool {
-    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return Bool::YES
+    };
     let s = NSString::retain(unsafe { msg_send![this, stringValue] });
     Bool::new(view.text_should_end_editing(s.to_str()))
 }
 
+/// Called by the field editor for editing commands (arrow keys, Return, Escape, and so on). If the
+/// delegate doesn't handle the command, we hand it back to `NSTextField`.
+extern "C" fn do_command_by_selector<T: TextFieldDelegate>(this: &Object, _: Sel, text_view: id, command: Sel) -> Bool {
+    if let Some(view) = load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        if view.do_command_by_selector(command.name()) {
+            return Bool::YES;
+        }
+    }
+
+    unsafe { msg_send![super(this, class!(NSTextField)), textView: text_view, doCommandBySelector: command] }
+}
+
+/// Checks whether the field editor for this text field currently has marked text, i.e an input
+/// method is mid-composition.
+pub(crate) fn has_marked_text(this: &Object) -> bool {
+    unsafe {
+        let editor: id = msg_send![this, currentEditor];
+        editor != nil && to_bool(msg_send![editor, hasMarkedText])
+    }
+}
+
 /// Injects an `NSTextField` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
 /// used if there's no delegates.
 pub(crate) fn register_view_class() -> &'static Class {
-    load_or_register_class("NSTextField", "RSTTextInputField", |decl| unsafe {})
+    static CLASS: StaticClass = StaticClass::new();
+
+    CLASS.get_or_register(|| load_or_register_class("NSTextField", "RSTTextInputField", |decl| unsafe {}))
+}
+
+/// Releases the weak reference to the delegate, before the text field is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    release_weak(this, TEXTFIELD_DELEGATE_PTR);
+
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSTextField)), dealloc];
+    }
 }
 
 /// Injects an `NSTextField` subclass, with some callback and pointer ivars for what we
@@ -52,6 +105,7 @@ pub(crate) fn register_view_class_with_delegate<T: TextFieldDelegate>(instance:
         // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
         // move.
         decl.add_ivar::<usize>(TEXTFIELD_DELEGATE_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
 
         decl.add_method(sel!(textDidEndEditing:), text_did_end_editing::<T> as extern "C" fn(_, _, _));
         decl.add_method(
@@ -67,5 +121,9 @@ pub(crate) fn register_view_class_with_delegate<T: TextFieldDelegate>(instance:
             sel!(textShouldEndEditing:),
             text_should_end_editing::<T> as extern "C" fn(_, _, _) -> _
         );
+        decl.add_method(
+            sel!(textView:doCommandBySelector:),
+            do_command_by_selector::<T> as extern "C" fn(_, _, _, _) -> _
+        );
     })
 }
@@ -0,0 +1,148 @@
+//! Keyboard configuration for text fields on UIKit.
+
+use crate::foundation::NSInteger;
+
+/// The kind of on-screen keyboard shown while editing a `TextField`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeyboardType {
+    /// The standard keyboard for the current input method.
+    Default,
+
+    /// A keyboard that only offers ASCII characters.
+    AsciiCapable,
+
+    /// Numbers and punctuation.
+    NumbersAndPunctuation,
+
+    /// Optimized for entering URLs (".", "/" and ".com" up front).
+    Url,
+
+    /// A numeric keypad, for PIN entry and the like.
+    NumberPad,
+
+    /// A phone keypad (digits, "*" and "#").
+    PhonePad,
+
+    /// A keyboard for entering a person's name or phone number.
+    NamePhonePad,
+
+    /// Optimized for entering email addresses ("@" and "." up front).
+    EmailAddress,
+
+    /// Numbers and a decimal point.
+    DecimalPad,
+
+    /// Optimized for social posts ("@" and "#" up front).
+    Twitter,
+
+    /// Optimized for search terms and URLs.
+    WebSearch,
+
+    /// A number pad that only outputs ASCII digits.
+    AsciiCapableNumberPad
+}
+
+impl From<KeyboardType> for NSInteger {
+    fn from(keyboard_type: KeyboardType) -> Self {
+        match keyboard_type {
+            KeyboardType::Default => 0,
+            KeyboardType::AsciiCapable => 1,
+            KeyboardType::NumbersAndPunctuation => 2,
+            KeyboardType::Url => 3,
+            KeyboardType::NumberPad => 4,
+            KeyboardType::PhonePad => 5,
+            KeyboardType::NamePhonePad => 6,
+            KeyboardType::EmailAddress => 7,
+            KeyboardType::DecimalPad => 8,
+            KeyboardType::Twitter => 9,
+            KeyboardType::WebSearch => 10,
+            KeyboardType::AsciiCapableNumberPad => 11
+        }
+    }
+}
+
+/// The title shown on the Return key of the keyboard.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReturnKeyType {
+    /// "return"
+    Default,
+
+    /// "Go"
+    Go,
+
+    /// "Google"
+    Google,
+
+    /// "Join"
+    Join,
+
+    /// "Next" - for moving on to the next field in a form.
+    Next,
+
+    /// "Route"
+    Route,
+
+    /// "Search"
+    Search,
+
+    /// "Send"
+    Send,
+
+    /// "Yahoo"
+    Yahoo,
+
+    /// "Done" - for the last field in a form.
+    Done,
+
+    /// "Emergency Call"
+    EmergencyCall,
+
+    /// "Continue"
+    Continue
+}
+
+impl From<ReturnKeyType> for NSInteger {
+    fn from(return_key_type: ReturnKeyType) -> Self {
+        match return_key_type {
+            ReturnKeyType::Default => 0,
+            ReturnKeyType::Go => 1,
+            ReturnKeyType::Google => 2,
+            ReturnKeyType::Join => 3,
+            ReturnKeyType::Next => 4,
+            ReturnKeyType::Route => 5,
+            ReturnKeyType::Search => 6,
+            ReturnKeyType::Send => 7,
+            ReturnKeyType::Yahoo => 8,
+            ReturnKeyType::Done => 9,
+            ReturnKeyType::EmergencyCall => 10,
+            ReturnKeyType::Continue => 11
+        }
+    }
+}
+
+/// When the keyboard should switch to capital letters on its own.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Autocapitalization {
+    /// Never; e.g, for usernames and codes.
+    None,
+
+    /// At the start of each word; e.g, for names and titles.
+    Words,
+
+    /// At the start of each sentence (the default).
+    Sentences,
+
+    /// Always.
+    AllCharacters
+}
+
+impl From<Autocapitalization> for NSInteger {
+    fn from(autocapitalization: Autocapitalization) -> Self {
+        match autocapitalization {
+            Autocapitalization::None => 0,
+            Autocapitalization::Words => 1,
+            Autocapitalization::Sentences => 2,
+            Autocapitalization::AllCharacters => 3
+        }
+    }
+}
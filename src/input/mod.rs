@@ -49,6 +49,7 @@ use objc::rc::{Id, Shared};
 use objc::runtime::{Class, Object};
 use objc::{class, msg_send, sel};
 
+use crate::binding::{Binding, NotificationObserver, Property};
 use crate::color::Color;
 use crate::control::Control;
 use crate::foundation::{id, nil, NSArray, NSInteger, NSString, NO, YES};
@@ -56,25 +57,44 @@ use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
 use crate::text::{Font, TextAlign};
 use crate::utils::properties::ObjcProperty;
+use crate::utils::DelegateRef;
 
 #[cfg(feature = "autolayout")]
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 
-#[cfg(feature = "appkit")]
+#[cfg(uikit)]
+use crate::foundation::BOOL;
+
+#[cfg(uikit)]
+use objc::runtime::Sel;
+
+#[cfg(appkit)]
 mod appkit;
 
-#[cfg(feature = "appkit")]
-use appkit::{register_view_class, register_view_class_with_delegate};
+#[cfg(appkit)]
+use appkit::{has_marked_text, register_view_class_with_delegate};
+
+#[cfg(appkit)]
+pub(crate) use appkit::register_view_class;
 
-#[cfg(feature = "uikit")]
+#[cfg(uikit)]
 mod uikit;
 
-#[cfg(all(feature = "uikit", not(feature = "appkit")))]
-use uikit::{register_view_class, register_view_class_with_delegate};
+#[cfg(uikit)]
+use uikit::{has_marked_text, register_view_class_with_delegate};
+
+#[cfg(uikit)]
+pub(crate) use uikit::register_view_class;
 
 mod traits;
 pub use traits::TextFieldDelegate;
 
+#[cfg(uikit)]
+mod enums;
+
+#[cfg(uikit)]
+pub use enums::{Autocapitalization, KeyboardType, ReturnKeyType};
+
 pub(crate) static TEXTFIELD_DELEGATE_PTR: &str = "rstTextFieldDelegatePtr";
 
 /// A helper method for instantiating view classes and applying default settings to them.
@@ -85,13 +105,28 @@ fn common_init(class: &Class) -> id {
         #[cfg(feature = "autolayout")]
         let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let _: () = msg_send![view, setWantsLayer: YES];
 
         view
     }
 }
 
+/// Returns the current text of a text field, which may be `nil` on uikit.
+fn string_value(obj: &Object) -> String {
+    unsafe {
+        #[cfg(appkit)]
+        let value: id = msg_send![obj, stringValue];
+        #[cfg(uikit)]
+        let value: id = msg_send![obj, text];
+
+        match value.is_null() {
+            true => String::new(),
+            false => NSString::retain(value).to_string()
+        }
+    }
+}
+
 /// A clone-able handler to an `NSTextField/UITextField` reference in the
 /// Objective-C runtime.
 #[derive(Debug)]
@@ -99,6 +134,9 @@ pub struct TextField<T = ()> {
     /// A pointer to the Objective-C runtime view controller.
     pub objc: ObjcProperty,
 
+    /// Keeps the delegate reachable from the Objective-C side; callbacks stop once this drops.
+    pub(crate) delegate_ref: Option<DelegateRef>,
+
     /// A pointer to the delegate for this view.
     pub delegate: Option<Box<T>>,
 
@@ -157,6 +195,7 @@ impl TextField {
 
         TextField {
             delegate: None,
+            delegate_ref: None,
             objc: ObjcProperty::retain(view),
 
             #[cfg(feature = "autolayout")]
@@ -203,15 +242,14 @@ where
         let mut delegate = Box::new(delegate);
 
         let input = common_init(class);
-        unsafe {
-            let ptr: *const T = &*delegate;
-            (&mut *input).set_ivar(TEXTFIELD_DELEGATE_PTR, ptr as usize);
-        };
-        #[cfg(feature = "uikit")]
+        let delegate_ref = DelegateRef::attach(unsafe { &mut *input }, TEXTFIELD_DELEGATE_PTR, &*delegate);
+
+        #[cfg(uikit)]
         let _: () = unsafe { msg_send![input, setDelegate: input] };
 
         let mut input = TextField {
             delegate: None,
+            delegate_ref: None,
             objc: ObjcProperty::retain(input),
 
             #[cfg(feature = "autolayout")]
@@ -247,6 +285,7 @@ where
 
         (&mut delegate).did_load(input.clone_as_handle());
         input.delegate = Some(delegate);
+        input.delegate_ref = Some(delegate_ref);
         input
     }
 }
@@ -259,6 +298,7 @@ impl<T> TextField<T> {
     pub(crate) fn clone_as_handle(&self) -> TextField {
         TextField {
             delegate: None,
+            delegate_ref: None,
             objc: self.objc.clone(),
 
             #[cfg(feature = "autolayout")]
@@ -294,17 +334,76 @@ impl<T> TextField<T> {
     }
 
     /// Grabs the value from the textfield and returns it as an owned String.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn get_value(&self) -> String {
         self.objc
             .get(|obj| unsafe { NSString::retain(msg_send![obj, stringValue]).to_string() })
     }
-    #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+    #[cfg(uikit)]
     pub fn get_value(&self) -> String {
         self.objc
             .get(|obj| unsafe { NSString::retain(msg_send![obj, text]).to_string() })
     }
 
+    /// Binds the value of this text field to `property`, in both directions: the field updates
+    /// when the property changes, and the property updates as the user types. The binding is
+    /// removed when the returned `Binding` is dropped.
+    pub fn bind_value(&self, property: &Property<String>) -> Binding {
+        let objc = self.objc.clone();
+
+        let subscription = property.observe_and_apply(move |text| {
+            // When the change came from the user typing in this field, the field already has the
+            // value; setting it again would reset the caret and selection.
+            if objc.get(string_value) == *text {
+                return;
+            }
+
+            let s = NSString::new(text);
+
+            objc.with_mut(|obj| unsafe {
+                #[cfg(appkit)]
+                let _: () = msg_send![obj, setStringValue:&*s];
+                #[cfg(uikit)]
+                let _: () = msg_send![obj, setText:&*s];
+            });
+        });
+
+        #[cfg(appkit)]
+        let notification = "NSControlTextDidChangeNotification";
+        #[cfg(uikit)]
+        let notification = "UITextFieldTextDidChangeNotification";
+
+        let objc = self.objc.clone();
+        let property = property.clone();
+
+        let observer = self.objc.get(|obj| {
+            NotificationObserver::new(notification, obj, move || {
+                property.set(objc.get(string_value));
+            })
+        });
+
+        Binding::with_notification(subscription, observer)
+    }
+
+    /// Moves keyboard focus to this field (selecting its text, on appkit), so the user can type
+    /// into it right away - e.g, focusing a URL bar on Command-L. Does nothing if the field isn't
+    /// in a window yet.
+    pub fn focus(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            #[cfg(appkit)]
+            {
+                let window: id = msg_send![obj, window];
+
+                if window != nil {
+                    let _: () = msg_send![window, makeFirstResponder: obj];
+                }
+            }
+
+            #[cfg(uikit)]
+            let _: BOOL = msg_send![obj, becomeFirstResponder];
+        });
+    }
+
     /// Call this to set the background color for the backing layer.
     pub fn set_background_color<C: AsRef<Color>>(&self, color: C) {
         self.objc.with_mut(|obj| unsafe {
@@ -319,9 +418,9 @@ impl<T> TextField<T> {
         let s = NSString::new(text);
 
         self.objc.with_mut(|obj| unsafe {
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let _: () = msg_send![obj, setStringValue:&*s];
-            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            #[cfg(uikit)]
             let _: () = msg_send![obj, setText:&*s];
         });
     }
@@ -331,9 +430,9 @@ impl<T> TextField<T> {
         let s = NSString::new(text);
 
         self.objc.with_mut(|obj| unsafe {
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let _: () = msg_send![obj, setPlaceholderString:&*s];
-            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            #[cfg(uikit)]
             let _: () = msg_send![obj, setPlaceholder:&*s];
         });
     }
@@ -342,9 +441,9 @@ impl<T> TextField<T> {
     pub fn set_text_alignment(&self, alignment: TextAlign) {
         self.objc.with_mut(|obj| unsafe {
             let alignment: NSInteger = alignment.into();
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let _: () = msg_send![obj, setAlignment: alignment];
-            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            #[cfg(uikit)]
             let _: () = msg_send![obj, setTextAlignment: alignment];
         });
     }
@@ -378,6 +477,30 @@ impl<T> TextField<T> {
         });
     }
 
+    /// Returns whether the user is currently composing text with an input method (e.g, for CJK
+    /// input), meaning the value contains marked, uncommitted text.
+    pub fn has_marked_text(&self) -> bool {
+        self.objc.get(|obj| has_marked_text(obj))
+    }
+
+    /// Commits any in-progress input method composition, accepting the marked text as-is. This is
+    /// useful before reading the value in response to something other than the user finishing
+    /// editing (e.g, a toolbar button).
+    pub fn unmark_text(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            #[cfg(appkit)]
+            {
+                let editor: id = msg_send![obj, currentEditor];
+                if editor != nil {
+                    let _: () = msg_send![editor, unmarkText];
+                }
+            }
+
+            #[cfg(uikit)]
+            let _: () = msg_send![obj, unmarkText];
+        });
+    }
+
     /// Sets the font for this input.
     pub fn set_font<F: AsRef<Font>>(&self, font: F) {
         let font = font.as_ref().clone();
@@ -386,6 +509,108 @@ impl<T> TextField<T> {
             let _: () = msg_send![obj, setFont:&*font];
         });
     }
+
+    /// Sets the kind of keyboard shown while editing - e.g, `KeyboardType::EmailAddress`.
+    #[cfg(uikit)]
+    pub fn set_keyboard_type(&self, keyboard_type: KeyboardType) {
+        self.objc.with_mut(|obj| unsafe {
+            let keyboard_type: NSInteger = keyboard_type.into();
+            let _: () = msg_send![obj, setKeyboardType: keyboard_type];
+        });
+    }
+
+    /// Sets the title of the keyboard's Return key - e.g, `ReturnKeyType::Next` for all but the
+    /// last field in a form.
+    #[cfg(uikit)]
+    pub fn set_return_key_type(&self, return_key_type: ReturnKeyType) {
+        self.objc.with_mut(|obj| unsafe {
+            let return_key_type: NSInteger = return_key_type.into();
+            let _: () = msg_send![obj, setReturnKeyType: return_key_type];
+        });
+    }
+
+    /// Sets when the keyboard switches to capital letters on its own.
+    #[cfg(uikit)]
+    pub fn set_autocapitalization(&self, autocapitalization: Autocapitalization) {
+        self.objc.with_mut(|obj| unsafe {
+            let autocapitalization: NSInteger = autocapitalization.into();
+            let _: () = msg_send![obj, setAutocapitalizationType: autocapitalization];
+        });
+    }
+
+    /// Sets whether the keyboard offers autocorrection. Turn this off for usernames, codes and
+    /// the like.
+    #[cfg(uikit)]
+    pub fn set_autocorrects(&self, autocorrects: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            // UITextAutocorrectionType: .no is 1, .yes is 2.
+            let autocorrection: NSInteger = match autocorrects {
+                true => 2,
+                false => 1
+            };
+
+            let _: () = msg_send![obj, setAutocorrectionType: autocorrection];
+        });
+    }
+
+    /// Sets whether this is a secure (password) field: the text is masked, and can't be copied.
+    #[cfg(uikit)]
+    pub fn set_secure_text_entry(&self, secure: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setSecureTextEntry: match secure {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
+    /// Sets a view to show above the keyboard while this field is being edited - typically a
+    /// toolbar. The view is sized by its frame, rather than by Autolayout.
+    #[cfg(uikit)]
+    pub fn set_input_accessory_view<V: Layout>(&self, view: &V) {
+        view.with_backing_obj_mut(|accessory| {
+            self.objc.with_mut(|obj| unsafe {
+                let _: () = msg_send![obj, setInputAccessoryView: accessory];
+                let _: () = msg_send![obj, reloadInputViews];
+            });
+        });
+    }
+
+    /// Shows a toolbar with a Done button above the keyboard, which ends editing when tapped.
+    /// This is the usual way to dismiss number pads, which have no Return key.
+    #[cfg(uikit)]
+    pub fn set_done_accessory(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            let toolbar: id = msg_send![class!(UIToolbar), new];
+
+            // UIBarButtonSystemItemFlexibleSpace and UIBarButtonSystemItemDone, respectively.
+            let space: id = msg_send![class!(UIBarButtonItem), alloc];
+            let space: id = msg_send![space, initWithBarButtonSystemItem: 5 as NSInteger, target: nil, action: None::<Sel>];
+            let done: id = msg_send![class!(UIBarButtonItem), alloc];
+            let done: id =
+                msg_send![done, initWithBarButtonSystemItem: 0 as NSInteger, target: obj, action: sel!(resignFirstResponder)];
+
+            let items = NSArray::new(&[space, done]);
+            let _: () = msg_send![toolbar, setItems: &*items];
+            let _: () = msg_send![toolbar, sizeToFit];
+
+            let _: () = msg_send![obj, setInputAccessoryView: toolbar];
+            let _: () = msg_send![obj, reloadInputViews];
+
+            let _: () = msg_send![space, release];
+            let _: () = msg_send![done, release];
+            let _: () = msg_send![toolbar, release];
+        });
+    }
+
+    /// Removes any view set with `set_input_accessory_view()` or `set_done_accessory()`.
+    #[cfg(uikit)]
+    pub fn clear_input_accessory_view(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setInputAccessoryView: nil];
+            let _: () = msg_send![obj, reloadInputViews];
+        });
+    }
 }
 
 impl<T> ObjcAccess for TextField<T> {
@@ -423,6 +648,8 @@ impl<T> Drop for TextField<T> {
 
 #[test]
 fn test_text_view() {
+    crate::testing::initialize();
+
     let text_field = TextField::new();
     let value = text_field.get_value();
     assert!(value.is_empty());
@@ -32,10 +32,30 @@ pub trait TextFieldDelegate {
     fn text_did_begin_editing(&self, value: &str) {}
 
     /// Posts a notification when the text changes, and forwards the message to the text field’s cell if it responds.
+    ///
+    /// This is not called while the user is composing text with an input method (e.g, for CJK
+    /// input); see `marked_text_did_change` for that.
     fn text_did_change(&self, value: &str) {}
 
+    /// Called instead of `text_did_change` while the user is composing text with an input method,
+    /// and the value contains uncommitted (marked) text. Once the composition is committed,
+    /// `text_did_change` fires with the final value.
+    fn marked_text_did_change(&self, value: &str) {}
+
     /// Performs validation on the text field’s new value.
     fn text_should_end_editing(&self, value: &str) -> bool {
         true
     }
+
+    /// Called when the user triggers an editing command while typing in the field - e.g,
+    /// `"moveUp:"` and `"moveDown:"` for the arrow keys, `"insertNewline:"` for Return, or
+    /// `"cancelOperation:"` for Escape. Return `true` if you handled it, or `false` to let the
+    /// field do its usual thing.
+    ///
+    /// This is handy for fields that drive another view, like a search field over a list of
+    /// results.
+    #[cfg(appkit)]
+    fn do_command_by_selector(&self, selector: &str) -> bool {
+        false
+    }
 }
@@ -4,42 +4,70 @@ use objc::declare::ClassDecl;
 use objc::runtime::{Bool, Class, Object, Sel};
 use objc::{class, msg_send, sel};
 
-use crate::foundation::{id, load_or_register_class, NSString, NSUInteger};
+use crate::foundation::{id, load_or_register_class, nil, NSString, NSUInteger};
 use crate::input::{TextFieldDelegate, TEXTFIELD_DELEGATE_PTR};
-use crate::utils::load;
+use crate::utils::{load_weak, release_weak};
 
 /// Called when editing this text field has ended (e.g. user pressed enter).
 extern "C" fn text_did_end_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _info: id) {
-    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
     let s = NSString::retain(unsafe { msg_send![this, text] });
     view.text_did_end_editing(s.to_str());
 }
 
 extern "C" fn text_did_begin_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _info: id) {
-    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
     let s = NSString::retain(unsafe { msg_send![this, text] });
     view.text_did_begin_editing(s.to_str());
 }
 
+/// Called when the text changes. If an input method is mid-composition, the text contains marked
+/// (uncommitted) text, and we route it to `marked_text_did_change` instead.
 extern "C" fn text_did_change<T: TextFieldDelegate>(this: &Object, _: Sel, _info: id) {
-    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
     let s = NSString::retain(unsafe { msg_send![this, text] });
-    view.text_did_change(s.to_str());
+
+    match has_marked_text(this) {
+        true => view.marked_text_did_change(s.to_str()),
+        false => view.text_did_change(s.to_str())
+    }
 }
 
 extern "C" fn text_should_begin_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _info: id) -> Bool {
-    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return Bool::YES
+    };
     let s = NSString::retain(unsafe { msg_send![this, text] });
 
     Bool::new(view.text_should_begin_editing(s.to_str()))
 }
 
 extern "C" fn text_should_end_editing<T: TextFieldDelegate>(this: &Object, _: Sel, _info: id) -> Bool {
-    let view = load::<T>(this, TEXTFIELD_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, TEXTFIELD_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return Bool::YES
+    };
     let s = NSString::retain(unsafe { msg_send![this, text] });
     Bool::new(view.text_should_end_editing(s.to_str()))
 }
 
+/// Checks whether this text field currently has marked text, i.e an input method is
+/// mid-composition.
+pub(crate) fn has_marked_text(this: &Object) -> bool {
+    let range: id = unsafe { msg_send![this, markedTextRange] };
+    range != nil
+}
+
 /// Injects an `UITextField` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
 /// used if there's no delegates.
@@ -56,6 +84,15 @@ pub(crate) fn register_view_class() -> &'static Class {
     unsafe { VIEW_CLASS.unwrap() }
 }
 
+/// Releases the weak reference to the delegate, before the text field is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    release_weak(this, TEXTFIELD_DELEGATE_PTR);
+
+    unsafe {
+        let _: () = msg_send![super(this, class!(UITextField)), dealloc];
+    }
+}
+
 /// Injects an `UITextField` subclass, with some callback and pointer ivars for what we
 /// need to do.
 pub(crate) fn register_view_class_with_delegate<T: TextFieldDelegate>(instance: &T) -> &'static Class {
@@ -63,6 +100,7 @@ pub(crate) fn register_view_class_with_delegate<T: TextFieldDelegate>(instance:
         // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
         // move.
         decl.add_ivar::<usize>(TEXTFIELD_DELEGATE_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
 
         decl.add_method(
             sel!(textFieldDidEndEditing:),
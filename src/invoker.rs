@@ -19,6 +19,10 @@ use crate::utils::load;
 
 pub static ACTION_CALLBACK_PTR: &str = "rstTargetActionPtr";
 
+/// `UIControlEventPrimaryActionTriggered`.
+#[cfg(uikit)]
+const UI_CONTROL_EVENT_PRIMARY_ACTION_TRIGGERED: crate::foundation::NSUInteger = 1 << 13;
+
 /// An Action is just an indirection layer to get around Rust and optimizing
 /// zero-sum types; without this, pointers to callbacks will end up being
 /// 0x1, and all point to whatever is there first (unsure if this is due to
@@ -59,8 +63,23 @@ impl TargetActionHandler {
             let invoker = msg_send_id![register_invoker_class::<F>(), alloc];
             let mut invoker: Id<Object, Owned> = msg_send_id![invoker, init];
             invoker.set_ivar(ACTION_CALLBACK_PTR, ptr as usize);
-            let _: () = msg_send![control, setAction: sel!(perform:)];
-            let _: () = msg_send![control, setTarget: &*invoker];
+
+            #[cfg(appkit)]
+            {
+                let _: () = msg_send![control, setAction: sel!(perform:)];
+                let _: () = msg_send![control, setTarget: &*invoker];
+            }
+
+            // UIControl has no single target/action slot; we attach to the primary action, which
+            // covers taps on iOS and select presses on tvOS.
+            #[cfg(uikit)]
+            let _: () = msg_send![
+                control,
+                addTarget: &*invoker,
+                action: sel!(perform:),
+                forControlEvents: UI_CONTROL_EVENT_PRIMARY_ACTION_TRIGGERED
+            ];
+
             invoker.into()
         };
 
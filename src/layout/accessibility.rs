@@ -0,0 +1,45 @@
+//! Helpers for working with accessibility identifiers, which UI tests (XCUITest and friends) use
+//! to find elements without depending on titles or positions.
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{msg_send, sel};
+
+use crate::foundation::{id, nil, NSArray, NSString};
+
+/// Returns the accessibility identifier of `object`, if one is set.
+pub(crate) fn accessibility_identifier(object: id) -> Option<String> {
+    let identifier: id = unsafe { msg_send![object, accessibilityIdentifier] };
+
+    match identifier == nil {
+        true => None,
+        false => Some(NSString::retain(identifier).to_string())
+    }
+}
+
+/// Sets the accessibility identifier of `object`. An empty string clears it.
+pub(crate) fn set_accessibility_identifier(object: id, identifier: &str) {
+    let identifier = NSString::new(identifier);
+
+    unsafe {
+        let _: () = msg_send![object, setAccessibilityIdentifier: &*identifier];
+    }
+}
+
+/// Searches `view` and its descendants, depth first, for a view with the given accessibility
+/// identifier.
+pub(crate) fn find_view_with_accessibility_identifier(view: id, identifier: &str) -> Option<Id<Object, Shared>> {
+    if view == nil {
+        return None;
+    }
+
+    if accessibility_identifier(view).as_deref() == Some(identifier) {
+        return unsafe { Id::retain(view) };
+    }
+
+    let subviews = NSArray::retain(unsafe { msg_send![view, subviews] });
+
+    subviews
+        .iter()
+        .find_map(|subview| find_view_with_accessibility_identifier(subview, identifier))
+}
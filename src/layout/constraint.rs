@@ -10,7 +10,7 @@ use objc::{class, msg_send, sel};
 
 use crate::foundation::{id, NO, YES};
 
-#[cfg(all(feature = "appkit", target_os = "macos"))]
+#[cfg(all(appkit, target_os = "macos"))]
 use super::LayoutConstraintAnimatorProxy;
 
 /// A wrapper for `NSLayoutConstraint`. This both acts as a central path through which to activate
@@ -33,7 +33,7 @@ pub struct LayoutConstraint {
 
     /// An animator proxy that can be used inside animation contexts.
     /// This is currently only supported on macOS with the `appkit` feature.
-    #[cfg(all(feature = "appkit", target_os = "macos"))]
+    #[cfg(all(appkit, target_os = "macos"))]
     pub animator: LayoutConstraintAnimatorProxy
 }
 
@@ -41,7 +41,7 @@ impl LayoutConstraint {
     /// An internal method for wrapping existing constraints.
     pub(crate) fn new(object: id) -> Self {
         LayoutConstraint {
-            #[cfg(all(feature = "appkit", target_os = "macos"))]
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: LayoutConstraintAnimatorProxy::new(object),
             constraint: unsafe { Id::retain(object).unwrap() },
             offset: 0.0,
@@ -59,7 +59,7 @@ impl LayoutConstraint {
         }
 
         LayoutConstraint {
-            #[cfg(all(feature = "appkit", target_os = "macos"))]
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: self.animator,
 
             constraint: self.constraint,
@@ -101,17 +101,59 @@ impl LayoutConstraint {
     // I regret nothing, lol. If you have a better solution I'm all ears.
     pub fn activate(constraints: &[LayoutConstraint]) {
         let ids: Vec<&Object> = constraints.into_iter().map(|constraint| &*constraint.constraint).collect();
-        unsafe {
-            let constraints: id = msg_send![class!(NSArray), arrayWithObjects:ids.as_ptr() count:ids.len()];
-            let _: () = msg_send![class!(NSLayoutConstraint), activateConstraints: constraints];
-        }
+        set_constraints_active(&ids, true);
     }
 
+    /// Call this with your batch of constraints to deactivate them.
     pub fn deactivate(constraints: &[LayoutConstraint]) {
         let ids: Vec<&Object> = constraints.into_iter().map(|constraint| &*constraint.constraint).collect();
-        unsafe {
-            let constraints: id = msg_send![class!(NSArray), arrayWithObjects:ids.as_ptr() count:ids.len()];
-            let _: () = msg_send![class!(NSLayoutConstraint), deactivateConstraints: constraints];
+        set_constraints_active(&ids, false);
+    }
+
+    /// Activates several groups of constraints (e.g, one `Vec` per subview) with a single call
+    /// into AutoLayout, rather than one call per group.
+    ///
+    /// ```rust,no_run
+    /// # use cacao::layout::LayoutConstraint;
+    /// # let (header, body, footer): (Vec<LayoutConstraint>, Vec<LayoutConstraint>, Vec<LayoutConstraint>) = (vec![], vec![], vec![]);
+    /// LayoutConstraint::activate_batch(&[&header, &body, &footer]);
+    /// ```
+    pub fn activate_batch(batches: &[&[LayoutConstraint]]) {
+        let ids: Vec<&Object> = batches
+            .iter()
+            .flat_map(|batch| batch.iter())
+            .map(|constraint| &*constraint.constraint)
+            .collect();
+
+        set_constraints_active(&ids, true);
+    }
+
+    /// Deactivates several groups of constraints with a single call into AutoLayout. See
+    /// `activate_batch()`.
+    pub fn deactivate_batch(batches: &[&[LayoutConstraint]]) {
+        let ids: Vec<&Object> = batches
+            .iter()
+            .flat_map(|batch| batch.iter())
+            .map(|constraint| &*constraint.constraint)
+            .collect();
+
+        set_constraints_active(&ids, false);
+    }
+}
+
+/// (De)activates `constraints` with one call to `NSLayoutConstraint`.
+fn set_constraints_active(constraints: &[&Object], active: bool) {
+    unsafe {
+        let constraints: id = msg_send![class!(NSArray), arrayWithObjects:constraints.as_ptr() count:constraints.len()];
+
+        match active {
+            true => {
+                let _: () = msg_send![class!(NSLayoutConstraint), activateConstraints: constraints];
+            },
+
+            false => {
+                let _: () = msg_send![class!(NSLayoutConstraint), deactivateConstraints: constraints];
+            }
         }
     }
 }
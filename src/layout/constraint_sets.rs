@@ -0,0 +1,105 @@
+//! Named groups of constraints that can be swapped between, for layouts that change shape at
+//! runtime (e.g, a sidebar that collapses, or a form that goes from one column to two).
+//!
+//! ```rust,no_run
+//! use cacao::layout::ConstraintSets;
+//! use cacao::view::View;
+//!
+//! let sidebar = View::new();
+//!
+//! let mut sets = ConstraintSets::new();
+//! sets.insert("expanded", vec![sidebar.width.constraint_equal_to_constant(240.)]);
+//! sets.insert("collapsed", vec![sidebar.width.constraint_equal_to_constant(0.)]);
+//!
+//! sets.activate("expanded");
+//!
+//! // Later, e.g in response to a toolbar button:
+//! sets.activate("collapsed");
+//! ```
+
+use std::collections::HashMap;
+
+use super::LayoutConstraint;
+
+/// A collection of named constraint sets, at most one of which is active at a time.
+///
+/// Switching sets deactivates the old constraints and activates the new ones back to back, so
+/// AutoLayout never sees both (or neither) at once. Constraints that should always apply don't
+/// belong here; activate those once, as usual.
+#[derive(Debug, Default)]
+pub struct ConstraintSets {
+    sets: HashMap<String, Vec<LayoutConstraint>>,
+    active: Option<String>
+}
+
+impl ConstraintSets {
+    /// Returns an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores `constraints` under `name`, replacing any set already stored there. If the replaced
+    /// set is the active one, the new constraints take its place immediately.
+    pub fn insert(&mut self, name: &str, constraints: Vec<LayoutConstraint>) {
+        let is_active = self.active.as_deref() == Some(name);
+
+        if is_active {
+            if let Some(previous) = self.sets.get(name) {
+                LayoutConstraint::deactivate(previous);
+            }
+
+            LayoutConstraint::activate(&constraints);
+        }
+
+        self.sets.insert(name.to_string(), constraints);
+    }
+
+    /// Removes (and returns) the set stored under `name`, deactivating it first if it's active.
+    pub fn remove(&mut self, name: &str) -> Option<Vec<LayoutConstraint>> {
+        if self.active.as_deref() == Some(name) {
+            self.deactivate();
+        }
+
+        self.sets.remove(name)
+    }
+
+    /// Swaps to the set stored under `name`. Returns `false` (and changes nothing) if there's no
+    /// such set. Activating the set that's already active is a no-op.
+    pub fn activate(&mut self, name: &str) -> bool {
+        let constraints = match self.sets.get(name) {
+            Some(constraints) => constraints,
+            None => return false
+        };
+
+        if self.active.as_deref() == Some(name) {
+            return true;
+        }
+
+        if let Some(previous) = self.active.as_ref().and_then(|active| self.sets.get(active)) {
+            LayoutConstraint::deactivate(previous);
+        }
+
+        LayoutConstraint::activate(constraints);
+        self.active = Some(name.to_string());
+        true
+    }
+
+    /// Deactivates the active set, if any.
+    pub fn deactivate(&mut self) {
+        if let Some(active) = self.active.take() {
+            if let Some(previous) = self.sets.get(&active) {
+                LayoutConstraint::deactivate(previous);
+            }
+        }
+    }
+
+    /// Returns the name of the active set, if any.
+    pub fn active(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+
+    /// Returns the constraints stored under `name`, e.g to adjust their offsets in place.
+    pub fn get(&self, name: &str) -> Option<&[LayoutConstraint]> {
+        self.sets.get(name).map(|constraints| constraints.as_slice())
+    }
+}
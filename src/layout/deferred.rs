@@ -0,0 +1,116 @@
+//! A guard that coalesces the layout flush after a batch of constraint changes.
+//!
+//! AutoLayout has no public switch for pausing the engine, and this doesn't add one: layout still
+//! happens whenever something forces it mid-batch (reading a frame, asking for a fitting size,
+//! and so on). What usually causes repeated layout passes is code that forces layout itself after
+//! each change; `DeferredLayout` gives that kind of code one place to finish up instead. When the
+//! outermost guard is dropped, every view registered with a guard is marked as needing layout and
+//! laid out - once each. On AppKit, it also keeps the window from flushing intermediate states to
+//! the screen.
+//!
+//! ```rust,no_run
+//! use cacao::layout::{DeferredLayout, LayoutConstraint};
+//! use cacao::view::View;
+//!
+//! let view = View::new();
+//! # let (old, new): (Vec<LayoutConstraint>, Vec<LayoutConstraint>) = (vec![], vec![]);
+//!
+//! {
+//!     let _guard = DeferredLayout::new(&view);
+//!     LayoutConstraint::deactivate(&old);
+//!     LayoutConstraint::activate(&new);
+//! } // `view` is laid out here, once.
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{msg_send, sel};
+
+use crate::foundation::id;
+use crate::layout::Layout;
+
+#[cfg(appkit)]
+use crate::foundation::{nil, YES};
+
+thread_local! {
+    /// How many guards are alive; layout only runs when the outermost one is dropped.
+    static DEPTH: Cell<usize> = Cell::new(0);
+
+    /// Views to lay out once the outermost guard is dropped.
+    static PENDING: RefCell<Vec<Id<Object, Shared>>> = RefCell::new(Vec::new());
+}
+
+/// Forces a single layout pass for a view when dropped, rather than holding layout off in the
+/// meantime. Guards can be nested (including for different views); the flush happens once the
+/// outermost one goes away. See the module documentation for more.
+#[derive(Debug)]
+pub struct DeferredLayout {
+    // Guards are tied to the thread (and thread-local state) they were created on.
+    _not_send: PhantomData<*const ()>
+}
+
+impl DeferredLayout {
+    /// Registers `view` to be laid out when the outermost guard is dropped.
+    pub fn new<V: Layout>(view: &V) -> Self {
+        let view: Id<Object, Shared> =
+            view.get_from_backing_obj(|obj| unsafe { Id::retain(obj as *const Object as id) }.unwrap());
+
+        #[cfg(appkit)]
+        unsafe {
+            let window: id = msg_send![&*view, window];
+
+            if window != nil {
+                let _: () = msg_send![window, disableScreenUpdatesUntilFlush];
+            }
+        }
+
+        PENDING.with(|pending| {
+            let mut pending = pending.borrow_mut();
+
+            if !pending
+                .iter()
+                .any(|existing| &**existing as *const Object == &*view as *const Object)
+            {
+                pending.push(view);
+            }
+        });
+
+        DEPTH.with(|depth| depth.set(depth.get() + 1));
+
+        DeferredLayout { _not_send: PhantomData }
+    }
+}
+
+impl Drop for DeferredLayout {
+    fn drop(&mut self) {
+        let depth = DEPTH.with(|depth| {
+            depth.set(depth.get() - 1);
+            depth.get()
+        });
+
+        if depth > 0 {
+            return;
+        }
+
+        let views = PENDING.with(|pending| pending.replace(Vec::new()));
+
+        for view in views {
+            unsafe {
+                #[cfg(appkit)]
+                {
+                    let _: () = msg_send![&*view, setNeedsLayout: YES];
+                    let _: () = msg_send![&*view, layoutSubtreeIfNeeded];
+                }
+
+                #[cfg(uikit)]
+                {
+                    let _: () = msg_send![&*view, setNeedsLayout];
+                    let _: () = msg_send![&*view, layoutIfNeeded];
+                }
+            }
+        }
+    }
+}
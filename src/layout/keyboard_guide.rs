@@ -0,0 +1,81 @@
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, NO, YES};
+use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
+
+/// A `KeyboardLayoutGuide` wraps `UIKeyboardLayoutGuide`, and tracks the area of a view that the
+/// on-screen keyboard occupies. Constraining content above `top` keeps it clear of the keyboard
+/// as it shows, hides and changes size - the system handles animating alongside it for you.
+///
+/// When the keyboard is hidden, this guide collapses to the bottom safe area of the view.
+#[derive(Clone, Debug)]
+pub struct KeyboardLayoutGuide {
+    /// A pointer to the underlying `UIKeyboardLayoutGuide`.
+    pub objc: Id<Object, Shared>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime left layout constraint.
+    pub left: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime right layout constraint.
+    pub right: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl KeyboardLayoutGuide {
+    /// Given a view pointer, will extract (and implicitly create, if necessary) the keyboard
+    /// layout guide for it and return a `KeyboardLayoutGuide` composed of its anchors.
+    pub fn new(view: id) -> Self {
+        let objc: Id<Object, Shared> = unsafe { msg_send_id![view, keyboardLayoutGuide] };
+        let guide: id = &*objc as *const Object as id;
+
+        Self {
+            top: LayoutAnchorY::top(guide),
+            left: LayoutAnchorX::left(guide),
+            leading: LayoutAnchorX::leading(guide),
+            right: LayoutAnchorX::right(guide),
+            trailing: LayoutAnchorX::trailing(guide),
+            bottom: LayoutAnchorY::bottom(guide),
+            width: LayoutAnchorDimension::width(guide),
+            height: LayoutAnchorDimension::height(guide),
+            center_x: LayoutAnchorX::center(guide),
+            center_y: LayoutAnchorY::center(guide),
+            objc
+        }
+    }
+
+    /// By default, this guide only tracks the docked keyboard. Set this to `true` to have it
+    /// follow undocked and floating keyboards on iPad as well.
+    pub fn set_follows_undocked_keyboard(&self, follows: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setFollowsUndockedKeyboard:match follows {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
@@ -6,12 +6,20 @@
 mod traits;
 pub use traits::Layout;
 
-#[cfg(all(feature = "appkit", target_os = "macos"))]
+pub(crate) mod accessibility;
+
+#[cfg(all(appkit, target_os = "macos"))]
 mod animator;
 
-#[cfg(all(feature = "appkit", target_os = "macos"))]
+#[cfg(all(appkit, target_os = "macos"))]
 pub use animator::LayoutConstraintAnimatorProxy;
 
+#[cfg(appkit)]
+mod tooltip;
+
+#[cfg(appkit)]
+pub use tooltip::ToolTipRect;
+
 #[cfg(feature = "autolayout")]
 mod attributes;
 
@@ -24,6 +32,18 @@ mod constraint;
 #[cfg(feature = "autolayout")]
 pub use constraint::LayoutConstraint;
 
+#[cfg(feature = "autolayout")]
+mod constraint_sets;
+
+#[cfg(feature = "autolayout")]
+pub use constraint_sets::ConstraintSets;
+
+#[cfg(all(any(appkit, uikit), feature = "autolayout"))]
+mod deferred;
+
+#[cfg(all(any(appkit, uikit), feature = "autolayout"))]
+pub use deferred::DeferredLayout;
+
 #[cfg(feature = "autolayout")]
 mod dimension;
 
@@ -47,3 +67,15 @@ mod safe_guide;
 
 #[cfg(feature = "autolayout")]
 pub use safe_guide::SafeAreaLayoutGuide;
+
+#[cfg(all(uikit, feature = "autolayout"))]
+mod keyboard_guide;
+
+#[cfg(all(uikit, feature = "autolayout"))]
+pub use keyboard_guide::KeyboardLayoutGuide;
+
+#[cfg(all(uikit, feature = "autolayout"))]
+mod readable_guide;
+
+#[cfg(all(uikit, feature = "autolayout"))]
+pub use readable_guide::ReadableContentGuide;
@@ -0,0 +1,61 @@
+use objc::{msg_send, sel};
+
+use crate::foundation::id;
+use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
+
+/// A `ReadableContentGuide` wraps a view's `readableContentGuide`, which describes an area with a
+/// width suitable for comfortably reading text. The width adapts to the user's Dynamic Type
+/// setting, so forms and long-form text laid out against it stay legible on large displays.
+#[derive(Clone, Debug)]
+pub struct ReadableContentGuide {
+    /// A pointer to the Objective-C runtime top layout constraint.
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime left layout constraint.
+    pub left: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime right layout constraint.
+    pub right: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    pub center_y: LayoutAnchorY
+}
+
+impl ReadableContentGuide {
+    /// Given a view pointer, will extract the readable content guide properties and return a
+    /// `ReadableContentGuide` composed of them.
+    pub fn new(view: id) -> Self {
+        let guide: id = unsafe { msg_send![view, readableContentGuide] };
+
+        Self {
+            top: LayoutAnchorY::top(guide),
+            left: LayoutAnchorX::left(guide),
+            leading: LayoutAnchorX::leading(guide),
+            right: LayoutAnchorX::right(guide),
+            trailing: LayoutAnchorX::trailing(guide),
+            bottom: LayoutAnchorY::bottom(guide),
+            width: LayoutAnchorDimension::width(guide),
+            height: LayoutAnchorDimension::height(guide),
+            center_x: LayoutAnchorX::center(guide),
+            center_y: LayoutAnchorY::center(guide)
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use objc::{msg_send, sel};
 
+use crate::available;
 use crate::foundation::id;
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
-use crate::utils::os;
 
 /// A SafeAreaLayoutGuide should exist on all view types, and ensures that there are anchor points
 /// that work within the system constraints. On macOS 11+, this will ensure you work around system
@@ -45,8 +45,9 @@ impl SafeAreaLayoutGuide {
     /// Given a view pointer, will extract the safe area layout guide properties and return a
     /// `SafeAreaLayoutGuide` composed of them.
     pub fn new(view: id) -> Self {
-        // For versions prior to Big Sur, we'll just use the default view anchors in place.
-        let guide: id = match os::is_minimum_version(11) {
+        // Where the margins guide isn't available (e.g, prior to Big Sur), we'll just use the
+        // default view anchors in place.
+        let guide: id = match available!(macos 11, ios 9, tvos 9) {
             true => unsafe { msg_send![view, layoutMarginsGuide] },
             false => view
         };
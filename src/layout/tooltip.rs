@@ -0,0 +1,43 @@
+//! A handle for tooltips attached to a region of a view.
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{msg_send, sel};
+
+use crate::foundation::{NSInteger, NSString};
+
+/// Represents a tooltip that's been attached to a rect within a view, via
+/// `Layout::add_tooltip_rect`.
+///
+/// The view doesn't retain the tooltip text, so this holds on to it for you. The tooltip is
+/// removed from the view when this is dropped, so keep it around for as long as you want the
+/// tooltip to show.
+#[derive(Debug)]
+pub struct ToolTipRect {
+    /// The tag AppKit vended for this tooltip rect.
+    pub tag: NSInteger,
+
+    view: Id<Object, Shared>,
+    text: NSString<'static>
+}
+
+impl ToolTipRect {
+    /// Internal method for wrapping a newly added tooltip rect.
+    pub(crate) fn new(tag: NSInteger, view: Id<Object, Shared>, text: NSString<'static>) -> Self {
+        ToolTipRect { tag, view, text }
+    }
+
+    /// Returns the text this tooltip displays.
+    pub fn text(&self) -> &str {
+        self.text.to_str()
+    }
+}
+
+impl Drop for ToolTipRect {
+    /// Removes the tooltip from the view, as the text it points to is about to be released.
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.view, removeToolTip: self.tag];
+        }
+    }
+}
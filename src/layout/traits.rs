@@ -6,15 +6,27 @@ use core_graphics::geometry::{CGPoint, CGRect, CGSize};
 
 use objc::rc::{Id, Shared};
 use objc::runtime::Object;
-use objc::{msg_send, sel};
+use objc::{class, msg_send, msg_send_id, sel};
 
 use crate::foundation::{id, nil, to_bool, NSArray, NSString, NO, YES};
 use crate::geometry::Rect;
+use crate::layout::accessibility;
 use crate::objc_access::ObjcAccess;
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
+use crate::foundation::NSInteger;
+#[cfg(appkit)]
+use crate::geometry::PixelAlignment;
+#[cfg(appkit)]
+use crate::image::Image;
+#[cfg(appkit)]
+use crate::layout::ToolTipRect;
+#[cfg(appkit)]
 use crate::pasteboard::PasteboardType;
 
+#[cfg(all(uikit, feature = "autolayout"))]
+use crate::layout::{KeyboardLayoutGuide, ReadableContentGuide};
+
 /// A trait that view wrappers must conform to. Enables managing the subview tree.
 #[allow(unused_variables)]
 pub trait Layout: ObjcAccess {
@@ -97,16 +109,294 @@ pub trait Layout: ObjcAccess {
     }
 
     /// Returns whether this is hidden, *or* whether an ancestor view is hidden.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn is_hidden_or_ancestor_is_hidden(&self) -> bool {
         self.get_from_backing_obj(|obj| to_bool(unsafe { msg_send![obj, isHiddenOrHasHiddenAncestor] }))
     }
 
+    /// Sets the tooltip shown when the user hovers over this view. Passing an empty string
+    /// removes it.
+    #[cfg(appkit)]
+    fn set_tooltip(&self, tooltip: &str) {
+        let tooltip = NSString::new(tooltip);
+
+        self.with_backing_obj_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setToolTip: &*tooltip];
+        });
+    }
+
+    /// Attaches a tooltip to a specific region of this view, specified in the view's own
+    /// coordinate space. This is useful for composite or data-dense views where different areas
+    /// need different annotations.
+    ///
+    /// The tooltip is removed when the returned `ToolTipRect` is dropped.
+    #[cfg(appkit)]
+    fn add_tooltip_rect(&self, rect: Rect, tooltip: &str) -> ToolTipRect {
+        let text = NSString::new(tooltip);
+        let owner: id = &*text.objc as *const Object as id;
+        let frame: CGRect = rect.into();
+
+        let (tag, view) = self.get_from_backing_obj(|obj| unsafe {
+            let tag: NSInteger =
+                msg_send![obj, addToolTipRect: frame, owner: owner, userData: std::ptr::null_mut::<std::ffi::c_void>()];
+            let view: Id<Object, Shared> = Id::retain(obj as *const Object as id).unwrap();
+            (tag, view)
+        });
+
+        ToolTipRect::new(tag, view, text)
+    }
+
+    /// Removes all tooltips from this view, including any added via `add_tooltip_rect`.
+    #[cfg(appkit)]
+    fn remove_all_tooltips(&self) {
+        self.with_backing_obj_mut(|obj| unsafe {
+            let _: () = msg_send![obj, removeAllToolTips];
+        });
+    }
+
+    /// Renders this view (and its subviews) into an `Image`. The backing bitmap is created at
+    /// the scale factor of the window the view is in, so snapshots stay sharp on Retina displays.
+    ///
+    /// This is useful for drag images, previews, or exporting a view as a PNG (see
+    /// `Image::png_data()`).
+    ///
+    /// Returns `None` if there's nothing to render, e.g because the view has an empty frame.
+    #[cfg(appkit)]
+    fn snapshot(&self) -> Option<Image> {
+        let bounds: CGRect = self.get_from_backing_obj(|obj| unsafe { msg_send![obj, bounds] });
+        self.snapshot_rect(bounds.into())
+    }
+
+    /// Renders the given region of this view (in the view's own coordinate space) into an
+    /// `Image`. See `snapshot()` for more information.
+    ///
+    /// Returns `None` if AppKit can't create a bitmap for `rect` (e.g, it's empty).
+    #[cfg(appkit)]
+    fn snapshot_rect(&self, rect: Rect) -> Option<Image> {
+        let rect: CGRect = rect.into();
+
+        self.get_from_backing_obj(|obj| unsafe {
+            let rep: id = msg_send![obj, bitmapImageRepForCachingDisplayInRect: rect];
+            if rep == nil {
+                return None;
+            }
+
+            let _: () = msg_send![obj, cacheDisplayInRect: rect, toBitmapImageRep: rep];
+
+            let alloc = msg_send_id![class!(NSImage), alloc];
+            let image: Id<Object, Shared> = msg_send_id![alloc, initWithSize: rect.size];
+            let _: () = msg_send![&*image, addRepresentation: rep];
+
+            Some(Image(image))
+        })
+    }
+
+    /// Converts a point from this view's coordinate system to that of another view.
+    fn convert_point_to_view<V: Layout>(&self, point: CGPoint, view: &V) -> CGPoint {
+        view.get_from_backing_obj(|other| {
+            self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertPoint: point, toView: other] })
+        })
+    }
+
+    /// Converts a point from another view's coordinate system to that of this view.
+    fn convert_point_from_view<V: Layout>(&self, point: CGPoint, view: &V) -> CGPoint {
+        view.get_from_backing_obj(|other| {
+            self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertPoint: point, fromView: other] })
+        })
+    }
+
+    /// Converts a rect from this view's coordinate system to that of another view.
+    fn convert_rect_to_view<V: Layout>(&self, rect: Rect, view: &V) -> Rect {
+        let rect: CGRect = rect.into();
+
+        view.get_from_backing_obj(|other| {
+            let converted: CGRect = self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertRect: rect, toView: other] });
+            converted.into()
+        })
+    }
+
+    /// Converts a rect from another view's coordinate system to that of this view.
+    fn convert_rect_from_view<V: Layout>(&self, rect: Rect, view: &V) -> Rect {
+        let rect: CGRect = rect.into();
+
+        view.get_from_backing_obj(|other| {
+            let converted: CGRect =
+                self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertRect: rect, fromView: other] });
+            converted.into()
+        })
+    }
+
+    /// Converts a point from this view's coordinate system to that of the window it's in.
+    fn convert_point_to_window(&self, point: CGPoint) -> CGPoint {
+        self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertPoint: point, toView: nil] })
+    }
+
+    /// Converts a point from the coordinate system of the window this view is in to that of this
+    /// view.
+    fn convert_point_from_window(&self, point: CGPoint) -> CGPoint {
+        self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertPoint: point, fromView: nil] })
+    }
+
+    /// Converts a rect from this view's coordinate system to that of the window it's in.
+    fn convert_rect_to_window(&self, rect: Rect) -> Rect {
+        let rect: CGRect = rect.into();
+        let converted: CGRect = self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertRect: rect, toView: nil] });
+        converted.into()
+    }
+
+    /// Converts a rect from the coordinate system of the window this view is in to that of this
+    /// view.
+    fn convert_rect_from_window(&self, rect: Rect) -> Rect {
+        let rect: CGRect = rect.into();
+        let converted: CGRect = self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertRect: rect, fromView: nil] });
+        converted.into()
+    }
+
+    /// Converts a rect from this view's coordinate system to screen coordinates. Returns `None`
+    /// if this view isn't currently in a window.
+    #[cfg(appkit)]
+    fn convert_rect_to_screen(&self, rect: Rect) -> Option<Rect> {
+        let rect: CGRect = rect.into();
+
+        self.get_from_backing_obj(|obj| unsafe {
+            let window: id = msg_send![obj, window];
+            if window == nil {
+                return None;
+            }
+
+            let in_window: CGRect = msg_send![obj, convertRect: rect, toView: nil];
+            let on_screen: CGRect = msg_send![window, convertRectToScreen: in_window];
+            Some(on_screen.into())
+        })
+    }
+
+    /// Converts a rect from screen coordinates to this view's coordinate system. Returns `None`
+    /// if this view isn't currently in a window.
+    #[cfg(appkit)]
+    fn convert_rect_from_screen(&self, rect: Rect) -> Option<Rect> {
+        let rect: CGRect = rect.into();
+
+        self.get_from_backing_obj(|obj| unsafe {
+            let window: id = msg_send![obj, window];
+            if window == nil {
+                return None;
+            }
+
+            let in_window: CGRect = msg_send![window, convertRectFromScreen: rect];
+            let in_view: CGRect = msg_send![obj, convertRect: in_window, fromView: nil];
+            Some(in_view.into())
+        })
+    }
+
+    /// Returns the backing scale (e.g, `1.0` for non Retina, `2.0` for Retina) of the display
+    /// this view is on. If it isn't in a window yet, this falls back to the main screen.
+    ///
+    /// This can change as windows move between displays; see
+    /// `ViewDelegate::did_change_backing_properties()` and
+    /// `WindowDelegate::did_change_backing_scale_factor()`.
+    #[cfg(appkit)]
+    fn backing_scale_factor(&self) -> f64 {
+        self.get_from_backing_obj(|obj| unsafe {
+            let mut window: id = msg_send![obj, window];
+            if window == nil {
+                window = msg_send![class!(NSScreen), mainScreen];
+            }
+
+            match window == nil {
+                true => 1.,
+                false => {
+                    let scale: CGFloat = msg_send![window, backingScaleFactor];
+                    scale as f64
+                }
+            }
+        })
+    }
+
+    /// Returns `rect` (in this view's coordinate system) with its edges moved onto device pixel
+    /// boundaries, for the display this view is currently on. Use this for frames you compute by
+    /// hand, so that custom-drawn content stays crisp.
+    #[cfg(appkit)]
+    fn backing_aligned_rect(&self, rect: Rect, alignment: PixelAlignment) -> Rect {
+        let rect: CGRect = rect.into();
+        let options: u64 = alignment.into();
+
+        let aligned: CGRect =
+            self.get_from_backing_obj(|obj| unsafe { msg_send![obj, backingAlignedRect: rect, options: options] });
+        aligned.into()
+    }
+
+    /// Converts a rect from this view's coordinate system to device pixels.
+    #[cfg(appkit)]
+    fn convert_rect_to_backing(&self, rect: Rect) -> Rect {
+        let rect: CGRect = rect.into();
+        let converted: CGRect = self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertRectToBacking: rect] });
+        converted.into()
+    }
+
+    /// Converts a rect in device pixels to this view's coordinate system.
+    #[cfg(appkit)]
+    fn convert_rect_from_backing(&self, rect: Rect) -> Rect {
+        let rect: CGRect = rect.into();
+        let converted: CGRect = self.get_from_backing_obj(|obj| unsafe { msg_send![obj, convertRectFromBacking: rect] });
+        converted.into()
+    }
+
+    /// Returns the deepest descendant of this view (including this view itself) that contains
+    /// the given point, or `None` if the point lies outside of this view.
+    ///
+    /// The point is expected to be in this view's coordinate system; this takes care of the
+    /// platform differences (AppKit expects the superview's coordinate system here).
+    fn hit_test(&self, point: CGPoint) -> Option<Id<Object, Shared>> {
+        self.get_from_backing_obj(|obj| unsafe {
+            #[cfg(appkit)]
+            let hit: id = {
+                let superview: id = msg_send![obj, superview];
+                let point: CGPoint = match superview == nil {
+                    true => point,
+                    false => msg_send![obj, convertPoint: point, toView: superview]
+                };
+
+                msg_send![obj, hitTest: point]
+            };
+
+            #[cfg(uikit)]
+            let hit: id = msg_send![obj, hitTest: point, withEvent: nil];
+
+            Id::retain(hit)
+        })
+    }
+
+    /// Sets an identifier for this view that UI tests (e.g, XCUITest) can use to find it,
+    /// regardless of its title or position. It isn't shown or read out to the user.
+    fn set_accessibility_identifier(&self, identifier: &str) {
+        self.with_backing_obj_mut(|obj| accessibility::set_accessibility_identifier(obj, identifier));
+    }
+
+    /// Returns the identifier set via `set_accessibility_identifier()`, if any.
+    fn accessibility_identifier(&self) -> Option<String> {
+        self.get_from_backing_obj(|obj| accessibility::accessibility_identifier(obj as *const Object as id))
+    }
+
+    /// Searches this view and its descendants for one with the given accessibility identifier,
+    /// returning the first match.
+    fn find_view_with_accessibility_identifier(&self, identifier: &str) -> Option<Id<Object, Shared>> {
+        self.get_from_backing_obj(|obj| {
+            accessibility::find_view_with_accessibility_identifier(obj as *const Object as id, identifier)
+        })
+    }
+
+    /// Returns whether this view is a descendant of the given view (or is the view itself).
+    fn is_descendant_of<V: Layout>(&self, view: &V) -> bool {
+        view.get_from_backing_obj(|other| {
+            self.get_from_backing_obj(|obj| to_bool(unsafe { msg_send![obj, isDescendantOfView: other] }))
+        })
+    }
+
     /// Register this view for drag and drop operations.
     ///
     /// This should be supported under UIKit as well, but is featured gated under AppKit
     /// currently to avoid compile issues.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn register_for_dragged_types(&self, types: &[PasteboardType]) {
         let types: NSArray = types
             .into_iter()
@@ -127,7 +417,7 @@ pub trait Layout: ObjcAccess {
     ///
     /// This should be supported under UIKit as well, but is featured gated under AppKit
     /// currently to avoid compile issues.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn unregister_dragged_types(&self) {
         self.with_backing_obj_mut(|obj| unsafe {
             let _: () = msg_send![obj, unregisterDraggedTypes];
@@ -138,7 +428,7 @@ pub trait Layout: ObjcAccess {
     ///
     /// If you have a high performance tableview or collectionview that has issues, disabling these
     /// can be helpful - but always test!
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn set_posts_frame_change_notifications(&self, posts: bool) {
         self.with_backing_obj_mut(|obj| unsafe {
             let _: () = msg_send![obj, setPostsFrameChangedNotifications:match posts {
@@ -152,7 +442,7 @@ pub trait Layout: ObjcAccess {
     ///
     /// If you have a high performance tableview or collectionview that has issues, disabling these
     /// can be helpful - but always test!
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn set_posts_bounds_change_notifications(&self, posts: bool) {
         self.with_backing_obj_mut(|obj| unsafe {
             let _: () = msg_send![obj, setPostsBoundsChangedNotifications:match posts {
@@ -164,7 +454,7 @@ pub trait Layout: ObjcAccess {
 
     /// Theoretically this belongs elsewhere, but we want to enable this on all view layers, since
     /// it's common enough anyway.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn set_alpha(&self, value: f64) {
         let value: CGFloat = value.into();
 
@@ -172,4 +462,21 @@ pub trait Layout: ObjcAccess {
             let _: () = msg_send![obj, setAlphaValue: value];
         });
     }
+
+    /// Returns a layout guide that tracks the on-screen keyboard for this view. Pin your content's
+    /// bottom anchor to the guide's `top` anchor to keep it clear of the keyboard; the system
+    /// animates the change alongside the keyboard for you.
+    ///
+    /// This is only supported on iOS 15 and above.
+    #[cfg(all(uikit, feature = "autolayout"))]
+    fn keyboard_layout_guide(&self) -> KeyboardLayoutGuide {
+        self.get_from_backing_obj(|obj| KeyboardLayoutGuide::new(obj as *const Object as id))
+    }
+
+    /// Returns a layout guide describing an area of this view with a width suitable for reading
+    /// text, which adjusts for the user's Dynamic Type setting.
+    #[cfg(all(uikit, feature = "autolayout"))]
+    fn readable_content_guide(&self) -> ReadableContentGuide {
+        self.get_from_backing_obj(|obj| ReadableContentGuide::new(obj as *const Object as id))
+    }
 }
@@ -63,11 +63,24 @@
 //! - `appkit`: Implements appkit-specific APIs (menus, toolbars, windowing, and so on).
 //! - `uikit`: Implements uikit-specific APIs (scenes, navigation controllers, and so on).
 //!
+//! Both can be enabled at once, in which case the target decides: macOS builds use AppKit, and
+//! iOS/tvOS builds use UIKit. This lets a single crate graph back apps on both platforms; see the
+//! `platform` module for re-exports that resolve per-target.
+//!
+//! Mac Catalyst is supported via the `uikit` feature: build for one of the `*-apple-ios-macabi`
+//! targets, and the Catalyst-only pieces (e.g, `Scene::titlebar()`) become available.
+//!
 //! The rest of the features in this framework attempt to expose a common API across the three
 //! aforementioned feature platforms; if you need something else, you can often implement it
 //! yourself by accessing the underlying `objc` property of a control and sending messages to it
 //! directly.
 //!
+//! ## Delegate State
+//!
+//! Delegate methods take `&self`, as the system can call back into a delegate while your code is
+//! already running inside of it. For state that changes over the lifetime of a delegate, use
+//! `state::State<T>` rather than reaching for `RefCell` or `RwLock`.
+//!
 //! ## Optional Features
 //!
 //! The following are a list of [Cargo features][cargo-features] that can be enabled or disabled.
@@ -76,10 +89,48 @@
 //! feature, but is gated to enable platforms that might shim AppKit without AutoLayout support.
 //! - `cloudkit`: Links `CloudKit.framework` and provides some wrappers around CloudKit
 //! functionality. Currently not feature complete.
+//! - `corespotlight`: Links `CoreSpotlight.framework` and provides the `corespotlight` module, for
+//! indexing app content so that it shows up in Spotlight results.
+//! - `declarative`: Enables the `declarative` module, a SwiftUI-style layer where a component
+//! function returns a tree of view descriptions that cacao reconciles into real controls. Requires
+//! `autolayout`, and is currently appkit-only.
+//! - `derive`: Provides derive macros for wrapper types: `WindowWrapper` and `ViewWrapper` forward
+//! to a window or view field, and `Dispatch` generates helpers for sending `Dispatcher` messages.
+//! - `tvos`: Builds the uikit feature for tvOS. This implies `uikit`, excludes APIs that don't exist
+//! on tvOS (e.g, haptic feedback), and can't be combined with `webview`. Views can opt into the
+//! focus engine via `ViewDelegate::can_become_focused()`; see `uikit::focus`.
+//! - `gnustep`: Builds the appkit feature against GNUstep rather than Apple's AppKit, linking
+//! `gnustep-base` and `gnustep-gui` in place of the system frameworks. Wrappers for newer AppKit
+//! APIs quietly do nothing where the runtime lacks them, OS version checks take their oldest
+//! fallback path, and `foundation::set_superclass_fallback` lets you stand in for missing
+//! classes. The `logging` module (`os_log` and signposts) and `appkit::feedback` are unavailable,
+//! as are the `log-dispatch` messages. Airyx ships Apple-style frameworks, and needs no feature flag.
+//! - `event-synthesis`: Provides `appkit::synthesis`, for posting synthetic keyboard and mouse
+//! events via `CGEvent` (e.g, for UI automation and tests).
+//! - `game-controller`: Links `GameController.framework` and provides the `game_controller`
+//! module, for reading input from (and rumbling) game controllers on macOS and tvOS.
+//! `CoreHaptics.framework` is loaded at runtime, where the system has it.
+//! - `exception`: Enables `exception::catch_exception`, for converting Objective-C exceptions into
+//! Rust values rather than aborting.
+//! - `catch-all`: Catches Objective-C exceptions around every message send (including delegate
+//! dispatch), turning them into readable panics. This implies `exception`, and has a runtime cost.
 //! - `color_fallbacks`: Provides fallback colors for older systems where `systemColor` types don't
 //! exist. This feature is very uncommon and you probably don't need it.
-//! - `quicklook`: Links `QuickLook.framework` and offers methods for generating preview images for
-//! files.
+//! - `log-dispatch`: Logs every callback dispatched to a delegate via `os_log`, at the debug level.
+//! See the `logging` module.
+//! - `object-tracking`: Counts the live Objective-C objects wrapped by cacao, by class, and warns
+//! when views are dropped while still installed in a view hierarchy or when callbacks arrive after
+//! a delegate has been dropped. See the `debug` module. This is intended for debug builds only.
+//! - `persistence`: Links the system's `libsqlite3` and provides the `persistence` module, a small
+//! record store whose change notifications can drive `ListView` updates.
+//! - `quicklook`: Links `QuickLook.framework` and `QuickLookThumbnailing.framework`, and offers
+//! methods for generating thumbnails and icons for files.
+//! - `shortcuts`: Provides the `shortcuts` module, for donating app actions to Shortcuts and Siri
+//! Suggestions (via `NSUserActivity`) and routing them back to handlers when they're run.
+//! - `testing`: Exposes the `testing` module, with helpers for running UI code in (headless) tests.
+//! - `raw-window-handle`: Implements `raw-window-handle`'s traits for `Window` and `View`, for
+//! handing them to e.g `wgpu`. To go the other way and augment a `winit` app, wrap its window or
+//! view with `Window::existing()`/`View::existing()`.
 //! - `user-notifications`: Links `UserNotifications.framework` and provides functionality for
 //! emitting notifications on appkit and uikit. Note that this _requires_ your application be
 //! code-signed, and will not work without it.
@@ -98,20 +149,26 @@ pub use lazy_static;
 pub use objc;
 pub use url;
 
-//#[cfg(all(feature = "appkit", feature = "uikit", not(feature = "doc_cfg")))]
-//compile_error!("The \"appkit\" and \"uikit\" features cannot be enabled together. Pick one. :)");
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use cacao_derive::{Dispatch, ViewWrapper, WindowWrapper};
+
+#[cfg(all(feature = "tvos", feature = "webview"))]
+compile_error!("The \"webview\" feature is not supported on tvOS, as the platform has no webview control.");
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 #[cfg_attr(docsrs, doc(cfg(feature = "appkit")))]
 pub mod appkit;
 
 //pub mod bundle;
 
-#[cfg(feature = "uikit")]
+#[cfg(uikit)]
 #[cfg_attr(docsrs, doc(cfg(feature = "uikit")))]
 pub mod uikit;
 
-#[cfg(feature = "appkit")]
+pub mod binding;
+
+#[cfg(any(appkit, uikit))]
 pub mod button;
 
 #[cfg(any(feature = "cloudkit", doc))]
@@ -120,29 +177,52 @@ pub mod cloudkit;
 
 pub mod color;
 
-#[cfg(any(feature = "appkit", feature = "uikit"))]
+#[cfg(any(appkit, uikit))]
 pub mod control;
 
-#[cfg(feature = "appkit")]
+#[cfg(feature = "corespotlight")]
+#[cfg_attr(docsrs, doc(cfg(feature = "corespotlight")))]
+pub mod corespotlight;
+
+#[cfg(all(feature = "declarative", appkit))]
+#[cfg_attr(docsrs, doc(cfg(feature = "declarative")))]
+pub mod declarative;
+
+#[cfg(appkit)]
 pub mod dragdrop;
 
+#[cfg(feature = "object-tracking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "object-tracking")))]
+pub mod debug;
+
 pub mod error;
 
-#[cfg(feature = "appkit")]
+#[cfg(feature = "exception")]
+#[cfg_attr(docsrs, doc(cfg(feature = "exception")))]
+pub mod exception;
+
+#[cfg(appkit)]
 pub mod events;
 
 pub mod defaults;
 
-#[cfg(any(feature = "appkit", feature = "uikit"))]
+#[cfg(any(appkit, uikit))]
 pub mod filesystem;
 
 pub mod foundation;
 pub mod geometry;
 
-#[cfg(any(feature = "appkit", feature = "uikit"))]
+#[cfg(feature = "game-controller")]
+#[cfg_attr(docsrs, doc(cfg(feature = "game-controller")))]
+pub mod game_controller;
+
+#[cfg(appkit)]
+pub mod group_box;
+
+#[cfg(any(appkit, uikit))]
 pub mod image;
 
-#[cfg(any(feature = "appkit", feature = "uikit"))]
+#[cfg(any(appkit, uikit))]
 pub mod input;
 pub(crate) mod invoker;
 
@@ -151,29 +231,62 @@ pub mod keys;
 pub mod layer;
 pub mod layout;
 
-#[cfg(feature = "appkit")]
+#[cfg(any(appkit, uikit))]
 pub mod listview;
+
+#[cfg(all(any(appkit, uikit), not(gnustep)))]
+pub mod logging;
+
+#[cfg(appkit)]
+pub mod metadata;
+
 pub mod networking;
 pub mod notification_center;
 pub mod objc_access;
 
-#[cfg(feature = "appkit")]
+#[cfg(feature = "persistence")]
+#[cfg_attr(docsrs, doc(cfg(feature = "persistence")))]
+pub mod persistence;
+
+#[cfg(appkit)]
 pub mod pasteboard;
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
+pub mod path_control;
+
+#[cfg(any(appkit, uikit))]
+pub mod platform;
+
+#[cfg(appkit)]
 pub mod progress;
 
-#[cfg(any(feature = "appkit", feature = "uikit"))]
+#[cfg(any(appkit, uikit))]
 pub mod scrollview;
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 pub mod switch;
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 pub mod select;
 
+#[cfg(feature = "shortcuts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "shortcuts")))]
+pub mod shortcuts;
+
+pub mod state;
+
+#[cfg(any(appkit, uikit))]
+pub mod subclass;
+
+#[cfg(any(test, feature = "testing"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+
 pub mod text;
 
+#[cfg(appkit)]
+pub mod textview;
+
 #[cfg(feature = "quicklook")]
 #[cfg_attr(docsrs, doc(cfg(feature = "quicklook")))]
 pub mod quicklook;
@@ -190,3 +303,6 @@ pub mod view;
 #[cfg(any(feature = "webview", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "webview")))]
 pub mod webview;
+
+#[cfg(all(feature = "raw-window-handle", any(appkit, uikit)))]
+mod window_handle;
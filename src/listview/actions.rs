@@ -1,11 +1,13 @@
-use objc::rc::{Id, Owned};
+use std::cell::RefCell;
+
+use objc::rc::{Id, Owned, Shared, WeakId};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
 use block::ConcreteBlock;
 
 use crate::color::Color;
-use crate::foundation::{id, NSString, NSUInteger};
+use crate::foundation::{id, NSString, NSUInteger, NO};
 use crate::image::Image;
 
 /// Represents the "type" or "style" of row action. A `Regular` action is
@@ -35,9 +37,40 @@ impl From<RowActionStyle> for NSUInteger {
     }
 }
 
+thread_local! {
+    /// The table view that most recently asked for row actions. AppKit only shows one set of row
+    /// actions at a time, so this is the one a `RowActionDismisser` hides.
+    static ACTIVE_TABLE_VIEW: RefCell<Option<WeakId<Object>>> = RefCell::new(None);
+}
+
+/// Records the table view that row actions are about to be shown in, so that their handlers can
+/// dismiss them.
+pub(crate) fn set_active_table_view(table_view: &Id<Object, Shared>) {
+    ACTIVE_TABLE_VIEW.with(|active| *active.borrow_mut() = Some(WeakId::new(table_view)));
+}
+
+/// Passed to a `RowAction` handler; lets you hide the row actions once you're done with them
+/// (e.g, after marking an item as read, but not after deleting it - the row's gone anyway).
+#[derive(Debug)]
+pub struct RowActionDismisser(Option<Id<Object, Shared>>);
+
+impl RowActionDismisser {
+    /// Hides the row actions, sliding the row back into place.
+    pub fn dismiss(&self) {
+        if let Some(table_view) = &self.0 {
+            unsafe {
+                let _: () = msg_send![&**table_view, setRowActionsVisible: NO];
+            }
+        }
+    }
+}
+
 /// Represents an action that can be displayed when a user swipes-to-reveal
 /// on a ListViewRow. You return this from the appropriate delegate method,
 /// and the system will handle displaying the necessary pieces for you.
+///
+/// Actions are sized by the system to fit their title (or image); `NSTableViewRowAction` has no
+/// width property, so there's no way to set one here.
 #[derive(Debug)]
 pub struct RowAction(pub Id<Object, Owned>);
 
@@ -46,19 +79,21 @@ impl RowAction {
     /// configure whatever action you want to show when a user swipes-to-reveal
     /// on your ListViewRow.
     ///
+    /// The handler is given the row the action was performed on, along with a
+    /// `RowActionDismisser` for hiding the actions again.
+    ///
     /// Additional configuration can be done after initialization, if need be.
     ///
     /// These run on the main thread, as they're UI handlers - so we can avoid Send + Sync on
     /// our definitions.
     pub fn new<F>(title: &str, style: RowActionStyle, handler: F) -> Self
     where
-        F: Fn(RowAction, usize) + 'static
+        F: Fn(usize, RowActionDismisser) + 'static
     {
         let title = NSString::new(title);
-        let block = ConcreteBlock::new(move |action: id, row: NSUInteger| {
-            let action = RowAction(unsafe { Id::retain(action).unwrap() });
-
-            handler(action, row as usize);
+        let block = ConcreteBlock::new(move |_action: id, row: NSUInteger| {
+            let table_view = ACTIVE_TABLE_VIEW.with(|active| active.borrow().as_ref().and_then(|table_view| table_view.load()));
+            handler(row as usize, RowActionDismisser(table_view));
         });
         let block = block.copy();
         let style = style as NSUInteger;
@@ -74,6 +109,19 @@ impl RowAction {
         })
     }
 
+    /// Creates and returns a new `RowAction` that shows an image (and background color) in place
+    /// of its title. The title is still used for accessibility.
+    pub fn with_image<C, F>(title: &str, image: Image, background_color: C, style: RowActionStyle, handler: F) -> Self
+    where
+        C: AsRef<Color>,
+        F: Fn(usize, RowActionDismisser) + 'static
+    {
+        let mut action = RowAction::new(title, style, handler);
+        action.set_image(image);
+        action.set_background_color(background_color);
+        action
+    }
+
     /// Sets the title of this action.
     pub fn set_title(&mut self, title: &str) {
         let title = NSString::new(title);
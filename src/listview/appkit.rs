@@ -7,19 +7,23 @@
 //! for in the modern era. It also implements a few helpers for things like setting a background
 //! color, and enforcing layer backing by default.
 
-use objc::rc::{Id, Owned};
+use objc::rc::{Id, Owned, Shared};
 use objc::runtime::{Bool, Class, Object, Sel};
-use objc::{msg_send, sel};
+use objc::{class, msg_send, sel};
 
 use crate::appkit::menu::Menu;
 use crate::dragdrop::DragInfo;
-use crate::foundation::{id, load_or_register_class, NSArray, NSInteger, NSUInteger};
+use crate::foundation::{id, load_or_register_class, nil, NSArray, NSInteger, NSUInteger, StaticClass};
+use crate::listview::actions::set_active_table_view;
 use crate::listview::{ListViewDelegate, RowEdge, LISTVIEW_DELEGATE_PTR};
-use crate::utils::load;
+use crate::utils::{load_weak, release_weak};
 
 /// Determines the number of items by way of the backing data source (the Rust struct).
 extern "C" fn number_of_items<T: ListViewDelegate>(this: &Object, _: Sel, _: id) -> NSInteger {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return 0
+    };
     view.number_of_items() as NSInteger
 }
 
@@ -37,7 +41,10 @@ extern "C" fn view_for_column<T: ListViewDelegate>(
         let _: () = msg_send![table_column, setWidth:frame.size.width];
     }*/
 
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return nil
+    };
     let item = view.item_for(item as usize);
 
     // A hacky method of returning the underlying pointer
@@ -60,12 +67,16 @@ extern "C" fn will_display_cell<T: ListViewDelegate>(
     _column: id,
     item: NSInteger
 ) {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
-    view.will_display_item(item as usize);
+    if let Some(view) = load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        view.will_display_item(item as usize);
+    }
 }
 
 extern "C" fn menu_needs_update<T: ListViewDelegate>(this: &Object, _: Sel, menu: id) {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
     let items = view.context_menu();
     let _ = Menu::append(menu, items);
 }
@@ -82,7 +93,10 @@ extern "C" fn select_row<T: ListViewDelegate>(
     _table_view: id,
     item: NSInteger
 ) -> Bool {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return Bool::NO
+    };
     view.item_selected(item as usize);
     Bool::YES
 }*/
@@ -93,7 +107,10 @@ extern "C" fn selection_did_change<T: ListViewDelegate>(this: &Object, _: Sel, n
         msg_send![tableview, selectedRow]
     };
 
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
     if selected_row == -1 {
         view.item_selected(None);
     } else {
@@ -104,19 +121,21 @@ extern "C" fn selection_did_change<T: ListViewDelegate>(this: &Object, _: Sel, n
 extern "C" fn row_actions_for_row<T: ListViewDelegate>(
     this: &Object,
     _: Sel,
-    _table_view: id,
+    table_view: id,
     row: NSInteger,
     edge: NSInteger
 ) -> id {
     let edge: RowEdge = edge.into();
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let mut actions = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view.actions_for(row as usize, edge),
+        None => vec![]
+    };
 
-    let mut ids: NSArray = view
-        .actions_for(row as usize, edge)
-        .iter_mut()
-        .map(|action| &*action.0)
-        .collect::<Vec<&Object>>()
-        .into();
+    if let Some(table_view) = unsafe { Id::<Object, Shared>::retain(table_view) } {
+        set_active_table_view(&table_view);
+    }
+
+    let mut ids: NSArray = actions.iter_mut().map(|action| &*action.0).collect::<Vec<&Object>>().into();
 
     &mut *ids
 }
@@ -128,7 +147,10 @@ extern "C" fn enforce_normalcy(_: &Object, _: Sel) -> Bool {
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn dragging_entered<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return 0
+    };
     view.dragging_entered(DragInfo {
         info: unsafe { Id::retain(info).unwrap() }
     })
@@ -137,7 +159,10 @@ extern "C" fn dragging_entered<T: ListViewDelegate>(this: &mut Object, _: Sel, i
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn prepare_for_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> Bool {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return Bool::NO
+    };
 
     Bool::new(view.prepare_for_drag_operation(DragInfo {
         info: unsafe { Id::retain(info).unwrap() }
@@ -146,7 +171,10 @@ extern "C" fn prepare_for_drag_operation<T: ListViewDelegate>(this: &mut Object,
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn perform_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) -> Bool {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return Bool::NO
+    };
 
     Bool::new(view.perform_drag_operation(DragInfo {
         info: unsafe { Id::retain(info).unwrap() }
@@ -155,7 +183,10 @@ extern "C" fn perform_drag_operation<T: ListViewDelegate>(this: &mut Object, _:
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn conclude_drag_operation<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
 
     view.conclude_drag_operation(DragInfo {
         info: unsafe { Id::retain(info).unwrap() }
@@ -164,7 +195,10 @@ extern "C" fn conclude_drag_operation<T: ListViewDelegate>(this: &mut Object, _:
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn dragging_exited<T: ListViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, LISTVIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
 
     view.dragging_exited(DragInfo {
         info: unsafe { Id::retain(info).unwrap() }
@@ -176,7 +210,18 @@ extern "C" fn dragging_exited<T: ListViewDelegate>(this: &mut Object, _: Sel, in
 /// `UITableView` semantics; if `NSTableView`'s multi column behavior is needed, then it can
 /// be added in.
 pub(crate) fn register_listview_class() -> &'static Class {
-    load_or_register_class("NSTableView", "RSTListView", |decl| unsafe {})
+    static CLASS: StaticClass = StaticClass::new();
+
+    CLASS.get_or_register(|| load_or_register_class("NSTableView", "RSTListView", |decl| unsafe {}))
+}
+
+/// Releases the weak reference to the delegate, before the list view is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    release_weak(this, LISTVIEW_DELEGATE_PTR);
+
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSTableView)), dealloc];
+    }
 }
 
 /// Injects an `NSTableView` subclass, with some callback and pointer ivars for what we
@@ -186,6 +231,7 @@ pub(crate) fn register_listview_class() -> &'static Class {
 pub(crate) fn register_listview_class_with_delegate<T: ListViewDelegate>(instance: &T) -> &'static Class {
     load_or_register_class("NSTableView", instance.subclass_name(), |decl| unsafe {
         decl.add_ivar::<usize>(LISTVIEW_DELEGATE_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
 
         decl.add_method(sel!(isFlipped), enforce_normalcy as extern "C" fn(_, _) -> _);
 
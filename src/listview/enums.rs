@@ -1,4 +1,7 @@
-use crate::foundation::{NSInteger, NSUInteger};
+use crate::foundation::NSInteger;
+
+#[cfg(appkit)]
+use crate::foundation::NSUInteger;
 
 /// This enum represents the different stock animations possible
 /// for ListView row operations. You can pass it to `insert_rows`
@@ -28,6 +31,7 @@ pub enum RowAnimation {
     SlideRight
 }
 
+#[cfg(appkit)]
 impl Into<NSUInteger> for RowAnimation {
     fn into(self) -> NSUInteger {
         match self {
@@ -42,6 +46,22 @@ impl Into<NSUInteger> for RowAnimation {
     }
 }
 
+/// Maps to the nearest `UITableViewRowAnimation`.
+#[cfg(uikit)]
+impl Into<NSInteger> for RowAnimation {
+    fn into(self) -> NSInteger {
+        match self {
+            RowAnimation::None => 5,
+            RowAnimation::Fade => 0,
+            RowAnimation::Gap => 6,
+            RowAnimation::SlideUp => 3,
+            RowAnimation::SlideDown => 4,
+            RowAnimation::SlideLeft => 2,
+            RowAnimation::SlideRight => 1
+        }
+    }
+}
+
 /// Specifies a row edge.
 ///
 /// Generally used to indicate where row actions (swipe-to-reveal) should appear.
@@ -69,3 +89,58 @@ impl Into<RowEdge> for NSInteger {
         }
     }
 }
+
+/// The overall style of a `ListView`. This is only supported on macOS 11.0+, and will always be
+/// `FullWidth` on anything older.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TableViewStyle {
+    /// Let the system decide, based on where the view is used.
+    Automatic,
+
+    /// Rows span the full width of the view, as in pre-11.0 releases.
+    FullWidth,
+
+    /// Rows are inset from the edges of the view, with rounded selections.
+    Inset,
+
+    /// The style used for sidebars.
+    SourceList,
+
+    /// No insets or padding around rows.
+    Plain
+}
+
+impl From<TableViewStyle> for NSInteger {
+    fn from(style: TableViewStyle) -> Self {
+        match style {
+            TableViewStyle::Automatic => 0,
+            TableViewStyle::FullWidth => 1,
+            TableViewStyle::Inset => 2,
+            TableViewStyle::SourceList => 3,
+            TableViewStyle::Plain => 4
+        }
+    }
+}
+
+/// How selected rows are highlighted in a `ListView`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SelectionHighlightStyle {
+    /// No highlight is drawn for selected rows.
+    None,
+
+    /// The standard highlight.
+    Regular,
+
+    /// The highlight used in sidebars. On macOS 11.0+, prefer `TableViewStyle::SourceList`.
+    SourceList
+}
+
+impl From<SelectionHighlightStyle> for NSInteger {
+    fn from(style: SelectionHighlightStyle) -> Self {
+        match style {
+            SelectionHighlightStyle::None => -1,
+            SelectionHighlightStyle::Regular => 0,
+            SelectionHighlightStyle::SourceList => 1
+        }
+    }
+}
@@ -59,40 +59,83 @@ use crate::layout::Layout;
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 
 use crate::objc_access::ObjcAccess;
-use crate::scrollview::ScrollView;
-use crate::utils::properties::{ObjcProperty, PropertyNullable};
-use crate::utils::{os, CGSize, CellFactory};
-use crate::view::{ViewAnimatorProxy, ViewDelegate};
+use crate::utils::properties::ObjcProperty;
+use crate::utils::{CGSize, CellFactory, DelegateRef};
+use crate::view::ViewDelegate;
 
-#[cfg(feature = "appkit")]
+#[cfg(all(appkit, target_os = "macos"))]
+use crate::view::ViewAnimatorProxy;
+
+#[cfg(appkit)]
+use crate::available;
+
+#[cfg(appkit)]
+use crate::error::Error;
+
+#[cfg(appkit)]
 use crate::appkit::menu::MenuItem;
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
+use crate::scrollview::{ScrollSync, ScrollView};
+
+#[cfg(appkit)]
+use crate::utils::properties::PropertyNullable;
+
+#[cfg(appkit)]
 mod appkit;
 
-#[cfg(feature = "appkit")]
-use appkit::{register_listview_class, register_listview_class_with_delegate};
+#[cfg(appkit)]
+pub(crate) use appkit::register_listview_class;
+#[cfg(appkit)]
+use appkit::register_listview_class_with_delegate;
 
-//#[cfg(target_os = "ios")]
-//mod ios;
+#[cfg(uikit)]
+mod uikit;
 
-//#[cfg(target_os = "ios")]
-//use ios::{register_view_class, register_view_class_with_delegate};
+#[cfg(uikit)]
+pub(crate) use uikit::register_listview_class;
+#[cfg(uikit)]
+use uikit::register_listview_class_with_delegate;
 
 mod enums;
-pub use enums::{RowAnimation, RowEdge};
+pub use enums::{RowAnimation, RowEdge, SelectionHighlightStyle, TableViewStyle};
 
 mod traits;
 pub use traits::ListViewDelegate;
 
 mod row;
+pub(crate) use row::register_listview_row_class;
 pub use row::ListViewRow;
 
+#[cfg(appkit)]
 mod actions;
-pub use actions::{RowAction, RowActionStyle};
+
+#[cfg(appkit)]
+pub use actions::{RowAction, RowActionDismisser, RowActionStyle};
+
+#[cfg(all(appkit, feature = "autolayout"))]
+mod sidebar;
+
+#[cfg(all(appkit, feature = "autolayout"))]
+pub use sidebar::{SidebarItem, SidebarRow};
 
 pub(crate) static LISTVIEW_DELEGATE_PTR: &str = "rstListViewDelegatePtr";
 
+/// UITableView addresses rows by `NSIndexPath`; this builds an array of them for the given rows,
+/// all in the first (and only) section.
+#[cfg(uikit)]
+fn index_paths(indexes: &[usize]) -> NSArray {
+    indexes
+        .iter()
+        .map(|index| unsafe {
+            let row = *index as NSInteger;
+            let section: NSInteger = 0;
+            msg_send![class!(NSIndexPath), indexPathForRow: row, inSection: section]
+        })
+        .collect::<Vec<id>>()
+        .into()
+}
+
 use std::any::Any;
 use std::sync::{Arc, RwLock};
 
@@ -102,12 +145,16 @@ use std::rc::Rc;
 /// A helper method for instantiating view classes and applying default settings to them.
 fn common_init(class: &Class) -> id {
     unsafe {
-        // Note: we do *not* enable AutoLayout here as we're by default placing this in a scroll
-        // view, and we want it to just do its thing.
+        // Note: on AppKit we do *not* enable AutoLayout here as we're by default placing this in a
+        // scroll view, and we want it to just do its thing.
         let tableview: id = msg_send![class, new];
 
+        // UITableView is its own scroll view, so it's laid out directly.
+        #[cfg(all(uikit, feature = "autolayout"))]
+        let _: () = msg_send![tableview, setTranslatesAutoresizingMaskIntoConstraints: NO];
+
         // Let's... make NSTableView into UITableView-ish.
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         {
             // @TODO: Clean this up in a dealloc method.
             let menu: id = msg_send![class!(NSMenu), new];
@@ -141,20 +188,28 @@ pub struct ListView<T = ()> {
     /// allocation and reuse, which is necessary for an "infinite" listview.
     cell_factory: CellFactory,
 
+    #[cfg(appkit)]
     menu: PropertyNullable<Vec<MenuItem>>,
 
     /// A pointer to the Objective-C runtime view controller.
     pub objc: ObjcProperty,
 
     /// An object that supports limited animations. Can be cloned into animation closures.
+    ///
+    /// This is currently only supported on macOS with the `appkit` feature.
+    #[cfg(all(appkit, target_os = "macos"))]
     pub animator: ViewAnimatorProxy,
 
     /// In AppKit, we need to manage the NSScrollView ourselves. It's a bit
     /// more old school like that...
     ///
-    /// In iOS, this is a pointer to the UITableView-owned UIScrollView.
+    /// UITableView is already a scroll view, so there's no equivalent on iOS and tvOS.
+    #[cfg(appkit)]
     pub scrollview: ScrollView,
 
+    /// Keeps the delegate reachable from the Objective-C side; callbacks stop once this drops.
+    pub(crate) delegate_ref: Option<DelegateRef>,
+
     /// A pointer to the delegate for this view.
     pub delegate: Option<Box<T>>,
 
@@ -211,7 +266,7 @@ impl ListView {
         let class = register_listview_class();
         let view = common_init(class);
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let scrollview = {
             let sview = ScrollView::new();
 
@@ -224,16 +279,20 @@ impl ListView {
 
         // For AppKit, we need to use the NSScrollView anchor points, not the NSTableView.
         // @TODO: Fix this with proper mutable access.
-        #[cfg(all(feature = "appkit", feature = "autolayout"))]
+        #[cfg(appkit)]
         let anchor_view: id = scrollview.objc.get(|obj| unsafe { msg_send![obj, self] });
 
-        //#[cfg(all(feature = "uikit", feature = "autolayout"))]
-        //let anchor_view: id = view;
+        #[cfg(all(uikit, feature = "autolayout"))]
+        let anchor_view: id = view;
 
         ListView {
             cell_factory: CellFactory::new(),
+
+            #[cfg(appkit)]
             menu: PropertyNullable::default(),
+
             delegate: None,
+            delegate_ref: None,
 
             #[cfg(feature = "autolayout")]
             top: LayoutAnchorY::top(anchor_view),
@@ -268,10 +327,12 @@ impl ListView {
             // Note that AppKit needs this to be the ScrollView!
             // @TODO: Figure out if there's a use case for exposing the inner tableview animator
             // property...
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: ViewAnimatorProxy::new(anchor_view),
 
             objc: ObjcProperty::retain(view),
 
+            #[cfg(appkit)]
             scrollview
         }
     }
@@ -289,14 +350,14 @@ where
         let mut delegate = Box::new(delegate);
         let cell = CellFactory::new();
 
+        let delegate_ref = DelegateRef::attach(unsafe { &mut *view }, LISTVIEW_DELEGATE_PTR, &*delegate);
+
         unsafe {
-            let delegate_ptr: *const T = &*delegate;
-            (&mut *view).set_ivar(LISTVIEW_DELEGATE_PTR, delegate_ptr as usize);
             let _: () = msg_send![view, setDelegate: view];
             let _: () = msg_send![view, setDataSource: view];
         };
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let scrollview = {
             let sview = ScrollView::new();
 
@@ -308,17 +369,23 @@ where
         };
 
         // For AppKit, we need to use the NSScrollView anchor points, not the NSTableView.
-        #[cfg(all(feature = "appkit", feature = "autolayout"))]
+        #[cfg(appkit)]
         let anchor_view: id = scrollview.objc.get(|obj| unsafe { msg_send![obj, self] });
 
-        //#[cfg(feature = "uikit")]
-        //let anchor_view = view;
+        #[cfg(all(uikit, feature = "autolayout"))]
+        let anchor_view: id = view;
 
         let mut view = ListView {
             cell_factory: cell,
+
+            #[cfg(appkit)]
             menu: PropertyNullable::default(),
+
             delegate: None,
+            delegate_ref: None,
             objc: ObjcProperty::retain(view),
+
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: ViewAnimatorProxy::new(anchor_view),
 
             #[cfg(feature = "autolayout")]
@@ -351,11 +418,13 @@ where
             #[cfg(feature = "autolayout")]
             center_y: LayoutAnchorY::center(anchor_view),
 
+            #[cfg(appkit)]
             scrollview
         };
 
         (&mut delegate).did_load(view.clone_as_handle());
         view.delegate = Some(delegate);
+        view.delegate_ref = Some(delegate_ref);
         view
     }
 }
@@ -368,9 +437,15 @@ impl<T> ListView<T> {
     pub fn clone_as_handle(&self) -> ListView {
         ListView {
             cell_factory: CellFactory::new(),
+
+            #[cfg(appkit)]
             menu: self.menu.clone(),
+
             delegate: None,
+            delegate_ref: None,
             objc: self.objc.clone(),
+
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: self.animator.clone(),
 
             #[cfg(feature = "autolayout")]
@@ -403,6 +478,7 @@ impl<T> ListView<T> {
             #[cfg(feature = "autolayout")]
             center_y: self.center_y.clone(),
 
+            #[cfg(appkit)]
             scrollview: self.scrollview.clone_as_handle()
         }
     }
@@ -419,7 +495,7 @@ impl<T> ListView<T> {
 
     /// Dequeue a reusable cell. If one is not in the queue, will create and cache one for reuse.
     pub fn dequeue<R: ViewDelegate + 'static>(&self, identifier: &'static str) -> ListViewRow<R> {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         {
             let key = NSString::new(identifier);
             let cell: id = self
@@ -435,6 +511,21 @@ impl<T> ListView<T> {
                 view
             }
         }
+
+        #[cfg(uikit)]
+        {
+            let key = NSString::new(identifier);
+            let cell: id = self
+                .objc
+                .get(|obj| unsafe { msg_send![obj, dequeueReusableCellWithIdentifier:&*key] });
+
+            if cell != nil {
+                ListViewRow::from_cached(cell)
+            } else {
+                let delegate: Box<R> = self.cell_factory.get(identifier);
+                ListViewRow::with_boxed_and_identifier(delegate, identifier)
+            }
+        }
     }
 
     /// Call this to set the background color for the backing layer.
@@ -447,26 +538,45 @@ impl<T> ListView<T> {
         });
     }
 
-    /// Sets the style for the underlying NSTableView. This property is only supported on macOS
-    /// 11.0+, and will always be `FullWidth` on anything older.
+    /// Sets the style for the underlying NSTableView, from a raw `NSTableViewStyle` value. This
+    /// property is only supported on macOS 11.0+, and will always be `FullWidth` on anything older.
     ///
-    /// On non-macOS platforms, this method is a noop.
-    #[cfg(feature = "appkit")]
-    pub fn set_style(&self, style: crate::foundation::NSInteger) {
-        #[cfg(target_os = "macos")]
-        if os::is_minimum_version(11) {
+    /// This does nothing where the style isn't supported; `set_table_view_style()` returns an
+    /// error instead.
+    #[cfg(appkit)]
+    #[deprecated(note = "Use `set_table_view_style` with a `TableViewStyle` instead.")]
+    pub fn set_style(&self, style: NSInteger) {
+        if available!(macos 11) {
             self.objc.with_mut(|obj| unsafe {
                 let _: () = msg_send![obj, setStyle: style];
             });
         }
     }
 
+    /// Sets the style for the underlying NSTableView. This property is only supported on macOS
+    /// 11.0+, and will always be `FullWidth` on anything older; calling this there returns an
+    /// error (see `Error::is_unsupported_on_this_os()`).
+    #[cfg(appkit)]
+    pub fn set_table_view_style(&self, style: TableViewStyle) -> Result<(), Error> {
+        if !available!(macos 11) {
+            return Err(Error::unsupported_on_this_os("ListView::set_table_view_style", "macOS 11"));
+        }
+
+        let style: NSInteger = style.into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setStyle: style];
+        });
+
+        Ok(())
+    }
+
     /// Set whether this control can appear with no row selected.
     ///
     /// This defaults to `true`, but some AppKit pieces (e.g, a sidebar) may want this set to
     /// `false`. This can be particularly useful when implementing a Source List style sidebar
     /// view for navigation purposes.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn set_allows_empty_selection(&self, allows: bool) {
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setAllowsEmptySelection:match allows {
@@ -476,8 +586,20 @@ impl<T> ListView<T> {
         });
     }
 
+    /// Set the selection highlight style, from a raw `NSTableViewSelectionHighlightStyle` value.
+    #[cfg(appkit)]
+    #[deprecated(note = "Use `set_selection_highlight` with a `SelectionHighlightStyle` instead.")]
+    pub fn set_selection_highlight_style(&self, style: NSInteger) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setSelectionHighlightStyle: style];
+        });
+    }
+
     /// Set the selection highlight style.
-    pub fn set_selection_highlight_style(&self, style: crate::foundation::NSInteger) {
+    #[cfg(appkit)]
+    pub fn set_selection_highlight(&self, style: SelectionHighlightStyle) {
+        let style: NSInteger = style.into();
+
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setSelectionHighlightStyle: style];
         });
@@ -485,6 +607,7 @@ impl<T> ListView<T> {
 
     /// Select the rows at the specified indexes, optionally adding to any existing selections.
     pub fn select_row_indexes(&self, indexes: &[usize], extends_existing: bool) {
+        #[cfg(appkit)]
         unsafe {
             let mut index_set: Id<Object, Owned> = msg_send_id![class!(NSMutableIndexSet), new];
 
@@ -499,6 +622,24 @@ impl<T> ListView<T> {
                 }];
             });
         }
+
+        #[cfg(uikit)]
+        self.objc.with_mut(|obj| unsafe {
+            if !extends_existing {
+                let selected: id = msg_send![obj, indexPathsForSelectedRows];
+
+                if selected != nil {
+                    for index_path in NSArray::retain(selected).iter() {
+                        let _: () = msg_send![obj, deselectRowAtIndexPath: index_path, animated: NO];
+                    }
+                }
+            }
+
+            for index_path in index_paths(indexes).iter() {
+                // UITableViewScrollPositionNone
+                let _: () = msg_send![obj, selectRowAtIndexPath: index_path, animated: NO, scrollPosition: 0 as NSInteger];
+            }
+        });
     }
 
     /// This hack exists to avoid a bug with how Rust's model isn't really friendly with more
@@ -544,21 +685,18 @@ impl<T> ListView<T> {
     /// ```
     pub fn perform_batch_updates<F: Fn(ListView)>(&self, update: F) {
         // Note that we need to thread the `with_mut` calls carefully, to avoid deadlocking.
-        #[cfg(feature = "appkit")]
-        {
-            self.objc.get(|obj| unsafe {
-                let _: () = msg_send![obj, beginUpdates];
-            });
+        self.objc.get(|obj| unsafe {
+            let _: () = msg_send![obj, beginUpdates];
+        });
 
-            let handle = self.clone_as_handle();
-            update(handle);
+        let handle = self.clone_as_handle();
+        update(handle);
 
-            // This is done for a very explicit reason; see the comments on the method itself for
-            // an explanation.
-            self.hack_avoid_dequeue_loop(|obj| unsafe {
-                let _: () = msg_send![obj, endUpdates];
-            });
-        }
+        // This is done for a very explicit reason; see the comments on the method itself for
+        // an explanation.
+        self.hack_avoid_dequeue_loop(|obj| unsafe {
+            let _: () = msg_send![obj, endUpdates];
+        });
     }
 
     /// Insert new rows at the specified indexes, with the specified animation.
@@ -567,7 +705,7 @@ impl<T> ListView<T> {
     /// rows at once, you should also run this inside a `perform_batch_updates` call, as that will
     /// optimize things accordingly.
     pub fn insert_rows(&self, indexes: &[usize], animation: RowAnimation) {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         unsafe {
             let mut index_set: Id<Object, Owned> = msg_send_id![class!(NSMutableIndexSet), new];
 
@@ -589,11 +727,23 @@ impl<T> ListView<T> {
                 let _: () = msg_send![obj, insertRowsAtIndexes: &*x, withAnimation: animation_options];
             });
         }
+
+        #[cfg(uikit)]
+        {
+            let paths = index_paths(indexes);
+            let animation: NSInteger = animation.into();
+
+            // This is done for a very explicit reason; see the comments on the method itself for
+            // an explanation.
+            self.hack_avoid_dequeue_loop(|obj| unsafe {
+                let _: () = msg_send![obj, insertRowsAtIndexPaths: &*paths, withRowAnimation: animation];
+            });
+        }
     }
 
     /// Reload the rows at the specified indexes.
     pub fn reload_rows(&self, indexes: &[usize]) {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         unsafe {
             let mut index_set: Id<Object, Owned> = msg_send_id![class!(NSMutableIndexSet), new];
 
@@ -612,6 +762,17 @@ impl<T> ListView<T> {
                 let _: () = msg_send![obj, reloadDataForRowIndexes: &*x, columnIndexes: &*y];
             });
         }
+
+        #[cfg(uikit)]
+        {
+            let paths = index_paths(indexes);
+            let animation: NSInteger = RowAnimation::None.into();
+
+            // Must use `get` to avoid a double lock.
+            self.objc.get(|obj| unsafe {
+                let _: () = msg_send![obj, reloadRowsAtIndexPaths: &*paths, withRowAnimation: animation];
+            });
+        }
     }
 
     /// Remove rows at the specified indexes, with the specified animation.
@@ -620,7 +781,7 @@ impl<T> ListView<T> {
     /// rows at once, you should also run this inside a `perform_batch_updates` call, as that will
     /// optimize things accordingly.
     pub fn remove_rows(&self, indexes: &[usize], animations: RowAnimation) {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         unsafe {
             let mut index_set: Id<Object, Owned> = msg_send_id![class!(NSMutableIndexSet), new];
 
@@ -640,6 +801,16 @@ impl<T> ListView<T> {
                 let _: () = msg_send![obj, removeRowsAtIndexes: &*x, withAnimation: animation_options];
             });
         }
+
+        #[cfg(uikit)]
+        {
+            let paths = index_paths(indexes);
+            let animation: NSInteger = animations.into();
+
+            self.objc.with_mut(|obj| unsafe {
+                let _: () = msg_send![obj, deleteRowsAtIndexPaths: &*paths, withRowAnimation: animation];
+            });
+        }
     }
 
     /// Sets an enforced row-height; if you need dynamic rows, you'll want to
@@ -656,7 +827,7 @@ impl<T> ListView<T> {
     ///
     /// It can make some scrolling situations much smoother.
     pub fn set_uses_automatic_row_heights(&self, uses: bool) {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setUsesAutomaticRowHeights:match uses {
                 true => YES,
@@ -669,7 +840,7 @@ impl<T> ListView<T> {
     /// background colors automatically. If you set this, you possibly want
     /// to hard-set a row height as well.
     pub fn set_uses_alternating_backgrounds(&self, uses: bool) {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setUsesAlternatingRowBackgroundColors:match uses {
                 true => YES,
@@ -680,7 +851,7 @@ impl<T> ListView<T> {
 
     /// End actions for a row. API subject to change.
     pub fn set_row_actions_visible(&self, visible: bool) {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setRowActionsVisible:match visible {
                 true => YES,
@@ -690,7 +861,7 @@ impl<T> ListView<T> {
     }
 
     /// Makes this table view the first responder.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn make_first_responder(&self) {
         self.objc.with_mut(|obj| unsafe {
             let window: id = msg_send![&*obj, window];
@@ -698,6 +869,35 @@ impl<T> ListView<T> {
         });
     }
 
+    /// Scrolls the list view, if need be, so that the row at `index` is visible.
+    pub fn scroll_row_to_visible(&self, index: usize) {
+        #[cfg(appkit)]
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, scrollRowToVisible: index as NSInteger];
+        });
+
+        #[cfg(uikit)]
+        {
+            let paths = index_paths(&[index]);
+
+            // UITableViewScrollPositionNone scrolls as little as it takes to show the row.
+            self.objc.with_mut(|obj| unsafe {
+                for index_path in paths.iter() {
+                    let _: () =
+                        msg_send![obj, scrollToRowAtIndexPath: index_path, atScrollPosition: 0 as NSInteger, animated: NO];
+                }
+            });
+        }
+    }
+
+    /// Keeps the vertical scroll position of this and `other` in step, for as long as the
+    /// returned `ScrollSync` is kept around - e.g, for a frozen column of row headers beside the
+    /// main table. Both should use the same row heights for the rows to line up.
+    #[cfg(appkit)]
+    pub fn sync_vertical_scrolling_with<U>(&self, other: &ListView<U>) -> ScrollSync {
+        self.scrollview.sync_vertical_scrolling_with(&other.scrollview)
+    }
+
     /// Reloads the underlying ListView. This is more expensive than handling insert/reload/remove
     /// calls yourself, but often easier to implement.
     ///
@@ -709,9 +909,21 @@ impl<T> ListView<T> {
         });
     }
 
-    /// Returns the selected row.
+    /// Returns the selected row, or `-1` if no row is selected.
     pub fn get_selected_row_index(&self) -> NSInteger {
-        self.objc.get(|obj| unsafe { msg_send![obj, selectedRow] })
+        #[cfg(appkit)]
+        return self.objc.get(|obj| unsafe { msg_send![obj, selectedRow] });
+
+        #[cfg(uikit)]
+        return self.objc.get(|obj| unsafe {
+            let index_path: id = msg_send![obj, indexPathForSelectedRow];
+
+            if index_path == nil {
+                return -1;
+            }
+
+            msg_send![index_path, row]
+        });
     }
 
     /// Returns the currently clicked row. This is AppKit-specific, and is generally used in context
@@ -744,6 +956,7 @@ impl<T> ListView<T> {
     ///     fn item_for(&self, _: usize) -> ListViewRow { todo!() }
     /// }
     /// ```
+    #[cfg(appkit)]
     pub fn get_clicked_row_index(&self) -> NSInteger {
         self.objc.get(|obj| unsafe { msg_send![obj, clickedRow] })
     }
@@ -753,8 +966,11 @@ impl<T> ObjcAccess for ListView<T> {
     fn with_backing_obj_mut<F: Fn(id)>(&self, handler: F) {
         // In AppKit, we need to provide the scrollview for layout purposes - iOS and tvOS will know
         // what to do normally.
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         self.scrollview.objc.with_mut(handler);
+
+        #[cfg(uikit)]
+        self.objc.with_mut(handler);
     }
 
     fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
@@ -763,8 +979,11 @@ impl<T> ObjcAccess for ListView<T> {
         //
         // @TODO: Review this, as property access isn't really used in the same place as layout
         // stuff... hmm...
-        #[cfg(feature = "appkit")]
-        self.scrollview.objc.get(handler)
+        #[cfg(appkit)]
+        return self.scrollview.objc.get(handler);
+
+        #[cfg(uikit)]
+        return self.objc.get(handler);
     }
 }
 
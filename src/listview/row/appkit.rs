@@ -12,7 +12,7 @@ use objc::runtime::{Bool, Class, Object, Sel};
 use objc::{class, msg_send, sel};
 
 use crate::dragdrop::DragInfo;
-use crate::foundation::{id, load_or_register_class, nil, NSUInteger};
+use crate::foundation::{id, load_or_register_class, nil, NSUInteger, StaticClass};
 use crate::listview::row::{ViewDelegate, BACKGROUND_COLOR, LISTVIEW_ROW_DELEGATE_PTR};
 use crate::utils::load;
 
@@ -98,8 +98,12 @@ extern "C" fn dealloc<T: ViewDelegate>(this: &Object, _: Sel) {
 /// have separate classes here since we don't want to waste cycles on methods that will never be
 /// used if there's no delegates.
 pub(crate) fn register_listview_row_class() -> &'static Class {
-    load_or_register_class("NSView", "RSTTableViewRow", |decl| unsafe {
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern "C" fn(_, _) -> _);
+    static CLASS: StaticClass = StaticClass::new();
+
+    CLASS.get_or_register(|| {
+        load_or_register_class("NSView", "RSTTableViewRow", |decl| unsafe {
+            decl.add_method(sel!(isFlipped), enforce_normalcy as extern "C" fn(_, _) -> _);
+        })
     })
 }
 
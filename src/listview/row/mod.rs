@@ -55,49 +55,96 @@ use crate::layer::Layer;
 use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
 use crate::utils::properties::ObjcProperty;
-#[cfg(all(feature = "appkit", target_os = "macos"))]
-use crate::view::{ViewAnimatorProxy, ViewDelegate};
+use crate::view::ViewDelegate;
+
+#[cfg(all(appkit, target_os = "macos"))]
+use crate::view::ViewAnimatorProxy;
+
+#[cfg(uikit)]
+use crate::foundation::NSInteger;
 
 #[cfg(feature = "autolayout")]
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY, SafeAreaLayoutGuide};
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 mod appkit;
 
-#[cfg(feature = "appkit")]
-use appkit::{register_listview_row_class, register_listview_row_class_with_delegate};
+#[cfg(appkit)]
+pub(crate) use appkit::register_listview_row_class;
+#[cfg(appkit)]
+use appkit::register_listview_row_class_with_delegate;
 
-//#[cfg(feature = "uikit")]
-//mod ios;
+#[cfg(uikit)]
+mod uikit;
 
-//#[cfg(feature = "uikit")]
-//use ios::{register_listview_row_view_class, register_listview_row_class_with_delegate};
+#[cfg(uikit)]
+pub(crate) use uikit::register_listview_row_class;
+#[cfg(uikit)]
+use uikit::register_listview_row_class_with_delegate;
 
 pub(crate) static BACKGROUND_COLOR: &str = "cacaoBackgroundColor";
 pub(crate) static LISTVIEW_ROW_DELEGATE_PTR: &str = "cacaoListViewRowDelegatePtr";
 
 /// A helper method for instantiating view classes and applying default settings to them.
-fn allocate_view(registration_fn: fn() -> &'static Class) -> id {
+///
+/// UIKit only reuses cells that were created with a reuse identifier, so it's passed in here
+/// rather than set afterwards.
+fn allocate_view(registration_fn: fn() -> &'static Class, identifier: Option<&'static str>) -> id {
     unsafe {
+        #[cfg(appkit)]
         let view: id = msg_send![registration_fn(), new];
 
-        #[cfg(feature = "autolayout")]
+        #[cfg(appkit)]
+        if let Some(identifier) = identifier {
+            let identifier = NSString::new(identifier);
+            let _: () = msg_send![view, setIdentifier:&*identifier];
+        }
+
+        // UITableViewCellStyleDefault; the row lays out its own content.
+        #[cfg(uikit)]
+        let view: id = {
+            let style: NSInteger = 0;
+            let cell: id = msg_send![registration_fn(), alloc];
+
+            match identifier {
+                Some(identifier) => {
+                    let identifier = NSString::new(identifier);
+                    msg_send![cell, initWithStyle: style, reuseIdentifier:&*identifier]
+                },
+
+                None => msg_send![cell, initWithStyle: style, reuseIdentifier: nil]
+            }
+        };
+
+        // The table view sizes and positions cells itself in UIKit.
+        #[cfg(all(appkit, feature = "autolayout"))]
         let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let _: () = msg_send![view, setWantsLayer: YES];
 
         view
     }
 }
 
+/// Returns the view that row content goes in: the row itself in AppKit, and the cell's
+/// `contentView` in UIKit.
+#[cfg(any(uikit, feature = "autolayout"))]
+fn content_view(view: id) -> id {
+    #[cfg(appkit)]
+    return view;
+
+    #[cfg(uikit)]
+    return unsafe { msg_send![view, contentView] };
+}
+
 /// A clone-able handler to a `ViewController` reference in the Objective C runtime. We use this
 /// instead of a stock `View` for easier recordkeeping, since it'll need to hold the `View` on that
 /// side anyway.
 #[derive(Debug)]
 pub struct ListViewRow<T = ()> {
     /// An object that supports limited animations. Can be cloned into animation closures.
-    #[cfg(all(feature = "appkit", target_os = "macos"))]
+    #[cfg(all(appkit, target_os = "macos"))]
     pub animator: ViewAnimatorProxy,
 
     /// A pointer to the Objective-C runtime view controller.
@@ -160,46 +207,49 @@ impl Default for ListViewRow {
 impl ListViewRow {
     /// Returns a default `View`, suitable for
     pub fn new() -> Self {
-        let view = allocate_view(register_listview_row_class);
+        let view = allocate_view(register_listview_row_class, None);
+
+        #[cfg(feature = "autolayout")]
+        let content = content_view(view);
 
         ListViewRow {
             delegate: None,
             objc: ObjcProperty::retain(view),
-            #[cfg(all(feature = "appkit", target_os = "macos"))]
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: ViewAnimatorProxy::new(view),
 
             #[cfg(feature = "autolayout")]
-            safe_layout_guide: SafeAreaLayoutGuide::new(view),
+            safe_layout_guide: SafeAreaLayoutGuide::new(content),
 
             #[cfg(feature = "autolayout")]
-            top: LayoutAnchorY::top(view),
+            top: LayoutAnchorY::top(content),
 
             #[cfg(feature = "autolayout")]
-            left: LayoutAnchorX::left(view),
+            left: LayoutAnchorX::left(content),
 
             #[cfg(feature = "autolayout")]
-            leading: LayoutAnchorX::leading(view),
+            leading: LayoutAnchorX::leading(content),
 
             #[cfg(feature = "autolayout")]
-            right: LayoutAnchorX::right(view),
+            right: LayoutAnchorX::right(content),
 
             #[cfg(feature = "autolayout")]
-            trailing: LayoutAnchorX::trailing(view),
+            trailing: LayoutAnchorX::trailing(content),
 
             #[cfg(feature = "autolayout")]
-            bottom: LayoutAnchorY::bottom(view),
+            bottom: LayoutAnchorY::bottom(content),
 
             #[cfg(feature = "autolayout")]
-            width: LayoutAnchorDimension::width(view),
+            width: LayoutAnchorDimension::width(content),
 
             #[cfg(feature = "autolayout")]
-            height: LayoutAnchorDimension::height(view),
+            height: LayoutAnchorDimension::height(content),
 
             #[cfg(feature = "autolayout")]
-            center_x: LayoutAnchorX::center(view),
+            center_x: LayoutAnchorX::center(content),
 
             #[cfg(feature = "autolayout")]
-            center_y: LayoutAnchorY::center(view)
+            center_y: LayoutAnchorY::center(content)
         }
     }
 }
@@ -227,44 +277,47 @@ where
             //&*obj
         };
 
+        #[cfg(feature = "autolayout")]
+        let content = content_view(view);
+
         let view = ListViewRow {
             delegate: Some(delegate),
             objc: ObjcProperty::retain(view),
-            #[cfg(all(feature = "appkit", target_os = "macos"))]
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: ViewAnimatorProxy::new(view),
 
             #[cfg(feature = "autolayout")]
-            safe_layout_guide: SafeAreaLayoutGuide::new(view),
+            safe_layout_guide: SafeAreaLayoutGuide::new(content),
 
             #[cfg(feature = "autolayout")]
-            top: LayoutAnchorY::top(view),
+            top: LayoutAnchorY::top(content),
 
             #[cfg(feature = "autolayout")]
-            left: LayoutAnchorX::left(view),
+            left: LayoutAnchorX::left(content),
 
             #[cfg(feature = "autolayout")]
-            leading: LayoutAnchorX::leading(view),
+            leading: LayoutAnchorX::leading(content),
 
             #[cfg(feature = "autolayout")]
-            right: LayoutAnchorX::right(view),
+            right: LayoutAnchorX::right(content),
 
             #[cfg(feature = "autolayout")]
-            trailing: LayoutAnchorX::trailing(view),
+            trailing: LayoutAnchorX::trailing(content),
 
             #[cfg(feature = "autolayout")]
-            bottom: LayoutAnchorY::bottom(view),
+            bottom: LayoutAnchorY::bottom(content),
 
             #[cfg(feature = "autolayout")]
-            width: LayoutAnchorDimension::width(view),
+            width: LayoutAnchorDimension::width(content),
 
             #[cfg(feature = "autolayout")]
-            height: LayoutAnchorDimension::height(view),
+            height: LayoutAnchorDimension::height(content),
 
             #[cfg(feature = "autolayout")]
-            center_x: LayoutAnchorX::center(view),
+            center_x: LayoutAnchorX::center(content),
 
             #[cfg(feature = "autolayout")]
-            center_y: LayoutAnchorY::center(view)
+            center_y: LayoutAnchorY::center(content)
         };
 
         view
@@ -277,51 +330,64 @@ where
 
     /// Initializes a new View with a given `ViewDelegate`. This enables you to respond to events
     /// and customize the view as a module, similar to class-based systems.
-    pub fn with_boxed(mut delegate: Box<T>) -> ListViewRow<T> {
-        let view = allocate_view(register_listview_row_class_with_delegate::<T>);
+    pub fn with_boxed(delegate: Box<T>) -> ListViewRow<T> {
+        Self::with_boxed_view(delegate, None)
+    }
+
+    /// Like `with_boxed()`, but creates the row with a reuse identifier - which UIKit needs up
+    /// front for the cell to be dequeued later.
+    pub(crate) fn with_boxed_and_identifier(delegate: Box<T>, identifier: &'static str) -> ListViewRow<T> {
+        Self::with_boxed_view(delegate, Some(identifier))
+    }
+
+    fn with_boxed_view(mut delegate: Box<T>, identifier: Option<&'static str>) -> ListViewRow<T> {
+        let view = allocate_view(register_listview_row_class_with_delegate::<T>, identifier);
         unsafe {
             let ptr: *const T = &*delegate;
             (&mut *view).set_ivar(LISTVIEW_ROW_DELEGATE_PTR, ptr as usize);
         };
 
+        #[cfg(feature = "autolayout")]
+        let content = content_view(view);
+
         let mut view = ListViewRow {
             delegate: None,
             objc: ObjcProperty::retain(view),
-            #[cfg(all(feature = "appkit", target_os = "macos"))]
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: ViewAnimatorProxy::new(view),
 
             #[cfg(feature = "autolayout")]
-            safe_layout_guide: SafeAreaLayoutGuide::new(view),
+            safe_layout_guide: SafeAreaLayoutGuide::new(content),
 
             #[cfg(feature = "autolayout")]
-            top: LayoutAnchorY::top(view),
+            top: LayoutAnchorY::top(content),
 
             #[cfg(feature = "autolayout")]
-            left: LayoutAnchorX::left(view),
+            left: LayoutAnchorX::left(content),
 
             #[cfg(feature = "autolayout")]
-            leading: LayoutAnchorX::leading(view),
+            leading: LayoutAnchorX::leading(content),
 
             #[cfg(feature = "autolayout")]
-            right: LayoutAnchorX::right(view),
+            right: LayoutAnchorX::right(content),
 
             #[cfg(feature = "autolayout")]
-            trailing: LayoutAnchorX::trailing(view),
+            trailing: LayoutAnchorX::trailing(content),
 
             #[cfg(feature = "autolayout")]
-            bottom: LayoutAnchorY::bottom(view),
+            bottom: LayoutAnchorY::bottom(content),
 
             #[cfg(feature = "autolayout")]
-            width: LayoutAnchorDimension::width(view),
+            width: LayoutAnchorDimension::width(content),
 
             #[cfg(feature = "autolayout")]
-            height: LayoutAnchorDimension::height(view),
+            height: LayoutAnchorDimension::height(content),
 
             #[cfg(feature = "autolayout")]
-            center_x: LayoutAnchorX::center(view),
+            center_x: LayoutAnchorX::center(content),
 
             #[cfg(feature = "autolayout")]
-            center_y: LayoutAnchorY::center(view)
+            center_y: LayoutAnchorY::center(content)
         };
 
         (&mut delegate).did_load(view.clone_as_handle());
@@ -340,7 +406,7 @@ where
         ListViewRow {
             delegate: None,
             objc: self.objc.clone(),
-            #[cfg(all(feature = "appkit", target_os = "macos"))]
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: self.animator.clone(),
 
             #[cfg(feature = "autolayout")]
@@ -387,10 +453,16 @@ impl<T> ListViewRow<T> {
     pub(crate) fn clone_as_handle(&self) -> crate::view::View {
         crate::view::View {
             delegate: None,
+            delegate_ref: None,
             is_handle: true,
             layer: Layer::new(), // @TODO: Fix & return cloned true layer for this row.
+
+            #[cfg(appkit)]
             objc: self.objc.clone(),
-            #[cfg(all(feature = "appkit", target_os = "macos"))]
+
+            #[cfg(uikit)]
+            objc: ObjcProperty::retain(self.objc.get(|obj| unsafe { msg_send![obj, contentView] })),
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: self.animator.clone(),
 
             #[cfg(feature = "autolayout")]
@@ -429,6 +501,10 @@ impl<T> ListViewRow<T> {
     }
 
     /// Sets the identifier, which enables cells to be reused and dequeued properly.
+    ///
+    /// UIKit cells can't change their reuse identifier after creation; `ListView::dequeue()`
+    /// handles this there.
+    #[cfg(appkit)]
     pub fn set_identifier(&self, identifier: &'static str) {
         let identifier = NSString::new(identifier);
 
@@ -441,20 +517,37 @@ impl<T> ListViewRow<T> {
     pub fn set_background_color<C: AsRef<Color>>(&self, color: C) {
         let color: id = color.as_ref().into();
 
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             // TODO: Fix this unnecessary retain!
             (&mut *obj).set_ivar::<id>(BACKGROUND_COLOR, msg_send![color, retain]);
         });
+
+        #[cfg(uikit)]
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setBackgroundColor: color];
+        });
     }
 }
 
 impl<T> ObjcAccess for ListViewRow<T> {
     fn with_backing_obj_mut<F: Fn(id)>(&self, handler: F) {
+        // In UIKit, subviews and constraints belong in the cell's content view.
+        #[cfg(appkit)]
         self.objc.with_mut(handler);
+
+        #[cfg(uikit)]
+        self.objc.with_mut(|obj| handler(content_view(obj)));
     }
 
     fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
-        self.objc.get(handler)
+        #[cfg(appkit)]
+        return self.objc.get(handler);
+
+        #[cfg(uikit)]
+        return self
+            .objc
+            .get(|obj| handler(unsafe { &*content_view(obj as *const Object as id) }));
     }
 }
 
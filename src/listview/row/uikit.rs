@@ -1,24 +1,43 @@
-use objc::declare::ClassDecl;
-use objc::runtime::{Class, Object, Sel, BOOL};
-use objc::{class, sel};
-use objc::rc::{Id, Owned};
-
-use crate::dragdrop::DragInfo;
-use crate::foundation::{id, NSUInteger, NO, YES};
-use crate::utils::load;
-use crate::view::{ViewDelegate, VIEW_DELEGATE_PTR};
-
-/// Injects an `NSView` subclass. This is used for the default views that don't use delegates - we
-/// have separate classes here since we don't want to waste cycles on methods that will never be
-/// used if there's no delegates.
-pub(crate) fn register_view_class() -> &'static Class {
-    load_or_register_class("UIView", "RSTView", |decl| unsafe {})
+//! Registers the `UITableViewCell` subclasses that back `ListViewRow` on iOS and tvOS. Rows lay
+//! their content out in the cell's `contentView`; the table view takes care of sizing the cell
+//! itself.
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel};
+
+use crate::foundation::{load_or_register_class, StaticClass};
+use crate::listview::row::{ViewDelegate, LISTVIEW_ROW_DELEGATE_PTR};
+
+/// Reusable cells are "forgotten" when they're handed to the table view, so the delegate is
+/// cleaned up here once the backing (cached) cell is deallocated on the Objective-C side.
+extern "C" fn dealloc<T: ViewDelegate>(this: &Object, _: Sel) {
+    // Load the Box pointer here, and just let it drop normally.
+    unsafe {
+        let ptr: usize = *(&*this).get_ivar(LISTVIEW_ROW_DELEGATE_PTR);
+        let obj = ptr as *mut T;
+        let _x = Box::from_raw(obj);
+
+        let _: () = msg_send![super(this, class!(UITableViewCell)), dealloc];
+    }
 }
 
-/// Injects an `NSView` subclass, with some callback and pointer ivars for what we
-/// need to do.
-pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>() -> &'static Class {
-    load_or_register_class("UIView", "RSTViewWithDelegate", |decl| unsafe {
-        decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
+/// Injects a `UITableViewCell` subclass. This is used for the default rows that don't use
+/// delegates.
+pub(crate) fn register_listview_row_class() -> &'static Class {
+    static CLASS: StaticClass = StaticClass::new();
+
+    CLASS.get_or_register(|| load_or_register_class("UITableViewCell", "RSTTableViewRow", |decl| unsafe {}))
+}
+
+/// Injects a `UITableViewCell` subclass, with a pointer ivar for the delegate. This is registered
+/// per delegate type, as `dealloc` needs to know what it's freeing.
+pub(crate) fn register_listview_row_class_with_delegate<T: ViewDelegate>() -> &'static Class {
+    load_or_register_class("UITableViewCell", T::NAME, |decl| unsafe {
+        // A pointer to the "view controller" on the Rust side. It's expected that this doesn't
+        // move.
+        decl.add_ivar::<usize>(LISTVIEW_ROW_DELEGATE_PTR);
+
+        // Cleanup
+        decl.add_method(sel!(dealloc), dealloc::<T> as extern "C" fn(_, _));
     })
 }
@@ -0,0 +1,179 @@
+//! A built-in row for source list sidebars: an icon, a title, and an optional trailing badge
+//! count (like the unread counts in Mail's sidebar).
+//!
+//! ```rust,no_run
+//! use cacao::image::Image;
+//! use cacao::listview::{ListView, ListViewDelegate, ListViewRow, SidebarItem};
+//!
+//! #[derive(Default)]
+//! struct Sidebar {
+//!     view: Option<ListView>
+//! }
+//!
+//! impl ListViewDelegate for Sidebar {
+//!     const NAME: &'static str = "Sidebar";
+//!
+//!     fn did_load(&mut self, view: ListView) {
+//!         self.view = Some(view);
+//!     }
+//!
+//!     fn number_of_items(&self) -> usize {
+//!         1
+//!     }
+//!
+//!     fn item_for(&self, _row: usize) -> ListViewRow {
+//!         let item = SidebarItem::new("Inbox").badge(3);
+//!         self.view.as_ref().unwrap().sidebar_row(&item)
+//!     }
+//! }
+//! ```
+
+use crate::color::Color;
+use crate::image::{Image, ImageView};
+use crate::layout::{Layout, LayoutConstraint};
+use crate::listview::{ListView, ListViewRow};
+use crate::text::{Font, Label, LineBreakMode, TextAlign};
+use crate::view::{View, ViewDelegate};
+
+/// The identifier `SidebarRow`s are registered and dequeued under.
+pub(crate) const SIDEBAR_ROW: &str = "RSTSidebarRow";
+
+/// Describes what a `SidebarRow` shows. Pass one to `ListView::sidebar_row()`.
+#[derive(Clone, Debug, Default)]
+pub struct SidebarItem {
+    /// The icon shown before the title, if any. Symbol and template images pick up the
+    /// sidebar's tint.
+    pub icon: Option<Image>,
+
+    /// The title.
+    pub title: String,
+
+    /// The count shown at the trailing edge. `None` (or zero) hides the badge.
+    pub badge: Option<usize>
+}
+
+impl SidebarItem {
+    /// Creates an item with just a title.
+    pub fn new<S: Into<String>>(title: S) -> Self {
+        SidebarItem {
+            icon: None,
+            title: title.into(),
+            badge: None
+        }
+    }
+
+    /// Sets the icon shown before the title.
+    pub fn icon(mut self, icon: Image) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the count shown at the trailing edge.
+    pub fn badge(mut self, count: usize) -> Self {
+        self.badge = Some(count);
+        self
+    }
+}
+
+/// A row view with an icon, a title and a trailing badge count, laid out the way source list
+/// sidebars usually are. You'll generally want `ListView::sidebar_row()` rather than using this
+/// directly.
+#[derive(Debug, Default)]
+pub struct SidebarRow {
+    /// The icon, shown at the leading edge.
+    pub icon: ImageView,
+
+    /// The title label.
+    pub title: Label,
+
+    /// The rounded background behind the badge count.
+    pub badge: View,
+
+    /// The badge count label.
+    pub count: Label
+}
+
+impl SidebarRow {
+    /// Updates this row to show `item`.
+    pub fn configure_with(&mut self, item: &SidebarItem) {
+        match &item.icon {
+            Some(icon) => {
+                self.icon.set_image(icon);
+                self.icon.set_hidden(false);
+            },
+
+            None => self.icon.set_hidden(true)
+        }
+
+        self.title.set_text(&item.title);
+
+        match item.badge {
+            Some(count) if count > 0 => {
+                self.count.set_text(count.to_string());
+                self.badge.set_hidden(false);
+            },
+
+            _ => self.badge.set_hidden(true)
+        }
+    }
+}
+
+impl ViewDelegate for SidebarRow {
+    const NAME: &'static str = "RSTSidebarRow";
+
+    fn did_load(&mut self, view: View) {
+        view.add_subview(&self.icon);
+        view.add_subview(&self.title);
+        view.add_subview(&self.badge);
+        self.badge.add_subview(&self.count);
+
+        self.title.set_line_break_mode(LineBreakMode::TruncateTail);
+
+        self.badge.set_background_color(Color::SystemFill);
+        self.badge.layer.set_corner_radius(8.);
+        self.count.set_font(Font::bold_system(11.));
+        self.count.set_text_color(Color::LabelSecondary);
+        self.count.set_text_alignment(TextAlign::Center);
+
+        LayoutConstraint::activate(&[
+            self.icon.leading.constraint_equal_to(&view.leading).offset(4.),
+            self.icon.center_y.constraint_equal_to(&view.center_y),
+            self.icon.width.constraint_equal_to_constant(18.),
+            self.icon.height.constraint_equal_to_constant(18.),
+            self.title.leading.constraint_equal_to(&self.icon.trailing).offset(6.),
+            self.title.center_y.constraint_equal_to(&view.center_y),
+            self.title
+                .trailing
+                .constraint_less_than_or_equal_to(&self.badge.leading)
+                .offset(-6.),
+            self.badge.trailing.constraint_equal_to(&view.trailing).offset(-4.),
+            self.badge.center_y.constraint_equal_to(&view.center_y),
+            self.badge.height.constraint_equal_to_constant(16.),
+            self.badge.width.constraint_greater_than_or_equal_to_constant(20.),
+            self.count.leading.constraint_equal_to(&self.badge.leading).offset(6.),
+            self.count.trailing.constraint_equal_to(&self.badge.trailing).offset(-6.),
+            self.count.center_y.constraint_equal_to(&self.badge.center_y)
+        ]);
+    }
+}
+
+impl ListView {
+    /// Dequeues a built-in `SidebarRow` and configures it with `item`. Return the result from
+    /// `ListViewDelegate::item_for()` for sidebars that don't need fully custom rows.
+    ///
+    /// The row type is registered the first time this is called, so there's no need to call
+    /// `register()` for it.
+    pub fn sidebar_row(&self, item: &SidebarItem) -> ListViewRow {
+        if !self.cell_factory.contains(SIDEBAR_ROW) {
+            self.register(SIDEBAR_ROW, SidebarRow::default);
+        }
+
+        let mut row = self.dequeue::<SidebarRow>(SIDEBAR_ROW);
+
+        if let Some(view) = &mut row.delegate {
+            view.configure_with(item);
+        }
+
+        row.into_row()
+    }
+}
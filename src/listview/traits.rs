@@ -1,11 +1,18 @@
 //! Various traits used for Views.
 
-use crate::appkit::menu::MenuItem;
-use crate::dragdrop::{DragInfo, DragOperation};
 use crate::layout::Layout;
-use crate::listview::{ListView, ListViewRow, RowAction, RowEdge};
+use crate::listview::{ListView, ListViewRow};
 use crate::view::View;
 
+#[cfg(appkit)]
+use crate::appkit::menu::MenuItem;
+
+#[cfg(appkit)]
+use crate::dragdrop::{DragInfo, DragOperation};
+
+#[cfg(appkit)]
+use crate::listview::{RowAction, RowEdge};
+
 #[allow(unused_variables)]
 pub trait ListViewDelegate {
     /// Used to cache subclass creations on the Objective-C side.
@@ -43,12 +50,19 @@ pub trait ListViewDelegate {
     /// Called when the menu for the tableview is about to be shown. You can update the menu here
     /// depending on, say, what the user has context-clicked on. You should avoid any expensive
     /// work in here and return the menu as fast as possible.
+    #[cfg(appkit)]
     fn context_menu(&self) -> Vec<MenuItem> {
         vec![]
     }
 
     /// An optional delegate method; implement this if you'd like swipe-to-reveal to be
     /// supported for a given row by returning a vector of actions to show.
+    ///
+    /// This is called separately for each `RowEdge`, so you can offer different actions when
+    /// swiping from the leading and trailing edges. Whether swiping all the way across the row
+    /// performs an action is up to AppKit (it uses the first action in the list); there's no API
+    /// to change it.
+    #[cfg(appkit)]
     fn actions_for(&self, row: usize, edge: RowEdge) -> Vec<RowAction> {
         Vec::new()
     }
@@ -66,24 +80,29 @@ pub trait ListViewDelegate {
     fn did_disappear(&self, animated: bool) {}
 
     /// Invoked when the dragged image enters destination bounds or frame; returns dragging operation to perform.
+    #[cfg(appkit)]
     fn dragging_entered(&self, info: DragInfo) -> DragOperation {
         DragOperation::None
     }
 
     /// Invoked when the image is released, allowing the receiver to agree to or refuse drag operation.
+    #[cfg(appkit)]
     fn prepare_for_drag_operation(&self, info: DragInfo) -> bool {
         false
     }
 
     /// Invoked after the released image has been removed from the screen, signaling the receiver to import the pasteboard data.
+    #[cfg(appkit)]
     fn perform_drag_operation(&self, info: DragInfo) -> bool {
         false
     }
 
     /// Invoked when the dragging operation is complete, signaling the receiver to perform any necessary clean-up.
+    #[cfg(appkit)]
     fn conclude_drag_operation(&self, info: DragInfo) {}
 
     /// Invoked when the dragged image exits the destination’s bounds rectangle (in the case of a view) or its frame
     /// rectangle (in the case of a window object).
+    #[cfg(appkit)]
     fn dragging_exited(&self, info: DragInfo) {}
 }
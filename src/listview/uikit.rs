@@ -0,0 +1,110 @@
+//! Registers the `UITableView` subclass that backs `ListView` on iOS and tvOS. The table is its
+//! own data source and delegate, forwarding to the `ListViewDelegate` on the Rust side - much as
+//! the `NSTableView` subclass does on macOS.
+//!
+//! `UITableView` works in sections and index paths; `ListView` is a flat list, so everything
+//! here lives in section `0` and only the row matters.
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel};
+
+use crate::foundation::{id, load_or_register_class, nil, NSInteger, StaticClass};
+use crate::listview::{ListViewDelegate, LISTVIEW_DELEGATE_PTR};
+use crate::utils::{load_weak, release_weak};
+
+/// Returns the row for an `NSIndexPath`.
+fn row(index_path: id) -> usize {
+    let row: NSInteger = unsafe { msg_send![index_path, row] };
+    row as usize
+}
+
+/// Determines the number of items by way of the backing data source (the Rust struct).
+extern "C" fn number_of_items<T: ListViewDelegate>(this: &Object, _: Sel, _: id, _section: NSInteger) -> NSInteger {
+    match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view.number_of_items() as NSInteger,
+        None => 0
+    }
+}
+
+/// Returns the cell for a row. As on macOS, the table retains the cell, so we hand back the
+/// pointer without transferring ownership.
+extern "C" fn cell_for_row<T: ListViewDelegate>(this: &Object, _: Sel, _: id, index_path: id) -> id {
+    let view = match load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return nil
+    };
+
+    let item = view.item_for(row(index_path));
+    item.objc.get(|obj| unsafe { msg_send![obj, self] })
+}
+
+extern "C" fn will_display_cell<T: ListViewDelegate>(this: &Object, _: Sel, _: id, _cell: id, index_path: id) {
+    if let Some(view) = load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        view.will_display_item(row(index_path));
+    }
+}
+
+extern "C" fn did_select_row<T: ListViewDelegate>(this: &Object, _: Sel, _: id, index_path: id) {
+    if let Some(view) = load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        view.item_selected(Some(row(index_path)));
+    }
+}
+
+/// UIKit deselects the old row before selecting a new one; this only reports a cleared selection
+/// if nothing else ends up selected.
+extern "C" fn did_deselect_row<T: ListViewDelegate>(this: &Object, _: Sel, table_view: id, _: id) {
+    let selected: id = unsafe { msg_send![table_view, indexPathForSelectedRow] };
+
+    if selected != nil {
+        return;
+    }
+
+    if let Some(view) = load_weak::<T>(this, LISTVIEW_DELEGATE_PTR) {
+        view.item_selected(None);
+    }
+}
+
+/// Injects a `UITableView` subclass. This is used for list views that don't use delegates.
+pub(crate) fn register_listview_class() -> &'static Class {
+    static CLASS: StaticClass = StaticClass::new();
+
+    CLASS.get_or_register(|| load_or_register_class("UITableView", "RSTListView", |decl| unsafe {}))
+}
+
+/// Releases the weak reference to the delegate, before the list view is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    release_weak(this, LISTVIEW_DELEGATE_PTR);
+
+    unsafe {
+        let _: () = msg_send![super(this, class!(UITableView)), dealloc];
+    }
+}
+
+/// Injects a `UITableView` subclass that acts as its own data source and delegate.
+pub(crate) fn register_listview_class_with_delegate<T: ListViewDelegate>(instance: &T) -> &'static Class {
+    load_or_register_class("UITableView", instance.subclass_name(), |decl| unsafe {
+        decl.add_ivar::<usize>(LISTVIEW_DELEGATE_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
+
+        decl.add_method(
+            sel!(tableView:numberOfRowsInSection:),
+            number_of_items::<T> as extern "C" fn(_, _, _, _) -> _
+        );
+        decl.add_method(
+            sel!(tableView:cellForRowAtIndexPath:),
+            cell_for_row::<T> as extern "C" fn(_, _, _, _) -> _
+        );
+        decl.add_method(
+            sel!(tableView:willDisplayCell:forRowAtIndexPath:),
+            will_display_cell::<T> as extern "C" fn(_, _, _, _, _)
+        );
+        decl.add_method(
+            sel!(tableView:didSelectRowAtIndexPath:),
+            did_select_row::<T> as extern "C" fn(_, _, _, _)
+        );
+        decl.add_method(
+            sel!(tableView:didDeselectRowAtIndexPath:),
+            did_deselect_row::<T> as extern "C" fn(_, _, _, _)
+        );
+    })
+}
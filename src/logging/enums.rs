@@ -0,0 +1,34 @@
+//! Enums used in logging.
+
+/// The level (`os_log_type_t`) of a log message. This determines whether a message is recorded,
+/// and how long it's kept around for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LogLevel {
+    /// The default level. Recorded, and persisted to disk.
+    Default,
+
+    /// Helpful, but not essential, information. Kept in memory, and only persisted if an error
+    /// occurs.
+    Info,
+
+    /// Information useful during development. Not recorded unless enabled.
+    Debug,
+
+    /// An error in the current process.
+    Error,
+
+    /// A bug, or a system-level failure.
+    Fault
+}
+
+impl From<LogLevel> for u8 {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Default => 0x00,
+            LogLevel::Info => 0x01,
+            LogLevel::Debug => 0x02,
+            LogLevel::Error => 0x10,
+            LogLevel::Fault => 0x11
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Wraps `os_log` and `os_signpost`, for structured logging that shows up in Console.app, and for
+//! marking up operations so they can be profiled in Instruments.
+//!
+//! ```rust,no_run
+//! use cacao::logging::{LogLevel, Logger};
+//!
+//! let logger = Logger::new("com.my.app", "networking");
+//! logger.info("Starting sync");
+//!
+//! {
+//!     // Shows up as an interval in the "os_signpost" instrument, ending when dropped.
+//!     let _interval = logger.signpost_interval("Sync", "Fetching records");
+//!     // ...
+//! }
+//!
+//! logger.log(LogLevel::Error, "Sync failed");
+//! ```
+//!
+//! Messages are always logged as public; don't log anything sensitive.
+//!
+//! With the `log-dispatch` feature enabled, cacao logs every callback it dispatches to one of your
+//! delegates at the `Debug` level, under the `com.cacao-rs` subsystem and `dispatch` category.
+//! Enable debug messages in Console.app (or via `log stream --level debug`) to see them.
+
+use std::collections::HashMap;
+use std::ffi::{c_void, CStr, CString};
+use std::fmt;
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+
+mod enums;
+pub use enums::LogLevel;
+
+mod signpost;
+pub use signpost::{SignpostId, SignpostInterval};
+
+/// An opaque `os_log_t`.
+type OsLog = *mut c_void;
+
+extern "C" {
+    static __dso_handle: c_void;
+    static _os_log_default: c_void;
+
+    fn os_log_create(subsystem: *const i8, category: *const i8) -> OsLog;
+    fn os_log_type_enabled(log: OsLog, level: u8) -> bool;
+    fn _os_log_impl(dso: *const c_void, log: OsLog, level: u8, format: *const i8, buf: *mut u8, size: u32);
+}
+
+/// The format string for all messages; the buffer built by `encode_string_argument` supplies the
+/// one argument.
+const FORMAT: &[u8] = b"%{public}s\0";
+
+/// Builds the argument buffer that the `os_log` family of macros would generate for a single
+/// public C string argument.
+pub(crate) fn encode_string_argument(message: &CStr) -> [u8; 12] {
+    let mut buffer = [0u8; 12];
+
+    // Summary: the buffer has non-scalar items, and there's one of them.
+    buffer[0] = 0x02;
+    buffer[1] = 0x01;
+
+    // The item: a public string, passed as a pointer.
+    buffer[2] = 0x22;
+    buffer[3] = 0x08;
+    buffer[4..].copy_from_slice(&(message.as_ptr() as usize as u64).to_ne_bytes());
+
+    buffer
+}
+
+/// Converts a message to a C string, stripping interior NUL bytes rather than failing.
+pub(crate) fn to_cstring(message: &str) -> CString {
+    CString::new(message.replace('\0', "")).unwrap()
+}
+
+lazy_static! {
+    /// Signpost names must outlive the log (Instruments reads them lazily), so we intern them.
+    static ref NAMES: Mutex<HashMap<String, &'static CStr>> = Mutex::new(HashMap::new());
+}
+
+/// Returns a `'static` C string for `name`, allocating it the first time it's seen.
+pub(crate) fn intern(name: &str) -> &'static CStr {
+    let mut names = NAMES.lock().unwrap();
+
+    if let Some(name) = names.get(name) {
+        return name;
+    }
+
+    let interned: &'static CStr = Box::leak(to_cstring(name).into_boxed_c_str());
+    names.insert(name.to_string(), interned);
+    interned
+}
+
+/// A handle to an `os_log` log object, which groups messages under a subsystem (generally your
+/// bundle identifier) and a category (the part of your app they're coming from).
+///
+/// Log objects are thread safe, and cheap to clone.
+#[derive(Clone)]
+pub struct Logger(OsLog);
+
+unsafe impl Send for Logger {}
+unsafe impl Sync for Logger {}
+
+impl fmt::Debug for Logger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Logger").field(&self.0).finish()
+    }
+}
+
+impl Default for Logger {
+    /// Returns the default log object, which has no subsystem or category.
+    fn default() -> Self {
+        Logger(unsafe { &_os_log_default as *const c_void as OsLog })
+    }
+}
+
+impl Logger {
+    /// Creates a log object for the given subsystem and category. The system caches these, so
+    /// calling this repeatedly with the same values is fine.
+    pub fn new(subsystem: &str, category: &str) -> Self {
+        let subsystem = to_cstring(subsystem);
+        let category = to_cstring(category);
+
+        Logger(unsafe { os_log_create(subsystem.as_ptr(), category.as_ptr()) })
+    }
+
+    /// Returns whether messages at `level` are currently being recorded. You can use this to skip
+    /// building expensive messages.
+    pub fn is_enabled(&self, level: LogLevel) -> bool {
+        unsafe { os_log_type_enabled(self.0, level.into()) }
+    }
+
+    /// Logs `message` at the given level.
+    pub fn log(&self, level: LogLevel, message: &str) {
+        let level: u8 = level.into();
+
+        if !unsafe { os_log_type_enabled(self.0, level) } {
+            return;
+        }
+
+        let message = to_cstring(message);
+        let mut buffer = encode_string_argument(&message);
+
+        unsafe {
+            _os_log_impl(
+                &__dso_handle,
+                self.0,
+                level,
+                FORMAT.as_ptr() as *const i8,
+                buffer.as_mut_ptr(),
+                buffer.len() as u32
+            );
+        }
+    }
+
+    /// Logs `message` at the `Debug` level.
+    pub fn debug(&self, message: &str) {
+        self.log(LogLevel::Debug, message);
+    }
+
+    /// Logs `message` at the `Info` level.
+    pub fn info(&self, message: &str) {
+        self.log(LogLevel::Info, message);
+    }
+
+    /// Logs `message` at the `Default` level.
+    pub fn notice(&self, message: &str) {
+        self.log(LogLevel::Default, message);
+    }
+
+    /// Logs `message` at the `Error` level.
+    pub fn error(&self, message: &str) {
+        self.log(LogLevel::Error, message);
+    }
+
+    /// Logs `message` at the `Fault` level.
+    pub fn fault(&self, message: &str) {
+        self.log(LogLevel::Fault, message);
+    }
+
+    /// Returns the underlying `os_log_t`.
+    pub(crate) fn as_ptr(&self) -> OsLog {
+        self.0
+    }
+}
+
+#[cfg(feature = "log-dispatch")]
+lazy_static! {
+    static ref DISPATCH_LOGGER: Logger = Logger::new("com.cacao-rs", "dispatch");
+}
+
+/// Logs a callback being dispatched from an Objective-C object to a Rust delegate.
+#[cfg(feature = "log-dispatch")]
+pub(crate) fn log_dispatch<T>(class: &str) {
+    if DISPATCH_LOGGER.is_enabled(LogLevel::Debug) {
+        DISPATCH_LOGGER.debug(&format!("{} -> {}", class, std::any::type_name::<T>()));
+    }
+}
@@ -0,0 +1,131 @@
+//! Signposts, for marking up events and intervals that show up in Instruments.
+
+use std::ffi::c_void;
+
+use super::{encode_string_argument, intern, to_cstring, Logger, FORMAT};
+
+extern "C" {
+    static __dso_handle: c_void;
+
+    fn os_signpost_enabled(log: *mut c_void) -> bool;
+    fn os_signpost_id_generate(log: *mut c_void) -> u64;
+    fn _os_signpost_emit_with_name_impl(
+        dso: *const c_void,
+        log: *mut c_void,
+        kind: u8,
+        id: u64,
+        name: *const i8,
+        format: *const i8,
+        buf: *mut u8,
+        size: u32
+    );
+}
+
+/// `OS_SIGNPOST_EVENT`.
+const EVENT: u8 = 0;
+
+/// `OS_SIGNPOST_INTERVAL_BEGIN`.
+const INTERVAL_BEGIN: u8 = 1;
+
+/// `OS_SIGNPOST_INTERVAL_END`.
+const INTERVAL_END: u8 = 2;
+
+/// Identifies a signpost, so that the beginning and end of an interval can be matched up (even if
+/// several intervals with the same name overlap).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SignpostId(pub u64);
+
+impl SignpostId {
+    /// `OS_SIGNPOST_ID_EXCLUSIVE`, for signposts that never overlap with others of the same name.
+    pub const EXCLUSIVE: SignpostId = SignpostId(0xEEEEB0B5B2B2EEEE);
+
+    /// Generates a new identifier, unique within the given log.
+    pub fn generate(logger: &Logger) -> Self {
+        SignpostId(unsafe { os_signpost_id_generate(logger.as_ptr()) })
+    }
+}
+
+/// Emits a signpost of the given kind.
+fn emit(logger: &Logger, kind: u8, id: SignpostId, name: &str, message: &str) {
+    if !unsafe { os_signpost_enabled(logger.as_ptr()) } {
+        return;
+    }
+
+    let name = intern(name);
+    let message = to_cstring(message);
+    let mut buffer = encode_string_argument(&message);
+
+    unsafe {
+        _os_signpost_emit_with_name_impl(
+            &__dso_handle,
+            logger.as_ptr(),
+            kind,
+            id.0,
+            name.as_ptr(),
+            FORMAT.as_ptr() as *const i8,
+            buffer.as_mut_ptr(),
+            buffer.len() as u32
+        );
+    }
+}
+
+impl Logger {
+    /// Returns whether signposts are currently being recorded (e.g, Instruments is attached).
+    pub fn signposts_enabled(&self) -> bool {
+        unsafe { os_signpost_enabled(self.as_ptr()) }
+    }
+
+    /// Emits a single point-in-time signpost.
+    pub fn signpost_event(&self, name: &str, message: &str) {
+        emit(self, EVENT, SignpostId::EXCLUSIVE, name, message);
+    }
+
+    /// Begins a signpost interval, which ends when the returned `SignpostInterval` is dropped (or
+    /// `end()` is called on it).
+    pub fn signpost_interval(&self, name: &str, message: &str) -> SignpostInterval {
+        let id = SignpostId::generate(self);
+        emit(self, INTERVAL_BEGIN, id, name, message);
+
+        SignpostInterval {
+            logger: self.clone(),
+            id,
+            name: name.to_string(),
+            ended: false
+        }
+    }
+}
+
+/// An in-progress signpost interval.
+#[derive(Debug)]
+pub struct SignpostInterval {
+    logger: Logger,
+    id: SignpostId,
+    name: String,
+    ended: bool
+}
+
+impl SignpostInterval {
+    /// Returns the identifier of this interval.
+    pub fn id(&self) -> SignpostId {
+        self.id
+    }
+
+    /// Emits an event within this interval.
+    pub fn event(&self, message: &str) {
+        emit(&self.logger, EVENT, self.id, &self.name, message);
+    }
+
+    /// Ends the interval, with a message.
+    pub fn end(mut self, message: &str) {
+        self.ended = true;
+        emit(&self.logger, INTERVAL_END, self.id, &self.name, message);
+    }
+}
+
+impl Drop for SignpostInterval {
+    fn drop(&mut self) {
+        if !self.ended {
+            emit(&self.logger, INTERVAL_END, self.id, &self.name, "");
+        }
+    }
+}
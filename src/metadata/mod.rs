@@ -0,0 +1,418 @@
+//! Wraps `NSMetadataQuery`, for searching files via Spotlight - the foundation for any "search my
+//! documents" feature.
+//!
+//! Queries are live: once the initial results have been gathered, you're told about files that
+//! start (or stop) matching as they're created, changed and removed.
+//!
+//! ```rust,no_run
+//! use cacao::metadata::{MetadataQuery, Predicate, SearchScope};
+//!
+//! let mut query = MetadataQuery::new(Predicate::name_matches("*.md"));
+//! query.set_scopes(&[SearchScope::Home]);
+//!
+//! query.on_finish_gathering(|items| {
+//!     for item in items {
+//!         println!("{:?}", item.path());
+//!     }
+//! });
+//!
+//! query.on_update(|update| {
+//!     println!("{} added, {} removed", update.added.len(), update.removed.len());
+//! });
+//!
+//! query.start();
+//! ```
+//!
+//! Queries deliver results on the thread they were started on, which needs a running run loop;
+//! in practice, start them from the main thread. Results stop once the `MetadataQuery` is dropped.
+//!
+//! This is macOS-only: iOS only supports searching iCloud containers, and tvOS has no
+//! `NSMetadataQuery` at all.
+
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use block::ConcreteBlock;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, nil, to_bool, to_system_time, NSArray, NSString, NSUInteger, BOOL, NO, YES};
+
+mod predicate;
+pub use predicate::Predicate;
+
+extern "C" {
+    static NSMetadataQueryUserHomeScope: id;
+    static NSMetadataQueryLocalComputerScope: id;
+    static NSMetadataQueryNetworkScope: id;
+    static NSMetadataQueryUbiquitousDocumentsScope: id;
+    static NSMetadataQueryUbiquitousDataScope: id;
+
+    static NSMetadataQueryUpdateAddedItemsKey: id;
+    static NSMetadataQueryUpdateChangedItemsKey: id;
+    static NSMetadataQueryUpdateRemovedItemsKey: id;
+
+    static NSMetadataUbiquitousItemDownloadingStatusNotDownloaded: id;
+    static NSMetadataUbiquitousItemDownloadingStatusDownloaded: id;
+    static NSMetadataUbiquitousItemDownloadingStatusCurrent: id;
+}
+
+/// Whether an iCloud file is available on this device (see `MetadataItem::download_status()`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DownloadStatus {
+    /// Only a placeholder is on this device; call `FileManager::start_downloading()` to fetch it.
+    NotDownloaded,
+
+    /// A local copy is available, but there's a newer version in iCloud.
+    Downloaded,
+
+    /// The local copy is the latest version.
+    Current
+}
+
+/// Where a `MetadataQuery` looks for files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SearchScope {
+    /// The user's home directory.
+    Home,
+
+    /// All local volumes.
+    LocalComputer,
+
+    /// Mounted network volumes.
+    Network,
+
+    /// The `Documents` folder of the app's iCloud containers.
+    UbiquitousDocuments,
+
+    /// Everything in the app's iCloud containers, outside of `Documents`.
+    UbiquitousData,
+
+    /// A specific directory (and everything inside it).
+    Directory(PathBuf)
+}
+
+impl SearchScope {
+    /// Returns the scope as an (autoreleased, or static) object for `setSearchScopes:`.
+    fn to_objc(&self) -> id {
+        unsafe {
+            match self {
+                SearchScope::Home => NSMetadataQueryUserHomeScope,
+                SearchScope::LocalComputer => NSMetadataQueryLocalComputerScope,
+                SearchScope::Network => NSMetadataQueryNetworkScope,
+                SearchScope::UbiquitousDocuments => NSMetadataQueryUbiquitousDocumentsScope,
+                SearchScope::UbiquitousData => NSMetadataQueryUbiquitousDataScope,
+
+                SearchScope::Directory(path) => {
+                    let path = NSString::new(&path.to_string_lossy());
+                    msg_send![class!(NSURL), fileURLWithPath:&*path]
+                }
+            }
+        }
+    }
+}
+
+/// A file found by a `MetadataQuery`.
+#[derive(Clone, Debug)]
+pub struct MetadataItem(pub Id<Object, Shared>);
+
+impl MetadataItem {
+    /// Wraps (and retains) an `NSMetadataItem`.
+    fn retain(item: id) -> Self {
+        MetadataItem(unsafe { Id::retain(item).unwrap() })
+    }
+
+    /// Returns the file's path.
+    pub fn path(&self) -> Option<PathBuf> {
+        self.string("kMDItemPath").map(PathBuf::from)
+    }
+
+    /// Returns the file's name, as it should be shown to the user (e.g, localized, and without a
+    /// hidden extension).
+    pub fn display_name(&self) -> Option<String> {
+        self.string("kMDItemDisplayName")
+    }
+
+    /// Returns the file's uniform type identifier (e.g, `"public.png"`).
+    pub fn content_type(&self) -> Option<String> {
+        self.string("kMDItemContentType")
+    }
+
+    /// Returns when the file's contents were last modified.
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.value("kMDItemFSContentChangeDate").map(to_system_time)
+    }
+
+    /// Returns when the file was created.
+    pub fn created(&self) -> Option<SystemTime> {
+        self.value("kMDItemFSCreationDate").map(to_system_time)
+    }
+
+    /// Returns the file's size, in bytes.
+    pub fn size(&self) -> Option<u64> {
+        self.value("kMDItemFSSize")
+            .map(|size| unsafe { msg_send![size, unsignedLongLongValue] })
+    }
+
+    /// For files in iCloud (see `SearchScope::UbiquitousDocuments`), returns whether the file is
+    /// available on this device.
+    pub fn download_status(&self) -> Option<DownloadStatus> {
+        let status = self.value("NSMetadataUbiquitousItemDownloadingStatusKey")?;
+
+        unsafe {
+            let is = |constant: id| -> bool { to_bool(msg_send![status, isEqualToString: constant]) };
+
+            if is(NSMetadataUbiquitousItemDownloadingStatusCurrent) {
+                Some(DownloadStatus::Current)
+            } else if is(NSMetadataUbiquitousItemDownloadingStatusDownloaded) {
+                Some(DownloadStatus::Downloaded)
+            } else if is(NSMetadataUbiquitousItemDownloadingStatusNotDownloaded) {
+                Some(DownloadStatus::NotDownloaded)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// For files in iCloud, returns whether the file is being downloaded.
+    pub fn is_downloading(&self) -> bool {
+        self.flag("NSMetadataUbiquitousItemIsDownloadingKey")
+    }
+
+    /// For files in iCloud, returns whether the file is being uploaded.
+    pub fn is_uploading(&self) -> bool {
+        self.flag("NSMetadataUbiquitousItemIsUploadingKey")
+    }
+
+    /// For files in iCloud, returns whether the file's latest changes have been uploaded.
+    pub fn is_uploaded(&self) -> bool {
+        self.flag("NSMetadataUbiquitousItemIsUploadedKey")
+    }
+
+    /// For files in iCloud that are downloading, returns how far along it is (from 0 to 100).
+    pub fn percent_downloaded(&self) -> Option<f64> {
+        self.value("NSMetadataUbiquitousItemPercentDownloadedKey")
+            .map(|percent| unsafe { msg_send![percent, doubleValue] })
+    }
+
+    /// For files in iCloud that are uploading, returns how far along it is (from 0 to 100).
+    pub fn percent_uploaded(&self) -> Option<f64> {
+        self.value("NSMetadataUbiquitousItemPercentUploadedKey")
+            .map(|percent| unsafe { msg_send![percent, doubleValue] })
+    }
+
+    /// Returns the raw value of `attribute` (e.g, `"kMDItemAuthors"`), for anything not covered
+    /// above.
+    pub fn value(&self, attribute: &str) -> Option<id> {
+        let attribute = NSString::new(attribute);
+        let value: id = unsafe { msg_send![&*self.0, valueForAttribute:&*attribute] };
+
+        match value == nil {
+            true => None,
+            false => Some(value)
+        }
+    }
+
+    /// Returns the value of a boolean `attribute`, treating a missing one as `false`.
+    fn flag(&self, attribute: &str) -> bool {
+        self.value(attribute)
+            .map(|value| to_bool(unsafe { msg_send![value, boolValue] }))
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of a string `attribute`.
+    fn string(&self, attribute: &str) -> Option<String> {
+        self.value(attribute).map(|value| NSString::retain(value).to_string())
+    }
+}
+
+/// The files that started or stopped matching a `MetadataQuery` (or that changed while matching
+/// it), since the last update.
+#[derive(Clone, Debug, Default)]
+pub struct MetadataQueryUpdate {
+    /// Files that now match.
+    pub added: Vec<MetadataItem>,
+
+    /// Files that still match, but changed.
+    pub changed: Vec<MetadataItem>,
+
+    /// Files that no longer match.
+    pub removed: Vec<MetadataItem>
+}
+
+/// A wrapper for `NSMetadataQuery`, a live Spotlight search.
+#[derive(Debug)]
+pub struct MetadataQuery {
+    /// A pointer to the underlying `NSMetadataQuery`.
+    pub objc: Id<Object, Shared>,
+
+    observers: Vec<Id<Object, Shared>>
+}
+
+impl MetadataQuery {
+    /// Creates a query for files matching `predicate`. It searches every local volume unless you
+    /// call `set_scopes()`, and doesn't do anything until you call `start()`.
+    pub fn new(predicate: Predicate) -> Self {
+        let objc: Id<Object, Shared> = unsafe {
+            let query: Id<Object, Shared> = msg_send_id![class!(NSMetadataQuery), new];
+            let _: () = msg_send![&*query, setPredicate:&*predicate.0];
+            query
+        };
+
+        MetadataQuery {
+            objc,
+            observers: Vec::new()
+        }
+    }
+
+    /// Limits the search to `scopes`.
+    pub fn set_scopes(&self, scopes: &[SearchScope]) {
+        let scopes: Vec<id> = scopes.iter().map(SearchScope::to_objc).collect();
+        let scopes = NSArray::new(&scopes);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setSearchScopes:&*scopes];
+        }
+    }
+
+    /// Sorts results by the value of `attribute` (e.g, `"kMDItemFSContentChangeDate"`).
+    pub fn set_sort_by(&self, attribute: &str, ascending: bool) {
+        let attribute = NSString::new(attribute);
+
+        unsafe {
+            let descriptor: id = msg_send![
+                class!(NSSortDescriptor),
+                sortDescriptorWithKey: &*attribute,
+                ascending: match ascending {
+                    true => YES,
+                    false => NO
+                }
+            ];
+
+            let descriptors = NSArray::new(&[descriptor]);
+            let _: () = msg_send![&*self.objc, setSortDescriptors:&*descriptors];
+        }
+    }
+
+    /// Sets how long (in seconds) updates are batched up for before being delivered. Defaults to
+    /// one second.
+    pub fn set_update_interval(&self, seconds: f64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setNotificationBatchingInterval: seconds];
+        }
+    }
+
+    /// Calls `handler` with every result once the initial search is done.
+    pub fn on_finish_gathering<F: Fn(Vec<MetadataItem>) + 'static>(&mut self, handler: F) {
+        let query = self.objc.clone();
+
+        self.observe("NSMetadataQueryDidFinishGatheringNotification", move |_notification| {
+            handler(results(&query));
+        });
+    }
+
+    /// Calls `handler` whenever the results change, after the initial search is done.
+    pub fn on_update<F: Fn(MetadataQueryUpdate) + 'static>(&mut self, handler: F) {
+        self.observe("NSMetadataQueryDidUpdateNotification", move |notification| unsafe {
+            let user_info: id = msg_send![notification, userInfo];
+
+            handler(MetadataQueryUpdate {
+                added: items_for_key(user_info, NSMetadataQueryUpdateAddedItemsKey),
+                changed: items_for_key(user_info, NSMetadataQueryUpdateChangedItemsKey),
+                removed: items_for_key(user_info, NSMetadataQueryUpdateRemovedItemsKey)
+            });
+        });
+    }
+
+    /// Starts the search. Returns `false` if it couldn't be started (e.g, it's already running).
+    pub fn start(&self) -> bool {
+        let started: BOOL = unsafe { msg_send![&*self.objc, startQuery] };
+        to_bool(started)
+    }
+
+    /// Stops the search, including live updates. The current results remain available.
+    pub fn stop(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, stopQuery];
+        }
+    }
+
+    /// Returns whether the initial search is still in progress.
+    pub fn is_gathering(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isGathering] })
+    }
+
+    /// Returns the current results.
+    pub fn results(&self) -> Vec<MetadataItem> {
+        results(&self.objc)
+    }
+
+    /// Observes notifications named `name` posted by this query.
+    fn observe<F: Fn(id) + 'static>(&mut self, name: &str, handler: F) {
+        let block = ConcreteBlock::new(move |notification: id| {
+            handler(notification);
+        });
+        let block = block.copy();
+
+        let name = NSString::new(name);
+
+        self.observers.push(unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+
+            msg_send_id![
+                center,
+                addObserverForName: &*name,
+                object: &*self.objc,
+                queue: nil,
+                usingBlock: &*block
+            ]
+        });
+    }
+}
+
+impl Drop for MetadataQuery {
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, stopQuery];
+
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+            for observer in self.observers.drain(..) {
+                let _: () = msg_send![center, removeObserver: &*observer];
+            }
+        }
+    }
+}
+
+/// Reads the results out of `query`, pausing updates so they can't change underneath us.
+fn results(query: &Object) -> Vec<MetadataItem> {
+    unsafe {
+        let _: () = msg_send![query, disableUpdates];
+
+        let count: NSUInteger = msg_send![query, resultCount];
+        let items = (0..count)
+            .map(|index| MetadataItem::retain(msg_send![query, resultAtIndex: index]))
+            .collect();
+
+        let _: () = msg_send![query, enableUpdates];
+
+        items
+    }
+}
+
+/// Reads an array of `NSMetadataItem`s out of a notification's `userInfo`.
+fn items_for_key(user_info: id, key: id) -> Vec<MetadataItem> {
+    unsafe {
+        if user_info == nil {
+            return Vec::new();
+        }
+
+        let items: id = msg_send![user_info, objectForKey: key];
+
+        match items == nil {
+            true => Vec::new(),
+            false => NSArray::retain(items).iter().map(MetadataItem::retain).collect()
+        }
+    }
+}
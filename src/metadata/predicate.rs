@@ -0,0 +1,138 @@
+//! Builds the `NSPredicate`s that `MetadataQuery` searches with.
+
+use std::time::SystemTime;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, to_nsdate, NSArray, NSString, NSUInteger};
+
+/// `NSComparisonPredicateOptions`: case and diacritic insensitive (`[cd]`).
+const CASE_AND_DIACRITIC_INSENSITIVE: NSUInteger = 1 | 2;
+
+/// `NSPredicateOperatorType` values.
+const LESS_THAN: NSUInteger = 0;
+const GREATER_THAN: NSUInteger = 2;
+const EQUAL_TO: NSUInteger = 4;
+const LIKE: NSUInteger = 7;
+const CONTAINS: NSUInteger = 99;
+
+/// What to search for with a `MetadataQuery`. Combine these with `and()`, `or()` and `negated()`.
+///
+/// ```rust,no_run
+/// use std::time::{Duration, SystemTime};
+/// use cacao::metadata::Predicate;
+///
+/// let week_ago = SystemTime::now() - Duration::from_secs(7 * 24 * 60 * 60);
+///
+/// let recent_images = Predicate::content_type("public.image")
+///     .and(Predicate::modified_after(week_ago));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Predicate(pub Id<Object, Shared>);
+
+impl Predicate {
+    /// Matches files whose name contains `text`, ignoring case and diacritics.
+    pub fn name_contains(text: &str) -> Self {
+        Predicate::compare("kMDItemFSName", CONTAINS, string(text), CASE_AND_DIACRITIC_INSENSITIVE)
+    }
+
+    /// Matches files whose name matches `pattern`, where `*` matches any run of characters and
+    /// `?` matches a single one (e.g, `"*.txt"`). Case and diacritics are ignored.
+    pub fn name_matches(pattern: &str) -> Self {
+        Predicate::compare("kMDItemFSName", LIKE, string(pattern), CASE_AND_DIACRITIC_INSENSITIVE)
+    }
+
+    /// Matches files whose text content contains `text`, ignoring case and diacritics.
+    pub fn content_contains(text: &str) -> Self {
+        Predicate::compare("kMDItemTextContent", CONTAINS, string(text), CASE_AND_DIACRITIC_INSENSITIVE)
+    }
+
+    /// Matches files that conform to the uniform type identifier `uti` - so `"public.image"`
+    /// matches PNGs, JPEGs and so on.
+    pub fn content_type(uti: &str) -> Self {
+        Predicate::compare("kMDItemContentTypeTree", EQUAL_TO, string(uti), 0)
+    }
+
+    /// Matches files whose contents were modified after `time`.
+    pub fn modified_after(time: SystemTime) -> Self {
+        Predicate::compare("kMDItemFSContentChangeDate", GREATER_THAN, to_nsdate(time), 0)
+    }
+
+    /// Matches files whose contents were modified before `time`.
+    pub fn modified_before(time: SystemTime) -> Self {
+        Predicate::compare("kMDItemFSContentChangeDate", LESS_THAN, to_nsdate(time), 0)
+    }
+
+    /// Matches files created after `time`.
+    pub fn created_after(time: SystemTime) -> Self {
+        Predicate::compare("kMDItemFSCreationDate", GREATER_THAN, to_nsdate(time), 0)
+    }
+
+    /// Matches files created before `time`.
+    pub fn created_before(time: SystemTime) -> Self {
+        Predicate::compare("kMDItemFSCreationDate", LESS_THAN, to_nsdate(time), 0)
+    }
+
+    /// Creates a predicate from a format string, for anything the other constructors don't cover
+    /// (e.g, `"kMDItemAuthors == 'Ryan'"`). There are no arguments to substitute, so write values
+    /// in as literals. Invalid formats (including ones with `%@`-style substitutions) raise an
+    /// Objective-C exception, so don't build these from user input.
+    pub fn with_format(format: &str) -> Self {
+        let format = NSString::new(format);
+        let arguments = NSArray::new(&[]);
+
+        Predicate(unsafe { msg_send_id![class!(NSPredicate), predicateWithFormat:&*format, argumentArray:&*arguments] })
+    }
+
+    /// Matches files that match both this and `other`.
+    pub fn and(self, other: Predicate) -> Self {
+        let subpredicates = NSArray::new(&[self.as_id(), other.as_id()]);
+
+        Predicate(unsafe { msg_send_id![class!(NSCompoundPredicate), andPredicateWithSubpredicates:&*subpredicates] })
+    }
+
+    /// Matches files that match either this or `other`.
+    pub fn or(self, other: Predicate) -> Self {
+        let subpredicates = NSArray::new(&[self.as_id(), other.as_id()]);
+
+        Predicate(unsafe { msg_send_id![class!(NSCompoundPredicate), orPredicateWithSubpredicates:&*subpredicates] })
+    }
+
+    /// Matches files that don't match this.
+    pub fn negated(self) -> Self {
+        Predicate(unsafe { msg_send_id![class!(NSCompoundPredicate), notPredicateWithSubpredicate:&*self.0] })
+    }
+
+    /// Builds `<attribute> <operator> <value>`.
+    fn compare(attribute: &str, operator: NSUInteger, value: id, options: NSUInteger) -> Self {
+        let attribute = NSString::new(attribute);
+
+        Predicate(unsafe {
+            let left: id = msg_send![class!(NSExpression), expressionForKeyPath:&*attribute];
+            let right: id = msg_send![class!(NSExpression), expressionForConstantValue: value];
+
+            msg_send_id![
+                class!(NSComparisonPredicate),
+                predicateWithLeftExpression: left,
+                rightExpression: right,
+                modifier: 0 as NSUInteger,
+                type: operator,
+                options: options,
+            ]
+        })
+    }
+
+    /// Returns the backing predicate as an `id`, for building arrays.
+    fn as_id(&self) -> id {
+        &*self.0 as *const Object as id
+    }
+}
+
+/// Returns an (autoreleased) `NSString` for `value`, which outlives the call it's passed to.
+fn string(value: &str) -> id {
+    let value = NSString::new(value);
+
+    unsafe { msg_send![class!(NSString), stringWithString:&*value] }
+}
@@ -1,10 +1,38 @@
 //! Implements a parent trait for the various sub-traits we use throughout Cacao. The methods
 //! defined on here provide access handlers for common properties that the sub-traits need to
 //! enable modifying.
+//!
+//! This also provides a (slightly) safer escape hatch for one-off calls that cacao doesn't wrap.
+//! Rather than dropping to `msg_send!`, you can use `PerformSelector` (or the shorthand methods on
+//! `ObjcAccess`), which checks that the receiver actually responds to the selector and returns an
+//! `Error` if it doesn't:
+//!
+//! ```rust,no_run
+//! use objc::sel;
+//! use cacao::objc_access::{ObjcAccess, PerformSelector};
+//! use cacao::view::View;
+//!
+//! let view = View::new();
+//!
+//! // Safety: `isOpaque` returns a `BOOL`, and `setNeedsDisplay:` takes one.
+//! unsafe {
+//!     let is_opaque = view.call_returning_bool(sel!(isOpaque)).unwrap();
+//!     view.perform(PerformSelector::new(sel!(setNeedsDisplay:)).with_bool(true)).unwrap();
+//! }
+//! ```
+//!
+//! This can't check that the argument and return types you've asked for match the method's
+//! actual signature, though - that part is still on you, which is why these calls are `unsafe`.
 
-use objc::runtime::Object;
+use std::mem;
 
-use crate::foundation::id;
+use core_graphics::base::CGFloat;
+use objc::ffi;
+use objc::runtime::{Bool, Object, Sel, BOOL};
+
+use crate::error::{Error, CACAO_ERROR_DOMAIN};
+use crate::foundation::{id, NSInteger};
+use crate::utils::responds_to;
 
 /// Types that implement this should provide access to their underlying root node type (e.g, the
 /// view or control). Traits that have this as their super-trait can rely on this to ensure access
@@ -20,4 +48,183 @@ pub trait ObjcAccess {
     ///
     /// Getters should use this.
     fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R;
+
+    /// Returns whether the underlying Objective-C instance responds to `sel`.
+    fn responds_to(&self, sel: Sel) -> bool {
+        self.get_from_backing_obj(|obj| responds_to(obj, sel))
+    }
+
+    /// Sends `request` to the underlying Objective-C instance, ignoring any return value.
+    ///
+    /// # Safety
+    ///
+    /// The receiver's method for the selector must take exactly the argument `request` carries (or
+    /// none, if it carries none), and must return either nothing or a non-struct, non-float value.
+    unsafe fn perform(&self, request: PerformSelector) -> Result<(), Error> {
+        self.get_from_backing_obj(|obj| request.send_to::<()>(obj))
+    }
+
+    /// Sends `sel` to the underlying Objective-C instance, and returns the resulting `BOOL`.
+    ///
+    /// # Safety
+    ///
+    /// The receiver's method for `sel` must take no arguments and return a `BOOL`.
+    unsafe fn call_returning_bool(&self, sel: Sel) -> Result<bool, Error> {
+        self.get_from_backing_obj(|obj| PerformSelector::new(sel).send_to::<Bool>(obj).map(Bool::as_bool))
+    }
+
+    /// Sends `sel` to the underlying Objective-C instance, passing `arg`.
+    ///
+    /// # Safety
+    ///
+    /// The receiver's method for `sel` must take a single object argument, and `arg` must be a
+    /// valid object (or `nil`, if the method accepts it).
+    unsafe fn call_with_id(&self, sel: Sel, arg: id) -> Result<(), Error> {
+        self.perform(PerformSelector::new(sel).with_object(arg))
+    }
+}
+
+/// An argument for a `PerformSelector` call.
+#[derive(Copy, Clone, Debug)]
+enum Argument {
+    Object(id),
+    Bool(bool),
+    Integer(NSInteger),
+    Float(CGFloat)
+}
+
+/// Describes a one-off message to an Objective-C object, for methods that cacao doesn't wrap.
+/// Sending checks `respondsToSelector:` first, and returns an `Error` rather than crashing if the
+/// receiver doesn't implement the method.
+///
+/// Only methods taking zero or one argument are supported.
+#[derive(Copy, Clone, Debug)]
+pub struct PerformSelector {
+    sel: Sel,
+    arg: Option<Argument>
+}
+
+impl PerformSelector {
+    /// Describes a message sending `sel`, with no arguments.
+    pub fn new(sel: Sel) -> Self {
+        PerformSelector { sel, arg: None }
+    }
+
+    /// Passes an object as the argument.
+    pub fn with_object(mut self, arg: id) -> Self {
+        self.arg = Some(Argument::Object(arg));
+        self
+    }
+
+    /// Passes a `BOOL` as the argument.
+    pub fn with_bool(mut self, arg: bool) -> Self {
+        self.arg = Some(Argument::Bool(arg));
+        self
+    }
+
+    /// Passes an `NSInteger` as the argument.
+    pub fn with_integer(mut self, arg: NSInteger) -> Self {
+        self.arg = Some(Argument::Integer(arg));
+        self
+    }
+
+    /// Passes a `CGFloat` as the argument.
+    pub fn with_float(mut self, arg: CGFloat) -> Self {
+        self.arg = Some(Argument::Float(arg));
+        self
+    }
+
+    /// Sends the message to `target`, ignoring any return value.
+    ///
+    /// # Safety
+    ///
+    /// The target's method for the selector must take exactly the argument set on this message (or
+    /// none), and must return either nothing or a non-struct, non-float value.
+    pub unsafe fn send<T: ObjcAccess>(self, target: &T) -> Result<(), Error> {
+        target.perform(self)
+    }
+
+    /// Sends the message to `target`, returning the resulting `BOOL`.
+    ///
+    /// # Safety
+    ///
+    /// The target's method for the selector must take exactly the argument set on this message (or
+    /// none), and must return a `BOOL`.
+    pub unsafe fn send_returning_bool<T: ObjcAccess>(self, target: &T) -> Result<bool, Error> {
+        target.get_from_backing_obj(|obj| self.send_to::<Bool>(obj).map(Bool::as_bool))
+    }
+
+    /// Sends the message to `target`, returning the resulting `NSInteger`.
+    ///
+    /// # Safety
+    ///
+    /// The target's method for the selector must take exactly the argument set on this message (or
+    /// none), and must return an `NSInteger` (or `NSUInteger`).
+    pub unsafe fn send_returning_integer<T: ObjcAccess>(self, target: &T) -> Result<NSInteger, Error> {
+        target.get_from_backing_obj(|obj| self.send_to::<NSInteger>(obj))
+    }
+
+    /// Sends the message to `target`, returning the resulting object. The object is not retained
+    /// for you.
+    ///
+    /// # Safety
+    ///
+    /// The target's method for the selector must take exactly the argument set on this message (or
+    /// none), and must return an object.
+    pub unsafe fn send_returning_id<T: ObjcAccess>(self, target: &T) -> Result<id, Error> {
+        target.get_from_backing_obj(|obj| self.send_to::<id>(obj))
+    }
+
+    /// Checks that `obj` responds to the selector, and sends the message with the return type
+    /// `R`.
+    ///
+    /// # Safety
+    ///
+    /// The argument and `R` must match the method's actual signature, and `R` must not be a struct
+    /// (which would need `objc_msgSend_stret` on some architectures) or a float (which would need
+    /// `objc_msgSend_fpret` on x86).
+    unsafe fn send_to<R>(&self, obj: &Object) -> Result<R, Error> {
+        if !responds_to(obj, self.sel) {
+            return Err(Error::custom(
+                CACAO_ERROR_DOMAIN,
+                0,
+                &format!("{} does not respond to {}", obj.class().name(), self.sel.name())
+            ));
+        }
+
+        let receiver = obj as *const Object as id;
+
+        // `objc_msgSend` has to be cast to the exact signature of the method being called; this is
+        // the same thing `msg_send!` does under the hood, but with a selector chosen at runtime.
+        Ok({
+            let imp: unsafe extern "C" fn() = ffi::objc_msgSend;
+
+            match self.arg {
+                None => {
+                    let send: unsafe extern "C" fn(id, Sel) -> R = mem::transmute(imp);
+                    send(receiver, self.sel)
+                },
+
+                Some(Argument::Object(arg)) => {
+                    let send: unsafe extern "C" fn(id, Sel, id) -> R = mem::transmute(imp);
+                    send(receiver, self.sel, arg)
+                },
+
+                Some(Argument::Bool(arg)) => {
+                    let send: unsafe extern "C" fn(id, Sel, BOOL) -> R = mem::transmute(imp);
+                    send(receiver, self.sel, Bool::new(arg).as_raw())
+                },
+
+                Some(Argument::Integer(arg)) => {
+                    let send: unsafe extern "C" fn(id, Sel, NSInteger) -> R = mem::transmute(imp);
+                    send(receiver, self.sel, arg)
+                },
+
+                Some(Argument::Float(arg)) => {
+                    let send: unsafe extern "C" fn(id, Sel, CGFloat) -> R = mem::transmute(imp);
+                    send(receiver, self.sel, arg)
+                }
+            }
+        })
+    }
 }
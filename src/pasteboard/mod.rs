@@ -12,8 +12,31 @@
 //! // Copy a piece of text to the clipboard
 //! pasteboard.copy_text("My message here");
 //! ```
+//!
+//! ## Observing Changes
+//! There's no notification for pasteboard changes, so (like every clipboard manager) you poll
+//! its change count. `Pasteboard::observe()` does this on a timer, and calls you when something
+//! new has been copied:
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use cacao::pasteboard::Pasteboard;
+//!
+//! let pasteboard = Pasteboard::default();
+//!
+//! // Keep this around for as long as you want the callbacks.
+//! let observer = pasteboard.observe(Duration::from_millis(500), |change| {
+//!     if let Some(text) = change.pasteboard.get_text() {
+//!         println!("Copied {:?} (types: {:?})", text, change.types);
+//!     }
+//! });
+//! ```
 
+use std::cell::Cell;
 use std::path::PathBuf;
+use std::time::Duration;
+
+use block::ConcreteBlock;
 
 use objc::rc::{Id, Shared};
 use objc::runtime::Object;
@@ -21,7 +44,8 @@ use objc::{class, msg_send, msg_send_id, sel};
 use url::Url;
 
 use crate::error::Error;
-use crate::foundation::{id, nil, NSArray, NSString, NSURL};
+use crate::foundation::{id, nil, NSArray, NSInteger, NSString, NSURL, YES};
+use crate::text::AttributedString;
 
 mod types;
 pub use types::{PasteboardName, PasteboardType};
@@ -67,6 +91,103 @@ impl Pasteboard {
         }
     }
 
+    /// Copies rich text to the clipboard, replacing the existing contents. This writes RTF, RTFD
+    /// and plain text representations, so it can be pasted into most other apps.
+    pub fn copy_attributed_string(&self, text: &AttributedString) {
+        unsafe {
+            let objects = NSArray::new(&[&*text.0 as *const Object as id]);
+            let _: () = msg_send![&*self.0, clearContents];
+            let _: () = msg_send![&*self.0, writeObjects: &*objects];
+        }
+    }
+
+    /// Reads rich text off of the pasteboard, if there is any. Plain text is returned as an
+    /// unstyled `AttributedString`.
+    pub fn get_attributed_string(&self) -> Option<AttributedString> {
+        unsafe {
+            let class: id = msg_send![class!(NSAttributedString), class];
+            let classes = NSArray::new(&[class]);
+            let contents: id = msg_send![&*self.0, readObjectsForClasses: &*classes, options: nil];
+
+            if contents == nil {
+                return None;
+            }
+
+            NSArray::retain(contents).iter().next().map(AttributedString::wrap)
+        }
+    }
+
+    /// Reads plain text off of the pasteboard, if there is any.
+    pub fn get_text(&self) -> Option<String> {
+        let ptype: NSString = PasteboardType::String.into();
+        let contents: id = unsafe { msg_send![&*self.0, stringForType: &*ptype] };
+
+        match contents == nil {
+            true => None,
+            false => Some(NSString::retain(contents).to_string())
+        }
+    }
+
+    /// Returns the pasteboard's change count, which goes up whenever its contents change (i.e,
+    /// when something is copied).
+    pub fn change_count(&self) -> NSInteger {
+        unsafe { msg_send![&*self.0, changeCount] }
+    }
+
+    /// Returns the types (uniform type identifiers, e.g `public.utf8-plain-text`) of the data
+    /// currently on the pasteboard.
+    pub fn types(&self) -> Vec<String> {
+        let types: id = unsafe { msg_send![&*self.0, types] };
+
+        match types == nil {
+            true => Vec::new(),
+            false => NSArray::retain(types)
+                .iter()
+                .map(|ptype| NSString::retain(ptype).to_string())
+                .collect()
+        }
+    }
+
+    /// Checks the change count every `interval` (on the main run loop), and calls `handler` when
+    /// the contents have changed since the last check. Changes made before this is called aren't
+    /// reported.
+    ///
+    /// Polling stops when the returned `PasteboardObserver` is dropped.
+    pub fn observe<F>(&self, interval: Duration, handler: F) -> PasteboardObserver
+    where
+        F: Fn(PasteboardChange) + 'static
+    {
+        let pasteboard = self.0.clone();
+        let last_change_count = Cell::new(self.change_count());
+
+        let block = ConcreteBlock::new(move |_timer: id| {
+            let pasteboard = Pasteboard(pasteboard.clone());
+            let change_count = pasteboard.change_count();
+
+            if change_count == last_change_count.get() {
+                return;
+            }
+
+            last_change_count.set(change_count);
+
+            handler(PasteboardChange {
+                change_count,
+                types: pasteboard.types(),
+                pasteboard
+            });
+        });
+        let block = block.copy();
+
+        PasteboardObserver(unsafe {
+            msg_send_id![
+                class!(NSTimer),
+                scheduledTimerWithTimeInterval: interval.as_secs_f64(),
+                repeats: YES,
+                block: &*block
+            ]
+        })
+    }
+
     /// Releases the receiver’s resources in the pasteboard server. It's rare-ish to need to use
     /// this, but considering this stuff happens on the Objective-C side you may need it.
     pub fn release_globally(&self) {
@@ -87,7 +208,7 @@ impl Pasteboard {
     /// _Note that this method returns a list of `Url` entities, in an attempt to be closer to how
     /// Cocoa & co operate. This method may go away in the future if it's determined that people
     /// wind up just using `get_file_paths()`._
-    pub fn get_file_urls(&self) -> Result<Vec<NSURL>, Box<dyn std::error::Error>> {
+    pub fn get_file_urls(&self) -> Result<Vec<NSURL>, Error> {
         unsafe {
             let class: id = msg_send![class!(NSURL), class];
             let classes = NSArray::new(&[class]);
@@ -99,11 +220,11 @@ impl Pasteboard {
                 // This error is not necessarily "correct", but in the event of an error in
                 // Pasteboard server retrieval I'm not sure where to check... and this stuff is
                 // kinda ancient and has conflicting docs in places. ;P
-                return Err(Box::new(Error {
-                    code: 666,
-                    domain: "com.cacao-rs.pasteboard".to_string(),
-                    description: "Pasteboard server returned no data.".to_string()
-                }));
+                return Err(Error::custom(
+                    "com.cacao-rs.pasteboard",
+                    666,
+                    "Pasteboard server returned no data."
+                ));
             }
 
             let urls = NSArray::retain(contents).iter().map(|url| NSURL::retain(url)).collect();
@@ -112,3 +233,28 @@ impl Pasteboard {
         }
     }
 }
+
+/// Describes a change to a pasteboard, as reported by `Pasteboard::observe()`.
+#[derive(Debug)]
+pub struct PasteboardChange {
+    /// The pasteboard that changed, for reading the new contents.
+    pub pasteboard: Pasteboard,
+
+    /// The pasteboard's new change count.
+    pub change_count: NSInteger,
+
+    /// The types of the data now on the pasteboard.
+    pub types: Vec<String>
+}
+
+/// Polls a pasteboard for changes. Dropping this stops polling.
+#[derive(Debug)]
+pub struct PasteboardObserver(Id<Object, Shared>);
+
+impl Drop for PasteboardObserver {
+    fn drop(&mut self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, invalidate];
+        }
+    }
+}
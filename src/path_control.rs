@@ -0,0 +1,346 @@
+//! A wrapper for `NSPathControl`, which shows a file path as a row of clickable breadcrumbs (or,
+//! in the pop up style, as a menu of its parent folders).
+//!
+//! ```rust,no_run
+//! use cacao::foundation::NSURL;
+//! use cacao::path_control::{PathControl, PathControlStyle};
+//!
+//! let mut path = PathControl::new();
+//! path.set_style(PathControlStyle::Standard);
+//! path.set_url(&NSURL::with_path("/Applications"));
+//!
+//! path.set_on_click(|url| {
+//!     println!("Clicked: {:?}", url.map(|url| url.pathbuf()));
+//! });
+//!
+//! // Let the user drop folders onto it.
+//! path.set_on_drop(|url| url.is_directory().unwrap_or(false));
+//! ```
+
+use std::fmt;
+
+use objc::rc::{Id, Owned, Shared};
+use objc::runtime::{Bool, Class, Object, Sel};
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::control::Control;
+use crate::dragdrop::DragOperation;
+use crate::foundation::{id, load_or_register_class, nil, NSInteger, NSUInteger, NO, NSURL, YES};
+use crate::invoker::TargetActionHandler;
+use crate::layout::Layout;
+#[cfg(feature = "autolayout")]
+use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
+use crate::objc_access::ObjcAccess;
+use crate::utils::load;
+use crate::utils::properties::ObjcProperty;
+
+pub(crate) static DROP_HANDLER_PTR: &str = "rstPathControlDropHandlerPtr";
+
+/// How a `PathControl` presents its path.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PathControlStyle {
+    /// Every component is shown inline, as breadcrumbs.
+    Standard,
+
+    /// Only the last component is shown; clicking pops up a menu of the whole path.
+    PopUp
+}
+
+impl From<PathControlStyle> for NSInteger {
+    fn from(style: PathControlStyle) -> Self {
+        match style {
+            PathControlStyle::Standard => 0,
+            PathControlStyle::PopUp => 2
+        }
+    }
+}
+
+/// An indirection layer for drop callbacks, for the same reasons as `invoker::Action`.
+struct DropHandler(Box<dyn Fn(NSURL<'static>) -> bool + 'static>);
+
+impl fmt::Debug for DropHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DropHandler").finish()
+    }
+}
+
+/// A wrapper for `NSPathControl`. Holds (retains) pointers for the Objective-C runtime
+/// where our `NSPathControl` lives.
+#[derive(Debug)]
+pub struct PathControl {
+    /// A pointer to the underlying Objective-C Object.
+    pub objc: ObjcProperty,
+
+    handler: Option<TargetActionHandler>,
+    drop_handler: Option<(Box<DropHandler>, Id<Object, Shared>)>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime left layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub left: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime right layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub right: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for PathControl {
+    fn default() -> Self {
+        PathControl::new()
+    }
+}
+
+impl PathControl {
+    /// Creates a new `NSPathControl` instance, configures it appropriately,
+    /// and retains the necessary Objective-C runtime pointer.
+    pub fn new() -> Self {
+        let view: id = unsafe {
+            let view: id = msg_send![register_class(), new];
+
+            #[cfg(feature = "autolayout")]
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+
+            view
+        };
+
+        let control = PathControl {
+            handler: None,
+            drop_handler: None,
+
+            #[cfg(feature = "autolayout")]
+            top: LayoutAnchorY::top(view),
+
+            #[cfg(feature = "autolayout")]
+            left: LayoutAnchorX::left(view),
+
+            #[cfg(feature = "autolayout")]
+            leading: LayoutAnchorX::leading(view),
+
+            #[cfg(feature = "autolayout")]
+            right: LayoutAnchorX::right(view),
+
+            #[cfg(feature = "autolayout")]
+            trailing: LayoutAnchorX::trailing(view),
+
+            #[cfg(feature = "autolayout")]
+            bottom: LayoutAnchorY::bottom(view),
+
+            #[cfg(feature = "autolayout")]
+            width: LayoutAnchorDimension::width(view),
+
+            #[cfg(feature = "autolayout")]
+            height: LayoutAnchorDimension::height(view),
+
+            #[cfg(feature = "autolayout")]
+            center_x: LayoutAnchorX::center(view),
+
+            #[cfg(feature = "autolayout")]
+            center_y: LayoutAnchorY::center(view),
+
+            objc: ObjcProperty::retain(view)
+        };
+
+        // `new` handed us a +1 reference, and `ObjcProperty::retain` took its own.
+        unsafe {
+            let _: () = msg_send![view, release];
+        }
+
+        control
+    }
+
+    /// Sets the path to display.
+    pub fn set_url(&self, url: &NSURL) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setURL: &*url.objc];
+        });
+    }
+
+    /// Returns the path being displayed, if any.
+    pub fn url(&self) -> Option<NSURL<'static>> {
+        let url: id = self.objc.get(|obj| unsafe { msg_send![obj, URL] });
+
+        match url == nil {
+            true => None,
+            false => Some(NSURL::retain(url))
+        }
+    }
+
+    /// Sets how the path is presented.
+    pub fn set_style(&self, style: PathControlStyle) {
+        let style: NSInteger = style.into();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setPathStyle: style];
+        });
+    }
+
+    /// Sets a callback for when the user clicks a component of the path (or picks one from the
+    /// pop up menu). The callback receives the URL of the clicked component - e.g, clicking
+    /// `Users` in `/Users/me/Documents` gives you `/Users`.
+    pub fn set_on_click<F: Fn(Option<NSURL<'static>>) + Send + Sync + 'static>(&mut self, handler: F) {
+        let this: Id<Object, Shared> = self.objc.get(|obj| unsafe { msg_send_id![obj, self] });
+
+        let action = TargetActionHandler::new(&*this, move |control| {
+            let url: id = unsafe {
+                let item: id = msg_send![control, clickedPathItem];
+
+                match item == nil {
+                    true => nil,
+                    false => msg_send![item, URL]
+                }
+            };
+
+            handler(match url == nil {
+                true => None,
+                false => Some(NSURL::retain(url))
+            });
+        });
+
+        self.handler = Some(action);
+    }
+
+    /// Lets the user drag files and folders onto this control. The callback receives the
+    /// dropped URL and returns whether to accept it; accepted URLs become the displayed path.
+    pub fn set_on_drop<F: Fn(NSURL<'static>) -> bool + 'static>(&mut self, handler: F) {
+        let handler = Box::new(DropHandler(Box::new(handler)));
+        let ptr: *const DropHandler = &*handler;
+
+        let delegate: Id<Object, Shared> = unsafe {
+            let mut delegate: Id<Object, Owned> = msg_send_id![register_delegate_class(), new];
+            delegate.set_ivar(DROP_HANDLER_PTR, ptr as usize);
+            delegate.into()
+        };
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setEditable: YES];
+            let _: () = msg_send![obj, setDelegate: &*delegate];
+        });
+
+        self.drop_handler = Some((handler, delegate));
+    }
+}
+
+impl ObjcAccess for PathControl {
+    fn with_backing_obj_mut<F: Fn(id)>(&self, handler: F) {
+        self.objc.with_mut(handler);
+    }
+
+    fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
+        self.objc.get(handler)
+    }
+}
+
+impl Layout for PathControl {
+    fn add_subview<V: Layout>(&self, _view: &V) {
+        panic!(
+            r#"
+            Tried to add a subview to a PathControl. This is not allowed in Cacao. If you think this should be supported,
+            open a discussion on the GitHub repo.
+        "#
+        );
+    }
+}
+
+impl Control for PathControl {}
+
+impl Drop for PathControl {
+    // The delegate is a weak reference, so it has to go before our side of it does.
+    fn drop(&mut self) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setTarget: nil];
+            let _: () = msg_send![obj, setAction: nil];
+            let _: () = msg_send![obj, setDelegate: nil];
+        });
+    }
+}
+
+/// Returns the URL being dragged, if there is one.
+fn dragged_url(info: id) -> Option<NSURL<'static>> {
+    unsafe {
+        let pasteboard: id = msg_send![info, draggingPasteboard];
+        let url: id = msg_send![class!(NSURL), URLFromPasteboard: pasteboard];
+
+        match url == nil {
+            true => None,
+            false => Some(NSURL::retain(url))
+        }
+    }
+}
+
+/// Offers a copy operation for anything that carries a URL.
+extern "C" fn validate_drop(_: &Object, _: Sel, _path_control: id, info: id) -> NSUInteger {
+    match dragged_url(info) {
+        Some(_) => DragOperation::Copy.into(),
+        None => DragOperation::None.into()
+    }
+}
+
+/// Asks the handler whether to take the dropped URL, and shows it if so.
+extern "C" fn accept_drop(this: &Object, _: Sel, path_control: id, info: id) -> Bool {
+    let url = match dragged_url(info) {
+        Some(url) => url,
+        None => return Bool::NO
+    };
+
+    let handler = load::<DropHandler>(this, DROP_HANDLER_PTR);
+
+    // We're responsible for updating the URL once we've implemented this.
+    if (handler.0)(url.clone()) {
+        unsafe {
+            let _: () = msg_send![path_control, setURL: &*url.objc];
+        }
+
+        return Bool::YES;
+    }
+
+    Bool::NO
+}
+
+/// Registers an `NSPathControl` subclass.
+fn register_class() -> &'static Class {
+    load_or_register_class("NSPathControl", "RSTPathControl", |decl| unsafe {})
+}
+
+/// Registers an `NSObject` subclass to act as the drop delegate.
+fn register_delegate_class() -> &'static Class {
+    load_or_register_class("NSObject", "RSTPathControlDelegate", |decl| unsafe {
+        decl.add_ivar::<usize>(DROP_HANDLER_PTR);
+
+        decl.add_method(
+            sel!(pathControl:validateDrop:),
+            validate_drop as extern "C" fn(_, _, _, _) -> _
+        );
+        decl.add_method(sel!(pathControl:acceptDrop:), accept_drop as extern "C" fn(_, _, _, _) -> _);
+    })
+}
@@ -0,0 +1,445 @@
+//! A small, SQLite-backed record store, for apps that need structured persistence that drives
+//! their UI.
+//!
+//! Records of a type live in a `Collection`, in the order they were inserted. Every change to a
+//! collection is reported to its observers as a set of row indexes, which map directly onto
+//! `ListView`'s row operations:
+//!
+//! ```rust,no_run
+//! use cacao::listview::{ListView, RowAnimation};
+//! use cacao::persistence::{Record, Store};
+//!
+//! struct Note {
+//!     text: String
+//! }
+//!
+//! impl Record for Note {
+//!     const TABLE: &'static str = "notes";
+//!
+//!     fn encode(&self) -> Vec<u8> {
+//!         self.text.as_bytes().to_vec()
+//!     }
+//!
+//!     fn decode(bytes: &[u8]) -> Option<Self> {
+//!         String::from_utf8(bytes.to_vec()).ok().map(|text| Note { text })
+//!     }
+//! }
+//!
+//! let list_view = ListView::new();
+//! let store = Store::open("/path/to/notes.sqlite").unwrap();
+//! let notes = store.collection::<Note>().unwrap();
+//!
+//! // Your `ListViewDelegate` reads from `notes.all()`; this keeps the list in step for as long
+//! // as the subscription is held.
+//! let list = list_view.clone_as_handle();
+//! let _subscription = notes.observe(move |changes| changes.apply_to(&list, RowAnimation::SlideDown));
+//!
+//! notes.insert(&Note { text: "Buy milk".into() }).unwrap();
+//! ```
+//!
+//! cacao doesn't wrap `NSCollectionView`/`UICollectionView` yet, but `Changes` maps onto their
+//! item updates the same way - `removed`, `inserted` and `updated` are the items to delete,
+//! insert and reload, in section `0`.
+//!
+//! How a record is encoded is up to you - `serde` with your format of choice works well.
+//!
+//! To use this module, you must specify the `persistence` feature flag in your `Cargo.toml`. It
+//! links the system's `libsqlite3`.
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::binding::Subscription;
+use crate::error::{Error, CACAO_ERROR_DOMAIN};
+
+#[cfg(any(appkit, uikit))]
+use crate::listview::{ListView, RowAnimation};
+
+mod sqlite;
+use sqlite::{Connection, Param};
+
+/// Describes how a type is stored in a `Store`.
+pub trait Record: Sized {
+    /// The name of the table records of this type are kept in. This must be unique per-type.
+    const TABLE: &'static str;
+
+    /// Encodes this record for storage.
+    fn encode(&self) -> Vec<u8>;
+
+    /// Decodes a record encoded by `encode()`. A record that fails to decode (e.g, one written by
+    /// an older version of your app) makes reads that include it return an error, rather than
+    /// being skipped - skipping it would throw off the row indexes `Changes` reports.
+    fn decode(bytes: &[u8]) -> Option<Self>;
+}
+
+/// Describes a change to a `Collection`, as indexes into `Collection::all()`.
+///
+/// `removed` indexes are from before the change, while `inserted` and `updated` are from after
+/// it - the same as `ListView` expects.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Changes {
+    /// The indexes of inserted records.
+    pub inserted: Vec<usize>,
+
+    /// The indexes of updated records.
+    pub updated: Vec<usize>,
+
+    /// The indexes of removed records.
+    pub removed: Vec<usize>
+}
+
+impl Changes {
+    /// Returns whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.inserted.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+
+    /// Applies these changes to `list_view`'s rows, animating insertions and removals with
+    /// `animation`.
+    #[cfg(any(appkit, uikit))]
+    pub fn apply_to(&self, list_view: &ListView, animation: RowAnimation) {
+        list_view.perform_batch_updates(|list_view| {
+            if !self.removed.is_empty() {
+                list_view.remove_rows(&self.removed, animation);
+            }
+
+            if !self.inserted.is_empty() {
+                list_view.insert_rows(&self.inserted, animation);
+            }
+        });
+
+        if !self.updated.is_empty() {
+            list_view.reload_rows(&self.updated);
+        }
+    }
+}
+
+/// Observers for each table in a `Store`, along with the ids they're removed by.
+type Observers = Rc<RefCell<HashMap<&'static str, Vec<(usize, Rc<dyn Fn(&Changes)>)>>>>;
+
+/// A database of records. This is cheap to clone, and clones share the underlying connection.
+///
+/// Stores aren't thread safe; use them from the main thread, alongside the UI they drive.
+#[derive(Clone)]
+pub struct Store {
+    connection: Rc<Connection>,
+    observers: Observers,
+    next_observer_id: Rc<Cell<usize>>
+}
+
+impl fmt::Debug for Store {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Store").field("connection", &self.connection).finish()
+    }
+}
+
+impl Store {
+    /// Opens the database at `path`, creating it if it doesn't exist yet.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref().to_string_lossy();
+        Store::with_connection(Connection::open(&path)?)
+    }
+
+    /// Opens a private, in-memory database, which is gone once the last clone of this is dropped.
+    pub fn in_memory() -> Result<Self, Error> {
+        Store::with_connection(Connection::open(":memory:")?)
+    }
+
+    /// Wraps an open connection.
+    fn with_connection(connection: Connection) -> Result<Self, Error> {
+        Ok(Store {
+            connection: Rc::new(connection),
+            observers: Rc::new(RefCell::new(HashMap::new())),
+            next_observer_id: Rc::new(Cell::new(0))
+        })
+    }
+
+    /// Returns a `Subscription` that removes the observer with `observer_id` from `table`.
+    fn subscription(&self, table: &'static str, observer_id: usize) -> Subscription {
+        let observers = Rc::downgrade(&self.observers);
+
+        Subscription::new(move || {
+            if let Some(observers) = observers.upgrade() {
+                if let Some(handlers) = observers.borrow_mut().get_mut(table) {
+                    handlers.retain(|(id, _)| *id != observer_id);
+                }
+            }
+        })
+    }
+
+    /// Returns the collection of `T` records, creating its table if it doesn't exist yet.
+    pub fn collection<T: Record>(&self) -> Result<Collection<T>, Error> {
+        // AUTOINCREMENT keeps ids from being reused, so insertion order is id order.
+        let sql = format!(
+            "CREATE TABLE IF NOT EXISTS \"{}\" (id INTEGER PRIMARY KEY AUTOINCREMENT, data BLOB NOT NULL)",
+            T::TABLE
+        );
+        self.connection.execute(&sql, &[])?;
+
+        Ok(Collection {
+            store: self.clone(),
+            record: PhantomData
+        })
+    }
+}
+
+/// The records of a single type in a `Store`, in the order they were inserted. Records are
+/// identified by the id they're given when inserted.
+///
+/// Collections are cheap to clone, and every clone (along with any other collection of the same
+/// type from the same store) shares its observers.
+pub struct Collection<T> {
+    store: Store,
+    record: PhantomData<T>
+}
+
+impl<T> Clone for Collection<T> {
+    fn clone(&self) -> Self {
+        Collection {
+            store: self.store.clone(),
+            record: PhantomData
+        }
+    }
+}
+
+impl<T: Record> fmt::Debug for Collection<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Collection").field("table", &T::TABLE).finish()
+    }
+}
+
+impl<T: Record> Collection<T> {
+    /// Returns the number of records.
+    pub fn len(&self) -> Result<usize, Error> {
+        let sql = format!("SELECT COUNT(*) FROM \"{}\"", T::TABLE);
+        let count = self.store.connection.query(&sql, &[], |row| row.integer(0))?;
+        Ok(count.first().copied().unwrap_or(0) as usize)
+    }
+
+    /// Returns whether there are no records.
+    pub fn is_empty(&self) -> Result<bool, Error> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Returns every record, along with its id. Returns an error if any record fails to decode.
+    pub fn all(&self) -> Result<Vec<(i64, T)>, Error> {
+        let sql = format!("SELECT id, data FROM \"{}\" ORDER BY id", T::TABLE);
+        let rows = self.store.connection.query(&sql, &[], |row| (row.integer(0), row.blob(1)))?;
+
+        rows.into_iter()
+            .map(|(id, data)| Ok((id, Self::decode(id, &data)?)))
+            .collect()
+    }
+
+    /// Returns the record with the given id, if there is one. Returns an error if it fails to
+    /// decode.
+    pub fn get(&self, id: i64) -> Result<Option<T>, Error> {
+        let sql = format!("SELECT data FROM \"{}\" WHERE id = ?", T::TABLE);
+        let rows = self.store.connection.query(&sql, &[Param::Integer(id)], |row| row.blob(0))?;
+
+        match rows.first() {
+            Some(data) => Ok(Some(Self::decode(id, data)?)),
+            None => Ok(None)
+        }
+    }
+
+    /// Adds `record` to the end of the collection, returning its id.
+    pub fn insert(&self, record: &T) -> Result<i64, Error> {
+        let data = record.encode();
+        let sql = format!("INSERT INTO \"{}\" (data) VALUES (?)", T::TABLE);
+        self.store.connection.execute(&sql, &[Param::Blob(&data)])?;
+
+        let id = self.store.connection.last_insert_rowid();
+        let index = self.index_of(id)?;
+
+        self.notify(Changes {
+            inserted: vec![index],
+            ..Changes::default()
+        });
+
+        Ok(id)
+    }
+
+    /// Replaces the record with the given id. Does nothing if there isn't one.
+    pub fn update(&self, id: i64, record: &T) -> Result<(), Error> {
+        let data = record.encode();
+        let sql = format!("UPDATE \"{}\" SET data = ? WHERE id = ?", T::TABLE);
+        self.store
+            .connection
+            .execute(&sql, &[Param::Blob(&data), Param::Integer(id)])?;
+
+        if self.store.connection.changes() > 0 {
+            let index = self.index_of(id)?;
+
+            self.notify(Changes {
+                updated: vec![index],
+                ..Changes::default()
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Removes the record with the given id. Does nothing if there isn't one.
+    pub fn remove(&self, id: i64) -> Result<(), Error> {
+        let index = self.index_of(id)?;
+
+        let sql = format!("DELETE FROM \"{}\" WHERE id = ?", T::TABLE);
+        self.store.connection.execute(&sql, &[Param::Integer(id)])?;
+
+        if self.store.connection.changes() > 0 {
+            self.notify(Changes {
+                removed: vec![index],
+                ..Changes::default()
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Registers `handler` to be called after every change to this collection. The handler is
+    /// removed when the returned `Subscription` is dropped.
+    ///
+    /// Handlers are free to read from and change the collection, or register other observers.
+    /// Observers registered while a change is being reported are called from the next one.
+    pub fn observe<F: Fn(&Changes) + 'static>(&self, handler: F) -> Subscription {
+        let observer_id = self.store.next_observer_id.get();
+        self.store.next_observer_id.set(observer_id + 1);
+
+        let mut observers = self.store.observers.borrow_mut();
+        observers
+            .entry(T::TABLE)
+            .or_insert_with(Vec::new)
+            .push((observer_id, Rc::new(handler)));
+
+        self.store.subscription(T::TABLE, observer_id)
+    }
+
+    /// Returns the index of the record with the given id, in `all()` order.
+    fn index_of(&self, id: i64) -> Result<usize, Error> {
+        let sql = format!("SELECT COUNT(*) FROM \"{}\" WHERE id < ?", T::TABLE);
+        let count = self
+            .store
+            .connection
+            .query(&sql, &[Param::Integer(id)], |row| row.integer(0))?;
+        Ok(count.first().copied().unwrap_or(0) as usize)
+    }
+
+    /// Decodes the data of the record with the given id.
+    fn decode(id: i64, data: &[u8]) -> Result<T, Error> {
+        T::decode(data).ok_or_else(|| {
+            let message = format!("The record with id {} in \"{}\" couldn't be decoded.", id, T::TABLE);
+            Error::custom(CACAO_ERROR_DOMAIN, 0, &message)
+        })
+    }
+
+    /// Calls this collection's observers with `changes`.
+    fn notify(&self, changes: Changes) {
+        // Handlers are cloned out first, so that they're free to use the collection (and its
+        // observers) while being called.
+        let handlers: Vec<Rc<dyn Fn(&Changes)>> = match self.store.observers.borrow().get(T::TABLE) {
+            Some(handlers) => handlers.iter().map(|(_, handler)| handler.clone()).collect(),
+            None => return
+        };
+
+        for handler in handlers {
+            handler(&changes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::{Changes, Record, Store};
+
+    #[derive(Debug, PartialEq)]
+    struct Note(String);
+
+    impl Record for Note {
+        const TABLE: &'static str = "notes";
+
+        fn encode(&self) -> Vec<u8> {
+            self.0.as_bytes().to_vec()
+        }
+
+        fn decode(bytes: &[u8]) -> Option<Self> {
+            String::from_utf8(bytes.to_vec()).ok().map(Note)
+        }
+    }
+
+    /// Shares `Note`'s table, to write data that isn't a valid `Note`.
+    struct Raw(Vec<u8>);
+
+    impl Record for Raw {
+        const TABLE: &'static str = "notes";
+
+        fn encode(&self) -> Vec<u8> {
+            self.0.clone()
+        }
+
+        fn decode(bytes: &[u8]) -> Option<Self> {
+            Some(Raw(bytes.to_vec()))
+        }
+    }
+
+    #[test]
+    fn changes_are_reported_as_row_indexes() {
+        let store = Store::in_memory().unwrap();
+        let notes = store.collection::<Note>().unwrap();
+
+        let reported = Rc::new(RefCell::new(Vec::new()));
+        let log = reported.clone();
+        let subscription = notes.observe(move |changes| log.borrow_mut().push(changes.clone()));
+
+        let first = notes.insert(&Note("first".into())).unwrap();
+        let second = notes.insert(&Note("second".into())).unwrap();
+        notes.update(second, &Note("changed".into())).unwrap();
+        notes.remove(first).unwrap();
+
+        assert_eq!(*reported.borrow(), vec![
+            Changes {
+                inserted: vec![0],
+                ..Changes::default()
+            },
+            Changes {
+                inserted: vec![1],
+                ..Changes::default()
+            },
+            Changes {
+                updated: vec![1],
+                ..Changes::default()
+            },
+            Changes {
+                removed: vec![0],
+                ..Changes::default()
+            },
+        ]);
+
+        assert_eq!(notes.all().unwrap(), vec![(second, Note("changed".into()))]);
+        assert_eq!(notes.get(first).unwrap(), None);
+
+        // Once the subscription is dropped, changes are no longer reported.
+        drop(subscription);
+        notes.insert(&Note("unobserved".into())).unwrap();
+        assert_eq!(reported.borrow().len(), 4);
+    }
+
+    #[test]
+    fn undecodable_records_are_errors() {
+        let store = Store::in_memory().unwrap();
+        let notes = store.collection::<Note>().unwrap();
+
+        notes.insert(&Note("valid".into())).unwrap();
+        let invalid = store.collection::<Raw>().unwrap().insert(&Raw(vec![0xff])).unwrap();
+
+        assert!(notes.all().is_err());
+        assert!(notes.get(invalid).is_err());
+    }
+}
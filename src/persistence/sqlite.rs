@@ -0,0 +1,208 @@
+//! A minimal binding to the system's `libsqlite3` - just enough to back `Store`.
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use crate::error::Error;
+
+#[allow(non_camel_case_types)]
+enum sqlite3 {}
+
+#[allow(non_camel_case_types)]
+enum sqlite3_stmt {}
+
+const SQLITE_OK: c_int = 0;
+const SQLITE_ROW: c_int = 100;
+const SQLITE_DONE: c_int = 101;
+
+const SQLITE_OPEN_READWRITE: c_int = 0x02;
+const SQLITE_OPEN_CREATE: c_int = 0x04;
+
+/// `SQLITE_TRANSIENT`, which has SQLite copy bound blobs rather than borrowing them.
+const SQLITE_TRANSIENT: isize = -1;
+
+extern "C" {
+    fn sqlite3_open_v2(filename: *const c_char, db: *mut *mut sqlite3, flags: c_int, vfs: *const c_char) -> c_int;
+    fn sqlite3_close_v2(db: *mut sqlite3) -> c_int;
+    fn sqlite3_errmsg(db: *mut sqlite3) -> *const c_char;
+    fn sqlite3_prepare_v2(
+        db: *mut sqlite3,
+        sql: *const c_char,
+        bytes: c_int,
+        stmt: *mut *mut sqlite3_stmt,
+        tail: *mut *const c_char
+    ) -> c_int;
+    fn sqlite3_step(stmt: *mut sqlite3_stmt) -> c_int;
+    fn sqlite3_finalize(stmt: *mut sqlite3_stmt) -> c_int;
+    fn sqlite3_bind_int64(stmt: *mut sqlite3_stmt, index: c_int, value: i64) -> c_int;
+    fn sqlite3_bind_blob(stmt: *mut sqlite3_stmt, index: c_int, value: *const c_void, bytes: c_int, destructor: isize) -> c_int;
+    fn sqlite3_column_int64(stmt: *mut sqlite3_stmt, column: c_int) -> i64;
+    fn sqlite3_column_blob(stmt: *mut sqlite3_stmt, column: c_int) -> *const c_void;
+    fn sqlite3_column_bytes(stmt: *mut sqlite3_stmt, column: c_int) -> c_int;
+    fn sqlite3_last_insert_rowid(db: *mut sqlite3) -> i64;
+    fn sqlite3_changes(db: *mut sqlite3) -> c_int;
+}
+
+/// A value bound to a `?` in a statement.
+#[derive(Debug)]
+pub(crate) enum Param<'a> {
+    Integer(i64),
+    Blob(&'a [u8])
+}
+
+/// A row in a query's results.
+#[derive(Debug)]
+pub(crate) struct Row(*mut sqlite3_stmt);
+
+impl Row {
+    /// Reads an integer column.
+    pub fn integer(&self, column: usize) -> i64 {
+        unsafe { sqlite3_column_int64(self.0, column as c_int) }
+    }
+
+    /// Reads a blob column.
+    pub fn blob(&self, column: usize) -> Vec<u8> {
+        unsafe {
+            let bytes = sqlite3_column_blob(self.0, column as c_int) as *const u8;
+            let len = sqlite3_column_bytes(self.0, column as c_int) as usize;
+
+            match bytes.is_null() {
+                true => Vec::new(),
+                false => std::slice::from_raw_parts(bytes, len).to_vec()
+            }
+        }
+    }
+}
+
+/// Finalizes a prepared statement when it goes out of scope.
+struct Statement(*mut sqlite3_stmt);
+
+impl Drop for Statement {
+    fn drop(&mut self) {
+        unsafe {
+            sqlite3_finalize(self.0);
+        }
+    }
+}
+
+/// An open database connection.
+#[derive(Debug)]
+pub(crate) struct Connection(*mut sqlite3);
+
+impl Connection {
+    /// Opens (creating, if need be) the database at `path`. `":memory:"` opens a private,
+    /// in-memory database.
+    pub fn open(path: &str) -> Result<Self, Error> {
+        let path = CString::new(path).map_err(|e| Error::custom(DOMAIN, 0, &e.to_string()))?;
+        let mut db: *mut sqlite3 = ptr::null_mut();
+
+        let result = unsafe {
+            sqlite3_open_v2(
+                path.as_ptr(),
+                &mut db,
+                SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE,
+                ptr::null()
+            )
+        };
+
+        let connection = Connection(db);
+
+        match result {
+            SQLITE_OK => Ok(connection),
+            code => Err(connection.error(code))
+        }
+    }
+
+    /// Runs a statement that doesn't return rows.
+    pub fn execute(&self, sql: &str, params: &[Param]) -> Result<(), Error> {
+        let statement = self.prepare(sql, params)?;
+
+        match unsafe { sqlite3_step(statement.0) } {
+            SQLITE_DONE | SQLITE_ROW => Ok(()),
+            code => Err(self.error(code))
+        }
+    }
+
+    /// Runs a query, mapping each row of the results with `map`.
+    pub fn query<T, F: FnMut(&Row) -> T>(&self, sql: &str, params: &[Param], mut map: F) -> Result<Vec<T>, Error> {
+        let statement = self.prepare(sql, params)?;
+        let mut results = Vec::new();
+
+        loop {
+            match unsafe { sqlite3_step(statement.0) } {
+                SQLITE_ROW => results.push(map(&Row(statement.0))),
+                SQLITE_DONE => return Ok(results),
+                code => return Err(self.error(code))
+            }
+        }
+    }
+
+    /// Returns the rowid of the most recent insert.
+    pub fn last_insert_rowid(&self) -> i64 {
+        unsafe { sqlite3_last_insert_rowid(self.0) }
+    }
+
+    /// Returns how many rows the most recent statement changed.
+    pub fn changes(&self) -> usize {
+        unsafe { sqlite3_changes(self.0) as usize }
+    }
+
+    /// Compiles `sql` and binds `params` to it.
+    fn prepare(&self, sql: &str, params: &[Param]) -> Result<Statement, Error> {
+        let sql = CString::new(sql).map_err(|e| Error::custom(DOMAIN, 0, &e.to_string()))?;
+        let mut stmt: *mut sqlite3_stmt = ptr::null_mut();
+
+        let result = unsafe { sqlite3_prepare_v2(self.0, sql.as_ptr(), -1, &mut stmt, ptr::null_mut()) };
+        if result != SQLITE_OK {
+            return Err(self.error(result));
+        }
+
+        let statement = Statement(stmt);
+
+        for (index, param) in params.iter().enumerate() {
+            // Parameters are 1-indexed.
+            let index = index as c_int + 1;
+
+            let result = unsafe {
+                match param {
+                    Param::Integer(value) => sqlite3_bind_int64(stmt, index, *value),
+                    Param::Blob(bytes) => sqlite3_bind_blob(
+                        stmt,
+                        index,
+                        bytes.as_ptr() as *const c_void,
+                        bytes.len() as c_int,
+                        SQLITE_TRANSIENT
+                    )
+                }
+            };
+
+            if result != SQLITE_OK {
+                return Err(self.error(result));
+            }
+        }
+
+        Ok(statement)
+    }
+
+    /// Builds an `Error` from the result `code` and the connection's last error message.
+    fn error(&self, code: c_int) -> Error {
+        let message = match self.0.is_null() {
+            true => "Unable to allocate a database connection".to_string(),
+            false => unsafe { CStr::from_ptr(sqlite3_errmsg(self.0)).to_string_lossy().into_owned() }
+        };
+
+        Error::custom(DOMAIN, code as usize, &message)
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        unsafe {
+            sqlite3_close_v2(self.0);
+        }
+    }
+}
+
+/// The error domain for database errors; codes are SQLite result codes.
+const DOMAIN: &str = "com.cacao-rs.sqlite";
@@ -0,0 +1,31 @@
+//! Target-conditional re-exports, for code that's shared between a macOS and an iOS app.
+//!
+//! With both the `appkit` and `uikit` features enabled, cacao picks the framework based on the
+//! target OS (AppKit on macOS, UIKit elsewhere). Views and controls are already shared between
+//! the two, but the application and window types differ; this module re-exports whichever set
+//! is active, so shared code can refer to `cacao::platform::App` and friends without its own
+//! `cfg` gates.
+//!
+//! Note that the types re-exported here aren't identical across platforms - e.g, a UIKit `App`
+//! takes scene delegates - so the code that actually sets up the app will usually still be
+//! per-platform. To branch on the platform in your own code, check `platform::IS_APPKIT`.
+
+#[cfg(appkit)]
+pub use crate::appkit::window::Window;
+#[cfg(appkit)]
+pub use crate::appkit::{App, AppDelegate};
+
+#[cfg(uikit)]
+pub use crate::uikit::{App, AppDelegate, Window};
+
+/// Whether cacao was built against AppKit for this target.
+pub const IS_APPKIT: bool = cfg!(appkit);
+
+/// Whether cacao was built against UIKit for this target.
+pub const IS_UIKIT: bool = cfg!(uikit);
+
+/// Whether this is a Mac Catalyst build (UIKit, running on macOS).
+pub const IS_CATALYST: bool = cfg!(catalyst);
+
+/// Whether cacao was built against GNUstep's AppKit (the `gnustep` feature).
+pub const IS_GNUSTEP: bool = cfg!(gnustep);
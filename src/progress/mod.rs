@@ -21,6 +21,7 @@ use objc::rc::{Id, Shared};
 use objc::runtime::{Class, Object};
 use objc::{class, msg_send, sel};
 
+use crate::binding::{Binding, Property};
 use crate::color::Color;
 use crate::foundation::{id, nil, NSUInteger, NO, YES};
 use crate::layout::Layout;
@@ -33,6 +34,9 @@ use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 mod enums;
 pub use enums::ProgressIndicatorStyle;
 
+mod task;
+pub use task::Progress;
+
 /// A control used for reporting progress to a user visually.
 #[derive(Debug)]
 pub struct ProgressIndicator {
@@ -91,13 +95,13 @@ impl ProgressIndicator {
     /// need it to stay around.
     pub fn new() -> Self {
         let view = unsafe {
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let view: id = msg_send![class!(NSProgressIndicator), new];
 
             #[cfg(feature = "autolayout")]
             let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
 
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let _: () = msg_send![view, setWantsLayer: YES];
 
             view
@@ -195,6 +199,20 @@ impl ProgressIndicator {
         });
     }
 
+    /// Binds the value of this progress indicator to `property`, so that it updates whenever the
+    /// property changes. The binding is removed when the returned `Binding` is dropped.
+    pub fn bind_value(&self, property: &Property<f64>) -> Binding {
+        let objc = self.objc.clone();
+
+        Binding::one_way(property.observe_and_apply(move |value| {
+            let value = *value as CGFloat;
+
+            objc.with_mut(|obj| unsafe {
+                let _: () = msg_send![obj, setDoubleValue: value];
+            });
+        }))
+    }
+
     /// Set whether this control is hidden or not.
     pub fn set_hidden(&self, hidden: bool) {
         self.objc.with_mut(|obj| unsafe {
@@ -0,0 +1,96 @@
+//! Wraps `NSProgress`, which is used for reporting the progress of a unit of work - as opposed to
+//! `ProgressIndicator`, which displays it.
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{to_bool, NSString, NO, YES};
+
+/// A wrapper for `NSProgress`. This is cheap to clone, and - as `NSProgress` is thread safe - can
+/// be sent to a background thread to report progress from there.
+#[derive(Clone, Debug)]
+pub struct Progress {
+    /// A pointer to the underlying `NSProgress`.
+    pub objc: Id<Object, Shared>
+}
+
+impl Progress {
+    /// Creates a new `Progress` that tracks `total_unit_count` units of work.
+    pub fn new(total_unit_count: i64) -> Self {
+        Progress {
+            objc: unsafe { msg_send_id![class!(NSProgress), discreteProgressWithTotalUnitCount: total_unit_count] }
+        }
+    }
+
+    /// Sets how many units of work have been completed.
+    pub fn set_completed_unit_count(&self, count: i64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCompletedUnitCount: count];
+        }
+    }
+
+    /// Returns how many units of work have been completed.
+    pub fn completed_unit_count(&self) -> i64 {
+        unsafe { msg_send![&*self.objc, completedUnitCount] }
+    }
+
+    /// Sets the total number of units of work tracked.
+    pub fn set_total_unit_count(&self, count: i64) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTotalUnitCount: count];
+        }
+    }
+
+    /// Returns the total number of units of work tracked.
+    pub fn total_unit_count(&self) -> i64 {
+        unsafe { msg_send![&*self.objc, totalUnitCount] }
+    }
+
+    /// Returns the fraction of work completed, from `0.0` to `1.0`.
+    pub fn fraction_completed(&self) -> f64 {
+        unsafe { msg_send![&*self.objc, fractionCompleted] }
+    }
+
+    /// Sets a user-facing description of the work being done.
+    pub fn set_localized_description(&self, description: &str) {
+        let description = NSString::new(description);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setLocalizedDescription: &*description];
+        }
+    }
+
+    /// Sets whether this work can be cancelled. Defaults to `true`.
+    pub fn set_cancellable(&self, cancellable: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setCancellable:match cancellable {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Cancels the work tracked by this `Progress`. Code doing the work should check
+    /// `is_cancelled()` periodically and bail out accordingly.
+    pub fn cancel(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, cancel];
+        }
+    }
+
+    /// Returns whether this has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isCancelled] })
+    }
+
+    /// Returns whether all units of work have been completed.
+    pub fn is_finished(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isFinished] })
+    }
+}
+
+// `NSProgress` is documented as being thread safe, and is explicitly designed for reporting
+// progress from one thread while observing it on another.
+unsafe impl Send for Progress {}
+unsafe impl Sync for Progress {}
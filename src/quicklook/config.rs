@@ -3,9 +3,9 @@ use std::path::Path;
 use core_graphics::base::CGFloat;
 use objc::rc::{Id, Shared};
 use objc::runtime::Object;
-use objc::{class, msg_send, sel};
+use objc::{class, msg_send, msg_send_id, sel};
 
-use crate::foundation::{id, NSString, NSUInteger, YES};
+use crate::foundation::{NSUInteger, NSURL, YES};
 use crate::utils::CGSize;
 
 /// Describes the quality of the thumbnail you expect back from the
@@ -55,24 +55,38 @@ impl From<ThumbnailQuality> for NSUInteger {
     }
 }
 
+/// Converts a `QLThumbnailRepresentationType`. Note that, unlike the masks used when making a
+/// request, these are plain values.
 impl From<NSUInteger> for ThumbnailQuality {
     fn from(i: NSUInteger) -> Self {
         match i {
             0 => ThumbnailQuality::Icon,
-            2 => ThumbnailQuality::Low,
-            4 => ThumbnailQuality::High,
-            NSUInteger::MAX => ThumbnailQuality::All,
+            1 => ThumbnailQuality::Low,
+            2 => ThumbnailQuality::High,
             i => ThumbnailQuality::Unknown(i)
         }
     }
 }
 
+/// Describes the thumbnail(s) to generate for a file.
 #[derive(Clone, Debug)]
 pub struct ThumbnailConfig {
+    /// The size (in points) the thumbnail should fit in. The generated thumbnail keeps the file's
+    /// aspect ratio, so it may be smaller in one dimension.
     pub size: (CGFloat, CGFloat),
+
+    /// The scale of the display the thumbnail is for (e.g, `2.` for Retina displays).
     pub scale: CGFloat,
+
+    /// The smallest size (in points) that's acceptable; anything smaller is discarded rather than
+    /// delivered. `0.` accepts any size.
     pub minimum_dimension: CGFloat,
+
+    /// Whether thumbnails should be styled as icons (e.g, with a page curl, or a border), as in
+    /// Finder's icon view.
     pub icon_mode: bool,
+
+    /// Which representations to generate.
     pub types: &'static [ThumbnailQuality]
 }
 
@@ -97,8 +111,8 @@ impl Default for ThumbnailConfig {
 impl ThumbnailConfig {
     /// Consumes the request and returns a native representation
     /// (`QLThumbnailGenerationRequest`).
-    pub fn to_request(self, path: &Path) -> id {
-        let file = NSString::new(path.to_str().unwrap());
+    pub fn to_request(self, path: &Path) -> Id<Object, Shared> {
+        let url = NSURL::with_path(path);
 
         let mut types: NSUInteger = 0;
         for mask in self.types {
@@ -108,21 +122,21 @@ impl ThumbnailConfig {
 
         unsafe {
             let size = CGSize::new(self.size.0, self.size.1);
-            // @TODO: Check nil here, or other bad conversion
-            let from_url: id = msg_send![class!(NSURL), fileURLWithPath:&*file];
 
-            let request: id = msg_send![class!(QLThumbnailGenerationRequest), alloc];
-            let request: id = msg_send![request, initWithFileAtURL:from_url
-                size:size
-                scale:self.scale
-                representationTypes:types];
+            let request: Id<Object, Shared> = msg_send_id![
+                msg_send_id![class!(QLThumbnailGenerationRequest), alloc],
+                initWithFileAtURL: &*url.objc,
+                size: size,
+                scale: self.scale,
+                representationTypes: types,
+            ];
 
             if self.icon_mode {
-                let _: () = msg_send![request, setIconMode: YES];
+                let _: () = msg_send![&*request, setIconMode: YES];
             }
 
             if self.minimum_dimension != 0. {
-                let _: () = msg_send![request, setMinimumDimension:self.minimum_dimension];
+                let _: () = msg_send![&*request, setMinimumDimension: self.minimum_dimension];
             }
 
             request
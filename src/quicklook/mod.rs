@@ -1,3 +1,28 @@
+//! Generates thumbnails and icons for files via `QLThumbnailGenerator`, the same way Finder does.
+//!
+//! Generation is asynchronous. You can either take each representation as it arrives - an icon
+//! right away, then progressively better thumbnails - or wait for the best one:
+//!
+//! ```rust,no_run
+//! use std::path::Path;
+//! use cacao::quicklook::{ThumbnailConfig, ThumbnailGenerator, ThumbnailQuality};
+//!
+//! let config = ThumbnailConfig {
+//!     size: (128., 128.),
+//!     scale: 2.,
+//!     ..ThumbnailConfig::default()
+//! };
+//!
+//! let request = ThumbnailGenerator::shared().generate_from_path(Path::new("/tmp/report.pdf"), config, |result| {
+//!     if let Ok((image, quality)) = result {
+//!         // Swap `image` in; it's an icon first, then better and better thumbnails.
+//!     }
+//! });
+//!
+//! // e.g, if the cell showing it scrolls offscreen:
+//! request.cancel();
+//! ```
+
 use std::path::Path;
 
 use objc::rc::{Id, Shared};
@@ -25,19 +50,22 @@ impl ThumbnailGenerator {
     /// Given a path and config, will generate a preview image, calling back on the provided
     /// callback closure.
     ///
+    /// The callback is called once for each representation requested in `config.types`, as it
+    /// becomes available - typically lowest quality first, so you can show something right away
+    /// and replace it as better thumbnails arrive. Representations that are slower to generate
+    /// than a better one are skipped.
+    ///
     /// Note that this callback can come back on a separate thread, so react accordingly to get to
     /// the main thread if you need to.
-    pub fn generate_from_path<F>(&self, path: &Path, config: ThumbnailConfig, callback: F)
+    pub fn generate_from_path<F>(&self, path: &Path, config: ThumbnailConfig, callback: F) -> ThumbnailRequest
     where
         F: Fn(Result<(Image, ThumbnailQuality), Error>) + Send + Sync + 'static
     {
         let block = ConcreteBlock::new(move |thumbnail: id, thumbnail_type: NSUInteger, error: id| {
             if error == nil {
-                unsafe {
-                    let image = Image::with(msg_send![thumbnail, NSImage]);
-                    let quality = ThumbnailQuality::from(thumbnail_type);
-                    callback(Ok((image, quality)));
-                }
+                let image = image(thumbnail);
+                let quality = ThumbnailQuality::from(thumbnail_type);
+                callback(Ok((image, quality)));
             } else {
                 let error = Error::new(error);
                 callback(Err(error));
@@ -50,9 +78,81 @@ impl ThumbnailGenerator {
         unsafe {
             let _: () = msg_send![
                 &*self.0,
-                generateRepresentationsForRequest: request,
+                generateRepresentationsForRequest: &*request,
                 updateHandler: &*block,
             ];
         }
+
+        ThumbnailRequest {
+            generator: self.0.clone(),
+            request
+        }
+    }
+
+    /// Generates only the best representation `config` allows, calling `callback` once with it
+    /// (or with an error, if nothing could be generated).
+    ///
+    /// Note that this callback can come back on a separate thread, so react accordingly to get to
+    /// the main thread if you need to.
+    pub fn generate_best_from_path<F>(&self, path: &Path, config: ThumbnailConfig, callback: F) -> ThumbnailRequest
+    where
+        F: Fn(Result<(Image, ThumbnailQuality), Error>) + Send + Sync + 'static
+    {
+        let block = ConcreteBlock::new(move |thumbnail: id, error: id| {
+            if thumbnail != nil {
+                let quality: NSUInteger = unsafe { msg_send![thumbnail, type] };
+                callback(Ok((image(thumbnail), ThumbnailQuality::from(quality))));
+            } else {
+                callback(Err(Error::new(error)));
+            }
+        });
+
+        let block = block.copy();
+        let request = config.to_request(path);
+
+        unsafe {
+            let _: () = msg_send![
+                &*self.0,
+                generateBestRepresentationForRequest: &*request,
+                completionHandler: &*block,
+            ];
+        }
+
+        ThumbnailRequest {
+            generator: self.0.clone(),
+            request
+        }
+    }
+}
+
+/// A handle to a thumbnail request that's in flight. Dropping this doesn't cancel the request;
+/// call `cancel()` for that.
+#[derive(Clone, Debug)]
+pub struct ThumbnailRequest {
+    generator: Id<Object, Shared>,
+    request: Id<Object, Shared>
+}
+
+impl ThumbnailRequest {
+    /// Cancels the request. Representations that haven't been delivered yet won't be, though one
+    /// that's already on its way may still arrive; the callback may also receive a cancellation
+    /// error.
+    pub fn cancel(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.generator, cancelRequest: &*self.request];
+        }
+    }
+}
+
+/// Pulls the platform image out of a `QLThumbnailRepresentation`.
+fn image(thumbnail: id) -> Image {
+    unsafe {
+        #[cfg(appkit)]
+        let image: id = msg_send![thumbnail, NSImage];
+
+        #[cfg(uikit)]
+        let image: id = msg_send![thumbnail, UIImage];
+
+        Image::with(image)
     }
 }
@@ -12,7 +12,7 @@ use objc::runtime::{Bool, Class, Object, Sel};
 use objc::sel;
 
 use crate::dragdrop::DragInfo;
-use crate::foundation::{id, load_or_register_class, NSUInteger};
+use crate::foundation::{id, load_or_register_class, NSUInteger, StaticClass};
 use crate::scrollview::{ScrollViewDelegate, SCROLLVIEW_DELEGATE_PTR};
 use crate::utils::load;
 
@@ -68,7 +68,9 @@ extern "C" fn dragging_exited<T: ScrollViewDelegate>(this: &mut Object, _: Sel,
 
 /// Injects an `NSScrollView` subclass.
 pub(crate) fn register_scrollview_class() -> &'static Class {
-    load_or_register_class("NSScrollView", "RSTScrollView", |decl| unsafe {})
+    static CLASS: StaticClass = StaticClass::new();
+
+    CLASS.get_or_register(|| load_or_register_class("NSScrollView", "RSTScrollView", |decl| unsafe {}))
 }
 
 /// Injects an `NSView` subclass, with some callback and pointer ivars for what we
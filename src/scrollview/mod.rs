@@ -57,21 +57,31 @@ use crate::utils::properties::ObjcProperty;
 #[cfg(feature = "autolayout")]
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 mod appkit;
 
-#[cfg(feature = "appkit")]
-use appkit::{register_scrollview_class, register_scrollview_class_with_delegate};
+#[cfg(appkit)]
+pub(crate) use appkit::register_scrollview_class;
+#[cfg(appkit)]
+use appkit::register_scrollview_class_with_delegate;
 
-#[cfg(feature = "uikit")]
+#[cfg(uikit)]
 mod uikit;
 
-#[cfg(all(feature = "uikit", not(feature = "appkit")))]
-use uikit::{register_scrollview_class, register_scrollview_class_with_delegate};
+#[cfg(uikit)]
+pub(crate) use uikit::register_scrollview_class;
+#[cfg(uikit)]
+use uikit::register_scrollview_class_with_delegate;
 
 mod traits;
 pub use traits::ScrollViewDelegate;
 
+#[cfg(appkit)]
+mod sync;
+
+#[cfg(appkit)]
+pub use sync::{ScrollSync, ScrollSyncAxis};
+
 pub(crate) static SCROLLVIEW_DELEGATE_PTR: &str = "rstScrollViewDelegatePtr";
 
 /// A helper method for instantiating view classes and applying default settings to them.
@@ -82,7 +92,7 @@ fn allocate_view(registration_fn: fn() -> &'static Class) -> id {
         #[cfg(feature = "autolayout")]
         let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         {
             let _: () = msg_send![view, setDrawsBackground: NO];
             let _: () = msg_send![view, setWantsLayer: YES];
@@ -303,6 +313,39 @@ impl<T> ScrollView<T> {
             let _: () = msg_send![layer, setBackgroundColor: color];
         });
     }
+
+    /// Sets whether a vertical scroller is shown. Views that scroll along with another one (see
+    /// `ScrollSync`) usually turn this off.
+    #[cfg(appkit)]
+    pub fn set_has_vertical_scroller(&self, has_scroller: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setHasVerticalScroller: match has_scroller {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
+    /// Sets whether a horizontal scroller is shown.
+    #[cfg(appkit)]
+    pub fn set_has_horizontal_scroller(&self, has_scroller: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setHasHorizontalScroller: match has_scroller {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
+    /// Keeps the vertical scroll position of this and `other` in step, for as long as the
+    /// returned `ScrollSync` is kept around. `other` is scrolled to match this one right away.
+    #[cfg(appkit)]
+    pub fn sync_vertical_scrolling_with<U>(&self, other: &ScrollView<U>) -> ScrollSync {
+        let mut sync = ScrollSync::vertical();
+        sync.add(self);
+        sync.add(other);
+        sync
+    }
 }
 
 impl<T> ObjcAccess for ScrollView<T> {
@@ -338,5 +381,7 @@ impl<T> Drop for ScrollView<T> {
 
 #[test]
 fn test_scrollview() {
+    crate::testing::initialize();
+
     let view = ScrollView::new();
 }
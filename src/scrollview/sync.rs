@@ -0,0 +1,177 @@
+//! Keeps the scroll position of two or more `ScrollView`s (or `ListView`s) in step - e.g, a frozen
+//! row-header column next to the main table of a spreadsheet, which should scroll vertically with
+//! it but stay put horizontally.
+//!
+//! Each scroll view's clip view is asked to post bounds change notifications, and whenever one of
+//! them moves, the others are scrolled to match along the synchronized axis.
+//!
+//! ```rust,no_run
+//! use cacao::listview::ListView;
+//! use cacao::scrollview::ScrollSync;
+//!
+//! let row_headers = ListView::new();
+//! let table = ListView::new();
+//! row_headers.scrollview.set_has_vertical_scroller(false);
+//!
+//! // Keep this around for as long as the two should scroll together.
+//! let sync = row_headers.sync_vertical_scrolling_with(&table);
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use block::ConcreteBlock;
+use core_graphics::geometry::{CGPoint, CGRect};
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, nil, NSString, YES};
+use crate::scrollview::ScrollView;
+
+/// Which direction(s) a `ScrollSync` keeps in step.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ScrollSyncAxis {
+    /// Only the vertical scroll position is shared (e.g, a frozen column).
+    Vertical,
+
+    /// Only the horizontal scroll position is shared (e.g, a frozen header row).
+    Horizontal,
+
+    /// Both scroll positions are shared.
+    Both
+}
+
+/// Synchronizes scrolling between a group of scroll views, for as long as it's kept around.
+/// Dropping it stops the synchronization, leaving each view where it is.
+#[derive(Debug)]
+pub struct ScrollSync {
+    axis: ScrollSyncAxis,
+    clip_views: Rc<RefCell<Vec<Id<Object, Shared>>>>,
+    syncing: Rc<Cell<bool>>,
+    observers: Vec<Id<Object, Shared>>
+}
+
+impl ScrollSync {
+    /// Creates an empty group that shares scroll positions along `axis`. Use `add()` to put
+    /// scroll views in it.
+    pub fn new(axis: ScrollSyncAxis) -> Self {
+        ScrollSync {
+            axis,
+            clip_views: Rc::new(RefCell::new(Vec::new())),
+            syncing: Rc::new(Cell::new(false)),
+            observers: Vec::new()
+        }
+    }
+
+    /// Shorthand for a group that shares the vertical scroll position.
+    pub fn vertical() -> Self {
+        Self::new(ScrollSyncAxis::Vertical)
+    }
+
+    /// Shorthand for a group that shares the horizontal scroll position.
+    pub fn horizontal() -> Self {
+        Self::new(ScrollSyncAxis::Horizontal)
+    }
+
+    /// Returns the axis this group keeps in step.
+    pub fn axis(&self) -> ScrollSyncAxis {
+        self.axis
+    }
+
+    /// Adds `scrollview` to the group. It's scrolled to match the views already in it right away,
+    /// and kept in step from then on. For a `ListView`, pass its `scrollview`.
+    pub fn add<T>(&mut self, scrollview: &ScrollView<T>) {
+        let clip_view: Id<Object, Shared> = scrollview.objc.get(|obj| unsafe {
+            let clip_view: id = msg_send![obj, contentView];
+            let _: () = msg_send![clip_view, setPostsBoundsChangedNotifications: YES];
+            Id::retain(clip_view).unwrap()
+        });
+
+        let leader = self.clip_views.borrow().first().cloned();
+        if let Some(leader) = leader {
+            self.syncing.set(true);
+            scroll_to_match(&*leader as *const Object as id, &*clip_view as *const Object as id, self.axis);
+            self.syncing.set(false);
+        }
+
+        self.clip_views.borrow_mut().push(clip_view.clone());
+
+        let axis = self.axis;
+        let clip_views = self.clip_views.clone();
+        let syncing = self.syncing.clone();
+
+        let block = ConcreteBlock::new(move |notification: id| {
+            // Scrolling the others posts notifications of its own; ignore those.
+            if syncing.get() {
+                return;
+            }
+
+            syncing.set(true);
+
+            let source: id = unsafe { msg_send![notification, object] };
+            for view in clip_views.borrow().iter() {
+                let view = &**view as *const Object as id;
+
+                if view != source {
+                    scroll_to_match(source, view, axis);
+                }
+            }
+
+            syncing.set(false);
+        });
+        let block = block.copy();
+
+        let name = NSString::new("NSViewBoundsDidChangeNotification");
+
+        self.observers.push(unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+
+            msg_send_id![
+                center,
+                addObserverForName: &*name,
+                object: &*clip_view,
+                queue: nil,
+                usingBlock: &*block
+            ]
+        });
+    }
+}
+
+impl Drop for ScrollSync {
+    fn drop(&mut self) {
+        unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+
+            for observer in &self.observers {
+                let _: () = msg_send![center, removeObserver: &**observer];
+            }
+        }
+    }
+}
+
+/// Scrolls the clip view `target` so that it lines up with `source` along `axis`, and lets its
+/// scroll view know so the scrollers follow.
+fn scroll_to_match(source: id, target: id, axis: ScrollSyncAxis) {
+    unsafe {
+        let source_bounds: CGRect = msg_send![source, bounds];
+        let target_bounds: CGRect = msg_send![target, bounds];
+
+        let point = match axis {
+            ScrollSyncAxis::Vertical => CGPoint::new(target_bounds.origin.x, source_bounds.origin.y),
+            ScrollSyncAxis::Horizontal => CGPoint::new(source_bounds.origin.x, target_bounds.origin.y),
+            ScrollSyncAxis::Both => source_bounds.origin
+        };
+
+        if point.x == target_bounds.origin.x && point.y == target_bounds.origin.y {
+            return;
+        }
+
+        let _: () = msg_send![target, scrollToPoint: point];
+
+        let scrollview: id = msg_send![target, superview];
+        if scrollview != nil {
+            let _: () = msg_send![scrollview, reflectScrolledClipView: target];
+        }
+    }
+}
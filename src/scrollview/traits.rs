@@ -1,4 +1,4 @@
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 use crate::dragdrop::{DragInfo, DragOperation};
 use crate::scrollview::ScrollView;
 
@@ -23,29 +23,29 @@ pub trait ScrollViewDelegate {
     /// Called when this has been removed from the view heirarchy.
     fn did_disappear(&self, _animated: bool) {}
 
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     /// Invoked when the dragged image enters destination bounds or frame; returns dragging operation to perform.
     fn dragging_entered(&self, _info: DragInfo) -> DragOperation {
         DragOperation::None
     }
 
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     /// Invoked when the image is released, allowing the receiver to agree to or refuse drag operation.
     fn prepare_for_drag_operation(&self, _info: DragInfo) -> bool {
         false
     }
 
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     /// Invoked after the released image has been removed from the screen, signaling the receiver to import the pasteboard data.
     fn perform_drag_operation(&self, _info: DragInfo) -> bool {
         false
     }
 
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     /// Invoked when the dragging operation is complete, signaling the receiver to perform any necessary clean-up.
     fn conclude_drag_operation(&self, _info: DragInfo) {}
 
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     /// Invoked when the dragged image exits the destination’s bounds rectangle (in the case of a view) or its frame
     /// rectangle (in the case of a window object).
     fn dragging_exited(&self, _info: DragInfo) {}
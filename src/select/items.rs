@@ -0,0 +1,197 @@
+//! Item management for `Select`, including the typed values that can be attached to each item.
+
+use std::any::Any;
+
+use objc::rc::{Id, Owned, Shared};
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, load_or_register_class, nil, to_bool, NSInteger, NSString};
+use crate::image::Image;
+use crate::utils::load;
+
+pub(crate) static SELECT_VALUE_PTR: &str = "rstSelectValuePtr";
+
+/// A handle to the items of a `Select`. You get one from `Select::items()`, or passed to the
+/// handler given to `Select::on_will_open()` - which is where you'd populate items lazily.
+///
+/// Indexes count separators, since they're items too.
+#[derive(Debug)]
+pub struct SelectItems(pub(crate) Id<Object, Shared>);
+
+impl SelectItems {
+    /// Returns the number of items (including separators).
+    pub fn len(&self) -> usize {
+        unsafe {
+            let count: NSInteger = msg_send![&*self.0, numberOfItems];
+            count as usize
+        }
+    }
+
+    /// Returns whether there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds an item to the end of the list.
+    ///
+    /// Unlike `Select::add_item()`, this won't replace an existing item with the same title.
+    pub fn add_item(&self, title: &str) {
+        self.insert_item(self.len(), title);
+    }
+
+    /// Adds an item to the end of the list, carrying `value`; see `Select::selected_value()`.
+    pub fn add_item_with_value<T: 'static>(&self, title: &str, value: T) {
+        self.insert_item_with_value(self.len(), title, value);
+    }
+
+    /// Inserts an item at `index`.
+    pub fn insert_item(&self, index: usize, title: &str) {
+        let item = menu_item(title);
+        self.insert(index, &item);
+    }
+
+    /// Inserts an item carrying `value` at `index`.
+    pub fn insert_item_with_value<T: 'static>(&self, index: usize, title: &str, value: T) {
+        let item = menu_item(title);
+        let value = SelectValue::new(Box::new(value));
+
+        unsafe {
+            let _: () = msg_send![&*item, setRepresentedObject: &*value];
+        }
+
+        self.insert(index, &item);
+    }
+
+    /// Adds a separator to the end of the list.
+    pub fn add_separator(&self) {
+        self.insert_separator(self.len());
+    }
+
+    /// Inserts a separator at `index`.
+    pub fn insert_separator(&self, index: usize) {
+        let item: Id<Object, Shared> = unsafe { msg_send_id![class!(NSMenuItem), separatorItem] };
+        self.insert(index, &item);
+    }
+
+    /// Removes the item at `index`.
+    pub fn remove_item_at_index(&self, index: usize) {
+        unsafe {
+            let _: () = msg_send![&*self.0, removeItemAtIndex: index as NSInteger];
+        }
+    }
+
+    /// Removes every item.
+    pub fn remove_all_items(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, removeAllItems];
+        }
+    }
+
+    /// Sets the image shown alongside the item at `index`.
+    pub fn set_image_at_index(&self, index: usize, image: &Image) {
+        if let Some(item) = self.item_at_index(index) {
+            unsafe {
+                let _: () = msg_send![item, setImage: &*image.0];
+            }
+        }
+    }
+
+    /// Returns a copy of the value attached to the item at `index`, if there is one and it's a
+    /// `T`.
+    pub fn value_at_index<T: Clone + 'static>(&self, index: usize) -> Option<T> {
+        let item = self.item_at_index(index)?;
+
+        unsafe {
+            let value: id = msg_send![item, representedObject];
+            SelectValue::get::<T>(value)
+        }
+    }
+
+    /// Returns the `NSMenuItem` at `index`, or `None` if it's out of bounds.
+    fn item_at_index(&self, index: usize) -> Option<id> {
+        let item: id = unsafe { msg_send![&*self.0, itemAtIndex: index as NSInteger] };
+
+        match item == nil {
+            true => None,
+            false => Some(item)
+        }
+    }
+
+    /// Inserts a menu item into the backing menu, clamping `index` to the end of the list.
+    fn insert<T>(&self, index: usize, item: &Id<Object, T>) {
+        let index = index.min(self.len()) as NSInteger;
+
+        unsafe {
+            let menu: id = msg_send![&*self.0, menu];
+            let _: () = msg_send![menu, insertItem: &**item, atIndex: index];
+        }
+    }
+}
+
+/// Creates a plain `NSMenuItem`. The pop up button takes care of its target and action.
+fn menu_item(title: &str) -> Id<Object, Owned> {
+    let title = NSString::new(title);
+    let key_equivalent = NSString::new("");
+
+    unsafe {
+        msg_send_id![
+            msg_send_id![class!(NSMenuItem), alloc],
+            initWithTitle: &*title,
+            action: None::<Sel>,
+            keyEquivalent: &*key_equivalent,
+        ]
+    }
+}
+
+/// An indirection layer for item values, for the same reasons as `invoker::Action`.
+struct SelectValue(Box<dyn Any>);
+
+impl SelectValue {
+    /// Wraps `value` in an Objective-C object, suitable for use as a `representedObject`.
+    fn new(value: Box<dyn Any>) -> Id<Object, Shared> {
+        let ptr = Box::into_raw(Box::new(SelectValue(value)));
+
+        unsafe {
+            let mut objc: Id<Object, Owned> = msg_send_id![register_value_class(), new];
+            objc.set_ivar(SELECT_VALUE_PTR, ptr as usize);
+            objc.into()
+        }
+    }
+
+    /// Reads a `T` back out of an object created by `new()`. Returns `None` for `nil`, objects we
+    /// didn't create, or values of a different type.
+    unsafe fn get<T: Clone + 'static>(objc: id) -> Option<T> {
+        if objc == nil {
+            return None;
+        }
+
+        if !to_bool(msg_send![objc, isKindOfClass: register_value_class()]) {
+            return None;
+        }
+
+        let value = load::<SelectValue>(&*objc, SELECT_VALUE_PTR);
+        value.0.downcast_ref::<T>().cloned()
+    }
+}
+
+/// Frees the boxed value when the item (and so its `representedObject`) goes away.
+extern "C" fn dealloc(this: &Object, _: Sel) {
+    unsafe {
+        let ptr: usize = *this.get_ivar(SELECT_VALUE_PTR);
+
+        if ptr != 0 {
+            drop(Box::from_raw(ptr as *mut SelectValue));
+        }
+
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
+/// Registers an `NSObject` subclass that owns a boxed Rust value.
+fn register_value_class() -> &'static Class {
+    load_or_register_class("NSObject", "RSTSelectValue", |decl| unsafe {
+        decl.add_ivar::<usize>(SELECT_VALUE_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
+    })
+}
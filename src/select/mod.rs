@@ -1,10 +1,12 @@
 //! Implements a Select-style dropdown. By default this uses NSPopupSelect on macOS.
 
+use block::ConcreteBlock;
 use core_graphics::geometry::CGRect;
 use objc::rc::{Id, Shared};
 use objc::runtime::{Class, Object};
-use objc::{msg_send, msg_send_id, sel};
+use objc::{class, msg_send, msg_send_id, sel};
 
+use crate::binding::{Binding, Property};
 use crate::control::Control;
 use crate::foundation::{id, load_or_register_class, nil, NSInteger, NSString, NO, YES};
 use crate::geometry::Rect;
@@ -15,6 +17,9 @@ use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 use crate::objc_access::ObjcAccess;
 use crate::utils::properties::ObjcProperty;
 
+mod items;
+pub use items::SelectItems;
+
 /// Wraps `NSPopUpSelect` on AppKit. Not currently implemented for iOS.
 ///
 /// Acts like a `<select>` dropdown, if you're familiar with HTML. Use for dropdown option
@@ -33,6 +38,23 @@ use crate::utils::properties::ObjcProperty;
 /// // Make sure you don't let your Select drop for as long as you need it.
 /// my_view.add_subview(&dropdown);
 /// ```
+///
+/// Items can carry a value of your own, which saves mapping indexes or titles back to your data:
+///
+/// ```rust,no_run
+/// use cacao::select::Select;
+///
+/// #[derive(Clone)]
+/// enum Quality { Low, High }
+///
+/// let dropdown = Select::new();
+/// let items = dropdown.items();
+/// items.add_item_with_value("Low", Quality::Low);
+/// items.add_separator();
+/// items.add_item_with_value("High", Quality::High);
+///
+/// let quality: Option<Quality> = dropdown.selected_value();
+/// ```
 #[derive(Debug)]
 pub struct Select {
     /// A handle for the underlying Objective-C object.
@@ -40,6 +62,8 @@ pub struct Select {
 
     handler: Option<TargetActionHandler>,
 
+    will_open: Option<Id<Object, Shared>>,
+
     /// A pointer to the Objective-C runtime top layout constraint.
     #[cfg(feature = "autolayout")]
     pub top: LayoutAnchorY,
@@ -99,6 +123,7 @@ impl Select {
 
         Select {
             handler: None,
+            will_open: None,
 
             #[cfg(feature = "autolayout")]
             top: LayoutAnchorY::top(view),
@@ -147,6 +172,35 @@ impl Select {
         self.handler = Some(handler);
     }
 
+    /// Binds the selected index of this dropdown to `property`, in both directions: the selection
+    /// updates when the property changes, and the property updates when the user picks an item.
+    /// The binding is removed when the returned `Binding` is dropped.
+    ///
+    /// Like `Switch::bind_checked()`, this takes over the target/action, replacing any
+    /// `set_action` callback.
+    pub fn bind_selected_index(&mut self, property: &Property<usize>) -> Binding {
+        let objc = self.objc.clone();
+
+        let subscription = property.observe_and_apply(move |index| {
+            objc.with_mut(|obj| unsafe {
+                let _: () = msg_send![obj, selectItemAtIndex: *index as NSInteger];
+            });
+        });
+
+        let sender = property.sender();
+        let this: Id<Object, Shared> = self.objc.get(|obj| unsafe { msg_send_id![obj, self] });
+        let handler = TargetActionHandler::new(&*this, move |control| {
+            let index: NSInteger = unsafe { msg_send![control, indexOfSelectedItem] };
+
+            if index >= 0 {
+                sender.send(index as usize);
+            }
+        });
+
+        self.handler = None;
+        Binding::with_action(subscription, handler)
+    }
+
     /// Sets whether this pulls down (dropdown) or pops up.
     pub fn set_pulls_down(&self, pulls_down: bool) {
         self.objc.with_mut(|obj| unsafe {
@@ -193,6 +247,59 @@ impl Select {
         })
     }
 
+    /// Returns a handle for managing the items in the dropdown: inserting items at an index,
+    /// attaching values and images, adding separators and so on.
+    pub fn items(&self) -> SelectItems {
+        SelectItems(self.objc.get(|obj| unsafe { msg_send_id![obj, self] }))
+    }
+
+    /// Returns a copy of the value attached to the selected item, if there is one and it's a `T`.
+    /// See `SelectItems::add_item_with_value()`.
+    pub fn selected_value<T: Clone + 'static>(&self) -> Option<T> {
+        let index: NSInteger = self.objc.get(|obj| unsafe { msg_send![obj, indexOfSelectedItem] });
+
+        match index {
+            -1 => None,
+            index => self.items().value_at_index(index as usize)
+        }
+    }
+
+    /// Sets a handler that's called right before the dropdown menu opens. This is where you can
+    /// (re)populate items that are expensive to compute, rather than keeping them up to date.
+    pub fn on_will_open<F: Fn(&SelectItems) + 'static>(&mut self, handler: F) {
+        self.remove_will_open_observer();
+
+        let items = self.items();
+        let block = ConcreteBlock::new(move |_notification: id| {
+            handler(&items);
+        });
+        let block = block.copy();
+
+        let name = NSString::new("NSPopUpButtonWillPopUpNotification");
+
+        self.will_open = Some(self.objc.get(|obj| unsafe {
+            let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+
+            msg_send_id![
+                center,
+                addObserverForName: &*name,
+                object: obj,
+                queue: nil,
+                usingBlock: &*block
+            ]
+        }));
+    }
+
+    /// Stops observing the menu opening, if we were.
+    fn remove_will_open_observer(&mut self) {
+        if let Some(observer) = self.will_open.take() {
+            unsafe {
+                let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+                let _: () = msg_send![center, removeObserver: &*observer];
+            }
+        }
+    }
+
     /// Returns the number of items in the dropdown.
     pub fn len(&self) -> usize {
         self.objc.get(|obj| unsafe {
@@ -253,6 +360,8 @@ impl Drop for Select {
     // Just to be sure, let's... nil these out. They should be weak references,
     // but I'd rather be paranoid and remove them later.
     fn drop(&mut self) {
+        self.remove_will_open_observer();
+
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![obj, setTarget: nil];
             let _: () = msg_send![obj, setAction: nil];
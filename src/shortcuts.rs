@@ -0,0 +1,197 @@
+//! Exposes app actions to Shortcuts and Siri Suggestions, by donating `NSUserActivity`s.
+//!
+//! Each action your app wants to expose gets an activity type (declared in your `Info.plist`
+//! under `NSUserActivityTypes`). Whenever the user performs that action, donate a `Shortcut` for
+//! it; the system learns from these, and the user can add them to Shortcuts. When a shortcut is
+//! run, your app is sent the activity back - route it to the handler you registered for its type
+//! from `AppDelegate::continue_user_activity()`:
+//!
+//! ```rust,no_run
+//! use cacao::appkit::AppDelegate;
+//! use cacao::shortcuts::{Shortcut, Shortcuts};
+//! use cacao::user_activity::UserActivity;
+//!
+//! struct MyApp;
+//!
+//! impl AppDelegate for MyApp {
+//!     fn did_finish_launching(&self) {
+//!         Shortcuts::on("com.example.new-note", |activity| {
+//!             let folder = activity.user_info_value("folder");
+//!             // Create a note in `folder`...
+//!         });
+//!     }
+//!
+//!     fn continue_user_activity<F: Fn()>(&self, activity: UserActivity, _handler: F) -> bool {
+//!         Shortcuts::handle(&activity)
+//!     }
+//! }
+//!
+//! // Later, when the user creates a note:
+//! let donation = Shortcut::new("com.example.new-note", "New Note in Inbox")
+//!     .user_info("folder", "inbox")
+//!     .donate();
+//! ```
+//!
+//! Richer integration (App Intents, with parameters the Shortcuts editor understands) requires
+//! Swift, and isn't covered here.
+//!
+//! To use this module, you must specify the `shortcuts` feature flag in your `Cargo.toml`.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use block::ConcreteBlock;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel};
+
+use crate::foundation::{id, NSArray, NSMutableDictionary, NSString, YES};
+use crate::user_activity::UserActivity;
+use crate::utils::responds_to;
+
+thread_local! {
+    /// Handlers are invoked from app delegate callbacks, which happen on the main thread.
+    static HANDLERS: RefCell<HashMap<String, Rc<dyn Fn(&UserActivity)>>> = RefCell::new(HashMap::new());
+}
+
+/// An action the user has just performed, to be donated to the system.
+#[derive(Clone, Debug, Default)]
+pub struct Shortcut {
+    /// The activity type, as declared in `NSUserActivityTypes`.
+    pub activity_type: String,
+
+    /// The user-facing title, e.g "New Note in Inbox".
+    pub title: String,
+
+    /// Identifies this donation, so it can be deleted later with `Shortcuts::delete()`.
+    pub persistent_identifier: Option<String>,
+
+    /// String values your handler needs to perform the action again.
+    pub user_info: Vec<(String, String)>
+}
+
+impl Shortcut {
+    /// Creates a new shortcut for the given activity type.
+    pub fn new(activity_type: &str, title: &str) -> Self {
+        Shortcut {
+            activity_type: activity_type.to_string(),
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the persistent identifier for this donation.
+    pub fn persistent_identifier(mut self, identifier: &str) -> Self {
+        self.persistent_identifier = Some(identifier.to_string());
+        self
+    }
+
+    /// Adds a value to the activity's `userInfo`.
+    pub fn user_info(mut self, key: &str, value: &str) -> Self {
+        self.user_info.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Donates this shortcut, making it the current user activity.
+    ///
+    /// The system only considers the activity while it's alive, so hold on to the returned
+    /// `UserActivity` for as long as the user is in this context (e.g, until they navigate away).
+    pub fn donate(&self) -> UserActivity {
+        let activity = UserActivity::new(&self.activity_type);
+        activity.set_title(&self.title);
+
+        let mut user_info = NSMutableDictionary::new();
+        for (key, value) in &self.user_info {
+            let value = NSString::new(value);
+            user_info.insert(NSString::new(key), &*value.objc as *const Object as id);
+        }
+
+        unsafe {
+            let _: () = msg_send![&*activity.0, setUserInfo: &*user_info];
+            let _: () = msg_send![&*activity.0, setEligibleForSearch: YES];
+
+            // Prediction (and persistent identifiers) aren't available on every OS version that
+            // has `NSUserActivity`; without them the donation still works for search and handoff.
+            if responds_to(&activity.0, sel!(setEligibleForPrediction:)) {
+                let _: () = msg_send![&*activity.0, setEligibleForPrediction: YES];
+            }
+
+            if let Some(identifier) = &self.persistent_identifier {
+                if responds_to(&activity.0, sel!(setPersistentIdentifier:)) {
+                    let identifier = NSString::new(identifier);
+                    let _: () = msg_send![&*activity.0, setPersistentIdentifier: &*identifier];
+                }
+            }
+
+            let _: () = msg_send![&*activity.0, becomeCurrent];
+        }
+
+        activity
+    }
+}
+
+/// Routes incoming shortcut activities to handlers, and manages donations.
+#[derive(Debug)]
+pub struct Shortcuts;
+
+impl Shortcuts {
+    /// Registers `handler` for activities of `activity_type`, replacing any existing handler.
+    pub fn on<F: Fn(&UserActivity) + 'static>(activity_type: &str, handler: F) {
+        HANDLERS.with(|handlers| {
+            handlers.borrow_mut().insert(activity_type.to_string(), Rc::new(handler));
+        });
+    }
+
+    /// Calls the handler registered for this activity's type. Returns whether there was one - which
+    /// makes this suitable as the return value of `continue_user_activity()`.
+    pub fn handle(activity: &UserActivity) -> bool {
+        let activity_type = activity.activity_type();
+
+        // Cloned out so that the handler is free to register (or replace) handlers itself.
+        let handler = HANDLERS.with(|handlers| handlers.borrow().get(&activity_type).cloned());
+
+        match handler {
+            Some(handler) => {
+                handler(activity);
+                true
+            },
+
+            None => false
+        }
+    }
+
+    /// Deletes donations with the given persistent identifiers, e.g when the content they refer to
+    /// has been deleted.
+    pub fn delete<F: Fn() + Send + Sync + 'static>(persistent_identifiers: &[&str], completion: F) {
+        let identifiers: Vec<NSString> = persistent_identifiers
+            .iter()
+            .map(|identifier| NSString::new(identifier))
+            .collect();
+        let identifiers: NSArray = identifiers
+            .iter()
+            .map(|identifier| &*identifier.objc as *const Object as id)
+            .collect::<Vec<id>>()
+            .into();
+
+        let block = ConcreteBlock::new(move || completion());
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![
+                class!(NSUserActivity),
+                deleteSavedUserActivitiesWithPersistentIdentifiers: &*identifiers,
+                completionHandler: &*block
+            ];
+        }
+    }
+
+    /// Deletes every donation this app has made.
+    pub fn delete_all<F: Fn() + Send + Sync + 'static>(completion: F) {
+        let block = ConcreteBlock::new(move || completion());
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![class!(NSUserActivity), deleteAllSavedUserActivitiesWithCompletionHandler: &*block];
+        }
+    }
+}
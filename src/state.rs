@@ -0,0 +1,153 @@
+//! A cell type for holding mutable state inside of delegates.
+//!
+//! Delegate methods take `&self`, and this is deliberate: the Objective-C side can call back into
+//! a delegate while Rust code is already running inside of it (e.g, setting a view's frame fires
+//! `did_change_frame()` synchronously). Handing out `&mut self` there would mean two live mutable
+//! references, which is undefined behavior.
+//!
+//! Delegates are only ever called on the main thread, though, so there's no need for the
+//! `Arc<RwLock<T>>` dance - `State<T>` is a lightweight, single-threaded cell for exactly this
+//! case.
+//!
+//! ```rust,no_run
+//! use cacao::appkit::window::WindowDelegate;
+//! use cacao::state::State;
+//!
+//! #[derive(Default)]
+//! struct MyWindow {
+//!     resize_count: State<usize>
+//! }
+//!
+//! impl WindowDelegate for MyWindow {
+//!     const NAME: &'static str = "MyWindow";
+//!
+//!     fn did_resize(&self) {
+//!         self.resize_count.with_mut(|count| *count += 1);
+//!     }
+//! }
+//! ```
+//!
+//! Mutable access is scoped to a closure, which keeps borrows short. If a callback re-enters and
+//! tries to mutate state that's already being mutated, this panics with a descriptive message
+//! rather than silently aliasing.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A main-thread cell for mutable delegate state. See the module documentation for more info.
+///
+/// This is neither `Send` nor `Sync`; it's intended to live on delegates, which are only accessed
+/// from the main thread.
+pub struct State<T> {
+    value: RefCell<T>,
+    _main_thread_only: PhantomData<*const ()>
+}
+
+impl<T> State<T> {
+    /// Wraps `value` in a new `State`.
+    pub fn new(value: T) -> Self {
+        State {
+            value: RefCell::new(value),
+            _main_thread_only: PhantomData
+        }
+    }
+
+    /// Runs `handler` with a shared reference to the value.
+    ///
+    /// Panics if the value is currently being mutated (i.e, from inside `with_mut`).
+    pub fn with<R, F: FnOnce(&T) -> R>(&self, handler: F) -> R {
+        let value = self
+            .value
+            .try_borrow()
+            .expect("State is being mutated; a callback re-entered while `with_mut` was running");
+
+        handler(&value)
+    }
+
+    /// Runs `handler` with a mutable reference to the value.
+    ///
+    /// Panics if the value is already borrowed - this generally means that a callback fired while
+    /// you were inside `with` or `with_mut`. Keep the closure to just the state change, and call
+    /// into other controls after it returns.
+    pub fn with_mut<R, F: FnOnce(&mut T) -> R>(&self, handler: F) -> R {
+        let mut value = self
+            .value
+            .try_borrow_mut()
+            .expect("State is already borrowed; a callback re-entered while `with` or `with_mut` was running");
+
+        handler(&mut value)
+    }
+
+    /// Replaces the value, returning the old one.
+    pub fn replace(&self, value: T) -> T {
+        self.with_mut(|current| std::mem::replace(current, value))
+    }
+
+    /// Sets the value, dropping the old one.
+    pub fn set(&self, value: T) {
+        self.replace(value);
+    }
+
+    /// Consumes the `State`, returning the value.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: Clone> State<T> {
+    /// Returns a clone of the value.
+    pub fn get(&self) -> T {
+        self.with(T::clone)
+    }
+}
+
+impl<T: Default> State<T> {
+    /// Takes the value, leaving `Default::default()` in its place.
+    pub fn take(&self) -> T {
+        self.replace(T::default())
+    }
+}
+
+impl<T: Default> Default for State<T> {
+    fn default() -> Self {
+        State::new(T::default())
+    }
+}
+
+impl<T> From<T> for State<T> {
+    fn from(value: T) -> Self {
+        State::new(value)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for State<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value.try_borrow() {
+            Ok(value) => f.debug_tuple("State").field(&*value).finish(),
+            Err(_) => f.write_str("State(<borrowed>)")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::State;
+
+    #[test]
+    fn state_mutation() {
+        let state = State::new(vec![1, 2]);
+        state.with_mut(|values| values.push(3));
+
+        assert_eq!(state.get(), vec![1, 2, 3]);
+        assert_eq!(state.take(), vec![1, 2, 3]);
+        assert_eq!(state.with(|values| values.len()), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn state_reentrant_mutation_panics() {
+        let state = State::new(0);
+        state.with_mut(|_| state.set(1));
+    }
+}
@@ -0,0 +1,264 @@
+//! Declaring your own subclasses of cacao's classes, with typed method overrides.
+//!
+//! Internally, cacao subclasses a good chunk of AppKit/UIKit to hook in delegates and so on. The
+//! delegate traits cover the common cases, but sooner or later you'll want to override something
+//! they don't expose - `acceptsFirstMouse:` on a view, say. Rather than dropping to `ClassDecl`
+//! and `extern "C"` functions, you can describe the overrides with a `Subclass` and hand the
+//! resulting class to a control's constructor:
+//!
+//! ```rust,no_run
+//! use cacao::subclass::Subclass;
+//! use cacao::utils::CGSize;
+//! use cacao::view::View;
+//!
+//! let class = Subclass::view("ClickThroughView")
+//!     .accepts_first_mouse(|_view, _event| true)
+//!     .intrinsic_content_size(|_view| CGSize::new(120., 44.))
+//!     .register();
+//!
+//! let view = View::with_class(class);
+//! ```
+//!
+//! Overrides are plain Rust closures; under the hood, each one is installed as a shared
+//! trampoline method that looks the closure up by class and selector. Subclasses descend from the
+//! same classes cacao uses internally, so the resulting objects work with everything the stock
+//! controls do.
+//!
+//! Closures must be `Send + Sync`, as they live in a global registry - but they'll only ever be
+//! called on the main thread, from the Objective-C runtime.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use lazy_static::lazy_static;
+use objc::runtime::{Bool, Class, Object, Sel};
+use objc::{msg_send, sel};
+
+#[cfg(appkit)]
+use crate::appkit::Event;
+
+use crate::foundation::{id, load_or_register_class};
+use crate::utils::CGSize;
+
+/// A registered override, keyed by class and selector.
+#[derive(Clone)]
+enum Handler {
+    Bool(Arc<dyn Fn(&Object) -> bool + Send + Sync + 'static>),
+    BoolWithObject(Arc<dyn Fn(&Object, id) -> bool + Send + Sync + 'static>),
+    Size(Arc<dyn Fn(&Object) -> CGSize + Send + Sync + 'static>)
+}
+
+lazy_static! {
+    static ref HANDLERS: RwLock<HashMap<(usize, String), Handler>> = RwLock::new(HashMap::new());
+}
+
+/// Finds the handler for `sel` on `this`, walking up the class hierarchy. We need to walk rather
+/// than look at the class directly, as the runtime can slot classes in underneath us (e.g, for
+/// KVO), and subclasses of subclasses should inherit overrides.
+fn handler_for(this: &Object, sel: Sel) -> Option<Handler> {
+    let handlers = HANDLERS.read().unwrap();
+    let name = sel.name().to_string();
+    let mut class = Some(this.class());
+
+    while let Some(cls) = class {
+        if let Some(handler) = handlers.get(&(cls as *const Class as usize, name.clone())) {
+            return Some(handler.clone());
+        }
+
+        class = cls.superclass();
+    }
+
+    None
+}
+
+/// Trampoline for `- (BOOL)method`.
+extern "C" fn bool_method(this: &Object, sel: Sel) -> Bool {
+    match handler_for(this, sel) {
+        Some(Handler::Bool(handler)) => Bool::new(handler(this)),
+        _ => Bool::NO
+    }
+}
+
+/// Trampoline for `- (BOOL)method:(id)arg`.
+extern "C" fn bool_with_object_method(this: &Object, sel: Sel, arg: id) -> Bool {
+    match handler_for(this, sel) {
+        Some(Handler::BoolWithObject(handler)) => Bool::new(handler(this, arg)),
+        _ => Bool::NO
+    }
+}
+
+/// Trampoline for `- (CGSize)method`.
+extern "C" fn size_method(this: &Object, sel: Sel) -> CGSize {
+    match handler_for(this, sel) {
+        Some(Handler::Size(handler)) => handler(this),
+        _ => CGSize::zero()
+    }
+}
+
+/// Describes a subclass of one of cacao's classes, along with the methods it overrides. Call
+/// `register()` to create (or load) the class.
+///
+/// Registering the same subclass name again returns the existing class. The closures from the
+/// latest call replace the old ones, but the set of overridden methods is fixed by the first
+/// registration.
+pub struct Subclass {
+    superclass_name: &'static str,
+    name: &'static str,
+    overrides: Vec<(Sel, Handler)>
+}
+
+impl Subclass {
+    /// Describes a subclass of an arbitrary class, by name. Prefer the control-specific
+    /// constructors (e.g, `Subclass::view()`) where possible, as they subclass the classes that
+    /// cacao's controls expect.
+    pub fn new(superclass_name: &'static str, name: &'static str) -> Self {
+        Subclass {
+            superclass_name,
+            name,
+            overrides: Vec::new()
+        }
+    }
+
+    /// Describes a subclass of the class that backs `View`. Pass the registered class to
+    /// `View::with_class()`.
+    pub fn view(name: &'static str) -> Self {
+        Subclass::new(crate::view::native_interface::register_view_class().name(), name)
+    }
+
+    /// Describes a subclass of the class that backs `Button`. Pass the registered class to
+    /// `Button::with_class()`.
+    pub fn button(name: &'static str) -> Self {
+        Subclass::new(crate::button::register_class().name(), name)
+    }
+
+    /// Overrides a method with the signature `- (BOOL)method`, such as `acceptsFirstResponder`.
+    pub fn override_bool<F>(mut self, sel: Sel, handler: F) -> Self
+    where
+        F: Fn(&Object) -> bool + Send + Sync + 'static
+    {
+        self.overrides.push((sel, Handler::Bool(Arc::new(handler))));
+        self
+    }
+
+    /// Overrides a method with the signature `- (BOOL)method:(id)arg`, such as
+    /// `acceptsFirstMouse:`.
+    pub fn override_bool_with_object<F>(mut self, sel: Sel, handler: F) -> Self
+    where
+        F: Fn(&Object, id) -> bool + Send + Sync + 'static
+    {
+        self.overrides.push((sel, Handler::BoolWithObject(Arc::new(handler))));
+        self
+    }
+
+    /// Overrides a method with the signature `- (CGSize)method`, such as `intrinsicContentSize`.
+    pub fn override_size<F>(mut self, sel: Sel, handler: F) -> Self
+    where
+        F: Fn(&Object) -> CGSize + Send + Sync + 'static
+    {
+        self.overrides.push((sel, Handler::Size(Arc::new(handler))));
+        self
+    }
+
+    /// Overrides `acceptsFirstResponder`, which controls whether the view can take keyboard focus.
+    pub fn accepts_first_responder<F>(self, handler: F) -> Self
+    where
+        F: Fn(&Object) -> bool + Send + Sync + 'static
+    {
+        self.override_bool(sel!(acceptsFirstResponder), handler)
+    }
+
+    /// Overrides `acceptsFirstMouse:`, which controls whether a click on this view in an inactive
+    /// window is delivered to the view (rather than just activating the window).
+    #[cfg(appkit)]
+    pub fn accepts_first_mouse<F>(self, handler: F) -> Self
+    where
+        F: Fn(&Object, Option<Event>) -> bool + Send + Sync + 'static
+    {
+        self.override_bool_with_object(sel!(acceptsFirstMouse:), move |this, event| {
+            let event = match event.is_null() {
+                true => None,
+                false => Some(Event::new(event))
+            };
+
+            handler(this, event)
+        })
+    }
+
+    /// Overrides `intrinsicContentSize`, which AutoLayout uses as the natural size of the view.
+    pub fn intrinsic_content_size<F>(self, handler: F) -> Self
+    where
+        F: Fn(&Object) -> CGSize + Send + Sync + 'static
+    {
+        self.override_size(sel!(intrinsicContentSize), handler)
+    }
+
+    /// Registers the subclass (or loads it, if it already exists) and installs the overrides.
+    pub fn register(self) -> &'static Class {
+        let methods: Vec<(Sel, MethodKind)> = self
+            .overrides
+            .iter()
+            .map(|(sel, handler)| (*sel, MethodKind::from(handler)))
+            .collect();
+
+        let class = load_or_register_class(self.superclass_name, self.name, move |decl| unsafe {
+            for (sel, kind) in &methods {
+                match kind {
+                    MethodKind::Bool => decl.add_method(*sel, bool_method as extern "C" fn(_, _) -> _),
+                    MethodKind::BoolWithObject => decl.add_method(*sel, bool_with_object_method as extern "C" fn(_, _, _) -> _),
+                    MethodKind::Size => decl.add_method(*sel, size_method as extern "C" fn(_, _) -> _)
+                }
+            }
+        });
+
+        let mut handlers = HANDLERS.write().unwrap();
+        for (sel, handler) in self.overrides {
+            handlers.insert((class as *const Class as usize, sel.name().to_string()), handler);
+        }
+
+        class
+    }
+}
+
+impl fmt::Debug for Subclass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let overrides: Vec<&str> = self.overrides.iter().map(|(sel, _)| sel.name()).collect();
+
+        f.debug_struct("Subclass")
+            .field("superclass_name", &self.superclass_name)
+            .field("name", &self.name)
+            .field("overrides", &overrides)
+            .finish()
+    }
+}
+
+/// The trampoline a given handler needs.
+#[derive(Copy, Clone, Debug)]
+enum MethodKind {
+    Bool,
+    BoolWithObject,
+    Size
+}
+
+impl From<&Handler> for MethodKind {
+    fn from(handler: &Handler) -> Self {
+        match handler {
+            Handler::Bool(_) => MethodKind::Bool,
+            Handler::BoolWithObject(_) => MethodKind::BoolWithObject,
+            Handler::Size(_) => MethodKind::Size
+        }
+    }
+}
+
+/// Asserts that `class` descends from `expected`. Control constructors that accept a custom class
+/// use this, as the rest of the control relies on ivars and methods from the cacao class.
+pub(crate) fn assert_subclass_of(class: &'static Class, expected: &'static Class) {
+    let is_subclass: Bool = unsafe { msg_send![class, isSubclassOfClass: expected] };
+
+    assert!(
+        is_subclass.as_bool(),
+        "{} must be a subclass of {}; create it with the matching `Subclass` constructor",
+        class.name(),
+        expected.name()
+    );
+}
@@ -5,7 +5,9 @@ use objc::rc::{Id, Shared};
 use objc::runtime::{Class, Object};
 use objc::{msg_send, msg_send_id, sel};
 
-use crate::foundation::{id, load_or_register_class, nil, NSString, NO};
+use crate::binding::{Binding, Property};
+use crate::control::Control;
+use crate::foundation::{id, load_or_register_class, nil, NSInteger, NSString, NO};
 use crate::invoker::TargetActionHandler;
 use crate::layout::Layout;
 #[cfg(feature = "autolayout")]
@@ -74,7 +76,7 @@ impl Switch {
             #[cfg(feature = "autolayout")]
             let _: () = msg_send![button, setTranslatesAutoresizingMaskIntoConstraints: NO];
 
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let _: () = msg_send![button, setButtonType:3];
 
             button
@@ -128,6 +130,36 @@ impl Switch {
         });
     }
 
+    /// Binds the checked state of this switch to `property`, in both directions: the switch
+    /// updates when the property changes, and the property updates when the user toggles the
+    /// switch. The binding is removed when the returned `Binding` is dropped.
+    ///
+    /// This takes over the target/action of the switch, so it replaces any `set_action` callback;
+    /// observe the property instead.
+    pub fn bind_checked(&mut self, property: &Property<bool>) -> Binding {
+        let objc = self.objc.clone();
+
+        let subscription = property.observe_and_apply(move |checked| {
+            objc.with_mut(|obj| unsafe {
+                let _: () = msg_send![obj, setState:match checked {
+                    true => 1,
+                    false => 0
+                }];
+            });
+        });
+
+        // Actions fire on the main thread, so this lands on the next turn of the run loop.
+        let sender = property.sender();
+        let this: Id<Object, Shared> = self.objc.get(|obj| unsafe { msg_send_id![obj, self] });
+        let handler = TargetActionHandler::new(&*this, move |control| {
+            let state: NSInteger = unsafe { msg_send![control, state] };
+            sender.send(state != 0);
+        });
+
+        self.handler = None;
+        Binding::with_action(subscription, handler)
+    }
+
     /// Attaches a callback for button press events. Don't get too creative now...
     /// best just to message pass or something.
     pub fn set_action<F: Fn(*const Object) + Send + Sync + 'static>(&mut self, action: F) {
@@ -159,6 +191,8 @@ impl Layout for Switch {
     }
 }
 
+impl Control for Switch {}
+
 impl Drop for Switch {
     // Just to be sure, let's... nil these out. They should be weak references,
     // but I'd rather be paranoid and remove them later.
@@ -0,0 +1,78 @@
+//! Helpers for running UI code in tests, including on headless CI machines.
+//!
+//! Constructing controls doesn't require a running application, but AppKit expects the shared
+//! `NSApplication` to exist before views are created. Call `initialize()` at the top of any test
+//! that touches UI code; it's safe to call more than once.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use cacao::testing::{initialize, run_loop_until};
+//! use cacao::view::View;
+//!
+//! initialize();
+//!
+//! let view = View::new();
+//! assert!(run_loop_until(|| true, Duration::from_secs(1)));
+//! ```
+//!
+//! For visual regression tests, see `assert_snapshot` and `Snapshot`.
+//!
+//! This module is available in the crate's own tests, and to downstream crates via the `testing`
+//! feature.
+
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use objc::{class, msg_send, sel};
+
+use crate::foundation::{id, NSString, BOOL};
+
+#[cfg(appkit)]
+mod snapshot;
+
+#[cfg(appkit)]
+pub use snapshot::{assert_snapshot, render_view, Snapshot, SnapshotComparison, RECORD_SNAPSHOTS_ENV};
+
+static INIT: Once = Once::new();
+
+/// Initializes enough of the application environment to construct and configure controls,
+/// without launching the app or activating it (so no Dock icon or menu bar appears).
+///
+/// On AppKit, this creates the shared `NSApplication` with the `Prohibited` activation policy. On
+/// UIKit, the application can only be created via `UIApplicationMain`, so this is a noop.
+pub fn initialize() {
+    INIT.call_once(|| {
+        #[cfg(appkit)]
+        unsafe {
+            let app: id = msg_send![class!(NSApplication), sharedApplication];
+            let _: () = msg_send![app, setActivationPolicy: 2 as crate::foundation::NSInteger];
+        }
+    });
+}
+
+/// Runs the current thread's run loop until `condition` returns `true`, or `timeout` elapses.
+/// Returns whether the condition was met.
+///
+/// This is useful for testing asynchronous UI work (e.g, animations, or callbacks that are
+/// dispatched to the run loop), without starting the full application.
+pub fn run_loop_until<F: Fn() -> bool>(condition: F, timeout: Duration) -> bool {
+    let start = Instant::now();
+    let mode = NSString::new("kCFRunLoopDefaultMode");
+
+    loop {
+        if condition() {
+            return true;
+        }
+
+        if start.elapsed() >= timeout {
+            return false;
+        }
+
+        unsafe {
+            let run_loop: id = msg_send![class!(NSRunLoop), currentRunLoop];
+            let date: id = msg_send![class!(NSDate), dateWithTimeIntervalSinceNow: 0.01f64];
+            let _: BOOL = msg_send![run_loop, runMode: &*mode, beforeDate: date];
+        }
+    }
+}
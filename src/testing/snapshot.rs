@@ -0,0 +1,340 @@
+//! Snapshot ("visual regression") testing for views.
+//!
+//! Views are rendered at a fixed size and a 1x scale, regardless of the displays attached to the
+//! machine, so snapshots are stable across machines. The first time a snapshot test runs, there's
+//! no reference image, so it's recorded; commit it alongside your tests. On later runs, the
+//! rendered view is compared against it, and on a mismatch the actual image and a diff image are
+//! written next to the reference.
+//!
+//! Set `CACAO_RECORD_SNAPSHOTS=1` in the environment to re-record references after an
+//! intentional change.
+//!
+//! ```rust,no_run
+//! use cacao::testing::{assert_snapshot, initialize};
+//! use cacao::view::View;
+//!
+//! initialize();
+//!
+//! let view = View::new();
+//! assert_snapshot(&view, 200., 100., "tests/snapshots/view.png", 2);
+//! ```
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::ptr;
+
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, nil, NSData, NSInteger, NSString, NSUInteger, NO, YES};
+use crate::geometry::Rect;
+use crate::image::Image;
+use crate::layout::Layout;
+
+/// The environment variable that, when set, causes `assert_snapshot` to overwrite references.
+pub const RECORD_SNAPSHOTS_ENV: &str = "CACAO_RECORD_SNAPSHOTS";
+
+/// Renders `view` at the given size (in points, at a 1x scale) into an `Image`.
+///
+/// The view's frame is set to the given size, and any pending layout is run first.
+pub fn render_view<V: Layout>(view: &V, width: f64, height: f64) -> Image {
+    let rect = CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(width, height));
+    view.set_frame(Rect::new(0., 0., width, height));
+
+    view.get_from_backing_obj(|obj| unsafe {
+        #[cfg(feature = "autolayout")]
+        let _: () = msg_send![obj, layoutSubtreeIfNeeded];
+
+        let rep = bitmap_rep(width.ceil() as usize, height.ceil() as usize);
+        let _: () = msg_send![obj, cacheDisplayInRect: rect, toBitmapImageRep: &*rep];
+
+        let alloc = msg_send_id![class!(NSImage), alloc];
+        let image: Id<Object, Shared> = msg_send_id![alloc, initWithSize: rect.size];
+        let _: () = msg_send![&*image, addRepresentation: &*rep];
+
+        Image(image)
+    })
+}
+
+/// Creates an RGBA, 8-bits-per-channel bitmap of exactly `width` x `height` pixels, with a point
+/// size to match (i.e, a 1x scale).
+fn bitmap_rep(width: usize, height: usize) -> Id<Object, Shared> {
+    let color_space = NSString::new("NSDeviceRGBColorSpace");
+
+    unsafe {
+        let alloc = msg_send_id![class!(NSBitmapImageRep), alloc];
+        let rep: Id<Object, Shared> = msg_send_id![
+            alloc,
+            initWithBitmapDataPlanes: ptr::null_mut::<*mut u8>(),
+            pixelsWide: width as NSInteger,
+            pixelsHigh: height as NSInteger,
+            bitsPerSample: 8 as NSInteger,
+            samplesPerPixel: 4 as NSInteger,
+            hasAlpha: YES,
+            isPlanar: NO,
+            colorSpaceName: &*color_space,
+            bytesPerRow: (width * 4) as NSInteger,
+            bitsPerPixel: 32 as NSInteger
+        ];
+
+        let _: () = msg_send![&*rep, setSize: CGSize::new(width as f64, height as f64)];
+        rep
+    }
+}
+
+/// The raw pixels of a rendered view or image, in a canonical format (8-bit RGBA, top row first)
+/// that can be compared byte-for-byte.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snapshot {
+    /// The width, in pixels.
+    pub width: usize,
+
+    /// The height, in pixels.
+    pub height: usize,
+
+    /// The pixel data, as RGBA bytes.
+    pub pixels: Vec<u8>
+}
+
+/// The result of comparing two snapshots.
+#[derive(Clone, Debug)]
+pub struct SnapshotComparison {
+    /// The number of pixels where some channel differed by more than the tolerance. If the
+    /// snapshots have different dimensions, this is every pixel.
+    pub differing_pixels: usize,
+
+    /// The largest difference seen in any single channel.
+    pub max_difference: u8,
+
+    /// An image highlighting the differing pixels in red, over a faded copy of the expected
+    /// image. This is `None` if the snapshots have different dimensions.
+    pub diff: Option<Snapshot>
+}
+
+impl SnapshotComparison {
+    /// Returns whether the snapshots matched (within the tolerance).
+    pub fn matches(&self) -> bool {
+        self.differing_pixels == 0
+    }
+}
+
+impl Snapshot {
+    /// Renders `view` at the given size and captures its pixels. See `render_view`.
+    pub fn of_view<V: Layout>(view: &V, width: f64, height: f64) -> Self {
+        Snapshot::of_image(
+            &render_view(view, width, height),
+            width.ceil() as usize,
+            height.ceil() as usize
+        )
+    }
+
+    /// Draws `image` into a `width` x `height` pixel bitmap and captures its pixels.
+    pub fn of_image(image: &Image, width: usize, height: usize) -> Self {
+        let rep = bitmap_rep(width, height);
+
+        unsafe {
+            let context: id = msg_send![class!(NSGraphicsContext), graphicsContextWithBitmapImageRep: &*rep];
+
+            let _: () = msg_send![class!(NSGraphicsContext), saveGraphicsState];
+            let _: () = msg_send![class!(NSGraphicsContext), setCurrentContext: context];
+
+            let rect = CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(width as f64, height as f64));
+            let _: () = msg_send![&*image.0, drawInRect: rect];
+
+            let _: () = msg_send![context, flushGraphics];
+            let _: () = msg_send![class!(NSGraphicsContext), restoreGraphicsState];
+
+            Snapshot::from_rep(&rep, width, height)
+        }
+    }
+
+    /// Reads a snapshot from PNG data. Returns `None` if the data isn't a valid image.
+    pub fn from_png(data: &[u8]) -> Option<Self> {
+        let data = NSData::with_slice(data);
+
+        unsafe {
+            let source: id = msg_send![class!(NSBitmapImageRep), imageRepWithData: &*data];
+            if source == nil {
+                return None;
+            }
+
+            let width: NSInteger = msg_send![source, pixelsWide];
+            let height: NSInteger = msg_send![source, pixelsHigh];
+            let (width, height) = (width as usize, height as usize);
+
+            let rep = bitmap_rep(width, height);
+            let context: id = msg_send![class!(NSGraphicsContext), graphicsContextWithBitmapImageRep: &*rep];
+
+            let _: () = msg_send![class!(NSGraphicsContext), saveGraphicsState];
+            let _: () = msg_send![class!(NSGraphicsContext), setCurrentContext: context];
+
+            let rect = CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(width as f64, height as f64));
+            let _: () = msg_send![source, drawInRect: rect];
+
+            let _: () = msg_send![context, flushGraphics];
+            let _: () = msg_send![class!(NSGraphicsContext), restoreGraphicsState];
+
+            Some(Snapshot::from_rep(&rep, width, height))
+        }
+    }
+
+    /// Reads a snapshot from a PNG file. Returns `None` if the file doesn't exist or isn't a valid
+    /// image.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Option<Self> {
+        fs::read(path).ok().and_then(|data| Snapshot::from_png(&data))
+    }
+
+    /// Copies the pixels out of one of our canonical bitmaps.
+    unsafe fn from_rep(rep: &Object, width: usize, height: usize) -> Self {
+        let bytes_per_row: NSInteger = msg_send![rep, bytesPerRow];
+        let data: *const u8 = msg_send![rep, bitmapData];
+
+        let mut pixels = Vec::with_capacity(width * height * 4);
+        for row in 0..height {
+            let start = data.add(row * bytes_per_row as usize);
+            pixels.extend_from_slice(std::slice::from_raw_parts(start, width * 4));
+        }
+
+        Snapshot { width, height, pixels }
+    }
+
+    /// Encodes this snapshot as PNG data.
+    pub fn png_data(&self) -> Option<Vec<u8>> {
+        let rep = bitmap_rep(self.width, self.height);
+
+        unsafe {
+            let data: *mut u8 = msg_send![&*rep, bitmapData];
+            ptr::copy_nonoverlapping(self.pixels.as_ptr(), data, self.pixels.len());
+
+            // NSBitmapImageFileTypePNG
+            let file_type: NSUInteger = 4;
+            let properties: id = msg_send![class!(NSDictionary), dictionary];
+            let png: id = msg_send![&*rep, representationUsingType: file_type, properties: properties];
+
+            match png == nil {
+                true => None,
+                false => Some(NSData::retain(png).into_vec())
+            }
+        }
+    }
+
+    /// Writes this snapshot to disk as a PNG, creating any missing parent directories.
+    pub fn write_png<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let path = path.as_ref();
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let data = self
+            .png_data()
+            .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::Other, "Unable to encode snapshot as PNG"))?;
+
+        fs::write(path, data)
+    }
+
+    /// Compares this snapshot (the actual rendering) against `expected`. Pixels where every
+    /// channel is within `tolerance` of the expected value are considered a match, which absorbs
+    /// minor antialiasing differences.
+    pub fn compare(&self, expected: &Snapshot, tolerance: u8) -> SnapshotComparison {
+        if self.width != expected.width || self.height != expected.height {
+            return SnapshotComparison {
+                differing_pixels: self.width.max(expected.width) * self.height.max(expected.height),
+                max_difference: u8::MAX,
+                diff: None
+            };
+        }
+
+        let mut differing_pixels = 0;
+        let mut max_difference = 0;
+        let mut diff = Vec::with_capacity(self.pixels.len());
+
+        for (actual, expected) in self.pixels.chunks(4).zip(expected.pixels.chunks(4)) {
+            let difference = actual
+                .iter()
+                .zip(expected.iter())
+                .map(|(a, e)| (*a as i16 - *e as i16).unsigned_abs() as u8)
+                .max()
+                .unwrap_or(0);
+
+            max_difference = max_difference.max(difference);
+
+            if difference > tolerance {
+                differing_pixels += 1;
+                diff.extend_from_slice(&[255, 0, 0, 255]);
+            } else {
+                // Faded, so the differences stand out.
+                diff.extend(expected[..3].iter().map(|channel| 191 + channel / 4));
+                diff.push(255);
+            }
+        }
+
+        SnapshotComparison {
+            differing_pixels,
+            max_difference,
+            diff: Some(Snapshot {
+                width: self.width,
+                height: self.height,
+                pixels: diff
+            })
+        }
+    }
+}
+
+/// Returns `path` with `suffix` inserted before the extension, e.g `view.png` -> `view.diff.png`.
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path.file_stem().map(|stem| stem.to_string_lossy()).unwrap_or_default();
+    path.with_file_name(format!("{}.{}.png", stem, suffix))
+}
+
+/// Renders `view` at the given size and compares it against the reference PNG at `reference`,
+/// panicking if they differ (see `Snapshot::compare` for how `tolerance` is applied).
+///
+/// If the reference doesn't exist, or `CACAO_RECORD_SNAPSHOTS` is set, the rendering is written
+/// as the new reference instead. On a mismatch, `<name>.actual.png` and `<name>.diff.png` are
+/// written next to the reference to help track down what changed.
+pub fn assert_snapshot<V: Layout, P: AsRef<Path>>(view: &V, width: f64, height: f64, reference: P, tolerance: u8) {
+    let reference = reference.as_ref();
+    let actual = Snapshot::of_view(view, width, height);
+
+    let expected = match env::var_os(RECORD_SNAPSHOTS_ENV) {
+        Some(_) => None,
+        None => Snapshot::from_file(reference)
+    };
+
+    let expected = match expected {
+        Some(expected) => expected,
+        None => {
+            actual
+                .write_png(reference)
+                .unwrap_or_else(|e| panic!("Unable to record snapshot at {}: {}", reference.display(), e));
+            return;
+        }
+    };
+
+    let comparison = actual.compare(&expected, tolerance);
+    if comparison.matches() {
+        return;
+    }
+
+    let actual_path = sibling_path(reference, "actual");
+    let _ = actual.write_png(&actual_path);
+
+    let diff_path = sibling_path(reference, "diff");
+    if let Some(diff) = &comparison.diff {
+        let _ = diff.write_png(&diff_path);
+    }
+
+    panic!(
+        "Snapshot {} does not match: {} pixels differ (max channel difference {}, tolerance {}). Wrote {} and {}.",
+        reference.display(),
+        comparison.differing_pixels,
+        comparison.max_difference,
+        tolerance,
+        actual_path.display(),
+        diff_path.display()
+    );
+}
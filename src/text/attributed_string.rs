@@ -1,23 +1,36 @@
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut, Range};
 use std::os::raw::c_char;
+use std::path::Path;
 use std::{fmt, slice, str};
 
+use core_graphics::base::CGFloat;
 use objc::rc::{Id, Owned};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
 use crate::color::Color;
-use crate::foundation::{id, to_bool, NSString, BOOL, NO, YES};
-use crate::utils::CFRange;
+use crate::error::Error;
+use crate::foundation::{
+    id, nil, to_bool, NSArray, NSData, NSInteger, NSMutableDictionary, NSNumber, NSString, NSUInteger, BOOL, NO, YES
+};
+use crate::utils::{CFRange, NSRange};
 
-use super::Font;
+use super::{DocumentType, Font, TextAlign};
+
+/// `NSUTF8StringEncoding`, used when reading and writing plain text and HTML.
+const UTF8_STRING_ENCODING: i64 = 4;
 
 extern "C" {
     static NSForegroundColorAttributeName: id;
     static NSFontAttributeName: id;
+    static NSLinkAttributeName: id;
+    static NSParagraphStyleAttributeName: id;
 }
 
+/// `NSTextCheckingTypeLink`, for finding URLs (and email addresses) with `NSDataDetector`.
+const LINK_CHECKING_TYPE: u64 = 1 << 5;
+
 /// A wrapper around `NSMutableAttributedString`, which can be used for more complex text
 /// rendering.
 ///
@@ -42,6 +55,112 @@ impl AttributedString {
         Self(unsafe { msg_send_id![value, mutableCopy] })
     }
 
+    /// Creates an `AttributedString` from data in the given format. This is what you'd use to load
+    /// a saved document, or to read rich text off of the pasteboard.
+    pub fn from_data(data: &[u8], document_type: DocumentType) -> Result<Self, Error> {
+        let data = NSData::with_slice(data);
+        let options = document_options(document_type);
+
+        unsafe {
+            let mut error: id = nil;
+            let alloc = msg_send_id![class!(NSMutableAttributedString), alloc];
+            let string: Option<Id<Object, Owned>> = msg_send_id![
+                alloc,
+                initWithData: &*data,
+                options: &*options,
+                documentAttributes: nil,
+                error: &mut error
+            ];
+
+            match string {
+                Some(string) => Ok(Self(string)),
+                None => Err(Error::new(error))
+            }
+        }
+    }
+
+    /// Creates an `AttributedString` from RTF data.
+    pub fn from_rtf(data: &[u8]) -> Result<Self, Error> {
+        Self::from_data(data, DocumentType::RTF)
+    }
+
+    /// Creates an `AttributedString` from (flat) RTFD data, including any attachments.
+    pub fn from_rtfd(data: &[u8]) -> Result<Self, Error> {
+        Self::from_data(data, DocumentType::RTFD)
+    }
+
+    /// Creates an `AttributedString` from HTML data. This must be called on the main thread.
+    pub fn from_html(data: &[u8]) -> Result<Self, Error> {
+        Self::from_data(data, DocumentType::HTML)
+    }
+
+    /// Creates an `AttributedString` from a file on disk. The format is detected from the file
+    /// itself, and this handles `.rtfd` packages (with their attachments) as well as flat files.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = NSString::new(&path.as_ref().to_string_lossy());
+        let options = NSMutableDictionary::new();
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), fileURLWithPath: &*path];
+
+            let mut error: id = nil;
+            let alloc = msg_send_id![class!(NSMutableAttributedString), alloc];
+            let string: Option<Id<Object, Owned>> = msg_send_id![
+                alloc,
+                initWithURL: url,
+                options: &*options,
+                documentAttributes: nil,
+                error: &mut error
+            ];
+
+            match string {
+                Some(string) => Ok(Self(string)),
+                None => Err(Error::new(error))
+            }
+        }
+    }
+
+    /// Converts the contents of this string to data in the given format, e.g for saving to disk or
+    /// placing on the pasteboard.
+    pub fn to_data(&self, document_type: DocumentType) -> Result<Vec<u8>, Error> {
+        let options = document_options(document_type);
+
+        unsafe {
+            let length: NSUInteger = msg_send![&*self.0, length];
+            let range = NSRange { location: 0, length };
+
+            let mut error: id = nil;
+            let data: id = msg_send![
+                &*self.0,
+                dataFromRange: range,
+                documentAttributes: &*options,
+                error: &mut error
+            ];
+
+            if data == nil {
+                return Err(Error::new(error));
+            }
+
+            Ok(NSData::retain(data).into_vec())
+        }
+    }
+
+    /// Converts the contents of this string to RTF data. Attachments are dropped; use `to_rtfd()`
+    /// if you need them.
+    pub fn to_rtf(&self) -> Result<Vec<u8>, Error> {
+        self.to_data(DocumentType::RTF)
+    }
+
+    /// Converts the contents of this string to (flat) RTFD data, including any attachments.
+    pub fn to_rtfd(&self) -> Result<Vec<u8>, Error> {
+        self.to_data(DocumentType::RTFD)
+    }
+
+    /// Converts the contents of this string to HTML data.
+    pub fn to_html(&self) -> Result<Vec<u8>, Error> {
+        self.to_data(DocumentType::HTML)
+    }
+
     /// Sets the text (foreground) color for the specified range.
     pub fn set_text_color<C: AsRef<Color>>(&mut self, color: C, range: Range<isize>) {
         let color: id = color.as_ref().into();
@@ -70,6 +189,108 @@ impl AttributedString {
             ];
         }
     }
+
+    /// Links the text in the specified range to `url`. Does nothing if `url` isn't a valid URL.
+    pub fn set_link(&mut self, url: &str, range: Range<isize>) {
+        let url = NSString::new(url);
+        let range = CFRange::init(range.start, range.end - range.start);
+
+        unsafe {
+            let url: id = msg_send![class!(NSURL), URLWithString:&*url];
+
+            if url != nil {
+                let _: () = msg_send![
+                    &*self.0,
+                    addAttribute: NSLinkAttributeName,
+                    value: url,
+                    range: range,
+                ];
+            }
+        }
+    }
+
+    /// Finds any URLs and email addresses in the text, and links each one to itself.
+    pub fn detect_links(&mut self) {
+        unsafe {
+            let detector: id = msg_send![
+                class!(NSDataDetector),
+                dataDetectorWithTypes: LINK_CHECKING_TYPE,
+                error: std::ptr::null_mut::<id>()
+            ];
+
+            if detector == nil {
+                return;
+            }
+
+            let string: id = msg_send![&*self.0, string];
+            let length: NSUInteger = msg_send![string, length];
+            let matches: id = msg_send![
+                detector,
+                matchesInString: string,
+                options: 0 as NSUInteger,
+                range: NSRange::from(0..length as usize),
+            ];
+
+            for result in NSArray::retain(matches).iter() {
+                let range: NSRange = msg_send![result, range];
+                let url: id = msg_send![result, URL];
+
+                if url != nil {
+                    let _: () = msg_send![
+                        &*self.0,
+                        addAttribute: NSLinkAttributeName,
+                        value: url,
+                        range: range,
+                    ];
+                }
+            }
+        }
+    }
+
+    /// Sets the line height multiple for the whole string, keeping the given alignment (as the
+    /// paragraph style carries both). A multiple of `1.0` is the font's natural line height.
+    pub fn set_line_height_multiple(&mut self, multiple: f64, alignment: TextAlign) {
+        self.set_paragraph_style(multiple, alignment.into(), None);
+    }
+
+    /// Applies a paragraph style with the given line height multiple, (raw) alignment and line
+    /// break mode to the whole string.
+    pub(crate) fn set_paragraph_style(&mut self, multiple: f64, alignment: NSInteger, line_break_mode: Option<NSUInteger>) {
+        unsafe {
+            let length: NSUInteger = msg_send![&*self.0, length];
+            let style: Id<Object, Owned> = msg_send_id![class!(NSMutableParagraphStyle), new];
+            let _: () = msg_send![&*style, setLineHeightMultiple: multiple as CGFloat];
+            let _: () = msg_send![&*style, setAlignment: alignment];
+
+            if let Some(mode) = line_break_mode {
+                let _: () = msg_send![&*style, setLineBreakMode: mode];
+            }
+
+            let _: () = msg_send![
+                &*self.0,
+                addAttribute: NSParagraphStyleAttributeName,
+                value: &*style,
+                range: NSRange::from(0..length as usize),
+            ];
+        }
+    }
+}
+
+/// Builds the options/attributes dictionary for reading or writing the given document type.
+fn document_options(document_type: DocumentType) -> NSMutableDictionary {
+    let mut options = NSMutableDictionary::new();
+
+    let value: NSString = document_type.into();
+    options.insert(NSString::new("DocumentType"), &*value.objc as *const Object as id);
+
+    // Plain text and HTML need to know the encoding; without it, reading guesses (and writing
+    // HTML falls back to a legacy encoding).
+    if document_type == DocumentType::PlainText || document_type == DocumentType::HTML {
+        let encoding = NSNumber::integer(UTF8_STRING_ENCODING);
+        options.insert(NSString::new("CharacterEncoding"), &*encoding.0 as *const Object as id);
+    }
+
+    options
 }
 
 impl fmt::Display for AttributedString {
@@ -1,4 +1,4 @@
-use crate::foundation::{NSInteger, NSUInteger};
+use crate::foundation::{NSInteger, NSString, NSUInteger};
 
 /// Specifies how text should align for a supported control.
 #[derive(Copy, Clone, Debug)]
@@ -65,3 +65,31 @@ impl Into<NSUInteger> for LineBreakMode {
         }
     }
 }
+
+/// Document formats that an `AttributedString` can be read from and written to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DocumentType {
+    /// Plain text, with no formatting. This is read and written as UTF-8.
+    PlainText,
+
+    /// Rich Text Format.
+    RTF,
+
+    /// Rich Text Format with attachments (e.g, images). When working with data (rather than a
+    /// file package), this is the "flat" RTFD format used by the pasteboard.
+    RTFD,
+
+    /// HTML. Note that reading HTML uses WebKit under the hood, and must happen on the main thread.
+    HTML
+}
+
+impl From<DocumentType> for NSString<'_> {
+    fn from(document_type: DocumentType) -> Self {
+        NSString::new(match document_type {
+            DocumentType::PlainText => "NSPlainText",
+            DocumentType::RTF => "NSRTF",
+            DocumentType::RTFD => "NSRTFD",
+            DocumentType::HTML => "NSHTML"
+        })
+    }
+}
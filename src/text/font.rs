@@ -8,8 +8,8 @@ use objc::rc::{Id, Shared};
 use objc::runtime::{Class, Object};
 use objc::{class, msg_send, msg_send_id, sel};
 
+use crate::available;
 use crate::foundation::{id, nil, NSArray, NSString, NO, YES};
-use crate::utils::os;
 
 /// A `Font` can be constructed and applied to supported controls to control things like text
 /// appearance and size.
@@ -22,10 +22,10 @@ impl Default for Font {
         let cls = Self::class();
         let default_size: id = unsafe { msg_send![cls, labelFontSize] };
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let font = Font(unsafe { msg_send_id![cls, labelFontOfSize: default_size] });
 
-        #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+        #[cfg(uikit)]
         let font = Font(unsafe { msg_send_id![cls, systemFontOfSize: default_size] });
         font
     }
@@ -33,9 +33,9 @@ impl Default for Font {
 
 impl Font {
     fn class() -> &'static Class {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let class = class!(NSFont);
-        #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+        #[cfg(uikit)]
         let class = class!(UIFont);
 
         class
@@ -66,7 +66,7 @@ impl Font {
         let size = size as CGFloat;
         let weight = weight as CGFloat;
 
-        if os::is_minimum_semversion(10, 15, 0) {
+        if available!(macos 10.15, ios 13) {
             Font(unsafe { msg_send_id![class!(NSFont), monospacedSystemFontOfSize: size, weight: weight] })
         } else {
             Font(unsafe { msg_send_id![class!(NSFont), systemFontOfSize: size, weight: weight] })
@@ -9,14 +9,16 @@
 
 use objc::runtime::Class;
 
-use crate::foundation::load_or_register_class;
+use crate::foundation::{load_or_register_class, StaticClass};
 use crate::text::label::{LabelDelegate, LABEL_DELEGATE_PTR};
 
 /// Injects an `NSTextField` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
 /// used if there's no delegates.
 pub(crate) fn register_view_class() -> &'static Class {
-    load_or_register_class("NSTextField", "RSTTextField", |decl| unsafe {})
+    static CLASS: StaticClass = StaticClass::new();
+
+    CLASS.get_or_register(|| load_or_register_class("NSTextField", "RSTTextField", |decl| unsafe {}))
 }
 
 /// Injects an `NSTextField` subclass, with some callback and pointer ivars for what we
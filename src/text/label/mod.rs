@@ -44,12 +44,19 @@
 //! For more information on Autolayout, view the module or check out the examples folder.
 
 use core_foundation::base::TCFType;
+#[cfg(appkit)]
+use core_graphics::base::CGFloat;
+#[cfg(appkit)]
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
 
 use objc::rc::{Id, Shared};
 use objc::runtime::{Class, Object};
 use objc::{msg_send, msg_send_id, sel};
 
+use crate::binding::{Binding, Property};
 use crate::color::Color;
+#[cfg(appkit)]
+use crate::foundation::to_bool;
 use crate::foundation::{id, nil, NSArray, NSInteger, NSString, NSUInteger, NO, YES};
 use crate::layer::Layer;
 use crate::layout::Layout;
@@ -60,17 +67,21 @@ use crate::utils::properties::ObjcProperty;
 #[cfg(feature = "autolayout")]
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 mod appkit;
 
-#[cfg(feature = "appkit")]
-use appkit::{register_view_class, register_view_class_with_delegate};
+#[cfg(appkit)]
+pub(crate) use appkit::register_view_class;
+#[cfg(appkit)]
+use appkit::register_view_class_with_delegate;
 
-#[cfg(feature = "uikit")]
+#[cfg(uikit)]
 mod uikit;
 
-#[cfg(all(feature = "uikit", not(feature = "appkit")))]
-use uikit::{register_view_class, register_view_class_with_delegate};
+#[cfg(uikit)]
+pub(crate) use uikit::register_view_class;
+#[cfg(uikit)]
+use uikit::register_view_class_with_delegate;
 
 mod traits;
 pub use traits::LabelDelegate;
@@ -80,7 +91,7 @@ pub(crate) static LABEL_DELEGATE_PTR: &str = "rstLabelDelegatePtr";
 /// A helper method for instantiating view classes and applying default settings to them.
 fn allocate_view(registration_fn: fn() -> &'static Class) -> id {
     unsafe {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let view: id = {
             // This sucks, but for now, sure.
             let blank = NSString::no_copy("");
@@ -92,13 +103,13 @@ fn allocate_view(registration_fn: fn() -> &'static Class) -> id {
             label
         };
 
-        #[cfg(feature = "uikit")]
+        #[cfg(uikit)]
         let view: id = msg_send![registration_fn(), new];
 
         #[cfg(feature = "autolayout")]
         let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let _: () = msg_send![view, setWantsLayer: YES];
 
         view
@@ -347,9 +358,9 @@ impl<T> Label<T> {
         let s = NSString::new(text);
 
         self.objc.with_mut(|obj| unsafe {
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let _: () = msg_send![obj, setStringValue:&*s];
-            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            #[cfg(uikit)]
             let _: () = msg_send![obj, setText:&*s];
         });
     }
@@ -357,20 +368,37 @@ impl<T> Label<T> {
     /// Sets the attributed string to be the attributed string value on this label.
     pub fn set_attributed_text(&self, text: AttributedString) {
         self.objc.with_mut(|obj| unsafe {
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let _: () = msg_send![obj, setAttributedStringValue:&*text];
-            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            #[cfg(uikit)]
             let _: () = msg_send![obj, setAttributedText:&*text];
         });
     }
 
+    /// Binds the text of this label to `property`, so that it updates whenever the property
+    /// changes. The binding is removed when the returned `Binding` is dropped.
+    pub fn bind_text(&self, property: &Property<String>) -> Binding {
+        let objc = self.objc.clone();
+
+        Binding::one_way(property.observe_and_apply(move |text| {
+            let s = NSString::new(text);
+
+            objc.with_mut(|obj| unsafe {
+                #[cfg(appkit)]
+                let _: () = msg_send![obj, setStringValue:&*s];
+                #[cfg(uikit)]
+                let _: () = msg_send![obj, setText:&*s];
+            });
+        }))
+    }
+
     /// Retrieve the text currently held in the label.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn get_text(&self) -> String {
         self.objc
             .get(|obj| unsafe { NSString::retain(msg_send![obj, stringValue]).to_string() })
     }
-    #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+    #[cfg(uikit)]
     pub fn get_text(&self) -> String {
         self.objc.get(|obj| {
             let val: id = unsafe { msg_send![obj, text] };
@@ -388,9 +416,9 @@ impl<T> Label<T> {
     pub fn set_text_alignment(&self, alignment: TextAlign) {
         self.objc.with_mut(|obj| unsafe {
             let alignment: NSInteger = alignment.into();
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let _: () = msg_send![obj, setAlignment: alignment];
-            #[cfg(all(feature = "uikit", not(feature = "appkit")))]
+            #[cfg(uikit)]
             let _: () = msg_send![obj, setTextAlignment: alignment];
         });
     }
@@ -419,16 +447,94 @@ impl<T> Label<T> {
     /// Sets the maximum number of lines.
     pub fn set_max_number_of_lines(&self, num: NSInteger) {
         self.objc.with_mut(|obj| unsafe {
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let _: () = msg_send![obj, setMaximumNumberOfLines: num];
-            #[cfg(feature = "uikit")]
+            #[cfg(uikit)]
             let _: () = msg_send![obj, setNumberOfLines: num];
         });
     }
 
+    /// Sets whether the user can select (and copy) the text in this label.
+    #[cfg(appkit)]
+    pub fn set_selectable(&self, selectable: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setSelectable: match selectable {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
+    /// Sets the text for the label, turning any URLs and email addresses in it into links that
+    /// open when clicked. This makes the label selectable, as AppKit only follows links in
+    /// selectable text.
+    #[cfg(appkit)]
+    pub fn set_text_with_links<S: AsRef<str>>(&self, text: S) {
+        self.set_text(text);
+
+        self.objc.with_mut(|obj| unsafe {
+            // Round-tripping through the plain text gives us a string that already carries the
+            // label's font, color and alignment.
+            let mut text = AttributedString::wrap(msg_send![obj, attributedStringValue]);
+            text.detect_links();
+
+            let _: () = msg_send![obj, setSelectable: YES];
+            let _: () = msg_send![obj, setAllowsEditingTextAttributes: YES];
+            let _: () = msg_send![obj, setAttributedStringValue:&*text];
+        });
+    }
+
+    /// Sets the line height, as a multiple of the font's natural line height. This applies to
+    /// the text currently set, so call it after `set_text()` (or `set_attributed_text()`).
+    #[cfg(appkit)]
+    pub fn set_line_height_multiple(&self, multiple: f64) {
+        self.objc.with_mut(|obj| unsafe {
+            let alignment: NSInteger = msg_send![obj, alignment];
+            let cell: id = msg_send![obj, cell];
+            let line_break_mode: NSUInteger = msg_send![cell, lineBreakMode];
+
+            let mut text = AttributedString::wrap(msg_send![obj, attributedStringValue]);
+            text.set_paragraph_style(multiple, alignment, Some(line_break_mode));
+
+            let _: () = msg_send![obj, setAttributedStringValue:&*text];
+        });
+    }
+
+    /// Returns whether the text is currently cut off - e.g, to only set a tooltip with the full
+    /// text when it's needed. This depends on the label's current size, so check it after layout.
+    #[cfg(appkit)]
+    pub fn is_truncated(&self) -> bool {
+        self.objc.get(|obj| unsafe {
+            let bounds: CGRect = msg_send![obj, bounds];
+            let cell: id = msg_send![obj, cell];
+            let wraps = to_bool(msg_send![cell, wraps]);
+
+            if wraps {
+                let unbounded = CGRect::new(&CGPoint::new(0., 0.), &CGSize::new(bounds.size.width, CGFloat::MAX));
+                let size: CGSize = msg_send![cell, cellSizeForBounds: unbounded];
+                size.height > bounds.size.height.ceil()
+            } else {
+                let size: CGSize = msg_send![cell, cellSize];
+                size.width > bounds.size.width.ceil()
+            }
+        })
+    }
+
+    /// Sets whether hovering over this label shows its full text in a tooltip when it's
+    /// truncated. This is on by default for labels that truncate.
+    #[cfg(appkit)]
+    pub fn set_allows_expansion_tooltips(&self, allows: bool) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setAllowsExpansionToolTips: match allows {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
     /// Set the line break mode for this label.
     pub fn set_line_break_mode(&self, mode: LineBreakMode) {
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             let cell: id = msg_send![obj, cell];
             let mode = mode as NSUInteger;
@@ -471,6 +577,8 @@ impl<T> Drop for Label<T> {
 
 #[test]
 fn test_label() {
+    crate::testing::initialize();
+
     let label = Label::new();
     let text = label.get_text();
     assert!(text.is_empty());
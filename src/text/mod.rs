@@ -7,8 +7,11 @@ pub use attributed_string::AttributedString;
 mod label;
 pub use label::Label;
 
+#[cfg(any(appkit, uikit))]
+pub(crate) use label::register_view_class as register_label_class;
+
 mod enums;
-pub use enums::{LineBreakMode, TextAlign};
+pub use enums::{DocumentType, LineBreakMode, TextAlign};
 
 mod font;
 pub use font::Font;
@@ -0,0 +1,67 @@
+//! Enums used in working with `TextView`.
+
+use crate::foundation::NSInteger;
+
+/// Actions that can be performed on the find bar of a `TextView`. These map to
+/// `NSTextFinderAction`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TextFinderAction {
+    /// Shows the find bar.
+    ShowFindInterface,
+
+    /// Selects the next match.
+    NextMatch,
+
+    /// Selects the previous match.
+    PreviousMatch,
+
+    /// Replaces all matches.
+    ReplaceAll,
+
+    /// Replaces the current match.
+    Replace,
+
+    /// Replaces the current match, then selects the next one.
+    ReplaceAndFind,
+
+    /// Uses the current selection as the search string.
+    SetSearchString,
+
+    /// Replaces all matches within the current selection.
+    ReplaceAllInSelection,
+
+    /// Selects all matches.
+    SelectAll,
+
+    /// Selects all matches within the current selection.
+    SelectAllInSelection,
+
+    /// Hides the find bar.
+    HideFindInterface,
+
+    /// Shows the find bar, with the replace field.
+    ShowReplaceInterface,
+
+    /// Hides the replace field.
+    HideReplaceInterface
+}
+
+impl From<TextFinderAction> for NSInteger {
+    fn from(action: TextFinderAction) -> Self {
+        match action {
+            TextFinderAction::ShowFindInterface => 1,
+            TextFinderAction::NextMatch => 2,
+            TextFinderAction::PreviousMatch => 3,
+            TextFinderAction::ReplaceAll => 4,
+            TextFinderAction::Replace => 5,
+            TextFinderAction::ReplaceAndFind => 6,
+            TextFinderAction::SetSearchString => 7,
+            TextFinderAction::ReplaceAllInSelection => 8,
+            TextFinderAction::SelectAll => 9,
+            TextFinderAction::SelectAllInSelection => 10,
+            TextFinderAction::HideFindInterface => 11,
+            TextFinderAction::ShowReplaceInterface => 12,
+            TextFinderAction::HideReplaceInterface => 13
+        }
+    }
+}
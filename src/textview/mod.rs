@@ -0,0 +1,408 @@
+//! Wraps `NSTextView` (inside of an `NSScrollView`) for multi-line text editing.
+//!
+//! The `TextView` itself is the scroll view, so it's what you add as a subview and lay out; the
+//! text editing methods all forward to the enclosed `NSTextView`. Beyond basic editing, this
+//! exposes the pieces of the text system that people expect from an editor on macOS: the
+//! standard find bar, spell and grammar checking, the automatic substitution toggles, and rich
+//! text that works with the standard Fonts and Colors windows.
+//!
+//! ```rust,no_run
+//! use cacao::textview::{TextFinderAction, TextView};
+//!
+//! let editor = TextView::new();
+//! editor.set_text("Hello, world");
+//! editor.set_uses_find_bar(true);
+//! editor.set_continuous_spell_checking(true);
+//! editor.perform_text_finder_action(TextFinderAction::ShowFindInterface);
+//! ```
+
+use std::ops::Range;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::color::Color;
+use crate::foundation::{id, nil, to_bool, NSInteger, NSString, NO, YES};
+use crate::layout::Layout;
+use crate::objc_access::ObjcAccess;
+use crate::text::Font;
+use crate::utils::properties::ObjcProperty;
+use crate::utils::NSRange;
+
+#[cfg(feature = "autolayout")]
+use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
+
+mod enums;
+pub use enums::TextFinderAction;
+
+/// A multi-line, scrollable text editor, backed by `NSTextView`.
+#[derive(Debug)]
+pub struct TextView {
+    /// A pointer to the Objective-C runtime scroll view that encloses the text view.
+    pub objc: ObjcProperty,
+
+    /// A pointer to the Objective-C runtime `NSTextView`.
+    pub text_view: Id<Object, Shared>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime left layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub left: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime right layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub right: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub center_y: LayoutAnchorY
+}
+
+impl Default for TextView {
+    fn default() -> Self {
+        TextView::new()
+    }
+}
+
+impl TextView {
+    /// Returns a new, empty, editable `TextView`.
+    pub fn new() -> Self {
+        let (view, text_view) = unsafe {
+            let view: id = msg_send![class!(NSTextView), scrollableTextView];
+
+            #[cfg(feature = "autolayout")]
+            let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+
+            let _: () = msg_send![view, setWantsLayer: YES];
+            let text_view: Id<Object, Shared> = msg_send_id![view, documentView];
+            (view, text_view)
+        };
+
+        TextView {
+            objc: ObjcProperty::retain(view),
+            text_view,
+
+            #[cfg(feature = "autolayout")]
+            top: LayoutAnchorY::top(view),
+
+            #[cfg(feature = "autolayout")]
+            left: LayoutAnchorX::left(view),
+
+            #[cfg(feature = "autolayout")]
+            leading: LayoutAnchorX::leading(view),
+
+            #[cfg(feature = "autolayout")]
+            right: LayoutAnchorX::right(view),
+
+            #[cfg(feature = "autolayout")]
+            trailing: LayoutAnchorX::trailing(view),
+
+            #[cfg(feature = "autolayout")]
+            bottom: LayoutAnchorY::bottom(view),
+
+            #[cfg(feature = "autolayout")]
+            width: LayoutAnchorDimension::width(view),
+
+            #[cfg(feature = "autolayout")]
+            height: LayoutAnchorDimension::height(view),
+
+            #[cfg(feature = "autolayout")]
+            center_x: LayoutAnchorX::center(view),
+
+            #[cfg(feature = "autolayout")]
+            center_y: LayoutAnchorY::center(view)
+        }
+    }
+
+    /// Sets the text for this view, replacing whatever is currently there.
+    pub fn set_text(&self, text: &str) {
+        let text = NSString::new(text);
+
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setString: &*text];
+        }
+    }
+
+    /// Returns the current text of this view.
+    pub fn get_text(&self) -> String {
+        NSString::retain(unsafe { msg_send![&*self.text_view, string] }).to_string()
+    }
+
+    /// Sets the font used for the text.
+    pub fn set_font<F: AsRef<Font>>(&self, font: F) {
+        let font = font.as_ref().clone();
+
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setFont: &*font];
+        }
+    }
+
+    /// Sets the text color.
+    pub fn set_text_color<C: AsRef<Color>>(&self, color: C) {
+        let color: id = color.as_ref().into();
+
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setTextColor: color];
+        }
+    }
+
+    /// Sets whether the text can be edited by the user.
+    pub fn set_editable(&self, editable: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setEditable: match editable {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the text can be selected by the user.
+    pub fn set_selectable(&self, selectable: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setSelectable: match selectable {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the text can carry styling - fonts, colors, bold, underlines and so on - per
+    /// character. With this on, the Fonts and Colors windows (see `FontPanel` and `ColorPanel`)
+    /// and the standard Format menu items apply to the selection, as users expect.
+    pub fn set_rich_text(&self, rich: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setRichText: match rich {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether the text can carry styling per character.
+    pub fn is_rich_text(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.text_view, isRichText] })
+    }
+
+    /// Sets whether this responds to the Fonts window, and keeps it in sync with the selection.
+    /// This is on by default.
+    pub fn set_uses_font_panel(&self, uses: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setUsesFontPanel: match uses {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets the font for the given range (in UTF-16 code units), e.g the `selected_range()`. The
+    /// text needs to be rich text (see `set_rich_text()`) for this to apply to anything less than
+    /// all of it.
+    pub fn set_font_for_range<F: AsRef<Font>>(&self, font: F, range: Range<usize>) {
+        let range: NSRange = range.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setFont: &*font.as_ref().0, range: range];
+        }
+    }
+
+    /// Sets the text color for the given range (in UTF-16 code units), e.g the
+    /// `selected_range()`. The text needs to be rich text (see `set_rich_text()`) for this to
+    /// apply to anything less than all of it.
+    pub fn set_text_color_for_range<C: AsRef<Color>>(&self, color: C, range: Range<usize>) {
+        let color: id = color.as_ref().into();
+        let range: NSRange = range.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setTextColor: color, range: range];
+        }
+    }
+
+    /// Sets whether this uses the inline find bar (as opposed to the legacy find panel). You
+    /// want this in most cases.
+    pub fn set_uses_find_bar(&self, uses: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setUsesFindBar: match uses {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether the find bar supports incremental searching (highlighting matches as the user
+    /// types).
+    pub fn set_incremental_searching_enabled(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setIncrementalSearchingEnabled: match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Performs a find bar action, e.g showing the find interface or jumping to the next match.
+    /// This is what the standard Find menu items do.
+    pub fn perform_text_finder_action(&self, action: TextFinderAction) {
+        let tag: NSInteger = action.into();
+
+        unsafe {
+            // The text view reads the action from the sender's tag.
+            let sender: Id<Object, Shared> = msg_send_id![class!(NSMenuItem), new];
+            let _: () = msg_send![&*sender, setTag: tag];
+            let _: () = msg_send![&*self.text_view, performTextFinderAction: &*sender];
+        }
+    }
+
+    /// Sets whether spelling is checked as the user types.
+    pub fn set_continuous_spell_checking(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setContinuousSpellCheckingEnabled: match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns whether spelling is checked as the user types.
+    pub fn is_continuous_spell_checking(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.text_view, isContinuousSpellCheckingEnabled] })
+    }
+
+    /// Sets whether grammar is checked as the user types. This requires continuous spell checking
+    /// to be enabled.
+    pub fn set_grammar_checking(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setGrammarCheckingEnabled: match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether misspelled words are automatically corrected.
+    pub fn set_automatic_spelling_correction(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setAutomaticSpellingCorrectionEnabled: match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether straight quotes are automatically replaced with smart quotes.
+    pub fn set_automatic_quote_substitution(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setAutomaticQuoteSubstitutionEnabled: match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether double hyphens are automatically replaced with dashes.
+    pub fn set_automatic_dash_substitution(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setAutomaticDashSubstitutionEnabled: match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets whether text replacements (as configured in System Settings) are applied.
+    pub fn set_automatic_text_replacement(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setAutomaticTextReplacementEnabled: match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Returns the currently selected range, in UTF-16 code units (as `NSString` counts them).
+    pub fn selected_range(&self) -> Range<usize> {
+        let range: NSRange = unsafe { msg_send![&*self.text_view, selectedRange] };
+        range.into()
+    }
+
+    /// Selects the given range, in UTF-16 code units (as `NSString` counts them).
+    pub fn set_selected_range(&self, range: Range<usize>) {
+        let range: NSRange = range.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.text_view, setSelectedRange: range];
+        }
+    }
+
+    /// Scrolls the view so that the given range (in UTF-16 code units) is visible.
+    pub fn scroll_range_to_visible(&self, range: Range<usize>) {
+        let range: NSRange = range.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.text_view, scrollRangeToVisible: range];
+        }
+    }
+
+    /// Returns whether the user is currently composing text with an input method (e.g, for CJK
+    /// input), meaning the text contains marked, uncommitted text.
+    pub fn has_marked_text(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.text_view, hasMarkedText] })
+    }
+
+    /// Commits any in-progress input method composition, accepting the marked text as-is.
+    pub fn unmark_text(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.text_view, unmarkText];
+        }
+    }
+
+    /// Makes the text view the first responder of its window, so it receives keyboard input.
+    pub fn focus(&self) {
+        unsafe {
+            let window: id = msg_send![&*self.text_view, window];
+
+            if window != nil {
+                let _: () = msg_send![window, makeFirstResponder: &*self.text_view];
+            }
+        }
+    }
+}
+
+impl ObjcAccess for TextView {
+    fn with_backing_obj_mut<F: Fn(id)>(&self, handler: F) {
+        self.objc.with_mut(handler);
+    }
+
+    fn get_from_backing_obj<F: Fn(&Object) -> R, R>(&self, handler: F) -> R {
+        self.objc.get(handler)
+    }
+}
+
+impl Layout for TextView {}
@@ -7,7 +7,7 @@ use objc::runtime::{Bool, Class, Object, Sel};
 use objc::sel;
 
 //use crate::error::Error;
-use crate::foundation::{id, load_or_register_class_with_optional_generated_suffix};
+use crate::foundation::{id, load_or_register_class_with_optional_generated_suffix, NSArray};
 use crate::uikit::app::{AppDelegate, APP_DELEGATE};
 use crate::uikit::scene::{SceneConnectionOptions, SceneSession};
 
@@ -41,6 +41,17 @@ extern "C" fn configuration_for_scene_session<T: AppDelegate>(this: &Object, _:
     )
 }
 
+/// Fires when the system has discarded one or more scene sessions (e.g, the user closed a window
+/// from the app switcher).
+extern "C" fn did_discard_scene_sessions<T: AppDelegate>(this: &Object, _: Sel, _: id, sessions: id) {
+    let sessions = NSArray::retain(sessions)
+        .iter()
+        .map(|session| SceneSession::with(session))
+        .collect();
+
+    app::<T>(this).did_discard_scene_sessions(sessions);
+}
+
 /// Registers an `NSObject` application delegate, and configures it for the various callbacks and
 /// pointers we need to have.
 pub(crate) fn register_app_delegate_class<T: AppDelegate>() -> &'static Class {
@@ -58,9 +69,9 @@ pub(crate) fn register_app_delegate_class<T: AppDelegate>() -> &'static Class {
             sel!(application:configurationForConnectingSceneSession:options:),
             configuration_for_scene_session::<T> as extern "C" fn(_, _, _, _, _) -> _
         );
-        /*decl.add_method(
+        decl.add_method(
             sel!(application:didDiscardSceneSessions:),
             did_discard_scene_sessions::<T> as extern "C" fn(_, _, _, _)
-        );*/
+        );
     })
 }
@@ -40,10 +40,11 @@ use std::ffi::CString;
 use objc::runtime::Object;
 use objc::{class, msg_send, sel};
 
-use crate::foundation::{id, nil, AutoReleasePool, NSString, NSUInteger, NO, YES};
+use crate::foundation::{id, nil, to_bool, AutoReleasePool, NSArray, NSString, NSUInteger, BOOL, NO, YES};
 use crate::notification_center::Dispatcher;
-use crate::uikit::scene::{register_window_scene_delegate_class, WindowSceneDelegate};
-use crate::utils::activate_cocoa_multithreading;
+use crate::uikit::scene::{register_window_scene_delegate_class, SceneSession, WindowSceneDelegate};
+use crate::user_activity::UserActivity;
+use crate::utils::{activate_cocoa_multithreading, register_builtin_classes};
 
 mod class;
 use class::register_app_class;
@@ -67,9 +68,9 @@ extern "C" {
 
 /// A handler to make some boilerplate less annoying.
 #[inline]
-fn shared_application<F: Fn(id)>(handler: F) {
+fn shared_application<T, F: Fn(id) -> T>(handler: F) -> T {
     let app: id = unsafe { msg_send![register_app_class(), sharedApplication] };
-    handler(app);
+    handler(app)
 }
 
 /// Wraps `UIApplication` and associated lifecycle pieces.
@@ -115,6 +116,8 @@ where
         activate_cocoa_multithreading();
 
         let pool = AutoReleasePool::new();
+        register_builtin_classes();
+
         let cls = register_app_class();
         let dl = register_app_delegate_class::<T>();
         let w = register_window_scene_delegate_class::<W, F>();
@@ -166,6 +169,77 @@ where
     }
 }
 
+impl App {
+    /// Returns whether the application supports displaying multiple scenes at once. This is
+    /// controlled by `UIApplicationSupportsMultipleScenes` in your `Info.plist`.
+    pub fn supports_multiple_scenes() -> bool {
+        shared_application(|app| {
+            let supports: BOOL = unsafe { msg_send![app, supportsMultipleScenes] };
+            to_bool(supports)
+        })
+    }
+
+    /// Returns all of the scene sessions currently known to the system. Note that this includes
+    /// sessions for scenes that are not currently connected.
+    pub fn open_sessions() -> Vec<SceneSession> {
+        shared_application(|app| {
+            let sessions = NSArray::retain(unsafe {
+                let sessions: id = msg_send![app, openSessions];
+                msg_send![sessions, allObjects]
+            });
+
+            sessions.iter().map(|session| SceneSession::with(session)).collect()
+        })
+    }
+
+    /// Asks the system to activate a scene. If `session` is `None`, a new scene (and window) will
+    /// be created; otherwise, the existing session is brought to the foreground.
+    ///
+    /// An optional `UserActivity` can be passed along, which will be available in the connection
+    /// options for the scene.
+    pub fn request_scene_session_activation(session: Option<&SceneSession>, activity: Option<&UserActivity>) {
+        shared_application(|app| unsafe {
+            let session: id = match session {
+                Some(session) => &*session.0 as *const Object as id,
+                None => nil
+            };
+
+            let activity: id = match activity {
+                Some(activity) => &*activity.0 as *const Object as id,
+                None => nil
+            };
+
+            let _: () = msg_send![
+                app,
+                requestSceneSessionActivation: session,
+                userActivity: activity,
+                options: nil,
+                errorHandler: nil
+            ];
+        });
+    }
+
+    /// Asks the system to dismiss the scene for the given session, and discard the session.
+    pub fn request_scene_session_destruction(session: &SceneSession) {
+        shared_application(|app| unsafe {
+            let _: () = msg_send![
+                app,
+                requestSceneSessionDestruction: &*session.0,
+                options: nil,
+                errorHandler: nil
+            ];
+        });
+    }
+
+    /// Asks the system to refresh the snapshot (and any state restoration data) for the given
+    /// session, which can be useful for sessions whose scene is in the background.
+    pub fn request_scene_session_refresh(session: &SceneSession) {
+        shared_application(|app| unsafe {
+            let _: () = msg_send![app, requestSceneSessionRefresh: &*session.0];
+        });
+    }
+}
+
 impl<T, W, F> Drop for App<T, W, F> {
     fn drop(&mut self) {
         println!("DROPPING");
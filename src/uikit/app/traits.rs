@@ -21,4 +21,9 @@ pub trait AppDelegate {
     fn did_finish_launching(&self) {}
 
     fn config_for_scene_session(&self, session: SceneSession, options: SceneConnectionOptions) -> SceneConfig;
+
+    /// Fired when the user (or system) has discarded one or more scene sessions. Use this to
+    /// release any state you were holding for those sessions, keyed on their
+    /// `persistent_identifier()`.
+    fn did_discard_scene_sessions(&self, _sessions: Vec<SceneSession>) {}
 }
@@ -0,0 +1,193 @@
+//! Wraps the `UIFeedbackGenerator` family, which provide haptic feedback on devices that support
+//! it (and silently do nothing on those that don't).
+//!
+//! For the best latency, call `prepare()` shortly before you expect to trigger feedback - this
+//! wakes up the Taptic Engine ahead of time.
+//!
+//! For AppKit, see `cacao::appkit::haptics`.
+
+use core_graphics::base::CGFloat;
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, NSInteger};
+
+/// Represents the intensity of the collision an `ImpactFeedbackGenerator` simulates.
+#[derive(Clone, Copy, Debug)]
+pub enum ImpactFeedbackStyle {
+    /// A collision between small, light user interface elements.
+    Light,
+
+    /// A collision between moderately sized user interface elements.
+    Medium,
+
+    /// A collision between large, heavy user interface elements.
+    Heavy,
+
+    /// A collision between user interface elements that are soft, exhibiting a large amount of
+    /// compression or elasticity.
+    Soft,
+
+    /// A collision between user interface elements that are rigid, exhibiting a small amount of
+    /// compression or elasticity.
+    Rigid
+}
+
+impl From<ImpactFeedbackStyle> for NSInteger {
+    fn from(style: ImpactFeedbackStyle) -> Self {
+        match style {
+            ImpactFeedbackStyle::Light => 0,
+            ImpactFeedbackStyle::Medium => 1,
+            ImpactFeedbackStyle::Heavy => 2,
+            ImpactFeedbackStyle::Soft => 3,
+            ImpactFeedbackStyle::Rigid => 4
+        }
+    }
+}
+
+/// Represents the outcome a `NotificationFeedbackGenerator` communicates.
+#[derive(Clone, Copy, Debug)]
+pub enum NotificationFeedbackType {
+    /// A task has completed successfully.
+    Success,
+
+    /// A task has produced a warning.
+    Warning,
+
+    /// A task has failed.
+    Error
+}
+
+impl From<NotificationFeedbackType> for NSInteger {
+    fn from(feedback_type: NotificationFeedbackType) -> Self {
+        match feedback_type {
+            NotificationFeedbackType::Success => 0,
+            NotificationFeedbackType::Warning => 1,
+            NotificationFeedbackType::Error => 2
+        }
+    }
+}
+
+/// Wraps `UIImpactFeedbackGenerator`, which simulates physical impacts - e.g, a view snapping
+/// into place.
+#[derive(Clone, Debug)]
+pub struct ImpactFeedbackGenerator(pub Id<Object, Shared>);
+
+impl ImpactFeedbackGenerator {
+    /// Creates a new generator for the given style.
+    pub fn new(style: ImpactFeedbackStyle) -> Self {
+        let style: NSInteger = style.into();
+
+        ImpactFeedbackGenerator(unsafe {
+            let alloc = msg_send_id![class!(UIImpactFeedbackGenerator), alloc];
+            msg_send_id![alloc, initWithStyle: style]
+        })
+    }
+
+    /// Prepares the Taptic Engine, reducing latency for the next `impact()` call.
+    pub fn prepare(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, prepare];
+        }
+    }
+
+    /// Triggers impact feedback.
+    pub fn impact(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, impactOccurred];
+        }
+    }
+
+    /// Triggers impact feedback with a given intensity, between `0.0` and `1.0`.
+    pub fn impact_with_intensity(&self, intensity: f64) {
+        let intensity: CGFloat = intensity;
+
+        unsafe {
+            let _: () = msg_send![&*self.0, impactOccurredWithIntensity: intensity];
+        }
+    }
+}
+
+impl Default for ImpactFeedbackGenerator {
+    /// Returns a generator with the `Medium` style.
+    fn default() -> Self {
+        ImpactFeedbackGenerator::new(ImpactFeedbackStyle::Medium)
+    }
+}
+
+/// Wraps `UINotificationFeedbackGenerator`, which communicates success, failure and warnings.
+#[derive(Clone, Debug)]
+pub struct NotificationFeedbackGenerator(pub Id<Object, Shared>);
+
+impl NotificationFeedbackGenerator {
+    /// Creates a new notification feedback generator.
+    pub fn new() -> Self {
+        NotificationFeedbackGenerator(unsafe { msg_send_id![class!(UINotificationFeedbackGenerator), new] })
+    }
+
+    /// Prepares the Taptic Engine, reducing latency for the next `notify()` call.
+    pub fn prepare(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, prepare];
+        }
+    }
+
+    /// Triggers feedback communicating the given outcome.
+    pub fn notify(&self, feedback_type: NotificationFeedbackType) {
+        let feedback_type: NSInteger = feedback_type.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, notificationOccurred: feedback_type];
+        }
+    }
+}
+
+impl Default for NotificationFeedbackGenerator {
+    fn default() -> Self {
+        NotificationFeedbackGenerator::new()
+    }
+}
+
+/// Wraps `UISelectionFeedbackGenerator`, which indicates a change in selection - e.g, a picker
+/// wheel ticking over.
+#[derive(Clone, Debug)]
+pub struct SelectionFeedbackGenerator(pub Id<Object, Shared>);
+
+impl SelectionFeedbackGenerator {
+    /// Creates a new selection feedback generator.
+    pub fn new() -> Self {
+        SelectionFeedbackGenerator(unsafe { msg_send_id![class!(UISelectionFeedbackGenerator), new] })
+    }
+
+    /// Prepares the Taptic Engine, reducing latency for the next `selection_changed()` call.
+    pub fn prepare(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, prepare];
+        }
+    }
+
+    /// Triggers feedback indicating the selection has changed.
+    pub fn selection_changed(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, selectionChanged];
+        }
+    }
+}
+
+impl Default for SelectionFeedbackGenerator {
+    fn default() -> Self {
+        SelectionFeedbackGenerator::new()
+    }
+}
+
+/// Plays the standard keyboard input click sound. This respects the user's keyboard click
+/// setting, and only plays when a custom input view conforming to `UIInputViewAudioFeedback` is
+/// on screen.
+pub fn play_input_click() {
+    unsafe {
+        let device: id = msg_send![class!(UIDevice), currentDevice];
+        let _: () = msg_send![device, playInputClick];
+    }
+}
@@ -0,0 +1,128 @@
+//! Support for the UIKit focus engine, which drives navigation on tvOS (and keyboard navigation on
+//! iPadOS).
+//!
+//! Controls like `Button` are focusable out of the box. For your own views, implement
+//! `ViewDelegate::can_become_focused()` and `ViewDelegate::did_update_focus()`. When the focus
+//! engine can't find a path between two areas of your layout (e.g, diagonally across an empty
+//! region), add a `FocusGuide` to bridge the gap.
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::foundation::{id, NSArray, NO, YES};
+use crate::layout::Layout;
+use crate::objc_access::ObjcAccess;
+
+#[cfg(feature = "autolayout")]
+use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
+
+/// Wraps `UIFocusGuide`: an invisible, focusable region that redirects focus to other views.
+///
+/// Guides are attached to a view on creation; position them with AutoLayout like any other view,
+/// and then tell them where focus should go with `set_preferred_focus_view()`.
+#[derive(Debug)]
+pub struct FocusGuide {
+    /// A pointer to the underlying `UIFocusGuide`.
+    pub objc: Id<Object, Shared>,
+
+    /// A pointer to the Objective-C runtime top layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub top: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime leading layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub leading: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime trailing layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub trailing: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime bottom layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub bottom: LayoutAnchorY,
+
+    /// A pointer to the Objective-C runtime width layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub width: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime height layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub height: LayoutAnchorDimension,
+
+    /// A pointer to the Objective-C runtime center X layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub center_x: LayoutAnchorX,
+
+    /// A pointer to the Objective-C runtime center Y layout constraint.
+    #[cfg(feature = "autolayout")]
+    pub center_y: LayoutAnchorY
+}
+
+impl FocusGuide {
+    /// Creates a new focus guide, and adds it to `view`.
+    pub fn new<V: Layout>(view: &V) -> Self {
+        let objc: Id<Object, Shared> = unsafe { msg_send_id![class!(UIFocusGuide), new] };
+        let guide = &*objc as *const Object as id;
+
+        view.with_backing_obj_mut(|obj| unsafe {
+            let _: () = msg_send![obj, addLayoutGuide: guide];
+        });
+
+        FocusGuide {
+            #[cfg(feature = "autolayout")]
+            top: LayoutAnchorY::top(guide),
+
+            #[cfg(feature = "autolayout")]
+            leading: LayoutAnchorX::leading(guide),
+
+            #[cfg(feature = "autolayout")]
+            trailing: LayoutAnchorX::trailing(guide),
+
+            #[cfg(feature = "autolayout")]
+            bottom: LayoutAnchorY::bottom(guide),
+
+            #[cfg(feature = "autolayout")]
+            width: LayoutAnchorDimension::width(guide),
+
+            #[cfg(feature = "autolayout")]
+            height: LayoutAnchorDimension::height(guide),
+
+            #[cfg(feature = "autolayout")]
+            center_x: LayoutAnchorX::center(guide),
+
+            #[cfg(feature = "autolayout")]
+            center_y: LayoutAnchorY::center(guide),
+
+            objc
+        }
+    }
+
+    /// Sets the view that focus moves to when the focus engine lands on this guide.
+    pub fn set_preferred_focus_view<V: Layout>(&self, view: &V) {
+        let environments = view.get_from_backing_obj(|obj| NSArray::new(&[obj as *const Object as id]));
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPreferredFocusEnvironments: &*environments];
+        }
+    }
+
+    /// Clears any preferred focus view, making this guide inert.
+    pub fn clear_preferred_focus_view(&self) {
+        let environments = NSArray::new(&[]);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setPreferredFocusEnvironments: &*environments];
+        }
+    }
+
+    /// Enables or disables this guide. Disabled guides are ignored by the focus engine.
+    pub fn set_enabled(&self, enabled: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setEnabled: match enabled {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+}
@@ -7,6 +7,14 @@
 mod app;
 pub use app::*;
 
+#[cfg(not(feature = "tvos"))]
+pub mod feedback;
+
+pub mod focus;
+
+mod trait_collection;
+pub use trait_collection::{SizeClass, TraitCollection, UserInterfaceStyle};
+
 mod scene;
 pub use scene::*;
 
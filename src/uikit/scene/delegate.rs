@@ -1,9 +1,12 @@
+use objc::rc::Id;
 use objc::runtime::{Class, Object, Protocol, Sel};
 use objc::{class, msg_send, sel};
 
-use crate::foundation::{id, load_or_register_class_with_optional_generated_suffix};
+use crate::error::Error;
+use crate::foundation::{id, load_or_register_class_with_optional_generated_suffix, nil, NSString};
 use crate::uikit::app::SCENE_DELEGATE_VENDOR;
 use crate::uikit::scene::{Scene, SceneConnectionOptions, SceneSession, WindowSceneDelegate};
+use crate::user_activity::UserActivity;
 use crate::utils::load;
 
 pub(crate) static WINDOW_SCENE_PTR: &str = "rstWindowSceneDelegatePtr";
@@ -42,6 +45,83 @@ extern "C" fn scene_will_connect_to_session_with_options<T: WindowSceneDelegate>
     );
 }
 
+/// Fires when the scene delegate receives a `sceneDidDisconnect:` message.
+extern "C" fn scene_did_disconnect<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) {
+    load::<T>(this, WINDOW_SCENE_PTR).did_disconnect(Scene::with(scene));
+}
+
+/// Fires when the scene delegate receives a `sceneDidBecomeActive:` message.
+extern "C" fn scene_did_become_active<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) {
+    load::<T>(this, WINDOW_SCENE_PTR).did_become_active(Scene::with(scene));
+}
+
+/// Fires when the scene delegate receives a `sceneWillResignActive:` message.
+extern "C" fn scene_will_resign_active<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) {
+    load::<T>(this, WINDOW_SCENE_PTR).will_resign_active(Scene::with(scene));
+}
+
+/// Fires when the scene delegate receives a `sceneWillEnterForeground:` message.
+extern "C" fn scene_will_enter_foreground<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) {
+    load::<T>(this, WINDOW_SCENE_PTR).will_enter_foreground(Scene::with(scene));
+}
+
+/// Fires when the scene delegate receives a `sceneDidEnterBackground:` message.
+extern "C" fn scene_did_enter_background<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) {
+    load::<T>(this, WINDOW_SCENE_PTR).did_enter_background(Scene::with(scene));
+}
+
+/// Fires when the system asks for a `stateRestorationActivityForScene:`.
+extern "C" fn state_restoration_activity<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id) -> id {
+    match load::<T>(this, WINDOW_SCENE_PTR).state_restoration_activity(Scene::with(scene)) {
+        Some(activity) => Id::autorelease_return(activity.0),
+        None => nil
+    }
+}
+
+/// Fires when the scene delegate receives a `scene:willContinueUserActivityWithType:` message.
+extern "C" fn will_continue_user_activity<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id, activity_type: id) {
+    let activity_type = NSString::retain(activity_type);
+    load::<T>(this, WINDOW_SCENE_PTR).will_continue_user_activity(Scene::with(scene), activity_type.to_str());
+}
+
+/// Fires when the scene delegate receives a `scene:continueUserActivity:` message.
+extern "C" fn continue_user_activity<T: WindowSceneDelegate>(this: &Object, _: Sel, scene: id, activity: id) {
+    let activity = UserActivity::with_inner(activity);
+    load::<T>(this, WINDOW_SCENE_PTR).continue_user_activity(Scene::with(scene), activity);
+}
+
+/// Fires when the scene delegate receives a `scene:didFailToContinueUserActivityWithType:error:`
+/// message.
+extern "C" fn failed_to_continue_user_activity<T: WindowSceneDelegate>(
+    this: &Object,
+    _: Sel,
+    scene: id,
+    activity_type: id,
+    error: id
+) {
+    let activity_type = NSString::retain(activity_type);
+
+    load::<T>(this, WINDOW_SCENE_PTR).failed_to_continue_user_activity(
+        Scene::with(scene),
+        activity_type.to_str(),
+        Error::new(error)
+    );
+}
+
+/// Called when the scene delegate is being deallocated. We reclaim the `WindowSceneDelegate`
+/// that was vended in `init` so that it's dropped alongside the scene.
+extern "C" fn dealloc<T: WindowSceneDelegate>(this: &Object, _: Sel) {
+    unsafe {
+        let ptr: usize = *this.get_ivar(WINDOW_SCENE_PTR);
+
+        if ptr != 0 {
+            let _ = Box::from_raw(ptr as *mut T);
+        }
+
+        let _: () = msg_send![super(this, class!(UIResponder)), dealloc];
+    }
+}
+
 /// Registers an `NSObject` application delegate, and configures it for the various callbacks and
 /// pointers we need to have.
 pub(crate) fn register_window_scene_delegate_class<T: WindowSceneDelegate, F: Fn() -> Box<T>>() -> &'static Class {
@@ -62,5 +142,44 @@ pub(crate) fn register_window_scene_delegate_class<T: WindowSceneDelegate, F: Fn
             sel!(scene:willConnectToSession:options:),
             scene_will_connect_to_session_with_options::<T> as extern "C" fn(_, _, _, _, _)
         );
+        decl.add_method(sel!(sceneDidDisconnect:), scene_did_disconnect::<T> as extern "C" fn(_, _, _));
+
+        // Transitioning to the foreground/background
+        decl.add_method(
+            sel!(sceneDidBecomeActive:),
+            scene_did_become_active::<T> as extern "C" fn(_, _, _)
+        );
+        decl.add_method(
+            sel!(sceneWillResignActive:),
+            scene_will_resign_active::<T> as extern "C" fn(_, _, _)
+        );
+        decl.add_method(
+            sel!(sceneWillEnterForeground:),
+            scene_will_enter_foreground::<T> as extern "C" fn(_, _, _)
+        );
+        decl.add_method(
+            sel!(sceneDidEnterBackground:),
+            scene_did_enter_background::<T> as extern "C" fn(_, _, _)
+        );
+
+        // State restoration and user activities
+        decl.add_method(
+            sel!(stateRestorationActivityForScene:),
+            state_restoration_activity::<T> as extern "C" fn(_, _, _) -> _
+        );
+        decl.add_method(
+            sel!(scene:willContinueUserActivityWithType:),
+            will_continue_user_activity::<T> as extern "C" fn(_, _, _, _)
+        );
+        decl.add_method(
+            sel!(scene:continueUserActivity:),
+            continue_user_activity::<T> as extern "C" fn(_, _, _, _)
+        );
+        decl.add_method(
+            sel!(scene:didFailToContinueUserActivityWithType:error:),
+            failed_to_continue_user_activity::<T> as extern "C" fn(_, _, _, _, _)
+        );
+
+        decl.add_method(sel!(dealloc), dealloc::<T> as extern "C" fn(_, _));
     })
 }
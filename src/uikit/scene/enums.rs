@@ -1,4 +1,4 @@
-use crate::foundation::{id, NSString};
+use crate::foundation::{id, NSInteger, NSString};
 
 /// Represents the types of sessions a Scene is for.
 #[derive(Clone, Copy, Debug)]
@@ -29,3 +29,30 @@ impl From<NSString<'_>> for SessionRole {
         }
     }
 }
+
+/// Represents the current activation state of a `Scene`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SceneActivationState {
+    /// The scene is not currently connected to your app.
+    Unattached,
+
+    /// The scene is onscreen and receiving events.
+    ForegroundActive,
+
+    /// The scene is onscreen, but not receiving events.
+    ForegroundInactive,
+
+    /// The scene is running in the background and is not visible.
+    Background
+}
+
+impl From<NSInteger> for SceneActivationState {
+    fn from(value: NSInteger) -> Self {
+        match value {
+            0 => SceneActivationState::ForegroundActive,
+            1 => SceneActivationState::ForegroundInactive,
+            2 => SceneActivationState::Background,
+            _ => SceneActivationState::Unattached
+        }
+    }
+}
@@ -3,13 +3,13 @@
 //! This is required for things like having multiple instances of your app in the app switcher on
 //! iPad. In general, you probably won't need to tweak this though.
 
-use core_graphics::geometry::CGRect;
+use core_graphics::geometry::{CGRect, CGSize};
 
 use objc::rc::{Id, Owned};
 use objc::runtime::Object;
 use objc::{class, msg_send, sel};
 
-use crate::foundation::id;
+use crate::foundation::{id, NSInteger, NSString};
 use crate::geometry::Rect;
 
 mod delegate;
@@ -30,6 +30,12 @@ pub use options::*;
 mod session;
 pub use session::*;
 
+#[cfg(catalyst)]
+mod titlebar;
+#[cfg(catalyst)]
+pub use titlebar::*;
+
+/// A wrapper for `UIWindowScene`.
 #[derive(Debug)]
 pub struct Scene(pub Id<Object, Owned>);
 
@@ -38,6 +44,53 @@ impl Scene {
         Scene(unsafe { Id::retain(scene).unwrap() })
     }
 
+    /// Returns the `SceneSession` this scene is currently attached to.
+    pub fn session(&self) -> SceneSession {
+        SceneSession::with(unsafe { msg_send![&*self.0, session] })
+    }
+
+    /// Returns the current activation state of this scene.
+    pub fn activation_state(&self) -> SceneActivationState {
+        let state: NSInteger = unsafe { msg_send![&*self.0, activationState] };
+        state.into()
+    }
+
+    /// Sets the title displayed for this scene in the app switcher.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setTitle: &*title];
+        }
+    }
+
+    /// Returns the macOS titlebar for this scene. This is only available under Mac Catalyst.
+    #[cfg(catalyst)]
+    pub fn titlebar(&self) -> Option<Titlebar> {
+        unsafe {
+            let titlebar: id = msg_send![&*self.0, titlebar];
+            Id::retain(titlebar).map(Titlebar)
+        }
+    }
+
+    /// Sets the minimum and maximum size of the window backing this scene. On iPad, the system
+    /// manages scene sizes and this has no effect; under Mac Catalyst, it constrains how far the
+    /// user can resize the window.
+    pub fn set_size_restrictions(&self, minimum: (f64, f64), maximum: (f64, f64)) {
+        unsafe {
+            let restrictions: id = msg_send![&*self.0, sizeRestrictions];
+
+            if restrictions.is_null() {
+                return;
+            }
+
+            let minimum = CGSize::new(minimum.0, minimum.1);
+            let maximum = CGSize::new(maximum.0, maximum.1);
+            let _: () = msg_send![restrictions, setMinimumSize: minimum];
+            let _: () = msg_send![restrictions, setMaximumSize: maximum];
+        }
+    }
+
     // This is temporary - I'm not wrapping `coordinateSpace` until I'm happy with the ergonomics
     // of everything.
     pub fn get_bounds(&self) -> Rect {
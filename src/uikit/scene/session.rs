@@ -4,7 +4,10 @@ use objc::{msg_send, msg_send_id, sel};
 
 use crate::foundation::{id, NSString};
 use crate::uikit::scene::enums::SessionRole;
+use crate::user_activity::UserActivity;
 
+/// A wrapper for `UISceneSession`. Sessions outlive their scenes - the system keeps them around
+/// (and may hand them back to you) even after a scene has been disconnected.
 #[derive(Debug)]
 pub struct SceneSession(pub Id<Object, Owned>);
 
@@ -16,4 +19,21 @@ impl SceneSession {
     pub fn role(&self) -> SessionRole {
         NSString::from_id(unsafe { msg_send_id![&*self.0, role] }).into()
     }
+
+    /// Returns the unique identifier for this session. This remains stable across launches, and
+    /// is what you'll want to key any per-window state on.
+    pub fn persistent_identifier(&self) -> String {
+        NSString::retain(unsafe { msg_send![&*self.0, persistentIdentifier] }).to_string()
+    }
+
+    /// Returns the `UserActivity` that was handed back from `state_restoration_activity` the last
+    /// time this session was backgrounded, if any.
+    pub fn state_restoration_activity(&self) -> Option<UserActivity> {
+        let activity: id = unsafe { msg_send![&*self.0, stateRestorationActivity] };
+
+        match activity.is_null() {
+            true => None,
+            false => Some(UserActivity::with_inner(activity))
+        }
+    }
 }
@@ -0,0 +1,135 @@
+//! Wraps `UITitlebar`, which only exists when running under Mac Catalyst.
+
+use objc::rc::{Id, Shared};
+use objc::runtime::Object;
+use objc::{msg_send, sel};
+
+use crate::foundation::{nil, NSInteger};
+
+/// Whether the window title is displayed in the titlebar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TitleVisibility {
+    /// The title is shown (the default).
+    Visible,
+
+    /// The title is hidden. Typically used with a unified toolbar.
+    Hidden
+}
+
+impl From<TitleVisibility> for NSInteger {
+    fn from(visibility: TitleVisibility) -> Self {
+        match visibility {
+            TitleVisibility::Visible => 0,
+            TitleVisibility::Hidden => 1
+        }
+    }
+}
+
+/// How the toolbar is laid out relative to the title.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TitlebarToolbarStyle {
+    /// The system decides.
+    Automatic,
+
+    /// The toolbar sits below the title.
+    Expanded,
+
+    /// Like `Expanded`, but the toolbar items are centered - as in a preferences window.
+    Preference,
+
+    /// The title and toolbar share a single row.
+    Unified,
+
+    /// Like `Unified`, with reduced margins.
+    UnifiedCompact
+}
+
+impl From<TitlebarToolbarStyle> for NSInteger {
+    fn from(style: TitlebarToolbarStyle) -> Self {
+        match style {
+            TitlebarToolbarStyle::Automatic => 0,
+            TitlebarToolbarStyle::Expanded => 1,
+            TitlebarToolbarStyle::Preference => 2,
+            TitlebarToolbarStyle::Unified => 3,
+            TitlebarToolbarStyle::UnifiedCompact => 4
+        }
+    }
+}
+
+/// How the titlebar is separated from the content below it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TitlebarSeparatorStyle {
+    /// The system decides.
+    Automatic,
+
+    /// No separator.
+    None,
+
+    /// A thin line.
+    Line,
+
+    /// A shadow.
+    Shadow
+}
+
+impl From<TitlebarSeparatorStyle> for NSInteger {
+    fn from(style: TitlebarSeparatorStyle) -> Self {
+        match style {
+            TitlebarSeparatorStyle::Automatic => 0,
+            TitlebarSeparatorStyle::None => 1,
+            TitlebarSeparatorStyle::Line => 2,
+            TitlebarSeparatorStyle::Shadow => 3
+        }
+    }
+}
+
+/// A wrapper for `UITitlebar`, the macOS titlebar of a window scene under Mac Catalyst. Retrieve
+/// one with `Scene::titlebar()`.
+#[derive(Debug)]
+pub struct Titlebar(pub Id<Object, Shared>);
+
+impl Titlebar {
+    /// Sets whether the window title is displayed.
+    pub fn set_title_visibility(&self, visibility: TitleVisibility) {
+        let visibility: NSInteger = visibility.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setTitleVisibility: visibility];
+        }
+    }
+
+    /// Sets how the toolbar is laid out relative to the title.
+    pub fn set_toolbar_style(&self, style: TitlebarToolbarStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setToolbarStyle: style];
+        }
+    }
+
+    /// Sets how the titlebar is separated from the content below it.
+    pub fn set_separator_style(&self, style: TitlebarSeparatorStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setSeparatorStyle: style];
+        }
+    }
+
+    /// Sets the `NSToolbar` shown in the titlebar.
+    ///
+    /// cacao's `Toolbar` wrapper is AppKit-only for now, so under Catalyst you'll need to build the
+    /// `NSToolbar` (and its delegate) yourself; it's available to Catalyst apps as-is.
+    pub fn set_toolbar(&self, toolbar: &Object) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setToolbar: toolbar];
+        }
+    }
+
+    /// Removes the toolbar from the titlebar, if there is one.
+    pub fn remove_toolbar(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.0, setToolbar: nil];
+        }
+    }
+}
@@ -1,5 +1,49 @@
+use crate::error::Error;
 use crate::uikit::scene::{Scene, SceneConnectionOptions, SceneSession};
+use crate::user_activity::UserActivity;
 
+/// `WindowSceneDelegate` is more or less `UIWindowSceneDelegate` from the Objective-C/Swift side.
+/// A new instance of your delegate is vended for every scene the system connects, which is what
+/// enables multiple windows on iPadOS.
+#[allow(unused_variables)]
 pub trait WindowSceneDelegate {
+    /// Fired when a new scene is being connected to your app. This is where you'll want to
+    /// configure and display your window.
     fn will_connect(&self, scene: Scene, session: SceneSession, options: SceneConnectionOptions);
+
+    /// Fired when the scene has been removed from your app. The system may reconnect the scene
+    /// later on, so you should release any resources associated with it but retain anything you
+    /// need to restore it.
+    fn did_disconnect(&self, scene: Scene) {}
+
+    /// Fired when the scene has become active and is now responding to user events.
+    fn did_become_active(&self, scene: Scene) {}
+
+    /// Fired when the scene is about to resign the active state and stop responding to user
+    /// events.
+    fn will_resign_active(&self, scene: Scene) {}
+
+    /// Fired when the scene is about to begin running in the foreground and become visible to the
+    /// user.
+    fn will_enter_foreground(&self, scene: Scene) {}
+
+    /// Fired when the scene is running in the background and is no longer onscreen.
+    fn did_enter_background(&self, scene: Scene) {}
+
+    /// Return a `UserActivity` describing the current state of this scene. The system will persist
+    /// it, and hand it back to you via the `SceneSession` when the scene is reconnected.
+    ///
+    /// The default implementation returns `None`, which opts out of state restoration.
+    fn state_restoration_activity(&self, scene: Scene) -> Option<UserActivity> {
+        None
+    }
+
+    /// Fired when the user is about to continue an activity of the given type in this scene.
+    fn will_continue_user_activity(&self, scene: Scene, activity_type: &str) {}
+
+    /// Fired when data for continuing an activity in this scene is available.
+    fn continue_user_activity(&self, scene: Scene, activity: UserActivity) {}
+
+    /// Fired when the activity could not be continued in this scene.
+    fn failed_to_continue_user_activity(&self, scene: Scene, activity_type: &str, error: Error) {}
 }
@@ -0,0 +1,125 @@
+//! Wraps the parts of `UITraitCollection` that adaptive layouts care about: size classes, display
+//! scale and light/dark mode.
+//!
+//! Views are told when these change via `ViewDelegate::trait_collection_did_change()`, which is
+//! the place to switch between constraint sets for compact and regular widths:
+//!
+//! ```rust,no_run
+//! use cacao::layout::LayoutConstraint;
+//! use cacao::uikit::{SizeClass, TraitCollection};
+//! use cacao::view::ViewDelegate;
+//!
+//! struct Content {
+//!     compact: Vec<LayoutConstraint>,
+//!     regular: Vec<LayoutConstraint>
+//! }
+//!
+//! impl ViewDelegate for Content {
+//!     const NAME: &'static str = "Content";
+//!
+//!     fn trait_collection_did_change(&self, traits: TraitCollection, previous: Option<TraitCollection>) {
+//!         if previous.map(|previous| previous.horizontal_size_class) == Some(traits.horizontal_size_class) {
+//!             return;
+//!         }
+//!
+//!         // Switch constraint sets here.
+//!     }
+//! }
+//! ```
+
+use core_graphics::base::CGFloat;
+use objc::{msg_send, sel};
+
+use crate::foundation::{id, NSInteger};
+
+/// A rough measure of the space available along one axis.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SizeClass {
+    /// Not known yet (e.g, before the view is in a window).
+    Unspecified,
+
+    /// Constrained space - e.g, the width of an iPhone in portrait.
+    Compact,
+
+    /// Expansive space - e.g, the width of a full screen iPad.
+    Regular
+}
+
+impl From<NSInteger> for SizeClass {
+    fn from(size_class: NSInteger) -> Self {
+        match size_class {
+            1 => SizeClass::Compact,
+            2 => SizeClass::Regular,
+            _ => SizeClass::Unspecified
+        }
+    }
+}
+
+/// Whether the interface is showing in light or dark mode.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UserInterfaceStyle {
+    /// Not known yet.
+    Unspecified,
+
+    /// Light mode.
+    Light,
+
+    /// Dark mode.
+    Dark
+}
+
+impl From<NSInteger> for UserInterfaceStyle {
+    fn from(style: NSInteger) -> Self {
+        match style {
+            1 => UserInterfaceStyle::Light,
+            2 => UserInterfaceStyle::Dark,
+            _ => UserInterfaceStyle::Unspecified
+        }
+    }
+}
+
+/// A snapshot of the environment a view is displayed in.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TraitCollection {
+    /// The horizontal size class; this is the one most layouts switch on.
+    pub horizontal_size_class: SizeClass,
+
+    /// The vertical size class (e.g, compact for an iPhone in landscape).
+    pub vertical_size_class: SizeClass,
+
+    /// The scale of the display, in pixels per point (e.g, `3.0`). This is `0.0` if unknown.
+    pub display_scale: f64,
+
+    /// Light or dark mode.
+    pub user_interface_style: UserInterfaceStyle
+}
+
+impl TraitCollection {
+    /// Reads the values we care about out of a `UITraitCollection`.
+    pub(crate) fn with(traits: id) -> Self {
+        unsafe {
+            let horizontal: NSInteger = msg_send![traits, horizontalSizeClass];
+            let vertical: NSInteger = msg_send![traits, verticalSizeClass];
+            let scale: CGFloat = msg_send![traits, displayScale];
+            let style: NSInteger = msg_send![traits, userInterfaceStyle];
+
+            TraitCollection {
+                horizontal_size_class: horizontal.into(),
+                vertical_size_class: vertical.into(),
+                display_scale: scale as f64,
+                user_interface_style: style.into()
+            }
+        }
+    }
+
+    /// Returns whether the horizontal size class is compact - the usual cue for a single column
+    /// layout.
+    pub fn is_compact_width(&self) -> bool {
+        self.horizontal_size_class == SizeClass::Compact
+    }
+
+    /// Returns whether the interface is in dark mode.
+    pub fn is_dark(&self) -> bool {
+        self.user_interface_style == UserInterfaceStyle::Dark
+    }
+}
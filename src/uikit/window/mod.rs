@@ -1,11 +1,12 @@
 use core_graphics::geometry::CGRect;
 
-use objc::rc::{Id, Owned};
+use objc::rc::{Id, Owned, Shared};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
 use crate::foundation::id;
 use crate::geometry::Rect;
+use crate::layout::accessibility;
 use crate::uikit::Scene;
 use crate::utils::Controller;
 
@@ -40,4 +41,15 @@ impl Window {
             let _: () = msg_send![&*self.0, makeKeyAndVisible];
         }
     }
+
+    /// Sets an identifier for this window that UI tests (e.g, XCUITest) can use to find it.
+    pub fn set_accessibility_identifier(&self, identifier: &str) {
+        accessibility::set_accessibility_identifier(&*self.0 as *const Object as id, identifier);
+    }
+
+    /// Searches this window's view hierarchy for a view with the given accessibility identifier,
+    /// returning the first match.
+    pub fn find_view_with_accessibility_identifier(&self, identifier: &str) -> Option<Id<Object, Shared>> {
+        accessibility::find_view_with_accessibility_identifier(&*self.0 as *const Object as id, identifier)
+    }
 }
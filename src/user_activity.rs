@@ -1,11 +1,16 @@
 //! A module wrapping `NSUserActivity`.
 //!
-//! This is primarily used in handling app handoff between devices.
+//! This is primarily used in handling app handoff between devices, and (on UIKit) for scene
+//! state restoration.
 
 use objc::rc::{Id, Shared};
 use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
 
-use crate::foundation::id;
+use crate::foundation::{id, nil, NSString};
+
+#[cfg(feature = "corespotlight")]
+use crate::foundation::to_bool;
 
 /// Represents an `NSUserActivity`, which acts as a lightweight method to capture
 /// the state of your app.
@@ -13,8 +18,71 @@ use crate::foundation::id;
 pub struct UserActivity(pub Id<Object, Shared>);
 
 impl UserActivity {
+    /// Creates a new `UserActivity` with the given activity type. Activity types are typically
+    /// reverse-DNS style strings (e.g, `com.myapp.editing-document`), and should be declared in
+    /// your `Info.plist` under `NSUserActivityTypes`.
+    pub fn new(activity_type: &str) -> Self {
+        let activity_type = NSString::new(activity_type);
+
+        UserActivity(unsafe {
+            let alloc = msg_send_id![class!(NSUserActivity), alloc];
+            msg_send_id![alloc, initWithActivityType: &*activity_type]
+        })
+    }
+
     /// An internal method for wrapping a system-provided activity.
     pub(crate) fn with_inner(object: id) -> Self {
         UserActivity(unsafe { Id::retain(object).unwrap() })
     }
+
+    /// Returns the activity type this was created with.
+    pub fn activity_type(&self) -> String {
+        NSString::retain(unsafe { msg_send![&*self.0, activityType] }).to_string()
+    }
+
+    /// Sets a user-visible title for this activity.
+    pub fn set_title(&self, title: &str) {
+        let title = NSString::new(title);
+
+        unsafe {
+            let _: () = msg_send![&*self.0, setTitle: &*title];
+        }
+    }
+
+    /// Returns the string stored under `key` in this activity's `userInfo`, if there is one.
+    pub fn user_info_value(&self, key: &str) -> Option<String> {
+        let key = NSString::new(key);
+
+        unsafe {
+            let user_info: id = msg_send![&*self.0, userInfo];
+            let value: id = msg_send![user_info, objectForKey: &*key];
+
+            match value != nil && NSString::is(value) {
+                true => Some(NSString::retain(value).to_string()),
+                false => None
+            }
+        }
+    }
+
+    /// If this activity was created because the user selected one of your app's Spotlight
+    /// results, returns the `unique_identifier` of the `SearchableItem` they picked.
+    #[cfg(feature = "corespotlight")]
+    pub fn searchable_item_identifier(&self) -> Option<String> {
+        use crate::corespotlight::{CSSearchableItemActionType, CSSearchableItemActivityIdentifier};
+
+        unsafe {
+            let activity_type: id = msg_send![&*self.0, activityType];
+            if !to_bool(msg_send![activity_type, isEqualToString: CSSearchableItemActionType]) {
+                return None;
+            }
+
+            let user_info: id = msg_send![&*self.0, userInfo];
+            let identifier: id = msg_send![user_info, objectForKey: CSSearchableItemActivityIdentifier];
+
+            match identifier == nil {
+                true => None,
+                false => Some(NSString::retain(identifier).to_string())
+            }
+        }
+    }
 }
@@ -9,7 +9,8 @@ use block::ConcreteBlock;
 use objc::{class, msg_send, sel};
 use uuid::Uuid;
 
-use crate::foundation::{id, nil, NSString, NSUInteger};
+use crate::error::Error;
+use crate::foundation::{id, nil, to_bool, NSString, NSUInteger, BOOL};
 
 pub mod enums;
 pub use enums::NotificationAuthOption;
@@ -22,29 +23,33 @@ pub use notifications::Notification;
 pub struct NotificationCenter;
 
 impl NotificationCenter {
-    /// Requests authorization from the user to send them notifications.
-    pub fn request_authorization(options: &[NotificationAuthOption]) {
-        unsafe {
-            // @TODO: Revisit.
-            let block = ConcreteBlock::new(|_: id, error: id| {
-                let localized_description = NSString::retain(msg_send![error, localizedDescription]);
-                let e = localized_description.to_str();
-                if e != "" {
-                    println!("{:?}", e);
-                }
-            });
+    /// Requests authorization from the user to send them notifications. The callback receives
+    /// whether authorization was granted, or the error that occurred while asking.
+    ///
+    /// Note that this callback can come back on a separate thread, so react accordingly to get to
+    /// the main thread if you need to.
+    pub fn request_authorization<F>(options: &[NotificationAuthOption], callback: F)
+    where
+        F: Fn(Result<bool, Error>) + Send + Sync + 'static
+    {
+        let block = ConcreteBlock::new(move |granted: BOOL, error: id| match error == nil {
+            true => callback(Ok(to_bool(granted))),
+            false => callback(Err(Error::new(error)))
+        });
+        let block = block.copy();
 
-            let mut opts: NSUInteger = 0;
-            for opt in options {
-                let o: NSUInteger = opt.into();
-                opts = opts << o;
-            }
+        let mut opts: NSUInteger = 0;
+        for opt in options {
+            let o: NSUInteger = opt.into();
+            opts = opts | o;
+        }
 
+        unsafe {
             let center: id = msg_send![class!(UNUserNotificationCenter), currentNotificationCenter];
             let _: () = msg_send![
                 center,
                 requestAuthorizationWithOptions:opts,
-                completionHandler: &*block.copy(),
+                completionHandler: &*block,
             ];
         }
     }
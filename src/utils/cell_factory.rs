@@ -43,6 +43,11 @@ impl CellFactory {
         );
     }
 
+    /// Returns whether a closure has been stored for the given identifier.
+    pub fn contains(&self, identifier: &'static str) -> bool {
+        self.0.borrow().contains_key(identifier)
+    }
+
     /// Attempts to retrieve the closure, downcasted to the specified type. This will panic if it's
     /// unable to retrieve the closure with the requested type.
     pub fn get<R>(&self, identifier: &'static str) -> Box<R>
@@ -0,0 +1,84 @@
+//! Storage for the pointer that ties an Objective-C object back to its Rust delegate.
+//!
+//! The original approach here stored the delegate pointer directly in a `usize` ivar. That's fine
+//! provided the Rust side outlives the Objective-C side, but nothing actually enforces that: the
+//! system can (and does) message objects after their owning component has dropped - e.g, a window
+//! that's retained elsewhere, or a view that's still animating out - at which point the pointer
+//! dangles and we crash (or worse).
+//!
+//! Instead, the owning component holds a `DelegateRef`, a reference-counted holder for the
+//! delegate pointer, and the Objective-C object only stores a `Weak` reference to it. When the
+//! owner drops, the weak reference can no longer be upgraded, and `load_weak` returns `None` - so
+//! callbacks that arrive late become no-ops rather than undefined behavior.
+
+use std::mem::ManuallyDrop;
+use std::rc::{Rc, Weak};
+
+use objc::runtime::Object;
+
+/// The strong side of a delegate pointer. The Objective-C object holds the weak side; once this
+/// is dropped, callbacks into the delegate stop.
+#[derive(Debug)]
+pub struct DelegateRef(Rc<usize>);
+
+impl DelegateRef {
+    /// Stores a weak reference to `delegate` in the `ivar` on `obj`, and returns the strong
+    /// reference that keeps it alive. The delegate must be boxed (or otherwise pinned) for as
+    /// long as the returned `DelegateRef` lives.
+    ///
+    /// If `obj` already holds a weak reference in `ivar`, it's released first.
+    pub fn attach<T>(obj: &mut Object, ivar: &str, delegate: &T) -> Self {
+        release_weak(obj, ivar);
+
+        let holder = Rc::new(delegate as *const T as usize);
+        let weak = Rc::downgrade(&holder);
+
+        unsafe {
+            obj.set_ivar(ivar, Weak::into_raw(weak) as usize);
+        }
+
+        DelegateRef(holder)
+    }
+}
+
+/// Retrieves the delegate for `this`, provided it's been set and the owning component hasn't been
+/// dropped. This is the counterpart to `DelegateRef::attach`; it's not valid for ivars that store
+/// a raw pointer (use `load` for those).
+pub fn load_weak<'a, T>(this: &'a Object, ptr_name: &str) -> Option<&'a T> {
+    #[cfg(all(feature = "log-dispatch", not(gnustep)))]
+    crate::logging::log_dispatch::<T>(this.class().name());
+
+    let ptr: usize = unsafe { *this.get_ivar(ptr_name) };
+
+    if ptr == 0 {
+        return None;
+    }
+
+    // The Objective-C object owns this weak reference, so we need to avoid releasing it here.
+    let weak = ManuallyDrop::new(unsafe { Weak::from_raw(ptr as *const usize) });
+    let holder = match weak.upgrade() {
+        Some(holder) => holder,
+
+        None => {
+            #[cfg(feature = "object-tracking")]
+            crate::debug::warn_delegate_dropped(this);
+
+            return None;
+        }
+    };
+
+    Some(unsafe { &*(*holder as *const T) })
+}
+
+/// Releases the weak reference stored in `ptr_name`. This should be called from `dealloc` on any
+/// class that uses `DelegateRef::attach`.
+pub fn release_weak(this: &mut Object, ptr_name: &str) {
+    unsafe {
+        let ptr: usize = *this.get_ivar(ptr_name);
+
+        if ptr != 0 {
+            drop(Weak::from_raw(ptr as *const usize));
+            this.set_ivar(ptr_name, 0usize);
+        }
+    }
+}
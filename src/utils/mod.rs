@@ -2,23 +2,47 @@
 //! belong to. These are typically internal, and if you rely on them... well, don't be surprised if
 //! they go away one day.
 
+use std::ops::Range;
+
 use core_foundation::base::CFIndex;
 use core_graphics::base::CGFloat;
 
 use objc::{class, msg_send, sel};
 
 use objc::rc::{Id, Shared};
-use objc::runtime::Object;
+use objc::runtime::{Bool, Object, Sel};
 use objc::{Encode, Encoding};
 
-use crate::foundation::{id, BOOL, NO, YES};
+use crate::foundation::{id, NSUInteger, BOOL, NO, YES};
 
 mod cell_factory;
 pub use cell_factory::CellFactory;
 
+mod delegate;
+pub use delegate::{load_weak, release_weak, DelegateRef};
+
+mod owner;
+pub(crate) use owner::tie_to_lifetime;
+
 pub mod os;
 pub mod properties;
 
+/// Registers cacao's most commonly used subclasses up front, so that the first window (or the
+/// first screen of a large `ListView`) doesn't pay for it mid-render. This is called from
+/// `App::new()`; anything not listed here (e.g, delegate-backed classes, which are generic) is
+/// still registered lazily on first use.
+#[cfg(any(appkit, uikit))]
+pub(crate) fn register_builtin_classes() {
+    crate::view::native_interface::register_view_class();
+    crate::text::register_label_class();
+    crate::button::register_class();
+    crate::image::register_image_view_class();
+    crate::input::register_view_class();
+    crate::scrollview::register_scrollview_class();
+    crate::listview::register_listview_class();
+    crate::listview::register_listview_row_class();
+}
+
 /// A generic trait that's used throughout multiple different controls in this framework - acts as
 /// a guard for whether something is a (View|Window|etc)Controller.
 pub trait Controller {
@@ -41,8 +65,12 @@ pub trait Controller {
 /// cloned, though.
 ///
 /// This is, like much in this framework, subject to revision pending more thorough testing and
-/// checking.
+/// checking. Windows, views, list views, text fields and web views have moved to `load_weak`,
+/// which can't dangle.
 pub fn load<'a, T>(this: &'a Object, ptr_name: &str) -> &'a T {
+    #[cfg(all(feature = "log-dispatch", not(gnustep)))]
+    crate::logging::log_dispatch::<T>(this.class().name());
+
     unsafe {
         let ptr: usize = *this.get_ivar(ptr_name);
         let obj = ptr as *const T;
@@ -50,6 +78,14 @@ pub fn load<'a, T>(this: &'a Object, ptr_name: &str) -> &'a T {
     }
 }
 
+/// Shorthand for `respondsToSelector:`. Non-Apple AppKit implementations (GNUstep, Airyx) don't
+/// implement everything that macOS does, so methods wrapping newer APIs check this and quietly do
+/// nothing rather than raising an unrecognized selector exception.
+pub fn responds_to(obj: &Object, sel: Sel) -> bool {
+    let responds: Bool = unsafe { msg_send![obj, respondsToSelector: sel] };
+    responds.as_bool()
+}
+
 /// Asynchronously execute a callback on the main thread via Grand Central Dispatch.
 pub fn async_main_thread<F>(method: F)
 where
@@ -116,6 +152,35 @@ unsafe impl Encode for CFRange {
     const ENCODING: Encoding = Encoding::Struct("CFRange", &[CFIndex::ENCODING, CFIndex::ENCODING]);
 }
 
+/// A wrapper for `NSRange`, which is used for text selection and the like. This is distinct from
+/// `CFRange` in that it's unsigned.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NSRange {
+    pub location: NSUInteger,
+    pub length: NSUInteger
+}
+
+impl From<Range<usize>> for NSRange {
+    fn from(range: Range<usize>) -> Self {
+        NSRange {
+            location: range.start as NSUInteger,
+            length: range.end.saturating_sub(range.start) as NSUInteger
+        }
+    }
+}
+
+impl From<NSRange> for Range<usize> {
+    fn from(range: NSRange) -> Self {
+        let start = range.location as usize;
+        start..(start + range.length as usize)
+    }
+}
+
+unsafe impl Encode for NSRange {
+    const ENCODING: Encoding = Encoding::Struct("_NSRange", &[NSUInteger::ENCODING, NSUInteger::ENCODING]);
+}
+
 /// A helper method for ensuring that Cocoa is running in multi-threaded mode.
 ///
 /// Why do we need this? According to Apple, if you're going to make use of standard POSIX threads,
@@ -135,3 +200,35 @@ pub fn activate_cocoa_multithreading() {
         let _: () = msg_send![thread, start];
     }
 }
+
+#[cfg(all(test, appkit))]
+mod tests {
+    use crate::button::Button;
+    use crate::foundation::class_lookups;
+    use crate::image::ImageView;
+    use crate::input::TextField;
+    use crate::scrollview::ScrollView;
+    use crate::text::Label;
+    use crate::view::View;
+
+    /// Once the built-in classes are registered (as `App::new()` does), creating the common
+    /// controls shouldn't need to go through the `ClassMap` at all.
+    #[test]
+    fn builtin_classes_skip_class_lookups() {
+        crate::testing::initialize();
+        super::register_builtin_classes();
+
+        let lookups = class_lookups();
+
+        for _ in 0..100 {
+            let _ = View::new();
+            let _ = Label::new();
+            let _ = Button::new("Button");
+            let _ = ImageView::new();
+            let _ = TextField::new();
+            let _ = ScrollView::new();
+        }
+
+        assert_eq!(class_lookups(), lookups);
+    }
+}
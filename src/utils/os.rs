@@ -1,8 +1,12 @@
-//! Helper methods for OS version checking.
+//! Helper methods for OS version checking. For most uses, the `available!` macro is the way in.
 
 use lazy_static::lazy_static;
+use objc::{class, msg_send, sel};
+use objc::{Encode, Encoding};
 use os_info::Version;
 
+use crate::foundation::{id, NSInteger};
+
 lazy_static! {
     /// A cached struct containing OS version for runtime checks.
     pub static ref OS_VERSION: os_info::Info = os_info::get();
@@ -10,8 +14,16 @@ lazy_static! {
 
 /// In rare cases we need to check whether something is a specific version of macOS. This is a
 /// runtime check thhat returns a boolean indicating whether the current version is a minimum target.
+///
+/// Under GNUstep the "OS version" is that of the host (e.g, a Linux kernel), which says nothing
+/// about which AppKit APIs exist - so this always returns `false` there, and callers take their
+/// oldest fallback path.
 #[inline(always)]
 pub fn is_minimum_version(minimum_major: u64) -> bool {
+    if cfg!(gnustep) {
+        return false;
+    }
+
     match OS_VERSION.version() {
         Version::Semantic(os_major, _, _) => *os_major >= minimum_major,
         _ => false
@@ -20,8 +32,146 @@ pub fn is_minimum_version(minimum_major: u64) -> bool {
 
 /// In rare cases we need to check whether something is a specific version of macOS. This is a
 /// runtime check thhat returns a boolean indicating whether the current version is a minimum target.
+///
+/// As with `is_minimum_version`, this always returns `false` under GNUstep.
 #[inline(always)]
 pub fn is_minimum_semversion(major: u64, minor: u64, patch: u64) -> bool {
+    if cfg!(gnustep) {
+        return false;
+    }
+
     let target = Version::Semantic(major, minor, patch);
     OS_VERSION.version() > &target
 }
+
+/// The version of the running OS, as reported by `NSProcessInfo`. Unlike `OS_VERSION`, this is
+/// accurate on every Apple platform (not just macOS).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperatingSystemVersion {
+    /// e.g, `12` for macOS 12.3.1.
+    pub major: u64,
+
+    /// e.g, `3` for macOS 12.3.1.
+    pub minor: u64,
+
+    /// e.g, `1` for macOS 12.3.1.
+    pub patch: u64
+}
+
+/// Mirrors `NSOperatingSystemVersion`, for reading it over `msg_send!`.
+#[repr(C)]
+struct NSOperatingSystemVersion {
+    major: NSInteger,
+    minor: NSInteger,
+    patch: NSInteger
+}
+
+unsafe impl Encode for NSOperatingSystemVersion {
+    const ENCODING: Encoding = Encoding::Struct("?", &[NSInteger::ENCODING, NSInteger::ENCODING, NSInteger::ENCODING]);
+}
+
+lazy_static! {
+    static ref PROCESS_OS_VERSION: OperatingSystemVersion = unsafe {
+        let info: id = msg_send![class!(NSProcessInfo), processInfo];
+        let version: NSOperatingSystemVersion = msg_send![info, operatingSystemVersion];
+
+        OperatingSystemVersion {
+            major: version.major as u64,
+            minor: version.minor as u64,
+            patch: version.patch as u64
+        }
+    };
+}
+
+/// Returns the version of the running OS.
+pub fn version() -> OperatingSystemVersion {
+    *PROCESS_OS_VERSION
+}
+
+/// The name that `available!` uses for the platform we're running on.
+fn current_platform() -> &'static str {
+    if cfg!(any(target_os = "macos", catalyst)) {
+        "macos"
+    } else if cfg!(target_os = "tvos") {
+        "tvos"
+    } else if cfg!(target_os = "ios") {
+        "ios"
+    } else {
+        "unknown"
+    }
+}
+
+/// Parses a version like `12`, `12.3` or `12.3.1`.
+fn parse_version(version: &str) -> Option<OperatingSystemVersion> {
+    let mut parts = version.split('.').map(|part| part.parse::<u64>());
+
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+
+    match parts.next() {
+        Some(_) => None,
+        None => Some(OperatingSystemVersion { major, minor, patch })
+    }
+}
+
+/// The function behind `available!`: given `(platform, minimum version)` pairs, returns whether
+/// the running OS is at least the version listed for its platform. Platforms that aren't listed
+/// are considered unavailable, as is everything under GNUstep.
+///
+/// Platform names are `macos` (which includes Mac Catalyst, as it reports the macOS version),
+/// `ios` and `tvos`.
+pub fn is_available(requirements: &[(&str, &str)]) -> bool {
+    if cfg!(gnustep) {
+        return false;
+    }
+
+    let platform = current_platform();
+
+    requirements
+        .iter()
+        .find(|(name, _)| *name == platform)
+        .and_then(|(_, minimum)| parse_version(minimum))
+        .map(|minimum| version() >= minimum)
+        .unwrap_or(false)
+}
+
+/// Checks whether the running OS is at least a given version, per platform:
+///
+/// ```rust,no_run
+/// use cacao::available;
+///
+/// if available!(macos 12, ios 15) {
+///     // Use the newer API...
+/// }
+///
+/// if available!(macos 10.15) {
+///     // ...
+/// }
+/// ```
+///
+/// Platforms that aren't listed evaluate to `false`. APIs in cacao that require a newer OS than
+/// the one running return an `Error` (see `Error::is_unsupported_on_this_os()`) rather than
+/// silently doing nothing.
+#[macro_export]
+macro_rules! available {
+    ($($platform:ident $version:literal),+ $(,)?) => {
+        $crate::utils::os::is_available(&[$((stringify!($platform), stringify!($version))),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_version, OperatingSystemVersion};
+
+    #[test]
+    fn parses_versions() {
+        let version = |major, minor, patch| Some(OperatingSystemVersion { major, minor, patch });
+
+        assert_eq!(parse_version("12"), version(12, 0, 0));
+        assert_eq!(parse_version("10.15"), version(10, 15, 0));
+        assert_eq!(parse_version("12.3.1"), version(12, 3, 1));
+        assert_eq!(parse_version("12.x"), None);
+        assert_eq!(parse_version("1.2.3.4"), None);
+    }
+}
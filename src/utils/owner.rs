@@ -0,0 +1,66 @@
+//! Ties the lifetime of Rust values to an Objective-C object.
+//!
+//! Normally a cacao component owns its Objective-C object (and, through it, the delegate). When a
+//! component is handed over to Objective-C or Swift code - e.g, a view embedded in a Swift app -
+//! that flips around: the Objective-C side decides when things go away, and the Rust side needs to
+//! stay alive until then. We do this by attaching a small owner object as an associated object;
+//! the owner drops the Rust values when it's deallocated, which happens when the object it's
+//! attached to is.
+
+use std::any::Any;
+use std::ffi::c_void;
+
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, ffi, msg_send, sel};
+
+use crate::foundation::{id, load_or_register_class};
+
+static OWNED_VALUE_PTR: &str = "rstOwnedValue";
+
+/// Used as the key for the associated object; only its address matters.
+static OWNER_KEY: u8 = 0;
+
+/// Drops the owned values, before the owner is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    unsafe {
+        let ptr: usize = *this.get_ivar(OWNED_VALUE_PTR);
+
+        if ptr != 0 {
+            drop(Box::from_raw(ptr as *mut Box<dyn Any>));
+            this.set_ivar(OWNED_VALUE_PTR, 0usize);
+        }
+
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
+/// Registers the owner class, which does nothing but hold (and eventually drop) a boxed value.
+fn register_owner_class() -> &'static Class {
+    load_or_register_class("NSObject", "RSTLifetimeOwner", |decl| unsafe {
+        decl.add_ivar::<usize>(OWNED_VALUE_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
+    })
+}
+
+/// Keeps `value` alive until `obj` is deallocated. Calling this again for the same object
+/// replaces (and drops) the previous value.
+///
+/// `value` must not hold a strong reference to `obj`, or neither will ever be freed.
+pub(crate) fn tie_to_lifetime<V: 'static>(obj: id, value: V) {
+    let value: Box<Box<dyn Any>> = Box::new(Box::new(value));
+
+    unsafe {
+        let owner: id = msg_send![register_owner_class(), new];
+        (&mut *owner).set_ivar(OWNED_VALUE_PTR, Box::into_raw(value) as usize);
+
+        // The associated object retains the owner, so we can let go of ours.
+        ffi::objc_setAssociatedObject(
+            obj as *mut _,
+            &OWNER_KEY as *const u8 as *const c_void,
+            owner as *mut _,
+            ffi::OBJC_ASSOCIATION_RETAIN_NONATOMIC
+        );
+
+        let _: () = msg_send![owner, release];
+    }
+}
@@ -1,7 +1,7 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use objc::rc::{Id, Owned};
+use objc::rc::{Id, Shared};
 use objc::runtime::Object;
 
 use crate::foundation::id;
@@ -10,35 +10,43 @@ use crate::foundation::id;
 ///
 /// An `ObjcProperty` is something that exists on the Objective-C side that we want to interact with, and
 /// support cloning with respect to our side and the general Rust rules. Thus, we do a layer of
-/// Rc/RefCell to shield things and make life easier.
+/// Rc to shield things and make life easier.
 ///
-/// It is possible we could remove the `Id` wrapper in here if we're just doing this ourselves, and
-/// is probably worth investigating at some point.
+/// There's deliberately no `RefCell` here: the wrapped object is never swapped out, and handlers
+/// only ever get a pointer to it, so borrow tracking bought us nothing but a per-call cost (which
+/// adds up when thousands of list rows are configured while scrolling) and panics on re-entrant
+/// access (e.g, a delegate callback touching the view that triggered it). Since `Rc` isn't `Send`,
+/// a property still can't leave the thread it was created on.
 #[derive(Clone, Debug)]
-pub struct ObjcProperty(Rc<RefCell<Id<Object, Owned>>>);
+pub struct ObjcProperty(Rc<Id<Object, Shared>>);
 
 impl ObjcProperty {
     /// Given an Objective-C object, retains it and wraps it as a `Property`.
     pub fn retain(obj: id) -> Self {
-        ObjcProperty(Rc::new(RefCell::new(unsafe { Id::retain(obj).unwrap() })))
+        let obj: Id<Object, Shared> = unsafe { Id::retain(obj).unwrap() };
+
+        #[cfg(feature = "object-tracking")]
+        crate::debug::track(&obj);
+
+        ObjcProperty(Rc::new(obj))
     }
 
     /// Runs a handler with mutable access for the underlying Objective-C object.
     ///
     /// Note that this is mutable access from the Rust side; we make every effort to ensure things are valid
     /// on the Objective-C side as well, but there be dragons.
+    #[inline]
     pub fn with_mut<F: Fn(id)>(&self, handler: F) {
-        let mut obj = self.0.borrow_mut();
-        handler(&mut **obj);
+        handler(&**self.0 as *const Object as id);
     }
 
     /// Runs a handler with the underlying Objective-C type.
     ///
     /// The handler can return whatever; this is primarily intended for dynamically calling getters
     /// on the underlying type.
+    #[inline]
     pub fn get<R, F: Fn(&Object) -> R>(&self, handler: F) -> R {
-        let obj = self.0.borrow();
-        handler(&**obj)
+        handler(&**self.0)
     }
 }
 
@@ -7,14 +7,20 @@
 //! for in the modern era. It also implements a few helpers for things like setting a background
 //! color, and enforcing layer backing by default.
 
+use core_graphics::base::CGFloat;
+use core_graphics::geometry::{CGPoint, CGRect, CGSize};
+
 use objc::declare::ClassDecl;
-use objc::rc::{Id, Owned};
+use objc::rc::{Id, Owned, Shared};
 use objc::runtime::{Bool, Class, Object, Sel};
-use objc::{class, msg_send, sel};
+use objc::{class, msg_send, msg_send_id, sel};
 
+use crate::appkit::Event;
+use crate::color::Color;
 use crate::dragdrop::DragInfo;
-use crate::foundation::{id, load_or_register_class, nil, NSUInteger};
-use crate::utils::load;
+use crate::foundation::{id, load_or_register_class, nil, NSString, NSUInteger, StaticClass};
+use crate::image::Image;
+use crate::utils::{load_weak, release_weak};
 use crate::view::{ViewDelegate, BACKGROUND_COLOR, VIEW_DELEGATE_PTR};
 
 /// Enforces normalcy, or: a needlessly cruel method in terms of the name. You get the idea though.
@@ -24,7 +30,10 @@ extern "C" fn enforce_normalcy(_: &Object, _: Sel) -> Bool {
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn dragging_entered<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> NSUInteger {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return 0
+    };
     view.dragging_entered(DragInfo {
         info: unsafe { Id::retain(info).unwrap() }
     })
@@ -33,7 +42,10 @@ extern "C" fn dragging_entered<T: ViewDelegate>(this: &mut Object, _: Sel, info:
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> Bool {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return Bool::NO
+    };
 
     Bool::new(view.prepare_for_drag_operation(DragInfo {
         info: unsafe { Id::retain(info).unwrap() }
@@ -42,7 +54,10 @@ extern "C" fn prepare_for_drag_operation<T: ViewDelegate>(this: &mut Object, _:
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) -> Bool {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return Bool::NO
+    };
 
     Bool::new(view.perform_drag_operation(DragInfo {
         info: unsafe { Id::retain(info).unwrap() }
@@ -51,7 +66,10 @@ extern "C" fn perform_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel,
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn conclude_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
 
     view.conclude_drag_operation(DragInfo {
         info: unsafe { Id::retain(info).unwrap() }
@@ -60,13 +78,244 @@ extern "C" fn conclude_drag_operation<T: ViewDelegate>(this: &mut Object, _: Sel
 
 /// Called when a drag/drop operation has entered this view.
 extern "C" fn dragging_exited<T: ViewDelegate>(this: &mut Object, _: Sel, info: id) {
-    let view = load::<T>(this, VIEW_DELEGATE_PTR);
+    let view = match load_weak::<T>(this, VIEW_DELEGATE_PTR) {
+        Some(view) => view,
+        None => return
+    };
 
     view.dragging_exited(DragInfo {
         info: unsafe { Id::retain(info).unwrap() }
     });
 }
 
+/// Generates the forwarding for a trackpad gesture (or other input event): the delegate gets
+/// first dibs, and anything it doesn't handle goes to `super` (and so on up the responder chain).
+macro_rules! gesture {
+    ($name:ident, $method:ident, $selector:ident) => {
+        extern "C" fn $name<T: ViewDelegate>(this: &Object, _: Sel, event: id) {
+            let handled = match delegate::<T>(this) {
+                Some(view) => view.$method(Event::new(event)),
+                None => false
+            };
+
+            if !handled {
+                unsafe {
+                    let _: () = msg_send![super(this, class!(NSView)), $selector: event];
+                }
+            }
+        }
+    };
+}
+
+gesture!(pressure_change, pressure_changed, pressureChangeWithEvent);
+gesture!(quick_look, quick_look, quickLookWithEvent);
+gesture!(swipe, swipe, swipeWithEvent);
+gesture!(magnify, magnify, magnifyWithEvent);
+gesture!(smart_magnify, smart_magnify, smartMagnifyWithEvent);
+gesture!(rotate, rotate, rotateWithEvent);
+
+gesture!(mouse_down, mouse_down, mouseDown);
+gesture!(mouse_dragged, mouse_dragged, mouseDragged);
+gesture!(mouse_up, mouse_up, mouseUp);
+gesture!(tablet_point, tablet_point, tabletPoint);
+gesture!(tablet_proximity, tablet_proximity, tabletProximity);
+
+gesture!(touches_began, touches_began, touchesBeganWithEvent);
+gesture!(touches_moved, touches_moved, touchesMovedWithEvent);
+gesture!(touches_ended, touches_ended, touchesEndedWithEvent);
+gesture!(touches_cancelled, touches_cancelled, touchesCancelledWithEvent);
+
+/// Pasteboard types we accept from Continuity Camera.
+const CONTINUITY_CAMERA_TYPES: [&str; 3] = ["public.png", "public.jpeg", "public.tiff"];
+
+/// Part of the Services protocol: Continuity Camera asks the responder chain for something that
+/// can take an image (with nothing to send). If the delegate accepts images, we're it.
+extern "C" fn valid_requestor<T: ViewDelegate>(this: &Object, _: Sel, send_type: id, return_type: id) -> id {
+    let accepts = match delegate::<T>(this) {
+        Some(view) => view.accepts_continuity_camera(),
+        None => false
+    };
+
+    let accepts_type = return_type != nil && {
+        let return_type = NSString::retain(return_type);
+        CONTINUITY_CAMERA_TYPES.contains(&return_type.to_str())
+    };
+
+    if accepts && send_type == nil && accepts_type {
+        return this as *const Object as id;
+    }
+
+    unsafe {
+        msg_send![
+            super(this, class!(NSView)),
+            validRequestorForSendType: send_type,
+            returnType: return_type
+        ]
+    }
+}
+
+/// Called with the pasteboard holding the photo or scan from Continuity Camera.
+extern "C" fn read_selection_from_pasteboard<T: ViewDelegate>(this: &Object, _: Sel, pasteboard: id) -> Bool {
+    let view = match delegate::<T>(this) {
+        Some(view) => view,
+        None => return Bool::NO
+    };
+
+    let image: Option<Id<Object, Shared>> =
+        unsafe { msg_send_id![msg_send_id![class!(NSImage), alloc], initWithPasteboard: pasteboard] };
+
+    match image {
+        Some(image) => {
+            view.did_receive_continuity_camera_image(Image(image));
+            Bool::YES
+        },
+
+        None => Bool::NO
+    }
+}
+
+/// Sends `selector` on to the next responder, for the font and color actions the delegate didn't
+/// handle. `NSView` doesn't implement them itself, so there's no `super` to call.
+fn forward_to_next_responder(this: &Object, selector: Sel, sender: id) {
+    unsafe {
+        let next: id = msg_send![this, nextResponder];
+
+        if next != nil {
+            let _: Bool = msg_send![next, tryToPerform: selector, with: sender];
+        }
+    }
+}
+
+/// Called when the user picks something in the Fonts window.
+extern "C" fn change_font<T: ViewDelegate>(this: &Object, _: Sel, sender: id) {
+    let handled = match delegate::<T>(this) {
+        Some(view) => view.change_font(),
+        None => false
+    };
+
+    if !handled {
+        forward_to_next_responder(this, sel!(changeFont:), sender);
+    }
+}
+
+/// Called when the user picks a color in the Colors window.
+extern "C" fn change_color<T: ViewDelegate>(this: &Object, _: Sel, sender: id) {
+    let handled = match delegate::<T>(this) {
+        Some(view) => view.change_color(Color::retain(unsafe { msg_send![sender, color] })),
+        None => false
+    };
+
+    if !handled {
+        forward_to_next_responder(this, sel!(changeColor:), sender);
+    }
+}
+
+/// Returns the delegate for this view, provided it's been set and is still alive. Some of the
+/// geometry methods we override can fire during initialization, before the delegate pointer has
+/// been stored.
+fn delegate<T: ViewDelegate>(this: &Object) -> Option<&T> {
+    load_weak::<T>(this, VIEW_DELEGATE_PTR)
+}
+
+/// Called when the view is laying out its subviews.
+extern "C" fn layout<T: ViewDelegate>(this: &Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), layout];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        view.layout();
+    }
+}
+
+/// Called when a live resize is about to start.
+extern "C" fn will_start_live_resize<T: ViewDelegate>(this: &Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), viewWillStartLiveResize];
+    }
+
+    if let Some(view) = load_weak::<T>(this, VIEW_DELEGATE_PTR) {
+        view.will_start_live_resize();
+    }
+}
+
+/// Called when a live resize has ended.
+extern "C" fn did_end_live_resize<T: ViewDelegate>(this: &Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), viewDidEndLiveResize];
+    }
+
+    if let Some(view) = load_weak::<T>(this, VIEW_DELEGATE_PTR) {
+        view.did_end_live_resize();
+    }
+}
+
+/// Called when the backing scale or color space changes, e.g the window moved to another display.
+extern "C" fn did_change_backing_properties<T: ViewDelegate>(this: &Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), viewDidChangeBackingProperties];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        let window: id = unsafe { msg_send![this, window] };
+
+        let scale: CGFloat = match window == nil {
+            true => 1.,
+            false => unsafe { msg_send![window, backingScaleFactor] }
+        };
+
+        view.did_change_backing_properties(scale as f64);
+    }
+}
+
+/// Called when the frame origin is set.
+extern "C" fn set_frame_origin<T: ViewDelegate>(this: &Object, _: Sel, origin: CGPoint) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), setFrameOrigin: origin];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        let frame: CGRect = unsafe { msg_send![this, frame] };
+        view.did_change_frame(frame.into());
+    }
+}
+
+/// Called when the frame size is set.
+extern "C" fn set_frame_size<T: ViewDelegate>(this: &Object, _: Sel, size: CGSize) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), setFrameSize: size];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        let frame: CGRect = unsafe { msg_send![this, frame] };
+        view.did_change_frame(frame.into());
+    }
+}
+
+/// Called when the bounds origin is set.
+extern "C" fn set_bounds_origin<T: ViewDelegate>(this: &Object, _: Sel, origin: CGPoint) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), setBoundsOrigin: origin];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        let bounds: CGRect = unsafe { msg_send![this, bounds] };
+        view.did_change_bounds(bounds.into());
+    }
+}
+
+/// Called when the bounds size is set.
+extern "C" fn set_bounds_size<T: ViewDelegate>(this: &Object, _: Sel, size: CGSize) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), setBoundsSize: size];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        let bounds: CGRect = unsafe { msg_send![this, bounds] };
+        view.did_change_bounds(bounds.into());
+    }
+}
+
 /// Called for layer updates.
 extern "C" fn update_layer(this: &Object, _: Sel) {
     unsafe {
@@ -84,15 +333,28 @@ extern "C" fn update_layer(this: &Object, _: Sel) {
 /// have separate classes here since we don't want to waste cycles on methods that will never be
 /// used if there's no delegates.
 pub(crate) fn register_view_class() -> &'static Class {
-    load_or_register_class("NSView", "RSTView", |decl| unsafe {
-        decl.add_method(sel!(isFlipped), enforce_normalcy as extern "C" fn(_, _) -> _);
-        decl.add_method(sel!(updateLayer), update_layer as extern "C" fn(_, _));
-        decl.add_method(sel!(wantsUpdateLayer), enforce_normalcy as extern "C" fn(_, _) -> _);
+    static CLASS: StaticClass = StaticClass::new();
 
-        decl.add_ivar::<id>(BACKGROUND_COLOR);
+    CLASS.get_or_register(|| {
+        load_or_register_class("NSView", "RSTView", |decl| unsafe {
+            decl.add_method(sel!(isFlipped), enforce_normalcy as extern "C" fn(_, _) -> _);
+            decl.add_method(sel!(updateLayer), update_layer as extern "C" fn(_, _));
+            decl.add_method(sel!(wantsUpdateLayer), enforce_normalcy as extern "C" fn(_, _) -> _);
+
+            decl.add_ivar::<id>(BACKGROUND_COLOR);
+        })
     })
 }
 
+/// Releases the weak reference to the delegate, before the view is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    release_weak(this, VIEW_DELEGATE_PTR);
+
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSView)), dealloc];
+    }
+}
+
 /// Injects an `NSView` subclass, with some callback and pointer ivars for what we
 /// need to do.
 pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>(instance: &T) -> &'static Class {
@@ -100,6 +362,7 @@ pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>(instance: &T) -
         // A pointer to the ViewDelegate instance on the Rust side.
         // It's expected that this doesn't move.
         decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
         decl.add_ivar::<id>(BACKGROUND_COLOR);
 
         decl.add_method(sel!(updateLayer), update_layer as extern "C" fn(_, _));
@@ -127,5 +390,57 @@ pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>(instance: &T) -
         );
 
         decl.add_method(sel!(draggingExited:), dragging_exited::<T> as extern "C" fn(_, _, _));
+
+        // Trackpad pressure and gestures
+        decl.add_method(sel!(pressureChangeWithEvent:), pressure_change::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(quickLookWithEvent:), quick_look::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(swipeWithEvent:), swipe::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(magnifyWithEvent:), magnify::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(smartMagnifyWithEvent:), smart_magnify::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(rotateWithEvent:), rotate::<T> as extern "C" fn(_, _, _));
+
+        // Mouse, tablet and touch input (e.g, for drawing)
+        decl.add_method(sel!(mouseDown:), mouse_down::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(mouseDragged:), mouse_dragged::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(mouseUp:), mouse_up::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(tabletPoint:), tablet_point::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(tabletProximity:), tablet_proximity::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(touchesBeganWithEvent:), touches_began::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(touchesMovedWithEvent:), touches_moved::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(touchesEndedWithEvent:), touches_ended::<T> as extern "C" fn(_, _, _));
+        decl.add_method(
+            sel!(touchesCancelledWithEvent:),
+            touches_cancelled::<T> as extern "C" fn(_, _, _)
+        );
+
+        // Continuity Camera (importing photos and scans from an iPhone or iPad)
+        decl.add_method(
+            sel!(validRequestorForSendType:returnType:),
+            valid_requestor::<T> as extern "C" fn(_, _, _, _) -> _
+        );
+        decl.add_method(
+            sel!(readSelectionFromPasteboard:),
+            read_selection_from_pasteboard::<T> as extern "C" fn(_, _, _) -> _
+        );
+
+        // The Fonts and Colors windows
+        decl.add_method(sel!(changeFont:), change_font::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(changeColor:), change_color::<T> as extern "C" fn(_, _, _));
+
+        // Layout and geometry changes
+        decl.add_method(sel!(layout), layout::<T> as extern "C" fn(_, _));
+        decl.add_method(
+            sel!(viewWillStartLiveResize),
+            will_start_live_resize::<T> as extern "C" fn(_, _)
+        );
+        decl.add_method(sel!(viewDidEndLiveResize), did_end_live_resize::<T> as extern "C" fn(_, _));
+        decl.add_method(
+            sel!(viewDidChangeBackingProperties),
+            did_change_backing_properties::<T> as extern "C" fn(_, _)
+        );
+        decl.add_method(sel!(setFrameOrigin:), set_frame_origin::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(setFrameSize:), set_frame_size::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(setBoundsOrigin:), set_bounds_origin::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(setBoundsSize:), set_bounds_size::<T> as extern "C" fn(_, _, _));
     })
 }
@@ -8,10 +8,16 @@ use crate::objc_access::ObjcAccess;
 use crate::utils::Controller;
 use crate::view::{View, ViewDelegate, VIEW_DELEGATE_PTR};
 
-#[cfg_attr(feature = "appkit", path = "appkit.rs")]
-#[cfg_attr(feature = "uikit", path = "uikit.rs")]
+#[cfg_attr(appkit, path = "appkit.rs")]
+#[cfg_attr(uikit, path = "uikit.rs")]
 mod native_interface;
 
+#[cfg(uikit)]
+mod presentation;
+
+#[cfg(uikit)]
+pub use presentation::{ModalPresentationStyle, SheetDetent};
+
 /// A `ViewController` is a wrapper around `NSViewController` in AppKit, and `UIViewController` in
 /// UIKit
 ///
@@ -67,8 +73,49 @@ where
             vc.into()
         };
 
+        #[cfg(feature = "object-tracking")]
+        crate::debug::track(&objc);
+
         ViewController { objc, view }
     }
+
+    /// Hands this controller over to Objective-C (or Swift) code, returning a retained (+1)
+    /// pointer to the underlying `NSViewController`/`UIViewController`. The delegate is kept alive
+    /// until the Objective-C controller is deallocated.
+    ///
+    /// See `View::into_raw()` for how to use this to embed Rust-built components in an existing app.
+    pub fn into_raw(mut self) -> id {
+        let owned = (self.view.delegate.take(), self.view.delegate_ref.take());
+        self.view.is_handle = true;
+
+        let controller = &*self.objc as *const Object as id;
+        crate::utils::tie_to_lifetime(controller, owned);
+
+        unsafe { msg_send![controller, retain] }
+    }
+}
+
+impl ViewController<()> {
+    /// Wraps an `NSViewController`/`UIViewController` that was created elsewhere - e.g, one owned
+    /// by a Swift app that you're migrating piecemeal. The controller is retained for as long as
+    /// the returned `ViewController` lives, and its view is wrapped with `View::existing()`; as
+    /// with that, the controller isn't torn down on the Rust side when this drops.
+    ///
+    /// Note that accessing the view will load it, if the controller hasn't yet.
+    ///
+    /// # Safety
+    ///
+    /// `controller` must be a valid, non-null pointer to an `NSViewController` (or
+    /// `UIViewController`).
+    pub unsafe fn existing(controller: *mut Object) -> Self {
+        let objc: Id<Object, Shared> = Id::retain(controller).unwrap();
+        let view: id = msg_send![&*objc, view];
+
+        ViewController {
+            objc,
+            view: View::existing(view)
+        }
+    }
 }
 
 impl<T> Controller for ViewController<T> {
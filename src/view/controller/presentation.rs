@@ -0,0 +1,264 @@
+//! Modal presentation for `ViewController` on UIKit: presenting and dismissing, presentation
+//! styles, and (on iOS 15 and later) resizable sheets with detents.
+//!
+//! ```rust,no_run
+//! use cacao::view::{ModalPresentationStyle, SheetDetent, ViewController, ViewDelegate};
+//!
+//! struct Root;
+//! impl ViewDelegate for Root {
+//!     const NAME: &'static str = "Root";
+//! }
+//!
+//! struct Details;
+//! impl ViewDelegate for Details {
+//!     const NAME: &'static str = "Details";
+//! }
+//!
+//! let root = ViewController::new(Root);
+//! let details = ViewController::new(Details);
+//!
+//! details.set_modal_presentation_style(ModalPresentationStyle::PageSheet);
+//! details.set_sheet_detents(&[SheetDetent::Medium, SheetDetent::Large]).ok();
+//! details.set_prefers_grabber_visible(true).ok();
+//!
+//! root.present(&details, true, || println!("Presented"));
+//! ```
+
+use block::ConcreteBlock;
+use objc::runtime::Object;
+use objc::{class, msg_send, sel};
+
+use crate::available;
+use crate::error::{Error, CACAO_ERROR_DOMAIN};
+use crate::foundation::{id, nil, NSArray, NSInteger, NSString, NO, YES};
+use crate::utils::Controller;
+use crate::view::ViewController;
+
+/// How a presented view controller is shown.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModalPresentationStyle {
+    /// Lets the system pick; on iOS 13 and later, this is usually a page sheet.
+    Automatic,
+
+    /// Covers the whole screen, removing the presenting view from the hierarchy.
+    FullScreen,
+
+    /// A card that covers most of the screen, leaving the presenting view peeking out at the top.
+    PageSheet,
+
+    /// A smaller, centered card on larger screens; a page sheet in compact widths.
+    FormSheet,
+
+    /// Covers the whole screen, but keeps the presenting view underneath - for content with
+    /// transparent areas.
+    OverFullScreen
+}
+
+impl From<ModalPresentationStyle> for NSInteger {
+    fn from(style: ModalPresentationStyle) -> Self {
+        match style {
+            ModalPresentationStyle::Automatic => -2,
+            ModalPresentationStyle::FullScreen => 0,
+            ModalPresentationStyle::PageSheet => 1,
+            ModalPresentationStyle::FormSheet => 2,
+            ModalPresentationStyle::OverFullScreen => 5
+        }
+    }
+}
+
+/// A height a sheet can rest at. See `ViewController::set_sheet_detents()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SheetDetent {
+    /// Roughly half the height of the screen.
+    Medium,
+
+    /// The full height of a sheet.
+    Large
+}
+
+impl SheetDetent {
+    /// Returns a (+0) `UISheetPresentationControllerDetent` for this detent.
+    fn to_objc(self) -> id {
+        unsafe {
+            match self {
+                SheetDetent::Medium => msg_send![class!(UISheetPresentationControllerDetent), mediumDetent],
+                SheetDetent::Large => msg_send![class!(UISheetPresentationControllerDetent), largeDetent]
+            }
+        }
+    }
+
+    /// Returns the detent's identifier. These are the values of
+    /// `UISheetPresentationControllerDetentIdentifierMedium` and `...Large`; we use them as
+    /// literals so that nothing iOS 15-only gets linked.
+    fn identifier(self) -> NSString<'static> {
+        NSString::new(match self {
+            SheetDetent::Medium => "com.apple.UIKit.medium",
+            SheetDetent::Large => "com.apple.UIKit.large"
+        })
+    }
+}
+
+impl<T> ViewController<T> {
+    /// Sets how this controller is shown when it's presented. Set this before calling
+    /// `present()`.
+    pub fn set_modal_presentation_style(&self, style: ModalPresentationStyle) {
+        let style: NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setModalPresentationStyle: style];
+        }
+    }
+
+    /// Sets whether the user can swipe this controller away while it's presented as a sheet.
+    /// Turn this off for sheets with unsaved changes.
+    pub fn set_modal_in_presentation(&self, modal: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setModalInPresentation: match modal {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Presents `controller` modally over this one, calling `completion` once it's on screen.
+    ///
+    /// You'll need to keep `controller` around while it's presented, the same as with
+    /// `Window::begin_sheet()` on AppKit.
+    pub fn present<C, F>(&self, controller: &C, animated: bool, completion: F)
+    where
+        C: Controller,
+        F: Fn() + Send + Sync + 'static
+    {
+        let controller = controller.get_backing_node();
+
+        let block = ConcreteBlock::new(move || {
+            completion();
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, presentViewController: &*controller, animated: match animated {
+                true => YES,
+                false => NO
+            }, completion: &*block];
+        }
+    }
+
+    /// Dismisses the controller this one presented - or, if this one was presented, dismisses
+    /// itself - calling `completion` once it's gone.
+    pub fn dismiss<F>(&self, animated: bool, completion: F)
+    where
+        F: Fn() + Send + Sync + 'static
+    {
+        let block = ConcreteBlock::new(move || {
+            completion();
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, dismissViewControllerAnimated: match animated {
+                true => YES,
+                false => NO
+            }, completion: &*block];
+        }
+    }
+
+    /// Returns whether this controller currently has another one presented over it.
+    pub fn is_presenting(&self) -> bool {
+        unsafe {
+            let presented: id = msg_send![&*self.objc, presentedViewController];
+            presented != nil
+        }
+    }
+
+    /// Sets the heights this controller's sheet can rest at, smallest first; the user drags
+    /// between them. Only applies when presented as a page or form sheet. Requires iOS 15.
+    pub fn set_sheet_detents(&self, detents: &[SheetDetent]) -> Result<(), Error> {
+        let sheet = self.sheet_presentation_controller("ViewController::set_sheet_detents")?;
+        let detents: Vec<id> = detents.iter().map(|detent| detent.to_objc()).collect();
+        let detents = NSArray::new(&detents);
+
+        unsafe {
+            let _: () = msg_send![sheet, setDetents: &*detents];
+        }
+
+        Ok(())
+    }
+
+    /// Moves this controller's sheet to `detent`, which should be one of those passed to
+    /// `set_sheet_detents()`. Pass `None` to use the smallest one. Requires iOS 15.
+    pub fn set_selected_sheet_detent(&self, detent: Option<SheetDetent>) -> Result<(), Error> {
+        let sheet = self.sheet_presentation_controller("ViewController::set_selected_sheet_detent")?;
+        let identifier = detent.map(SheetDetent::identifier);
+
+        let block = ConcreteBlock::new(move || {
+            let identifier = match &identifier {
+                Some(identifier) => &*identifier.objc as *const Object as id,
+                None => nil
+            };
+
+            unsafe {
+                let _: () = msg_send![sheet, setSelectedDetentIdentifier: identifier];
+            }
+        });
+        let block = block.copy();
+
+        unsafe {
+            let _: () = msg_send![sheet, animateChanges: &*block];
+        }
+
+        Ok(())
+    }
+
+    /// Sets the largest detent at which the content behind the sheet stays interactive, rather
+    /// than dimmed. Pass `None` to always dim it (the default). Requires iOS 15.
+    pub fn set_largest_undimmed_sheet_detent(&self, detent: Option<SheetDetent>) -> Result<(), Error> {
+        let sheet = self.sheet_presentation_controller("ViewController::set_largest_undimmed_sheet_detent")?;
+        let identifier = detent.map(SheetDetent::identifier);
+
+        unsafe {
+            let identifier = match &identifier {
+                Some(identifier) => &*identifier.objc as *const Object as id,
+                None => nil
+            };
+
+            let _: () = msg_send![sheet, setLargestUndimmedDetentIdentifier: identifier];
+        }
+
+        Ok(())
+    }
+
+    /// Sets whether the sheet shows a grabber at the top, hinting that it can be resized.
+    /// Requires iOS 15.
+    pub fn set_prefers_grabber_visible(&self, visible: bool) -> Result<(), Error> {
+        let sheet = self.sheet_presentation_controller("ViewController::set_prefers_grabber_visible")?;
+
+        unsafe {
+            let _: () = msg_send![sheet, setPrefersGrabberVisible: match visible {
+                true => YES,
+                false => NO
+            }];
+        }
+
+        Ok(())
+    }
+
+    /// Returns the `UISheetPresentationController` for this controller, or an error if the
+    /// running OS is older than iOS 15 (or this isn't being presented as a sheet).
+    fn sheet_presentation_controller(&self, api: &str) -> Result<id, Error> {
+        if !available!(ios 15) {
+            return Err(Error::unsupported_on_this_os(api, "iOS 15"));
+        }
+
+        let sheet: id = unsafe { msg_send![&*self.objc, sheetPresentationController] };
+
+        match sheet == nil {
+            true => Err(Error::custom(
+                CACAO_ERROR_DOMAIN,
+                0,
+                "This controller isn't set to be presented as a sheet."
+            )),
+            false => Ok(sheet)
+        }
+    }
+}
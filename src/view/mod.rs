@@ -51,35 +51,48 @@ use crate::layer::Layer;
 use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
 use crate::utils::properties::ObjcProperty;
+use crate::utils::DelegateRef;
 
 #[cfg(feature = "autolayout")]
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY, SafeAreaLayoutGuide};
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
+use crate::appkit::Appearance;
+
+#[cfg(appkit)]
+use crate::foundation::NSUInteger;
+
+#[cfg(appkit)]
 use crate::pasteboard::PasteboardType;
 
-#[cfg(all(feature = "appkit", target_os = "macos"))]
+#[cfg(uikit)]
+use crate::uikit::TraitCollection;
+
+#[cfg(all(appkit, target_os = "macos"))]
 mod animator;
 
-#[cfg(all(feature = "appkit", target_os = "macos"))]
+#[cfg(all(appkit, target_os = "macos"))]
 pub use animator::ViewAnimatorProxy;
 
-#[cfg_attr(feature = "appkit", path = "appkit.rs")]
-#[cfg_attr(feature = "uikit", path = "uikit.rs")]
-mod native_interface;
+#[cfg_attr(appkit, path = "appkit.rs")]
+#[cfg_attr(uikit, path = "uikit.rs")]
+pub(crate) mod native_interface;
 
 mod controller;
 pub use controller::ViewController;
 
-#[cfg(feature = "appkit")]
+#[cfg(uikit)]
+pub use controller::{ModalPresentationStyle, SheetDetent};
+
+#[cfg(appkit)]
 mod splitviewcontroller;
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 pub use splitviewcontroller::SplitViewController;
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 mod popover;
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 pub use popover::*;
 mod traits;
 pub use traits::ViewDelegate;
@@ -103,13 +116,18 @@ pub struct View<T = ()> {
     /// An object that supports limited animations. Can be cloned into animation closures.
     ///
     /// This is currently only supported on macOS with the `appkit` feature.
-    #[cfg(all(feature = "appkit", target_os = "macos"))]
+    #[cfg(all(appkit, target_os = "macos"))]
     pub animator: ViewAnimatorProxy,
 
     /// References the underlying layer. This is consistent across AppKit & UIKit - in AppKit
     /// we explicitly opt in to layer backed views.
     pub layer: Layer,
 
+    /// Keeps the delegate reachable from the Objective-C side; callbacks stop once this drops.
+    /// Declared ahead of `delegate` so that it drops first - otherwise there's a window where
+    /// callbacks could reach a freed delegate.
+    pub(crate) delegate_ref: Option<DelegateRef>,
+
     /// A pointer to the delegate for this view.
     pub delegate: Option<Box<T>>,
 
@@ -172,17 +190,42 @@ impl View {
     /// This handles grabbing autolayout anchor pointers, as well as things related to layering and
     /// so on. It returns a generic `View<T>`, which the caller can then customize as needed.
     pub(crate) fn init<T>(view: id) -> View<T> {
+        #[cfg(feature = "autolayout")]
         unsafe {
-            #[cfg(feature = "autolayout")]
             let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
+        }
 
-            #[cfg(feature = "appkit")]
+        View::wrap(view)
+    }
+
+    /// Wraps an `NSView`/`UIView` that was created elsewhere - e.g, the content view of a window
+    /// created by `winit`, obtained via its raw window handle. This lets you add cacao views to
+    /// (and constrain them against) a view that cacao doesn't own.
+    ///
+    /// The returned `View` is a handle: dropping it won't remove the view from its superview.
+    /// Unlike views created by cacao, `translatesAutoresizingMaskIntoConstraints` is left alone,
+    /// as whoever created the view is likely relying on it.
+    ///
+    /// # Safety
+    ///
+    /// `view` must be a valid, non-null pointer to an `NSView` (or `UIView`).
+    pub unsafe fn existing(view: *mut Object) -> View {
+        let mut view = View::wrap(view);
+        view.is_handle = true;
+        view
+    }
+
+    /// Builds the `View` struct around `view`, without touching its autolayout configuration.
+    fn wrap<T>(view: id) -> View<T> {
+        #[cfg(appkit)]
+        unsafe {
             let _: () = msg_send![view, setWantsLayer: YES];
         }
 
         View {
             is_handle: false,
             delegate: None,
+            delegate_ref: None,
 
             #[cfg(feature = "autolayout")]
             safe_layout_guide: SafeAreaLayoutGuide::new(view),
@@ -219,7 +262,7 @@ impl View {
 
             layer: Layer::from_id(unsafe { msg_send_id![view, layer] }),
 
-            #[cfg(all(feature = "appkit", target_os = "macos"))]
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: ViewAnimatorProxy::new(view),
             objc: ObjcProperty::retain(view)
         }
@@ -229,6 +272,15 @@ impl View {
     pub fn new() -> Self {
         View::init(unsafe { msg_send![native_interface::register_view_class(), new] })
     }
+
+    /// Returns a `View` backed by a custom subclass, as created via `Subclass::view()`. This lets
+    /// you override methods that `ViewDelegate` doesn't expose.
+    ///
+    /// Panics if `class` doesn't descend from the class backing `View`.
+    pub fn with_class(class: &'static Class) -> Self {
+        crate::subclass::assert_subclass_of(class, native_interface::register_view_class());
+        View::init(unsafe { msg_send![class, new] })
+    }
 }
 
 impl<T> View<T>
@@ -243,17 +295,51 @@ where
 
         let view = unsafe {
             let view: id = msg_send![class, new];
-            let ptr = Box::into_raw(delegate);
-            (&mut *view).set_ivar(VIEW_DELEGATE_PTR, ptr as usize);
-            delegate = Box::from_raw(ptr);
             view
         };
 
+        let delegate_ref = DelegateRef::attach(unsafe { &mut *view }, VIEW_DELEGATE_PTR, &*delegate);
+
         let mut view = View::init(view);
         (&mut delegate).did_load(view.clone_as_handle());
         view.delegate = Some(delegate);
+        view.delegate_ref = Some(delegate_ref);
         view
     }
+
+    /// Hands this view over to Objective-C (or Swift) code, returning a retained (+1) pointer to
+    /// the underlying `NSView`/`UIView`. This is the way to embed a Rust-built component in an
+    /// existing app: export a function that builds the view and returns this pointer, and take
+    /// ownership of it on the other side.
+    ///
+    /// The delegate is kept alive for as long as the Objective-C view is, and dropped when it's
+    /// deallocated; the view is no longer removed from its superview on the Rust side.
+    ///
+    /// ```rust,no_run
+    /// use std::ffi::c_void;
+    /// use cacao::view::{View, ViewDelegate};
+    ///
+    /// struct Profile;
+    ///
+    /// impl ViewDelegate for Profile {
+    ///     const NAME: &'static str = "ProfileView";
+    /// }
+    ///
+    /// // Swift: `Unmanaged<NSView>.fromOpaque(make_profile_view()).takeRetainedValue()`
+    /// #[no_mangle]
+    /// pub extern "C" fn make_profile_view() -> *mut c_void {
+    ///     View::with(Profile).into_raw() as *mut c_void
+    /// }
+    /// ```
+    pub fn into_raw(mut self) -> id {
+        let owned = (self.delegate.take(), self.delegate_ref.take());
+        self.is_handle = true;
+
+        let view = self.objc.get(|obj| obj as *const Object as id);
+        crate::utils::tie_to_lifetime(view, owned);
+
+        unsafe { msg_send![view, retain] }
+    }
 }
 
 impl<T> View<T> {
@@ -264,11 +350,12 @@ impl<T> View<T> {
     pub fn clone_as_handle(&self) -> View {
         View {
             delegate: None,
+            delegate_ref: None,
             is_handle: true,
             layer: self.layer.clone(),
             objc: self.objc.clone(),
 
-            #[cfg(all(feature = "appkit", target_os = "macos"))]
+            #[cfg(all(appkit, target_os = "macos"))]
             animator: self.animator.clone(),
 
             #[cfg(feature = "autolayout")]
@@ -310,24 +397,35 @@ impl<T> View<T> {
     pub fn set_background_color<C: AsRef<Color>>(&self, color: C) {
         let color: id = color.as_ref().into();
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         self.objc.with_mut(|obj| unsafe {
             // TODO: Fix this unnecessary retain!
             (&mut *obj).set_ivar::<id>(BACKGROUND_COLOR, msg_send![color, retain]);
         });
 
-        #[cfg(feature = "uikit")]
+        #[cfg(uikit)]
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![&*obj, setBackgroundColor: color];
         });
     }
 
+    /// Returns the view's current trait collection: its size classes, display scale and
+    /// light/dark mode. See `ViewDelegate::trait_collection_did_change()` to be told when these
+    /// change.
+    #[cfg(uikit)]
+    pub fn trait_collection(&self) -> TraitCollection {
+        self.objc.get(|obj| {
+            let traits: id = unsafe { msg_send![obj, traitCollection] };
+            TraitCollection::with(traits)
+        })
+    }
+
     /// A setter for `[NSView layerContentsRedrawPolicy]`.
     ///
     /// For more information, consult:
     ///
     /// [https://developer.apple.com/documentation/appkit/nsview/1483514-layercontentsredrawpolicy?language=objc](https://developer.apple.com/documentation/appkit/nsview/1483514-layercontentsredrawpolicy?language=objc)
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn set_contents_redraw_policy(&self, policy: LayerContentsRedrawPolicy) {
         self.objc.with_mut(|obj| unsafe {
             let policy = policy.to_nsinteger();
@@ -335,10 +433,62 @@ impl<T> View<T> {
         });
     }
 
+    /// Forces this view (and its subviews) into `appearance`, regardless of the window or system
+    /// setting.
+    #[cfg(appkit)]
+    pub fn set_appearance(&self, appearance: Appearance) {
+        let appearance = appearance.to_appearance();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setAppearance: appearance];
+        });
+    }
+
+    /// Removes an appearance set with `set_appearance`, so that this view inherits its
+    /// appearance from its superview (or window) again.
+    #[cfg(appkit)]
+    pub fn clear_appearance(&self) {
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setAppearance: nil];
+        });
+    }
+
+    /// Returns the appearance this view is actually drawn with - its own override, or the one
+    /// it inherits.
+    #[cfg(appkit)]
+    pub fn effective_appearance(&self) -> Appearance {
+        if !crate::available!(macos 10.14) {
+            return Appearance::Light;
+        }
+
+        self.objc
+            .get(|obj| Appearance::from_appearance(unsafe { msg_send![obj, effectiveAppearance] }))
+    }
+
+    /// Opts this view in to (or out of) receiving individual trackpad touches, via
+    /// `ViewDelegate::touches_began()` and friends. Set `resting` to also get fingers that are
+    /// just resting on the trackpad.
+    #[cfg(appkit)]
+    pub fn set_accepts_touches(&self, accepts: bool, resting: bool) {
+        // NSTouchTypeMaskIndirect, i.e a trackpad.
+        let mask: NSUInteger = match accepts {
+            true => 1 << 0,
+            false => 0
+        };
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, setAllowedTouchTypes: mask];
+            let _: () = msg_send![obj, setWantsRestingTouches: match resting {
+                true => YES,
+                false => NO
+            }];
+        });
+    }
+
     /// Mark all child layers as being able to be drawn into a single CALayer. This can be useful
     /// for moments when you need to lower your total layer count, which can impair composition
     /// time.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn set_can_draw_subviews_into_layer(&self, can: bool) {
         self.objc.with_mut(|obj| unsafe {
             let _: () = msg_send![&*obj, setCanDrawSubviewsIntoLayer:match can {
@@ -372,13 +522,16 @@ impl<T> Drop for View<T> {
     /// runtime, you can consider flagging your instance as a handle - it will avoid the drop logic here.
     fn drop(&mut self) {
         if !self.is_handle {
+            #[cfg(feature = "object-tracking")]
+            self.objc.get(crate::debug::check_view_drop);
+
             self.remove_from_superview();
         }
     }
 }
 
 /// Variants describing what an underlying NSView layer redraw policy should be.
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 #[derive(Debug)]
 pub enum LayerContentsRedrawPolicy {
     Never,
@@ -388,7 +541,7 @@ pub enum LayerContentsRedrawPolicy {
     Crossfade
 }
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 impl LayerContentsRedrawPolicy {
     /// Mapping required for ObjC setters.
     pub fn to_nsinteger(&self) -> NSInteger {
@@ -404,6 +557,8 @@ impl LayerContentsRedrawPolicy {
 
 #[test]
 fn test_view() {
+    crate::testing::initialize();
+
     let view = View::new();
     let _clone = view.clone_as_handle();
     view.set_background_color(Color::SystemGreen);
@@ -0,0 +1,59 @@
+//! Handles the Objective-C functionality for the Popover module.
+
+use objc::runtime::{Bool, Class, Object, Sel};
+use objc::sel;
+
+use crate::foundation::{id, load_or_register_class};
+use crate::utils::load;
+use crate::view::popover::{PopoverDelegate, POPOVER_DELEGATE_PTR};
+
+/// Called when the popover receives a `popoverWillShow:` message.
+extern "C" fn will_show<T: PopoverDelegate>(this: &Object, _: Sel, _: id) {
+    load::<T>(this, POPOVER_DELEGATE_PTR).will_show();
+}
+
+/// Called when the popover receives a `popoverDidShow:` message.
+extern "C" fn did_show<T: PopoverDelegate>(this: &Object, _: Sel, _: id) {
+    load::<T>(this, POPOVER_DELEGATE_PTR).did_show();
+}
+
+/// Called when the popover receives a `popoverShouldClose:` message.
+extern "C" fn should_close<T: PopoverDelegate>(this: &Object, _: Sel, _: id) -> Bool {
+    Bool::new(load::<T>(this, POPOVER_DELEGATE_PTR).should_close())
+}
+
+/// Called when the popover receives a `popoverWillClose:` message.
+extern "C" fn will_close<T: PopoverDelegate>(this: &Object, _: Sel, _: id) {
+    load::<T>(this, POPOVER_DELEGATE_PTR).will_close();
+}
+
+/// Called when the popover receives a `popoverDidClose:` message.
+extern "C" fn did_close<T: PopoverDelegate>(this: &Object, _: Sel, _: id) {
+    load::<T>(this, POPOVER_DELEGATE_PTR).did_close();
+}
+
+/// Called when the popover receives a `popoverShouldDetach:` message.
+extern "C" fn should_detach<T: PopoverDelegate>(this: &Object, _: Sel, _: id) -> Bool {
+    Bool::new(load::<T>(this, POPOVER_DELEGATE_PTR).should_detach())
+}
+
+/// Called when the popover receives a `popoverDidDetach:` message.
+extern "C" fn did_detach<T: PopoverDelegate>(this: &Object, _: Sel, _: id) {
+    load::<T>(this, POPOVER_DELEGATE_PTR).did_detach();
+}
+
+/// Registers an `NSObject` subclass to act as our `NSPopoverDelegate`, and configures it to hold
+/// a pointer back to the Rust-side delegate.
+pub(crate) fn register_popover_delegate_class<T: PopoverDelegate>(instance: &T) -> &'static Class {
+    load_or_register_class("NSObject", instance.subclass_name(), |decl| unsafe {
+        decl.add_ivar::<usize>(POPOVER_DELEGATE_PTR);
+
+        decl.add_method(sel!(popoverWillShow:), will_show::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(popoverDidShow:), did_show::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(popoverShouldClose:), should_close::<T> as extern "C" fn(_, _, _) -> _);
+        decl.add_method(sel!(popoverWillClose:), will_close::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(popoverDidClose:), did_close::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(popoverShouldDetach:), should_detach::<T> as extern "C" fn(_, _, _) -> _);
+        decl.add_method(sel!(popoverDidDetach:), did_detach::<T> as extern "C" fn(_, _, _));
+    })
+}
@@ -1,22 +1,33 @@
 use core_graphics::geometry::CGRect;
-use objc::rc::{Id, Shared};
+use objc::rc::{Id, Owned, Shared};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 use crate::appkit::toolbar::ToolbarItem;
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 use crate::appkit::window::Window;
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 use crate::appkit::App;
+#[cfg(appkit)]
+use crate::available;
+use crate::available;
 
-use crate::foundation::{id, nil, NSString};
+use crate::foundation::{id, nil, to_bool, NSString, BOOL, NO, YES};
 use crate::geometry::{Edge, Rect};
 use crate::layout::Layout;
-use crate::utils::{os, CGSize, Controller};
+use crate::utils::{CGSize, Controller};
 use crate::view::{View, ViewController, ViewDelegate};
 
-#[derive(Debug, Eq, PartialEq)]
+mod class;
+use class::register_popover_delegate_class;
+
+mod traits;
+pub use traits::PopoverDelegate;
+
+pub(crate) static POPOVER_DELEGATE_PTR: &str = "rstPopoverDelegatePtr";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[repr(i64)]
 pub enum PopoverBehaviour {
     /// Your application assumes responsibility for closing the popover.
@@ -47,13 +58,32 @@ impl Default for PopoverConfig {
     }
 }
 
+/// Creates and configures the underlying `NSPopover`.
+fn make_popover<Content>(view_controller: &ViewController<Content>, config: PopoverConfig) -> Id<Object, Shared> {
+    unsafe {
+        let pop: Id<Object, Shared> = msg_send_id![class!(NSPopover), new];
+        let _: () = msg_send![&pop, setContentSize: config.content_size];
+        let _: () = msg_send![&pop, setBehavior: config.behaviour as i64];
+        let _: () = msg_send![&pop, setAnimates: config.animates];
+        let _: () = msg_send![&pop, setContentViewController: &*view_controller.objc];
+
+        pop
+    }
+}
+
 #[derive(Debug)]
-pub struct Popover<Content> {
+pub struct Popover<Content, T = ()> {
     /// A reference to the underlying Objective-C NSPopover
     pub objc: Id<Object, Shared>,
 
     /// The wrapped ViewController.
-    pub view_controller: ViewController<Content>
+    pub view_controller: ViewController<Content>,
+
+    /// A pointer to the underlying Objective-C delegate, if one was set.
+    pub objc_delegate: Option<Id<Object, Shared>>,
+
+    /// The user supplied delegate, if one was set.
+    pub delegate: Option<Box<T>>
 }
 
 impl<Content> Popover<Content>
@@ -62,21 +92,50 @@ where
 {
     pub fn new(content: Content, config: PopoverConfig) -> Self {
         let view_controller = ViewController::new(content);
-        let objc = unsafe {
-            let pop: Id<Object, Shared> = msg_send_id![class!(NSPopover), new];
-            let _: () = msg_send![&pop, setContentSize: config.content_size];
-            let _: () = msg_send![&pop, setBehavior: config.behaviour as i64];
-            let _: () = msg_send![&pop, setAnimates: config.animates];
-            let _: () = msg_send![&pop, setContentViewController: &*view_controller.objc];
-
-            pop
+        let objc = make_popover(&view_controller, config);
+
+        Popover {
+            objc,
+            view_controller,
+            objc_delegate: None,
+            delegate: None
+        }
+    }
+}
+
+impl<Content, T> Popover<Content, T>
+where
+    Content: ViewDelegate + 'static,
+    T: PopoverDelegate + 'static
+{
+    /// Creates a new `Popover` with a `PopoverDelegate`, which receives show/close/detach events.
+    pub fn with_delegate(content: Content, config: PopoverConfig, delegate: T) -> Self {
+        let view_controller = ViewController::new(content);
+        let objc = make_popover(&view_controller, config);
+
+        let cls = register_popover_delegate_class::<T>(&delegate);
+        let delegate = Box::new(delegate);
+
+        let objc_delegate = unsafe {
+            let mut objc_delegate: Id<Object, Owned> = msg_send_id![cls, new];
+            let ptr: *const T = &*delegate;
+            objc_delegate.set_ivar(POPOVER_DELEGATE_PTR, ptr as usize);
+
+            let objc_delegate: Id<Object, Shared> = Id::into_shared(objc_delegate);
+            let _: () = msg_send![&*objc, setDelegate: &*objc_delegate];
+            objc_delegate
         };
 
-        Popover { objc, view_controller }
+        Popover {
+            objc,
+            view_controller,
+            objc_delegate: Some(objc_delegate),
+            delegate: Some(delegate)
+        }
     }
 }
 
-impl<Content> Popover<Content> {
+impl<Content, T> Popover<Content, T> {
     /// Show a popover relative to a view
     pub fn show_popover<V: Layout>(&self, relative_to: Rect, view: &V, edge: Edge) {
         let rect: CGRect = relative_to.into();
@@ -88,7 +147,7 @@ impl<Content> Popover<Content> {
     }
 
     /// Show the popover relative to the content view of the main window
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     pub fn show_popover_main(&self, rect: Rect, edge: Edge) {
         let window = App::main_window();
         unsafe {
@@ -97,4 +156,99 @@ impl<Content> Popover<Content> {
             let _: () = msg_send![&*self.objc, showRelativeToRect:rect ofView: content_view preferredEdge: edge as u32];
         }
     }
+
+    /// Show the popover anchored to the bounds of any `Layout`-implementing view.
+    pub fn show_relative_to_view<V: Layout>(&self, view: &V, edge: Edge) {
+        // An empty rect tells AppKit to use the bounds of the positioning view.
+        let rect: CGRect = Rect::zero().into();
+
+        view.with_backing_obj_mut(|obj| unsafe {
+            let _: () = msg_send![&*self.objc, showRelativeToRect:rect ofView: &*obj preferredEdge: edge as u32];
+        });
+    }
+
+    /// Show the popover anchored to a `ToolbarItem`.
+    ///
+    /// On macOS 14 and above this uses the native toolbar item anchoring, which also handles items
+    /// that have moved into the overflow menu. On older versions, this falls back to anchoring to
+    /// the item's view - so the item needs to have one (e.g, via `set_button`).
+    #[cfg(appkit)]
+    pub fn show_relative_to_toolbar_item(&self, item: &ToolbarItem) {
+        unsafe {
+            if available!(macos 14) {
+                let _: () = msg_send![&*self.objc, showRelativeToToolbarItem: &*item.objc];
+                return;
+            }
+
+            let view: id = msg_send![&*item.objc, view];
+
+            if view != nil {
+                let rect: CGRect = Rect::zero().into();
+                let edge = Edge::MaxY;
+                let _: () = msg_send![&*self.objc, showRelativeToRect:rect ofView: view preferredEdge: edge as u32];
+            }
+        }
+    }
+
+    /// Sets how (and whether) the system closes this popover in response to user interaction.
+    pub fn set_behaviour(&self, behaviour: PopoverBehaviour) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setBehavior: behaviour as i64];
+        }
+    }
+
+    /// Sets whether this popover animates when showing, closing, or resizing.
+    pub fn set_animates(&self, animates: bool) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAnimates:match animates {
+                true => YES,
+                false => NO
+            }];
+        }
+    }
+
+    /// Sets the size of the content area of this popover.
+    pub fn set_content_size(&self, width: f64, height: f64) {
+        let size = CGSize::new(width, height);
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setContentSize: size];
+        }
+    }
+
+    /// Returns whether this popover is currently being shown.
+    pub fn is_shown(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isShown] })
+    }
+
+    /// Returns whether this popover has been detached into its own window.
+    pub fn is_detached(&self) -> bool {
+        to_bool(unsafe { msg_send![&*self.objc, isDetached] })
+    }
+
+    /// Attempts to close the popover. This consults `PopoverDelegate::should_close()` first, as
+    /// if the user had dismissed it.
+    pub fn perform_close(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, performClose: nil];
+        }
+    }
+
+    /// Forces the popover to close, without consulting the delegate.
+    pub fn close(&self) {
+        unsafe {
+            let _: () = msg_send![&*self.objc, close];
+        }
+    }
+}
+
+impl<Content, T> Drop for Popover<Content, T> {
+    /// If we have a delegate, unhook it so the system doesn't message a dangling pointer.
+    fn drop(&mut self) {
+        if self.delegate.is_some() {
+            unsafe {
+                let _: () = msg_send![&*self.objc, setDelegate: nil];
+            }
+        }
+    }
 }
@@ -0,0 +1,45 @@
+//! Traits used for responding to `Popover` events.
+
+/// A trait that you can implement to have your struct/etc act as an `NSPopoverDelegate`.
+#[allow(unused_variables)]
+pub trait PopoverDelegate {
+    /// Used to cache subclass creations on the Objective-C side.
+    /// You can just set this to be the name of your delegate type. This
+    /// value *must* be unique per-type.
+    const NAME: &'static str;
+
+    /// You should rarely (read: probably never) need to implement this yourself.
+    /// It simply acts as a getter for the associated `NAME` const on this trait.
+    fn subclass_name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    /// Fired immediately before the popover is shown.
+    fn will_show(&self) {}
+
+    /// Fired after the popover has been shown.
+    fn did_show(&self) {}
+
+    /// Fired when the user dismisses the popover (e.g, by clicking outside a transient one), or
+    /// when `Popover::perform_close()` is called. Return `false` to keep it open.
+    ///
+    /// `Popover::close()` always closes the popover, without consulting this.
+    fn should_close(&self) -> bool {
+        true
+    }
+
+    /// Fired immediately before the popover closes.
+    fn will_close(&self) {}
+
+    /// Fired after the popover has closed.
+    fn did_close(&self) {}
+
+    /// Return `true` here to allow the user to drag the popover away from its anchor, detaching
+    /// it into its own window.
+    fn should_detach(&self) -> bool {
+        false
+    }
+
+    /// Fired after the popover has been detached into its own window.
+    fn did_detach(&self) {}
+}
@@ -3,9 +3,11 @@ use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
 use crate::appkit::toolbar::ToolbarItem;
-use crate::foundation::{id, nil, NSString};
+use crate::available;
+use crate::error::Error;
+use crate::foundation::{id, nil, NSString, NO, YES};
 use crate::layout::Layout;
-use crate::utils::{os, Controller};
+use crate::utils::Controller;
 use crate::view::{View, ViewController, ViewDelegate};
 
 /// A SplitViewItem wraps a ViewController, and provides system hooks for operating in a
@@ -45,49 +47,92 @@ where
     /// Creates and returns a new `SplitViewItem`. The returned item is optimized to be a
     /// "sidebar"; that is, a typically left-most view that should be treated as such.
     ///
-    /// On macOS Big Sur, this automatically gets the vibrancy backed sidebar view and will extend
-    /// extend to the top of the window provided the other necessary window flags are set. On macOS
-    /// versions prior to Big Sur, this returns a standard SplitViewItem.
-    pub fn sidebar(view: T) -> Self {
-        #[cfg(target_os = "macos")]
-        {
-            if !os::is_minimum_version(11) {
-                return Self::item(view);
-            }
+    /// This automatically gets the vibrancy backed sidebar view and will extend to the top of the
+    /// window provided the other necessary window flags are set.
+    ///
+    /// Requires macOS 11; returns an error (see `Error::is_unsupported_on_this_os()`) otherwise.
+    /// Use `item()` as a fallback.
+    pub fn sidebar(view: T) -> Result<Self, Error> {
+        if !available!(macos 11) {
+            return Err(Error::unsupported_on_this_os("SplitViewItem::sidebar", "macOS 11"));
+        }
 
-            let view_controller = ViewController::new(view);
+        let view_controller = ViewController::new(view);
 
-            SplitViewItem {
-                objc: unsafe {
-                    msg_send_id![class!(NSSplitViewItem),
-                        sidebarWithViewController:&*view_controller.objc
-                    ]
-                },
+        Ok(SplitViewItem {
+            objc: unsafe {
+                msg_send_id![class!(NSSplitViewItem),
+                    sidebarWithViewController:&*view_controller.objc
+                ]
+            },
 
-                view_controller
-            }
+            view_controller
+        })
+    }
+
+    /// Sets whether this item's view extends into the titlebar area of the window, giving the
+    /// full-height sidebar look found in apps like Mail and Finder. This requires the window to
+    /// use `WindowStyle::FullSizeContentView` (which `WindowConfig::default()` includes).
+    ///
+    /// This API was introduced in macOS 11.0 (Big Sur); on anything prior, this returns an error
+    /// (see `Error::is_unsupported_on_this_os()`).
+    #[cfg(appkit)]
+    pub fn set_allows_full_height_layout(&self, allows: bool) -> Result<(), Error> {
+        if !available!(macos 11) {
+            return Err(Error::unsupported_on_this_os(
+                "SplitViewItem::set_allows_full_height_layout",
+                "macOS 11"
+            ));
         }
 
-        // Non-macOS platforms default to the old-school API, where everything is just a generic
-        // item.
-        #[cfg(not(target_os = "macos"))]
-        Self::item(view)
+        unsafe {
+            let _: () = msg_send![&*self.objc, setAllowsFullHeightLayout:match allows {
+                true => YES,
+                false => NO
+            }];
+        }
+
+        Ok(())
     }
 
-    /// Sets the titlebar separator style for this `SplitView`.
+    /// Sets the titlebar separator style for this `SplitView`, from a raw
+    /// `NSTitlebarSeparatorStyle` value.
     ///
     /// You'd use this if, say, you wanted a border under one part of the `SplitViewController` but
     /// not the other. This API was introduced in macOS 11.0 (Big Sur) and is a noop on anything
-    /// prior.
-    #[cfg(feature = "appkit")]
+    /// prior; `set_titlebar_separator()` returns an error there instead.
+    #[cfg(appkit)]
+    #[deprecated(note = "Use `set_titlebar_separator` with a `TitlebarSeparatorStyle` instead.")]
     pub fn set_titlebar_separator_style(&self, style: crate::foundation::NSInteger) {
-        #[cfg(target_os = "macos")]
-        if os::is_minimum_version(11) {
+        if available!(macos 11) {
             unsafe {
                 let _: () = msg_send![&*self.objc, setTitlebarSeparatorStyle: style];
             }
         }
     }
+
+    /// Sets the titlebar separator style for this `SplitView`.
+    ///
+    /// You'd use this if, say, you wanted a border under one part of the `SplitViewController` but
+    /// not the other. This API was introduced in macOS 11.0 (Big Sur); on anything prior, this
+    /// returns an error (see `Error::is_unsupported_on_this_os()`).
+    #[cfg(appkit)]
+    pub fn set_titlebar_separator(&self, style: crate::appkit::window::TitlebarSeparatorStyle) -> Result<(), Error> {
+        if !available!(macos 11) {
+            return Err(Error::unsupported_on_this_os(
+                "SplitViewItem::set_titlebar_separator",
+                "macOS 11"
+            ));
+        }
+
+        let style: crate::foundation::NSInteger = style.into();
+
+        unsafe {
+            let _: () = msg_send![&*self.objc, setTitlebarSeparatorStyle: style];
+        }
+
+        Ok(())
+    }
 }
 
 /// A SplitViewController manages two or more view controllers in a split-pane view.
@@ -130,9 +175,13 @@ where
     Content: ViewDelegate + 'static,
     Details: ViewDelegate + 'static
 {
-    /// Creates and returns a new `SplitViewController`.
+    /// Creates and returns a new `SplitViewController`. The sidebar uses the native sidebar style
+    /// where it's available (macOS 11 and later), and is a standard item otherwise.
     pub fn new(sidebar: Sidebar, content: Content, details: Option<Details>) -> Self {
-        let sidebar = SplitViewItem::sidebar(sidebar);
+        let sidebar = match available!(macos 11) {
+            true => SplitViewItem::sidebar(sidebar).expect("macOS 11 is available"),
+            false => SplitViewItem::item(sidebar)
+        };
         let content = SplitViewItem::item(content);
 
         let details = match details {
@@ -1,8 +1,21 @@
 //! Various traits used for Views.
 
-#[cfg(feature = "appkit")]
+#[cfg(appkit)]
 use crate::dragdrop::{DragInfo, DragOperation};
 
+#[cfg(appkit)]
+use crate::appkit::Event;
+
+#[cfg(appkit)]
+use crate::image::Image;
+
+#[cfg(appkit)]
+use crate::color::Color;
+
+#[cfg(uikit)]
+use crate::uikit::TraitCollection;
+
+use crate::geometry::Rect;
 use crate::view::View;
 
 /// This trait can be used for implementing custom View behavior. You implement this trait on your
@@ -41,36 +54,218 @@ pub trait ViewDelegate {
     /// Called when this has been removed from the view heirarchy.
     fn did_disappear(&self, animated: bool) {}
 
+    /// Called when the view lays out its subviews (`layout` on AppKit, `layoutSubviews` on
+    /// UIKit), after the system has done its own pass. This is the place to reposition any
+    /// manually-managed subviews or `CALayer` content.
+    fn layout(&self) {}
+
+    /// Called when the frame of this view has changed, either in size or position. The frame is
+    /// in the coordinate system of the superview.
+    fn did_change_frame(&self, frame: Rect) {}
+
+    /// Called when the bounds of this view have changed.
+    fn did_change_bounds(&self, bounds: Rect) {}
+
+    /// Whether this view can be focused by the focus engine (tvOS remote navigation, or keyboard
+    /// navigation on iPadOS). Defaults to `false`.
+    #[cfg(uikit)]
+    fn can_become_focused(&self) -> bool {
+        false
+    }
+
+    /// Called when this view gains (`true`) or loses (`false`) focus.
+    #[cfg(uikit)]
+    fn did_update_focus(&self, is_focused: bool) {}
+
+    /// Called when the view's trait collection changes - its size classes (e.g, on rotation or
+    /// iPad multitasking), display scale, or light/dark mode. `previous` is `None` the first time
+    /// the view gets traits. Compare the two to decide whether to switch constraint sets.
+    #[cfg(uikit)]
+    fn trait_collection_did_change(&self, traits: TraitCollection, previous: Option<TraitCollection>) {}
+
+    /// Called when the view's backing properties change - most notably its backing scale, e.g
+    /// when its window moves between a Retina and a non-Retina display. If you draw into layers
+    /// or bitmaps yourself, redraw them at the new `scale` here to keep them crisp.
+    #[cfg(appkit)]
+    fn did_change_backing_properties(&self, scale: f64) {}
+
+    /// Called when a live resize (e.g, the user dragging the window edge) is about to start. You
+    /// can use this to switch to a cheaper drawing path for the duration of the resize.
+    #[cfg(appkit)]
+    fn will_start_live_resize(&self) {}
+
+    /// Called after a live resize has ended.
+    #[cfg(appkit)]
+    fn did_end_live_resize(&self) {}
+
     /// Invoked when the dragged image enters destination bounds or frame; returns dragging
     /// operation to perform.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn dragging_entered(&self, info: DragInfo) -> DragOperation {
         DragOperation::None
     }
 
     /// Invoked when the image is released, allowing the receiver to agree to or refuse
     /// drag operation.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn prepare_for_drag_operation(&self, info: DragInfo) -> bool {
         false
     }
 
     /// Invoked after the released image has been removed from the screen, signaling the
     /// receiver to import the pasteboard data.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn perform_drag_operation(&self, info: DragInfo) -> bool {
         false
     }
 
     /// Invoked when the dragging operation is complete, signaling the receiver to perform
     /// any necessary clean-up.
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn conclude_drag_operation(&self, info: DragInfo) {}
 
     /// Invoked when the dragged image exits the destination’s bounds rectangle (in the case
     /// of a view) or its frame rectangle (in the case of a window object).
-    #[cfg(feature = "appkit")]
+    #[cfg(appkit)]
     fn dragging_exited(&self, info: DragInfo) {}
 
+    /// Called as the pressure on a Force Touch trackpad changes while clicking in this view. Use
+    /// `Event::stage()` and `Event::stage_transition()` to implement force click previews.
+    /// Return `true` if you handled it, or `false` to pass it up the responder chain.
+    ///
+    /// To have the system recognize force clicks as such, make sure the default "Force Click and
+    /// haptic feedback" setting is left on.
+    #[cfg(appkit)]
+    fn pressure_changed(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called for the Look Up gesture (a force click, or three finger tap) in this view. Return
+    /// `true` if you handled it, or `false` to have the system look up the content under the
+    /// pointer.
+    #[cfg(appkit)]
+    fn quick_look(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called for a (three finger, by default) swipe in this view; see `Event::delta_x()` and
+    /// `Event::delta_y()` for the direction. Return `true` if you handled it.
+    #[cfg(appkit)]
+    fn swipe(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called during a pinch gesture in this view; see `Event::magnification()`. Return `true`
+    /// if you handled it.
+    #[cfg(appkit)]
+    fn magnify(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called for a two finger double tap in this view. Return `true` if you handled it.
+    #[cfg(appkit)]
+    fn smart_magnify(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called during a rotation gesture in this view; see `Event::rotation()`. Return `true` if
+    /// you handled it.
+    #[cfg(appkit)]
+    fn rotate(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called when the mouse (or a tablet stylus) goes down in this view. Use `Event::typed()` to
+    /// get the location, and any stylus pressure and tilt. Return `true` if you handled it, or
+    /// `false` to pass it up the responder chain.
+    #[cfg(appkit)]
+    fn mouse_down(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called as the mouse (or a tablet stylus) is dragged, after going down in this view. Return
+    /// `true` if you handled it.
+    #[cfg(appkit)]
+    fn mouse_dragged(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called when the mouse (or a tablet stylus) goes up, after going down in this view. Return
+    /// `true` if you handled it.
+    #[cfg(appkit)]
+    fn mouse_up(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called for tablet events that don't come as part of a mouse event (e.g, the stylus tilting
+    /// while hovering); see `Event::tablet_point()`. Return `true` if you handled it.
+    #[cfg(appkit)]
+    fn tablet_point(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called when a tablet stylus comes into or leaves range; see `Event::tablet_proximity()`.
+    /// Return `true` if you handled it.
+    #[cfg(appkit)]
+    fn tablet_proximity(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called when fingers touch down on the trackpad, once the view has opted in with
+    /// `View::set_accepts_touches()`; see `Event::touches()`. Return `true` if you handled it.
+    #[cfg(appkit)]
+    fn touches_began(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called when fingers move on the trackpad. Return `true` if you handled it.
+    #[cfg(appkit)]
+    fn touches_moved(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called when fingers lift off the trackpad. Return `true` if you handled it.
+    #[cfg(appkit)]
+    fn touches_ended(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Called when the system stops tracking touches (e.g, because a gesture took over); drop
+    /// any state you were keeping for them. Return `true` if you handled it.
+    #[cfg(appkit)]
+    fn touches_cancelled(&self, event: Event) -> bool {
+        false
+    }
+
+    /// Return `true` to accept photos and document scans from Continuity Camera (i.e, an iPhone
+    /// or iPad signed in to the same account). The view then gets the "Import from iPhone or iPad"
+    /// items in its context menu - and in your app's menus, via `MenuItem::import_from_device()` -
+    /// while it's the first responder (or in the responder chain).
+    #[cfg(appkit)]
+    fn accepts_continuity_camera(&self) -> bool {
+        false
+    }
+
+    /// Called with the photo or scan the user took on their device via Continuity Camera.
+    #[cfg(appkit)]
+    fn did_receive_continuity_camera_image(&self, image: Image) {}
+
+    /// Called when the user picks something in the Fonts window (or a Font menu item, like Bold)
+    /// while this view is in the responder chain. Use `FontPanel::convert()` to get the new
+    /// version of each font in your selection. Return `true` if you handled it, or `false` to
+    /// pass it up the responder chain.
+    #[cfg(appkit)]
+    fn change_font(&self) -> bool {
+        false
+    }
+
+    /// Called when the user picks a color in the Colors window while this view is in the
+    /// responder chain. Return `true` if you handled it, or `false` to pass it up the responder
+    /// chain.
+    #[cfg(appkit)]
+    fn change_color(&self, color: Color) -> bool {
+        false
+    }
+
     //fn perform_key_equivalent(&self, event: Event) -> bool { false }
 }
@@ -1,18 +1,123 @@
+use core_graphics::geometry::CGRect;
 use objc::declare::ClassDecl;
 use objc::rc::{Id, Owned};
-use objc::runtime::{Class, Object, Sel, BOOL};
-use objc::{class, sel};
 
-use crate::foundation::load_or_register_class;
-use crate::foundation::{id, NSUInteger, NO, YES};
-use crate::utils::load;
+use objc::runtime::{Bool, Class, Object, Sel, BOOL};
+use objc::{class, msg_send, sel};
+
+use crate::foundation::{id, nil, NSUInteger, NO, YES};
+use crate::foundation::{load_or_register_class, StaticClass};
+use crate::uikit::TraitCollection;
+use crate::utils::{load_weak, release_weak};
 use crate::view::{ViewDelegate, VIEW_DELEGATE_PTR};
 
+/// Returns the delegate for this view, provided it's been set and is still alive. Some of the
+/// geometry methods we override can fire during initialization, before the delegate pointer has
+/// been stored.
+fn delegate<T: ViewDelegate>(this: &Object) -> Option<&T> {
+    load_weak::<T>(this, VIEW_DELEGATE_PTR)
+}
+
+/// Called when the view is laying out its subviews.
+extern "C" fn layout_subviews<T: ViewDelegate>(this: &Object, _: Sel) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(UIView)), layoutSubviews];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        view.layout();
+    }
+}
+
+/// Called when the frame is set.
+extern "C" fn set_frame<T: ViewDelegate>(this: &Object, _: Sel, frame: CGRect) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(UIView)), setFrame: frame];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        let frame: CGRect = unsafe { msg_send![this, frame] };
+        view.did_change_frame(frame.into());
+    }
+}
+
+/// Called when the bounds are set.
+extern "C" fn set_bounds<T: ViewDelegate>(this: &Object, _: Sel, bounds: CGRect) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(UIView)), setBounds: bounds];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        let bounds: CGRect = unsafe { msg_send![this, bounds] };
+        view.did_change_bounds(bounds.into());
+    }
+}
+
+/// Called by the focus engine to check whether this view can take focus.
+extern "C" fn can_become_focused<T: ViewDelegate>(this: &Object, _: Sel) -> Bool {
+    match delegate::<T>(this) {
+        Some(view) => Bool::new(view.can_become_focused()),
+        None => Bool::NO
+    }
+}
+
+/// Called when focus moves; we only forward this if the update involves this view.
+extern "C" fn did_update_focus<T: ViewDelegate>(this: &Object, _: Sel, context: id, coordinator: id) {
+    unsafe {
+        let _: () = msg_send![
+            super(this, class!(UIView)),
+            didUpdateFocusInContext: context,
+            withAnimationCoordinator: coordinator
+        ];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        let this_ptr = this as *const Object as id;
+        let next: id = unsafe { msg_send![context, nextFocusedView] };
+        let previous: id = unsafe { msg_send![context, previouslyFocusedView] };
+
+        if next == this_ptr {
+            view.did_update_focus(true);
+        } else if previous == this_ptr {
+            view.did_update_focus(false);
+        }
+    }
+}
+
+/// Called when the trait collection (size classes, display scale, light/dark mode) changes.
+extern "C" fn trait_collection_did_change<T: ViewDelegate>(this: &Object, _: Sel, previous: id) {
+    unsafe {
+        let _: () = msg_send![super(this, class!(UIView)), traitCollectionDidChange: previous];
+    }
+
+    if let Some(view) = delegate::<T>(this) {
+        let traits: id = unsafe { msg_send![this, traitCollection] };
+
+        let previous = match previous == nil {
+            true => None,
+            false => Some(TraitCollection::with(previous))
+        };
+
+        view.trait_collection_did_change(TraitCollection::with(traits), previous);
+    }
+}
+
 /// Injects an `NSView` subclass. This is used for the default views that don't use delegates - we
 /// have separate classes here since we don't want to waste cycles on methods that will never be
 /// used if there's no delegates.
 pub(crate) fn register_view_class() -> &'static Class {
-    load_or_register_class("UIView", "RSTView", |decl| unsafe {})
+    static CLASS: StaticClass = StaticClass::new();
+
+    CLASS.get_or_register(|| load_or_register_class("UIView", "RSTView", |decl| unsafe {}))
+}
+
+/// Releases the weak reference to the delegate, before the view is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    release_weak(this, VIEW_DELEGATE_PTR);
+
+    unsafe {
+        let _: () = msg_send![super(this, class!(UIView)), dealloc];
+    }
 }
 
 /// Injects a `UIView` subclass, with some callback and pointer ivars for what we
@@ -20,5 +125,21 @@ pub(crate) fn register_view_class() -> &'static Class {
 pub(crate) fn register_view_class_with_delegate<T: ViewDelegate>(instance: &T) -> &'static Class {
     load_or_register_class("UIView", instance.subclass_name(), |decl| unsafe {
         decl.add_ivar::<usize>(VIEW_DELEGATE_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
+
+        decl.add_method(sel!(layoutSubviews), layout_subviews::<T> as extern "C" fn(_, _));
+        decl.add_method(sel!(setFrame:), set_frame::<T> as extern "C" fn(_, _, _));
+        decl.add_method(sel!(setBounds:), set_bounds::<T> as extern "C" fn(_, _, _));
+
+        decl.add_method(sel!(canBecomeFocused), can_become_focused::<T> as extern "C" fn(_, _) -> _);
+        decl.add_method(
+            sel!(didUpdateFocusInContext:withAnimationCoordinator:),
+            did_update_focus::<T> as extern "C" fn(_, _, _, _)
+        );
+
+        decl.add_method(
+            sel!(traitCollectionDidChange:),
+            trait_collection_did_change::<T> as extern "C" fn(_, _, _)
+        );
     })
 }
@@ -12,11 +12,12 @@ use objc::declare::ClassDecl;
 use objc::runtime::{Bool, Class, Object, Sel};
 use objc::{class, msg_send, msg_send_id, sel};
 
+use crate::error::Error;
 use crate::foundation::{id, load_or_register_class, nil, NSArray, NSInteger, NSString};
 use crate::webview::actions::{NavigationAction, NavigationResponse};
 use crate::webview::{mimetype::MimeType, WebViewDelegate, WEBVIEW_DELEGATE_PTR}; //, OpenPanelParameters};
                                                                                  //use crate::webview::enums::{NavigationPolicy, NavigationResponsePolicy};
-use crate::utils::load;
+use crate::utils::{load_weak, release_weak};
 
 /// Called when an `alert()` from the underlying `WKWebView` is fired. Will call over to your
 /// `WebViewController`, where you should handle the event.
@@ -45,7 +46,10 @@ extern "C" fn alert<T: WebViewDelegate>(_: &Object, _: Sel, _: id, _: id, _: id,
 
 /// Fires when a message has been passed from the underlying `WKWebView`.
 extern "C" fn on_message<T: WebViewDelegate>(this: &Object, _: Sel, _: id, script_message: id) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = match load_weak::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return
+    };
 
     unsafe {
         let name = NSString::from_id(msg_send_id![script_message, name]);
@@ -56,7 +60,10 @@ extern "C" fn on_message<T: WebViewDelegate>(this: &Object, _: Sel, _: id, scrip
 
 /// Fires when a custom protocol URI is requested from the underlying `WKWebView`.
 extern "C" fn start_url_scheme_task<T: WebViewDelegate>(this: &Object, _: Sel, _webview: id, task: id) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = match load_weak::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return
+    };
 
     unsafe {
         let request: id = msg_send![task, request];
@@ -94,35 +101,69 @@ extern "C" fn stop_url_scheme_task<T: WebViewDelegate>(_: &Object, _: Sel, _webv
 
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
 extern "C" fn decide_policy_for_action<T: WebViewDelegate>(this: &Object, _: Sel, _: id, action: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let handler = handler as *const Block<(NSInteger,), ()>;
+
+    // WebKit requires the handler to be called; if the delegate is gone, cancel the navigation.
+    let delegate = match load_weak::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return unsafe { (*handler).call((0,)) }
+    };
 
     let action = NavigationAction::new(action);
 
     delegate.policy_for_navigation_action(action, |policy| unsafe {
-        let handler = handler as *const Block<(NSInteger,), ()>;
         (*handler).call((policy.into(),));
     });
 }
 
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
 extern "C" fn decide_policy_for_response<T: WebViewDelegate>(this: &Object, _: Sel, _: id, response: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let handler = handler as *const Block<(NSInteger,), ()>;
+
+    // WebKit requires the handler to be called; if the delegate is gone, cancel the navigation.
+    let delegate = match load_weak::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return unsafe { (*handler).call((0,)) }
+    };
 
     let response = NavigationResponse::new(response);
 
     delegate.policy_for_navigation_response(response, |policy| unsafe {
-        let handler = handler as *const Block<(NSInteger,), ()>;
         (*handler).call((policy.into(),));
     });
 }
 
+/// Fires when a navigation fails before any content has loaded.
+extern "C" fn did_fail_provisional_navigation<T: WebViewDelegate>(this: &Object, _: Sel, _: id, _: id, error: id) {
+    if let Some(delegate) = load_weak::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        delegate.did_fail_provisional_navigation(Error::new(error));
+    }
+}
+
+/// Fires when a navigation has committed, i.e the new page is now the current one.
+extern "C" fn did_commit_navigation<T: WebViewDelegate>(this: &Object, _: Sel, _: id, _: id) {
+    if let Some(delegate) = load_weak::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        delegate.did_commit_navigation();
+    }
+}
+
+/// Fires when a navigation fails after content has started loading.
+extern "C" fn did_fail_navigation<T: WebViewDelegate>(this: &Object, _: Sel, _: id, _: id, error: id) {
+    if let Some(delegate) = load_weak::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        delegate.did_fail_navigation(Error::new(error));
+    }
+}
+
 /// Fires when deciding a navigation policy - i.e, should something be allowed or not.
 extern "C" fn run_open_panel<T: WebViewDelegate>(this: &Object, _: Sel, _: id, params: id, _: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let handler = handler as *const Block<(id,), ()>;
 
-    delegate.run_open_panel(params.into(), move |urls| unsafe {
-        let handler = handler as *const Block<(id,), ()>;
+    let delegate = match load_weak::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return unsafe { (*handler).call((nil,)) }
+    };
 
+    delegate.run_open_panel(params.into(), move |urls| unsafe {
         match urls {
             Some(u) => {
                 let mut nsurls: NSArray = u
@@ -149,7 +190,10 @@ extern "C" fn run_open_panel<T: WebViewDelegate>(this: &Object, _: Sel, _: id, p
 /// API.
 #[cfg(feature = "webview-downloading-macos")]
 extern "C" fn handle_download<T: WebViewDelegate>(this: &Object, _: Sel, download: id, suggested_filename: id, handler: usize) {
-    let delegate = load::<T>(this, WEBVIEW_DELEGATE_PTR);
+    let delegate = match load_weak::<T>(this, WEBVIEW_DELEGATE_PTR) {
+        Some(delegate) => delegate,
+        None => return unsafe { msg_send![download, cancel] }
+    };
 
     let handler = handler as *const Block<(objc::runtime::Bool, id), ()>;
     let filename = NSString::retain(suggested_filename);
@@ -179,12 +223,22 @@ pub fn register_webview_class() -> &'static Class {
     })
 }
 
+/// Releases the weak reference to the `WebViewDelegate`, before this object is deallocated.
+extern "C" fn dealloc(this: &mut Object, _: Sel) {
+    release_weak(this, WEBVIEW_DELEGATE_PTR);
+
+    unsafe {
+        let _: () = msg_send![super(this, class!(NSObject)), dealloc];
+    }
+}
+
 /// Registers an `NSViewController` that we effectively turn into a `WebViewController`. Acts as
 /// both a subclass of `NSViewController` and a delegate of the held `WKWebView` (for the various
 /// varieties of delegates needed there).
 pub fn register_webview_delegate_class<T: WebViewDelegate>(instance: &T) -> &'static Class {
     load_or_register_class("NSObject", instance.subclass_name(), |decl| unsafe {
         decl.add_ivar::<usize>(WEBVIEW_DELEGATE_PTR);
+        decl.add_method(sel!(dealloc), dealloc as extern "C" fn(_, _));
 
         // WKNavigationDelegate
         decl.add_method(
@@ -195,6 +249,18 @@ pub fn register_webview_delegate_class<T: WebViewDelegate>(instance: &T) -> &'st
             sel!(webView:decidePolicyForNavigationResponse:decisionHandler:),
             decide_policy_for_response::<T> as extern "C" fn(_, _, _, _, _)
         );
+        decl.add_method(
+            sel!(webView:didFailProvisionalNavigation:withError:),
+            did_fail_provisional_navigation::<T> as extern "C" fn(_, _, _, _, _)
+        );
+        decl.add_method(
+            sel!(webView:didCommitNavigation:),
+            did_commit_navigation::<T> as extern "C" fn(_, _, _, _)
+        );
+        decl.add_method(
+            sel!(webView:didFailNavigation:withError:),
+            did_fail_navigation::<T> as extern "C" fn(_, _, _, _, _)
+        );
 
         // WKScriptMessageHandler
         decl.add_method(
@@ -13,22 +13,29 @@
 //! Apple does not ship `WKWebView` on tvOS, and as a result this control is not provided on that
 //! platform.
 
+use block::ConcreteBlock;
 use core_graphics::geometry::CGRect;
 
 use objc::rc::{Id, Owned, Shared};
 use objc::runtime::Object;
 use objc::{class, msg_send, msg_send_id, sel};
 
-use crate::foundation::{id, nil, NSString, NO, YES};
+use crate::available;
+use crate::error::Error;
+use crate::foundation::{id, nil, to_bool, NSData, NSString, NO, YES};
 use crate::geometry::Rect;
 use crate::layer::Layer;
 use crate::layout::Layout;
 use crate::objc_access::ObjcAccess;
 use crate::utils::properties::ObjcProperty;
+use crate::utils::DelegateRef;
 
 #[cfg(feature = "autolayout")]
 use crate::layout::{LayoutAnchorDimension, LayoutAnchorX, LayoutAnchorY};
 
+#[cfg(appkit)]
+use crate::appkit::printing::{PrintInfo, PrintOperation};
+
 mod actions;
 pub use actions::*;
 
@@ -43,6 +50,10 @@ use class::{register_webview_class, register_webview_delegate_class};
 //pub(crate) mod process_pool;
 
 mod mimetype;
+
+mod pdf;
+pub use pdf::PdfConfiguration;
+
 mod traits;
 pub use traits::WebViewDelegate;
 
@@ -79,7 +90,7 @@ fn allocate_webview(mut config: WebViewConfig, objc_delegate: Option<&Object>) -
         let webview_alloc: id = msg_send![register_webview_class(), alloc];
         let webview: id = msg_send![webview_alloc, initWithFrame:zero configuration: &*config.objc];
 
-        #[cfg(feature = "appkit")]
+        #[cfg(appkit)]
         let _: () = msg_send![webview, setWantsLayer: YES];
 
         #[cfg(feature = "autolayout")]
@@ -110,6 +121,9 @@ pub struct WebView<T = ()> {
     /// we do so.
     pub objc_delegate: Option<Id<Object, Shared>>,
 
+    /// Keeps the delegate reachable from the Objective-C side; callbacks stop once this drops.
+    pub(crate) delegate_ref: Option<DelegateRef>,
+
     /// A pointer to the delegate for this view.
     pub delegate: Option<Box<T>>,
 
@@ -170,13 +184,14 @@ impl WebView {
         unsafe {
             let _: () = msg_send![view, setTranslatesAutoresizingMaskIntoConstraints: NO];
 
-            #[cfg(feature = "appkit")]
+            #[cfg(appkit)]
             let _: () = msg_send![view, setWantsLayer: YES];
         }
 
         WebView {
             is_handle: false,
             delegate: None,
+            delegate_ref: None,
             objc_delegate: None,
 
             #[cfg(feature = "autolayout")]
@@ -232,18 +247,15 @@ where
         let delegate_class = register_webview_delegate_class(&delegate);
         let mut delegate = Box::new(delegate);
 
-        let objc_delegate = unsafe {
-            let mut objc_delegate: Id<Object, Owned> = msg_send_id![delegate_class, new];
-            let ptr: *const T = &*delegate;
-            objc_delegate.set_ivar(WEBVIEW_DELEGATE_PTR, ptr as usize);
-            objc_delegate
-        };
+        let mut objc_delegate: Id<Object, Owned> = unsafe { msg_send_id![delegate_class, new] };
+        let delegate_ref = DelegateRef::attach(&mut *objc_delegate, WEBVIEW_DELEGATE_PTR, &*delegate);
 
         let view = allocate_webview(config, Some(&objc_delegate));
         let mut view = WebView::init(view);
 
         &delegate.did_load(view.clone_as_handle());
         view.delegate = Some(delegate);
+        view.delegate_ref = Some(delegate_ref);
         view
     }
 }
@@ -256,6 +268,7 @@ impl<T> WebView<T> {
     pub(crate) fn clone_as_handle(&self) -> WebView {
         WebView {
             delegate: None,
+            delegate_ref: None,
             is_handle: true,
             layer: self.layer.clone(),
             objc: self.objc.clone(),
@@ -330,6 +343,71 @@ impl<T> WebView<T> {
             let _: () = msg_send![&*obj, goForward];
         });
     }
+
+    /// Returns whether there's a page to go back to.
+    pub fn can_go_back(&self) -> bool {
+        self.objc.get(|obj| unsafe { to_bool(msg_send![obj, canGoBack]) })
+    }
+
+    /// Returns whether there's a page to go forward to.
+    pub fn can_go_forward(&self) -> bool {
+        self.objc.get(|obj| unsafe { to_bool(msg_send![obj, canGoForward]) })
+    }
+
+    /// Returns a print operation for the page that's loaded, laid out per `info`. Run it with
+    /// `PrintOperation::run_modal_for_window()`; WebKit lays the page out asynchronously, so the
+    /// blocking `run()` isn't supported.
+    ///
+    /// Requires macOS 11; returns an error (see `Error::is_unsupported_on_this_os()`) otherwise.
+    #[cfg(appkit)]
+    pub fn print(&self, info: &PrintInfo) -> Result<PrintOperation, Error> {
+        if !available!(macos 11) {
+            return Err(Error::unsupported_on_this_os("WebView::print", "macOS 11"));
+        }
+
+        let objc = self.objc.get(|obj| unsafe {
+            let operation: Id<Object, Shared> = msg_send_id![obj, printOperationWithPrintInfo: &*info.0];
+
+            // Without a frame, the operation's view prints blank pages.
+            let bounds: CGRect = msg_send![obj, bounds];
+            let view: id = msg_send![&*operation, view];
+            let _: () = msg_send![view, setFrame: bounds];
+
+            operation
+        });
+
+        Ok(PrintOperation::with_objc(objc))
+    }
+
+    /// Renders the page that's loaded (or the part of it in `config.rect`) to a PDF, calling
+    /// `callback` with the PDF data - or the error, if it failed - on the main thread.
+    ///
+    /// Requires macOS 11 or iOS 14; returns an error (see `Error::is_unsupported_on_this_os()`)
+    /// otherwise.
+    pub fn create_pdf<F>(&self, config: PdfConfiguration, callback: F) -> Result<(), Error>
+    where
+        F: Fn(Result<NSData, Error>) + Send + Sync + 'static
+    {
+        if !available!(macos 11, ios 14) {
+            return Err(Error::unsupported_on_this_os("WebView::create_pdf", "macOS 11 or iOS 14"));
+        }
+
+        let block = ConcreteBlock::new(move |data: id, error: id| {
+            callback(match data == nil {
+                true => Err(Error::new(error)),
+                false => Ok(NSData::retain(data))
+            });
+        });
+        let block = block.copy();
+
+        let config = config.to_objc();
+
+        self.objc.with_mut(|obj| unsafe {
+            let _: () = msg_send![obj, createPDFWithConfiguration: &*config, completionHandler: &*block];
+        });
+
+        Ok(())
+    }
 }
 
 impl<T> ObjcAccess for WebView<T> {
@@ -0,0 +1,32 @@
+//! Options for `WebView::create_pdf()`.
+
+use core_graphics::geometry::CGRect;
+use objc::rc::{Id, Owned};
+use objc::runtime::Object;
+use objc::{class, msg_send, msg_send_id, sel};
+
+use crate::geometry::Rect;
+
+/// Configures `WebView::create_pdf()`, wrapping `WKPDFConfiguration`.
+#[derive(Clone, Debug, Default)]
+pub struct PdfConfiguration {
+    /// The part of the page to capture, in the web view's coordinates. Leave this as `None` to
+    /// capture the whole page, including what's scrolled out of view.
+    pub rect: Option<Rect>
+}
+
+impl PdfConfiguration {
+    /// Builds the `WKPDFConfiguration` for these options.
+    pub(crate) fn to_objc(&self) -> Id<Object, Owned> {
+        unsafe {
+            let config: Id<Object, Owned> = msg_send_id![class!(WKPDFConfiguration), new];
+
+            if let Some(rect) = self.rect {
+                let rect: CGRect = rect.into();
+                let _: () = msg_send![&*config, setRect: rect];
+            }
+
+            config
+        }
+    }
+}
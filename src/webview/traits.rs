@@ -2,6 +2,7 @@
 //! `WKWebView`. It allows you to do things such as handle opening a file (for uploads or
 //! in-browser-processing), handling navigation actions or JS message callbacks, and so on.
 
+use crate::error::Error;
 use crate::webview::actions::{NavigationAction, NavigationResponse, OpenPanelParameters};
 use crate::webview::enums::{NavigationPolicy, NavigationResponsePolicy};
 use crate::webview::WebView;
@@ -61,6 +62,17 @@ pub trait WebViewDelegate {
         handler(NavigationResponsePolicy::Allow);
     }
 
+    /// Called when a navigation has started receiving content for the main frame, and is now
+    /// the current page - a good time to update back/forward buttons, the URL bar and so on.
+    fn did_commit_navigation(&self) {}
+
+    /// Called when a navigation fails before the page has started loading (e.g, the host could not
+    /// be resolved, or there's no network connection).
+    fn did_fail_provisional_navigation(&self, _error: Error) {}
+
+    /// Called when a navigation fails after the page has started loading.
+    fn did_fail_navigation(&self, _error: Error) {}
+
     /// Given a callback handler and some open panel parameters (e.g, if the user is clicking an
     /// upload field that pre-specifies supported options), you should create a `FileSelectPanel`
     /// and thread the callbacks accordingly.
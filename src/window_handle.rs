@@ -0,0 +1,120 @@
+//! Implements the `raw-window-handle` traits for `Window` and `View`, so that cacao types can be
+//! handed to crates that render into a native surface (`wgpu`, `glutin`, `softbuffer`, and so on).
+//!
+//! Going the other way - wrapping a window or view created by e.g `winit` - is done with
+//! `Window::existing()` and `View::existing()`.
+
+use std::ffi::c_void;
+
+use objc::runtime::Object;
+use objc::{msg_send, sel};
+
+use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle, RawDisplayHandle, RawWindowHandle};
+
+#[cfg(appkit)]
+use raw_window_handle::{AppKitDisplayHandle, AppKitWindowHandle};
+
+#[cfg(uikit)]
+use raw_window_handle::{UiKitDisplayHandle, UiKitWindowHandle};
+
+use crate::foundation::id;
+use crate::objc_access::ObjcAccess;
+use crate::view::View;
+
+#[cfg(appkit)]
+use crate::appkit::window::Window;
+
+#[cfg(uikit)]
+use crate::uikit::Window;
+
+/// Erases an Objective-C pointer into what `raw-window-handle` expects.
+fn to_ptr(obj: id) -> *mut c_void {
+    obj as *mut c_void
+}
+
+#[cfg(appkit)]
+unsafe impl<T> HasRawWindowHandle for Window<T> {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        let mut handle = AppKitWindowHandle::empty();
+        handle.ns_window = to_ptr(&*self.objc as *const Object as id);
+        handle.ns_view = to_ptr(unsafe { msg_send![&*self.objc, contentView] });
+        RawWindowHandle::AppKit(handle)
+    }
+}
+
+#[cfg(appkit)]
+unsafe impl<T> HasRawDisplayHandle for Window<T> {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        RawDisplayHandle::AppKit(AppKitDisplayHandle::empty())
+    }
+}
+
+/// The window pointer is null if the view isn't currently in a window.
+#[cfg(appkit)]
+unsafe impl<T> HasRawWindowHandle for View<T> {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        let mut handle = AppKitWindowHandle::empty();
+        let (window, view) = self.get_from_backing_obj(|obj| {
+            let window: id = unsafe { msg_send![obj, window] };
+            (to_ptr(window), to_ptr(obj as *const Object as id))
+        });
+
+        handle.ns_window = window;
+        handle.ns_view = view;
+        RawWindowHandle::AppKit(handle)
+    }
+}
+
+#[cfg(appkit)]
+unsafe impl<T> HasRawDisplayHandle for View<T> {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        RawDisplayHandle::AppKit(AppKitDisplayHandle::empty())
+    }
+}
+
+/// The view and view controller are those of the window's root view controller, if it has one.
+#[cfg(uikit)]
+unsafe impl HasRawWindowHandle for Window {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        let mut handle = UiKitWindowHandle::empty();
+        handle.ui_window = to_ptr(&*self.0 as *const Object as id);
+
+        unsafe {
+            let controller: id = msg_send![&*self.0, rootViewController];
+            handle.ui_view_controller = to_ptr(controller);
+            handle.ui_view = to_ptr(msg_send![controller, view]);
+        }
+
+        RawWindowHandle::UiKit(handle)
+    }
+}
+
+#[cfg(uikit)]
+unsafe impl HasRawDisplayHandle for Window {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        RawDisplayHandle::UiKit(UiKitDisplayHandle::empty())
+    }
+}
+
+/// The window pointer is null if the view isn't currently in a window.
+#[cfg(uikit)]
+unsafe impl<T> HasRawWindowHandle for View<T> {
+    fn raw_window_handle(&self) -> RawWindowHandle {
+        let mut handle = UiKitWindowHandle::empty();
+        let (window, view) = self.get_from_backing_obj(|obj| {
+            let window: id = unsafe { msg_send![obj, window] };
+            (to_ptr(window), to_ptr(obj as *const Object as id))
+        });
+
+        handle.ui_window = window;
+        handle.ui_view = view;
+        RawWindowHandle::UiKit(handle)
+    }
+}
+
+#[cfg(uikit)]
+unsafe impl<T> HasRawDisplayHandle for View<T> {
+    fn raw_display_handle(&self) -> RawDisplayHandle {
+        RawDisplayHandle::UiKit(UiKitDisplayHandle::empty())
+    }
+}